@@ -0,0 +1,15 @@
+use std::env;
+
+// Crates depending on this one can't see which of its features are on, so the
+// ones which change the code `#[wasm_bindgen]` expands to are passed on to
+// their build scripts as `DEP_WASM_BINDGEN_FEATURES_*` variables. `web-sys`
+// checks these before using bindings it was shipped with already expanded.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    for feature in ["static-descriptors", "stable-abi"].iter() {
+        let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace("-", "_"));
+        if env::var_os(var).is_some() {
+            println!("cargo:{}=1", feature.replace("-", "_"));
+        }
+    }
+}
@@ -19,12 +19,27 @@ pub struct Program {
     pub structs: Vec<Struct>,
     /// rust consts
     pub consts: Vec<Const>,
+    /// rust consts and statics exported to js
+    pub exported_consts: Vec<ExportedConst>,
     /// rust submodules
     pub modules: Vec<Module>,
     /// "dictionaries", generated for WebIDL, which are basically just "typed
     /// objects" in the sense that they represent a JS object with a particular
     /// shape in JIT parlance.
     pub dictionaries: Vec<Dictionary>,
+    /// rust structs received from JS as plain options objects
+    pub options: Vec<OptionsStruct>,
+    /// rust enums received from JS as a union of their variants' types
+    pub unions: Vec<Union>,
+    /// rust traits whose objects cross to JS as instances of a class
+    pub traits: Vec<Trait>,
+    /// The path to the `wasm_bindgen` crate to use in the generated code, if
+    /// it isn't `::wasm_bindgen`, for example because it's re-exported by
+    /// another crate.
+    pub wasm_bindgen: Option<syn::Path>,
+    /// The path to the `wasm_bindgen_futures` crate which exported `async fn`s
+    /// use, if it isn't `::wasm_bindgen_futures`.
+    pub wasm_bindgen_futures: Option<syn::Path>,
 }
 
 /// A rust to js interface. Allows interaction with rust objects/functions
@@ -32,8 +47,11 @@ pub struct Program {
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
 #[derive(Clone)]
 pub struct Export {
-    /// The javascript class name.
+    /// The rust type of the class this method belongs to.
     pub class: Option<Ident>,
+    /// The javascript class name, which is the name of `class` unless the
+    /// struct was exported under a different one.
+    pub js_class: Option<String>,
     /// The type of `self` (either `self`, `&self`, or `&mut self`)
     pub method_self: Option<MethodSelf>,
     /// The name of the constructor function (e.g. new).
@@ -47,6 +65,39 @@ pub struct Export {
     pub comments: Vec<String>,
     /// The name of the rust function/method on the rust side.
     pub rust_name: Ident,
+    /// Whether trailing `Option` arguments may be omitted by JS callers.
+    pub optional_args: bool,
+    /// Whether maps in the signature are JS `Map`s rather than plain objects.
+    pub js_map: bool,
+    /// The JS default value, if any, of each argument.
+    pub defaults: Vec<Option<String>>,
+    /// Whether the generated method is enumerable.
+    pub enumerable: bool,
+    /// Whether the generated method is configurable.
+    pub configurable: bool,
+    /// Whether this is an `async fn`, whose future is returned to JS as a
+    /// `Promise`.
+    pub is_async: bool,
+    /// The JS property this method is a getter for, if any.
+    pub getter: Option<String>,
+    /// The JS property this method is a setter for, if any.
+    pub setter: Option<String>,
+    /// Which indexing operation on instances of the class, if any, this
+    /// method implements.
+    pub indexing: Option<IndexingKind>,
+    /// The well-known symbol, like `toPrimitive` for `Symbol.toPrimitive`,
+    /// which this method is keyed by in JS rather than by its name.
+    pub symbol: Option<String>,
+}
+
+/// The indexing operations on instances of an exported class which can be
+/// forwarded to its methods, like `obj[i]` for `IndexingKind::Getter`.
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone, Copy)]
+pub enum IndexingKind {
+    Getter,
+    Setter,
+    Deleter,
 }
 
 /// The 3 types variations of `self`.
@@ -65,6 +116,8 @@ pub enum MethodSelf {
 #[derive(Clone)]
 pub struct Import {
     pub module: Option<String>,
+    /// The module to import from instead when targeting Node.js.
+    pub node_module: Option<String>,
     pub js_namespace: Option<Ident>,
     pub kind: ImportKind,
 }
@@ -85,6 +138,19 @@ pub struct ImportFunction {
     pub rust_name: Ident,
     pub js_ret: Option<syn::Type>,
     pub catch: bool,
+    pub assert_no_shim: bool,
+    /// Whether trailing `None` arguments are left out of the call, rather than
+    /// passed as `undefined`.
+    pub optional_args: bool,
+    /// Whether the last argument is a slice which is spread into the call, as
+    /// the arguments of a variadic JS function.
+    pub variadic: bool,
+    /// Prefixes, like `webkit`, of the names to fall back on when the global
+    /// function is missing.
+    pub vendor_prefixes: Vec<Ident>,
+    /// A module exporting an implementation of the function to use when the
+    /// browser doesn't have one.
+    pub polyfill: Option<String>,
     pub structural: bool,
     pub kind: ImportFunctionKind,
     pub shim: Ident,
@@ -147,6 +213,15 @@ pub struct ImportType {
     pub doc_comment: Option<String>,
     pub instanceof_shim: String,
     pub extends: Vec<Ident>,
+    /// If set, `instanceof` checks for the exported struct of another wasm
+    /// module with this `shared_class` name, instead of a JS class.
+    pub shared_class: Option<String>,
+    /// Prefixes, like `webkit`, of the names to fall back on when the global
+    /// class is missing.
+    pub vendor_prefixes: Vec<Ident>,
+    /// A module exporting an implementation of the class to use when the
+    /// browser doesn't have one.
+    pub polyfill: Option<String>,
 }
 
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
@@ -178,8 +253,30 @@ pub struct Function {
 #[derive(Clone)]
 pub struct Struct {
     pub name: Ident,
+    /// The name of the JS class, which is `name` unless it's renamed with
+    /// `js_name`.
+    pub js_name: String,
     pub fields: Vec<StructField>,
     pub comments: Vec<String>,
+    pub extends: Option<Ident>,
+    /// The field holding the parent, if this extends an exported Rust struct.
+    pub parent_field: Option<Ident>,
+    /// Set if this struct is exported as a Web Component.
+    pub custom_element: Option<CustomElement>,
+    /// Whether JS can iterate over this struct with `for await`.
+    pub async_iterator: bool,
+    /// Whether JS can iterate over this struct's `Iterator` implementation
+    /// with `for..of`.
+    pub iterator: bool,
+    /// The name other wasm modules can recognize instances of this struct by.
+    pub shared_class: Option<String>,
+}
+
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub struct CustomElement {
+    pub name: String,
+    pub observed_attributes: Vec<String>,
 }
 
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
@@ -188,12 +285,45 @@ pub struct StructField {
     pub name: Ident,
     pub struct_name: Ident,
     pub readonly: bool,
+    pub enumerable: bool,
+    pub configurable: bool,
     pub ty: syn::Type,
     pub getter: Ident,
     pub setter: Ident,
     pub comments: Vec<String>,
 }
 
+/// A trait exported with `#[wasm_bindgen]`.
+///
+/// `Box<dyn Trait>` is passed to JS as an instance of an exported struct
+/// wrapping it, whose methods call the trait's. In the other direction
+/// `&dyn Trait` is received from JS as an imported type which implements the
+/// trait by calling the methods of the same names on the JS object.
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub struct Trait {
+    pub name: Ident,
+    /// The exported struct wrapping a `Box<dyn Trait>`.
+    pub rust_struct: Ident,
+    /// The imported type of JS objects implementing the trait.
+    pub js_type: Ident,
+}
+
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub struct OptionsStruct {
+    pub name: Ident,
+    pub fields: Vec<OptionsField>,
+}
+
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub struct OptionsField {
+    pub name: Ident,
+    pub js_name: String,
+    pub ty: syn::Type,
+}
+
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
 #[derive(Clone)]
 pub struct Enum {
@@ -207,6 +337,24 @@ pub struct Enum {
 pub struct Variant {
     pub name: Ident,
     pub value: u32,
+    /// The string JS uses for this variant, if the enum is a string enum.
+    pub string_value: Option<String>,
+}
+
+/// An enum whose variants each wrap a value of a different type, which JS
+/// passes as a value of any of those types.
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub struct Union {
+    pub name: Ident,
+    pub variants: Vec<UnionVariant>,
+}
+
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub struct UnionVariant {
+    pub name: Ident,
+    pub ty: syn::Type,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -245,6 +393,18 @@ pub enum ConstValue {
     Null,
 }
 
+/// A `const` or `static` exported to JS as a constant.
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub struct ExportedConst {
+    pub js_name: String,
+    pub rust_name: Ident,
+    /// The value as a JS literal, which is always the literal the item was
+    /// defined with.
+    pub value: String,
+    pub comments: Vec<String>,
+}
+
 /// A rust module
 ///
 /// This exists to give the ability to namespace js imports.
@@ -262,6 +422,7 @@ pub struct Module {
 pub struct Dictionary {
     pub name: Ident,
     pub fields: Vec<DictionaryField>,
+    pub doc_comment: Option<String>,
 }
 
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
@@ -270,6 +431,7 @@ pub struct DictionaryField {
     pub name: Ident,
     pub required: bool,
     pub ty: syn::Type,
+    pub doc_comment: Option<String>,
 }
 
 impl Program {
@@ -278,6 +440,7 @@ impl Program {
             exports: self.exports.iter().map(|a| a.shared()).collect(),
             structs: self.structs.iter().map(|a| a.shared()).collect(),
             enums: self.enums.iter().map(|a| a.shared()).collect(),
+            consts: self.exported_consts.iter().map(|a| a.shared()).collect(),
             imports: self.imports.iter()
                 // add in imports from inside modules
                 .chain(self.modules.iter().flat_map(|m| m.imports.iter()))
@@ -285,6 +448,11 @@ impl Program {
                 .collect::<Result<_, Diagnostic>>()?,
             version: shared::version(),
             schema_version: shared::SCHEMA_VERSION.to_string(),
+            stable_abi: if cfg!(feature = "stable-abi") {
+                Some(shared::STABLE_ABI_VERSION)
+            } else {
+                None
+            },
         })
     }
 }
@@ -316,8 +484,8 @@ impl Export {
     /// "high level" form before calling the actual function.
     pub(crate) fn export_name(&self) -> String {
         let fn_name = self.function.name.to_string();
-        match &self.class {
-            Some(class) => shared::struct_function_export_name(&class.to_string(), &fn_name),
+        match &self.js_class {
+            Some(class) => shared::struct_function_export_name(class, &fn_name),
             None => shared::free_function_export_name(&fn_name),
         }
     }
@@ -329,12 +497,26 @@ impl Export {
             None => (false, false),
         };
         shared::Export {
-            class: self.class.as_ref().map(|s| s.to_string()),
+            class: self.js_class.clone(),
             method,
             consumed,
             constructor: self.constructor.clone(),
             function: self.function.shared(),
+            rust_name: self.rust_name.to_string(),
             comments: self.comments.clone(),
+            optional_args: self.optional_args,
+            js_map: self.js_map,
+            defaults: self.defaults.clone(),
+            enumerable: self.enumerable,
+            configurable: self.configurable,
+            getter: self.getter.clone(),
+            setter: self.setter.clone(),
+            indexing: self.indexing.map(|kind| match kind {
+                IndexingKind::Getter => shared::IndexingKind::Getter,
+                IndexingKind::Setter => shared::IndexingKind::Setter,
+                IndexingKind::Deleter => shared::IndexingKind::Deleter,
+            }),
+            symbol: self.symbol.clone(),
         }
     }
 }
@@ -349,11 +531,23 @@ impl Enum {
     }
 }
 
+impl ExportedConst {
+    fn shared(&self) -> shared::Const {
+        shared::Const {
+            name: self.js_name.clone(),
+            rust_name: self.rust_name.to_string(),
+            value: self.value.clone(),
+            comments: self.comments.clone(),
+        }
+    }
+}
+
 impl Variant {
     fn shared(&self) -> shared::EnumVariant {
         shared::EnumVariant {
             name: self.name.to_string(),
             value: self.value,
+            string_value: self.string_value.clone(),
         }
     }
 }
@@ -362,6 +556,7 @@ impl Import {
     fn shared(&self) -> Result<shared::Import, Diagnostic> {
         Ok(shared::Import {
             module: self.module.clone(),
+            node_module: self.node_module.clone(),
             js_namespace: self.js_namespace.as_ref().map(|s| s.to_string()),
             kind: self.kind.shared(),
         })
@@ -449,6 +644,11 @@ impl ImportFunction {
         shared::ImportFunction {
             shim: self.shim.to_string(),
             catch: self.catch,
+            assert_no_shim: self.assert_no_shim,
+            optional_args: self.optional_args,
+            variadic: self.variadic,
+            vendor_prefixes: self.vendor_prefixes.iter().map(|p| p.to_string()).collect(),
+            polyfill: self.polyfill.clone(),
             method,
             structural: self.structural,
             function: self.function.shared(),
@@ -469,7 +669,11 @@ impl ImportType {
     fn shared(&self) -> shared::ImportType {
         shared::ImportType {
             name: self.js_name.clone(),
+            rust_name: self.rust_name.to_string(),
             instanceof_shim: self.instanceof_shim.clone(),
+            shared_class: self.shared_class.clone(),
+            vendor_prefixes: self.vendor_prefixes.iter().map(|p| p.to_string()).collect(),
+            polyfill: self.polyfill.clone(),
         }
     }
 }
@@ -483,9 +687,25 @@ impl ImportEnum {
 impl Struct {
     fn shared(&self) -> shared::Struct {
         shared::Struct {
-            name: self.name.to_string(),
+            name: self.js_name.clone(),
+            rust_name: self.name.to_string(),
             fields: self.fields.iter().map(|s| s.shared()).collect(),
             comments: self.comments.clone(),
+            extends: self.extends.as_ref().map(|e| e.to_string()),
+            extends_rust: self.parent_field.is_some(),
+            custom_element: self.custom_element.as_ref().map(|c| c.shared()),
+            async_iterator: self.async_iterator,
+            iterator: self.iterator,
+            shared_class: self.shared_class.clone(),
+        }
+    }
+}
+
+impl CustomElement {
+    fn shared(&self) -> shared::CustomElement {
+        shared::CustomElement {
+            name: self.name.clone(),
+            observed_attributes: self.observed_attributes.clone(),
         }
     }
 }
@@ -495,6 +715,8 @@ impl StructField {
         shared::StructField {
             name: self.name.to_string(),
             readonly: self.readonly,
+            enumerable: self.enumerable,
+            configurable: self.configurable,
             comments: self.comments.clone(),
         }
     }
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::mem;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
@@ -10,7 +11,7 @@ use syn;
 
 use ast;
 use Diagnostic;
-use util::ShortHash;
+use util::{self, ShortHash};
 
 pub trait TryToTokens {
     fn try_to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostic>;
@@ -24,7 +25,8 @@ pub trait TryToTokens {
 
 impl TryToTokens for ast::Program {
     // Generate wrappers for all the items that we've found
-    fn try_to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostic> {
+    fn try_to_tokens(&self, into: &mut TokenStream) -> Result<(), Diagnostic> {
+        let tokens = &mut TokenStream::new();
         let mut errors = Vec::new();
         for export in self.exports.iter() {
             if let Err(e) = export.try_to_tokens(tokens) {
@@ -77,6 +79,15 @@ impl TryToTokens for ast::Program {
         for d in self.dictionaries.iter() {
             d.to_tokens(tokens);
         }
+        for o in self.options.iter() {
+            o.to_tokens(tokens);
+        }
+        for u in self.unions.iter() {
+            u.to_tokens(tokens);
+        }
+        for t in self.traits.iter() {
+            t.to_tokens(tokens);
+        }
 
         Diagnostic::from_vec(errors)?;
 
@@ -117,6 +128,17 @@ impl TryToTokens for ast::Program {
                 *#generated_static_value;
         }).to_tokens(tokens);
 
+        // Everything above refers to the crates as `::wasm_bindgen` and
+        // `::wasm_bindgen_futures`, so that's the only thing which needs
+        // changing to use other paths to them.
+        let mut tokens = mem::replace(tokens, TokenStream::new());
+        if let Some(path) = &self.wasm_bindgen {
+            tokens = util::replace_crate_path(tokens, "wasm_bindgen", path);
+        }
+        if let Some(path) = &self.wasm_bindgen_futures {
+            tokens = util::replace_crate_path(tokens, "wasm_bindgen_futures", path);
+        }
+        into.extend(tokens);
         Ok(())
     }
 }
@@ -124,26 +146,15 @@ impl TryToTokens for ast::Program {
 impl ToTokens for ast::Struct {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = &self.name;
-        let name_str = name.to_string();
-        let name_len = name_str.len() as u32;
-        let name_chars = name_str.chars().map(|c| c as u32);
-        let new_fn = Ident::new(&shared::new_function(&name_str), Span::call_site());
-        let free_fn = Ident::new(&shared::free_function(&name_str), Span::call_site());
+        let describe = Description::default()
+            .number(quote!(RUST_STRUCT))
+            .string(&self.js_name)
+            .impl_items();
+        let new_fn = Ident::new(&shared::new_function(&self.js_name), Span::call_site());
+        let free_fn = Ident::new(&shared::free_function(&self.js_name), Span::call_site());
         (quote! {
             impl ::wasm_bindgen::describe::WasmDescribe for #name {
-                fn describe() {
-                    use wasm_bindgen::__wbindgen_if_not_std;
-                    __wbindgen_if_not_std! {
-                        compile_error! {
-                            "exporting a class to JS requires the `std` feature to \
-                             be enabled in the `wasm-bindgen` crate"
-                        }
-                    }
-                    use wasm_bindgen::describe::*;
-                    inform(RUST_STRUCT);
-                    inform(#name_len);
-                    #(inform(#name_chars);)*
-                }
+                #describe
             }
 
             impl ::wasm_bindgen::convert::IntoWasmAbi for #name {
@@ -152,8 +163,15 @@ impl ToTokens for ast::Struct {
                 fn into_abi(self, _extra: &mut ::wasm_bindgen::convert::Stack)
                     -> u32
                 {
-                    use wasm_bindgen::__rt::std::boxed::Box;
-                    use wasm_bindgen::__rt::WasmRefCell;
+                    use ::wasm_bindgen::__wbindgen_if_not_std;
+                    __wbindgen_if_not_std! {
+                        compile_error! {
+                            "exporting a class to JS requires the `std` feature to \
+                             be enabled in the `wasm-bindgen` crate"
+                        }
+                    }
+                    use ::wasm_bindgen::__rt::std::boxed::Box;
+                    use ::wasm_bindgen::__rt::WasmRefCell;
                     Box::into_raw(Box::new(WasmRefCell::new(self))) as u32
                 }
             }
@@ -164,8 +182,8 @@ impl ToTokens for ast::Struct {
                 unsafe fn from_abi(js: u32, _extra: &mut ::wasm_bindgen::convert::Stack)
                     -> Self
                 {
-                    use wasm_bindgen::__rt::std::boxed::Box;
-                    use wasm_bindgen::__rt::{assert_not_null, WasmRefCell};
+                    use ::wasm_bindgen::__rt::std::boxed::Box;
+                    use ::wasm_bindgen::__rt::{assert_not_null, WasmRefCell};
 
                     let ptr = js as *mut WasmRefCell<#name>;
                     assert_not_null(ptr);
@@ -205,6 +223,16 @@ impl ToTokens for ast::Struct {
                 }
             }
 
+            impl ::wasm_bindgen::convert::OptionIntoWasmAbi for #name {
+                fn none() -> u32 { 0 }
+            }
+
+            impl ::wasm_bindgen::convert::OptionFromWasmAbi for #name {
+                fn is_none(abi: &u32) -> bool { *abi == 0 }
+            }
+
+            impl ::wasm_bindgen::convert::VectorIntoWasmAbi for #name {}
+
             #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
             #[no_mangle]
             #[doc(hidden)]
@@ -242,8 +270,40 @@ impl ToTokens for ast::Struct {
                     (*js).borrow_mut()
                 }
             }
+
+            impl ::wasm_bindgen::convert::OptionRefFromWasmAbi for #name {
+                fn is_none(abi: &u32) -> bool { *abi == 0 }
+            }
+
+            impl ::wasm_bindgen::convert::OptionRefMutFromWasmAbi for #name {
+                fn is_none(abi: &u32) -> bool { *abi == 0 }
+            }
         }).to_tokens(tokens);
 
+        if let (Some(parent), Some(field)) = (&self.extends, &self.parent_field) {
+            // JS will use a pointer to this struct as a pointer to its parent,
+            // so make sure the parent field really is of the parent's type.
+            let assert_fn = Ident::new(
+                &format!("__wbindgen_assert_{}_extends_{}", name, parent),
+                Span::call_site(),
+            );
+            // The value of a `WasmRefCell` is at the same offset for everything
+            // up to the alignment of its borrow flag, but a child aligned more
+            // than that, and more than its parent, would have its value further
+            // along than JS expects a parent's to be. The array lengths only
+            // match otherwise.
+            (quote! {
+                #[allow(dead_code, non_snake_case)]
+                fn #assert_fn(child: &#name) -> &#parent {
+                    use ::wasm_bindgen::__rt::core::mem::align_of;
+                    use ::wasm_bindgen::__rt::WasmRefCell;
+                    let _: [(); 0] = [(); ((align_of::<#name>() > align_of::<WasmRefCell<()>>())
+                        & (align_of::<#name>() != align_of::<#parent>())) as usize];
+                    &child.#field
+                }
+            }).to_tokens(tokens);
+        }
+
         for field in self.fields.iter() {
             field.to_tokens(tokens);
         }
@@ -264,8 +324,7 @@ impl ToTokens for ast::StructField {
             pub unsafe extern fn #getter(js: u32)
                 -> <#ty as ::wasm_bindgen::convert::IntoWasmAbi>::Abi
             {
-                use wasm_bindgen::__rt::{WasmRefCell, assert_not_null};
-                use wasm_bindgen::convert::{GlobalStack, IntoWasmAbi};
+                use ::wasm_bindgen::__rt::{WasmRefCell, assert_not_null, return_abi};
 
                 fn assert_copy<T: Copy>(){}
                 assert_copy::<#ty>();
@@ -273,16 +332,11 @@ impl ToTokens for ast::StructField {
                 let js = js as *mut WasmRefCell<#struct_name>;
                 assert_not_null(js);
                 let val = (*js).borrow().#name;
-                <#ty as IntoWasmAbi>::into_abi(
-                    val,
-                    &mut GlobalStack::new(),
-                )
+                return_abi::<#ty>(val)
             }
         }).to_tokens(tokens);
 
-        Descriptor(&getter, quote! {
-            <#ty as WasmDescribe>::describe();
-        }).to_tokens(tokens);
+        Descriptor(&getter, Description::default().ty(ty)).to_tokens(tokens);
 
         if self.readonly {
             return;
@@ -296,15 +350,12 @@ impl ToTokens for ast::StructField {
                 js: u32,
                 val: <#ty as ::wasm_bindgen::convert::FromWasmAbi>::Abi,
             ) {
-                use wasm_bindgen::__rt::{WasmRefCell, assert_not_null};
-                use wasm_bindgen::convert::{GlobalStack, FromWasmAbi};
+                use ::wasm_bindgen::__rt::{WasmRefCell, assert_not_null, arg_from_abi};
+                use ::wasm_bindgen::convert::GlobalStack;
 
                 let js = js as *mut WasmRefCell<#struct_name>;
                 assert_not_null(js);
-                let val = <#ty as FromWasmAbi>::from_abi(
-                    val,
-                    &mut GlobalStack::new(),
-                );
+                let val = arg_from_abi::<#ty>(val, &mut GlobalStack::new());
                 (*js).borrow_mut().#name = val;
             }
         }).to_tokens(tokens);
@@ -335,10 +386,7 @@ impl TryToTokens for ast::Export {
                 let class = self.class.as_ref().unwrap();
                 arg_conversions.push(quote! {
                     let me = unsafe {
-                        <#class as ::wasm_bindgen::convert::FromWasmAbi>::from_abi(
-                            me,
-                            &mut ::wasm_bindgen::convert::GlobalStack::new(),
-                        )
+                        ::wasm_bindgen::__rt::arg_from_abi::<#class>(me, &mut __stack)
                     };
                 });
                 quote! { me.#name }
@@ -347,11 +395,7 @@ impl TryToTokens for ast::Export {
                 let class = self.class.as_ref().unwrap();
                 arg_conversions.push(quote! {
                     let mut me = unsafe {
-                        <#class as ::wasm_bindgen::convert::RefMutFromWasmAbi>
-                            ::ref_mut_from_abi(
-                                me,
-                                &mut ::wasm_bindgen::convert::GlobalStack::new(),
-                            )
+                        ::wasm_bindgen::__rt::ref_mut_from_abi::<#class>(me, &mut __stack)
                     };
                     let me = &mut *me;
                 });
@@ -361,11 +405,7 @@ impl TryToTokens for ast::Export {
                 let class = self.class.as_ref().unwrap();
                 arg_conversions.push(quote! {
                     let me = unsafe {
-                        <#class as ::wasm_bindgen::convert::RefFromWasmAbi>
-                            ::ref_from_abi(
-                                me,
-                                &mut ::wasm_bindgen::convert::GlobalStack::new(),
-                            )
+                        ::wasm_bindgen::__rt::ref_from_abi::<#class>(me, &mut __stack)
                     };
                     let me = &*me;
                 });
@@ -380,6 +420,40 @@ impl TryToTokens for ast::Export {
         for (i, syn::ArgCaptured { ty, .. }) in self.function.arguments.iter().enumerate() {
             let i = i + offset;
             let ident = Ident::new(&format!("arg{}", i), Span::call_site());
+            // `Option<&T>` is passed the same way as `&T`, with JS sending a
+            // value `T` recognizes as none for `None`.
+            if let Some(reference) = util::option_ref(ty) {
+                let elem = &reference.elem;
+                if reference.mutability.is_some() {
+                    args.push(quote! {
+                        #ident: <#elem as ::wasm_bindgen::convert::RefMutFromWasmAbi>::Abi
+                    });
+                    arg_conversions.push(quote! {
+                        let mut #ident = unsafe {
+                            ::wasm_bindgen::__rt::option_ref_mut_from_abi::<#elem>(
+                                #ident,
+                                &mut __stack,
+                            )
+                        };
+                        let #ident = #ident.as_mut().map(|r| &mut **r);
+                    });
+                } else {
+                    args.push(quote! {
+                        #ident: <#elem as ::wasm_bindgen::convert::RefFromWasmAbi>::Abi
+                    });
+                    arg_conversions.push(quote! {
+                        let #ident = unsafe {
+                            ::wasm_bindgen::__rt::option_ref_from_abi::<#elem>(
+                                #ident,
+                                &mut __stack,
+                            )
+                        };
+                        let #ident = #ident.as_ref().map(|r| &**r);
+                    });
+                }
+                converted_arguments.push(quote! { #ident });
+                continue;
+            }
             match *ty {
                 syn::Type::Reference(syn::TypeReference {
                     mutability: Some(_),
@@ -391,8 +465,7 @@ impl TryToTokens for ast::Export {
                     });
                     arg_conversions.push(quote! {
                         let mut #ident = unsafe {
-                            <#elem as ::wasm_bindgen::convert::RefMutFromWasmAbi>
-                                ::ref_mut_from_abi(#ident, &mut __stack)
+                            ::wasm_bindgen::__rt::ref_mut_from_abi::<#elem>(#ident, &mut __stack)
                         };
                         let #ident = &mut *#ident;
                     });
@@ -403,8 +476,7 @@ impl TryToTokens for ast::Export {
                     });
                     arg_conversions.push(quote! {
                         let #ident = unsafe {
-                            <#elem as ::wasm_bindgen::convert::RefFromWasmAbi>
-                                ::ref_from_abi(#ident, &mut __stack)
+                            ::wasm_bindgen::__rt::ref_from_abi::<#elem>(#ident, &mut __stack)
                         };
                         let #ident = &*#ident;
                     });
@@ -415,8 +487,7 @@ impl TryToTokens for ast::Export {
                     });
                     arg_conversions.push(quote! {
                         let #ident = unsafe {
-                            <#ty as ::wasm_bindgen::convert::FromWasmAbi>
-                                ::from_abi(#ident, &mut __stack)
+                            ::wasm_bindgen::__rt::arg_from_abi::<#ty>(#ident, &mut __stack)
                         };
                     });
                 }
@@ -426,6 +497,18 @@ impl TryToTokens for ast::Export {
         let ret_ty;
         let convert_ret;
         match &self.function.ret {
+            // An `async fn` hands its future off to `wasm-bindgen-futures`,
+            // which drives it to completion and gives us back a `Promise`.
+            _ if self.is_async => {
+                ret_ty = quote! {
+                    -> <::wasm_bindgen::JsValue as ::wasm_bindgen::convert::IntoWasmAbi>::Abi
+                };
+                convert_ret = quote! {
+                    ::wasm_bindgen::__rt::return_abi::<::wasm_bindgen::JsValue>(
+                        ::wasm_bindgen_futures::__rt::async_to_promise(#ret).into(),
+                    )
+                };
+            }
             Some(syn::Type::Reference(_)) => {
                 bail_span!(
                     self.function.ret,
@@ -437,10 +520,7 @@ impl TryToTokens for ast::Export {
                     -> <#ty as ::wasm_bindgen::convert::IntoWasmAbi>::Abi
                 };
                 convert_ret = quote! {
-                    <#ty as ::wasm_bindgen::convert::IntoWasmAbi>
-                        ::into_abi(#ret, &mut unsafe {
-                            ::wasm_bindgen::convert::GlobalStack::new()
-                        })
+                    ::wasm_bindgen::__rt::return_abi::<#ty>(#ret)
                 };
             }
             None => {
@@ -448,35 +528,46 @@ impl TryToTokens for ast::Export {
                 convert_ret = quote!();
             }
         }
-        let describe_ret = match &self.function.ret {
-            Some(ty) => {
-                quote! {
-                    inform(1);
-                    <#ty as WasmDescribe>::describe();
-                }
-            }
-            None => quote! { inform(0); },
+        let mut description = Description::default()
+            .number(quote!(FUNCTION))
+            .number(self.function.arguments.len() as u32);
+        for arg in self.function.arguments.iter() {
+            description = description.ty(&arg.ty);
+        }
+        description = match &self.function.ret {
+            _ if self.is_async => description.number(quote!(1)).ty(quote!(::wasm_bindgen::JsValue)),
+            Some(ty) => description.number(quote!(1)).ty(ty),
+            None => description.number(quote!(0)),
         };
-        let nargs = self.function.arguments.len() as u32;
-        let argtys = self.function.arguments.iter().map(|arg| &arg.ty);
         let attrs = &self.function.rust_attrs;
+        let shim_doc = format!(
+            "The shim which the generated JS calls for `{}`, converting its \
+             arguments and return value to and from the wasm ABI.",
+            match &self.class {
+                Some(class) => format!("{}::{}", class, name),
+                None => name.to_string(),
+            },
+        );
 
         (quote! {
             #(#attrs)*
+            #[doc = #shim_doc]
             #[export_name = #export_name]
             #[allow(non_snake_case)]
             #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
             pub extern fn #generated_name(#(#args),*) #ret_ty {
                 // See definition of `link_mem_intrinsics` for what this is doing
                 ::wasm_bindgen::__rt::link_mem_intrinsics();
-                let #ret = {
-                    let mut __stack = unsafe {
-                        ::wasm_bindgen::convert::GlobalStack::new()
+                ::wasm_bindgen::__rt::catch_unwind(move || {
+                    let #ret = {
+                        let mut __stack = unsafe {
+                            ::wasm_bindgen::convert::GlobalStack::new()
+                        };
+                        #(#arg_conversions)*
+                        #receiver(#(#converted_arguments),*)
                     };
-                    #(#arg_conversions)*
-                    #receiver(#(#converted_arguments),*)
-                };
-                #convert_ret
+                    #convert_ret
+                })
             }
         }).to_tokens(into);
 
@@ -497,12 +588,7 @@ impl TryToTokens for ast::Export {
         // this, but the tl;dr; is that this is stripped from the final wasm
         // binary along with anything it references.
         let export = Ident::new(&export_name, Span::call_site());
-        Descriptor(&export, quote! {
-            inform(FUNCTION);
-            inform(#nargs);
-            #(<#argtys as WasmDescribe>::describe();)*
-            #describe_ret
-        }).to_tokens(into);
+        Descriptor(&export, description).to_tokens(into);
 
         Ok(())
     }
@@ -532,7 +618,13 @@ impl ToTokens for ast::ImportType {
         };
         let const_name = format!("__wbg_generated_const_{}", rust_name);
         let const_name = Ident::new(&const_name, Span::call_site());
+        let const_doc = format!(
+            "The conversions between `{}` and the wasm ABI, which are all the \
+             same as `JsValue`'s.",
+            rust_name,
+        );
         let instanceof_shim = Ident::new(&self.instanceof_shim, Span::call_site());
+        let describe = Description::default().ty(quote!(JsValue)).impl_items();
         (quote! {
             #[allow(bad_style)]
             #(#attrs)*
@@ -543,18 +635,17 @@ impl ToTokens for ast::ImportType {
             }
 
             #[allow(bad_style)]
+            #[doc = #const_doc]
             const #const_name: () = {
-                use wasm_bindgen::convert::{IntoWasmAbi, FromWasmAbi, Stack};
-                use wasm_bindgen::convert::{OptionIntoWasmAbi, OptionFromWasmAbi};
-                use wasm_bindgen::convert::RefFromWasmAbi;
-                use wasm_bindgen::describe::WasmDescribe;
-                use wasm_bindgen::{JsValue, JsCast};
-                use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+                use ::wasm_bindgen::convert::{IntoWasmAbi, FromWasmAbi, Stack};
+                use ::wasm_bindgen::convert::{OptionIntoWasmAbi, OptionFromWasmAbi};
+                use ::wasm_bindgen::convert::{RefFromWasmAbi, OptionRefFromWasmAbi};
+                use ::wasm_bindgen::describe::WasmDescribe;
+                use ::wasm_bindgen::{JsValue, JsCast};
+                use ::wasm_bindgen::__rt::core::mem::ManuallyDrop;
 
                 impl WasmDescribe for #rust_name {
-                    fn describe() {
-                        JsValue::describe();
-                    }
+                    #describe
                 }
 
                 impl IntoWasmAbi for #rust_name {
@@ -607,6 +698,10 @@ impl ToTokens for ast::ImportType {
                     }
                 }
 
+                impl OptionRefFromWasmAbi for #rust_name {
+                    fn is_none(abi: &Self::Abi) -> bool { *abi == 0 }
+                }
+
                 // TODO: remove this on the next major version
                 impl From<JsValue> for #rust_name {
                     fn from(obj: JsValue) -> #rust_name {
@@ -672,21 +767,21 @@ impl ToTokens for ast::ImportType {
             (quote! {
                 impl From<#rust_name> for #superclass {
                     fn from(obj: #rust_name) -> #superclass {
-                        use wasm_bindgen::JsCast;
+                        use ::wasm_bindgen::JsCast;
                         #superclass::unchecked_from_js(obj.into())
                     }
                 }
 
                 impl AsRef<#superclass> for #rust_name {
                     fn as_ref(&self) -> &#superclass {
-                        use wasm_bindgen::JsCast;
+                        use ::wasm_bindgen::JsCast;
                         #superclass::unchecked_from_js_ref(self.as_ref())
                     }
                 }
 
                 impl AsMut<#superclass> for #rust_name {
                     fn as_mut(&mut self) -> &mut #superclass {
-                        use wasm_bindgen::JsCast;
+                        use ::wasm_bindgen::JsCast;
                         #superclass::unchecked_from_js_mut(self.as_mut())
                     }
                 }
@@ -727,6 +822,16 @@ impl ToTokens for ast::ImportEnum {
         // Borrow variant_paths because we need to use it multiple times inside the quote! macro
         let variant_paths_ref = &variant_paths;
 
+        // The variants cross the boundary as their index, and the JS glue
+        // looks the strings up in a table built from this descriptor.
+        let mut description = Description::default()
+            .number(quote!(STRING_ENUM))
+            .number(variants.len() as u32);
+        for v in variant_strings.iter() {
+            description = description.string(v);
+        }
+        let describe = description.impl_items();
+
         (quote! {
             #[allow(bad_style)]
             #(#attrs)*
@@ -746,29 +851,31 @@ impl ToTokens for ast::ImportEnum {
             }
 
             impl ::wasm_bindgen::describe::WasmDescribe for #name {
-                fn describe() {
-                    ::wasm_bindgen::JsValue::describe()
-                }
+                #describe
             }
 
             impl ::wasm_bindgen::convert::IntoWasmAbi for #name {
-                type Abi = <::wasm_bindgen::JsValue as
-                    ::wasm_bindgen::convert::IntoWasmAbi>::Abi;
+                type Abi = u32;
 
-                fn into_abi(self, extra: &mut ::wasm_bindgen::convert::Stack) -> Self::Abi {
-                    ::wasm_bindgen::JsValue::from(self).into_abi(extra)
+                fn into_abi(self, _extra: &mut ::wasm_bindgen::convert::Stack) -> u32 {
+                    match self {
+                        #name::__Nonexhaustive => panic!(#expect_string),
+                        other => other as u32,
+                    }
                 }
             }
 
             impl ::wasm_bindgen::convert::FromWasmAbi for #name {
-                type Abi = <::wasm_bindgen::JsValue as
-                    ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+                type Abi = u32;
 
                 unsafe fn from_abi(
-                    js: Self::Abi,
-                    extra: &mut ::wasm_bindgen::convert::Stack,
+                    js: u32,
+                    _extra: &mut ::wasm_bindgen::convert::Stack,
                 ) -> Self {
-                    #name::from_js_value(&::wasm_bindgen::JsValue::from_abi(js, extra)).unwrap_or(#name::__Nonexhaustive)
+                    match js {
+                        #(#variant_indexes_ref => #variant_paths_ref,)*
+                        _ => #name::__Nonexhaustive,
+                    }
                 }
             }
 
@@ -842,8 +949,7 @@ impl TryToTokens for ast::ImportFunction {
                 quote! { #name }
             };
             arg_conversions.push(quote! {
-                let #name = <#ty as ::wasm_bindgen::convert::IntoWasmAbi>
-                    ::into_abi(#var, &mut __stack);
+                let #name = ::wasm_bindgen::__rt::arg_into_abi::<#ty>(#var, &mut __stack);
             });
         }
         let abi_ret;
@@ -857,11 +963,7 @@ impl TryToTokens for ast::ImportFunction {
                     <#ty as ::wasm_bindgen::convert::FromWasmAbi>::Abi
                 };
                 convert_ret = quote! {
-                    <#ty as ::wasm_bindgen::convert::FromWasmAbi>
-                        ::from_abi(
-                            #ret_ident,
-                            &mut ::wasm_bindgen::convert::GlobalStack::new(),
-                        )
+                    ::wasm_bindgen::__rt::from_return_abi::<#ty>(#ret_ident)
                 };
             }
             None => {
@@ -878,13 +980,7 @@ impl TryToTokens for ast::ImportFunction {
             abi_arguments.push(quote! { #exn_data_ptr: *mut u32 });
             convert_ret = quote! { Ok(#convert_ret) };
             exceptional_ret = quote! {
-                if #exn_data[0] == 1 {
-                    return Err(
-                        <
-                            ::wasm_bindgen::JsValue as ::wasm_bindgen::convert::FromWasmAbi
-                        >::from_abi(#exn_data[1], &mut ::wasm_bindgen::convert::GlobalStack::new())
-                    )
-                }
+                ::wasm_bindgen::__rt::take_exception(&#exn_data)?;
             };
             quote! {
                 let mut #exn_data = [0; 2];
@@ -938,8 +1034,7 @@ impl TryToTokens for ast::ImportFunction {
             #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
             #[doc = #doc_comment]
             #vis fn #rust_name(#me #(#arguments),*) #ret {
-                panic!("cannot call wasm-bindgen imported functions on \
-                        non-wasm targets");
+                ::wasm_bindgen::__rt::import_on_non_wasm()
             }
 
         };
@@ -969,24 +1064,26 @@ impl<'a> ToTokens for DescribeImport<'a> {
             ast::ImportKind::Type(_) => return,
             ast::ImportKind::Enum(_) => return,
         };
-        let argtys = f.function.arguments.iter().map(|arg| &arg.ty);
-        let nargs = f.function.arguments.len() as u32;
-        let inform_ret = match &f.js_ret {
-            Some(ref t) => quote! { inform(1); <#t as WasmDescribe>::describe(); },
-            None => quote! { inform(0); },
+        let mut description = Description::default()
+            .number(quote!(FUNCTION))
+            .number(f.function.arguments.len() as u32);
+        for arg in f.function.arguments.iter() {
+            description = description.ty(&arg.ty);
+        }
+        description = match &f.js_ret {
+            Some(ref t) => description.number(quote!(1)).ty(t),
+            None => description.number(quote!(0)),
         };
 
-        Descriptor(&f.shim, quote! {
-            inform(FUNCTION);
-            inform(#nargs);
-            #(<#argtys as WasmDescribe>::describe();)*
-            #inform_ret
-        }).to_tokens(tokens);
+        Descriptor(&f.shim, description).to_tokens(tokens);
     }
 }
 
 impl ToTokens for ast::Enum {
     fn to_tokens(&self, into: &mut TokenStream) {
+        if self.variants.iter().any(|v| v.string_value.is_some()) {
+            return self.string_enum_to_tokens(into);
+        }
         let enum_name = &self.name;
         let cast_clauses = self.variants.iter().map(|variant| {
             let variant_name = &variant.name;
@@ -996,7 +1093,57 @@ impl ToTokens for ast::Enum {
                 }
             }
         });
+        let from_js_clauses = self.variants.iter().map(|variant| {
+            let variant_name = &variant.name;
+            quote! {
+                if n == #enum_name::#variant_name as u32 as f64 {
+                    Some(#enum_name::#variant_name)
+                }
+            }
+        });
+        let try_from = if cfg!(feature = "try-from") {
+            quote! {
+                impl ::wasm_bindgen::__rt::core::convert::TryFrom<::wasm_bindgen::JsValue>
+                    for #enum_name
+                {
+                    type Error = ::wasm_bindgen::JsValue;
+
+                    fn try_from(
+                        value: ::wasm_bindgen::JsValue,
+                    ) -> Result<#enum_name, ::wasm_bindgen::JsValue> {
+                        match #enum_name::from_js_value(&value) {
+                            Some(e) => Ok(e),
+                            None => Err(value),
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let describe = Description::default().number(quote!(ENUM)).impl_items();
         (quote! {
+            impl #enum_name {
+                /// Attempts to convert a JS value back into this enum,
+                /// returning `None` if the value is not a number matching
+                /// one of the enum's discriminants.
+                #[allow(dead_code)]
+                pub fn from_js_value(obj: &::wasm_bindgen::JsValue) -> Option<#enum_name> {
+                    let n = obj.as_f64()?;
+                    #(#from_js_clauses else)* {
+                        None
+                    }
+                }
+            }
+
+            impl From<#enum_name> for ::wasm_bindgen::JsValue {
+                fn from(value: #enum_name) -> ::wasm_bindgen::JsValue {
+                    ::wasm_bindgen::JsValue::from_f64((value as u32).into())
+                }
+            }
+
+            #try_from
+
             impl ::wasm_bindgen::convert::IntoWasmAbi for #enum_name {
                 type Abi = u32;
 
@@ -1019,11 +1166,112 @@ impl ToTokens for ast::Enum {
             }
 
             impl ::wasm_bindgen::describe::WasmDescribe for #enum_name {
-                fn describe() {
-                    use wasm_bindgen::describe::*;
-                    inform(ENUM);
+                #describe
+            }
+        }).to_tokens(into);
+    }
+}
+
+impl ast::Enum {
+    /// Enums whose variants are strings in JS cross the boundary as the index
+    /// of their variant, like imported string enums do.
+    fn string_enum_to_tokens(&self, into: &mut TokenStream) {
+        let enum_name = &self.name;
+        let variant_paths = self
+            .variants
+            .iter()
+            .map(|v| {
+                let name = &v.name;
+                quote!(#enum_name::#name)
+            })
+            .collect::<Vec<_>>();
+        let variant_paths = &variant_paths;
+        let variant_strings = self
+            .variants
+            .iter()
+            .map(|v| v.string_value.clone().unwrap())
+            .collect::<Vec<_>>();
+        let variant_strings = &variant_strings;
+        let variant_indexes = (0..self.variants.len() as u32).collect::<Vec<_>>();
+        let variant_indexes = &variant_indexes;
+        let mut description = Description::default()
+            .number(quote!(STRING_ENUM))
+            .number(self.variants.len() as u32);
+        for v in variant_strings.iter() {
+            description = description.string(v);
+        }
+        let describe = description.impl_items();
+        let try_from = if cfg!(feature = "try-from") {
+            quote! {
+                impl ::wasm_bindgen::__rt::core::convert::TryFrom<::wasm_bindgen::JsValue>
+                    for #enum_name
+                {
+                    type Error = ::wasm_bindgen::JsValue;
+
+                    fn try_from(
+                        value: ::wasm_bindgen::JsValue,
+                    ) -> Result<#enum_name, ::wasm_bindgen::JsValue> {
+                        match #enum_name::from_js_value(&value) {
+                            Some(e) => Ok(e),
+                            None => Err(value),
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        (quote! {
+            impl #enum_name {
+                /// Attempts to convert a JS value back into this enum,
+                /// returning `None` if the value is not a string matching
+                /// one of the enum's variants.
+                #[allow(dead_code)]
+                pub fn from_js_value(obj: &::wasm_bindgen::JsValue) -> Option<#enum_name> {
+                    obj.as_string().and_then(|obj_str| match obj_str.as_str() {
+                        #(#variant_strings => Some(#variant_paths),)*
+                        _ => None,
+                    })
+                }
+            }
+
+            impl From<#enum_name> for ::wasm_bindgen::JsValue {
+                fn from(value: #enum_name) -> ::wasm_bindgen::JsValue {
+                    match value {
+                        #(#variant_paths => ::wasm_bindgen::JsValue::from_str(#variant_strings),)*
+                    }
                 }
             }
+
+            #try_from
+
+            impl ::wasm_bindgen::convert::IntoWasmAbi for #enum_name {
+                type Abi = u32;
+
+                fn into_abi(self, _extra: &mut ::wasm_bindgen::convert::Stack) -> u32 {
+                    match self {
+                        #(#variant_paths => #variant_indexes,)*
+                    }
+                }
+            }
+
+            impl ::wasm_bindgen::convert::FromWasmAbi for #enum_name {
+                type Abi = u32;
+
+                unsafe fn from_abi(
+                    js: u32,
+                    _extra: &mut ::wasm_bindgen::convert::Stack,
+                ) -> Self {
+                    match js {
+                        #(#variant_indexes => #variant_paths,)*
+                        _ => ::wasm_bindgen::throw("invalid enum value passed"),
+                    }
+                }
+            }
+
+            impl ::wasm_bindgen::describe::WasmDescribe for #enum_name {
+                #describe
+            }
         }).to_tokens(into);
     }
 }
@@ -1044,11 +1292,7 @@ impl ToTokens for ast::ImportStatic {
                         fn #shim_name() -> <#ty as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
                     }
                     unsafe {
-                        <#ty as ::wasm_bindgen::convert::FromWasmAbi>::from_abi(
-                            #shim_name(),
-                            &mut ::wasm_bindgen::convert::GlobalStack::new(),
-                        )
-
+                        ::wasm_bindgen::__rt::from_return_abi::<#ty>(#shim_name())
                     }
                 }
                 #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
@@ -1156,10 +1400,23 @@ impl ToTokens for ast::Dictionary {
         let required_names2 = required_names;
         let required_names3 = required_names;
 
-        let const_name = Ident::new(&format!("_CONST_{}", name), Span::call_site());
+        let doc_comment = match &self.doc_comment {
+            None => "",
+            Some(doc_string) => doc_string,
+        };
+
+        let const_name = format!("__wbg_generated_const_{}", name);
+        let const_name = Ident::new(&const_name, Span::call_site());
+        let const_doc = format!(
+            "The conversions between `{}` and the wasm ABI, which are all the \
+             same as `Object`'s.",
+            name,
+        );
+        let describe = Description::default().ty(quote!(Object)).impl_items();
         (quote! {
             #[derive(Clone, Debug)]
             #[repr(transparent)]
+            #[doc = #doc_comment]
             pub struct #name {
                 obj: ::js_sys::Object,
             }
@@ -1175,12 +1432,13 @@ impl ToTokens for ast::Dictionary {
             }
 
             #[allow(bad_style)]
+            #[doc = #const_doc]
             const #const_name: () = {
                 use js_sys::Object;
-                use wasm_bindgen::describe::WasmDescribe;
-                use wasm_bindgen::convert::*;
-                use wasm_bindgen::{JsValue, JsCast};
-                use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+                use ::wasm_bindgen::describe::WasmDescribe;
+                use ::wasm_bindgen::convert::*;
+                use ::wasm_bindgen::{JsValue, JsCast};
+                use ::wasm_bindgen::__rt::core::mem::ManuallyDrop;
 
                 // interop w/ JsValue
                 impl From<#name> for JsValue {
@@ -1197,9 +1455,7 @@ impl ToTokens for ast::Dictionary {
 
                 // Boundary conversion impls
                 impl WasmDescribe for #name {
-                    fn describe() {
-                        Object::describe();
-                    }
+                    #describe
                 }
 
                 impl IntoWasmAbi for #name {
@@ -1230,7 +1486,9 @@ impl ToTokens for ast::Dictionary {
                     fn none() -> Self::Abi { <&'a Object>::none() }
                 }
                 impl OptionFromWasmAbi for #name {
-                    fn is_none(abi: &Self::Abi) -> bool { Object::is_none(abi) }
+                    fn is_none(abi: &Self::Abi) -> bool {
+                        <Object as OptionFromWasmAbi>::is_none(abi)
+                    }
                 }
 
                 impl RefFromWasmAbi for #name {
@@ -1245,6 +1503,12 @@ impl ToTokens for ast::Dictionary {
                     }
                 }
 
+                impl OptionRefFromWasmAbi for #name {
+                    fn is_none(abi: &Self::Abi) -> bool {
+                        <Object as OptionRefFromWasmAbi>::is_none(abi)
+                    }
+                }
+
                 impl JsCast for #name {
                     fn instanceof(val: &JsValue) -> bool {
                         Object::instanceof(val)
@@ -1271,9 +1535,14 @@ impl ToTokens for ast::DictionaryField {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = &self.name;
         let ty = &self.ty;
+        let doc_comment = match &self.doc_comment {
+            None => "",
+            Some(doc_string) => doc_string,
+        };
         (quote! {
+            #[doc = #doc_comment]
             pub fn #name(&mut self, val: #ty) -> &mut Self {
-                use wasm_bindgen::JsValue;
+                use ::wasm_bindgen::JsValue;
                 ::js_sys::Reflect::set(
                     self.obj.as_ref(),
                     &JsValue::from(stringify!(#name)),
@@ -1285,11 +1554,308 @@ impl ToTokens for ast::DictionaryField {
     }
 }
 
+impl ToTokens for ast::Union {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let abi = Ident::new(&format!("__wbg_union_abi_{}", name), Span::call_site());
+        let nvariants = self.variants.len() as u32;
+        let mut description = Description::default()
+            .number(quote!(UNION))
+            .string(&name.to_string())
+            .number(nvariants);
+        for v in self.variants.iter() {
+            description = description.ty(&v.ty);
+        }
+        let describe = description.impl_items();
+        let fields = &self
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(i, _)| Ident::new(&format!("variant{}", i), Span::call_site()))
+            .collect::<Vec<_>>();
+        let fields2 = fields;
+        let variants = &self.variants.iter().map(|v| &v.name).collect::<Vec<_>>();
+        let tys = &self.variants.iter().map(|v| &v.ty).collect::<Vec<_>>();
+        let tys2 = tys;
+        let tags = (0..nvariants).collect::<Vec<_>>();
+        let names = self.variants.iter().map(|_| name);
+        // JS passes the index of the variant it picked along with that
+        // variant's value, leaving every other variant zeroed.
+        (quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            #[allow(bad_style)]
+            pub struct #abi {
+                tag: u32,
+                #(#fields: <#tys as ::wasm_bindgen::convert::FromWasmAbi>::Abi,)*
+            }
+
+            unsafe impl ::wasm_bindgen::convert::WasmAbi for #abi {}
+
+            impl ::wasm_bindgen::describe::WasmDescribe for #name {
+                #describe
+            }
+
+            impl ::wasm_bindgen::convert::FromWasmAbi for #name {
+                type Abi = #abi;
+
+                unsafe fn from_abi(
+                    js: #abi,
+                    extra: &mut ::wasm_bindgen::convert::Stack,
+                ) -> Self {
+                    #(
+                        if js.tag == #tags {
+                            return #names::#variants(
+                                <#tys2 as ::wasm_bindgen::convert::FromWasmAbi>
+                                    ::from_abi(js.#fields2, extra),
+                            );
+                        }
+                    )*
+                    ::wasm_bindgen::throw("invalid union variant passed from JS")
+                }
+            }
+        }).to_tokens(tokens);
+    }
+}
+
+impl ToTokens for ast::Trait {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let rust_struct = &self.rust_struct;
+        let js_type = &self.js_type;
+        let anchor = Ident::new(&format!("__wbg_ref_{}", name), Span::call_site());
+        let describe_box = Description::default().ty(quote!(#rust_struct)).impl_items();
+        let describe_dyn = Description::default().number(quote!(ANYREF)).impl_items();
+        // Boxed trait objects are passed the same way as the struct wrapping
+        // them, while references are borrowed JS objects, which may also be
+        // instances of that struct's class.
+        (quote! {
+            impl ::wasm_bindgen::describe::WasmDescribe for Box<dyn #name> {
+                #describe_box
+            }
+
+            impl ::wasm_bindgen::convert::IntoWasmAbi for Box<dyn #name> {
+                type Abi = u32;
+
+                fn into_abi(self, extra: &mut ::wasm_bindgen::convert::Stack) -> u32 {
+                    ::wasm_bindgen::convert::IntoWasmAbi::into_abi(#rust_struct(self), extra)
+                }
+            }
+
+            impl ::wasm_bindgen::convert::FromWasmAbi for Box<dyn #name> {
+                type Abi = u32;
+
+                unsafe fn from_abi(js: u32, extra: &mut ::wasm_bindgen::convert::Stack) -> Self {
+                    <#rust_struct as ::wasm_bindgen::convert::FromWasmAbi>::from_abi(js, extra).0
+                }
+            }
+
+            impl ::wasm_bindgen::convert::OptionIntoWasmAbi for Box<dyn #name> {
+                fn none() -> u32 { 0 }
+            }
+
+            impl ::wasm_bindgen::convert::OptionFromWasmAbi for Box<dyn #name> {
+                fn is_none(abi: &u32) -> bool { *abi == 0 }
+            }
+
+            impl ::wasm_bindgen::convert::VectorIntoWasmAbi for Box<dyn #name> {}
+
+            impl ::wasm_bindgen::__rt::core::convert::From<Box<dyn #name>> for
+                ::wasm_bindgen::JsValue
+            {
+                fn from(value: Box<dyn #name>) -> Self {
+                    #rust_struct(value).into()
+                }
+            }
+
+            #[doc(hidden)]
+            #[allow(bad_style)]
+            pub struct #anchor(::wasm_bindgen::__rt::core::mem::ManuallyDrop<#js_type>);
+
+            impl ::wasm_bindgen::__rt::core::ops::Deref for #anchor {
+                type Target = dyn #name;
+
+                fn deref(&self) -> &(dyn #name + 'static) {
+                    let js: &#js_type = &self.0;
+                    js
+                }
+            }
+
+            impl ::wasm_bindgen::describe::WasmDescribe for dyn #name {
+                #describe_dyn
+            }
+
+            impl ::wasm_bindgen::convert::RefFromWasmAbi for dyn #name {
+                type Abi = u32;
+                type Anchor = #anchor;
+
+                unsafe fn ref_from_abi(
+                    js: u32,
+                    extra: &mut ::wasm_bindgen::convert::Stack,
+                ) -> Self::Anchor {
+                    #anchor(<#js_type as ::wasm_bindgen::convert::RefFromWasmAbi>
+                        ::ref_from_abi(js, extra))
+                }
+            }
+
+            impl ::wasm_bindgen::convert::OptionRefFromWasmAbi for dyn #name {
+                fn is_none(abi: &u32) -> bool { *abi == 0 }
+            }
+        }).to_tokens(tokens);
+    }
+}
+
+impl ToTokens for ast::OptionsStruct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let abi = Ident::new(&format!("__wbg_options_abi_{}", name), Span::call_site());
+        let mut description = Description::default()
+            .number(quote!(OPTIONS))
+            .string(&name.to_string())
+            .number(self.fields.len() as u32);
+        for f in self.fields.iter() {
+            description = description.string(&f.js_name).ty(&f.ty);
+        }
+        let describe = description.impl_items();
+        let names = &self.fields.iter().map(|f| &f.name).collect::<Vec<_>>();
+        let names2 = names;
+        let tys = &self.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+        (quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            #[allow(bad_style)]
+            pub struct #abi {
+                #(#names: <#tys as ::wasm_bindgen::convert::FromWasmAbi>::Abi,)*
+            }
+
+            unsafe impl ::wasm_bindgen::convert::WasmAbi for #abi {}
+
+            impl ::wasm_bindgen::describe::WasmDescribe for #name {
+                #describe
+            }
+
+            impl ::wasm_bindgen::convert::FromWasmAbi for #name {
+                type Abi = #abi;
+
+                unsafe fn from_abi(
+                    js: #abi,
+                    extra: &mut ::wasm_bindgen::convert::Stack,
+                ) -> Self {
+                    #name {
+                        #(
+                            #names: <#tys as ::wasm_bindgen::convert::FromWasmAbi>
+                                ::from_abi(js.#names2, extra),
+                        )*
+                    }
+                }
+            }
+
+            impl ::wasm_bindgen::convert::WasmOptions for #name {}
+        }).to_tokens(tokens);
+    }
+}
+
+/// The numbers and types describing a type or function to the CLI, in order.
+///
+/// This is emitted as the body of a `describe` function informing the CLI of
+/// them, or with the `static-descriptors` feature as a `Description` constant.
+#[derive(Default)]
+struct Description(Vec<DescriptionPiece>);
+
+enum DescriptionPiece {
+    Number(TokenStream),
+    Type(TokenStream),
+}
+
+impl Description {
+    fn number<T: ToTokens>(mut self, number: T) -> Description {
+        self.0.push(DescriptionPiece::Number(number.into_token_stream()));
+        self
+    }
+
+    /// Strings are described by their length in `char`s and then each `char`.
+    fn string(mut self, s: &str) -> Description {
+        self = self.number(s.chars().count() as u32);
+        for c in s.chars() {
+            self = self.number(c as u32);
+        }
+        self
+    }
+
+    fn ty<T: ToTokens>(mut self, ty: T) -> Description {
+        self.0.push(DescriptionPiece::Type(ty.into_token_stream()));
+        self
+    }
+
+    fn inform(&self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        for piece in self.0.iter() {
+            match piece {
+                DescriptionPiece::Number(n) => (quote! { inform(#n); }).to_tokens(&mut tokens),
+                DescriptionPiece::Type(t) => (quote! {
+                    <#t as WasmDescribe>::describe();
+                }).to_tokens(&mut tokens),
+            }
+        }
+        tokens
+    }
+
+    fn constant(&self) -> TokenStream {
+        // Runs of numbers are put in one slice rather than one each.
+        let mut parts = Vec::new();
+        let mut numbers = Vec::new();
+        for piece in self.0.iter() {
+            match piece {
+                DescriptionPiece::Number(n) => numbers.push(n),
+                DescriptionPiece::Type(t) => {
+                    if !numbers.is_empty() {
+                        let numbers = mem::replace(&mut numbers, Vec::new());
+                        parts.push(quote! { Description::Numbers(&[#(#numbers),*]) });
+                    }
+                    parts.push(quote! { <#t as WasmDescribe>::DESCRIPTION });
+                }
+            }
+        }
+        if !numbers.is_empty() {
+            parts.push(quote! { Description::Numbers(&[#(#numbers),*]) });
+        }
+        quote! {
+            {
+                use ::wasm_bindgen::describe::*;
+                Description::Concat(&[#(#parts),*])
+            }
+        }
+    }
+
+    /// The items of a `WasmDescribe` impl for a type with this description.
+    ///
+    /// The `DESCRIPTION` is always there, for impls written in terms of it,
+    /// and without `static-descriptors` `describe` informs the CLI of it
+    /// directly rather than reading it.
+    fn impl_items(&self) -> TokenStream {
+        let constant = self.constant();
+        let mut items = quote! {
+            const DESCRIPTION: ::wasm_bindgen::describe::Description = #constant;
+        };
+        if !cfg!(feature = "static-descriptors") {
+            let inform = self.inform();
+            items.extend(quote! {
+                fn describe() {
+                    use ::wasm_bindgen::describe::*;
+                    #inform
+                }
+            });
+        }
+        items
+    }
+}
+
 /// Emits the necessary glue tokens for "descriptor", generating an appropriate
-/// symbol name as well as attributes around the descriptor function itself.
-struct Descriptor<'a, T>(&'a Ident, T);
+/// symbol name as well as attributes around the descriptor function itself,
+/// or with the `static-descriptors` feature the data it would describe.
+struct Descriptor<'a>(&'a Ident, Description);
 
-impl<'a, T: ToTokens> ToTokens for Descriptor<'a, T> {
+impl<'a> ToTokens for Descriptor<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         // It's possible for the same descriptor to be emitted in two different
         // modules (aka a value imported twice in a crate, each in a separate
@@ -1307,14 +1873,45 @@ impl<'a, T: ToTokens> ToTokens for Descriptor<'a, T> {
         }
 
         let name = Ident::new(&format!("__wbindgen_describe_{}", self.0), self.0.span());
-        let inner = &self.1;
+
+        // The CLI reads these out of the custom section under the name of the
+        // describe function they replace, and removes the section.
+        if cfg!(feature = "static-descriptors") {
+            let name_str = name.to_string();
+            let description = Ident::new(
+                &format!("__wbindgen_description_{}", self.0),
+                self.0.span(),
+            );
+            let constant = self.1.constant();
+            (quote! {
+                #[allow(non_upper_case_globals)]
+                #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+                const #description: ::wasm_bindgen::describe::Description = #constant;
+
+                #[allow(non_upper_case_globals)]
+                #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+                #[link_section = "__wasm_bindgen_descriptors"]
+                #[doc(hidden)]
+                pub static #name: [u8; ::wasm_bindgen::describe::encoded_len(#name_str, &#description)] =
+                    ::wasm_bindgen::describe::encode(#name_str, &#description);
+            }).to_tokens(tokens);
+            return
+        }
+
+        let doc = format!(
+            "Describes the type of `{}` to the `wasm-bindgen` CLI, which runs \
+             this while generating bindings and then removes it from the wasm.",
+            self.0,
+        );
+        let inner = self.1.inform();
         (quote! {
+            #[doc = #doc]
             #[no_mangle]
             #[allow(non_snake_case)]
             #[doc(hidden)]
             #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
             pub extern fn #name() {
-                use wasm_bindgen::describe::*;
+                use ::wasm_bindgen::describe::*;
                 #inner
             }
         }).to_tokens(tokens);
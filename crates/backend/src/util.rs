@@ -8,7 +8,8 @@ use std::sync::atomic::{AtomicBool, ATOMIC_BOOL_INIT};
 use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT};
 
 use ast;
-use proc_macro2::{self, Ident};
+use proc_macro2::{self, Group, Ident, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
 use syn;
 
 fn is_rust_keyword(name: &str) -> bool {
@@ -91,14 +92,91 @@ pub fn ident_ty(ident: Ident) -> syn::Type {
     simple_path_ty(Some(ident))
 }
 
+/// Returns the reference in `ty` if it's syntactically `Option<&T>` or
+/// `Option<&mut T>`.
+pub fn option_ref(ty: &syn::Type) -> Option<&syn::TypeReference> {
+    let path = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path,
+        _ => return None,
+    };
+    let seg = path.segments.last()?.into_value();
+    if seg.ident != "Option" {
+        return None;
+    }
+    let args = match seg.arguments {
+        syn::PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => args,
+        _ => return None,
+    };
+    match args.args[0] {
+        syn::GenericArgument::Type(syn::Type::Reference(ref reference)) => Some(reference),
+        _ => None,
+    }
+}
+
 pub fn wrap_import_function(function: ast::ImportFunction) -> ast::Import {
     ast::Import {
         module: None,
+        node_module: None,
         js_namespace: None,
         kind: ast::ImportKind::Function(function),
     }
 }
 
+/// Replaces every path starting with `::#name` in `tokens`, like
+/// `::wasm_bindgen`, so that it starts with `path` instead.
+pub fn replace_crate_path(tokens: TokenStream, name: &str, path: &syn::Path) -> TokenStream {
+    replace_path_root(tokens, name, &path.into_token_stream())
+}
+
+fn replace_path_root(tokens: TokenStream, name: &str, path: &TokenStream) -> TokenStream {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut ret = TokenStream::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        // `foo::wasm_bindgen` is a different path which just happens to end
+        // in `wasm_bindgen`, so only replace paths which start here.
+        let starts_path = match i.checked_sub(1).map(|prev| &tokens[prev]) {
+            Some(TokenTree::Ident(ident)) => {
+                let name = ident.to_string();
+                is_rust_keyword(&name) && !["crate", "self", "super", "Self"].contains(&&*name)
+            }
+            _ => true,
+        };
+        if starts_path && is_path_root(&tokens[i..], name) {
+            ret.extend(path.clone());
+            i += 3;
+            continue;
+        }
+        let token = match &tokens[i] {
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), replace_path_root(group.stream(), name, path));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group)
+            }
+            token => token.clone(),
+        };
+        ret.extend(Some(token));
+        i += 1;
+    }
+    ret
+}
+
+fn is_path_root(tokens: &[TokenTree], name: &str) -> bool {
+    if tokens.len() < 3 {
+        return false;
+    }
+    match (&tokens[0], &tokens[1], &tokens[2]) {
+        (TokenTree::Punct(a), TokenTree::Punct(b), TokenTree::Ident(ident)) => {
+            a.as_char() == ':'
+                && a.spacing() == Spacing::Joint
+                && b.as_char() == ':'
+                && ident == name
+        }
+        _ => false,
+    }
+}
+
 /// Small utility used when generating symbol names.
 ///
 /// Hashes the public field here along with a few cargo-set env vars to
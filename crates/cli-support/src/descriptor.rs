@@ -19,6 +19,8 @@ tys! {
     U32
     I64
     U64
+    I128
+    U128
     F32
     F64
     BOOLEAN
@@ -34,6 +36,13 @@ tys! {
     RUST_STRUCT
     CHAR
     OPTIONAL
+    OPTIONS
+    DATE
+    TUPLE
+    UNION
+    STRING_ENUM
+    UNIT
+    MAP
 }
 
 #[derive(Debug)]
@@ -46,6 +55,8 @@ pub enum Descriptor {
     U32,
     I64,
     U64,
+    I128,
+    U128,
     F32,
     F64,
     Boolean,
@@ -61,6 +72,13 @@ pub enum Descriptor {
     RustStruct(String),
     Char,
     Option(Box<Descriptor>),
+    Options(Box<Options>),
+    Date,
+    Tuple(Vec<Descriptor>),
+    Union(Box<Union>),
+    StringEnum(Vec<String>),
+    Unit,
+    Map(Box<Descriptor>),
 }
 
 #[derive(Debug)]
@@ -69,6 +87,18 @@ pub struct Function {
     pub ret: Option<Descriptor>,
 }
 
+#[derive(Debug)]
+pub struct Options {
+    pub name: String,
+    pub fields: Vec<(String, Descriptor)>,
+}
+
+#[derive(Debug)]
+pub struct Union {
+    pub name: String,
+    pub variants: Vec<Descriptor>,
+}
+
 #[derive(Debug)]
 pub struct Closure {
     pub function: Function,
@@ -108,6 +138,8 @@ impl Descriptor {
             U16 => Descriptor::U16,
             U32 => Descriptor::U32,
             U64 => Descriptor::U64,
+            I128 => Descriptor::I128,
+            U128 => Descriptor::U128,
             F32 => Descriptor::F32,
             F64 => Descriptor::F64,
             BOOLEAN => Descriptor::Boolean,
@@ -121,13 +153,19 @@ impl Descriptor {
             STRING => Descriptor::String,
             ANYREF => Descriptor::Anyref,
             ENUM => Descriptor::Enum,
-            RUST_STRUCT => {
-                let name = (0..get(data))
-                    .map(|_| char::from_u32(get(data)).unwrap())
-                    .collect();
-                Descriptor::RustStruct(name)
-            }
+            RUST_STRUCT => Descriptor::RustStruct(get_string(data)),
+            OPTIONS => Descriptor::Options(Box::new(Options::decode(data))),
             CHAR => Descriptor::Char,
+            DATE => Descriptor::Date,
+            TUPLE => Descriptor::Tuple(
+                (0..get(data)).map(|_| Descriptor::_decode(data)).collect(),
+            ),
+            UNION => Descriptor::Union(Box::new(Union::decode(data))),
+            STRING_ENUM => Descriptor::StringEnum(
+                (0..get(data)).map(|_| get_string(data)).collect(),
+            ),
+            UNIT => Descriptor::Unit,
+            MAP => Descriptor::Map(Box::new(Descriptor::_decode(data))),
             other => panic!("unknown descriptor: {}", other),
         }
     }
@@ -182,6 +220,14 @@ impl Descriptor {
         }
     }
 
+    pub fn get_128(&self) -> Option<bool> {
+        match *self {
+            Descriptor::I128 => Some(true),
+            Descriptor::U128 => Some(false),
+            _ => None,
+        }
+    }
+
     pub fn is_ref_anyref(&self) -> bool {
         match *self {
             Descriptor::Ref(ref s) => s.is_anyref(),
@@ -237,6 +283,21 @@ impl Descriptor {
             Descriptor::F32 => Some(VectorKind::F32),
             Descriptor::F64 => Some(VectorKind::F64),
             Descriptor::Anyref => Some(VectorKind::Anyref),
+            // These are converted to `JsValue`s on the Rust side.
+            Descriptor::String | Descriptor::RustStruct(_) => Some(VectorKind::Anyref),
+            _ => None,
+        }
+    }
+
+    /// The TypeScript type of a vector of strings or exported structs, which
+    /// is more specific than the `any[]` of their `VectorKind`.
+    pub fn array_ty(&self) -> Option<String> {
+        match *self {
+            Descriptor::Vector(ref d) => match **d {
+                Descriptor::String => Some("string[]".to_string()),
+                Descriptor::RustStruct(ref name) => Some(format!("{}[]", name)),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -286,6 +347,12 @@ fn get(a: &mut &[u32]) -> u32 {
     ret
 }
 
+fn get_string(data: &mut &[u32]) -> String {
+    (0..get(data))
+        .map(|_| char::from_u32(get(data)).unwrap())
+        .collect()
+}
+
 impl Closure {
     fn decode(data: &mut &[u32]) -> Closure {
         let mutable = get(data) == REFMUT;
@@ -311,6 +378,27 @@ impl Function {
     }
 }
 
+impl Options {
+    fn decode(data: &mut &[u32]) -> Options {
+        let name = get_string(data);
+        let fields = (0..get(data))
+            .map(|_| {
+                let field = get_string(data);
+                (field, Descriptor::_decode(data))
+            })
+            .collect();
+        Options { name, fields }
+    }
+}
+
+impl Union {
+    fn decode(data: &mut &[u32]) -> Union {
+        let name = get_string(data);
+        let variants = (0..get(data)).map(|_| Descriptor::_decode(data)).collect();
+        Union { name, variants }
+    }
+}
+
 impl VectorKind {
     pub fn js_ty(&self) -> &str {
         match *self {
@@ -0,0 +1,37 @@
+//! Problems with the generated bindings which don't stop them from being
+//! generated, but which the user probably wants to know about.
+//!
+//! `Bindgen::generate` collects these rather than printing them, so that the
+//! CLI, or tools driving this crate directly, can report them however they
+//! like.
+
+use std::fmt;
+
+/// A problem found while generating bindings.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// What sort of problem this is.
+    pub kind: DiagnosticKind,
+    /// The JS item the problem is with, such as `foo` or `Foo.bar`.
+    pub item: String,
+    /// A description of the problem.
+    pub message: String,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticKind {
+    /// Two items were given the same JS name, so only one of them can be
+    /// used.
+    NameCollision,
+    /// An item was left out of the bindings because the wasm module doesn't
+    /// describe its types, for example because the function it binds is
+    /// never called.
+    SkippedMember,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.message.fmt(f)
+    }
+}
@@ -0,0 +1,190 @@
+//! A JSON manifest of the JS API generated for a module, and comparisons of
+//! two manifests to find out whether one breaks users of the other.
+//!
+//! Each exported item is recorded with its TypeScript signature, so any change
+//! to how it can be called shows up as a changed signature.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The exported JS API of a module.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Interface {
+    /// Exported functions, keyed by name, with their TypeScript signature.
+    pub functions: BTreeMap<String, String>,
+    /// Exported classes, keyed by name.
+    pub classes: BTreeMap<String, Class>,
+    /// Exported enums, keyed by name, with the JS value of each variant, such
+    /// as `3` or `"fast"`.
+    pub enums: BTreeMap<String, BTreeMap<String, String>>,
+    /// Exported constants, keyed by name, with their TypeScript type.
+    #[serde(default)]
+    pub consts: BTreeMap<String, String>,
+}
+
+/// An exported class.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Class {
+    /// The class this class extends, if any.
+    pub extends: Option<String>,
+    /// Methods, static methods, the constructor and fields, keyed by name,
+    /// with their TypeScript signature.
+    pub members: BTreeMap<String, String>,
+}
+
+/// A difference between two interfaces.
+#[derive(Debug, PartialEq)]
+pub enum Change {
+    /// An item which was exported before has been removed.
+    Removed(String),
+    /// An item is still exported but its signature has changed.
+    Changed {
+        item: String,
+        old: String,
+        new: String,
+    },
+    /// A new item is exported.
+    Added(String),
+}
+
+impl Change {
+    /// Whether this change can break code using the old interface.
+    pub fn is_breaking(&self) -> bool {
+        match *self {
+            Change::Added(_) => false,
+            Change::Removed(_) | Change::Changed { .. } => true,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Change::Removed(ref item) => write!(f, "removed `{}`", item),
+            Change::Changed {
+                ref item,
+                ref old,
+                ref new,
+            } => write!(f, "changed `{}` from `{}` to `{}`", item, old, new),
+            Change::Added(ref item) => write!(f, "added `{}`", item),
+        }
+    }
+}
+
+/// Lists the differences between the `old` and `new` interfaces.
+pub fn diff(old: &Interface, new: &Interface) -> Vec<Change> {
+    let mut changes = Vec::new();
+    diff_maps(
+        &old.functions,
+        &new.functions,
+        "",
+        &mut changes,
+        &mut |item, old, new, changes| {
+            if old != new {
+                changes.push(Change::Changed {
+                    item: item.to_string(),
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        },
+    );
+    diff_maps(
+        &old.classes,
+        &new.classes,
+        "",
+        &mut changes,
+        &mut |item, old, new, changes| {
+            if old.extends != new.extends {
+                let show = |e: &Option<String>| match *e {
+                    Some(ref parent) => format!("extends {}", parent),
+                    None => "no base class".to_string(),
+                };
+                changes.push(Change::Changed {
+                    item: item.to_string(),
+                    old: show(&old.extends),
+                    new: show(&new.extends),
+                });
+            }
+            let prefix = format!("{}.", item);
+            diff_maps(
+                &old.members,
+                &new.members,
+                &prefix,
+                changes,
+                &mut |item, old, new, changes| {
+                    if old != new {
+                        changes.push(Change::Changed {
+                            item: item.to_string(),
+                            old: old.clone(),
+                            new: new.clone(),
+                        });
+                    }
+                },
+            );
+        },
+    );
+    diff_maps(
+        &old.consts,
+        &new.consts,
+        "",
+        &mut changes,
+        &mut |item, old, new, changes| {
+            if old != new {
+                changes.push(Change::Changed {
+                    item: item.to_string(),
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        },
+    );
+    diff_maps(
+        &old.enums,
+        &new.enums,
+        "",
+        &mut changes,
+        &mut |item, old, new, changes| {
+            let prefix = format!("{}.", item);
+            diff_maps(
+                old,
+                new,
+                &prefix,
+                changes,
+                &mut |item, old, new, changes| {
+                    if old != new {
+                        changes.push(Change::Changed {
+                            item: item.to_string(),
+                            old: old.to_string(),
+                            new: new.to_string(),
+                        });
+                    }
+                },
+            );
+        },
+    );
+    changes
+}
+
+/// Reports items only in `old` as removed and items only in `new` as added,
+/// calling `both` for the items in both.
+fn diff_maps<T>(
+    old: &BTreeMap<String, T>,
+    new: &BTreeMap<String, T>,
+    prefix: &str,
+    changes: &mut Vec<Change>,
+    both: &mut FnMut(&str, &T, &T, &mut Vec<Change>),
+) {
+    for (name, old_item) in old {
+        let item = format!("{}{}", prefix, name);
+        match new.get(name) {
+            Some(new_item) => both(&item, old_item, new_item, changes),
+            None => changes.push(Change::Removed(item)),
+        }
+    }
+    for name in new.keys() {
+        if !old.contains_key(name) {
+            changes.push(Change::Added(format!("{}{}", prefix, name)));
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use failure::Error;
+use serde_json;
 
 use super::Context;
-use descriptor::{Descriptor, Function};
+use descriptor::{Closure, Descriptor, Function, Union, VectorKind};
 
 /// Helper struct for manufacturing a shim in JS used to translate JS types to
 /// Rust, aka pass from JS back into Rust
@@ -26,9 +27,22 @@ pub struct Js2Rust<'a, 'b: 'a> {
     /// Index of the next argument for unique name generation purposes.
     arg_idx: usize,
 
+    /// Variables declared up front so that `undo` can see how far conversions
+    /// in the prelude got.
+    hoisted: Vec<String>,
+
+    /// Cleanup run if the shim throws before calling into wasm, releasing
+    /// anything the prelude allocated on behalf of Rust. Once the call is made
+    /// ownership has passed to Rust and this no longer runs.
+    undo: String,
+
+    /// Number of arguments pushed onto the global stack of borrowed objects,
+    /// which is restored to its original depth once the call finishes.
+    borrowed_objects: usize,
+
     /// Typescript expression representing the type of the return value of this
     /// function.
-    ret_ty: String,
+    pub ret_ty: String,
 
     /// Expression used to generate the return value. The string "RET" in this
     /// expression is replaced with the actual wasm invocation eventually.
@@ -37,10 +51,42 @@ pub struct Js2Rust<'a, 'b: 'a> {
     /// Name of the JS shim/function that we're generating, primarily for
     /// TypeScript right now.
     js_name: String,
+
+    /// Whether trailing `Option` arguments may be omitted by callers.
+    optional_args: bool,
+
+    /// Number of trailing arguments which are optional in the JS signature.
+    num_optional: usize,
+
+    /// Index of the options object argument, if it can be left out because
+    /// all of its fields are optional.
+    optional_options: Option<usize>,
+
+    /// JS default values for arguments, if any.
+    defaults: Vec<Option<String>>,
+
+    /// Whether maps are passed as `Map`s rather than plain objects.
+    js_map: bool,
+
+    /// Whether errors thrown out of this shim are passed to the error hook.
+    report_errors: bool,
 }
 
 impl<'a, 'b> Js2Rust<'a, 'b> {
     pub fn new(js_name: &str, cx: &'a mut Context<'b>) -> Js2Rust<'a, 'b> {
+        if cx.config.poison_on_panic {
+            cx.expose_poison();
+        }
+        if cx.config.profile_boundary {
+            cx.expose_profile();
+        }
+        // Calling `wasm_bindgen::set_error_hook` implies the hook is wanted
+        // even if it wasn't asked for on the command line.
+        let report_errors = cx.config.error_hook
+            || cx.wasm_import_needed("__wbindgen_set_error_hook");
+        if report_errors {
+            cx.expose_error_hook();
+        }
         Js2Rust {
             cx,
             js_name: js_name.to_string(),
@@ -49,21 +95,82 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
             prelude: String::new(),
             finally: String::new(),
             arg_idx: 0,
+            hoisted: Vec::new(),
+            undo: String::new(),
+            borrowed_objects: 0,
             ret_ty: String::new(),
             ret_expr: String::new(),
+            optional_args: false,
+            num_optional: 0,
+            optional_options: None,
+            defaults: Vec::new(),
+            js_map: false,
+            report_errors,
         }
     }
 
     /// Generates all bindings necessary for the signature in `Function`,
     /// creating necessary argument conversions and return value processing.
     pub fn process(&mut self, function: &Function) -> Result<&mut Self, Error> {
-        for arg in function.arguments.iter() {
+        for (i, arg) in function.arguments.iter().enumerate() {
+            if let Descriptor::Options(_) = arg {
+                if i + 1 != function.arguments.len() {
+                    bail!("options objects must be the last argument of a function");
+                }
+            }
             self.argument(arg)?;
         }
+        if self.optional_args {
+            // Arguments with default values are already optional, so skip
+            // over them when looking for trailing `Option` arguments.
+            self.num_optional = function
+                .arguments
+                .iter()
+                .enumerate()
+                .rev()
+                .take_while(|&(i, arg)| match arg {
+                    Descriptor::Option(_) => true,
+                    _ => self.default_value(i).is_some(),
+                })
+                .count();
+        }
         self.ret(&function.ret)?;
         Ok(self)
     }
 
+    /// Flag trailing `Option` arguments of this shim as optional, allowing JS
+    /// callers to omit them entirely.
+    pub fn optional_args(&mut self, optional: bool) -> &mut Self {
+        self.optional_args = optional;
+        self
+    }
+
+    /// Configure JS default values for the arguments of this shim.
+    pub fn defaults(&mut self, defaults: &[Option<String>]) -> &mut Self {
+        self.defaults = defaults.to_vec();
+        self
+    }
+
+    /// Pass the maps in the signature of this shim as `Map`s rather than
+    /// plain objects.
+    pub fn js_map(&mut self, js_map: bool) -> &mut Self {
+        self.js_map = js_map;
+        self
+    }
+
+    fn default_value(&self, idx: usize) -> Option<&str> {
+        match self.defaults.get(idx) {
+            Some(Some(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn is_optional(&self, idx: usize) -> bool {
+        idx + self.num_optional >= self.js_arguments.len()
+            || self.default_value(idx).is_some()
+            || self.optional_options == Some(idx)
+    }
+
     /// Flag this shim as a method call into Rust, so the first Rust argument
     /// passed should be `this.ptr`.
     pub fn method(&mut self, method: bool, consumed: bool) -> &mut Self {
@@ -76,10 +183,11 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
             if consumed {
                 self.prelude(
                     "\
-                     const ptr = this.ptr;\n\
+                     ptr = this.ptr;\n\
                      this.ptr = 0;\n\
                      ",
                 );
+                self.hoist(&["ptr".to_string()], "if (ptr !== undefined) this.ptr = ptr;");
                 self.rust_arguments.insert(0, "ptr".to_string());
             } else {
                 self.rust_arguments.insert(0, "this.ptr".to_string());
@@ -106,6 +214,17 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
         self
     }
 
+    /// Declares `names` ahead of the prelude and registers `undo` to release
+    /// what they refer to if the shim throws before calling into wasm.
+    fn hoist(&mut self, names: &[String], undo: &str) -> &mut Self {
+        self.hoisted.extend(names.iter().cloned());
+        for line in undo.lines() {
+            self.undo.push_str(line);
+            self.undo.push_str("\n");
+        }
+        self
+    }
+
     /// Add an Rust argument to be passed manually.
     pub fn rust_argument(&mut self, s: &str) -> &mut Self {
         self.rust_arguments.push(s.to_string());
@@ -127,6 +246,60 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
             _ => (arg, false),
         };
 
+        if let Descriptor::Options(ref options) = *arg {
+            if optional {
+                bail!("optional options objects aren't currently supported");
+            }
+            // When no field has to be given, neither does the object.
+            let all_optional = options.fields.iter().all(|(_, ty)| match ty {
+                Descriptor::Option(_) => true,
+                _ => false,
+            });
+            let object = if all_optional {
+                self.optional_options = Some(self.js_arguments.len());
+                format!("({} === undefined ? {{}} : {})", name, name)
+            } else {
+                name.clone()
+            };
+            self.js_arguments.push((name.clone(), options.name.clone()));
+            let mut fields = Vec::new();
+            for (field, ty) in options.fields.iter() {
+                // Each field is converted as if it were its own argument, but
+                // it's read from the options object rather than being a
+                // parameter of the shim. Its name from `js_name` needn't be
+                // an identifier, so it's always indexed with a string.
+                let key = serde_json::to_string(field).unwrap();
+                self.prelude(&format!(
+                    "const arg{} = {}[{}];",
+                    self.arg_idx, object, key
+                ));
+                self.argument(ty)?;
+                let (_, ts) = self.js_arguments.pop().unwrap();
+                let optional = match ty {
+                    Descriptor::Option(_) => "?",
+                    _ => "",
+                };
+                let field = if is_js_ident(field) { field.clone() } else { key };
+                fields.push(format!("{}{}: {};", field, optional, ts));
+            }
+            self.cx.expose_options_interface(&options.name, &fields);
+            return Ok(self);
+        }
+
+        if let Descriptor::Union(ref union) = *arg {
+            if optional {
+                bail!("optional unions aren't currently supported");
+            }
+            return self.union_argument(union, &name, i);
+        }
+
+        if let Descriptor::Map(ref value) = *arg {
+            if optional {
+                bail!("optional maps aren't currently supported");
+            }
+            return self.map_argument(value, &name, i);
+        }
+
         if let Some(kind) = arg.vector_kind() {
             self.js_arguments
                 .push((name.clone(), kind.js_ty().to_string()));
@@ -139,10 +312,19 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
                 format!("{}({})", func, name)
             };
             self.prelude(&format!(
-                "const [ptr{i}, len{i}] = {val};",
+                "[ptr{i}, len{i}] = {val};",
                 i = i,
                 val = val,
             ));
+            self.hoist(
+                &[format!("ptr{}", i), format!("len{}", i)],
+                &format!(
+                    "if (ptr{i} !== undefined) wasm.__wbindgen_free(ptr{i}, len{i} * {size});",
+                    i = i,
+                    size = kind.size(),
+                ),
+            );
+            self.cx.require_internal_export("__wbindgen_free")?;
             if arg.is_by_ref() {
                 if optional {
                     bail!("optional slices aren't currently supported");
@@ -179,15 +361,99 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
         if arg.is_anyref() {
             self.js_arguments.push((name.clone(), "any".to_string()));
             self.cx.expose_add_heap_object();
+            self.cx.expose_drop_ref();
             if optional {
                 self.cx.expose_is_like_none();
-                self.rust_arguments.push(format!(
-                    "isLikeNone({0}) ? 0 : addHeapObject({0})",
+                self.prelude(&format!(
+                    "idx{i} = isLikeNone({0}) ? 0 : addHeapObject({0});",
                     name,
+                    i = i,
                 ));
             } else {
-                self.rust_arguments.push(format!("addHeapObject({})", name));
+                self.prelude(&format!("idx{} = addHeapObject({});", i, name));
             }
+            self.hoist(
+                &[format!("idx{}", i)],
+                &format!("if (idx{0} !== undefined) dropRef(idx{0});", i),
+            );
+            self.rust_arguments.push(format!("idx{}", i));
+            return Ok(self);
+        }
+
+        if let Some(s) = arg.rust_struct() {
+            self.js_arguments.push((name.clone(), s.to_string()));
+            if optional {
+                self.cx.expose_is_like_none();
+            }
+
+            if self.cx.config.debug {
+                self.cx.expose_assert_class();
+                let assert = format!("_assertClass({}, {});", name, s);
+                self.prelude(&if optional {
+                    format!("if (!isLikeNone({})) {{ {} }}", name, assert)
+                } else {
+                    assert
+                });
+            }
+
+            if arg.is_by_ref() {
+                self.rust_arguments.push(if optional {
+                    format!("isLikeNone({0}) ? 0 : {0}.ptr", name)
+                } else {
+                    format!("{}.ptr", name)
+                });
+            } else {
+                let mut take = String::new();
+                if self.cx.subclassed.contains(s) {
+                    take.push_str(&format!(
+                        "\
+                         if ({arg}.constructor !== {struct_}) {{
+                             throw new Error('cannot pass a subclass of `{struct_}` by value');
+                         }}
+                         ",
+                        arg = name,
+                        struct_ = s,
+                    ));
+                }
+                take.push_str(&format!(
+                    "\
+                    ptr{i} = {arg}.ptr;\n\
+                    if (ptr{i} === 0) {{
+                        throw new Error('Attempt to use a moved value');
+                    }}
+                    {arg}.ptr = 0;\n\
+                ",
+                    i = i,
+                    arg = name
+                ));
+                if optional {
+                    self.prelude(&format!("if (!isLikeNone({})) {{\n{}}}", name, take));
+                } else {
+                    self.prelude(&take);
+                }
+                self.hoist(
+                    &[format!("ptr{}", i)],
+                    &format!("if (ptr{i} !== undefined) {arg}.ptr = ptr{i};", i = i, arg = name),
+                );
+                self.rust_arguments.push(if optional {
+                    format!("isLikeNone({}) ? 0 : ptr{}", name, i)
+                } else {
+                    format!("ptr{}", i)
+                });
+            }
+            return Ok(self);
+        }
+
+        if optional && arg.is_ref_anyref() {
+            self.js_arguments.push((name.clone(), "any".to_string()));
+            self.cx.expose_is_like_none();
+            self.cx.expose_borrowed_objects();
+            if self.borrowed_objects == 0 {
+                self.prelude("const stackDepth = stack.length;");
+            }
+            self.borrowed_objects += 1;
+            self.rust_arguments
+                .push(format!("isLikeNone({0}) ? 0 : addBorrowedObject({0})", name));
             return Ok(self);
         }
 
@@ -233,23 +499,20 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
                 return Ok(self);
             }
 
-            if let Some(signed) = arg.get_64() {
-                let f = if signed {
-                    self.cx.expose_int64_cvt_shim()
-                } else {
-                    self.cx.expose_uint64_cvt_shim()
-                };
-                self.cx.expose_uint32_memory();
-                self.cx.expose_global_argument_ptr()?;
+            if arg.get_64().is_some() {
+                self.cx.expose_split_int64();
                 self.js_arguments.push((name.clone(), "BigInt".to_string()));
                 self.prelude(&format!(
                     "
-                        {f}[0] = isLikeNone({name}) ? BigInt(0) : {name};
-                        const low{i} = isLikeNone({name}) ? 0 : u32CvtShim[0];
-                        const high{i} = isLikeNone({name}) ? 0 : u32CvtShim[1];
+                        let low{i} = 0;
+                        let high{i} = 0;
+                        if (!isLikeNone({name})) {{
+                            const parts = splitInt64({name});
+                            low{i} = parts[0];
+                            high{i} = parts[1];
+                        }}
                     ",
                     i = i,
-                    f = f,
                     name = name,
                 ));
                 self.rust_arguments.push(format!("!isLikeNone({})", name));
@@ -259,6 +522,22 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
                 return Ok(self);
             }
 
+            if arg.get_128().is_some() {
+                self.cx.expose_is_like_none();
+                self.cx.expose_split_int128();
+                self.js_arguments.push((name.clone(), "BigInt".to_string()));
+                self.prelude(&format!(
+                    "const limbs{i} = isLikeNone({name}) ? [0, 0, 0, 0] : splitInt128({name});",
+                    i = i,
+                    name = name,
+                ));
+                self.rust_arguments.push(format!("!isLikeNone({})", name));
+                for limb in 0..4 {
+                    self.rust_arguments.push(format!("limbs{}[{}]", i, limb));
+                }
+                return Ok(self);
+            }
+
             match *arg {
                 Descriptor::Boolean => {
                     self.cx.expose_is_like_none();
@@ -279,48 +558,23 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
                 },
                 Descriptor::Char => {
                     self.cx.expose_is_like_none();
+                    self.cx.expose_char_code_point();
                     self.js_arguments.push((name.clone(), "string".to_string()));
                     self.rust_arguments.push(format!("!isLikeNone({0})", name));
-                    self.rust_arguments.push(format!("isLikeNone({0}) ? 0 : {0}.codePointAt(0)", name));
+                    self.rust_arguments.push(format!("isLikeNone({0}) ? 0 : charCodePoint({0})", name));
+                    return Ok(self);
+                },
+                Descriptor::Date => {
+                    self.cx.expose_is_like_none();
+                    self.js_arguments.push((name.clone(), "Date".to_string()));
+                    self.rust_arguments.push(format!("!isLikeNone({0})", name));
+                    self.rust_arguments.push(format!("isLikeNone({0}) ? 0 : {0}.getTime()", name));
                     return Ok(self);
                 },
                 _ => bail!("unsupported optional argument type for calling Rust function from JS: {:?}", arg),
             };
         }
 
-        if let Some(s) = arg.rust_struct() {
-            self.js_arguments.push((name.clone(), s.to_string()));
-
-            if self.cx.config.debug {
-                self.cx.expose_assert_class();
-                self.prelude(&format!(
-                    "\
-                     _assertClass({arg}, {struct_});\n\
-                     ",
-                    arg = name,
-                    struct_ = s
-                ));
-            }
-
-            if arg.is_by_ref() {
-                self.rust_arguments.push(format!("{}.ptr", name));
-            } else {
-                self.prelude(&format!(
-                    "\
-                    const ptr{i} = {arg}.ptr;\n\
-                    if (ptr{i} === 0) {{
-                        throw new Error('Attempt to use a moved value');
-                    }}
-                    {arg}.ptr = 0;\n\
-                ",
-                    i = i,
-                    arg = name
-                ));
-                self.rust_arguments.push(format!("ptr{}", i));
-            }
-            return Ok(self);
-        }
-
         if arg.is_number() {
             self.js_arguments.push((name.clone(), "number".to_string()));
 
@@ -333,23 +587,16 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
             return Ok(self);
         }
 
-        if let Some(signed) = arg.get_64() {
-            let f = if signed {
-                self.cx.expose_int64_cvt_shim()
-            } else {
-                self.cx.expose_uint64_cvt_shim()
-            };
-            self.cx.expose_uint32_memory();
-            self.cx.expose_global_argument_ptr()?;
+        if arg.get_64().is_some() {
+            self.cx.expose_split_int64();
             self.js_arguments.push((name.clone(), "BigInt".to_string()));
             self.prelude(&format!(
                 "
-                 {f}[0] = {name};
-                 const low{i} = u32CvtShim[0];
-                 const high{i} = u32CvtShim[1];
+                 const parts{i} = splitInt64({name});
+                 const low{i} = parts{i}[0];
+                 const high{i} = parts{i}[1];
                  ",
                 i = i,
-                f = f,
                 name = name,
             ));
             self.rust_arguments.push(format!("low{}", i));
@@ -357,10 +604,23 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
             return Ok(self);
         }
 
+        if arg.get_128().is_some() {
+            self.cx.expose_split_int128();
+            self.js_arguments.push((name.clone(), "BigInt".to_string()));
+            self.prelude(&format!("const limbs{i} = splitInt128({name});", i = i, name = name));
+            for limb in 0..4 {
+                self.rust_arguments.push(format!("limbs{}[{}]", i, limb));
+            }
+            return Ok(self);
+        }
+
         if arg.is_ref_anyref() {
             self.js_arguments.push((name.clone(), "any".to_string()));
             self.cx.expose_borrowed_objects();
-            self.finally("stack.pop();");
+            if self.borrowed_objects == 0 {
+                self.prelude("const stackDepth = stack.length;");
+            }
+            self.borrowed_objects += 1;
             self.rust_arguments
                 .push(format!("addBorrowedObject({})", name));
             return Ok(self);
@@ -382,22 +642,179 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
                 self.rust_arguments.push(format!("{} ? 1 : 0", name));
             }
             Descriptor::Char => {
+                self.cx.expose_char_code_point();
                 self.js_arguments.push((name.clone(), "string".to_string()));
-                self.rust_arguments.push(format!("{}.codePointAt(0)", name))
+                self.rust_arguments.push(format!("charCodePoint({})", name))
+            }
+            Descriptor::Date => {
+                self.js_arguments.push((name.clone(), "Date".to_string()));
+                self.rust_arguments.push(format!("{}.getTime()", name))
+            }
+            Descriptor::StringEnum(ref variants) => {
+                // Strings which aren't one of the variants are passed as -1,
+                // which Rust converts to the hidden `__Nonexhaustive` variant.
+                let table = self.cx.expose_string_enum(variants);
+                self.js_arguments.push((name.clone(), string_enum_ts(variants)));
+                self.rust_arguments.push(format!("{}.indexOf({})", table, name))
             }
             _ => bail!("unsupported argument type for calling Rust function from JS: {:?}", arg),
         }
         Ok(self)
     }
 
+    /// Passes a value of any of the types in `union`, which are told apart
+    /// with `instanceof` and `typeof`.
+    ///
+    /// Rust receives the index of the matching variant followed by the values
+    /// of all variants, where every variant but the matching one is zero.
+    fn union_argument(&mut self, union: &Union, name: &str, i: usize) -> Result<&mut Self, Error> {
+        let mut tys = Vec::new();
+        let mut words = Vec::new();
+        let mut branches = Vec::new();
+        for (j, variant) in union.variants.iter().enumerate() {
+            let (ty, branch) = if let Some(s) = variant.rust_struct() {
+                if variant.is_by_ref() {
+                    bail!("the union `{}` can't contain references", union.name);
+                }
+                let word = format!("variant{}_{}", i, j);
+                let guard = if self.cx.subclassed.contains(s) {
+                    format!(
+                        "\
+                         if ({arg}.constructor !== {struct_}) {{
+                             throw new Error('cannot pass a subclass of `{struct_}` by value');
+                         }}
+                         ",
+                        arg = name,
+                        struct_ = s,
+                    )
+                } else {
+                    String::new()
+                };
+                let branch = format!(
+                    "\
+                     if ({arg} instanceof {struct_}) {{
+                         {guard}\
+                         tag{i} = {j};
+                         {word} = {arg}.ptr;
+                         if ({word} === 0) {{
+                             throw new Error('Attempt to use a moved value');
+                         }}
+                         {arg}.ptr = 0;
+                     }}",
+                    arg = name,
+                    struct_ = s,
+                    guard = guard,
+                    i = i,
+                    j = j,
+                    word = word,
+                );
+                words.push(word);
+                (s.to_string(), branch)
+            } else if let Descriptor::String = *variant {
+                let func = self.cx.pass_to_wasm_function(VectorKind::String)?;
+                let ptr = format!("variant{}_{}_ptr", i, j);
+                let len = format!("variant{}_{}_len", i, j);
+                let branch = format!(
+                    "\
+                     if (typeof {arg} === 'string') {{
+                         tag{i} = {j};
+                         [{ptr}, {len}] = {func}({arg});
+                     }}",
+                    arg = name,
+                    i = i,
+                    j = j,
+                    ptr = ptr,
+                    len = len,
+                    func = func,
+                );
+                words.push(ptr);
+                words.push(len);
+                ("string".to_string(), branch)
+            } else {
+                bail!(
+                    "unsupported type in the union `{}`, only exported structs \
+                     and `String` are supported: {:?}",
+                    union.name,
+                    variant
+                )
+            };
+            if tys.contains(&ty) {
+                bail!(
+                    "the union `{}` contains more than one variant of type `{}`",
+                    union.name,
+                    ty
+                );
+            }
+            tys.push(ty);
+            branches.push(branch);
+        }
+
+        let ts = tys.join(" | ");
+        self.prelude(&format!("let tag{};", i));
+        self.prelude(&format!("let {} = 0;", words.join(" = 0, ")));
+        self.prelude(&format!(
+            "\
+             {} else {{
+                 throw new Error('expected a value of type `{}`');
+             }}
+             ",
+            branches.join(" else "),
+            ts,
+        ));
+        self.js_arguments.push((name.to_string(), ts));
+        self.rust_arguments.push(format!("tag{}", i));
+        self.rust_arguments.extend(words);
+        Ok(self)
+    }
+
+    /// Passes a plain object or `Map` as an array of its keys and values,
+    /// checking first that the values are of the type Rust expects.
+    fn map_argument(&mut self, value: &Descriptor, name: &str, i: usize) -> Result<&mut Self, Error> {
+        let (check, ts) = map_value_ty(value)?;
+        self.js_arguments.push((name.to_string(), self.map_ts(&ts)));
+        self.cx.expose_map_entries(self.js_map);
+        let entries = if self.js_map { "mapToEntries" } else { "objectToEntries" };
+        let func = self.cx.pass_to_wasm_function(VectorKind::Anyref)?;
+        self.prelude(&format!(
+            "[ptr{i}, len{i}] = {func}({entries}({name}, {check}));",
+            i = i,
+            func = func,
+            entries = entries,
+            name = name,
+            check = check,
+        ));
+        self.hoist(
+            &[format!("ptr{}", i), format!("len{}", i)],
+            &format!(
+                "if (ptr{i} !== undefined) wasm.__wbindgen_free(ptr{i}, len{i} * 4);",
+                i = i,
+            ),
+        );
+        self.cx.require_internal_export("__wbindgen_free")?;
+        self.rust_arguments.push(format!("ptr{}", i));
+        self.rust_arguments.push(format!("len{}", i));
+        Ok(self)
+    }
+
+    /// The TypeScript type of a map with values of type `ts`.
+    fn map_ts(&self, ts: &str) -> String {
+        if self.js_map {
+            format!("Map<string, {}>", ts)
+        } else {
+            format!("{{ [key: string]: {} }}", ts)
+        }
+    }
+
     pub fn ret(&mut self, ret: &Option<Descriptor>) -> Result<&mut Self, Error> {
         let ty = match *ret {
-            Some(ref t) => t,
-            None => {
+            // `Result<(), E>` describes its `()`, which returns nothing just
+            // like a function without a return type.
+            Some(Descriptor::Unit) | None => {
                 self.ret_ty = "void".to_string();
                 self.ret_expr = format!("return RET;");
                 return Ok(self);
             }
+            Some(ref t) => t,
         };
 
         let (ty, optional) = match ty {
@@ -405,9 +822,30 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
             _ => (ty, false),
         };
 
-        if let Some(ty) = ty.vector_kind() {
-            self.ret_ty = ty.js_ty().to_string();
-            let f = self.cx.expose_get_vector_from_wasm(ty);
+        if let Descriptor::Tuple(ref elems) = *ty {
+            if optional {
+                bail!("optional tuples cannot be returned from Rust to JS yet")
+            }
+            return self.ret_tuple(elems);
+        }
+
+        if let Some(closure) = ty.closure() {
+            if optional {
+                bail!("optional closures cannot be returned from Rust to JS yet")
+            }
+            return self.ret_closure(closure);
+        }
+
+        if let Descriptor::Map(ref value) = *ty {
+            if optional {
+                bail!("optional maps cannot be returned from Rust to JS yet")
+            }
+            return self.ret_map(value);
+        }
+
+        if let Some(kind) = ty.vector_kind() {
+            self.ret_ty = ty.array_ty().unwrap_or_else(|| kind.js_ty().to_string());
+            let f = self.cx.expose_get_vector_from_wasm(kind);
             self.cx.expose_global_argument_ptr()?;
             self.cx.expose_uint32_memory();
             self.cx.require_internal_export("__wbindgen_free")?;
@@ -425,7 +863,7 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
                  return realRet;\n\
                  ",
                 f,
-                ty.size(),
+                kind.size(),
                 guard = if optional { "if (rustptr === 0) return;" } else { "" },
             );
             return Ok(self);
@@ -491,23 +929,40 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
             if let Some(signed) = ty.get_64() {
                 self.ret_ty = "BigInt".to_string();
                 self.cx.expose_global_argument_ptr()?;
-                let f = if signed {
-                    self.cx.expose_int64_memory();
-                    "getInt64Memory"
-                } else {
-                    self.cx.expose_uint64_memory();
-                    "getUint64Memory"
-                };
+                self.cx.expose_uint32_memory();
+                self.cx.expose_join_int64();
                 self.prelude("const retptr = globalArgumentPtr();");
                 self.rust_arguments.insert(0, "retptr".to_string());
                 self.ret_expr = format!(
                     "
                         RET;
-                        const present = getUint32Memory()[retptr / 4];
-                        const value = {}()[retptr / 8 + 1];
+                        const mem = getUint32Memory();
+                        const present = mem[retptr / 4];
+                        const value = joinInt64(mem[retptr / 4 + 2], mem[retptr / 4 + 3], {});
                         return present === 0 ? undefined : value;
                     ",
-                    f
+                    signed
+                );
+                return Ok(self);
+            }
+
+            if let Some(signed) = ty.get_128() {
+                self.ret_ty = "BigInt".to_string();
+                self.cx.expose_global_argument_ptr()?;
+                self.cx.expose_uint32_memory();
+                self.cx.expose_join_int128();
+                self.prelude("const retptr = globalArgumentPtr();");
+                self.rust_arguments.insert(0, "retptr".to_string());
+                self.ret_expr = format!(
+                    "
+                        RET;
+                        const mem = getUint32Memory();
+                        if (mem[retptr / 4] === 0) {{
+                            return undefined;
+                        }}
+                        return joinInt128(mem[retptr / 4 + 1], mem[retptr / 4 + 2], mem[retptr / 4 + 3], mem[retptr / 4 + 4], {});
+                    ",
+                    signed
                 );
                 return Ok(self);
             }
@@ -535,6 +990,32 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
                     ".to_string();
                     return Ok(self);
                 },
+                Descriptor::Date => {
+                    self.ret_ty = "Date".to_string();
+                    self.cx.expose_global_argument_ptr()?;
+                    self.cx.expose_uint32_memory();
+                    self.cx.expose_f64_memory();
+                    self.prelude("const retptr = globalArgumentPtr();");
+                    self.rust_arguments.insert(0, "retptr".to_string());
+                    self.ret_expr = "
+                        RET;
+                        const present = getUint32Memory()[retptr / 4];
+                        const value = getFloat64Memory()[retptr / 8 + 1];
+                        return present === 0 ? undefined : new Date(value);
+                    ".to_string();
+                    return Ok(self);
+                },
+                Descriptor::RustStruct(ref name) => {
+                    self.ret_ty = name.to_string();
+                    self.ret_expr = format!(
+                        "
+                            const ptr = RET;
+                            return ptr === 0 ? undefined : {}.__construct(ptr);
+                        ",
+                        name,
+                    );
+                    return Ok(self);
+                },
                 _ => bail!("unsupported optional return type for calling Rust function from JS: {:?}", ty),
             };
         }
@@ -565,21 +1046,35 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
         if let Some(signed) = ty.get_64() {
             self.ret_ty = "BigInt".to_string();
             self.cx.expose_global_argument_ptr()?;
-            let f = if signed {
-                self.cx.expose_int64_memory();
-                "getInt64Memory"
-            } else {
-                self.cx.expose_uint64_memory();
-                "getUint64Memory"
-            };
+            self.cx.expose_uint32_memory();
+            self.cx.expose_join_int64();
             self.prelude("const retptr = globalArgumentPtr();");
             self.rust_arguments.insert(0, "retptr".to_string());
             self.ret_expr = format!(
                 "\
                  RET;\n\
-                 return {}()[retptr / 8];\n\
+                 const mem = getUint32Memory();\n\
+                 return joinInt64(mem[retptr / 4], mem[retptr / 4 + 1], {});\n\
                  ",
-                f
+                signed
+            );
+            return Ok(self);
+        }
+
+        if let Some(signed) = ty.get_128() {
+            self.ret_ty = "BigInt".to_string();
+            self.cx.expose_global_argument_ptr()?;
+            self.cx.expose_uint32_memory();
+            self.cx.expose_join_int128();
+            self.prelude("const retptr = globalArgumentPtr();");
+            self.rust_arguments.insert(0, "retptr".to_string());
+            self.ret_expr = format!(
+                "\
+                 RET;\n\
+                 const mem = getUint32Memory();\n\
+                 return joinInt128(mem[retptr / 4], mem[retptr / 4 + 1], mem[retptr / 4 + 2], mem[retptr / 4 + 3], {});\n\
+                 ",
+                signed
             );
             return Ok(self);
         }
@@ -593,14 +1088,224 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
                 self.ret_ty = "string".to_string();
                 self.ret_expr = format!("return String.fromCodePoint(RET);")
             }
+            Descriptor::Date => {
+                self.ret_ty = "Date".to_string();
+                self.ret_expr = format!("return new Date(RET);")
+            }
+            Descriptor::StringEnum(ref variants) => {
+                let table = self.cx.expose_string_enum(variants);
+                self.ret_ty = string_enum_ts(variants);
+                self.ret_expr = format!("return {}[RET];", table)
+            }
             _ => bail!("unsupported return type for calling Rust function from JS: {:?}", ty),
         }
         Ok(self)
     }
 
+    /// Tuples are returned through `retptr` as a `#[repr(C)]` struct of each
+    /// element's ABI representation, so this lays the struct out the same way
+    /// and reads each element back out into a JS array.
+    fn ret_tuple(&mut self, elems: &[Descriptor]) -> Result<&mut Self, Error> {
+        self.cx.expose_global_argument_ptr()?;
+        self.cx.expose_uint32_memory();
+        self.prelude("const retptr = globalArgumentPtr();");
+        self.rust_arguments.insert(0, "retptr".to_string());
+
+        let mut reads = String::new();
+        let mut converts = String::new();
+        let mut values = Vec::new();
+        let mut tys = Vec::new();
+        let mut offset = 0;
+        for (i, elem) in elems.iter().enumerate() {
+            let (size, align) = match *elem {
+                Descriptor::F64 => (8, 8),
+                ref d if d.vector_kind().is_some() => (8, 4),
+                _ => (4, 4),
+            };
+            offset = (offset + align - 1) / align * align;
+            let u32_at = |offset: usize| format!("getUint32Memory()[retptr / 4 + {}]", offset / 4);
+
+            if let Some(kind) = elem.vector_kind() {
+                if elem.is_by_ref() {
+                    bail!("cannot return references from Rust to JS yet")
+                }
+                let f = self.cx.expose_get_vector_from_wasm(kind);
+                self.cx.require_internal_export("__wbindgen_free")?;
+                reads.push_str(&format!(
+                    "\
+                     const elemptr{i} = {ptr};\n\
+                     const elemlen{i} = {len};\n\
+                     ",
+                    ptr = u32_at(offset),
+                    len = u32_at(offset + 4),
+                    i = i,
+                ));
+                converts.push_str(&format!(
+                    "\
+                     const elemv{i} = {f}(elemptr{i}, elemlen{i}).slice();\n\
+                     wasm.__wbindgen_free(elemptr{i}, elemlen{i} * {size});\n\
+                     ",
+                    f = f,
+                    size = kind.size(),
+                    i = i,
+                ));
+                values.push(format!("elemv{}", i));
+                tys.push(kind.js_ty().to_string());
+                offset += size;
+                continue;
+            }
+
+            let (read, value, ty) = match *elem {
+                Descriptor::I8 | Descriptor::I16 | Descriptor::I32 => {
+                    self.cx.expose_int32_memory();
+                    (
+                        format!("getInt32Memory()[retptr / 4 + {}]", offset / 4),
+                        format!("elem{}", i),
+                        "number".to_string(),
+                    )
+                }
+                Descriptor::U8 | Descriptor::U16 | Descriptor::U32 | Descriptor::Enum => {
+                    (u32_at(offset), format!("elem{}", i), "number".to_string())
+                }
+                Descriptor::F32 => {
+                    self.cx.expose_f32_memory();
+                    (
+                        format!("getFloat32Memory()[retptr / 4 + {}]", offset / 4),
+                        format!("elem{}", i),
+                        "number".to_string(),
+                    )
+                }
+                Descriptor::F64 => {
+                    self.cx.expose_f64_memory();
+                    (
+                        format!("getFloat64Memory()[retptr / 8 + {}]", offset / 8),
+                        format!("elem{}", i),
+                        "number".to_string(),
+                    )
+                }
+                Descriptor::Boolean => {
+                    (u32_at(offset), format!("elem{} !== 0", i), "boolean".to_string())
+                }
+                Descriptor::Char => (
+                    u32_at(offset),
+                    format!("String.fromCodePoint(elem{})", i),
+                    "string".to_string(),
+                ),
+                Descriptor::Anyref => {
+                    self.cx.expose_take_object();
+                    (u32_at(offset), format!("takeObject(elem{})", i), "any".to_string())
+                }
+                Descriptor::RustStruct(ref name) => (
+                    u32_at(offset),
+                    format!("{}.__construct(elem{})", name, i),
+                    name.to_string(),
+                ),
+                _ => bail!("unsupported tuple element type for returning from Rust to JS: {:?}", elem),
+            };
+            reads.push_str(&format!("const elem{} = {};\n", i, read));
+            values.push(value);
+            tys.push(ty);
+            offset += size;
+        }
+
+        self.ret_ty = format!("[{}]", tys.join(", "));
+        self.ret_expr = format!(
+            "\
+             RET;\n\
+             {}\
+             {}\
+             return [{}];\n\
+             ",
+            reads,
+            converts,
+            values.join(", "),
+        );
+        Ok(self)
+    }
+
+    /// Maps are returned through `retptr` as an array of their keys and
+    /// values, which is turned back into a plain object or `Map`.
+    fn ret_map(&mut self, value: &Descriptor) -> Result<&mut Self, Error> {
+        let (_, ts) = map_value_ty(value)?;
+        self.ret_ty = self.map_ts(&ts);
+        self.cx.expose_map_entries(self.js_map);
+        let f = self.cx.expose_get_vector_from_wasm(VectorKind::Anyref);
+        self.cx.expose_global_argument_ptr()?;
+        self.cx.expose_uint32_memory();
+        self.cx.require_internal_export("__wbindgen_free")?;
+        self.prelude("const retptr = globalArgumentPtr();");
+        self.rust_arguments.insert(0, "retptr".to_string());
+        self.ret_expr = format!(
+            "\
+             RET;\n\
+             const mem = getUint32Memory();\n\
+             const rustptr = mem[retptr / 4];\n\
+             const rustlen = mem[retptr / 4 + 1];\n\
+             const entries = {}(rustptr, rustlen).slice();\n\
+             wasm.__wbindgen_free(rustptr, rustlen * 4);\n\
+             return {}(entries);\n\
+             ",
+            f,
+            if self.js_map { "entriesToMap" } else { "entriesToObject" },
+        );
+        Ok(self)
+    }
+
+    /// Boxed closures are returned as a pointer to their Rust data, with the
+    /// function table indices of their invoke and destroy functions in the
+    /// global argument stack. The resulting JS function owns the closure and
+    /// has a `free` method to deallocate it.
+    fn ret_closure(&mut self, closure: &Closure) -> Result<&mut Self, Error> {
+        let (js, ts_args, ts_ret) = {
+            let mut builder = Js2Rust::new("", self.cx);
+            if closure.mutable {
+                builder
+                    .prelude("let a = this.a;\n")
+                    .prelude("this.a = 0;\n")
+                    .rust_argument("a")
+                    .finally("this.a = a;\n");
+            } else {
+                builder.rust_argument("this.a");
+            }
+            builder.process(&closure.function)?;
+            let (js, _ts, _js_doc) = builder.finish("function", "this.f");
+            (js, builder.ts_arguments(), builder.ret_ty.clone())
+        };
+        self.cx.expose_get_global_argument()?;
+        self.cx.function_table_needed = true;
+        self.ret_ty = format!("(({}) => {}) & {{ free(): void }}", ts_args, ts_ret);
+        self.ret_expr = format!(
+            "\
+             const ptr = RET;\n\
+             const f = getGlobalArgument(0);\n\
+             const destroy = getGlobalArgument(1);\n\
+             const cb = {js};\n\
+             cb.f = wasm.__wbg_function_table.get(f);\n\
+             cb.a = ptr;\n\
+             const real = cb.bind(cb);\n\
+             real.free = function() {{\n\
+                 const a = cb.a;\n\
+                 cb.a = 0;\n\
+                 if (a !== 0) {{\n\
+                     wasm.__wbg_function_table.get(destroy)(a);\n\
+                 }}\n\
+             }};\n\
+             return real;\n\
+             ",
+            js = js,
+        );
+        Ok(self)
+    }
+
     pub fn js_doc_comments(&self) -> String {
-        let mut ret: String = self.js_arguments.iter().map(|a| {
-            format!("@param {{{}}} {}\n", a.1, a.0)
+        let mut ret: String = self.js_arguments.iter().enumerate().map(|(i, a)| {
+            if let Some(default) = self.default_value(i) {
+                format!("@param {{{}}} [{}={}]\n", a.1, a.0, default)
+            } else if self.is_optional(i) {
+                format!("@param {{{}}} [{}]\n", a.1, a.0)
+            } else {
+                format!("@param {{{}}} {}\n", a.1, a.0)
+            }
         }).collect();
         ret.push_str(&format!("@returns {{{}}}", self.ret_ty));
         ret
@@ -619,42 +1324,185 @@ impl<'a, 'b> Js2Rust<'a, 'b> {
         let js_args = self
             .js_arguments
             .iter()
-            .map(|s| &s.0[..])
+            .enumerate()
+            .map(|(i, s)| match self.default_value(i) {
+                Some(default) => format!("{} = {}", s.0, default),
+                None => s.0.clone(),
+            })
             .collect::<Vec<_>>()
             .join(", ");
         let mut js = format!("{}({}) {{\n", prefix, js_args);
-        js.push_str(&self.prelude);
-        let rust_args = self.rust_arguments.join(", ");
+        if self.cx.config.poison_on_panic {
+            js.push_str("assertNotPoisoned();\n");
+        }
+        if self.cx.config.profile_boundary {
+            js.push_str("const profileStart = profileNow();\ntry {\n");
+        }
+        let mut prelude = self.prelude.clone();
+        let mut rust_args = self.rust_arguments.clone();
+        if self.undo.len() > 0 {
+            // The `catch` below only sees the hoisted names, so declaring one
+            // again in the prelude would leave it with nothing to release.
+            for name in self.hoisted.iter() {
+                assert!(
+                    !declares(&self.prelude, name),
+                    "`{}` is hoisted, but is declared again in the prelude of `{}`",
+                    name,
+                    self.js_name,
+                );
+            }
+            js.push_str(&format!("let {};\n", self.hoisted.join(", ")));
+            js.push_str("let entered = false;\n");
+            js.push_str("try {\n");
 
+            // Evaluate anything that might still throw before flagging that
+            // ownership of the arguments has passed to Rust.
+            for (i, arg) in rust_args.iter_mut().enumerate() {
+                if is_js_ident(arg) {
+                    continue;
+                }
+                prelude.push_str(&format!("const wasmArg{} = {};\n", i, arg));
+                *arg = format!("wasmArg{}", i);
+            }
+            prelude.push_str("entered = true;\n");
+        }
+        js.push_str(&prelude);
+        let rust_args = rust_args.join(", ");
+
+        // Profiling stats are keyed by the wasm export, as method names alone
+        // aren't unique across classes.
+        let profile_name = if invoc.starts_with("wasm.") { &invoc[5..] } else { "closure" };
         let invoc = self
             .ret_expr
             .replace("RET", &format!("{}({})", invoc, rust_args));
-        let invoc = if self.finally.len() == 0 {
+        // Restoring the stack to the depth it had on entry, rather than
+        // popping once per argument, keeps it intact if an exception is thrown
+        // before all of the borrowed arguments were pushed.
+        let mut finally = String::new();
+        if self.borrowed_objects > 0 {
+            if self.cx.config.debug {
+                finally.push_str(&format!(
+                    "restoreStack(stackDepth, {});\n",
+                    self.borrowed_objects
+                ));
+            } else {
+                finally.push_str("restoreStack(stackDepth);\n");
+            }
+        }
+        finally.push_str(&self.finally);
+        let mut catch = String::new();
+        if self.report_errors {
+            let name = if self.js_name.len() > 0 { &self.js_name[..] } else { "closure" };
+            catch.push_str(&format!("reportWasmError(e, '{}', arguments);\n", name));
+        }
+        if self.cx.config.poison_on_panic {
+            catch.push_str("throw poisonOnTrap(e);\n");
+        } else if catch.len() > 0 {
+            catch.push_str("throw e;\n");
+        }
+        if catch.len() > 0 {
+            catch = format!("\n}} catch (e) {{\n{}", catch);
+        }
+        let invoc = if finally.len() == 0 && catch.len() == 0 {
             invoc
+        } else if finally.len() == 0 {
+            format!("try {{\n{}{}}}\n", &invoc, catch)
         } else {
             format!(
                 "\
                 try {{\n\
-                    {}
+                    {}{}
                 \n}} finally {{\n\
                     {}
                 }}\n\
                 ",
-                &invoc, &self.finally,
+                &invoc, catch, &finally,
             )
         };
         js.push_str(&invoc);
+        if self.undo.len() > 0 {
+            js.push_str(&format!(
+                "\
+                \n}} catch (e) {{\n\
+                    if (!entered) {{\n\
+                        {}\
+                    }}\n\
+                    throw e;\n\
+                }}\
+                ",
+                self.undo,
+            ));
+        }
+        if self.cx.config.profile_boundary {
+            js.push_str(&format!(
+                "\n}} finally {{\nrecordProfile('{}', profileStart);\n}}",
+                profile_name,
+            ));
+        }
         js.push_str("\n}");
-        let ts_args = self
-            .js_arguments
-            .iter()
-            .map(|s| format!("{}: {}", s.0, s.1))
-            .collect::<Vec<_>>()
-            .join(", ");
         let ts = format!(
             "{} {}({}): {};\n",
-            prefix, self.js_name, ts_args, self.ret_ty
+            prefix, self.js_name, self.ts_arguments(), self.ret_ty
         );
         (js, ts, self.js_doc_comments())
     }
+
+    /// Returns the TypeScript list of arguments for this shim.
+    pub fn ts_arguments(&self) -> String {
+        self.js_arguments
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let optional = if self.is_optional(i) { "?" } else { "" };
+                format!("{}{}: {}", s.0, optional, s.1)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// The TypeScript type of a string enum, a union of its string values.
+fn string_enum_ts(variants: &[String]) -> String {
+    variants
+        .iter()
+        .map(|v| serde_json::to_string(v).unwrap())
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// The `typeof` JS checks the values of a map passed to Rust against, if
+/// any, and the TypeScript type of the values.
+fn map_value_ty(value: &Descriptor) -> Result<(&'static str, String), Error> {
+    let ty = match *value {
+        Descriptor::Boolean => ("'boolean'", "boolean"),
+        Descriptor::String => ("'string'", "string"),
+        Descriptor::Anyref => ("undefined", "any"),
+        ref d if d.is_number() => ("'number'", "number"),
+        _ => bail!("unsupported type for the values of a map: {:?}", value),
+    };
+    Ok((ty.0, ty.1.to_string()))
+}
+
+/// Whether `js` has a `let`, `const` or `var` declaring `name`.
+fn declares(js: &str, name: &str) -> bool {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+    ["let ", "const ", "var "].iter().any(|keyword| {
+        js.match_indices(keyword).any(|(i, _)| {
+            if js[..i].chars().next_back().map_or(false, is_ident_char) {
+                return false;
+            }
+            let rest = &js[i + keyword.len()..];
+            let end = rest.find(|c| c == '=' || c == ';' || c == '\n').unwrap_or(rest.len());
+            rest[..end].split(|c| !is_ident_char(c)).any(|s| s == name)
+        })
+    })
+}
+
+fn is_js_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
 }
@@ -5,16 +5,21 @@ use std::mem;
 use failure::{Error, ResultExt};
 use parity_wasm;
 use parity_wasm::elements::*;
+use serde_json;
 use shared;
 use wasm_gc;
 
 use super::Bindgen;
 use descriptor::{Descriptor, VectorKind};
+use diagnostic::{Diagnostic, DiagnosticKind};
+use interface::Interface;
 
 mod js2rust;
 use self::js2rust::Js2Rust;
 mod rust2js;
 use self::rust2js::Rust2Js;
+#[cfg(test)]
+mod tests;
 
 pub struct Context<'a> {
     pub globals: String,
@@ -40,23 +45,122 @@ pub struct Context<'a> {
     pub imported_identifiers: HashMap<String, usize>,
 
     pub exported_classes: HashMap<String, ExportedClass>,
+    pub options_interfaces: HashSet<String>,
+
+    /// Names of the JS tables holding the strings of each string enum, keyed
+    /// by the strings themselves.
+    pub string_enums: HashMap<Vec<String>, String>,
+
+    /// The exported API, recorded for `--emit-interface`.
+    pub interface: Interface,
+
+    /// Names of all classes which are extended by an exported class.
+    pub subclassed: HashSet<String>,
+    /// What the imported types which are extended by an exported class are
+    /// called in the generated JS, keyed by their Rust names.
+    pub imported_parents: HashMap<String, String>,
+    /// What to fall back on for imported globals which might be missing,
+    /// keyed by their JS names.
+    pub global_fallbacks: HashMap<String, GlobalFallbacks>,
+    /// Names of everything exported from the JS module so far.
+    pub exported_names: HashSet<String>,
+    /// Problems found so far which don't stop the bindings from being
+    /// generated.
+    pub diagnostics: Vec<Diagnostic>,
     pub function_table_needed: bool,
     pub run_descriptor: &'a Fn(&str) -> Option<Vec<u32>>,
 }
 
+/// What to use for an imported global when the browser doesn't define it.
+pub struct GlobalFallbacks {
+    /// Prefixes of the names it might be defined with instead, in the order
+    /// they're tried.
+    pub vendor_prefixes: Vec<String>,
+    /// A module exporting an implementation to use if all else fails.
+    pub polyfill: Option<String>,
+}
+
 #[derive(Default)]
 pub struct ExportedClass {
     comments: String,
     contents: String,
     typescript: String,
     constructor: Option<String>,
+    constructor_typescript: Option<String>,
     fields: Vec<ClassField>,
+    /// Properties defined by exported `getter` and `setter` methods.
+    accessors: Vec<ClassAccessor>,
+    extends: Option<String>,
+    extends_rust: bool,
+    custom_element: Option<String>,
+    observed_attributes: Vec<String>,
+    methods: HashSet<String>,
+    /// `Object.defineProperty` calls to run once the class is defined.
+    descriptors: String,
+    async_iterator: bool,
+    /// Whether the struct is marked `iterator`, and the TypeScript type of
+    /// its items once the method returning them has been generated.
+    iterator: bool,
+    iterator_item_ty: Option<String>,
+    shared_class: Option<String>,
+    /// The methods indexing instances with numbers is forwarded to, if any.
+    /// Instances of classes with any of these are wrapped in a `Proxy`.
+    indexing_getter: Option<String>,
+    indexing_setter: Option<String>,
+    indexing_deleter: Option<String>,
+    /// The TypeScript type of the values instances hold at each index.
+    index_ty: Option<String>,
 }
 
+/// The lifecycle callbacks of custom elements, and the names of the Rust
+/// methods they're forwarded to.
+const CUSTOM_ELEMENT_CALLBACKS: &[(&str, &str)] = &[
+    ("connectedCallback", "connected_callback"),
+    ("disconnectedCallback", "disconnected_callback"),
+    ("adoptedCallback", "adopted_callback"),
+    ("attributeChangedCallback", "attribute_changed_callback"),
+];
+
 struct ClassField {
     comments: Vec<String>,
     name: String,
     readonly: bool,
+    enumerable: bool,
+    configurable: bool,
+}
+
+struct ClassAccessor {
+    name: String,
+    ty: String,
+    readonly: bool,
+}
+
+impl ClassAccessor {
+    fn typescript(&self) -> String {
+        let readonly = if self.readonly { "readonly " } else { "" };
+        format!("{}{}: {}", readonly, self.name, self.ty)
+    }
+}
+
+/// Changes the property descriptor of a property which a class already has.
+fn define_property(
+    dst: &mut String,
+    target: &str,
+    name: &str,
+    enumerable: bool,
+    configurable: bool,
+) {
+    // Computed names like `[Symbol.toPrimitive]` are keyed by the expression
+    // in the brackets rather than a string.
+    let key = if name.starts_with('[') {
+        name[1..name.len() - 1].to_string()
+    } else {
+        format!("'{}'", name)
+    };
+    dst.push_str(&format!(
+        "Object.defineProperty({}, {}, {{ enumerable: {}, configurable: {} }});\n",
+        target, key, enumerable, configurable,
+    ));
 }
 
 pub struct SubContext<'a, 'b: 'a> {
@@ -68,6 +172,17 @@ const INITIAL_SLAB_VALUES: &[&str] = &["undefined", "null", "true", "false"];
 
 impl<'a> Context<'a> {
     fn export(&mut self, name: &str, contents: &str, comments: Option<String>) {
+        if !self.exported_names.insert(name.to_string()) {
+            self.warn(
+                DiagnosticKind::NameCollision,
+                name,
+                format!(
+                    "`{}` is exported more than once, so only one of its \
+                     definitions can be used",
+                    name
+                ),
+            );
+        }
         let contents = contents.trim();
         if let Some(ref c) = comments {
             self.globals.push_str(c);
@@ -78,7 +193,7 @@ impl<'a> Context<'a> {
             } else {
                 format!("module.exports.{} = {};\n", name, contents)
             }
-        } else if self.config.no_modules {
+        } else if self.use_no_modules_wrapper() {
             if contents.starts_with("class") {
                 format!("{1}\n__exports.{0} = {0};\n", name, contents)
             } else {
@@ -96,6 +211,38 @@ impl<'a> Context<'a> {
         self.global(&global);
     }
 
+    fn warn(&mut self, kind: DiagnosticKind, item: &str, message: String) {
+        self.diagnostics.push(Diagnostic {
+            kind,
+            item: item.to_string(),
+            message,
+        });
+    }
+
+    /// Records that `item` was left out of the bindings because the wasm
+    /// module has no description of its types.
+    fn skip_undescribed(&mut self, item: &str) {
+        self.warn(
+            DiagnosticKind::SkippedMember,
+            item,
+            format!(
+                "no bindings were generated for `{}` because the wasm module \
+                 doesn't describe its types",
+                item
+            ),
+        );
+    }
+
+    fn warn_member_collision(&mut self, class: &str, member: &str) {
+        let item = format!("{}.{}", class, member);
+        let message = format!(
+            "`{}` is defined more than once, so only one of its definitions \
+             can be used",
+            item
+        );
+        self.warn(DiagnosticKind::NameCollision, &item, message);
+    }
+
     fn require_internal_export(&mut self, name: &'static str) -> Result<(), Error> {
         if !self.required_internal_exports.insert(name) {
             return Ok(());
@@ -170,6 +317,25 @@ impl<'a> Context<'a> {
             ))
         })?;
 
+        self.bind("__wbindgen_string_new_utf16", &|me| {
+            me.expose_add_heap_object();
+            me.expose_get_array_u16_from_wasm();
+            Ok(String::from(
+                "
+                function(p, l) {
+                    const units = getArrayU16FromWasm(p, l);
+                    let s = '';
+                    // Convert in chunks to stay clear of engine limits on the
+                    // number of arguments to a function.
+                    for (let i = 0; i < units.length; i += 4096) {
+                        s += String.fromCharCode.apply(null, units.subarray(i, i + 4096));
+                    }
+                    return addHeapObject(s);
+                }
+                ",
+            ))
+        })?;
+
         self.bind("__wbindgen_number_new", &|me| {
             me.expose_add_heap_object();
             Ok(String::from(
@@ -314,6 +480,27 @@ impl<'a> Context<'a> {
             ))
         })?;
 
+        self.bind("__wbindgen_string_get_utf16", &|me| {
+            me.expose_pass_array16_to_wasm()?;
+            me.expose_get_object();
+            me.expose_uint32_memory();
+            Ok(String::from(
+                "
+                function(i, len_ptr) {
+                    let obj = getObject(i);
+                    if (typeof(obj) !== 'string') return 0;
+                    const units = new Uint16Array(obj.length);
+                    for (let i = 0; i < obj.length; i++) {
+                        units[i] = obj.charCodeAt(i);
+                    }
+                    const [ptr, len] = passArray16ToWasm(units);
+                    getUint32Memory()[len_ptr / 4] = len;
+                    return ptr;
+                }
+                ",
+            ))
+        })?;
+
         self.bind("__wbindgen_cb_drop", &|me| {
             me.expose_drop_ref();
             Ok(String::from(
@@ -338,6 +525,46 @@ impl<'a> Context<'a> {
             ))
         })?;
 
+        self.bind("__wbindgen_queue_microtask", &|me| {
+            me.require_internal_export("__wbindgen_microtask_run")?;
+            Ok(String::from(
+                "
+                function(f) {
+                    const run = () => wasm.__wbindgen_microtask_run(f);
+                    if (typeof(queueMicrotask) === 'function') {
+                        queueMicrotask(run);
+                    } else {
+                        Promise.resolve().then(run);
+                    }
+                }
+                ",
+            ))
+        })?;
+
+        self.bind("__wbindgen_panic_error", &|me| {
+            me.expose_get_string_from_wasm();
+            me.expose_add_heap_object();
+            me.expose_wasm_panic_error();
+            Ok(String::from(
+                "
+                function(ptr, len) {
+                    return addHeapObject(new WasmPanicError(getStringFromWasm(ptr, len)));
+                }
+                ",
+            ))
+        })?;
+
+        self.bind("__wbindgen_rethrow", &|me| {
+            me.expose_take_object();
+            Ok(String::from(
+                "
+                function(idx) {
+                    throw takeObject(idx);
+                }
+                ",
+            ))
+        })?;
+
         self.bind("__wbindgen_json_parse", &|me| {
             me.expose_add_heap_object();
             me.expose_get_string_from_wasm();
@@ -383,6 +610,133 @@ impl<'a> Context<'a> {
         // `__wbindgen_malloc` function may call this but we only want to
         // generate code for this if it's actually live (and __wbindgen_malloc
         // isn't gc'd).
+        self.bind("__wbindgen_set_error_hook", &|me| {
+            me.expose_get_object();
+            me.expose_error_hook();
+            Ok(String::from(
+                "
+                function(i) {
+                    const hook = getObject(i);
+                    wasmErrorHook = typeof(hook) === 'function' ? hook : null;
+                }
+                ",
+            ))
+        })?;
+
+        self.bind("__wbindgen_copy_to_wasm", &|me| {
+            me.expose_get_object();
+            Ok(String::from(
+                "
+                function(idx, ptr, len) {
+                    const array = getObject(idx);
+                    new array.constructor(wasm.memory.buffer, ptr, len).set(array);
+                }
+                ",
+            ))
+        })?;
+
+        self.bind("__wbindgen_copy_from_wasm", &|me| {
+            me.expose_get_object();
+            Ok(String::from(
+                "
+                function(idx, ptr, len) {
+                    const array = getObject(idx);
+                    array.set(new array.constructor(wasm.memory.buffer, ptr, len));
+                }
+                ",
+            ))
+        })?;
+
+        self.bind("__wbindgen_replay_commands", &|me| {
+            me.expose_get_object();
+            me.expose_f64_memory();
+            me.expose_get_string_from_wasm();
+            me.expose_command_strings();
+            // The commands are copied out first as the calls being replayed
+            // may call back into wasm and grow its memory.
+            Ok(String::from(
+                "
+                function(ptr, len) {
+                    const words = getFloat64Memory().slice(ptr / 8, ptr / 8 + len);
+                    let i = 0;
+                    const str = () => {
+                        const key = words[i] * 4294967296 + words[i + 1];
+                        let s = commandStrings.get(key);
+                        if (s === undefined) {
+                            s = getStringFromWasm(words[i], words[i + 1]);
+                            commandStrings.set(key, s);
+                        }
+                        i += 2;
+                        return s;
+                    };
+                    const arg = () => {
+                        switch (words[i++]) {
+                            case 0: return words[i++];
+                            case 1: return words[i++] !== 0;
+                            case 2: return getObject(words[i++]);
+                            default: return str();
+                        }
+                    };
+                    while (i < len) {
+                        const cmd = words[i++];
+                        if (cmd === 0) {
+                            const func = getObject(words[i++]);
+                            const target = getObject(words[i++]);
+                            const args = new Array(words[i++]);
+                            for (let j = 0; j < args.length; j++) args[j] = arg();
+                            func.apply(target, args);
+                        } else if (cmd === 1) {
+                            const target = getObject(words[i++]);
+                            const name = str();
+                            const args = new Array(words[i++]);
+                            for (let j = 0; j < args.length; j++) args[j] = arg();
+                            target[name].apply(target, args);
+                        } else {
+                            const target = getObject(words[i++]);
+                            const name = str();
+                            target[name] = arg();
+                        }
+                    }
+                }
+                ",
+            ))
+        })?;
+
+        self.bind("__wbindgen_heap_stats", &|me| {
+            me.expose_global_slab();
+            me.expose_uint32_memory();
+            Ok(format!(
+                "
+                function(ret) {{
+                    let live = 0;
+                    for (let i = {}; i < slab.length; i++) {{
+                        if (typeof(slab[i]) !== 'number') live += 1;
+                    }}
+                    const mem = getUint32Memory();
+                    mem[ret / 4] = live;
+                    mem[ret / 4 + 1] = slab.length;
+                }}
+                ",
+                INITIAL_SLAB_VALUES.len(),
+            ))
+        })?;
+
+        self.bind("__wbindgen_heap_entries", &|me| {
+            me.expose_heap_dump();
+            me.expose_add_heap_object();
+            Ok(String::from("function() { return addHeapObject(heapDump()); }"))
+        })?;
+
+        self.bind("__wbindgen_supports_bigint64", &|me| {
+            me.expose_supports_bigint64();
+            Ok(String::from("function() { return supportsBigInt64 ? 1 : 0; }"))
+        })?;
+
+        self.bind("__wbindgen_supports_weak_ref", &|me| {
+            me.expose_supports_weak_ref();
+            Ok(String::from("function() { return supportsWeakRef ? 1 : 0; }"))
+        })?;
+
         self.bind("__wbindgen_throw", &|me| {
             me.expose_get_string_from_wasm();
             Ok(String::from(
@@ -395,38 +749,134 @@ impl<'a> Context<'a> {
         })?;
 
         self.rewrite_imports(module_name);
+        let imports = if self.config.import_memory {
+            self.import_memory(module_name)
+        } else {
+            format!("const imports = {{ './{}': __exports }};", module_name)
+        };
 
-        let mut js = if self.config.no_modules {
+        let mut js = if self.use_no_modules_wrapper() {
+            // Instantiating a second time, for example from `init.module` after
+            // the first instance trapped, starts over with fresh JS state too.
+            let mut reset = String::new();
+            if self.exposed_globals.contains("slab") {
+                reset.push_str(&format!("slab.length = {};\n", INITIAL_SLAB_VALUES.len()));
+            }
+            if self.exposed_globals.contains("slab_next") {
+                reset.push_str("slab_next = slab.length;\n");
+            }
+            if self.exposed_globals.contains("stack") {
+                reset.push_str("stack.length = 0;\n");
+            }
+            if self.exposed_globals.contains("poison") {
+                reset.push_str("wasmPoisoned = false;\n");
+            }
+            if self.exposed_globals.contains("command_strings") {
+                reset.push_str("commandStrings.clear();\n");
+            }
+            let global_name = self.config.no_modules_global
+                .as_ref()
+                .map(|s| &**s)
+                .unwrap_or("wasm_bindgen");
+            let fetch = "
+                const fetchPromise = fetch(module_or_path);
+                if (typeof WebAssembly.instantiateStreaming === 'function') {
+                    resultPromise = WebAssembly.instantiateStreaming(fetchPromise, imports);
+                } else {
+                    resultPromise = fetchPromise
+                        .then(response => response.arrayBuffer())
+                        .then(buffer => WebAssembly.instantiate(buffer, imports));
+                }
+            ";
+            // Universal output works out where it's running when it's loaded:
+            // node reads the wasm file next to the script and browsers and
+            // workers fetch it, by default from next to the script too.
+            let (detect, load, expose) = if self.config.universal {
+                (
+                    format!(
+                        "
+                        const isNode = typeof process === 'object' && process.versions != null && process.versions.node != null;
+                        let defaultPath;
+                        if (isNode) {{
+                            defaultPath = require('path').join(__dirname, '{module}_bg.wasm');
+                        }} else if (typeof document === 'object' && document.currentScript) {{
+                            defaultPath = new URL('{module}_bg.wasm', document.currentScript.src).href;
+                        }} else {{
+                            defaultPath = new URL('{module}_bg.wasm', self.location.href).href;
+                        }}
+                        ",
+                        module = module_name,
+                    ),
+                    format!(
+                        "
+                        }} else if (isNode) {{
+                            resultPromise = new Promise((resolve, reject) => {{
+                                require('fs').readFile(module_or_path, (err, buffer) => {{
+                                    if (err) reject(err);
+                                    else resolve(buffer);
+                                }});
+                            }}).then(buffer => WebAssembly.instantiate(buffer, imports));
+                        }} else {{
+                            {fetch}
+                        ",
+                        fetch = fetch,
+                    ),
+                    format!(
+                        "
+                        if (typeof module === 'object' && module.exports) {{
+                            module.exports = Object.assign(init, __exports);
+                        }} else {{
+                            self.{global_name} = Object.assign(init, __exports);
+                        }}
+                        ",
+                        global_name = global_name,
+                    ),
+                )
+            } else {
+                (
+                    String::new(),
+                    format!("}} else {{\n{}", fetch),
+                    format!("self.{} = Object.assign(init, __exports);", global_name),
+                )
+            };
+            let default_path = if self.config.universal {
+                "if (module_or_path === undefined) module_or_path = defaultPath;"
+            } else {
+                ""
+            };
             format!(
                     "
                     (function() {{
                         var wasm;
                         const __exports = {{}};
+                        {detect}
                         {globals}
-                        function init(wasm_path) {{
-                            const fetchPromise = fetch(wasm_path);
+                        function init(module_or_path, options) {{
+                            {default_path}
+                            {imports}
                             let resultPromise;
-                            if (typeof WebAssembly.instantiateStreaming === 'function') {{
-                                resultPromise = WebAssembly.instantiateStreaming(fetchPromise, {{ './{module}': __exports }});
-                            }} else {{
-                                resultPromise = fetchPromise
-                                    .then(response => response.arrayBuffer())
-                                    .then(buffer => WebAssembly.instantiate(buffer, {{ './{module}': __exports }}));
+                            if (module_or_path instanceof WebAssembly.Module) {{
+                                resultPromise = WebAssembly.instantiate(module_or_path, imports)
+                                    .then(instance => ({{ instance, module: module_or_path }}));
+                            {load}
                             }}
-                            return resultPromise.then(({{instance}}) => {{
+                            return resultPromise.then(({{instance, module}}) => {{
+                                {reset}
                                 wasm = init.wasm = instance.exports;
+                                init.module = module;
                                 return;
                             }});
                         }};
-                        self.{global_name} = Object.assign(init, __exports);
+                        {expose}
                     }})();
                     ",
+                    detect = detect,
+                    default_path = default_path,
+                    imports = imports,
+                    load = load,
+                    expose = expose,
+                    reset = reset,
                     globals = self.globals,
-                    module = module_name,
-                    global_name = self.config.no_modules_global
-                        .as_ref()
-                        .map(|s| &**s)
-                        .unwrap_or("wasm_bindgen"),
             )
         } else {
             let import_wasm = if self.globals.len() == 0 {
@@ -435,6 +885,9 @@ impl<'a> Context<'a> {
                 self.footer
                     .push_str(&format!("wasm = require('./{}_bg');", module_name));
                 format!("var wasm;")
+            } else if self.config.nodejs_experimental_modules {
+                // Node's ES module loader doesn't add file extensions.
+                format!("import * as wasm from './{}_bg.mjs';", module_name)
             } else {
                 format!("import * as wasm from './{}_bg';", module_name)
             };
@@ -480,17 +933,195 @@ impl<'a> Context<'a> {
 
     fn write_classes(&mut self) -> Result<(), Error> {
         let classes = mem::replace(&mut self.exported_classes, Default::default());
-        for (class, exports) in classes {
-            self.write_class(&class, &exports)?;
+        let mut written = HashSet::new();
+        for class in classes.keys() {
+            self.write_class_and_parents(class, &classes, &mut written, &mut Vec::new())?;
         }
         Ok(())
     }
 
+    /// JS classes must be defined before they're extended, so this writes out
+    /// the exported parents of a class before the class itself.
+    fn write_class_and_parents(
+        &mut self,
+        name: &str,
+        classes: &HashMap<String, ExportedClass>,
+        written: &mut HashSet<String>,
+        stack: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        if written.contains(name) {
+            return Ok(());
+        }
+        if stack.iter().any(|s| s == name) {
+            bail!("exported class `{}` cannot extend itself", name);
+        }
+        let class = &classes[name];
+        if let Some(ref parent) = class.extends {
+            match (classes.contains_key(parent), class.extends_rust) {
+                (true, true) => {
+                    stack.push(name.to_string());
+                    self.write_class_and_parents(parent, classes, written, stack)?;
+                    stack.pop();
+                }
+                (false, false) => {}
+                (true, false) => bail!(
+                    "exported class `{}` extends the exported class `{}`, so it \
+                     must be #[repr(C)] with a `{1}` as its first field",
+                    name,
+                    parent,
+                ),
+                (false, true) => bail!(
+                    "exported class `{}` extends `{}`, which isn't an exported class",
+                    name,
+                    parent,
+                ),
+            }
+        }
+        written.insert(name.to_string());
+        self.write_class(name, class)
+    }
+
     fn write_class(&mut self, name: &str, class: &ExportedClass) -> Result<(), Error> {
-        let mut dst = format!("class {} {{\n", name);
+        let extends = match class.extends {
+            Some(ref parent) if !class.extends_rust => {
+                Some(self.imported_parents.get(parent).unwrap_or(parent).clone())
+            }
+            ref extends => extends.clone(),
+        };
+        self.interface
+            .classes
+            .entry(name.to_string())
+            .or_insert_with(Default::default)
+            .extends = extends.clone();
+        let mut dst = match extends {
+            Some(ref parent) => format!("class {} extends {} {{\n", name, parent),
+            None => format!("class {} {{\n", name),
+        };
         let mut ts_dst = format!("export {}", dst);
+        // Custom elements can only be used in a browser's main thread, but
+        // they shouldn't stop the rest of the module loading elsewhere, like
+        // in node or a worker.
+        let global_parent = extends.as_ref().map(|s| &s[..]) == Some("HTMLElement");
+        if class.custom_element.is_some() && global_parent {
+            dst = format!(
+                "class {} extends (typeof HTMLElement === 'undefined' ? Object : HTMLElement) {{\n",
+                name,
+            );
+        }
 
-        if self.config.debug || class.constructor.is_some() {
+        // Fields are usually accessors on the prototype, but enumerable ones
+        // are defined on each instance instead so that `Object.keys` and
+        // friends see them.
+        let mut descriptors = class.descriptors.clone();
+        let mut fields = String::new();
+        let mut ts_fields = String::new();
+        let mut own_fields = String::new();
+        for field in class.fields.iter() {
+            let wasm_getter = shared::struct_field_get(name, &field.name);
+            let wasm_setter = shared::struct_field_set(name, &field.name);
+            let descriptor = match self.describe(&wasm_getter) {
+                None => {
+                    self.skip_undescribed(&format!("{}.{}", name, field.name));
+                    continue;
+                }
+                Some(d) => d,
+            };
+
+            let (set, ts) = {
+                let mut cx = Js2Rust::new(&field.name, self);
+                cx.method(true, false).argument(&descriptor)?.ret(&None)?;
+                let ts = format!(
+                    "{}{}: {}",
+                    if field.readonly { "readonly " } else { "" },
+                    field.name,
+                    &cx.js_arguments[0].1
+                );
+                (cx.finish("", &format!("wasm.{}", wasm_setter)).0, ts)
+            };
+            ts_fields.push_str(&ts);
+            ts_fields.push_str("\n");
+            let previous = self
+                .interface
+                .classes
+                .entry(name.to_string())
+                .or_insert_with(Default::default)
+                .members
+                .insert(field.name.clone(), ts);
+            if previous.is_some() {
+                self.warn_member_collision(name, &field.name);
+            }
+            let (get, _ts, js_doc) = Js2Rust::new(&field.name, self)
+                .method(true, false)
+                .ret(&Some(descriptor))?
+                .finish("", &format!("wasm.{}", wasm_getter));
+            if field.enumerable {
+                own_fields.push_str(&format!(
+                    "Object.defineProperty(this, '{}', {{\nget: function{},\n",
+                    field.name, get,
+                ));
+                if !field.readonly {
+                    own_fields.push_str(&format!("set: function{},\n", set));
+                }
+                own_fields.push_str(&format!(
+                    "enumerable: true,\nconfigurable: {},\n}});\n",
+                    field.configurable,
+                ));
+                continue;
+            }
+            if !fields.ends_with("\n") {
+                fields.push_str("\n");
+            }
+            fields.push_str(&format_doc_comments(&field.comments, Some(js_doc)));
+            fields.push_str("get ");
+            fields.push_str(&field.name);
+            fields.push_str(&get);
+            fields.push_str("\n");
+            if !field.readonly {
+                fields.push_str("set ");
+                fields.push_str(&field.name);
+                fields.push_str(&set);
+            }
+            if !field.configurable {
+                let target = format!("{}.prototype", name);
+                define_property(&mut descriptors, &target, &field.name, false, false);
+            }
+        }
+
+        // Exported parents are always created with a `ConstructorToken` so
+        // that the `super` call here doesn't allocate anything in Rust.
+        let super_call = match class.extends {
+            Some(_) if class.extends_rust => {
+                self.expose_constructor_token();
+                "super(new ConstructorToken(0));"
+            }
+            Some(_) => "super();",
+            None => "",
+        };
+        let super_call = format!("{}\n{}", super_call, own_fields);
+
+        // Indexing classes hand out a `Proxy` of each instance from their
+        // constructor, which forwards numeric keys to the indexing methods.
+        let indexed = class.indexing_getter.is_some()
+            || class.indexing_setter.is_some()
+            || class.indexing_deleter.is_some();
+        let return_this = if indexed {
+            if class.custom_element.is_some() {
+                bail!(
+                    "the custom element `{}` cannot have indexing methods",
+                    name
+                );
+            }
+            self.expose_indexing_handler(name, class);
+            format!("return new Proxy(this, {}IndexingHandler);", name)
+        } else {
+            String::new()
+        };
+
+        if self.config.debug
+            || class.constructor.is_some()
+            || class.custom_element.is_some()
+            || self.subclassed.contains(name)
+        {
             self.expose_constructor_token();
 
             dst.push_str(&format!(
@@ -500,26 +1131,61 @@ impl<'a> Context<'a> {
                 }}
 
                 constructor(...args) {{
+                    {}
                     if (args.length === 1 && args[0] instanceof ConstructorToken) {{
                         this.ptr = args[0].ptr;
-                        return;
+                        {}
                     }}
                 ",
-                name
+                name,
+                super_call,
+                if indexed { &return_this } else { "return;" },
             ));
 
             if let Some(ref constructor) = class.constructor {
-                ts_dst.push_str(&format!("constructor(...args: any[]);\n"));
+                match class.constructor_typescript {
+                    Some(ref ts) => ts_dst.push_str(ts),
+                    None => ts_dst.push_str("constructor(...args: any[]);\n"),
+                }
 
-                dst.push_str(&format!(
-                    "
-                    // This invocation of new will call this constructor with a ConstructorToken
-                    let instance = {class}.{constructor}(...args);
-                    this.ptr = instance.ptr;
-                    ",
-                    class = name,
-                    constructor = constructor
-                ));
+                if class.custom_element.is_some() {
+                    // Browsers create custom elements with `new` and then
+                    // use the object they get back, so rather than creating
+                    // another instance the Rust object is stored directly
+                    // in this one.
+                    let wasm_name = shared::struct_function_export_name(name, constructor);
+                    let takes_args = self
+                        .describe(&wasm_name)
+                        .map(|d| !d.unwrap_function().arguments.is_empty())
+                        .unwrap_or(false);
+                    if takes_args {
+                        bail!(
+                            "the constructor of the custom element `{}` cannot \
+                             take any arguments",
+                            name
+                        );
+                    }
+                    dst.push_str(&format!("this.ptr = wasm.{}();\n", wasm_name));
+                } else {
+                    dst.push_str(&format!(
+                        "
+                        // This invocation of new will call this constructor with a ConstructorToken
+                        let instance = {class}.{constructor}(...args);
+                        this.ptr = instance.ptr;
+                        ",
+                        class = name,
+                        constructor = constructor
+                    ));
+                    if indexed {
+                        dst.push_str(&format!("{}\n", return_this));
+                    }
+                }
+            } else if class.custom_element.is_some() {
+                bail!(
+                    "the custom element `{}` needs a method annotated as a \
+                     constructor so that browsers can create it",
+                    name
+                );
             } else {
                 dst.push_str(
                     "throw new Error('you cannot invoke `new` directly without having a \
@@ -536,10 +1202,14 @@ impl<'a> Context<'a> {
                 }}
 
                 constructor(ptr) {{
+                    {}
                     this.ptr = ptr;
+                    {}
                 }}
                 ",
-                name
+                name,
+                super_call,
+                return_this,
             ));
         }
 
@@ -561,52 +1231,167 @@ impl<'a> Context<'a> {
             );
         }
 
-        for field in class.fields.iter() {
-            let wasm_getter = shared::struct_field_get(name, &field.name);
-            let wasm_setter = shared::struct_field_set(name, &field.name);
-            let descriptor = match self.describe(&wasm_getter) {
-                None => continue,
-                Some(d) => d,
-            };
+        dst.push_str(&fields);
+        ts_dst.push_str(&ts_fields);
+        for accessor in class.accessors.iter() {
+            ts_dst.push_str(&accessor.typescript());
+            ts_dst.push_str("\n");
+        }
+        if let Some(ref ty) = class.index_ty {
+            ts_dst.push_str(&format!("[index: number]: {};\n", ty));
+        }
 
-            let set = {
-                let mut cx = Js2Rust::new(&field.name, self);
-                cx.method(true, false).argument(&descriptor)?.ret(&None)?;
-                ts_dst.push_str(&format!(
-                    "{}{}: {}\n",
-                    if field.readonly { "readonly " } else { "" },
-                    field.name,
-                    &cx.js_arguments[0].1
+        if class.custom_element.is_some() {
+            if class.observed_attributes.len() > 0 {
+                let attrs = class
+                    .observed_attributes
+                    .iter()
+                    .map(|a| format!("{:?}", a))
+                    .collect::<Vec<_>>();
+                dst.push_str(&format!(
+                    "
+                    static get observedAttributes() {{
+                        return [{}];
+                    }}
+                    ",
+                    attrs.join(", "),
                 ));
-                cx.finish("", &format!("wasm.{}", wasm_setter)).0
-            };
-            let (get, _ts, js_doc) = Js2Rust::new(&field.name, self)
-                .method(true, false)
-                .ret(&Some(descriptor))?
-                .finish("", &format!("wasm.{}", wasm_getter));
-            if !dst.ends_with("\n") {
-                dst.push_str("\n");
-            }
-            dst.push_str(&format_doc_comments(&field.comments, Some(js_doc)));
-            dst.push_str("get ");
-            dst.push_str(&field.name);
-            dst.push_str(&get);
-            dst.push_str("\n");
-            if !field.readonly {
-                dst.push_str("set ");
-                dst.push_str(&field.name);
-                dst.push_str(&set);
             }
+            for &(callback, method) in CUSTOM_ELEMENT_CALLBACKS {
+                if !class.methods.contains(method) {
+                    continue;
+                }
+                dst.push_str(&format!(
+                    "
+                    {}(...args) {{
+                        return this.{}(...args);
+                    }}
+                    ",
+                    callback,
+                    method,
+                ));
+            }
+        }
+
+        if class.async_iterator {
+            if !class.methods.contains("next") {
+                bail!(
+                    "the exported class `{}` is an async iterator, so it needs \
+                     a `next` method",
+                    name
+                );
+            }
+            dst.push_str(
+                "
+                [Symbol.asyncIterator]() {
+                    return this;
+                }
+                ",
+            );
+            ts_dst.push_str("[Symbol.asyncIterator](): this;\n");
+        }
+
+        if class.iterator {
+            if class.methods.contains("next") {
+                bail!(
+                    "the exported class `{}` is an iterator, so its `next` \
+                     method is generated and it can't export its own",
+                    name
+                );
+            }
+            dst.push_str(&format!(
+                "
+                next() {{
+                    const value = this.{}();
+                    return value === undefined ? {{ value, done: true }} : {{ value, done: false }};
+                }}
+
+                [Symbol.iterator]() {{
+                    return this;
+                }}
+                ",
+                shared::ITERATOR_NEXT,
+            ));
+            let ty = class.iterator_item_ty.as_ref().map(|s| &s[..]).unwrap_or("any");
+            let ts = format!("next(): IteratorResult<{}>;", ty);
+            ts_dst.push_str(&ts);
+            ts_dst.push_str("\n[Symbol.iterator](): this;\n");
+            self.interface
+                .classes
+                .entry(name.to_string())
+                .or_insert_with(Default::default)
+                .members
+                .insert("next".to_string(), ts);
         }
 
+        // Node's `console.log` and `util.inspect` don't use `toString`, so it's
+        // hooked up to node's own symbol for custom inspection as well.
+        if class.methods.contains("toString") {
+            dst.push_str(
+                "
+                [Symbol.for('nodejs.util.inspect.custom')]() {
+                    return this.toString();
+                }
+                ",
+            );
+        }
+
+        // Other wasm modules can't get at this class to use `instanceof`, so
+        // instances are branded with a name those modules can check for.
+        if let Some(ref shared_class) = class.shared_class {
+            dst.push_str(&format!(
+                "
+                get [Symbol.for('wasm-bindgen.shared_class')]() {{
+                    return {:?};
+                }}
+                ",
+                shared_class,
+            ));
+        }
+
+        // Where JS has a `FinalizationRegistry` an instance's Rust struct is
+        // also freed once the instance is garbage collected. The pointer is
+        // kept in a cell which is what's registered, as everything moving the
+        // struct out of the instance zeroes `ptr`, and there's nothing left
+        // to free once it has.
+        self.expose_supports_weak_ref();
+        self.expose_ptr_cell();
+        let finalization = format!("{}Finalization", name);
+        self.global(&format!(
+            "
+            const {} = supportsWeakRef
+                ? new FinalizationRegistry(cell => {{
+                    if (cell.ptr !== 0) wasm.{}(cell.ptr);
+                }})
+                : null;
+            ",
+            finalization,
+            shared::free_function(&name),
+        ));
         dst.push_str(&format!(
             "
+            get ptr() {{
+                const cell = this[ptrCell];
+                return cell === undefined ? 0 : cell.ptr;
+            }}
+
+            set ptr(ptr) {{
+                let cell = this[ptrCell];
+                if (cell === undefined) {{
+                    cell = {{ ptr: 0 }};
+                    this[ptrCell] = cell;
+                    if ({0} !== null) {0}.register(this, cell);
+                }}
+                cell.ptr = ptr;
+            }}
+
             free() {{
                 const ptr = this.ptr;
                 this.ptr = 0;
-                wasm.{}(ptr);
+                wasm.{1}(ptr);
             }}
             ",
+            finalization,
             shared::free_function(&name)
         ));
         ts_dst.push_str("free(): void;\n");
@@ -618,6 +1403,22 @@ impl<'a> Context<'a> {
         self.export(&name, &dst, Some(class.comments.clone()));
         self.typescript.push_str(&ts_dst);
 
+        if descriptors.len() > 0 {
+            self.global(&descriptors);
+        }
+
+        if let Some(ref element) = class.custom_element {
+            self.global(&format!(
+                "
+                if (typeof customElements !== 'undefined') {{
+                    customElements.define({:?}, {});
+                }}
+                ",
+                element,
+                name,
+            ));
+        }
+
         Ok(())
     }
 
@@ -719,6 +1520,119 @@ impl<'a> Context<'a> {
         math_imports
     }
 
+    /// Turns the memory and function table defined by the wasm module into
+    /// imports, returning the JS which builds the imports object passed to
+    /// the module by `init`.
+    ///
+    /// A memory imported from `env` through the linker's `--import-memory` is
+    /// renamed to be imported from the glue too. Either way, `init` uses the
+    /// memory and table in its options, creating them if they're missing.
+    fn import_memory(&mut self, module_name: &str) -> String {
+        let glue = format!("./{}", module_name);
+        let mut memory = None;
+        let mut table = None;
+        let mut imported = Vec::new();
+        for section in self.module.sections_mut() {
+            match *section {
+                Section::Memory(ref mut s) if s.entries().len() > 0 => {
+                    let ty = s.entries_mut().remove(0);
+                    memory = Some(ty.limits().clone());
+                    imported.push(ImportEntry::new(
+                        glue.clone(),
+                        "__wbindgen_memory".to_string(),
+                        External::Memory(ty),
+                    ));
+                }
+                Section::Table(ref mut s) if s.entries().len() > 0 => {
+                    // The linker gives the table a maximum of exactly its
+                    // size, which a table shared with other modules can't
+                    // have, and nothing here grows the table anyway.
+                    let ty = s.entries_mut().remove(0);
+                    let ty = TableType::new(ty.limits().initial(), None);
+                    table = Some(ty.limits().clone());
+                    imported.push(ImportEntry::new(
+                        glue.clone(),
+                        "__wbindgen_function_table".to_string(),
+                        External::Table(ty),
+                    ));
+                }
+                Section::Import(ref mut s) => {
+                    for import in s.entries_mut() {
+                        if import.module() != "env" || import.field() != "memory" {
+                            continue;
+                        }
+                        if let External::Memory(ref ty) = *import.external() {
+                            memory = Some(ty.limits().clone());
+                        }
+                        *import.module_mut() = glue.clone();
+                        *import.field_mut() = "__wbindgen_memory".to_string();
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.module.sections_mut().retain(|s| match *s {
+            Section::Memory(ref s) => s.entries().len() > 0,
+            Section::Table(ref s) => s.entries().len() > 0,
+            _ => true,
+        });
+
+        // Imported memories and tables come first in their index spaces, so
+        // the ones defined by the module are still index 0 once they're
+        // imported.
+        if imported.len() > 0 {
+            let has_imports = self.module.sections().iter().any(|s| match *s {
+                Section::Import(_) => true,
+                _ => false,
+            });
+            if !has_imports {
+                let pos = self.module.sections().iter().position(|s| match *s {
+                    Section::Custom(_) | Section::Unparsed { .. } | Section::Type(_) => false,
+                    _ => true,
+                }).unwrap_or(self.module.sections().len());
+                self.module
+                    .sections_mut()
+                    .insert(pos, Section::Import(ImportSection::with_entries(Vec::new())));
+            }
+            for section in self.module.sections_mut() {
+                if let Section::Import(ref mut s) = *section {
+                    s.entries_mut().extend(imported.drain(..));
+                    break;
+                }
+            }
+        }
+
+        // The glue reads the memory through `wasm.memory`, which the linker
+        // doesn't export when it imports memory itself.
+        for section in self.module.sections_mut() {
+            if let Section::Export(ref mut s) = *section {
+                if !s.entries().iter().any(|e| e.field() == "memory") {
+                    let entry = ExportEntry::new("memory".to_string(), Internal::Memory(0));
+                    s.entries_mut().push(entry);
+                }
+                break;
+            }
+        }
+
+        let mut js = String::from("const env = Object.assign({}, __exports);\n");
+        if let Some(limits) = memory {
+            js.push_str(&format!(
+                "env.__wbindgen_memory = options && options.memory || \
+                 new WebAssembly.Memory({{ {} }});\n",
+                limits_to_js(&limits),
+            ));
+        }
+        if let Some(limits) = table {
+            js.push_str(&format!(
+                "env.__wbindgen_function_table = options && options.table || \
+                 new WebAssembly.Table({{ {}, element: 'anyfunc' }});\n",
+                limits_to_js(&limits),
+            ));
+        }
+        js.push_str(&format!("const imports = {{ '{}': env }};", glue));
+        js
+    }
+
     fn unexport_unused_internal_exports(&mut self) {
         let required = &self.required_internal_exports;
         for section in self.module.sections_mut() {
@@ -803,6 +1717,193 @@ impl<'a> Context<'a> {
         }
     }
 
+    fn expose_poison(&mut self) {
+        if !self.exposed_globals.insert("poison") {
+            return;
+        }
+        self.global(
+            "
+            let wasmPoisoned = false;
+
+            function assertNotPoisoned() {
+                if (wasmPoisoned) {
+                    throw new Error('the wasm module has panicked and can no longer be used');
+                }
+            }
+
+            function poisonOnTrap(e) {
+                if (e instanceof WebAssembly.RuntimeError) wasmPoisoned = true;
+                return e;
+            }
+            ",
+        );
+    }
+
+    /// Returns the name of a table of the string values of an enum's variants,
+    /// indexed by the values Rust uses for them.
+    fn expose_string_enum(&mut self, variants: &[String]) -> String {
+        if let Some(name) = self.string_enums.get(variants) {
+            return name.clone();
+        }
+        let name = format!("stringEnum{}", self.string_enums.len());
+        let strings = variants
+            .iter()
+            .map(|v| serde_json::to_string(v).unwrap())
+            .collect::<Vec<_>>();
+        self.global(&format!("const {} = [{}];", name, strings.join(", ")));
+        self.string_enums.insert(variants.to_vec(), name.clone());
+        name
+    }
+
+    fn expose_command_strings(&mut self) {
+        if !self.exposed_globals.insert("command_strings") {
+            return;
+        }
+        self.global("const commandStrings = new Map();");
+    }
+
+    fn expose_profile(&mut self) {
+        if !self.exposed_globals.insert("profile") {
+            return;
+        }
+        self.global(
+            "
+            const profileNow = typeof(performance) === 'object' && typeof(performance.now) === 'function'
+                ? () => performance.now()
+                : () => Date.now();
+
+            let profileStats = {};
+
+            function recordProfile(name, start) {
+                const elapsed = profileNow() - start;
+                let stats = profileStats[name];
+                if (stats === undefined) {
+                    stats = profileStats[name] = { name, calls: 0, totalMs: 0, maxMs: 0 };
+                }
+                stats.calls += 1;
+                stats.totalMs += elapsed;
+                if (elapsed > stats.maxMs) stats.maxMs = elapsed;
+            }
+            ",
+        );
+        self.export(
+            "__wbindgen_profile_report",
+            "
+            function(reset) {
+                const report = Object.keys(profileStats)
+                    .map(name => Object.assign({}, profileStats[name]))
+                    .sort((a, b) => b.totalMs - a.totalMs);
+                if (reset) profileStats = {};
+                return report;
+            }
+            ",
+            Some(format_doc_comments(
+                &vec![
+                    String::from(" Returns the number of calls and time spent in each function"),
+                    String::from(" crossing the JS/wasm boundary, most expensive first. Passing"),
+                    String::from(" `true` also clears the collected stats."),
+                ],
+                None,
+            )),
+        );
+        self.typescript.push_str(
+            "export function __wbindgen_profile_report(reset?: boolean): \
+             { name: string, calls: number, totalMs: number, maxMs: number }[];\n",
+        );
+    }
+
+    fn expose_error_hook(&mut self) {
+        if !self.exposed_globals.insert("error_hook") {
+            return;
+        }
+        self.global(
+            "
+            let wasmErrorHook = null;
+            const reportedErrors = typeof(WeakSet) === 'function' ? new WeakSet() : null;
+            ",
+        );
+        let args = if self.config.debug {
+            self.global(
+                "
+                function summarizeArg(arg) {
+                    const ty = typeof(arg);
+                    if (ty === 'string') {
+                        return JSON.stringify(arg.length > 32 ? arg.slice(0, 32) + '...' : arg);
+                    }
+                    if (ty === 'function') return 'function';
+                    if (ty !== 'object' || arg === null) return String(arg);
+                    return (arg.constructor && arg.constructor.name) || 'object';
+                }
+                ",
+            );
+            "context.args = Array.prototype.map.call(args, summarizeArg);\n"
+        } else {
+            ""
+        };
+        // An error thrown from a nested export propagates through each of the
+        // enclosing ones as well, but is only reported the first time.
+        self.global(&format!(
+            "
+            function reportWasmError(e, name, args) {{
+                if (wasmErrorHook === null) return;
+                if (reportedErrors !== null && typeof(e) === 'object' && e !== null) {{
+                    if (reportedErrors.has(e)) return;
+                    reportedErrors.add(e);
+                }}
+                const context = {{ name }};
+                {}
+                try {{
+                    wasmErrorHook(e, context);
+                }} catch (_) {{}}
+            }}
+            ",
+            args,
+        ));
+        self.export(
+            "setWasmErrorHook",
+            "
+            function(hook) {
+                wasmErrorHook = typeof(hook) === 'function' ? hook : null;
+            }
+            ",
+            Some(format_doc_comments(
+                &vec![
+                    String::from(" Installs `hook` to be called with every error thrown out of wasm,"),
+                    String::from(" along with the name of the function it was thrown from. Passing"),
+                    String::from(" anything other than a function removes the hook."),
+                ],
+                None,
+            )),
+        );
+        self.typescript.push_str(
+            "export function setWasmErrorHook(\
+             hook: ((error: any, context: { name: string, args?: string[] }) => void) | null\
+             ): void;\n",
+        );
+    }
+
+    fn expose_wasm_panic_error(&mut self) {
+        if !self.exposed_globals.insert("wasm_panic_error") {
+            return;
+        }
+        self.export(
+            "WasmPanicError",
+            "
+            class WasmPanicError extends Error {
+                constructor(message) {
+                    super(message);
+                    this.name = 'WasmPanicError';
+                }
+            }
+            ",
+            Some(format_doc_comments(
+                &vec![String::from(" Thrown when an exported Rust function panics.")],
+                None,
+            )),
+        );
+        self.typescript.push_str("export class WasmPanicError extends Error {}\n");
+    }
+
     fn expose_global_slab(&mut self) {
         if !self.exposed_globals.insert("slab") {
             return;
@@ -827,7 +1928,44 @@ impl<'a> Context<'a> {
                 ),
                 None,
             );
+            self.expose_heap_dump();
+            self.export(
+                "__wbindgen_heap_dump",
+                "function() { return heapDump(); }",
+                Some(format_doc_comments(
+                    &vec![
+                        String::from(" Lists the JS values currently owned by Rust, along with the"),
+                        String::from(" stack trace of where each of them was created."),
+                    ],
+                    None,
+                )),
+            );
+            self.typescript.push_str(
+                "export function __wbindgen_heap_dump(): \
+                 { index: number, value: any, refs: number, stack: string }[];\n",
+            );
+        }
+    }
+
+    fn expose_heap_dump(&mut self) {
+        if !self.exposed_globals.insert("heap_dump") {
+            return;
         }
+        self.expose_global_slab();
+        self.global(&format!(
+            "
+            function heapDump() {{
+                const live = [];
+                for (let i = {}; i < slab.length; i++) {{
+                    const val = slab[i];
+                    if (typeof(val) === 'number') continue;
+                    live.push({{ index: i << 1, value: val.obj, refs: val.cnt, stack: val.stack }});
+                }}
+                return live;
+            }}
+            ",
+            INITIAL_SLAB_VALUES.len(),
+        ));
     }
 
     fn expose_global_slab_next(&mut self) {
@@ -891,6 +2029,25 @@ impl<'a> Context<'a> {
         ));
     }
 
+    /// Exposes `charCodePoint`, which converts a string passed as a `char` to
+    /// its code point, throwing if it isn't a single character.
+    fn expose_char_code_point(&mut self) {
+        if !self.exposed_globals.insert("char_code_point") {
+            return;
+        }
+        self.global(
+            "
+            function charCodePoint(s) {
+                const c = typeof(s) === 'string' ? s.codePointAt(0) : undefined;
+                if (c === undefined || s.length !== (c > 0xFFFF ? 2 : 1)) {
+                    throw new Error('expected a string of a single character for a char');
+                }
+                return c;
+            }
+            ",
+        );
+    }
+
     fn expose_assert_bool(&mut self) {
         if !self.exposed_globals.insert("assert_bool") {
             return;
@@ -986,6 +2143,72 @@ impl<'a> Context<'a> {
         Ok(())
     }
 
+    /// Exposes the functions converting between the plain objects or `Map`s
+    /// which JS sees and the arrays of keys and values which maps cross the
+    /// boundary as. Values are checked against `typeof` if a type is given.
+    fn expose_map_entries(&mut self, js_map: bool) {
+        if js_map {
+            if !self.exposed_globals.insert("map_entries") {
+                return;
+            }
+            self.global("
+                function mapToEntries(map, ty) {
+                    const entries = [];
+                    for (const [key, value] of map) {
+                        if (typeof(key) !== 'string') {
+                            throw new Error('map keys must be strings');
+                        }
+                        if (ty !== undefined && typeof(value) !== ty) {
+                            throw new Error(`expected the values of the map to be of type ${ty}`);
+                        }
+                        entries.push(key, value);
+                    }
+                    return entries;
+                }
+
+                function entriesToMap(entries) {
+                    const map = new Map();
+                    for (let i = 0; i < entries.length; i += 2) {
+                        map.set(entries[i], entries[i + 1]);
+                    }
+                    return map;
+                }
+            ");
+        } else {
+            if !self.exposed_globals.insert("object_entries") {
+                return;
+            }
+            // Properties are defined rather than assigned so keys like
+            // `__proto__` are read back as they were in Rust.
+            self.global("
+                function objectToEntries(obj, ty) {
+                    const entries = [];
+                    for (const key of Object.keys(obj)) {
+                        const value = obj[key];
+                        if (ty !== undefined && typeof(value) !== ty) {
+                            throw new Error(`expected the values of the object to be of type ${ty}`);
+                        }
+                        entries.push(key, value);
+                    }
+                    return entries;
+                }
+
+                function entriesToObject(entries) {
+                    const obj = {};
+                    for (let i = 0; i < entries.length; i += 2) {
+                        Object.defineProperty(obj, entries[i], {
+                            value: entries[i + 1],
+                            writable: true,
+                            enumerable: true,
+                            configurable: true,
+                        });
+                    }
+                    return obj;
+                }
+            ");
+        }
+    }
+
     fn pass_array_to_wasm(
         &mut self,
         name: &'static str,
@@ -996,15 +2219,24 @@ impl<'a> Context<'a> {
             return Ok(());
         }
         self.require_internal_export("__wbindgen_malloc")?;
+        // Views of 64-bit integers throw if they aren't supported, which has
+        // to happen before anything is allocated so that it isn't leaked.
+        let check = if delegate == "getInt64Memory" || delegate == "getUint64Memory" {
+            format!("{}();\n", delegate)
+        } else {
+            String::new()
+        };
         self.global(&format!(
             "
             function {}(arg) {{
+                {}\
                 const ptr = wasm.__wbindgen_malloc(arg.length * {size});
                 {}().set(arg, ptr / {size});
                 return [ptr, arg.length];
             }}
             ",
             name,
+            check,
             delegate,
             size = size
         ));
@@ -1069,22 +2301,97 @@ impl<'a> Context<'a> {
         );
     }
 
+    fn expose_options_interface(&mut self, name: &str, fields: &[String]) {
+        if !self.options_interfaces.insert(name.to_string()) {
+            return;
+        }
+        self.typescript.push_str(&format!("export interface {} {{\n", name));
+        for field in fields {
+            self.typescript.push_str(field);
+            self.typescript.push_str("\n");
+        }
+        self.typescript.push_str("}\n");
+    }
+
     fn expose_constructor_token(&mut self) {
         if !self.exposed_globals.insert("ConstructorToken") {
             return;
         }
-
+
+        self.global(
+            "
+            class ConstructorToken {
+                constructor(ptr) {
+                    this.ptr = ptr;
+                }
+            }
+            ",
+        );
+    }
+
+    fn expose_is_index_key(&mut self) {
+        if !self.exposed_globals.insert("is_index_key") {
+            return;
+        }
         self.global(
             "
-            class ConstructorToken {
-                constructor(ptr) {
-                    this.ptr = ptr;
-                }
+            function isIndexKey(key) {
+                return typeof(key) === 'string' && /^(0|[1-9][0-9]*)$/.test(key);
             }
             ",
         );
     }
 
+    /// Defines the `Proxy` handler for instances of `name`, whose traps call
+    /// the class's indexing methods for keys which are array indices and
+    /// otherwise behave as if there were no proxy.
+    fn expose_indexing_handler(&mut self, name: &str, class: &ExportedClass) {
+        self.expose_is_index_key();
+        let mut traps = String::new();
+        if let Some(ref getter) = class.indexing_getter {
+            traps.push_str(&format!(
+                "
+                get(target, key, receiver) {{
+                    if (isIndexKey(key)) {{
+                        return target.{}(Number(key));
+                    }}
+                    return Reflect.get(target, key, receiver);
+                }},
+                ",
+                getter,
+            ));
+        }
+        if let Some(ref setter) = class.indexing_setter {
+            traps.push_str(&format!(
+                "
+                set(target, key, value, receiver) {{
+                    if (isIndexKey(key)) {{
+                        target.{}(Number(key), value);
+                        return true;
+                    }}
+                    return Reflect.set(target, key, value, receiver);
+                }},
+                ",
+                setter,
+            ));
+        }
+        if let Some(ref deleter) = class.indexing_deleter {
+            traps.push_str(&format!(
+                "
+                deleteProperty(target, key) {{
+                    if (isIndexKey(key)) {{
+                        target.{}(Number(key));
+                        return true;
+                    }}
+                    return Reflect.deleteProperty(target, key);
+                }},
+                ",
+                deleter,
+            ));
+        }
+        self.global(&format!("const {}IndexingHandler = {{{}}};", name, traps));
+    }
+
     fn expose_get_string_from_wasm(&mut self) {
         if !self.exposed_globals.insert("get_string_from_wasm") {
             return;
@@ -1121,6 +2428,30 @@ impl<'a> Context<'a> {
         );
     }
 
+    /// Like `getArrayJsValueFromWasm`, but for a borrowed slice, whose values
+    /// are still owned by Rust.
+    fn expose_get_array_js_value_ref_from_wasm(&mut self) -> &'static str {
+        if !self.exposed_globals.insert("get_array_js_value_ref_from_wasm") {
+            return "getArrayJsValueRefFromWasm";
+        }
+        self.expose_uint32_memory();
+        self.expose_get_object();
+        self.global(
+            "
+            function getArrayJsValueRefFromWasm(ptr, len) {
+                const mem = getUint32Memory();
+                const slice = mem.subarray(ptr / 4, ptr / 4 + len);
+                const result = [];
+                for (let i = 0; i < slice.length; i++) {
+                    result.push(getObject(slice[i]));
+                }
+                return result;
+            }
+            ",
+        );
+        "getArrayJsValueRefFromWasm"
+    }
+
     fn expose_get_array_i8_from_wasm(&mut self) {
         self.expose_int8_memory();
         self.arrayget("getArrayI8FromWasm", "getInt8Memory", 1);
@@ -1284,10 +2615,26 @@ impl<'a> Context<'a> {
         if !self.exposed_globals.insert(name) {
             return;
         }
+        // Only slices of 64-bit integers use these, which can't be passed at
+        // all without them.
+        let check = if js.starts_with("Big") {
+            self.expose_supports_bigint64();
+            format!(
+                "
+                if (!supportsBigInt64) {{
+                    throw new Error('slices of 64-bit integers can only be passed where `{}` is supported');
+                }}
+                ",
+                js,
+            )
+        } else {
+            String::new()
+        };
         self.global(&format!(
             "
             let cache{name} = null;
             function {name}() {{
+                {check}\
                 if (cache{name} === null || cache{name}.buffer !== wasm.memory.buffer) {{
                     cache{name} = new {js}(wasm.memory.buffer);
                 }}
@@ -1296,6 +2643,7 @@ impl<'a> Context<'a> {
             ",
             name = name,
             js = js,
+            check = check,
         ));
     }
 
@@ -1328,6 +2676,30 @@ impl<'a> Context<'a> {
             }
             ",
         );
+        if self.config.debug {
+            self.global(
+                "
+                function restoreStack(depth, pushed) {
+                    const len = stack.length;
+                    if (len > depth) stack.length = depth;
+                    if (len < depth || len > depth + pushed) {
+                        throw new Error(
+                            'stack of borrowed objects is unbalanced, expected a depth ' +
+                            `between ${depth} and ${depth + pushed} but found ${len}`
+                        );
+                    }
+                }
+                ",
+            );
+        } else {
+            self.global(
+                "
+                function restoreStack(depth) {
+                    if (stack.length > depth) stack.length = depth;
+                }
+                ",
+            );
+        }
     }
 
     fn expose_take_object(&mut self) {
@@ -1367,6 +2739,13 @@ impl<'a> Context<'a> {
                 ",
             )
         };
+        // Remembering where each value was created makes it possible to track
+        // down leaks with `__wbindgen_heap_dump`.
+        let entry = if self.config.debug {
+            "{ obj, cnt: 1, stack: new Error().stack }"
+        } else {
+            "{ obj, cnt: 1 }"
+        };
         self.global(&format!(
             "
             function addHeapObject(obj) {{
@@ -1374,11 +2753,12 @@ impl<'a> Context<'a> {
                 const idx = slab_next;
                 const next = slab[idx];
                 {}
-                slab[idx] = {{ obj, cnt: 1 }};
+                slab[idx] = {};
                 return idx << 1;
             }}
             ",
-            set_slab_next
+            set_slab_next,
+            entry,
         ));
     }
 
@@ -1563,8 +2943,9 @@ impl<'a> Context<'a> {
             return name;
         }
         let n = self.expose_u32_cvt_shim();
+        self.expose_supports_bigint64();
         self.global(&format!(
-            "const {} = new BigInt64Array({}.buffer);",
+            "const {} = supportsBigInt64 ? new BigInt64Array({}.buffer) : null;",
             name, n
         ));
         name
@@ -1576,13 +2957,187 @@ impl<'a> Context<'a> {
             return name;
         }
         let n = self.expose_u32_cvt_shim();
+        self.expose_supports_bigint64();
         self.global(&format!(
-            "const {} = new BigUint64Array({}.buffer);",
+            "const {} = supportsBigInt64 ? new BigUint64Array({}.buffer) : null;",
             name, n
         ));
         name
     }
 
+    fn expose_supports_bigint64(&mut self) {
+        if !self.exposed_globals.insert("supports_bigint64") {
+            return;
+        }
+        self.global(
+            "
+            const supportsBigInt64 = typeof BigInt64Array === 'function';
+            ",
+        );
+    }
+
+    /// Exposes the key which exported classes keep the cell holding their
+    /// pointer under, which is a symbol so that it isn't enumerable.
+    fn expose_ptr_cell(&mut self) {
+        if !self.exposed_globals.insert("ptr_cell") {
+            return;
+        }
+        self.global("const ptrCell = Symbol('ptr');");
+    }
+
+    fn expose_supports_weak_ref(&mut self) {
+        if !self.exposed_globals.insert("supports_weak_ref") {
+            return;
+        }
+        self.global(
+            "
+            const supportsWeakRef = typeof WeakRef === 'function' &&
+                typeof FinalizationRegistry === 'function';
+            ",
+        );
+    }
+
+    /// Exposes `splitInt64`, which splits a 64-bit integer into its low and
+    /// high 32 bits, returned in `u32CvtShim`.
+    ///
+    /// 64-bit integers are `BigInt`s where `BigInt64Array` is supported.
+    /// Otherwise they're numbers, which have to be safe integers, or with
+    /// `--u64-pairs` they may also be `{ lo, hi }` objects.
+    fn expose_split_int64(&mut self) {
+        if !self.exposed_globals.insert("split_int64") {
+            return;
+        }
+        self.expose_int64_cvt_shim();
+        let pairs = if self.config.u64_pairs {
+            "
+                    if (typeof n === 'object' && n !== null) {
+                        u32CvtShim[0] = n.lo;
+                        u32CvtShim[1] = n.hi;
+                        return u32CvtShim;
+                    }
+            "
+        } else {
+            ""
+        };
+        self.global(&format!(
+            "
+            function splitInt64(n) {{
+                if (supportsBigInt64) {{
+                    int64CvtShim[0] = n;
+                }} else {{
+                    {}
+                    if (typeof n !== 'number') n = Number(n);
+                    if (!Number.isSafeInteger(n)) {{
+                        throw new RangeError(`${{n}} can't be passed as a 64-bit integer without BigInt`);
+                    }}
+                    const high = Math.floor(n / 4294967296);
+                    u32CvtShim[0] = n - high * 4294967296;
+                    u32CvtShim[1] = high;
+                }}
+                return u32CvtShim;
+            }}
+            ",
+            pairs,
+        ));
+    }
+
+    /// Exposes `joinInt64`, the reverse of `splitInt64`.
+    fn expose_join_int64(&mut self) {
+        if !self.exposed_globals.insert("join_int64") {
+            return;
+        }
+        self.expose_int64_cvt_shim();
+        self.expose_uint64_cvt_shim();
+        let fallback = if self.config.u64_pairs {
+            "return { lo: low >>> 0, hi: signed ? high | 0 : high >>> 0 };"
+        } else {
+            "
+                const n = (signed ? high | 0 : high >>> 0) * 4294967296 + (low >>> 0);
+                if (!Number.isSafeInteger(n)) {
+                    throw new RangeError(`64-bit integer ${n} can't be represented without BigInt`);
+                }
+                return n;
+            "
+        };
+        self.global(&format!(
+            "
+            function joinInt64(low, high, signed) {{
+                if (supportsBigInt64) {{
+                    u32CvtShim[0] = low;
+                    u32CvtShim[1] = high;
+                    return signed ? int64CvtShim[0] : uint64CvtShim[0];
+                }}
+                {}
+            }}
+            ",
+            fallback,
+        ));
+    }
+
+    /// Exposes `splitInt128`, which splits a 128-bit integer into an array of
+    /// its four 32-bit limbs, starting from the lowest.
+    ///
+    /// Like 64-bit integers they're `BigInt`s where `BigInt64Array` is
+    /// supported, and otherwise numbers which have to be safe integers.
+    fn expose_split_int128(&mut self) {
+        if !self.exposed_globals.insert("split_int128") {
+            return;
+        }
+        self.expose_supports_bigint64();
+        self.global(
+            "
+            function splitInt128(n) {
+                if (supportsBigInt64) {
+                    n = BigInt.asUintN(128, n);
+                    const mask = BigInt(4294967295);
+                    const shift = BigInt(32);
+                    const limbs = [];
+                    for (let i = 0; i < 4; i++) {
+                        limbs.push(Number(n & mask));
+                        n >>= shift;
+                    }
+                    return limbs;
+                }
+                if (typeof n !== 'number') n = Number(n);
+                if (!Number.isSafeInteger(n)) {
+                    throw new RangeError(`${n} can't be passed as a 128-bit integer without BigInt`);
+                }
+                const high = Math.floor(n / 4294967296);
+                const ext = n < 0 ? 4294967295 : 0;
+                return [n - high * 4294967296, high >>> 0, ext, ext];
+            }
+            ",
+        );
+    }
+
+    /// Exposes `joinInt128`, the reverse of `splitInt128`.
+    fn expose_join_int128(&mut self) {
+        if !self.exposed_globals.insert("join_int128") {
+            return;
+        }
+        self.expose_supports_bigint64();
+        self.global(
+            "
+            function joinInt128(low, midLow, midHigh, high, signed) {
+                if (supportsBigInt64) {
+                    const shift = BigInt(32);
+                    let n = BigInt(high >>> 0);
+                    n = (n << shift) | BigInt(midHigh >>> 0);
+                    n = (n << shift) | BigInt(midLow >>> 0);
+                    n = (n << shift) | BigInt(low >>> 0);
+                    return signed ? BigInt.asIntN(128, n) : n;
+                }
+                const ext = signed && (midLow & 0x80000000) ? 4294967295 : 0;
+                const n = (signed ? midLow | 0 : midLow >>> 0) * 4294967296 + (low >>> 0);
+                if ((midHigh >>> 0) !== ext || (high >>> 0) !== ext || !Number.isSafeInteger(n)) {
+                    throw new RangeError(`128-bit integer can't be represented without BigInt`);
+                }
+                return n;
+            }
+            ",
+        );
+    }
+
     fn expose_is_like_none(&mut self) {
         if !self.exposed_globals.insert("is_like_none") {
             return
@@ -1629,6 +3184,10 @@ impl<'a> Context<'a> {
     fn use_node_require(&self) -> bool {
         self.config.nodejs && !self.config.nodejs_experimental_modules
     }
+
+    fn use_no_modules_wrapper(&self) -> bool {
+        self.config.no_modules || self.config.universal
+    }
 }
 
 impl<'a, 'b> SubContext<'a, 'b> {
@@ -1647,6 +3206,9 @@ impl<'a, 'b> SubContext<'a, 'b> {
         for e in self.program.enums.iter() {
             self.generate_enum(e);
         }
+        for c in self.program.consts.iter() {
+            self.generate_const(c);
+        }
         for s in self.program.structs.iter() {
             let mut class = self
                 .cx
@@ -1654,9 +3216,20 @@ impl<'a, 'b> SubContext<'a, 'b> {
                 .entry(s.name.clone())
                 .or_insert_with(Default::default);
             class.comments = format_doc_comments(&s.comments, None);
+            class.extends = s.extends.clone();
+            class.extends_rust = s.extends_rust;
+            class.async_iterator = s.async_iterator;
+            class.iterator = s.iterator;
+            class.shared_class = s.shared_class.clone();
+            if let Some(ref element) = s.custom_element {
+                class.custom_element = Some(element.name.clone());
+                class.observed_attributes = element.observed_attributes.clone();
+            }
             class.fields.extend(s.fields.iter().map(|f| ClassField {
                 name: f.name.clone(),
                 readonly: f.readonly,
+                enumerable: f.enumerable,
+                configurable: f.configurable,
                 comments: f.comments.clone(),
             }));
         }
@@ -1670,11 +3243,17 @@ impl<'a, 'b> SubContext<'a, 'b> {
         }
 
         let descriptor = match self.cx.describe(&export.function.name) {
-            None => return Ok(()),
+            None => {
+                self.cx.skip_undescribed(&export.function.name);
+                return Ok(());
+            }
             Some(d) => d,
         };
 
         let (js, ts, js_doc) = Js2Rust::new(&export.function.name, self.cx)
+            .optional_args(export.optional_args)
+            .js_map(export.js_map)
+            .defaults(&export.defaults)
             .process(descriptor.unwrap_function())?
             .finish("function", &format!("wasm.{}", export.function.name));
         self.cx.export(
@@ -1686,6 +3265,10 @@ impl<'a, 'b> SubContext<'a, 'b> {
         self.cx.typescript.push_str("export ");
         self.cx.typescript.push_str(&ts);
         self.cx.typescript.push_str("\n");
+        self.cx
+            .interface
+            .functions
+            .insert(export.function.name.clone(), ts.trim().to_string());
         Ok(())
     }
 
@@ -1697,46 +3280,202 @@ impl<'a, 'b> SubContext<'a, 'b> {
         let wasm_name = shared::struct_function_export_name(class_name, &export.function.name);
 
         let descriptor = match self.cx.describe(&wasm_name) {
-            None => return Ok(()),
+            None => {
+                let item = format!("{}.{}", class_name, export.function.name);
+                self.cx.skip_undescribed(&item);
+                return Ok(());
+            }
             Some(d) => d,
         };
 
-        let (js, ts, js_doc) = Js2Rust::new(&export.function.name, self.cx)
-            .method(export.method, export.consumed)
-            .process(descriptor.unwrap_function())?
-            .finish("", &format!("wasm.{}", wasm_name));
+        // Subclasses have a different layout in Rust, so they can't be
+        // consumed by the methods of their parent.
+        let guard_subclass = export.consumed && self.cx.subclassed.contains(class_name);
+
+        // Methods keyed by a well-known symbol use a computed name, both in the
+        // class body and in the TypeScript.
+        let method_name = match export.symbol {
+            Some(ref symbol) => format!("[Symbol.{}]", symbol),
+            None => export.function.name.clone(),
+        };
+
+        let (js, ts, js_doc, ts_args, property_ty) = {
+            let mut cx = Js2Rust::new(&method_name, self.cx);
+            if guard_subclass {
+                cx.prelude(&format!(
+                    "\
+                     if (this.constructor !== {0}) {{
+                         throw new Error('cannot consume a subclass of `{0}`');
+                     }}
+                     ",
+                    class_name,
+                ));
+            }
+            cx.method(export.method, export.consumed)
+                .optional_args(export.optional_args)
+                .js_map(export.js_map)
+                .defaults(&export.defaults)
+                .process(descriptor.unwrap_function())?;
+            let (js, ts, js_doc) = cx.finish("", &format!("wasm.{}", wasm_name));
+            let property_ty = match (&export.setter, export.indexing) {
+                (Some(_), _) => cx.js_arguments[0].1.clone(),
+                (_, Some(shared::IndexingKind::Setter)) => cx.js_arguments[1].1.clone(),
+                _ => cx.ret_ty.clone(),
+            };
+            (js, ts, js_doc, cx.ts_arguments(), property_ty)
+        };
+
+        // Getters and setters are named after the property they define rather
+        // than the Rust method.
+        let (name, prefix) = match (&export.getter, &export.setter) {
+            (Some(name), _) => (name, "get "),
+            (_, Some(name)) => (name, "set "),
+            _ => (&export.function.name, ""),
+        };
 
         let class = self
             .cx
             .exported_classes
             .entry(class_name.to_string())
             .or_insert(ExportedClass::default());
+        if export.function.name == shared::ITERATOR_NEXT {
+            // This is only called by the class's own `next` method, so it's
+            // left out of the TypeScript and the interface.
+            class.iterator_item_ty = Some(property_ty);
+            class.contents.push_str(&export.function.name);
+            class.contents.push_str(&js);
+            class.contents.push_str("\n");
+            return Ok(());
+        }
         class
             .contents
             .push_str(&format_doc_comments(&export.comments, Some(js_doc)));
-        if !export.method {
+        if let Some(kind) = export.indexing {
+            if !descriptor.unwrap_function().arguments[0].is_number() {
+                bail!(
+                    "the indexing method `{}` of `{}` must take a number as its index",
+                    export.function.name,
+                    class_name
+                );
+            }
+            let method = Some(export.function.name.clone());
+            match kind {
+                shared::IndexingKind::Getter => {
+                    class.indexing_getter = method;
+                    class.index_ty = Some(property_ty.clone());
+                }
+                shared::IndexingKind::Setter => {
+                    class.indexing_setter = method;
+                    if class.index_ty.is_none() {
+                        class.index_ty = Some(property_ty.clone());
+                    }
+                }
+                shared::IndexingKind::Deleter => class.indexing_deleter = method,
+            }
+        }
+        if !prefix.is_empty() {
+            // A getter and setter for the same property share its descriptor,
+            // so the flags are only applied once.
+            let seen = class.accessors.iter().any(|a| a.name == *name);
+            if (export.enumerable || !export.configurable) && !seen {
+                let target = format!("{}.prototype", class_name);
+                define_property(
+                    &mut class.descriptors,
+                    &target,
+                    name,
+                    export.enumerable,
+                    export.configurable,
+                );
+            }
+            let accessor = match class.accessors.iter().position(|a| a.name == *name) {
+                Some(i) => &mut class.accessors[i],
+                None => {
+                    class.accessors.push(ClassAccessor {
+                        name: name.clone(),
+                        ty: property_ty.clone(),
+                        readonly: true,
+                    });
+                    class.accessors.last_mut().unwrap()
+                }
+            };
+            if export.setter.is_some() {
+                accessor.readonly = false;
+            } else {
+                accessor.ty = property_ty;
+            }
+            let ts = accessor.typescript();
+            let previous = self
+                .cx
+                .interface
+                .classes
+                .entry(class_name.to_string())
+                .or_insert_with(Default::default)
+                .members
+                .insert(name.clone(), ts);
+            class.contents.push_str(prefix);
+            class.contents.push_str(name);
+            class.contents.push_str(&js);
+            class.contents.push_str("\n");
+            if previous.is_some() && !seen {
+                self.cx.warn_member_collision(class_name, name);
+            }
+            return Ok(());
+        }
+
+        if export.method {
+            class.methods.insert(method_name.clone());
+        } else {
             class.contents.push_str("static ");
             class.typescript.push_str("static ");
         }
+        if export.enumerable || !export.configurable {
+            let target = if export.method {
+                format!("{}.prototype", class_name)
+            } else {
+                class_name.to_string()
+            };
+            define_property(
+                &mut class.descriptors,
+                &target,
+                &method_name,
+                export.enumerable,
+                export.configurable,
+            );
+        }
 
-        let constructors: Vec<String> = self
+        // JS classes can only have one real constructor, so if multiple
+        // constructors are listed the first one is what `new` invokes and the
+        // rest are only available as static factory functions.
+        let constructor = self
             .program
             .exports
             .iter()
             .filter(|x| x.class == Some(class_name.to_string()))
             .filter_map(|x| x.constructor.clone())
-            .collect();
-
-        class.constructor = match constructors.len() {
-            0 => None,
-            1 => Some(constructors[0].clone()),
-            x @ _ => bail!("there must be only one constructor, not {}", x),
-        };
-        class.contents.push_str(&export.function.name);
+            .next();
+        let members = &mut self
+            .cx
+            .interface
+            .classes
+            .entry(class_name.to_string())
+            .or_insert_with(Default::default)
+            .members;
+        if constructor.is_some() && export.constructor == constructor {
+            let ts = format!("constructor({});", ts_args);
+            members.insert("constructor".to_string(), ts.clone());
+            class.constructor_typescript = Some(format!("{}\n", ts));
+        }
+        let static_ = if export.method { "" } else { "static " };
+        let previous = members.insert(method_name.clone(), format!("{}{}", static_, ts.trim()));
+        class.constructor = constructor;
+        class.contents.push_str(&method_name);
         class.contents.push_str(&js);
         class.contents.push_str("\n");
         class.typescript.push_str(&ts);
         class.typescript.push_str("\n");
+        if previous.is_some() {
+            self.cx.warn_member_collision(class_name, &export.function.name);
+        }
         Ok(())
     }
 
@@ -1805,11 +3544,17 @@ impl<'a, 'b> SubContext<'a, 'b> {
             Some(d) => d,
         };
 
+        // Whether `target` could be handed to wasm as the import itself, and if
+        // not why it can't.
+        let mut direct = Ok(());
         let target = match &import.method {
             Some(shared::MethodData { class, kind }) => {
                 let class = self.import_name(info, class)?;
                 match kind {
-                    shared::MethodKind::Constructor => format!("new {}", class),
+                    shared::MethodKind::Constructor => {
+                        direct = Err("it's a constructor, which is called with `new`");
+                        format!("new {}", class)
+                    }
                     shared::MethodKind::Operation(shared::Operation { is_static, kind }) => {
                         let target = if import.structural {
                             let location = if *is_static { &class } else { "this" };
@@ -1898,6 +3643,12 @@ impl<'a, 'b> SubContext<'a, 'b> {
                             }
                         };
 
+                        if import.structural {
+                            direct = Err("it's structural, so it's looked up on each call");
+                        } else if !*is_static {
+                            direct = Err("it's a method, which is called with `this`");
+                        }
+
                         let fallback = if import.structural {
                             "".to_string()
                         } else {
@@ -1934,15 +3685,36 @@ impl<'a, 'b> SubContext<'a, 'b> {
                     ));
                     format!("{}_target", import.shim)
                 } else {
+                    if info.module.is_none() {
+                        direct = Err("it's a global, which is looked up on each call");
+                    }
                     name
                 }
             }
         };
 
-        let js = Rust2Js::new(self.cx)
-            .catch(import.catch)
-            .process(descriptor.unwrap_function())?
-            .finish(&target);
+        let mut shim = Rust2Js::new(self.cx);
+        shim.catch(import.catch)
+            .optional_args(import.optional_args)
+            .variadic(import.variadic)
+            .process(descriptor.unwrap_function())?;
+        if import.assert_no_shim {
+            if let Some(reason) = shim.shim_required() {
+                direct = Err(reason);
+            }
+            if let Err(reason) = direct {
+                bail!(
+                    "`{}` is marked `assert_no_shim` but needs a JS shim because {}",
+                    import.function.name,
+                    reason
+                );
+            }
+            // Wasm calls the imported function itself, which is bound once
+            // when the JS glue is loaded.
+            self.cx.export(&import.shim, &target, None);
+            return Ok(());
+        }
+        let js = shim.finish(&target);
         self.cx.export(&import.shim, &js, None);
         Ok(())
     }
@@ -1952,11 +3724,29 @@ impl<'a, 'b> SubContext<'a, 'b> {
         info: &shared::Import,
         import: &shared::ImportType,
     ) -> Result<(), Error> {
+        // Exported classes name their parent by its Rust name, so make sure
+        // the JS class is in scope if one of them extends this type.
+        if self.cx.subclassed.contains(&import.rust_name) {
+            let name = self.import_name(info, &import.name)?;
+            self.cx.imported_parents.insert(import.rust_name.clone(), name);
+        }
         if !self.cx.wasm_import_needed(&import.instanceof_shim) {
             return Ok(());
         }
-        let name = self.import_name(info, &import.name)?;
         self.cx.expose_get_object();
+        if let Some(ref shared_class) = import.shared_class {
+            let body = format!("
+                    function(idx) {{
+                        const obj = getObject(idx);
+                        return obj != null && obj[Symbol.for('wasm-bindgen.shared_class')] === {:?} ? 1 : 0;
+                    }}
+                ",
+                shared_class,
+            );
+            self.cx.export(&import.instanceof_shim, &body, None);
+            return Ok(());
+        }
+        let name = self.import_name(info, &import.name)?;
         let body = format!("
                 function(idx) {{
                     return getObject(idx) instanceof {} ? 1 : 0;
@@ -1968,11 +3758,44 @@ impl<'a, 'b> SubContext<'a, 'b> {
         Ok(())
     }
 
+    fn generate_const(&mut self, const_: &shared::Const) {
+        // The value is always a literal, so its type can be read off it.
+        let ty = if const_.value.starts_with('"') {
+            "string"
+        } else if const_.value == "true" || const_.value == "false" {
+            "boolean"
+        } else {
+            "number"
+        };
+        self.cx.export(
+            &const_.name,
+            &const_.value,
+            Some(format_doc_comments(&const_.comments, None)),
+        );
+        self.cx
+            .typescript
+            .push_str(&format!("export const {}: {};\n", const_.name, ty));
+        self.cx
+            .interface
+            .consts
+            .insert(const_.name.clone(), ty.to_string());
+    }
+
     fn generate_enum(&mut self, enum_: &shared::Enum) {
+        // String enums are represented by their strings, and everything else
+        // by its discriminant.
+        let values = enum_
+            .variants
+            .iter()
+            .map(|v| match v.string_value {
+                Some(ref s) => serde_json::to_string(s).unwrap(),
+                None => v.value.to_string(),
+            })
+            .collect::<Vec<_>>();
         let mut variants = String::new();
 
-        for variant in enum_.variants.iter() {
-            variants.push_str(&format!("{}:{},", variant.name, variant.value));
+        for (variant, value) in enum_.variants.iter().zip(&values) {
+            variants.push_str(&format!("{}:{},", variant.name, value));
         }
         self.cx.export(
             &enum_.name,
@@ -1984,18 +3807,33 @@ impl<'a, 'b> SubContext<'a, 'b> {
             .push_str(&format!("export enum {} {{", enum_.name));
 
         variants.clear();
-        for variant in enum_.variants.iter() {
-            variants.push_str(&format!("{},", variant.name));
+        for (variant, value) in enum_.variants.iter().zip(&values) {
+            variants.push_str(&format!("{} = {},", variant.name, value));
         }
         self.cx.typescript.push_str(&variants);
         self.cx.typescript.push_str("}\n");
+        self.cx.interface.enums.insert(
+            enum_.name.clone(),
+            enum_
+                .variants
+                .iter()
+                .zip(values)
+                .map(|(v, value)| (v.name.clone(), value))
+                .collect(),
+        );
     }
 
     fn import_name(&mut self, import: &shared::Import, item: &str) -> Result<String, Error> {
         // First up, imports don't work at all in `--no-modules` mode as we're
-        // not sure how to import them.
+        // not sure how to import them. That includes the polyfills of globals.
+        let polyfill = match (&import.module, &import.js_namespace) {
+            (None, None) => self.cx.global_fallbacks
+                .get(item)
+                .and_then(|fallbacks| fallbacks.polyfill.as_ref()),
+            _ => None,
+        };
         if self.cx.config.no_modules {
-            if let Some(module) = &import.module {
+            if let Some(module) = import.module.as_ref().or(polyfill) {
                 bail!(
                     "import from `{}` module not allowed with `--no-modules`; \
                      use `--nodejs` or `--browser` instead",
@@ -2003,6 +3841,15 @@ impl<'a, 'b> SubContext<'a, 'b> {
                 );
             }
         }
+        if self.cx.config.universal {
+            if let Some(module) = import.module.as_ref().or(polyfill) {
+                bail!(
+                    "import from `{}` module not allowed with `--universal`; \
+                     use `--nodejs` or `--browser` instead",
+                    module
+                );
+            }
+        }
 
         // Figure out what identifier we're importing from the module. If we've
         // got a namespace we use that, otherwise it's the name specified above.
@@ -2023,31 +3870,62 @@ impl<'a, 'b> SubContext<'a, 'b> {
         // generate a new identifier and are sure to generate the appropriate JS
         // import for our new identifier.
         let use_node_require = self.cx.use_node_require();
+        let global_fallbacks = &self.cx.global_fallbacks;
         let imported_identifiers = &mut self.cx.imported_identifiers;
         let imports = &mut self.cx.imports;
+        let globals = &mut self.cx.globals;
         let identifier = self.cx.imported_names.entry(import.module.clone())
             .or_insert_with(Default::default)
             .entry(name_to_import.to_string())
             .or_insert_with(|| {
-                let name = generate_identifier(name_to_import, imported_identifiers);
-                if let Some(module) = &import.module {
-                    if use_node_require {
-                        imports.push_str(&format!(
-                            "const {} = require(String.raw`{}`).{};\n",
-                            name, module, name_to_import
-                        ));
-                    } else if name_to_import == name {
-                        imports.push_str(&format!(
-                            "import {{ {} }} from '{}';\n",
-                            name, module
-                        ));
-                    } else {
-                        imports.push_str(&format!(
-                            "import {{ {} as {} }} from '{}';\n",
-                            name_to_import, name, module
+                // Globals which might be missing are looked up once, falling
+                // back on each vendor-prefixed name in turn and then on the
+                // polyfill, if there is one.
+                if import.module.is_none() && import.js_namespace.is_none() {
+                    if let Some(fallbacks) = global_fallbacks.get(name_to_import) {
+                        let mut lookup = String::from("undefined");
+                        if let Some(module) = &fallbacks.polyfill {
+                            lookup = generate_identifier(
+                                &format!("__wbg_polyfill_{}", name_to_import),
+                                imported_identifiers,
+                            );
+                            import_from_module(
+                                imports,
+                                use_node_require,
+                                module,
+                                name_to_import,
+                                &lookup,
+                            );
+                        }
+                        for prefix in fallbacks.vendor_prefixes.iter().rev() {
+                            let mut chars = name_to_import.chars();
+                            let prefixed = format!(
+                                "{}{}{}",
+                                prefix,
+                                chars.next().into_iter().flat_map(|c| c.to_uppercase()).collect::<String>(),
+                                chars.as_str()
+                            );
+                            lookup = format!(
+                                "typeof {0} !== 'undefined' ? {0} : {1}",
+                                prefixed, lookup
+                            );
+                        }
+                        let name = generate_identifier(
+                            &format!("__wbg_{}", name_to_import),
+                            imported_identifiers,
+                        );
+                        globals.push_str(&format!(
+                            "const {0} = typeof {1} !== 'undefined' ? {1} : {2};\n",
+                            name, name_to_import, lookup
                         ));
+                        return name;
                     }
                 }
+
+                let name = generate_identifier(name_to_import, imported_identifiers);
+                if let Some(module) = &import.module {
+                    import_from_module(imports, use_node_require, module, name_to_import, &name);
+                }
                 name
             });
 
@@ -2061,6 +3939,29 @@ impl<'a, 'b> SubContext<'a, 'b> {
     }
 }
 
+/// Imports `item` from `module` into the generated JS as `name`.
+fn import_from_module(
+    imports: &mut String,
+    use_node_require: bool,
+    module: &str,
+    item: &str,
+    name: &str,
+) {
+    if use_node_require {
+        imports.push_str(&format!(
+            "const {} = require(String.raw`{}`).{};\n",
+            name, module, item
+        ));
+    } else if item == name {
+        imports.push_str(&format!("import {{ {} }} from '{}';\n", name, module));
+    } else {
+        imports.push_str(&format!(
+            "import {{ {} as {} }} from '{}';\n",
+            item, name, module
+        ));
+    }
+}
+
 fn generate_identifier(name: &str, used_names: &mut HashMap<String, usize>) -> String {
     let cnt = used_names.entry(name.to_string()).or_insert(0);
     *cnt += 1;
@@ -2083,3 +3984,10 @@ fn format_doc_comments(comments: &Vec<String>, js_doc_comments: Option<String>)
     };
     format!("/**\n{}{}*/\n", body, doc)
 }
+
+fn limits_to_js(limits: &ResizableLimits) -> String {
+    match limits.maximum() {
+        Some(max) => format!("initial: {}, maximum: {}", limits.initial(), max),
+        None => format!("initial: {}", limits.initial()),
+    }
+}
@@ -1,7 +1,7 @@
 use failure::Error;
 
 use super::{Context, Js2Rust};
-use descriptor::{Descriptor, Function};
+use descriptor::{Descriptor, Function, VectorKind};
 
 /// Helper struct for manufacturing a shim in JS used to translate Rust types to
 /// JS, then invoking an imported JS function.
@@ -36,6 +36,16 @@ pub struct Rust2Js<'a, 'b: 'a> {
 
     /// Whether or not we're catching JS exceptions
     catch: bool,
+
+    /// Whether trailing `None` arguments are left out of the invocation.
+    optional_args: bool,
+
+    /// How many of the arguments at the end of `js_arguments` are `Option`s.
+    trailing_optional: usize,
+
+    /// Whether the last argument is an array which is spread into the
+    /// invocation.
+    variadic: bool,
 }
 
 impl<'a, 'b> Rust2Js<'a, 'b> {
@@ -50,6 +60,9 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
             arg_idx: 0,
             ret_expr: String::new(),
             catch: false,
+            optional_args: false,
+            trailing_optional: 0,
+            variadic: false,
         }
     }
 
@@ -62,12 +75,44 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
         self
     }
 
+    /// Leaves trailing `None` arguments out of the invocation, so that the
+    /// imported function sees how many arguments were actually given.
+    pub fn optional_args(&mut self, optional: bool) -> &mut Self {
+        self.optional_args = optional;
+        self
+    }
+
+    /// Spreads the last argument, which is a slice, into the invocation as the
+    /// arguments of a variadic function.
+    pub fn variadic(&mut self, variadic: bool) -> &mut Self {
+        self.variadic = variadic;
+        self
+    }
+
     /// Generates all bindings necessary for the signature in `Function`,
     /// creating necessary argument conversions and return value processing.
     pub fn process(&mut self, function: &Function) -> Result<&mut Self, Error> {
         for arg in function.arguments.iter() {
             self.argument(arg)?;
         }
+        self.trailing_optional = function
+            .arguments
+            .iter()
+            .rev()
+            .take_while(|arg| match arg {
+                Descriptor::Option(_) => true,
+                _ => false,
+            })
+            .count();
+        if self.variadic {
+            match function.arguments.last().and_then(|arg| arg.vector_kind()) {
+                Some(_) => {
+                    let last = self.js_arguments.pop().unwrap();
+                    self.js_arguments.push(format!("...{}", last));
+                }
+                None => bail!("the last argument of a variadic function must be a slice"),
+            }
+        }
         self.ret(&function.ret)?;
         Ok(self)
     }
@@ -89,7 +134,12 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
 
         if let Some(ty) = arg.vector_kind() {
             let abi2 = self.shim_argument();
-            let f = self.cx.expose_get_vector_from_wasm(ty);
+            let f = match ty {
+                VectorKind::Anyref if arg.is_by_ref() => {
+                    self.cx.expose_get_array_js_value_ref_from_wasm()
+                }
+                _ => self.cx.expose_get_vector_from_wasm(ty),
+            };
             self.prelude(&format!(
                 "let v{0} = {prefix}{func}({0}, {1});",
                 abi,
@@ -115,6 +165,26 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
                 ));
                 self.cx.require_internal_export("__wbindgen_free")?;
             }
+            if arg.is_mut_ref() {
+                // Hand out a copy rather than a view of wasm memory, since a
+                // view is emptied if the import grows memory, and copy the
+                // import's writes back afterwards.
+                let get = self.cx.memview_function(ty);
+                self.prelude(&format!(
+                    "{start}v{0} = v{0}.slice();{end}",
+                    abi,
+                    start = if optional { format!("if ({} !== 0) {{ ", abi) } else { String::new() },
+                    end = if optional { " }" } else { "" },
+                ));
+                self.finally(&format!(
+                    "{start}{get}().set(v{0}, {0} / {size});{end}",
+                    abi,
+                    get = get,
+                    size = ty.size(),
+                    start = if optional { format!("if ({} !== 0) {{ ", abi) } else { String::new() },
+                    end = if optional { " }" } else { "" },
+                ));
+            }
             self.js_arguments.push(format!("v{}", abi));
             return Ok(());
         }
@@ -148,25 +218,36 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
             }
 
             if let Some(signed) = arg.get_64() {
-                let f = if signed {
-                    self.cx.expose_int64_cvt_shim()
-                } else {
-                    self.cx.expose_uint64_cvt_shim()
-                };
+                self.cx.expose_join_int64();
                 self.shim_argument();
                 let low = self.shim_argument();
                 let high = self.shim_argument();
                 let name = format!("n{}", abi);
                 self.prelude(&format!(
                     "
-                        u32CvtShim[0] = {present} === 0 ? 0 : {low};
-                        u32CvtShim[1] = {present} === 0 ? 0 : {high};
-                        const {name} = {present} === 0 ? undefined : {f}[0];
+                        const {name} = {present} === 0 ? undefined : joinInt64({low}, {high}, {signed});
                     ",
                     present = abi,
                     low = low,
                     high = high,
-                    f = f,
+                    signed = signed,
+                    name = name,
+                ));
+                self.js_arguments.push(name);
+                return Ok(());
+            }
+
+            if let Some(signed) = arg.get_128() {
+                self.cx.expose_join_int128();
+                let limbs = (0..4).map(|_| self.shim_argument()).collect::<Vec<_>>();
+                let name = format!("n{}", abi);
+                self.prelude(&format!(
+                    "
+                        const {name} = {present} === 0 ? undefined : joinInt128({limbs}, {signed});
+                    ",
+                    present = abi,
+                    limbs = limbs.join(", "),
+                    signed = signed,
                     name = name,
                 ));
                 self.js_arguments.push(name);
@@ -187,27 +268,56 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
                     ));
                     return Ok(())
                 },
+                Descriptor::Date => {
+                    let value = self.shim_argument();
+                    self.js_arguments.push(format!(
+                        "{present} === 0 ? undefined : new Date({value})",
+                        value = value,
+                        present = abi,
+                    ));
+                    return Ok(())
+                },
+                Descriptor::RustStruct(ref class) => {
+                    self.prelude(&format!(
+                        "let c{0} = {0} === 0 ? undefined : {1}.__construct({0});",
+                        abi,
+                        class,
+                    ));
+                    self.js_arguments.push(format!("c{}", abi));
+                    return Ok(())
+                },
                 _ => bail!("unsupported optional argument type for calling JS function from Rust: {:?}", arg),
             };
         }
 
         if let Some(signed) = arg.get_64() {
-            let f = if signed {
-                self.cx.expose_int64_cvt_shim()
-            } else {
-                self.cx.expose_uint64_cvt_shim()
-            };
+            self.cx.expose_join_int64();
             let high = self.shim_argument();
             let name = format!("n{}", abi);
             self.prelude(&format!(
                 "\
-                 u32CvtShim[0] = {low};
-                 u32CvtShim[1] = {high};
-                 const {name} = {f}[0];
+                 const {name} = joinInt64({low}, {high}, {signed});
                  ",
                 low = abi,
                 high = high,
-                f = f,
+                signed = signed,
+                name = name,
+            ));
+            self.js_arguments.push(name);
+            return Ok(());
+        }
+
+        if let Some(signed) = arg.get_128() {
+            self.cx.expose_join_int128();
+            let mut limbs = vec![abi.clone()];
+            limbs.extend((0..3).map(|_| self.shim_argument()));
+            let name = format!("n{}", abi);
+            self.prelude(&format!(
+                "\
+                 const {name} = joinInt128({limbs}, {signed});
+                 ",
+                limbs = limbs.join(", "),
+                signed = signed,
                 name = name,
             ));
             self.js_arguments.push(name);
@@ -313,6 +423,10 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
             ref d if d.is_number() => abi,
             Descriptor::Boolean => format!("{} !== 0", abi),
             Descriptor::Char => format!("String.fromCodePoint({})", abi),
+            Descriptor::Date => format!("new Date({})", abi),
+            Descriptor::StringEnum(ref variants) => {
+                format!("{}[{}]", self.cx.expose_string_enum(variants), abi)
+            }
             _ => bail!("unsupported argument type for calling JS function from Rust: {:?}", arg),
         };
         self.js_arguments.push(invoc_arg);
@@ -417,25 +531,37 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
                 return Ok(());
             }
 
-            if let Some(signed) = ty.get_64() {
+            if ty.get_64().is_some() {
                 self.cx.expose_is_like_none();
                 self.cx.expose_uint32_memory();
-                let f = if signed {
-                    self.cx.expose_int64_memory();
-                    "getInt64Memory"
-                } else {
-                    self.cx.expose_uint64_memory();
-                    "getUint64Memory"
-                };
+                self.cx.expose_split_int64();
                 self.shim_arguments.insert(0, "ret".to_string());
-                self.ret_expr = format!(
-                    "
-                        const val = JS;
-                        getUint32Memory()[ret / 4] = !isLikeNone(val);
-                        {}()[ret / 8 + 1] = isLikeNone(val) ? BigInt(0) : val;
-                    ",
-                    f
-                );
+                self.ret_expr = "
+                    const val = JS;
+                    const mem = getUint32Memory();
+                    mem[ret / 4] = !isLikeNone(val);
+                    if (!isLikeNone(val)) {
+                        const parts = splitInt64(val);
+                        mem[ret / 4 + 2] = parts[0];
+                        mem[ret / 4 + 3] = parts[1];
+                    }
+                ".to_string();
+                return Ok(());
+            }
+
+            if ty.get_128().is_some() {
+                self.cx.expose_is_like_none();
+                self.cx.expose_uint32_memory();
+                self.cx.expose_split_int128();
+                self.shim_arguments.insert(0, "ret".to_string());
+                self.ret_expr = "
+                    const val = JS;
+                    const mem = getUint32Memory();
+                    mem[ret / 4] = !isLikeNone(val);
+                    if (!isLikeNone(val)) {
+                        mem.set(splitInt128(val), ret / 4 + 1);
+                    }
+                ".to_string();
                 return Ok(());
             }
 
@@ -451,14 +577,46 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
                 Descriptor::Char => {
                     self.cx.expose_is_like_none();
                     self.cx.expose_uint32_memory();
+                    self.cx.expose_char_code_point();
                     self.shim_arguments.insert(0, "ret".to_string());
                     self.ret_expr = "
                         const val = JS;
                         getUint32Memory()[ret / 4] = !isLikeNone(val);
-                        getUint32Memory()[ret / 4 + 1] = isLikeNone(val) ? 0 : val.codePointAt(0);
+                        getUint32Memory()[ret / 4 + 1] = isLikeNone(val) ? 0 : charCodePoint(val);
                     ".to_string();
                     return Ok(());
                 },
+                Descriptor::Date => {
+                    self.cx.expose_is_like_none();
+                    self.cx.expose_uint32_memory();
+                    self.cx.expose_f64_memory();
+                    self.shim_arguments.insert(0, "ret".to_string());
+                    self.ret_expr = "
+                        const val = JS;
+                        getUint32Memory()[ret / 4] = !isLikeNone(val);
+                        getFloat64Memory()[ret / 8 + 1] = isLikeNone(val) ? 0 : val.getTime();
+                    ".to_string();
+                    return Ok(());
+                },
+                Descriptor::RustStruct(ref class) => {
+                    self.cx.expose_is_like_none();
+                    self.ret_expr = format!(
+                        "
+                            const val = JS;
+                            if (isLikeNone(val)) {{
+                                return 0;
+                            }}
+                            if (!(val instanceof {0})) {{
+                                throw new Error('expected value of type {0}');
+                            }}
+                            const ret = val.ptr;
+                            val.ptr = 0;
+                            return ret;
+                        ",
+                        class,
+                    );
+                    return Ok(());
+                },
                 _ => bail!("unsupported optional return type for calling JS function from Rust: {:?}", ty),
             };
         }
@@ -466,22 +624,26 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
             self.ret_expr = "return JS;".to_string();
             return Ok(());
         }
-        if let Some(signed) = ty.get_64() {
-            let f = if signed {
-                self.cx.expose_int64_memory();
-                "getInt64Memory"
-            } else {
-                self.cx.expose_uint64_memory();
-                "getUint64Memory"
-            };
+        if ty.get_64().is_some() {
+            self.cx.expose_uint32_memory();
+            self.cx.expose_split_int64();
             self.shim_arguments.insert(0, "ret".to_string());
-            self.ret_expr = format!(
-                "\
-                 const val = JS;\n\
-                 {}()[ret / 8] = val;\n\
-                 ",
-                f
-            );
+            self.ret_expr = "\
+                 const parts = splitInt64(JS);\n\
+                 const mem = getUint32Memory();\n\
+                 mem[ret / 4] = parts[0];\n\
+                 mem[ret / 4 + 1] = parts[1];\n\
+                 ".to_string();
+            return Ok(());
+        }
+        if ty.get_128().is_some() {
+            self.cx.expose_uint32_memory();
+            self.cx.expose_split_int128();
+            self.shim_arguments.insert(0, "ret".to_string());
+            self.ret_expr = "\
+                 const parts = splitInt128(JS);\n\
+                 getUint32Memory().set(parts, ret / 4);\n\
+                 ".to_string();
             return Ok(());
         }
 
@@ -509,12 +671,41 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
 
         self.ret_expr = match *ty {
             Descriptor::Boolean => "return JS ? 1 : 0;".to_string(),
-            Descriptor::Char => "return JS.codePointAt(0);".to_string(),
+            Descriptor::Char => {
+                self.cx.expose_char_code_point();
+                "return charCodePoint(JS);".to_string()
+            }
+            Descriptor::Date => "return JS.getTime();".to_string(),
+            Descriptor::StringEnum(ref variants) => {
+                format!("return {}.indexOf(JS);", self.cx.expose_string_enum(variants))
+            }
             _ => bail!("unsupported return type for calling JS function from Rust: {:?}", ty),
         };
         Ok(())
     }
 
+    /// Returns why calling the import needs a JS shim, or `None` if the values
+    /// wasm passes can be handed to the import, and its return value handed
+    /// back, as they are.
+    pub fn shim_required(&self) -> Option<&'static str> {
+        if self.catch {
+            return Some("it catches exceptions");
+        }
+        if self.optional_args && self.trailing_optional > 0 {
+            return Some("it leaves out the arguments which are `None`");
+        }
+        if self.prelude.len() > 0
+            || self.finally.len() > 0
+            || self.shim_arguments != self.js_arguments
+        {
+            return Some("its arguments need to be converted");
+        }
+        if self.ret_expr != "JS;" && self.ret_expr != "return JS;" {
+            return Some("its return value needs to be converted");
+        }
+        None
+    }
+
     pub fn finish(&self, invoc: &str) -> String {
         let mut ret = String::new();
         ret.push_str("function(");
@@ -528,10 +719,31 @@ impl<'a, 'b> Rust2Js<'a, 'b> {
         ret.push_str(") {\n");
         ret.push_str(&self.prelude);
 
-        let mut invoc = self.ret_expr.replace(
-            "JS",
-            &format!("{}({})", invoc, self.js_arguments.join(", ")),
-        );
+        let call = if self.optional_args && self.trailing_optional > 0 {
+            // Each optional argument is only passed if it, or one after it, is
+            // given, so the import is called with as many arguments as there
+            // are up to the last one which isn't `None`.
+            let required = self.js_arguments.len() - self.trailing_optional;
+            let mut args = self.js_arguments[..required].to_vec();
+            let mut calls = vec![format!("{}({})", invoc, args.join(", "))];
+            for (i, arg) in self.js_arguments[required..].iter().enumerate() {
+                let name = format!("opt{}", i);
+                ret.push_str(&format!("const {} = {};\n", name, arg));
+                args.push(name.clone());
+                calls.push(format!(
+                    "{} !== undefined ? {}({}) : ",
+                    name,
+                    invoc,
+                    args.join(", ")
+                ));
+            }
+            let last = calls.remove(0);
+            calls.reverse();
+            format!("({}{})", calls.concat(), last)
+        } else {
+            format!("{}({})", invoc, self.js_arguments.join(", "))
+        };
+        let mut invoc = self.ret_expr.replace("JS", &call);
         if self.catch {
             let catch = "\
                          const view = getUint32Memory();\n\
@@ -0,0 +1,222 @@
+//! Tests of the generated JS, which is run with node against a stand-in for
+//! the wasm module's exports.
+
+use std::process::Command;
+
+use parity_wasm::elements::*;
+
+use super::js2rust::Js2Rust;
+use super::{Context, ExportedClass};
+use descriptor::Descriptor;
+use Bindgen;
+
+/// A module exporting `names`, which is all `require_internal_export` looks
+/// for.
+fn module(names: &[&str]) -> Module {
+    let entries = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| ExportEntry::new(name.to_string(), Internal::Function(i as u32)))
+        .collect();
+    Module::new(vec![Section::Export(ExportSection::with_entries(entries))])
+}
+
+fn context<'a>(config: &'a Bindgen, module: &'a mut Module) -> Context<'a> {
+    Context {
+        globals: String::new(),
+        imports: String::new(),
+        footer: String::new(),
+        typescript: String::new(),
+        exposed_globals: Default::default(),
+        required_internal_exports: Default::default(),
+        imported_names: Default::default(),
+        imported_identifiers: Default::default(),
+        exported_classes: Default::default(),
+        options_interfaces: Default::default(),
+        string_enums: Default::default(),
+        interface: Default::default(),
+        subclassed: Default::default(),
+        imported_parents: Default::default(),
+        global_fallbacks: Default::default(),
+        exported_names: Default::default(),
+        diagnostics: Vec::new(),
+        config,
+        module,
+        function_table_needed: false,
+        run_descriptor: &|_| None,
+    }
+}
+
+/// Runs `script` with node, failing with its output if it throws.
+fn run_node(args: &[&str], script: &str) {
+    let output = Command::new("node").args(args).arg("-e").arg(script).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}\n{}",
+        script,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// A stand-in for the wasm module's exports, whose allocator hands out
+/// consecutive addresses from 8 and records everything freed.
+const WASM: &str = "
+    const assert = require('assert');
+    const allocated = [];
+    const freed = [];
+    let next = 8;
+    const wasm = {
+        memory: new WebAssembly.Memory({ initial: 1 }),
+        __wbindgen_malloc(len) {
+            const ptr = next;
+            next += len;
+            allocated.push(ptr);
+            return ptr;
+        },
+        __wbindgen_free(ptr, len) {
+            freed.push([ptr, len]);
+        },
+        __wbg_foo_free(ptr) {
+            freed.push(ptr);
+        },
+        take(...args) {
+            throw new Error('wasm shouldn\\'t be called');
+        },
+    };
+";
+
+#[test]
+fn frees_arguments_when_a_later_one_throws() {
+    let mut config = Bindgen::new();
+    // Checking the type of each string argument is what throws here.
+    config.debug(true);
+    let mut module = module(&["__wbindgen_malloc", "__wbindgen_free"]);
+    let mut cx = context(&config, &mut module);
+    let shim = {
+        let mut js2rust = Js2Rust::new("takeStrings", &mut cx);
+        js2rust
+            .argument(&Descriptor::String)
+            .unwrap()
+            .argument(&Descriptor::String)
+            .unwrap()
+            .ret(&None)
+            .unwrap();
+        js2rust.finish("function takeStrings", "wasm.take").0
+    };
+    let script = format!(
+        "
+        {wasm}
+        {globals}
+        {shim}
+        assert.throws(() => takeStrings('foo', 1), /expected a string argument/);
+        assert.deepStrictEqual(freed, [[8, 3]]);
+        ",
+        wasm = WASM,
+        globals = cx.globals,
+        shim = shim,
+    );
+    run_node(&[], &script);
+}
+
+#[test]
+fn slices_of_64_bit_integers_without_bigint() {
+    let config = Bindgen::new();
+    let mut module = module(&["__wbindgen_malloc", "__wbindgen_free"]);
+    let mut cx = context(&config, &mut module);
+    let shim = {
+        let mut js2rust = Js2Rust::new("takeI64s", &mut cx);
+        js2rust
+            .argument(&Descriptor::Vector(Box::new(Descriptor::I64)))
+            .unwrap()
+            .ret(&None)
+            .unwrap();
+        js2rust.finish("function takeI64s", "wasm.take").0
+    };
+    let script = format!(
+        "
+        {wasm}
+        delete globalThis.BigInt64Array;
+        delete globalThis.BigUint64Array;
+        {globals}
+        {shim}
+        assert.throws(
+            () => takeI64s([1]),
+            /slices of 64-bit integers can only be passed where `BigUint64Array` is supported/,
+        );
+        assert.deepStrictEqual(allocated, []);
+        ",
+        wasm = WASM,
+        globals = cx.globals,
+        shim = shim,
+    );
+    run_node(&[], &script);
+}
+
+#[test]
+fn classes_are_freed_when_collected() {
+    let mut config = Bindgen::new();
+    config.nodejs(true);
+    let mut module = module(&[]);
+    let mut cx = context(&config, &mut module);
+    cx.write_class("Foo", &ExportedClass::default()).unwrap();
+    // Only the instance still owning its struct is freed, and only once.
+    let script = format!(
+        "
+        {wasm}
+        {globals}
+        (() => {{
+            Foo.__construct(1);
+            Foo.__construct(2).ptr = 0;
+            Foo.__construct(3).free();
+        }})();
+        setTimeout(() => {{
+            gc();
+            setTimeout(() => assert.deepStrictEqual(freed, [3, 1]), 10);
+        }}, 10);
+        ",
+        wasm = WASM,
+        globals = cx.globals,
+    );
+    run_node(&["--expose-gc"], &script);
+}
+
+#[test]
+fn custom_elements_outside_browsers() {
+    let mut config = Bindgen::new();
+    config.nodejs(true);
+    let mut module = module(&[]);
+    let mut cx = context(&config, &mut module);
+    let class = ExportedClass {
+        constructor: Some("new".to_string()),
+        extends: Some("HTMLElement".to_string()),
+        custom_element: Some("my-element".to_string()),
+        ..Default::default()
+    };
+    cx.write_class("Foo", &class).unwrap();
+    let globals = cx.globals.clone();
+
+    // Loading the module doesn't throw without `HTMLElement` and
+    // `customElements`, like in node...
+    let script = format!("{}\n{}\nassert.strictEqual(typeof Foo, 'function');", WASM, globals);
+    run_node(&[], &script);
+
+    // ...and the element is still defined where they exist.
+    let script = format!(
+        "
+        {wasm}
+        globalThis.HTMLElement = class {{}};
+        const defined = [];
+        globalThis.customElements = {{
+            define(name, class_) {{
+                defined.push([name, class_]);
+            }},
+        }};
+        {globals}
+        assert.deepStrictEqual(defined, [['my-element', Foo]]);
+        assert.ok(Foo.prototype instanceof HTMLElement);
+        ",
+        wasm = WASM,
+        globals = globals,
+    );
+    run_node(&[], &script);
+}
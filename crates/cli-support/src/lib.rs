@@ -2,6 +2,8 @@
 
 extern crate parity_wasm;
 extern crate wasm_bindgen_shared as shared;
+#[macro_use]
+extern crate serde_derive;
 extern crate serde_json;
 extern crate wasm_gc;
 extern crate wasmi;
@@ -9,17 +11,21 @@ extern crate wasmi;
 extern crate failure;
 
 use std::any::Any;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt;
 use std::fs;
 use std::mem;
 use std::path::{Path, PathBuf};
 
+use diagnostic::{Diagnostic, DiagnosticKind};
 use failure::{Error, ResultExt};
 use parity_wasm::elements::*;
 
 mod descriptor;
+pub mod diagnostic;
+pub mod interface;
 mod js;
+mod stable_abi;
 pub mod wasm2es6js;
 
 pub struct Bindgen {
@@ -33,6 +39,16 @@ pub struct Bindgen {
     typescript: bool,
     demangle: bool,
     keep_debug: bool,
+    poison_on_panic: bool,
+    error_hook: bool,
+    profile_boundary: bool,
+    emit_interface: bool,
+    dual_modules: bool,
+    universal: bool,
+    import_memory: bool,
+    u64_pairs: bool,
+    strict: bool,
+    diagnostics: Vec<Diagnostic>,
 }
 
 enum Input {
@@ -55,6 +71,16 @@ impl Bindgen {
             typescript: false,
             demangle: true,
             keep_debug: false,
+            poison_on_panic: false,
+            error_hook: false,
+            profile_boundary: false,
+            emit_interface: false,
+            dual_modules: false,
+            universal: false,
+            import_memory: false,
+            u64_pairs: false,
+            strict: false,
+            diagnostics: Vec::new(),
         }
     }
 
@@ -138,21 +164,93 @@ impl Bindgen {
         self
     }
 
+    pub fn poison_on_panic(&mut self, poison_on_panic: bool) -> &mut Bindgen {
+        self.poison_on_panic = poison_on_panic;
+        self
+    }
+
+    pub fn error_hook(&mut self, error_hook: bool) -> &mut Bindgen {
+        self.error_hook = error_hook;
+        self
+    }
+
+    pub fn profile_boundary(&mut self, profile_boundary: bool) -> &mut Bindgen {
+        self.profile_boundary = profile_boundary;
+        self
+    }
+
+    pub fn emit_interface(&mut self, emit_interface: bool) -> &mut Bindgen {
+        self.emit_interface = emit_interface;
+        self
+    }
+
+    /// Generates both CommonJS and ES module output for node.js, sharing one
+    /// wasm file, along with a `{stem}.package.json` listing the fields a
+    /// `package.json` needs to point `require` and `import` at the right one.
+    pub fn dual_modules(&mut self, dual_modules: bool) -> &mut Bindgen {
+        self.dual_modules = dual_modules;
+        self
+    }
+
+    /// Generates a single script which works in browsers, workers and node.js
+    /// alike, detecting which one it's running in when it's initialized.
+    ///
+    /// Like `no_modules` the script exports an `init` function loading the
+    /// wasm, and is attached to the `no_modules_global` global unless it's
+    /// loaded as a CommonJS module.
+    pub fn universal(&mut self, universal: bool) -> &mut Bindgen {
+        self.universal = universal;
+        self
+    }
+
+    /// Makes the wasm module import its memory and function table instead of
+    /// defining them, so `init` can be passed an existing `WebAssembly.Memory`
+    /// and `WebAssembly.Table` to use.
+    ///
+    /// This requires `no_modules` or `universal` output, which are the ones
+    /// with an `init` function.
+    pub fn import_memory(&mut self, import_memory: bool) -> &mut Bindgen {
+        self.import_memory = import_memory;
+        self
+    }
+
+    /// Represents `u64` and `i64` values as `{ lo, hi }` objects of their low
+    /// and high 32 bits in engines without `BigInt64Array`, instead of as
+    /// numbers which are checked to be in the range where they're exact.
+    pub fn u64_pairs(&mut self, u64_pairs: bool) -> &mut Bindgen {
+        self.u64_pairs = u64_pairs;
+        self
+    }
+
+    /// Makes `generate` fail if any item is left out of the bindings, rather
+    /// than only reporting it in `diagnostics`.
+    pub fn strict(&mut self, strict: bool) -> &mut Bindgen {
+        self.strict = strict;
+        self
+    }
+
     pub fn generate<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
         self._generate(path.as_ref())
     }
 
+    /// The problems found by the last call to `generate` which didn't stop
+    /// the bindings from being generated.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
     fn _generate(&mut self, out_dir: &Path) -> Result<(), Error> {
+        self.diagnostics.clear();
         let (mut module, stem) = match self.input {
             Input::None => bail!("must have an input by now"),
             Input::Module(ref mut m, ref name) => {
                 let blank_module = Module::new(Vec::new());
-                (mem::replace(m, blank_module), &name[..])
+                (mem::replace(m, blank_module), name.clone())
             }
             Input::Bytes(ref b, ref name) => {
                 let module = parity_wasm::deserialize_buffer::<Module>(&b)
                     .context("failed to parse input file as wasm")?;
-                (module, &name[..])
+                (module, name.clone())
             }
             Input::Path(ref path) => {
                 let contents = fs::read(&path)
@@ -160,12 +258,26 @@ impl Bindgen {
                 let module = parity_wasm::deserialize_buffer::<Module>(&contents)
                     .context("failed to parse input file as wasm")?;
                 let stem = path.file_stem().unwrap().to_str().unwrap();
-                (module, stem)
+                (module, stem.to_string())
             }
         };
-        let programs = extract_programs(&mut module)
+        check_not_dynamically_linked(&module)?;
+        let mut programs = extract_programs(&mut module)
             .with_context(|_| "failed to extract wasm-bindgen custom sections")?;
 
+        // Imports with a `node_module` are imported from it instead of their
+        // usual module when the output is for Node.js.
+        if self.nodejs || self.nodejs_experimental_modules || self.dual_modules {
+            for import in programs.iter_mut().flat_map(|p| p.imports.iter_mut()) {
+                if let Some(module) = import.node_module.take() {
+                    import.module = Some(module);
+                }
+            }
+        }
+        check_name_collisions(&programs)?;
+        let descriptors = extract_descriptors(&mut module)
+            .with_context(|_| "failed to extract wasm-bindgen descriptors")?;
+
         // Here we're actually instantiating the module we've parsed above for
         // execution. Why, you might be asking, are we executing wasm code? A
         // good question!
@@ -180,14 +292,131 @@ impl Bindgen {
         // execute a shim function which informs us about its type so we can
         // then generate the appropriate bindings.
         //
+        // With the `static-descriptors` feature the macro instead writes what
+        // those shims would describe into a custom section, and if there are
+        // no shims left to run the module isn't instantiated at all.
+        //
         // TODO: avoid a `clone` here of the module if we can
-        let instance = wasmi::Module::from_parity_wasm_module(module.clone())
-            .with_context(|_| "failed to create wasmi module")?;
-        let instance = wasmi::ModuleInstance::new(&instance, &MyResolver)
-            .with_context(|_| "failed to instantiate wasm module")?;
-        let instance = instance.not_started_instance();
+        let has_describe_shims = module
+            .export_section()
+            .into_iter()
+            .flat_map(|s| s.entries())
+            .any(|e| e.field().starts_with("__wbindgen_describe_"));
+        let instance = if has_describe_shims {
+            Some(wasmi::Module::from_parity_wasm_module(module.clone())
+                .with_context(|_| "failed to create wasmi module")?)
+        } else {
+            None
+        };
+        let instance = match instance {
+            Some(ref instance) => Some(wasmi::ModuleInstance::new(instance, &MyResolver)
+                .with_context(|_| "failed to instantiate wasm module")?),
+            None => None,
+        };
+        let instance = instance.as_ref().map(|i| i.not_started_instance());
+
+        let run_descriptor = |name: &str| {
+            if let Some(descriptor) = descriptors.get(name) {
+                return Some(descriptor.clone());
+            }
+            let instance = instance?;
+            let mut v = MyExternals(Vec::new());
+            match instance.invoke_export(name, &[], &mut v) {
+                Ok(None) => Some(v.0),
+                Ok(Some(_)) => unreachable!(
+                    "there is only one export, and we only return None from it"
+                ),
+                // Allow missing exported describe functions. This can
+                // happen when a nested dependency crate exports things
+                // but the root crate doesn't use them.
+                Err(wasmi::Error::Function(_)) => None,
+                Err(e) => panic!("unexpected error running descriptor: {}", e),
+            }
+        };
+
+        if self.universal {
+            if self.nodejs || self.nodejs_experimental_modules || self.browser
+                || self.no_modules || self.dual_modules
+            {
+                bail!("universal output can't be combined with other output options");
+            }
+        }
+
+        if self.import_memory && !(self.no_modules || self.universal) {
+            bail!("importing memory requires `--no-modules` or `--universal` output");
+        }
+
+        let wasm_path = out_dir.join(format!("{}_bg", stem)).with_extension("wasm");
+        let (module, ts, interface, diagnostics) = if self.dual_modules {
+            if self.nodejs || self.nodejs_experimental_modules || self.browser || self.no_modules {
+                bail!("dual module output can't be combined with other output options");
+            }
+            // Both flavors are generated from the same input, and the JS glue
+            // doesn't depend on the flavor when changing the wasm, so they
+            // can share one wasm file.
+            self.nodejs = true;
+            let cjs = self.generate_js(module.clone(), &programs, &run_descriptor, &stem, out_dir);
+            self.nodejs_experimental_modules = true;
+            let esm = self.generate_js(module, &programs, &run_descriptor, &stem, out_dir);
+            self.nodejs = false;
+            self.nodejs_experimental_modules = false;
+            let (cjs_module, ts, interface, diagnostics) = cjs?;
+            let (esm_module, _, _, _) = esm?;
+            if parity_wasm::serialize(cjs_module.clone())? != parity_wasm::serialize(esm_module)? {
+                bail!("the CommonJS and ES module output need different wasm files");
+            }
+            self.write_package_exports(&stem, out_dir)?;
+            (cjs_module, ts, interface, diagnostics)
+        } else {
+            self.generate_js(module, &programs, &run_descriptor, &stem, out_dir)?
+        };
+        self.diagnostics = diagnostics;
+        if self.strict {
+            let skipped = self
+                .diagnostics
+                .iter()
+                .filter(|d| d.kind == DiagnosticKind::SkippedMember)
+                .count();
+            if skipped > 0 {
+                bail!(
+                    "left {} item(s) out of the bindings, which strict mode doesn't allow",
+                    skipped
+                );
+            }
+        }
+
+        if self.typescript {
+            let ts_path = out_dir.join(&stem).with_extension("d.ts");
+            fs::write(&ts_path, ts)
+                .with_context(|_| format!("failed to write `{}`", ts_path.display()))?;
+        }
 
-        let (js, ts) = {
+        if self.emit_interface {
+            let path = out_dir.join(format!("{}.interface.json", stem));
+            let json = serde_json::to_string_pretty(&interface)?;
+            fs::write(&path, json)
+                .with_context(|_| format!("failed to write `{}`", path.display()))?;
+        }
+
+        let wasm_bytes = parity_wasm::serialize(module)?;
+        fs::write(&wasm_path, wasm_bytes)
+            .with_context(|_| format!("failed to write `{}`", wasm_path.display()))?;
+        Ok(())
+    }
+
+    /// Generates the JS glue for `module` in the flavor currently configured,
+    /// writing the JS files and returning the final wasm module along with
+    /// the TypeScript definitions and interface of the glue, and the problems
+    /// found generating it.
+    fn generate_js(
+        &self,
+        mut module: Module,
+        programs: &[shared::Program],
+        run_descriptor: &Fn(&str) -> Option<Vec<u32>>,
+        stem: &str,
+        out_dir: &Path,
+    ) -> Result<(Module, String, interface::Interface, Vec<Diagnostic>), Error> {
+        let (js, ts, interface, diagnostics) = {
             let mut cx = js::Context {
                 globals: String::new(),
                 imports: String::new(),
@@ -198,23 +427,43 @@ impl Bindgen {
                 imported_names: Default::default(),
                 imported_identifiers: Default::default(),
                 exported_classes: Default::default(),
+                options_interfaces: Default::default(),
+                string_enums: Default::default(),
+                interface: Default::default(),
+                subclassed: programs
+                    .iter()
+                    .flat_map(|p| p.structs.iter())
+                    .filter_map(|s| s.extends.clone())
+                    .collect(),
+                imported_parents: Default::default(),
+                global_fallbacks: programs
+                    .iter()
+                    .flat_map(|p| p.imports.iter())
+                    .filter(|i| i.module.is_none() && i.js_namespace.is_none())
+                    .filter_map(|i| match &i.kind {
+                        shared::ImportKind::Type(t) => {
+                            Some((&t.name, &t.vendor_prefixes, &t.polyfill))
+                        }
+                        shared::ImportKind::Function(f) if f.method.is_none() => {
+                            Some((&f.function.name, &f.vendor_prefixes, &f.polyfill))
+                        }
+                        _ => None,
+                    })
+                    .filter(|(_, prefixes, polyfill)| !prefixes.is_empty() || polyfill.is_some())
+                    .map(|(name, prefixes, polyfill)| {
+                        let fallbacks = js::GlobalFallbacks {
+                            vendor_prefixes: prefixes.clone(),
+                            polyfill: polyfill.clone(),
+                        };
+                        (name.clone(), fallbacks)
+                    })
+                    .collect(),
+                exported_names: Default::default(),
+                diagnostics: Vec::new(),
                 config: &self,
                 module: &mut module,
                 function_table_needed: false,
-                run_descriptor: &|name| {
-                    let mut v = MyExternals(Vec::new());
-                    match instance.invoke_export(name, &[], &mut v) {
-                        Ok(None) => Some(v.0),
-                        Ok(Some(_)) => unreachable!(
-                            "there is only one export, and we only return None from it"
-                        ),
-                        // Allow missing exported describe functions. This can
-                        // happen when a nested dependency crate exports things
-                        // but the root crate doesn't use them.
-                        Err(wasmi::Error::Function(_)) => None,
-                        Err(e) => panic!("unexpected error running descriptor: {}", e),
-                    }
-                },
+                run_descriptor,
             };
             for program in programs.iter() {
                 js::SubContext {
@@ -222,7 +471,8 @@ impl Bindgen {
                     cx: &mut cx,
                 }.generate()?;
             }
-            cx.finalize(stem)?
+            let (js, ts) = cx.finalize(stem)?;
+            (js, ts, cx.interface, cx.diagnostics)
         };
 
         let extension = if self.nodejs_experimental_modules { "mjs" } else { "js" };
@@ -230,28 +480,64 @@ impl Bindgen {
         fs::write(&js_path, reset_indentation(&js))
             .with_context(|_| format!("failed to write `{}`", js_path.display()))?;
 
-        if self.typescript {
-            let ts_path = out_dir.join(stem).with_extension("d.ts");
-            fs::write(&ts_path, ts)
-                .with_context(|_| format!("failed to write `{}`", ts_path.display()))?;
-        }
-
-        let wasm_path = out_dir.join(format!("{}_bg", stem)).with_extension("wasm");
-
         if self.nodejs {
+            let wasm_path = out_dir.join(format!("{}_bg", stem)).with_extension("wasm");
             let js_path = wasm_path.with_extension(extension);
-            let shim = self.generate_node_wasm_import(&module, &wasm_path);
+            let shim = self.generate_node_wasm_import(&module, &wasm_path, stem);
             fs::write(&js_path, shim)
                 .with_context(|_| format!("failed to write `{}`", js_path.display()))?;
         }
 
-        let wasm_bytes = parity_wasm::serialize(module)?;
-        fs::write(&wasm_path, wasm_bytes)
-            .with_context(|_| format!("failed to write `{}`", wasm_path.display()))?;
+        Ok((module, ts, interface, diagnostics))
+    }
+
+    /// Writes the fields of a `package.json` pointing `require` at the
+    /// CommonJS output and `import` at the ES module output.
+    fn write_package_exports(&self, stem: &str, out_dir: &Path) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct PackageExports {
+            main: String,
+            module: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            types: Option<String>,
+            exports: BTreeMap<String, Conditions>,
+        }
+
+        #[derive(Serialize)]
+        struct Conditions {
+            import: String,
+            require: String,
+        }
+
+        let cjs = format!("./{}.js", stem);
+        let esm = format!("./{}.mjs", stem);
+        let mut exports = BTreeMap::new();
+        exports.insert(
+            ".".to_string(),
+            Conditions {
+                import: esm.clone(),
+                require: cjs.clone(),
+            },
+        );
+        let package = PackageExports {
+            main: cjs,
+            module: esm,
+            types: if self.typescript {
+                Some(format!("./{}.d.ts", stem))
+            } else {
+                None
+            },
+            exports,
+        };
+
+        let path = out_dir.join(format!("{}.package.json", stem));
+        let json = serde_json::to_string_pretty(&package)? + "\n";
+        fs::write(&path, json)
+            .with_context(|_| format!("failed to write `{}`", path.display()))?;
         Ok(())
     }
 
-    fn generate_node_wasm_import(&self, m: &Module, path: &Path) -> String {
+    fn generate_node_wasm_import(&self, m: &Module, path: &Path, stem: &str) -> String {
         let mut imports = BTreeSet::new();
         if let Some(i) = m.import_section() {
             for i in i.entries() {
@@ -262,9 +548,18 @@ impl Bindgen {
         let mut shim = String::new();
 
         if self.nodejs_experimental_modules {
+            // The wasm imports the JS glue as `./{stem}`, but node's ES module
+            // loader doesn't add file extensions so it's imported by its full
+            // name here.
+            let glue = format!("./{}", stem);
             for (i, module) in imports.iter().enumerate() {
+                let specifier = if **module == glue {
+                    format!("{}.mjs", glue)
+                } else {
+                    module.to_string()
+                };
                 shim.push_str(&format!("import * as import{} from '{}';\n",
-                                       i, module));
+                                       i, specifier));
             }
             // On windows skip the leading `/` which comes out when we parse a
             // url to use `C:\...` instead of `\C:\...`
@@ -320,10 +615,136 @@ impl Bindgen {
     }
 }
 
+/// Rejects modules linked with `--shared`, which need a dynamic linker to
+/// relocate them into a memory and table shared with a main module.
+///
+/// The JS glue assumes it owns the module's memory, function table and
+/// `JsValue` heap, which side modules would have to share, so for now they're
+/// reported up front rather than failing with a confusing error later on.
+fn check_not_dynamically_linked(module: &Module) -> Result<(), Error> {
+    let dynamic = module.sections().iter().any(|s| match *s {
+        Section::Custom(ref s) => s.name() == "dylink" || s.name() == "dylink.0",
+        _ => false,
+    });
+    if dynamic {
+        bail!(
+            "this wasm file was linked as a dynamically loaded side module \
+             (with `-C link-arg=--shared`), which isn't supported yet; \
+             compile each crate as its own module instead"
+        );
+    }
+    Ok(())
+}
+
+/// A name defined in the generated JS, along with the Rust item it came from.
+struct JsName {
+    item: String,
+    accessor: Option<&'static str>,
+}
+
+/// Checks that no two exported Rust items end up with the same name in the
+/// generated JS, where only one of them could be used.
+///
+/// Names can clash because of `js_name`, or because they're exported from
+/// different crates, so this checks the top-level exports of every program
+/// together, along with the members of each class. A getter and a setter are
+/// the only things which can share a name, as they define the same property.
+fn check_name_collisions(programs: &[shared::Program]) -> Result<(), Error> {
+    fn define(
+        names: &mut BTreeMap<String, JsName>,
+        js_name: String,
+        item: String,
+        accessor: Option<&'static str>,
+    ) -> Result<(), Error> {
+        if let Some(other) = names.get(&js_name) {
+            let pair = (other.accessor, accessor);
+            if pair != (Some("get"), Some("set")) && pair != (Some("set"), Some("get")) {
+                bail!(
+                    "{} and {} are both exported to JS as `{}`; rename one of \
+                     them with `#[wasm_bindgen(js_name = ...)]`",
+                    other.item,
+                    item,
+                    js_name
+                );
+            }
+        }
+        names.insert(js_name, JsName { item, accessor });
+        Ok(())
+    }
+
+    let mut module = BTreeMap::new();
+    let mut members = BTreeMap::new();
+    let mut rust_names = BTreeMap::new();
+    for s in programs.iter().flat_map(|p| p.structs.iter()) {
+        let item = format!("the struct `{}`", s.rust_name);
+        define(&mut module, s.name.clone(), item, None)?;
+        rust_names.insert(&s.name[..], &s.rust_name[..]);
+        let instance = members
+            .entry(s.name.clone())
+            .or_insert_with(BTreeMap::new);
+        let free = "the `free` method generated for every class".to_string();
+        define(instance, format!("{}.free", s.name), free, None)?;
+        let generated = [
+            (s.iterator, "[Symbol.iterator]", "`iterator`"),
+            (s.async_iterator, "[Symbol.asyncIterator]", "`async_iterator`"),
+        ];
+        for &(_, name, attr) in generated.iter().filter(|g| g.0) {
+            let item = format!("the method generated for {} structs", attr);
+            define(instance, format!("{}.{}", s.name, name), item, None)?;
+        }
+        for field in s.fields.iter() {
+            let item = format!("the field `{}` of `{}`", field.name, s.rust_name);
+            define(instance, format!("{}.{}", s.name, field.name), item, None)?;
+        }
+    }
+    for e in programs.iter().flat_map(|p| p.enums.iter()) {
+        define(&mut module, e.name.clone(), format!("the enum `{}`", e.name), None)?;
+    }
+    for c in programs.iter().flat_map(|p| p.consts.iter()) {
+        let item = format!("the constant `{}`", c.rust_name);
+        define(&mut module, c.name.clone(), item, None)?;
+    }
+    for export in programs.iter().flat_map(|p| p.exports.iter()) {
+        let class = match export.class {
+            Some(ref class) => class,
+            None => {
+                let item = format!("the function `{}`", export.rust_name);
+                define(&mut module, export.function.name.clone(), item, None)?;
+                continue;
+            }
+        };
+        let (name, accessor, what) = match (&export.getter, &export.setter) {
+            (Some(name), _) => (name.clone(), Some("get"), "getter"),
+            (_, Some(name)) => (name.clone(), Some("set"), "setter"),
+            _ => {
+                let name = match export.symbol {
+                    Some(ref symbol) => format!("[Symbol.{}]", symbol),
+                    None => export.function.name.clone(),
+                };
+                let what = if export.method { "method" } else { "function" };
+                (name, None, what)
+            }
+        };
+        let rust_class = rust_names.get(&class[..]).cloned().unwrap_or(&class[..]);
+        let item = format!("the {} `{}::{}`", what, rust_class, export.rust_name);
+        // Static methods live on the class itself rather than its instances,
+        // so they're kept apart from the instance members.
+        let key = if export.method {
+            class.clone()
+        } else {
+            format!("{} (static)", class)
+        };
+        let names = members.entry(key).or_insert_with(BTreeMap::new);
+        define(names, format!("{}.{}", class, name), item, accessor)?;
+    }
+    Ok(())
+}
+
 fn extract_programs(module: &mut Module) -> Result<Vec<shared::Program>, Error> {
     let version = shared::version();
     let mut ret = Vec::new();
     let mut to_remove = Vec::new();
+    let mut stable_abis = BTreeSet::new();
 
     for (i, s) in module.sections().iter().enumerate() {
         let custom = match *s {
@@ -362,6 +783,11 @@ fn extract_programs(module: &mut Module) -> Result<Vec<shared::Program>, Error>
                 Ok(f) => f,
                 Err(e) => bail!("failed to decode what looked like wasm-bindgen data: {}", e),
             };
+            if let Some(abi) = p.stable_abi {
+                ret.push(stable_abi::decode_program(abi, a)?);
+                stable_abis.insert(abi);
+                continue;
+            }
             if p.schema_version != shared::SCHEMA_VERSION {
                 bail!(
                     "
@@ -398,6 +824,64 @@ to open an issue at https://github.com/rustwasm/wasm-bindgen/issues!
         }
     }
 
+    for i in to_remove.into_iter().rev() {
+        module.sections_mut().remove(i);
+    }
+    for abi in stable_abis {
+        stable_abi::remove_prefixes(module, abi);
+    }
+    Ok(ret)
+}
+
+/// Reads the descriptors written by the `static-descriptors` feature out of
+/// the `__wasm_bindgen_descriptors` custom sections, keyed by the name of the
+/// describe shim each one replaces, and removes the sections.
+///
+/// Each descriptor is the little-endian length of the name and the name,
+/// followed by the count of numbers in the descriptor and the numbers.
+fn extract_descriptors(module: &mut Module) -> Result<HashMap<String, Vec<u32>>, Error> {
+    fn read_u32(payload: &mut &[u8]) -> Result<u32, Error> {
+        if payload.len() < 4 {
+            bail!("descriptor ended unexpectedly");
+        }
+        let (a, b) = payload.split_at(4);
+        *payload = b;
+        Ok((a[0] as u32) | (a[1] as u32) << 8 | (a[2] as u32) << 16 | (a[3] as u32) << 24)
+    }
+
+    let mut ret = HashMap::new();
+    let mut to_remove = Vec::new();
+
+    for (i, s) in module.sections().iter().enumerate() {
+        let custom = match *s {
+            Section::Custom(ref s) => s,
+            _ => continue,
+        };
+        if custom.name() != "__wasm_bindgen_descriptors" {
+            continue;
+        }
+        to_remove.push(i);
+
+        let mut payload = custom.payload();
+        while !payload.is_empty() {
+            let len = read_u32(&mut payload)? as usize;
+            if payload.len() < len {
+                bail!("descriptor ended unexpectedly");
+            }
+            let (name, rest) = payload.split_at(len);
+            payload = rest;
+            let name = match String::from_utf8(name.to_vec()) {
+                Ok(name) => name,
+                Err(_) => bail!("descriptor name isn't valid UTF-8"),
+            };
+            let count = read_u32(&mut payload)?;
+            let descriptor = (0..count)
+                .map(|_| read_u32(&mut payload))
+                .collect::<Result<Vec<_>, _>>()?;
+            ret.insert(name, descriptor);
+        }
+    }
+
     for i in to_remove.into_iter().rev() {
         module.sections_mut().remove(i);
     }
@@ -525,3 +1009,346 @@ fn reset_indentation(s: &str) -> String {
     }
     return dst
 }
+
+#[cfg(test)]
+mod tests {
+    use super::check_name_collisions;
+    use shared::*;
+
+    fn program() -> Program {
+        Program {
+            exports: Vec::new(),
+            enums: Vec::new(),
+            imports: Vec::new(),
+            structs: Vec::new(),
+            consts: Vec::new(),
+            version: version(),
+            schema_version: SCHEMA_VERSION.to_string(),
+            stable_abi: None,
+        }
+    }
+
+    fn strukt(name: &str, rust_name: &str) -> Struct {
+        Struct {
+            name: name.to_string(),
+            rust_name: rust_name.to_string(),
+            fields: Vec::new(),
+            comments: Vec::new(),
+            extends: None,
+            extends_rust: false,
+            custom_element: None,
+            async_iterator: false,
+            iterator: false,
+            shared_class: None,
+        }
+    }
+
+    fn field(name: &str) -> StructField {
+        StructField {
+            name: name.to_string(),
+            readonly: false,
+            enumerable: true,
+            configurable: false,
+            comments: Vec::new(),
+        }
+    }
+
+    fn enum_(name: &str) -> Enum {
+        Enum {
+            name: name.to_string(),
+            variants: Vec::new(),
+            comments: Vec::new(),
+        }
+    }
+
+    fn const_(name: &str, rust_name: &str) -> Const {
+        Const {
+            name: name.to_string(),
+            rust_name: rust_name.to_string(),
+            value: "0".to_string(),
+            comments: Vec::new(),
+        }
+    }
+
+    fn function(name: &str, rust_name: &str) -> Export {
+        Export {
+            class: None,
+            method: false,
+            consumed: false,
+            constructor: None,
+            function: Function { name: name.to_string() },
+            rust_name: rust_name.to_string(),
+            comments: Vec::new(),
+            optional_args: false,
+            js_map: false,
+            defaults: Vec::new(),
+            enumerable: false,
+            configurable: false,
+            getter: None,
+            setter: None,
+            indexing: None,
+            symbol: None,
+        }
+    }
+
+    fn method(class: &str, name: &str, rust_name: &str) -> Export {
+        Export {
+            class: Some(class.to_string()),
+            method: true,
+            ..function(name, rust_name)
+        }
+    }
+
+    fn static_method(class: &str, name: &str, rust_name: &str) -> Export {
+        Export {
+            method: false,
+            ..method(class, name, rust_name)
+        }
+    }
+
+    fn check(program: Program) -> Result<(), String> {
+        check_name_collisions(&[program]).map_err(|e| e.to_string())
+    }
+
+    fn assert_collides(program: Program, a: &str, b: &str, js_name: &str) {
+        let err = check(program).unwrap_err();
+        let expected = format!(
+            "{} and {} are both exported to JS as `{}`; rename one of \
+             them with `#[wasm_bindgen(js_name = ...)]`",
+            a, b, js_name
+        );
+        assert_eq!(err, expected);
+    }
+
+    #[test]
+    fn structs() {
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        p.structs.push(strukt("Bar", "Bar"));
+        check(p).unwrap();
+
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        p.structs.push(strukt("Foo", "Bar"));
+        assert_collides(p, "the struct `Foo`", "the struct `Bar`", "Foo");
+    }
+
+    #[test]
+    fn free() {
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        p.exports.push(method("Foo", "release", "free"));
+        check(p).unwrap();
+
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        p.exports.push(method("Foo", "free", "release"));
+        assert_collides(
+            p,
+            "the `free` method generated for every class",
+            "the method `Foo::release`",
+            "Foo.free",
+        );
+    }
+
+    #[test]
+    fn iterators() {
+        let mut p = program();
+        let mut s = strukt("Foo", "Foo");
+        s.iterator = true;
+        s.async_iterator = true;
+        p.structs.push(s);
+        let mut next = method("Foo", "next", "next");
+        next.symbol = Some("toPrimitive".to_string());
+        p.exports.push(next);
+        check(p).unwrap();
+
+        let generated = [("iterator", "iterator"), ("async_iterator", "asyncIterator")];
+        for &(attr, symbol) in generated.iter() {
+            let mut p = program();
+            let mut s = strukt("Foo", "Foo");
+            s.iterator = attr == "iterator";
+            s.async_iterator = attr == "async_iterator";
+            p.structs.push(s);
+            let mut m = method("Foo", "iter", "iter");
+            m.symbol = Some(symbol.to_string());
+            p.exports.push(m);
+            assert_collides(
+                p,
+                &format!("the method generated for `{}` structs", attr),
+                "the method `Foo::iter`",
+                &format!("Foo.[Symbol.{}]", symbol),
+            );
+        }
+    }
+
+    #[test]
+    fn fields() {
+        let mut p = program();
+        let mut s = strukt("Foo", "Foo");
+        s.fields.push(field("a"));
+        s.fields.push(field("b"));
+        p.structs.push(s);
+        p.exports.push(method("Foo", "c", "c"));
+        check(p).unwrap();
+
+        let mut p = program();
+        let mut s = strukt("Foo", "Foo");
+        s.fields.push(field("a"));
+        p.structs.push(s);
+        p.exports.push(method("Foo", "a", "get_a"));
+        assert_collides(
+            p,
+            "the field `a` of `Foo`",
+            "the method `Foo::get_a`",
+            "Foo.a",
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let mut p = program();
+        p.enums.push(enum_("Color"));
+        p.structs.push(strukt("Foo", "Foo"));
+        check(p).unwrap();
+
+        let mut p = program();
+        p.structs.push(strukt("Color", "Color"));
+        p.enums.push(enum_("Color"));
+        assert_collides(p, "the struct `Color`", "the enum `Color`", "Color");
+    }
+
+    #[test]
+    fn consts() {
+        let mut p = program();
+        p.consts.push(const_("A", "A"));
+        p.consts.push(const_("B", "B"));
+        check(p).unwrap();
+
+        let mut p = program();
+        p.enums.push(enum_("A"));
+        p.consts.push(const_("A", "B"));
+        assert_collides(p, "the enum `A`", "the constant `B`", "A");
+    }
+
+    #[test]
+    fn functions() {
+        let mut p = program();
+        p.exports.push(function("foo", "foo"));
+        p.exports.push(function("bar", "bar"));
+        check(p).unwrap();
+
+        let mut p = program();
+        p.consts.push(const_("foo", "FOO"));
+        p.exports.push(function("foo", "bar"));
+        assert_collides(p, "the constant `FOO`", "the function `bar`", "foo");
+    }
+
+    #[test]
+    fn methods() {
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        p.structs.push(strukt("Bar", "Bar"));
+        p.exports.push(method("Foo", "run", "run"));
+        p.exports.push(method("Bar", "run", "run"));
+        check(p).unwrap();
+
+        let mut p = program();
+        p.structs.push(strukt("JsFoo", "Foo"));
+        p.exports.push(method("JsFoo", "run", "run"));
+        p.exports.push(method("JsFoo", "run", "run2"));
+        assert_collides(
+            p,
+            "the method `Foo::run`",
+            "the method `Foo::run2`",
+            "JsFoo.run",
+        );
+    }
+
+    #[test]
+    fn static_methods() {
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        p.exports.push(method("Foo", "run", "run"));
+        p.exports.push(static_method("Foo", "run", "new_run"));
+        check(p).unwrap();
+
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        p.exports.push(static_method("Foo", "run", "run"));
+        p.exports.push(static_method("Foo", "run", "run2"));
+        assert_collides(
+            p,
+            "the function `Foo::run`",
+            "the function `Foo::run2`",
+            "Foo.run",
+        );
+    }
+
+    #[test]
+    fn getters_and_setters() {
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        let mut get = method("Foo", "x", "x");
+        get.getter = Some("x".to_string());
+        let mut set = method("Foo", "set_x", "set_x");
+        set.setter = Some("x".to_string());
+        p.exports.push(get);
+        p.exports.push(set);
+        check(p).unwrap();
+
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        let mut get = method("Foo", "x", "x");
+        get.getter = Some("x".to_string());
+        let mut get2 = method("Foo", "x2", "x2");
+        get2.getter = Some("x".to_string());
+        p.exports.push(get);
+        p.exports.push(get2);
+        assert_collides(
+            p,
+            "the getter `Foo::x`",
+            "the getter `Foo::x2`",
+            "Foo.x",
+        );
+
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        let mut get = method("Foo", "x", "x");
+        get.getter = Some("x".to_string());
+        p.exports.push(get);
+        p.exports.push(method("Foo", "x", "x_method"));
+        assert_collides(
+            p,
+            "the getter `Foo::x`",
+            "the method `Foo::x_method`",
+            "Foo.x",
+        );
+    }
+
+    #[test]
+    fn symbols() {
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        let mut symbol = method("Foo", "iterator", "iter");
+        symbol.symbol = Some("iterator".to_string());
+        p.exports.push(symbol);
+        p.exports.push(method("Foo", "iterator", "iterator"));
+        check(p).unwrap();
+
+        let mut p = program();
+        p.structs.push(strukt("Foo", "Foo"));
+        let mut a = method("Foo", "a", "a");
+        a.symbol = Some("toPrimitive".to_string());
+        let mut b = method("Foo", "b", "b");
+        b.symbol = Some("toPrimitive".to_string());
+        p.exports.push(a);
+        p.exports.push(b);
+        assert_collides(
+            p,
+            "the method `Foo::a`",
+            "the method `Foo::b`",
+            "Foo.[Symbol.toPrimitive]",
+        );
+    }
+}
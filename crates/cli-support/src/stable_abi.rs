@@ -0,0 +1,73 @@
+//! Reading wasm files built against a stable ABI, with the `stable-abi`
+//! feature of the `wasm-bindgen` crate.
+//!
+//! Normally a wasm file can only be processed by a CLI with exactly the same
+//! schema version as the `wasm-bindgen` crate it was built with. A stable ABI
+//! freezes everything the two need to agree on instead: the schema of the
+//! programs in the custom section, the numbering of descriptors, how each type
+//! crosses the boundary, the names derived by the helpers in
+//! `wasm-bindgen-shared`, and the runtime's own imports and exports, whose
+//! names start with `shared::stable_abi_prefix`.
+//!
+//! Each version this CLI still supports is translated here into what the rest
+//! of the CLI expects. Version 1 is what it expects already, apart from the
+//! prefixes.
+
+use failure::Error;
+use parity_wasm::elements::*;
+use serde_json;
+use shared;
+
+/// Decodes a program built against the stable ABI `version`.
+pub fn decode_program(version: u32, data: &[u8]) -> Result<shared::Program, Error> {
+    match version {
+        // The schema is still the one version 1 was frozen with, as fields
+        // added since have defaults.
+        1 => match serde_json::from_slice(data) {
+            Ok(p) => Ok(p),
+            Err(e) => bail!("failed to decode what looked like wasm-bindgen data: {}", e),
+        },
+        _ => bail!(
+            "this wasm file was built against version {} of wasm-bindgen's stable \
+             ABI, but this binary only supports versions up to {}; you can update \
+             it with\n\n    cargo install -f wasm-bindgen-cli",
+            version,
+            shared::STABLE_ABI_VERSION,
+        ),
+    }
+}
+
+/// Renames the runtime's imports and exports from their names in the stable
+/// ABI `version` to the ones the rest of the CLI looks for.
+pub fn remove_prefixes(module: &mut Module, version: u32) {
+    let prefix = shared::stable_abi_prefix(version);
+    for section in module.sections_mut() {
+        match *section {
+            Section::Import(ref mut s) => {
+                for import in s.entries_mut() {
+                    if import.module() != "__wbindgen_placeholder__" {
+                        continue;
+                    }
+                    if let Some(name) = unprefixed(import.field(), &prefix) {
+                        *import.field_mut() = name;
+                    }
+                }
+            }
+            Section::Export(ref mut s) => {
+                for export in s.entries_mut() {
+                    if let Some(name) = unprefixed(export.field(), &prefix) {
+                        *export.field_mut() = name;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn unprefixed(name: &str, prefix: &str) -> Option<String> {
+    match name.strip_prefix(prefix) {
+        Some(name) if name.starts_with("__wbindgen_") => Some(name.to_string()),
+        _ => None,
+    }
+}
@@ -2,15 +2,19 @@ extern crate wasm_bindgen_cli_support;
 #[macro_use]
 extern crate serde_derive;
 extern crate docopt;
+extern crate serde_json;
 extern crate wasm_bindgen_shared;
 #[macro_use]
 extern crate failure;
 
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::process;
 
 use docopt::Docopt;
-use failure::Error;
+use failure::{Error, ResultExt};
+use wasm_bindgen_cli_support::diagnostic::{Diagnostic, DiagnosticKind};
+use wasm_bindgen_cli_support::interface::{self, Interface};
 use wasm_bindgen_cli_support::Bindgen;
 
 const USAGE: &'static str = "
@@ -18,6 +22,7 @@ Generating JS bindings for a wasm file
 
 Usage:
     wasm-bindgen [options] <input>
+    wasm-bindgen diff-iface <old> <new>
     wasm-bindgen -h | --help
     wasm-bindgen -V | --version
 
@@ -28,11 +33,22 @@ Options:
     --browser                Generate output that only works in a browser
     --no-modules             Generate output that only works in a browser (without modules)
     --no-modules-global VAR  Name of the global variable to initialize
+    --dual-modules           Generate both CommonJS and ES modules for node.js
+    --universal              Generate output that works in browsers, workers and node.js
+    --import-memory          Let `init` be passed the memory and table to use
+    --u64-pairs              Pass 64-bit integers as `{ lo, hi }` without BigInt
     --typescript             Output a TypeScript definition file (on by default)
     --no-typescript          Don't emit a *.d.ts file
     --debug                  Include otherwise-extraneous debug checks in output
     --no-demangle            Don't demangle Rust symbol names
     --keep-debug             Keep debug sections in wasm files
+    --poison-on-panic        Refuse to call into wasm again after it traps
+    --error-hook             Report errors thrown out of wasm to `setWasmErrorHook`
+    --profile-boundary       Count and time every call between JS and wasm
+    --emit-interface         Write a manifest of the JS API for `diff-iface`
+    --message-format FMT     Print warnings and errors as `human` or `json`
+                             [default: human]
+    --strict                 Fail if any item is left out of the bindings
     -V --version             Print the version number of wasm-bindgen
 ";
 
@@ -48,10 +64,44 @@ struct Args {
     flag_version: bool,
     flag_no_demangle: bool,
     flag_no_modules_global: Option<String>,
+    flag_dual_modules: bool,
+    flag_universal: bool,
+    flag_import_memory: bool,
+    flag_u64_pairs: bool,
     flag_keep_debug: bool,
+    flag_poison_on_panic: bool,
+    flag_error_hook: bool,
+    flag_profile_boundary: bool,
+    flag_emit_interface: bool,
+    flag_message_format: MessageFormat,
+    flag_strict: bool,
+    cmd_diff_iface: bool,
+    arg_old: Option<PathBuf>,
+    arg_new: Option<PathBuf>,
     arg_input: Option<PathBuf>,
 }
 
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum MessageFormat {
+    Human,
+    Json,
+}
+
+/// A warning or error as printed with `--message-format json`, one per line
+/// on stdout, for tools which want to show them to users themselves.
+#[derive(Serialize)]
+struct JsonMessage<'a> {
+    level: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<DiagnosticKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item: Option<&'a str>,
+    message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    causes: Vec<String>,
+}
+
 fn main() {
     let args: Args = Docopt::new(USAGE)
         .and_then(|d| d.deserialize())
@@ -65,6 +115,17 @@ fn main() {
         Ok(()) => return,
         Err(e) => e,
     };
+    if args.flag_message_format == MessageFormat::Json {
+        let message = JsonMessage {
+            level: "error",
+            kind: None,
+            item: None,
+            message: err.to_string(),
+            causes: err.iter_causes().map(|c| c.to_string()).collect(),
+        };
+        println!("{}", serde_json::to_string(&message).unwrap());
+        process::exit(1);
+    }
     eprintln!("error: {}", err);
     for cause in err.iter_causes() {
         eprintln!("\tcaused by: {}", cause);
@@ -72,7 +133,31 @@ fn main() {
     process::exit(1);
 }
 
+fn print_warnings(diagnostics: &[Diagnostic], format: MessageFormat) {
+    for diagnostic in diagnostics {
+        match format {
+            MessageFormat::Human => eprintln!("warning: {}", diagnostic),
+            MessageFormat::Json => {
+                let message = JsonMessage {
+                    level: "warning",
+                    kind: Some(diagnostic.kind),
+                    item: Some(&diagnostic.item),
+                    message: diagnostic.message.clone(),
+                    causes: Vec::new(),
+                };
+                println!("{}", serde_json::to_string(&message).unwrap());
+            }
+        }
+    }
+}
+
 fn rmain(args: &Args) -> Result<(), Error> {
+    if args.cmd_diff_iface {
+        let old = args.arg_old.as_ref().unwrap();
+        let new = args.arg_new.as_ref().unwrap();
+        return diff_iface(old, new);
+    }
+
     let input = match args.arg_input {
         Some(ref s) => s,
         None => bail!("input file expected"),
@@ -85,9 +170,18 @@ fn rmain(args: &Args) -> Result<(), Error> {
         .nodejs(args.flag_nodejs)
         .browser(args.flag_browser)
         .no_modules(args.flag_no_modules)
+        .dual_modules(args.flag_dual_modules)
+        .universal(args.flag_universal)
+        .import_memory(args.flag_import_memory)
+        .u64_pairs(args.flag_u64_pairs)
         .debug(args.flag_debug)
         .demangle(!args.flag_no_demangle)
         .keep_debug(args.flag_keep_debug)
+        .poison_on_panic(args.flag_poison_on_panic)
+        .error_hook(args.flag_error_hook)
+        .profile_boundary(args.flag_profile_boundary)
+        .emit_interface(args.flag_emit_interface)
+        .strict(args.flag_strict)
         .typescript(typescript);
     if let Some(ref name) = args.flag_no_modules_global {
         b.no_modules_global(name);
@@ -98,5 +192,31 @@ fn rmain(args: &Args) -> Result<(), Error> {
         None => bail!("the `--out-dir` argument is now required"),
     };
 
-    b.generate(out_dir)
+    let result = b.generate(out_dir);
+    print_warnings(b.diagnostics(), args.flag_message_format);
+    result
+}
+
+fn diff_iface(old: &Path, new: &Path) -> Result<(), Error> {
+    let read = |path: &Path| -> Result<Interface, Error> {
+        let contents =
+            fs::read(path).with_context(|_| format!("failed to read `{}`", path.display()))?;
+        let interface = serde_json::from_slice(&contents)
+            .with_context(|_| format!("failed to parse `{}`", path.display()))?;
+        Ok(interface)
+    };
+    let changes = interface::diff(&read(old)?, &read(new)?);
+    let mut breaking = 0;
+    for change in changes.iter() {
+        if change.is_breaking() {
+            breaking += 1;
+            println!("breaking: {}", change);
+        } else {
+            println!("compatible: {}", change);
+        }
+    }
+    if breaking > 0 {
+        bail!("found {} breaking change(s) to the JS interface", breaking);
+    }
+    Ok(())
 }
@@ -0,0 +1,514 @@
+//! Tests which run the `wasm-bindgen` CLI with its various options over the
+//! wasm file of the crate in `tests/fixture`, checking the JS it generates,
+//! often by running it in node.
+
+extern crate parity_wasm;
+#[macro_use]
+extern crate serde_json;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::{Once, ONCE_INIT};
+
+use parity_wasm::elements::{CustomSection, Internal, Module, Section};
+
+/// Where the fixture is built and the CLI's output is written.
+fn target_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target/cli-tests")
+}
+
+/// Builds the fixture crate for wasm, once for all the tests, returning the
+/// path of its wasm file.
+fn fixture() -> PathBuf {
+    static BUILD: Once = ONCE_INIT;
+    let target_dir = target_dir();
+    BUILD.call_once(|| {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixture/Cargo.toml");
+        let status = Command::new(env::var("CARGO").unwrap_or("cargo".to_string()))
+            .arg("build")
+            .arg("--target=wasm32-unknown-unknown")
+            .arg("--manifest-path")
+            .arg(&manifest)
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "failed to build the fixture crate");
+    });
+    target_dir.join("wasm32-unknown-unknown/debug/cli_fixture.wasm")
+}
+
+/// The `wasm-bindgen` binary built alongside these tests.
+fn wasm_bindgen_bin() -> PathBuf {
+    let mut path = env::current_exe().unwrap();
+    path.pop();
+    if path.ends_with("deps") {
+        path.pop();
+    }
+    path.join(format!("wasm-bindgen{}", env::consts::EXE_SUFFIX))
+}
+
+/// Runs the CLI over `input` with `args`, writing into a fresh output
+/// directory named after the test.
+fn run(test: &str, input: &Path, args: &[&str]) -> (PathBuf, Output) {
+    let out_dir = target_dir().join("out").join(test);
+    drop(fs::remove_dir_all(&out_dir));
+    fs::create_dir_all(&out_dir).unwrap();
+    let output = Command::new(wasm_bindgen_bin())
+        .arg(input)
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .args(args)
+        .output()
+        .unwrap();
+    (out_dir, output)
+}
+
+/// Like `run`, over the fixture, asserting that the CLI succeeds.
+fn generate(test: &str, args: &[&str]) -> PathBuf {
+    let (out_dir, output) = run(test, &fixture(), args);
+    assert!(
+        output.status.success(),
+        "wasm-bindgen failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    out_dir
+}
+
+/// Writes a copy of the fixture without the function describing the types
+/// of the export `name`, so the CLI has to skip it.
+fn fixture_without_descriptor(name: &str) -> PathBuf {
+    let mut module: Module = parity_wasm::deserialize_file(fixture()).unwrap();
+    let describe = format!("__wbindgen_describe_{}", name);
+    for section in module.sections_mut() {
+        if let Section::Export(ref mut s) = *section {
+            s.entries_mut().retain(|e| match *e.internal() {
+                Internal::Function(_) => e.field() != describe,
+                _ => true,
+            });
+        }
+    }
+    let dir = target_dir().join(format!("without_{}", describe));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("cli_fixture.wasm");
+    parity_wasm::serialize_to_file(&path, module).unwrap();
+    path
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+/// Runs `script` in node from `dir`, returning what it prints.
+fn node(dir: &Path, script: &str) -> String {
+    run_node(dir, Command::new("node").arg("-e").arg(script))
+}
+
+/// Like `node`, running `script` as an ES module.
+fn node_module(dir: &Path, script: &str) -> String {
+    fs::write(dir.join("test.mjs"), script).unwrap();
+    run_node(dir, Command::new("node").arg("--experimental-modules").arg("test.mjs"))
+}
+
+fn run_node(dir: &Path, cmd: &mut Command) -> String {
+    let output = cmd.current_dir(dir).output().unwrap();
+    assert!(
+        output.status.success(),
+        "node failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn poison_on_panic() {
+    let dir = generate("poison_on_panic", &["--nodejs", "--poison-on-panic"]);
+    assert!(read(&dir.join("cli_fixture.js")).contains("assertNotPoisoned()"));
+    let out = node(
+        &dir,
+        "
+        const wasm = require('./cli_fixture');
+        try { wasm.trap(); } catch (e) { console.log(e instanceof WebAssembly.RuntimeError); }
+        try { wasm.add(1, 2); } catch (e) { console.log(e.message); }
+        ",
+    );
+    assert_eq!(out, "true\nthe wasm module has panicked and can no longer be used\n");
+
+    // Without the option the module can still be called into.
+    let dir = generate("no_poison_on_panic", &["--nodejs"]);
+    let out = node(
+        &dir,
+        "
+        const wasm = require('./cli_fixture');
+        try { wasm.trap(); } catch (e) {}
+        console.log(wasm.add(1, 2));
+        ",
+    );
+    assert_eq!(out, "3\n");
+}
+
+#[test]
+fn poison_on_panic_reinstantiate() {
+    let dir = generate("poison_on_panic_reinstantiate", &["--no-modules", "--poison-on-panic"]);
+    let out = node(
+        &dir,
+        "
+        global.self = global;
+        require('./cli_fixture');
+        const bytes = require('fs').readFileSync('cli_fixture_bg.wasm');
+        const init = wasm_bindgen;
+        init(new WebAssembly.Module(bytes))
+            .then(() => {
+                try { init.trap(); } catch (e) {}
+                try { init.add(1, 2); } catch (e) { console.log('poisoned'); }
+                return init(init.module);
+            })
+            .then(() => console.log(init.add(1, 2)));
+        ",
+    );
+    assert_eq!(out, "poisoned\n3\n");
+}
+
+#[test]
+fn profile_boundary() {
+    let dir = generate("profile_boundary", &["--nodejs", "--profile-boundary"]);
+    assert!(read(&dir.join("cli_fixture.d.ts")).contains("__wbindgen_profile_report"));
+    let out = node(
+        &dir,
+        "
+        const wasm = require('./cli_fixture');
+        wasm.add(1, 2);
+        wasm.add(3, 4);
+        const report = wasm.__wbindgen_profile_report(true);
+        console.log(report.map(r => `${r.name} ${r.calls} ${r.totalMs >= r.maxMs}`).join());
+        console.log(wasm.__wbindgen_profile_report().length);
+        ",
+    );
+    assert_eq!(out, "add 2 true\n0\n");
+
+    let dir = generate("no_profile_boundary", &["--nodejs"]);
+    assert!(!read(&dir.join("cli_fixture.js")).contains("profile"));
+}
+
+#[test]
+fn string_enum_table() {
+    let dir = generate("string_enum_table", &["--nodejs"]);
+    let js = read(&dir.join("cli_fixture.js"));
+    // The table is shared by the argument and the return value.
+    assert_eq!(js.matches("[\"up\", \"down\"]").count(), 1);
+    assert!(js.contains("const stringEnum0 = [\"up\", \"down\"];"));
+    let ts = read(&dir.join("cli_fixture.d.ts"));
+    assert!(ts.contains("flip(arg0: \"up\" | \"down\"): \"up\" | \"down\";"));
+    let out = node(
+        &dir,
+        "
+        const wasm = require('./cli_fixture');
+        console.log(wasm.flip('up'), wasm.flip('down'));
+        try { wasm.flip('sideways'); } catch (e) { console.log(e.message); }
+        ",
+    );
+    assert_eq!(out, "down up\ninvalid enum value passed\n");
+}
+
+#[test]
+fn emit_interface() {
+    let dir = generate("emit_interface", &["--nodejs", "--emit-interface"]);
+    let path = dir.join("cli_fixture.interface.json");
+    let interface: serde_json::Value = serde_json::from_str(&read(&path)).unwrap();
+    assert_eq!(
+        interface["functions"]["add"],
+        "function add(arg0: number, arg1: number): number;"
+    );
+    assert_eq!(interface["enums"]["Direction"]["Up"], "\"up\"");
+
+    let diff_iface = |old: &Path, new: &Path| {
+        Command::new(wasm_bindgen_bin())
+            .arg("diff-iface")
+            .arg(old)
+            .arg(new)
+            .output()
+            .unwrap()
+    };
+    let output = diff_iface(&path, &path);
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"");
+
+    let mut changed = interface.clone();
+    {
+        let functions = changed["functions"].as_object_mut().unwrap();
+        functions.remove("trap");
+        functions.insert("add".to_string(), "function add(arg0: number): number;".into());
+        functions.insert("sub".to_string(), "function sub(arg0: number): number;".into());
+    }
+    let changed_path = dir.join("changed.interface.json");
+    fs::write(&changed_path, changed.to_string()).unwrap();
+    let output = diff_iface(&path, &changed_path);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "breaking: changed `add` from `function add(arg0: number, arg1: number): number;` \
+         to `function add(arg0: number): number;`\n\
+         breaking: removed `trap`\n\
+         compatible: added `sub`\n"
+    );
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("found 2 breaking change(s) to the JS interface"));
+
+    // Going back the other way removes `sub`, which breaks users too.
+    let output = diff_iface(&changed_path, &path);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn dual_modules() {
+    let dir = generate("dual_modules", &["--dual-modules"]);
+    let package: serde_json::Value =
+        serde_json::from_str(&read(&dir.join("cli_fixture.package.json"))).unwrap();
+    assert_eq!(package["main"], "./cli_fixture.js");
+    assert_eq!(package["module"], "./cli_fixture.mjs");
+    assert_eq!(package["exports"]["."]["require"], "./cli_fixture.js");
+    assert_eq!(package["exports"]["."]["import"], "./cli_fixture.mjs");
+
+    let out = node(&dir, "console.log(require('./cli_fixture').add(1, 2));");
+    assert_eq!(out, "3\n");
+    let out = node_module(
+        &dir,
+        "
+        import { add } from './cli_fixture.mjs';
+        console.log(add(1, 2));
+        ",
+    );
+    assert_eq!(out, "3\n");
+
+    // Both flavors share the one wasm file.
+    let wasm_files = fs::read_dir(&dir)
+        .unwrap()
+        .filter(|e| e.as_ref().unwrap().path().extension() == Some("wasm".as_ref()))
+        .count();
+    assert_eq!(wasm_files, 1);
+    assert!(read(&dir.join("cli_fixture_bg.js")).contains("cli_fixture_bg.wasm"));
+    assert!(read(&dir.join("cli_fixture_bg.mjs")).contains("cli_fixture_bg.wasm"));
+    let (_, output) = run("dual_modules_nodejs", &fixture(), &["--dual-modules", "--nodejs"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("dual module output can't be combined with other output options"));
+}
+
+#[test]
+fn universal() {
+    let dir = generate("universal", &["--universal"]);
+
+    // In node it's a CommonJS module which reads the wasm file itself.
+    let out = node(
+        &dir,
+        "
+        const init = require('./cli_fixture');
+        init().then(() => console.log(init.add(1, 2)));
+        ",
+    );
+    assert_eq!(out, "3\n");
+
+    // Elsewhere it's a script attached to a global which fetches the wasm
+    // file from next to the page.
+    let out = node(
+        &dir,
+        "
+        const fs = require('fs');
+        const vm = require('vm');
+        const fetched = [];
+        // Without `instantiateStreaming`, which needs a real `Response`.
+        const { Module, Instance, Memory, Table, RuntimeError, instantiate } = WebAssembly;
+        const context = {
+            WebAssembly: { Module, Instance, Memory, Table, RuntimeError, instantiate },
+            URL,
+            TextDecoder,
+            console,
+            location: { href: 'https://example.com/app/index.html' },
+            fetch(url) {
+                fetched.push(url);
+                return Promise.resolve({
+                    arrayBuffer: () => Promise.resolve(fs.readFileSync('cli_fixture_bg.wasm')),
+                });
+            },
+        };
+        context.self = context;
+        vm.runInNewContext(fs.readFileSync('cli_fixture.js', 'utf8'), context);
+        const init = context.wasm_bindgen;
+        init().then(() => console.log(fetched.join(), init.add(1, 2)));
+        ",
+    );
+    assert_eq!(out, "https://example.com/app/cli_fixture_bg.wasm 3\n");
+
+    let (_, output) = run("universal_nodejs", &fixture(), &["--universal", "--nodejs"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("universal output can't be combined with other output options"));
+}
+
+#[test]
+fn import_memory() {
+    let dir = generate("import_memory", &["--no-modules", "--import-memory"]);
+    let out = node(
+        &dir,
+        "
+        global.self = global;
+        require('./cli_fixture');
+        const init = wasm_bindgen;
+        const module = new WebAssembly.Module(require('fs').readFileSync('cli_fixture_bg.wasm'));
+        const imports = WebAssembly.Module.imports(module)
+            .filter(i => i.kind === 'memory' || i.kind === 'table')
+            .map(i => `${i.module} ${i.name} ${i.kind}`)
+            .sort();
+        console.log(imports.join());
+        const memory = new WebAssembly.Memory({ initial: 256 });
+        init(module, { memory })
+            .then(() => console.log(init.wasm.memory === memory, init.add(1, 2)))
+            // Without options it creates a memory itself.
+            .then(() => init(module))
+            .then(() => console.log(init.wasm.memory === memory, init.add(1, 2)));
+        ",
+    );
+    assert_eq!(
+        out,
+        "./cli_fixture __wbindgen_function_table table,./cli_fixture __wbindgen_memory memory\n\
+         true 3\n\
+         false 3\n"
+    );
+
+    let (_, output) = run("import_memory_nodejs", &fixture(), &["--import-memory", "--nodejs"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("importing memory requires `--no-modules` or `--universal` output"));
+}
+
+#[test]
+fn rejects_side_modules() {
+    for name in ["dylink", "dylink.0"].iter() {
+        let mut module: Module = parity_wasm::deserialize_file(fixture()).unwrap();
+        let mut section = CustomSection::default();
+        *section.name_mut() = name.to_string();
+        module.sections_mut().insert(0, Section::Custom(section));
+        let input = target_dir().join(format!("side_module_{}.wasm", name));
+        parity_wasm::serialize_to_file(&input, module).unwrap();
+
+        let (_, output) = run("rejects_side_modules", &input, &["--nodejs"]);
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr)
+            .contains("this wasm file was linked as a dynamically loaded side module"));
+    }
+}
+
+#[test]
+fn u64_pairs() {
+    // Engines without `BigInt64Array` pass 64-bit integers as numbers, which
+    // have to be exact, or with `--u64-pairs` also as `{ lo, hi }` pairs.
+    let without_bigint = "
+        delete global.BigInt64Array;
+        const wasm = require('./cli_fixture');
+        const show = f => { try { return JSON.stringify(f()); } catch (e) { return e.name; } };
+    ";
+
+    let dir = generate("u64_pairs", &["--nodejs", "--u64-pairs"]);
+    let out = node(
+        &dir,
+        &format!(
+            "{}
+            console.log(show(() => wasm.negate(5)));
+            console.log(show(() => wasm.negate({{ lo: 0, hi: 1 }})));
+            console.log(show(() => wasm.negate({{ lo: 1, hi: -1 }})));
+            console.log(show(() => wasm.negate(2 ** 60)));
+            ",
+            without_bigint
+        ),
+    );
+    assert_eq!(
+        out,
+        "{\"lo\":4294967291,\"hi\":-1}\n\
+         {\"lo\":0,\"hi\":-1}\n\
+         {\"lo\":4294967295,\"hi\":0}\n\
+         RangeError\n"
+    );
+
+    let dir = generate("no_u64_pairs", &["--nodejs"]);
+    let out = node(
+        &dir,
+        &format!(
+            "{}
+            console.log(show(() => wasm.negate(5)));
+            console.log(show(() => wasm.negate(2 ** 60)));
+            console.log(show(() => wasm.negate({{ lo: 0, hi: 1 }})));
+            ",
+            without_bigint
+        ),
+    );
+    assert_eq!(out, "-5\nRangeError\nRangeError\n");
+
+    // With `BigInt64Array` the option makes no difference.
+    let dir = generate("u64_pairs_bigint", &["--nodejs", "--u64-pairs"]);
+    let out = node(&dir, "console.log(String(require('./cli_fixture').negate(5n)));");
+    assert_eq!(out, "-5\n");
+}
+
+#[test]
+fn message_format_json() {
+    let input = fixture_without_descriptor("add");
+    let (dir, output) = run("message_format_json", &input, &["--nodejs", "--message-format", "json"]);
+    assert!(output.status.success());
+    assert_eq!(output.stderr, b"");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let messages = stdout
+        .lines()
+        .map(|l| serde_json::from_str::<serde_json::Value>(l).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [json!({
+            "level": "warning",
+            "kind": "skipped-member",
+            "item": "add",
+            "message": "no bindings were generated for `add` because the wasm \
+                        module doesn't describe its types",
+        })]
+    );
+    assert!(!read(&dir.join("cli_fixture.js")).contains("exports.add"));
+
+    // The same warning for people.
+    let (_, output) = run("message_format_human", &input, &["--nodejs"]);
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"");
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "warning: no bindings were generated for `add` because the wasm \
+         module doesn't describe its types\n"
+    );
+
+    // Errors are on stdout too, with the errors causing them.
+    let missing = target_dir().join("missing.wasm");
+    let (_, output) = run("message_format_json_error", &missing, &["--message-format", "json"]);
+    assert!(!output.status.success());
+    assert_eq!(output.stderr, b"");
+    let message: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(message["level"], "error");
+    assert_eq!(message["message"], format!("failed to read `{}`", missing.display()));
+    assert_eq!(message["causes"].as_array().unwrap().len(), 1);
+    assert!(message.get("kind").is_none());
+    assert!(message.get("item").is_none());
+}
+
+#[test]
+fn strict() {
+    let (_, output) = run("strict", &fixture(), &["--nodejs", "--strict"]);
+    assert!(output.status.success());
+
+    let input = fixture_without_descriptor("add");
+    let (_, output) = run("strict_skipped", &input, &["--nodejs", "--strict"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "warning: no bindings were generated for `add` because the wasm \
+         module doesn't describe its types\n\
+         error: left 1 item(s) out of the bindings, which strict mode doesn't allow\n"
+    );
+}
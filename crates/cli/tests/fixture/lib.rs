@@ -0,0 +1,37 @@
+//! The crate whose wasm file `tests/cli.rs` runs the `wasm-bindgen` CLI on.
+
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+#[wasm_bindgen]
+pub fn trap() {
+    panic!("trapped")
+}
+
+#[wasm_bindgen]
+pub enum Direction {
+    Up = "up",
+    Down = "down",
+}
+
+#[wasm_bindgen]
+pub fn flip(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Down,
+        Direction::Down => Direction::Up,
+        _ => unreachable!(),
+    }
+}
+
+#[wasm_bindgen]
+pub fn negate(a: i64) -> i64 {
+    -a
+}
@@ -0,0 +1,166 @@
+//! Smoke tests examples which have already been built with their `build.sh`,
+//! by loading each one in a headless browser and checking that its wasm is
+//! instantiated and that it runs without throwing or logging any errors.
+//!
+//! ```text
+//! $ cargo run -p example-tests -- examples/hello_world examples/webgl
+//! ```
+//!
+//! The browser is driven the same way and configured with the same
+//! environment variables, like `GECKODRIVER`, as `wasm-bindgen-test-runner`
+//! does for headless tests.
+
+extern crate curl;
+extern crate env_logger;
+#[macro_use]
+extern crate failure;
+#[macro_use]
+extern crate log;
+extern crate rouille;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+
+use failure::{Error, ResultExt};
+use rouille::{Response, Server};
+
+#[path = "../../cli/src/bin/wasm-bindgen-test-runner/headless.rs"]
+mod headless;
+#[path = "../../cli/src/bin/wasm-bindgen-test-runner/shell.rs"]
+mod shell;
+
+/// What each example does once it's loaded, if it waits for input before
+/// calling into its wasm, as the body of an `async` function.
+const INTERACTIONS: &[(&str, &str)] = &[
+    ("char", "
+        document.getElementById('add-counter').click();
+        document.querySelector('.plus-button').click();
+    "),
+    ("closures", "
+        document.getElementById('green-square').click();
+        if (document.getElementById('num-clicks').textContent != '1')
+            throw new Error('clicking the green square wasn\\'t counted');
+    "),
+    ("comments", "
+        document.getElementById('name').value = 'name';
+        document.getElementById('comment').value = 'comment';
+        document.getElementById('add-comment-button').click();
+        if (document.querySelector('#comments .comment') === null)
+            throw new Error('the comment wasn\\'t added');
+    "),
+    ("julia_set", "
+        document.getElementById('render').click();
+    "),
+    ("wasm-in-web-worker", "
+        document.getElementById('n').value = '1000';
+        document.getElementById('count').click();
+        const result = document.getElementById('result');
+        while (!result.textContent.startsWith('There are')) {
+            if (result.textContent.startsWith('Failed'))
+                throw new Error(result.textContent);
+            await new Promise(resolve => setTimeout(resolve, 100));
+        }
+    "),
+    ("webaudio", "
+        document.querySelector('input[onclick]').click();
+        document.getElementById('organ').click();
+        document.getElementById('fade').click();
+    "),
+];
+
+/// Examples which shouldn't use WebAssembly at all once they're built.
+const WITHOUT_WASM: &[&str] = &["asm.js"];
+
+fn main() {
+    env_logger::init();
+    let shell = shell::Shell::new();
+
+    let examples = env::args_os().skip(1).map(PathBuf::from).collect::<Vec<_>>();
+    if examples.is_empty() {
+        eprintln!("error: must have the directories of the examples to test as arguments");
+        process::exit(1);
+    }
+
+    let mut failed = Vec::new();
+    for example in examples {
+        println!("smoke testing {}", example.display());
+        if let Err(err) = smoke_test(&example, &shell) {
+            println!("error: {}", err);
+            for cause in err.iter_causes() {
+                println!("\tcaused by: {}", cause);
+            }
+            failed.push(example);
+        }
+    }
+
+    if !failed.is_empty() {
+        println!("failed examples:");
+        for example in failed {
+            println!("    {}", example.display());
+        }
+        process::exit(1);
+    }
+}
+
+fn smoke_test(example: &Path, shell: &shell::Shell) -> Result<(), Error> {
+    let name = match example.file_name().and_then(|s| s.to_str()) {
+        Some(name) => name,
+        None => bail!("{} isn't the directory of an example", example.display()),
+    };
+    let index = fs::read_to_string(example.join("index.html"))
+        .context(format!("failed to read {}/index.html", example.display()))?;
+
+    // The example's page is served with a script of our own running before
+    // any of the example's, which watches for errors and reports how it went
+    // in the same way that the test harness does.
+    let interaction = INTERACTIONS
+        .iter()
+        .find(|(example, _)| *example == name)
+        .map(|(_, interaction)| *interaction)
+        .unwrap_or("");
+    let prelude = include_str!("prelude.js")
+        .replace("USES_WASM", &(!WITHOUT_WASM.contains(&name)).to_string())
+        .replace("/* INTERACTION */", interaction);
+    let index = match index.find("<head>") {
+        Some(i) => format!(
+            "{}<script>{}</script>{}",
+            &index[..i + "<head>".len()],
+            prelude,
+            &index[i + "<head>".len()..],
+        ),
+        None => format!("<script>{}</script>{}", prelude, index),
+    };
+
+    // Examples built with webpack have their bundle in `dist` and everything
+    // else they need, like the page itself, at the top of the example, so
+    // assets are looked for in both.
+    let example = example.to_path_buf();
+    let srv = Server::new("127.0.0.1:0", move |request| {
+        if request.url() == "/" || request.url() == "/index.html" {
+            return Response::html(index.clone())
+        }
+        let mut response = rouille::match_assets(request, &example.join("dist"));
+        if !response.is_success() {
+            response = rouille::match_assets(request, &example);
+        }
+        response.headers.retain(|(k, _)| k != "Cache-Control");
+
+        // `WebAssembly.instantiateStreaming` requires the right MIME type.
+        if request.url().ends_with(".wasm") {
+            response = response.with_unique_header("Content-Type", "application/wasm");
+        }
+        response
+    }).map_err(|e| format_err!("{}", e))?;
+    let addr = srv.server_addr();
+    thread::spawn(move || srv.run());
+
+    headless::run(&addr, shell)
+}
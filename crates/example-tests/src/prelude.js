@@ -0,0 +1,89 @@
+// Runs before any of an example's own scripts to record any errors it runs
+// into, and once it's finished reports whether there were any in the
+// `#output` element that `wasm-bindgen-test-runner` scrapes for test results.
+(function() {
+  const usesWasm = USES_WASM;
+  const logs = [];
+  const errors = [];
+
+  const log = console.log;
+  console.log = function(...args) {
+    logs.push(args.join(' '));
+    log.apply(this, args);
+  };
+  const error = console.error;
+  console.error = function(...args) {
+    errors.push(args.join(' '));
+    error.apply(this, args);
+  };
+  window.alert = message => console.log(`alert: ${message}`);
+  window.addEventListener('error', event => {
+    errors.push(`uncaught ${event.error ? event.error.stack || event.error : event.message}`);
+  });
+  window.addEventListener('unhandledrejection', event => {
+    errors.push(`unhandled rejection: ${event.reason}`);
+  });
+
+  // Examples instantiating their wasm at all is the first thing to check,
+  // and for those which shouldn't it's taken away from them.
+  let wasmInstantiated;
+  const instantiated = new Promise(resolve => wasmInstantiated = resolve);
+  if (usesWasm) {
+    for (const name of ['instantiate', 'instantiateStreaming']) {
+      const instantiate = WebAssembly[name];
+      if (!instantiate)
+        continue;
+      WebAssembly[name] = function(...args) {
+        const result = instantiate.apply(this, args);
+        result.then(wasmInstantiated, () => {});
+        return result;
+      };
+    }
+  } else {
+    delete window.WebAssembly;
+  }
+
+  const delay = ms => new Promise(resolve => setTimeout(resolve, ms));
+  const timeout = (promise, what) => Promise.race([
+    promise,
+    delay(10000).then(() => { throw new Error(`timed out waiting for ${what}`); }),
+  ]);
+
+  async function interact() {
+    /* INTERACTION */
+  }
+
+  async function run() {
+    await new Promise(resolve => window.addEventListener('load', resolve));
+    if (usesWasm)
+      await timeout(instantiated, 'the wasm module to be instantiated');
+
+    // Give the example a moment to do what it does once it's loaded, before
+    // doing whatever it's waiting for the user to do.
+    await delay(1000);
+    await timeout(interact(), 'the example to respond');
+    await delay(1000);
+  }
+
+  const pre = id => {
+    const pre = document.createElement('pre');
+    pre.id = id;
+    document.body.appendChild(pre);
+    return pre;
+  };
+  document.addEventListener('DOMContentLoaded', () => {
+    const output = pre('output');
+    const consoleLog = pre('console_log');
+    const consoleError = pre('console_error');
+
+    run()
+      .catch(e => errors.push(`${e}`))
+      .then(() => {
+        consoleLog.textContent = logs.join('\n');
+        consoleError.textContent = errors.join('\n');
+        output.textContent = errors.length == 0
+          ? 'test result: ok'
+          : `test result: FAILED. ${errors.length} errors`;
+      });
+  });
+})();
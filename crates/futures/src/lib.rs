@@ -7,7 +7,7 @@
 //! ability to interoperate with JavaScript events and JavaScript I/O
 //! primitives.
 //!
-//! There are two main interfaces in this crate currently:
+//! There are three main interfaces in this crate currently:
 //!
 //! 1. [**`JsFuture`**](./struct.JsFuture.html)
 //!
@@ -21,7 +21,13 @@
 //!    JavaScript `Promise`. The future's result will translate to either a
 //!    rejected or resolved `Promise` in JavaScript.
 //!
-//! These two items should provide enough of a bridge to interoperate the two
+//! 3. [**`stream_to_async_iterator`**](./fn.stream_to_async_iterator.html)
+//!
+//!    Converts a Rust `Stream<Item = JsValue, Error = JsValue>` into a
+//!    JavaScript async iterator, which can be consumed with a
+//!    `for await (...)` loop.
+//!
+//! These items should provide enough of a bridge to interoperate the two
 //! systems and make sure that Rust/JavaScript can work together with
 //! asynchronous and I/O work.
 //!
@@ -110,13 +116,15 @@ extern crate futures;
 extern crate wasm_bindgen;
 extern crate js_sys;
 
+use std::cell::{Cell, RefCell};
+use std::mem;
+use std::rc::Rc;
 use std::sync::Arc;
-use std::cell::{RefCell, Cell};
 
 use futures::executor::{self, Spawn, Notify};
 use futures::prelude::*;
 use futures::sync::oneshot;
-use js_sys::{Function, Promise};
+use js_sys::{Error, Function, Object, Promise, Reflect};
 use wasm_bindgen::prelude::*;
 
 /// A Rust `Future` backed by a JavaScript `Promise`.
@@ -360,3 +368,267 @@ fn _future_to_promise(future: Box<Future<Item = JsValue, Error = JsValue>>) -> P
         }
     }
 }
+
+/// Converts a Rust `Stream` into a JavaScript async iterator.
+///
+/// The returned `StreamIterator` can be passed to JavaScript, where it can be
+/// consumed with `for await (const item of iterator)`. Each item of the stream
+/// is a value of the iterator, and if the stream fails then the iteration is
+/// ended by rejecting the promise of the next item with the stream's error.
+///
+/// Like `future_to_promise`, the stream is polled in the background whenever
+/// JavaScript asks for its next item.
+pub fn stream_to_async_iterator<S>(stream: S) -> StreamIterator
+    where S: Stream<Item = JsValue, Error = JsValue> + 'static,
+{
+    StreamIterator {
+        state: Rc::new(RefCell::new(StreamState::Idle(Box::new(stream)))),
+    }
+}
+
+/// A JavaScript async iterator backed by a Rust `Stream`.
+///
+/// This type is created with `stream_to_async_iterator`, and implements the
+/// async iteration protocol in JavaScript.
+#[wasm_bindgen(async_iterator)]
+pub struct StreamIterator {
+    state: Rc<RefCell<StreamState>>,
+}
+
+enum StreamState {
+    // The stream is waiting for `next` to be called.
+    Idle(Box<Stream<Item = JsValue, Error = JsValue>>),
+
+    // A call to `next` is waiting for the stream to produce an item.
+    Busy,
+
+    // The stream has ended, been cancelled, or failed.
+    Done,
+}
+
+#[wasm_bindgen]
+impl StreamIterator {
+    /// Returns a `Promise` which resolves to an iterator result for the next
+    /// item of the stream.
+    ///
+    /// Only one item can be requested at a time, so the `Promise` is rejected
+    /// if the previous one hasn't resolved yet.
+    pub fn next(&mut self) -> Promise {
+        let state = mem::replace(&mut *self.state.borrow_mut(), StreamState::Busy);
+        let stream = match state {
+            StreamState::Idle(stream) => stream,
+            StreamState::Busy => {
+                let error = Error::new("the previous item of the stream isn't ready yet");
+                return Promise::reject(&error.into());
+            }
+            StreamState::Done => {
+                *self.state.borrow_mut() = StreamState::Done;
+                return Promise::resolve(&iterator_result(None));
+            }
+        };
+
+        // If the iterator is cancelled with `return` while we're waiting for
+        // an item then the stream is dropped once the item arrives.
+        let ok = self.state.clone();
+        let err = self.state.clone();
+        let future = stream
+            .into_future()
+            .map(move |(item, stream)| {
+                let mut state = ok.borrow_mut();
+                if let StreamState::Busy = *state {
+                    *state = match item {
+                        Some(_) => StreamState::Idle(stream),
+                        None => StreamState::Done,
+                    };
+                }
+                iterator_result(item)
+            }).map_err(move |(error, _stream)| {
+                *err.borrow_mut() = StreamState::Done;
+                error
+            });
+        future_to_promise(future)
+    }
+
+    /// Ends the iteration early, dropping the stream.
+    ///
+    /// This is called by `for await` loops which exit before the stream has
+    /// ended.
+    #[wasm_bindgen(js_name = "return")]
+    pub fn return_(&mut self) -> Promise {
+        *self.state.borrow_mut() = StreamState::Done;
+        Promise::resolve(&iterator_result(None))
+    }
+}
+
+// Creates an object of the form `{ value, done }`, which is what the promises
+// returned from an async iterator's `next` method resolve to.
+fn iterator_result(value: Option<JsValue>) -> JsValue {
+    let result = JsValue::from(Object::new());
+    let done = value.is_none();
+    let value = value.unwrap_or(JsValue::undefined());
+    Reflect::set(&result, &JsValue::from("value"), &value);
+    Reflect::set(&result, &JsValue::from("done"), &JsValue::from(done));
+    result
+}
+
+// Support for `#[wasm_bindgen] async fn`, whose futures the generated shims
+// hand to `async_to_promise` below. These are `std::future::Future`s rather
+// than the `futures` 0.1 ones used by the rest of this crate, so they get a
+// separate (and much smaller) executor built on `spawn_microtask`.
+#[doc(hidden)]
+pub mod __rt {
+    use std::cell::{Cell, RefCell};
+    use std::future::Future;
+    use std::mem;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    use js_sys::{Function, Promise};
+    use wasm_bindgen::prelude::*;
+
+    /// The values an exported `async fn` may return.
+    pub trait IntoJsResult {
+        fn into_js_result(self) -> Result<JsValue, JsValue>;
+    }
+
+    impl IntoJsResult for () {
+        fn into_js_result(self) -> Result<JsValue, JsValue> {
+            Ok(JsValue::undefined())
+        }
+    }
+
+    impl IntoJsResult for JsValue {
+        fn into_js_result(self) -> Result<JsValue, JsValue> {
+            Ok(self)
+        }
+    }
+
+    impl<E: Into<JsValue>> IntoJsResult for Result<(), E> {
+        fn into_js_result(self) -> Result<JsValue, JsValue> {
+            self.map(|()| JsValue::undefined()).map_err(Into::into)
+        }
+    }
+
+    impl<E: Into<JsValue>> IntoJsResult for Result<JsValue, E> {
+        fn into_js_result(self) -> Result<JsValue, JsValue> {
+            self.map_err(Into::into)
+        }
+    }
+
+    /// Returns a `Promise` which is resolved with the output of `future`, or
+    /// rejected with it if it's an `Err`.
+    ///
+    /// The future is polled once immediately, and then again on the microtask
+    /// queue each time it's woken up.
+    pub fn async_to_promise<F>(future: F) -> Promise
+        where F: Future + 'static,
+              F::Output: IntoJsResult,
+    {
+        let future = Box::pin(IntoJs(future)) as Pin<Box<Future<Output = _>>>;
+        let mut future = Some(future);
+        Promise::new(&mut |resolve, reject| {
+            Task::poll(&Rc::new(Task {
+                future: RefCell::new(future.take()),
+                resolve,
+                reject,
+                queued: Cell::new(false),
+            }));
+        })
+    }
+
+    // Adapts a future's output into what the `Promise` is settled with.
+    struct IntoJs<F>(F);
+
+    impl<F> Future for IntoJs<F>
+        where F: Future,
+              F::Output: IntoJsResult,
+    {
+        type Output = Result<JsValue, JsValue>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+            // The inner future is never moved out of `IntoJs`.
+            let future = unsafe { self.map_unchecked_mut(|me| &mut me.0) };
+            future.poll(cx).map(IntoJsResult::into_js_result)
+        }
+    }
+
+    struct Task {
+        // The future being run, which is taken out once it has completed.
+        future: RefCell<Option<Pin<Box<Future<Output = Result<JsValue, JsValue>>>>>>,
+
+        // The callbacks of the `Promise` returned to JavaScript.
+        resolve: Function,
+        reject: Function,
+
+        // Whether a poll has already been queued up by a wake-up, in which
+        // case more wake-ups can be ignored until it has run.
+        queued: Cell<bool>,
+    }
+
+    impl Task {
+        fn poll(me: &Rc<Task>) {
+            me.queued.set(false);
+            let result = {
+                let mut slot = me.future.borrow_mut();
+                let result = match slot.as_mut() {
+                    Some(future) => {
+                        let waker = unsafe { Waker::from_raw(raw_waker(me.clone())) };
+                        match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+                            Poll::Ready(result) => result,
+                            Poll::Pending => return,
+                        }
+                    }
+                    // A stale wake-up after the future has completed.
+                    None => return,
+                };
+                *slot = None;
+                result
+            };
+            let (val, f) = match result {
+                Ok(val) => (val, &me.resolve),
+                Err(val) => (val, &me.reject),
+            };
+            drop(f.call1(&JsValue::undefined(), &val));
+        }
+
+        // Polling is always deferred to a microtask, even for wake-ups that
+        // happen while the future is being polled, so the future is never
+        // polled reentrantly.
+        fn wake(me: Rc<Task>) {
+            if me.queued.replace(true) {
+                return;
+            }
+            ::wasm_bindgen::spawn_microtask(move || Task::poll(&me));
+        }
+    }
+
+    // Wasm is single threaded, so the waker can be an `Rc<Task>` despite
+    // wakers being `Send`.
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop_waker);
+
+    fn raw_waker(task: Rc<Task>) -> RawWaker {
+        RawWaker::new(Rc::into_raw(task) as *const (), &VTABLE)
+    }
+
+    unsafe fn clone(ptr: *const ()) -> RawWaker {
+        let task = Rc::from_raw(ptr as *const Task);
+        let ret = raw_waker(task.clone());
+        mem::forget(task);
+        ret
+    }
+
+    unsafe fn wake(ptr: *const ()) {
+        Task::wake(Rc::from_raw(ptr as *const Task));
+    }
+
+    unsafe fn wake_by_ref(ptr: *const ()) {
+        let task = Rc::from_raw(ptr as *const Task);
+        Task::wake(task.clone());
+        mem::forget(task);
+    }
+
+    unsafe fn drop_waker(ptr: *const ()) {
+        drop(Rc::from_raw(ptr as *const Task));
+    }
+}
@@ -0,0 +1,16 @@
+exports.join_async = async iterator => {
+  const items = [];
+  for await (const item of iterator)
+    items.push(item);
+  return items.join(',');
+};
+
+exports.break_after_first = async iterator => {
+  for await (const item of iterator) {
+    if (item !== 1)
+      throw new Error('wrong first item');
+    break;
+  }
+  const next = await iterator.next();
+  return next.done;
+};
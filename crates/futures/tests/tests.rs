@@ -7,11 +7,18 @@ extern crate wasm_bindgen;
 extern crate wasm_bindgen_futures;
 extern crate wasm_bindgen_test;
 
-use futures::Future;
+use futures::{stream, Future};
+use js_sys::Promise;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::{future_to_promise, JsFuture};
+use wasm_bindgen_futures::{future_to_promise, stream_to_async_iterator, JsFuture, StreamIterator};
 use wasm_bindgen_test::*;
 
+#[wasm_bindgen(module = "tests/tests.js")]
+extern {
+    fn join_async(iterator: StreamIterator) -> Promise;
+    fn break_after_first(iterator: StreamIterator) -> Promise;
+}
+
 #[wasm_bindgen_test(async)]
 fn promise_resolve_is_ok_future() -> impl Future<Item = (), Error = JsValue> {
     let p = js_sys::Promise::resolve(&JsValue::from(42));
@@ -49,3 +56,136 @@ fn error_future_is_rejected_promise() -> impl Future<Item = (), Error = JsValue>
         Ok(())
     })
 }
+
+#[wasm_bindgen_test(async)]
+fn stream_is_async_iterator() -> impl Future<Item = (), Error = JsValue> {
+    let items = vec![JsValue::from(1), JsValue::from(2), JsValue::from(3)];
+    let iterator = stream_to_async_iterator(stream::iter_ok(items));
+    JsFuture::from(join_async(iterator))
+        .map(|x| {
+            assert_eq!(x, "1,2,3");
+        }).map_err(|_| unreachable!())
+}
+
+#[wasm_bindgen_test(async)]
+fn error_stream_rejects_iteration() -> impl Future<Item = (), Error = JsValue> {
+    let items = vec![Ok(JsValue::from(1)), Err(JsValue::from(42))];
+    let iterator = stream_to_async_iterator(stream::iter_result(items));
+    JsFuture::from(join_async(iterator)).map(|_| unreachable!()).or_else(|e| {
+        assert_eq!(e, 42);
+        Ok(())
+    })
+}
+
+#[wasm_bindgen_test(async)]
+fn break_ends_stream() -> impl Future<Item = (), Error = JsValue> {
+    let items = vec![JsValue::from(1), JsValue::from(2)];
+    let iterator = stream_to_async_iterator(stream::iter_ok(items));
+    JsFuture::from(break_after_first(iterator))
+        .map(|x| {
+            assert_eq!(x, true);
+        }).map_err(|_| unreachable!())
+}
+
+#[wasm_bindgen_test(async)]
+fn promise_new_with_resolves() -> impl Future<Item = (), Error = JsValue> {
+    let p = Promise::new_with(|resolve, _reject| {
+        resolve.call1(&JsValue::undefined(), &JsValue::from(42)).unwrap();
+    });
+    JsFuture::from(p)
+        .map(|x| {
+            assert_eq!(x, 42);
+        }).map_err(|_| unreachable!())
+}
+
+#[wasm_bindgen_test(async)]
+fn promise_then_with_chains() -> impl Future<Item = (), Error = JsValue> {
+    let p = Promise::resolve(&JsValue::from(1))
+        .then_with(|x| Ok(JsValue::from(x.as_f64().unwrap() + 1.0)))
+        .then_with(|x| Ok(JsValue::from(x.as_f64().unwrap() * 10.0)));
+    JsFuture::from(p)
+        .map(|x| {
+            assert_eq!(x, 20);
+        }).map_err(|_| unreachable!())
+}
+
+#[wasm_bindgen_test(async)]
+fn promise_then_with_error_rejects() -> impl Future<Item = (), Error = JsValue> {
+    let p = Promise::resolve(&JsValue::from(1))
+        .then_with(|_| Err(JsValue::from("nope")))
+        .then_with(|_| unreachable!());
+    JsFuture::from(p).map(|_| unreachable!()).or_else(|e| {
+        assert_eq!(e, "nope");
+        Ok(())
+    })
+}
+
+#[wasm_bindgen_test(async)]
+fn promise_then2_with_picks_callback() -> impl Future<Item = (), Error = JsValue> {
+    let p = Promise::reject(&JsValue::from(42))
+        .then2_with(|_| unreachable!(), |e| Ok(JsValue::from(e.as_f64().unwrap() + 1.0)));
+    JsFuture::from(p)
+        .map(|x| {
+            assert_eq!(x, 43);
+        }).map_err(|_| unreachable!())
+}
+
+#[wasm_bindgen_test(async)]
+fn promise_catch_with_recovers() -> impl Future<Item = (), Error = JsValue> {
+    let p = Promise::reject(&JsValue::from(42))
+        .then_with(|_| unreachable!())
+        .catch_with(|e| {
+            assert_eq!(e, 42);
+            Ok(JsValue::from("recovered"))
+        })
+        .catch_with(|_| unreachable!());
+    JsFuture::from(p)
+        .map(|x| {
+            assert_eq!(x, "recovered");
+        }).map_err(|_| unreachable!())
+}
+
+#[wasm_bindgen_test(async)]
+fn promise_finally_with_passes_through() -> impl Future<Item = (), Error = JsValue> {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let ran = Rc::new(Cell::new(0));
+    let ran2 = ran.clone();
+    let ran3 = ran.clone();
+    let ok = Promise::resolve(&JsValue::from(1))
+        .finally_with(move || ran2.set(ran2.get() + 1));
+    let err = Promise::reject(&JsValue::from(2))
+        .finally_with(move || ran3.set(ran3.get() + 1));
+    JsFuture::from(ok)
+        .map(|x| {
+            assert_eq!(x, 1);
+        })
+        .and_then(move |()| JsFuture::from(err))
+        .map(|_| unreachable!())
+        .or_else(move |e| {
+            assert_eq!(e, 2);
+            assert_eq!(ran.get(), 2);
+            Ok(())
+        })
+}
+
+#[wasm_bindgen_test(async)]
+fn spawn_microtask_runs_later() -> impl Future<Item = (), Error = JsValue> {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let ran = Rc::new(Cell::new(false));
+    let ran2 = ran.clone();
+    let p = Promise::new_with(move |resolve, _reject| {
+        wasm_bindgen::spawn_microtask(move || {
+            ran2.set(true);
+            resolve.call0(&JsValue::undefined()).unwrap();
+        });
+    });
+    assert!(!ran.get());
+    JsFuture::from(p)
+        .map(move |_| {
+            assert!(ran.get());
+        }).map_err(|_| unreachable!())
+}
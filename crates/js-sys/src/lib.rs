@@ -21,10 +21,15 @@
 
 extern crate wasm_bindgen;
 
-use std::mem;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::rc::Rc;
 
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 
 // When adding new imports:
 //
@@ -196,6 +201,14 @@ extern "C" {
     #[wasm_bindgen(method, js_name = forEach)]
     pub fn for_each(this: &Array, callback: &mut FnMut(JsValue, u32, Array));
 
+    /// Retrieves the element at the index `index` (counting from 0), the same
+    /// as `array[index]` in JavaScript. Out of bounds indices return
+    /// `undefined`.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array#Accessing_array_elements
+    #[wasm_bindgen(method, indexing_getter)]
+    pub fn get(this: &Array, index: u32) -> JsValue;
+
     /// The includes() method determines whether an array includes a certain
     /// element, returning true or false as appropriate.
     ///
@@ -319,6 +332,14 @@ extern "C" {
     #[wasm_bindgen(method)]
     pub fn reverse(this: &Array) -> Array;
 
+    /// Sets the element at the index `index` (counting from 0) to `value`, the
+    /// same as `array[index] = value` in JavaScript. Setting an index past the
+    /// end of the array extends its length.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array#Accessing_array_elements
+    #[wasm_bindgen(method, indexing_setter)]
+    pub fn set(this: &Array, index: u32, value: &JsValue);
+
     /// The shift() method removes the first element from an array and returns
     /// that removed element. This method changes the length of the array.
     ///
@@ -382,6 +403,132 @@ extern "C" {
     pub fn unshift(this: &Array, value: &JsValue) -> u32;
 }
 
+// TypedJsArray
+
+/// An `Array` whose elements are all expected to be instances of the imported
+/// JS type `T`.
+///
+/// JS arrays can hold anything, so every element read through this wrapper is
+/// checked with `instanceof` before being handed out as a `T`. Note that this
+/// means primitive values, such as a `"string"` rather than a `new
+/// String("string")`, are never instances of the wrapper types in this crate.
+pub struct TypedJsArray<T> {
+    array: Array,
+    _marker: PhantomData<T>,
+}
+
+impl<T: JsCast> TypedJsArray<T> {
+    /// Creates a new, empty array.
+    pub fn new() -> TypedJsArray<T> {
+        TypedJsArray::from_array(Array::new())
+    }
+
+    /// Wraps an existing `Array`.
+    ///
+    /// The elements are not checked here, but rather each time they're read.
+    pub fn from_array(array: Array) -> TypedJsArray<T> {
+        TypedJsArray {
+            array,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the underlying `Array`.
+    pub fn as_array(&self) -> &Array {
+        &self.array
+    }
+
+    /// Unwraps this into the underlying `Array`.
+    pub fn into_array(self) -> Array {
+        self.array
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn length(&self) -> u32 {
+        self.array.length()
+    }
+
+    /// Returns the element at `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds or if the element there is
+    /// not an instance of `T`.
+    pub fn get(&self, index: u32) -> Option<T> {
+        if index >= self.length() {
+            return None;
+        }
+        self.array.get(index).dyn_into().ok()
+    }
+
+    /// Appends `value` to the end of the array, returning the new length.
+    pub fn push(&self, value: &T) -> u32 {
+        self.array.push(value.as_ref())
+    }
+
+    /// Returns an iterator over the elements of the array.
+    ///
+    /// Each element that is an instance of `T` is yielded as `Ok`, and each
+    /// one that isn't is yielded as an `Err` holding the original value.
+    pub fn iter(&self) -> TypedJsArrayIter<T> {
+        TypedJsArrayIter {
+            array: self,
+            index: 0,
+        }
+    }
+}
+
+impl<T> Clone for TypedJsArray<T> {
+    fn clone(&self) -> TypedJsArray<T> {
+        TypedJsArray {
+            array: self.array.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for TypedJsArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.array.fmt(f)
+    }
+}
+
+impl<T: JsCast> From<Array> for TypedJsArray<T> {
+    fn from(array: Array) -> TypedJsArray<T> {
+        TypedJsArray::from_array(array)
+    }
+}
+
+impl<T> From<TypedJsArray<T>> for Array {
+    fn from(array: TypedJsArray<T>) -> Array {
+        array.array
+    }
+}
+
+impl<T> From<TypedJsArray<T>> for JsValue {
+    fn from(array: TypedJsArray<T>) -> JsValue {
+        array.array.into()
+    }
+}
+
+/// An iterator over the elements of a `TypedJsArray`, created by
+/// `TypedJsArray::iter`.
+pub struct TypedJsArrayIter<'a, T: 'a> {
+    array: &'a TypedJsArray<T>,
+    index: u32,
+}
+
+impl<'a, T: JsCast> std::iter::Iterator for TypedJsArrayIter<'a, T> {
+    type Item = Result<T, JsValue>;
+
+    fn next(&mut self) -> Option<Result<T, JsValue>> {
+        if self.index >= self.array.length() {
+            return None;
+        }
+        let value = self.array.array.get(self.index);
+        self.index += 1;
+        Some(value.dyn_into())
+    }
+}
+
 // ArrayBuffer
 #[wasm_bindgen]
 extern "C" {
@@ -470,6 +617,13 @@ extern "C" {
     #[wasm_bindgen(constructor)]
     pub fn new(value: &JsValue) -> Boolean;
 
+    /// The `toString()` method returns a string representing the specified
+    /// Boolean object.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Boolean/toString
+    #[wasm_bindgen(method, js_name = toString)]
+    pub fn to_string(this: &Boolean) -> JsString;
+
     /// The `valueOf()` method returns the primitive value of a `Boolean` object.
     ///
     /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Boolean/valueOf
@@ -477,6 +631,52 @@ extern "C" {
     pub fn value_of(this: &Boolean) -> bool;
 }
 
+impl Boolean {
+    /// Returns the `Boolean` value of this JS value if it's a primitive
+    /// boolean.
+    ///
+    /// If this JS value is not a boolean then this returns `None`.
+    pub fn try_from(val: &JsValue) -> Option<&Boolean> {
+        if val.as_bool().is_some() {
+            Some(unsafe { mem::transmute(val) })
+        } else {
+            None
+        }
+    }
+}
+
+impl PartialEq for Boolean {
+    fn eq(&self, other: &Boolean) -> bool {
+        self.value_of() == other.value_of()
+    }
+}
+
+impl PartialEq<bool> for Boolean {
+    fn eq(&self, other: &bool) -> bool {
+        self.value_of() == *other
+    }
+}
+
+impl From<bool> for Boolean {
+    fn from(b: bool) -> Boolean {
+        Boolean {
+            obj: JsValue::from(b),
+        }
+    }
+}
+
+impl<'a> From<&'a Boolean> for bool {
+    fn from(b: &'a Boolean) -> bool {
+        b.value_of()
+    }
+}
+
+impl From<Boolean> for bool {
+    fn from(b: Boolean) -> bool {
+        b.value_of()
+    }
+}
+
 // DataView
 #[wasm_bindgen]
 extern "C" {
@@ -1545,6 +1745,112 @@ extern "C" {
     pub fn value_of(this: &Number) -> f64;
 }
 
+impl Number {
+    /// The difference between 1 and the smallest floating point number
+    /// greater than 1.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/EPSILON
+    pub const EPSILON: f64 = std::f64::EPSILON;
+
+    /// The maximum safe integer in JavaScript (`2^53 - 1`).
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_SAFE_INTEGER
+    pub const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+    /// The largest positive representable number.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_VALUE
+    pub const MAX_VALUE: f64 = std::f64::MAX;
+
+    /// The minimum safe integer in JavaScript (`-(2^53 - 1)`).
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MIN_SAFE_INTEGER
+    pub const MIN_SAFE_INTEGER: f64 = -9007199254740991.0;
+
+    /// The smallest positive representable number, that is the positive
+    /// number closest to zero (without actually being zero).
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MIN_VALUE
+    pub const MIN_VALUE: f64 = 5E-324;
+
+    /// Special "Not a Number" value.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/NaN
+    pub const NAN: f64 = std::f64::NAN;
+
+    /// Special value representing negative infinity, returned on overflow.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/NEGATIVE_INFINITY
+    pub const NEGATIVE_INFINITY: f64 = std::f64::NEG_INFINITY;
+
+    /// Special value representing infinity, returned on overflow.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/POSITIVE_INFINITY
+    pub const POSITIVE_INFINITY: f64 = std::f64::INFINITY;
+
+    /// Returns the `Number` value of this JS value if it's a primitive
+    /// number.
+    ///
+    /// If this JS value is not a number then this returns `None`.
+    pub fn try_from(val: &JsValue) -> Option<&Number> {
+        if val.as_f64().is_some() {
+            Some(unsafe { mem::transmute(val) })
+        } else {
+            None
+        }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Number) -> bool {
+        self.value_of() == other.value_of()
+    }
+}
+
+impl PartialEq<f64> for Number {
+    fn eq(&self, other: &f64) -> bool {
+        self.value_of() == *other
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Number) -> Option<std::cmp::Ordering> {
+        self.value_of().partial_cmp(&other.value_of())
+    }
+}
+
+impl PartialOrd<f64> for Number {
+    fn partial_cmp(&self, other: &f64) -> Option<std::cmp::Ordering> {
+        self.value_of().partial_cmp(other)
+    }
+}
+
+macro_rules! number_from {
+    ($($n:ident)*) => ($(
+        impl From<$n> for Number {
+            fn from(n: $n) -> Number {
+                Number {
+                    obj: JsValue::from(n),
+                }
+            }
+        }
+    )*)
+}
+
+number_from! { i8 u8 i16 u16 i32 u32 f32 f64 }
+
+impl<'a> From<&'a Number> for f64 {
+    fn from(n: &'a Number) -> f64 {
+        n.value_of()
+    }
+}
+
+impl From<Number> for f64 {
+    fn from(n: Number) -> f64 {
+        n.value_of()
+    }
+}
+
 // Date.
 #[wasm_bindgen]
 extern "C" {
@@ -2412,6 +2718,111 @@ extern {
     pub fn unicode(this: &RegExp) -> bool;
 }
 
+impl RegExp {
+    /// Like `exec`, but converts a successful match into a `RegExpMatch`
+    /// rather than leaving it as an `Array` with extra properties.
+    ///
+    /// As with `exec`, global and sticky regular expressions start matching
+    /// at `last_index` and update it afterwards.
+    pub fn exec_match(&self, text: &str) -> Option<RegExpMatch> {
+        self.exec(text).map(|result| RegExpMatch::from_exec_result(&result))
+    }
+
+    /// Returns every match of this regular expression in `text`.
+    ///
+    /// Regular expressions without the `g` flag match at most once. The
+    /// `last_index` of global regular expressions is reset to 0 before and
+    /// after matching.
+    pub fn match_all(&self, text: &str) -> Vec<RegExpMatch> {
+        if !self.global() {
+            return self.exec_match(text).into_iter().collect();
+        }
+        let mut matches = Vec::new();
+        self.set_last_index(0);
+        while let Some(m) = self.exec_match(text) {
+            // Step past empty matches so they're not found again forever.
+            if m.matched.is_empty() {
+                let last_index = self.last_index();
+                self.set_last_index(last_index + 1);
+            }
+            matches.push(m);
+        }
+        self.set_last_index(0);
+        matches
+    }
+}
+
+/// A successful match of a `RegExp`, as returned by `RegExp::exec_match`.
+///
+/// All indices are offsets into the matched string in UTF-16 code units, as
+/// they are in JS.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegExpMatch {
+    /// The text matched by the whole regular expression.
+    pub matched: String,
+    /// The index at which the match starts.
+    pub index: u32,
+    /// The text matched by each numbered capture group, starting from group
+    /// 1. Groups which didn't participate in the match are `None`.
+    pub captures: Vec<Option<String>>,
+    /// The text matched by each named capture group.
+    pub groups: BTreeMap<String, Option<String>>,
+    /// The `(start, end)` indices of the whole match followed by those of each
+    /// numbered capture group, if the regular expression has the `d` flag.
+    pub indices: Option<Vec<Option<(u32, u32)>>>,
+}
+
+impl RegExpMatch {
+    fn from_exec_result(result: &Array) -> RegExpMatch {
+        let matched = result.get(0).as_string().unwrap_or_default();
+        let index = Reflect::get(result.as_ref(), &"index".into()).as_f64().unwrap_or(0.0) as u32;
+        let captures = (1..result.length())
+            .map(|i| result.get(i).as_string())
+            .collect();
+
+        let mut groups = BTreeMap::new();
+        let group_object = Reflect::get(result.as_ref(), &"groups".into());
+        if group_object.is_object() {
+            let names = Object::keys(group_object.unchecked_ref());
+            for i in 0..names.length() {
+                let name = names.get(i);
+                let value = Reflect::get(&group_object, &name).as_string();
+                groups.insert(name.as_string().unwrap_or_default(), value);
+            }
+        }
+
+        let indices = Reflect::get(result.as_ref(), &"indices".into());
+        let indices = if indices.is_object() {
+            let indices: &Array = indices.unchecked_ref();
+            Some(
+                (0..indices.length())
+                    .map(|i| {
+                        let pair = indices.get(i);
+                        if pair.is_object() {
+                            let pair: &Array = pair.unchecked_ref();
+                            let start = pair.get(0).as_f64().unwrap_or(0.0) as u32;
+                            let end = pair.get(1).as_f64().unwrap_or(0.0) as u32;
+                            Some((start, end))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+        RegExpMatch {
+            matched,
+            index,
+            captures,
+            groups,
+            indices,
+        }
+    }
+}
+
 // Set
 #[wasm_bindgen]
 extern {
@@ -2751,6 +3162,51 @@ extern "C" {
     pub fn byte_offset(this: &Uint32Array) -> u32;
 }
 
+macro_rules! typed_array_copies {
+    ($($name:ident: $t:ident)*) => ($(
+        impl $name {
+            /// Copies the contents of this array into `dst` with one
+            /// `TypedArray.prototype.set`, which is much faster than copying
+            /// the elements one at a time.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `dst` isn't the same length as this array.
+            pub fn copy_to(&self, dst: &mut [$t]) {
+                assert_eq!(self.length() as usize, dst.len());
+                unsafe {
+                    wasm_bindgen::__rt::copy_to_wasm(self.as_ref(), dst.as_mut_ptr(), dst.len());
+                }
+            }
+
+            /// Copies `src` into this array with one
+            /// `TypedArray.prototype.set`, the reverse of `copy_to`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `src` isn't the same length as this array.
+            pub fn copy_from(&self, src: &[$t]) {
+                assert_eq!(self.length() as usize, src.len());
+                unsafe {
+                    wasm_bindgen::__rt::copy_from_wasm(self.as_ref(), src.as_ptr(), src.len());
+                }
+            }
+        }
+    )*)
+}
+
+typed_array_copies! {
+    Int8Array: i8
+    Int16Array: i16
+    Int32Array: i32
+    Uint8Array: u8
+    Uint8ClampedArray: u8
+    Uint16Array: u16
+    Uint32Array: u32
+    Float32Array: f32
+    Float64Array: f64
+}
+
 // URIError
 #[wasm_bindgen]
 extern {
@@ -2996,6 +3452,14 @@ extern "C" {
     #[wasm_bindgen(method, js_class = "String", js_name = lastIndexOf)]
     pub fn last_index_of(this: &JsString, search_value: &str, from_index: i32) -> i32;
 
+    /// The `localeCompare()` method returns a number indicating whether a
+    /// reference string comes before or after or is the same as the given
+    /// string in sort order.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+    #[wasm_bindgen(method, js_class = "String", js_name = localeCompare)]
+    pub fn locale_compare(this: &JsString, compare_string: &str, locales: &Array, options: &Object) -> i32;
+
     /// The normalize() method returns the Unicode Normalization Form
     /// of a given string (if the value isn't a string, it will be converted to one first).
     ///
@@ -3028,6 +3492,13 @@ extern "C" {
     #[wasm_bindgen(method, js_class = "String")]
     pub fn repeat(this: &JsString, count: i32) -> JsString;
 
+    /// The `replace()` method returns a new string with the first match of a
+    /// `pattern` replaced by a `replacement`.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn replace(this: &JsString, pattern: &str, replacement: &str) -> JsString;
+
     /// The `slice()` method extracts a section of a string and returns it as a
     /// new string, without modifying the original string.
     ///
@@ -3035,6 +3506,21 @@ extern "C" {
     #[wasm_bindgen(method, js_class = "String")]
     pub fn slice(this: &JsString, start: u32, end: u32) -> JsString;
 
+    /// The `split()` method splits a String object into an array of strings by
+    /// separating the string into substrings.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn split(this: &JsString, separator: &str) -> Array;
+
+    /// The `split()` method splits a String object into an array of strings by
+    /// separating the string into substrings, returning at most `limit` of
+    /// them.
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split
+    #[wasm_bindgen(method, js_class = "String", js_name = split)]
+    pub fn split_limit(this: &JsString, separator: &str, limit: u32) -> Array;
+
     /// The `startsWith()` method determines whether a string begins with the
     /// characters of a specified string, returning true or false as
     /// appropriate.
@@ -3149,6 +3635,103 @@ impl JsString {
             None
         }
     }
+
+    /// Creates a string from its UTF-16 code units.
+    ///
+    /// Any sequence of code units is accepted, including ones with unpaired
+    /// surrogates, so this round-trips losslessly with `as_code_units`.
+    pub fn from_code_units(units: &[u16]) -> JsString {
+        JsString {
+            obj: JsValue::from_utf16(units),
+        }
+    }
+
+    /// Copies the UTF-16 code units of this string into a `Vec<u16>`.
+    ///
+    /// Unlike converting to a `String`, which replaces unpaired surrogates
+    /// with U+FFFD, this preserves the string exactly.
+    pub fn as_code_units(&self) -> Vec<u16> {
+        match self.obj.as_utf16() {
+            Some(units) => units,
+            // `String` objects, rather than primitive strings, are read one
+            // code unit at a time.
+            None => self.iter().collect(),
+        }
+    }
+
+    /// Returns whether this string is well-formed UTF-16, that is whether
+    /// every surrogate in it is part of a pair.
+    ///
+    /// Only well-formed strings can be converted to a `String` without loss.
+    pub fn is_valid_utf16(&self) -> bool {
+        std::char::decode_utf16(self.as_code_units()).all(|c| c.is_ok())
+    }
+
+    /// Converts this string to a `String`, returning `None` rather than
+    /// replacing unpaired surrogates if it isn't well-formed UTF-16.
+    pub fn as_valid_string(&self) -> Option<String> {
+        String::from_utf16(&self.as_code_units()).ok()
+    }
+
+    /// Returns an iterator over the UTF-16 code units of this string.
+    ///
+    /// JS strings aren't guaranteed to be valid UTF-16, so the code units may
+    /// include unpaired surrogates.
+    pub fn iter(&self) -> JsStringIter {
+        JsStringIter {
+            string: self,
+            index: 0,
+            length: self.length(),
+        }
+    }
+
+    /// Returns an iterator over the `char`s of this string, decoded from its
+    /// UTF-16 code units.
+    ///
+    /// Unpaired surrogates are yielded as errors rather than being replaced.
+    pub fn chars(&self) -> std::char::DecodeUtf16<JsStringIter> {
+        std::char::decode_utf16(self.iter())
+    }
+}
+
+/// An iterator over the UTF-16 code units of a `JsString`, created by
+/// `JsString::iter`.
+pub struct JsStringIter<'a> {
+    string: &'a JsString,
+    index: u32,
+    length: u32,
+}
+
+impl<'a> std::iter::Iterator for JsStringIter<'a> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if self.index >= self.length {
+            return None;
+        }
+        let unit = self.string.char_code_at(self.index) as u16;
+        self.index += 1;
+        Some(unit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.length - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Strings are compared by their UTF-16 code units, just like JS's `===` and
+/// `<` operators compare primitive strings.
+impl PartialEq for JsString {
+    fn eq(&self, other: &JsString) -> bool {
+        self.length() == other.length() && self.iter().eq(other.iter())
+    }
+}
+
+impl PartialOrd for JsString {
+    fn partial_cmp(&self, other: &JsString) -> Option<std::cmp::Ordering> {
+        Some(self.iter().cmp(other.iter()))
+    }
 }
 
 impl PartialEq<str> for JsString {
@@ -3189,6 +3772,8 @@ impl From<String> for JsString {
     }
 }
 
+/// Any unpaired surrogates in the string are replaced with U+FFFD. Use
+/// `JsString::as_valid_string` or `JsString::as_code_units` to avoid the loss.
 impl<'a> From<&'a JsString> for String {
     fn from(s: &'a JsString) -> Self {
         s.obj.as_string().unwrap()
@@ -3635,4 +4220,119 @@ extern {
     /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
     #[wasm_bindgen(method)]
     pub fn finally(this: &Promise, cb: &Closure<FnMut()>) -> Promise;
+
+    #[wasm_bindgen(method, js_name = then)]
+    fn then_map(this: &Promise,
+                resolve: &Closure<FnMut(JsValue) -> JsValue>,
+                reject: &Closure<FnMut(JsValue) -> JsValue>) -> Promise;
+}
+
+type PromiseCallbacks = (
+    Closure<FnMut(JsValue) -> JsValue>,
+    Closure<FnMut(JsValue) -> JsValue>,
+);
+
+impl Promise {
+    /// Creates a new `Promise`, running `executor` immediately with the
+    /// promise's `resolve` and `reject` functions.
+    ///
+    /// Unlike `Promise::new` the executor may consume its environment, so it
+    /// can move the `resolve` and `reject` functions into other closures.
+    pub fn new_with<F>(executor: F) -> Promise
+        where F: FnOnce(Function, Function)
+    {
+        let mut executor = Some(executor);
+        Promise::new(&mut |resolve, reject| {
+            if let Some(executor) = executor.take() {
+                executor(resolve, reject);
+            }
+        })
+    }
+
+    /// Registers `f` to run with this promise's value once it's fulfilled.
+    ///
+    /// The returned promise is resolved with the value `f` returns, or
+    /// rejected with its error. If this promise is rejected then `f` is not
+    /// run and the returned promise is rejected with the same reason.
+    ///
+    /// The closure is kept alive until this promise settles and is cleaned up
+    /// afterwards, so there's no `Closure` to manage or `forget`. Note that if
+    /// this promise never settles the closure is never freed.
+    pub fn then_with<F>(&self, f: F) -> Promise
+        where F: FnOnce(JsValue) -> Result<JsValue, JsValue> + 'static
+    {
+        self.settle_with(move |result| result.and_then(f))
+    }
+
+    /// Same as `then_with`, except that `reject` is run with the reason if
+    /// this promise is rejected.
+    pub fn then2_with<F, G>(&self, resolve: F, reject: G) -> Promise
+        where F: FnOnce(JsValue) -> Result<JsValue, JsValue> + 'static,
+              G: FnOnce(JsValue) -> Result<JsValue, JsValue> + 'static,
+    {
+        self.settle_with(move |result| match result {
+            Ok(value) => resolve(value),
+            Err(reason) => reject(reason),
+        })
+    }
+
+    /// Registers `f` to run with the reason this promise is rejected with.
+    ///
+    /// The returned promise settles according to what `f` returns. If this
+    /// promise is fulfilled then `f` is not run and the returned promise is
+    /// fulfilled with the same value.
+    pub fn catch_with<F>(&self, f: F) -> Promise
+        where F: FnOnce(JsValue) -> Result<JsValue, JsValue> + 'static
+    {
+        self.settle_with(move |result| result.or_else(f))
+    }
+
+    /// Registers `f` to run once this promise settles, whether it's fulfilled
+    /// or rejected.
+    ///
+    /// The returned promise settles the same way as this one.
+    pub fn finally_with<F>(&self, f: F) -> Promise
+        where F: FnOnce() + 'static
+    {
+        self.settle_with(move |result| {
+            f();
+            result
+        })
+    }
+
+    fn settle_with<F>(&self, f: F) -> Promise
+        where F: FnOnce(Result<JsValue, JsValue>) -> Result<JsValue, JsValue> + 'static
+    {
+        // Both callbacks hold on to `callbacks`, and `callbacks` holds on to
+        // both of them. Whichever is invoked breaks the cycle by dropping the
+        // pair, which is fine to do while one of them is still running.
+        let f = Rc::new(RefCell::new(Some(f)));
+        let callbacks: Rc<RefCell<Option<PromiseCallbacks>>> = Rc::new(RefCell::new(None));
+
+        let settle = {
+            let callbacks = callbacks.clone();
+            move |result| {
+                drop(callbacks.borrow_mut().take());
+                let f = f.borrow_mut()
+                    .take()
+                    .expect("promise callback invoked more than once");
+                match f(result) {
+                    Ok(value) => value,
+                    Err(reason) => Promise::reject(&reason).into(),
+                }
+            }
+        };
+        let settle = Rc::new(settle);
+        let resolve = {
+            let settle = settle.clone();
+            Closure::wrap(Box::new(move |value| settle(Ok(value)))
+                as Box<FnMut(JsValue) -> JsValue>)
+        };
+        let reject = Closure::wrap(Box::new(move |reason| settle(Err(reason)))
+            as Box<FnMut(JsValue) -> JsValue>);
+
+        let ret = self.then_map(&resolve, &reject);
+        *callbacks.borrow_mut() = Some((resolve, reject));
+        ret
+    }
 }
@@ -307,3 +307,43 @@ fn array_inheritance() {
     assert!(array.is_instance_of::<Array>());
     assert!(array.is_instance_of::<Object>());
 }
+
+#[wasm_bindgen_test]
+fn get_and_set() {
+    let array = js_array!["a", "b"];
+    assert_eq!(array.get(0), "a");
+    assert_eq!(array.get(1), "b");
+    assert!(array.get(2).is_undefined());
+
+    array.set(1, &"c".into());
+    array.set(2, &"d".into());
+    assert_eq!(to_rust(&array), array!["a", "c", "d"]);
+}
+
+#[wasm_bindgen_test]
+fn typed_js_array() {
+    let array = TypedJsArray::<Array>::new();
+    assert_eq!(array.length(), 0);
+    assert!(array.get(0).is_none());
+
+    assert_eq!(array.push(&js_array![1]), 1);
+    assert_eq!(array.push(&js_array![2, 3]), 2);
+    assert_eq!(array.get(1).unwrap().length(), 2);
+    assert!(array.get(2).is_none());
+
+    array.as_array().push(&"not an array".into());
+    assert_eq!(array.length(), 3);
+    assert!(array.get(2).is_none());
+
+    let lengths = array
+        .iter()
+        .map(|x| x.map(|x| x.length()))
+        .collect::<Vec<_>>();
+    assert_eq!(lengths[..2], [Ok(1), Ok(2)]);
+    assert_eq!(lengths[2], Err(JsValue::from("not an array")));
+
+    let array: TypedJsArray<Array> = js_array![js_array![], 1].into();
+    assert!(array.get(0).is_some());
+    assert!(array.get(1).is_none());
+    assert_eq!(array.into_array().length(), 2);
+}
@@ -19,3 +19,23 @@ fn boolean_inheritance() {
     assert!(b.is_instance_of::<Boolean>());
     assert!(b.is_instance_of::<Object>());
 }
+
+#[wasm_bindgen_test]
+fn to_string() {
+    assert_eq!(Boolean::new(&JsValue::from(true)).to_string(), "true");
+    assert_eq!(Boolean::from(false).to_string(), "false");
+}
+
+#[wasm_bindgen_test]
+fn try_from() {
+    assert_eq!(Boolean::try_from(&JsValue::TRUE).unwrap().value_of(), true);
+    assert!(Boolean::try_from(&JsValue::from(1)).is_none());
+}
+
+#[wasm_bindgen_test]
+fn conversions() {
+    assert_eq!(bool::from(Boolean::from(true)), true);
+    assert_eq!(bool::from(&Boolean::new(&JsValue::from(0))), false);
+    assert_eq!(Boolean::from(true), Boolean::new(&JsValue::from(1)));
+    assert_eq!(Boolean::from(false), false);
+}
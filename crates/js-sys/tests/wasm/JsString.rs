@@ -288,3 +288,75 @@ fn value_of() {
     let greeting = JsString::from("Hello world!");
     assert_eq!(greeting.value_of(), "Hello world!");
 }
+
+#[wasm_bindgen_test]
+fn locale_compare() {
+    let a = JsString::from("a");
+    assert_eq!(a.locale_compare("a", &Array::new(), &Object::new()), 0);
+    assert!(a.locale_compare("b", &Array::new(), &Object::new()) < 0);
+}
+
+#[wasm_bindgen_test]
+fn replace() {
+    let s = JsString::from("The quick brown fox, the quick dog");
+    assert_eq!(s.replace("quick", "slow"), "The slow brown fox, the quick dog");
+}
+
+#[wasm_bindgen_test]
+fn split() {
+    let s = JsString::from("a,b,,c");
+    let parts = s.split(",");
+    assert_eq!(parts.length(), 4);
+    assert_eq!(parts.get(2), "");
+    assert_eq!(s.split_limit(",", 2).length(), 2);
+}
+
+#[wasm_bindgen_test]
+fn iter() {
+    let s = JsString::from("a😀");
+    assert_eq!(s.iter().collect::<Vec<u16>>(), [0x61, 0xd83d, 0xde00]);
+    assert_eq!(s.iter().size_hint(), (3, Some(3)));
+    assert_eq!(
+        s.chars().collect::<Result<String, _>>().unwrap(),
+        "a😀",
+    );
+
+    let lone = JsString::from_char_code2(0x61, 0xd83d);
+    let chars = lone.chars().collect::<Vec<_>>();
+    assert_eq!(chars[0], Ok('a'));
+    assert_eq!(chars[1].as_ref().unwrap_err().unpaired_surrogate(), 0xd83d);
+}
+
+#[wasm_bindgen_test]
+fn comparisons() {
+    assert_eq!(JsString::from("abc"), JsString::from("abc"));
+    assert_eq!(JsString::from("hi"), JsString::from(new_string_object()));
+    assert!(JsString::from("abc") != JsString::from("abd"));
+    assert!(JsString::from("abc") < JsString::from("abd"));
+    assert!(JsString::from("ab") < JsString::from("abc"));
+    // UTF-16 ordering puts surrogates below U+E000..U+FFFF, unlike UTF-8.
+    assert!(JsString::from("😀") < JsString::from("\u{ff61}"));
+}
+
+#[wasm_bindgen_test]
+fn code_units() {
+    let s = JsString::from("a😀");
+    assert_eq!(s.as_code_units(), [0x61, 0xd83d, 0xde00]);
+    assert!(s.is_valid_utf16());
+    assert_eq!(s.as_valid_string().unwrap(), "a😀");
+
+    let units = [0x61, 0xd83d, 0x62];
+    let lone = JsString::from_code_units(&units);
+    assert_eq!(lone.length(), 3);
+    assert_eq!(lone.char_code_at(1), 0xd83d as f64);
+    assert_eq!(lone.as_code_units(), units);
+    assert!(!lone.is_valid_utf16());
+    assert!(lone.as_valid_string().is_none());
+    assert_eq!(String::from(&lone), "a\u{fffd}b");
+
+    let object = JsString::from(new_string_object());
+    assert_eq!(object.as_code_units(), [0x68, 0x69]);
+
+    let long = (0..10000).map(|i| (i % 0x3000) as u16 + 0x20).collect::<Vec<_>>();
+    assert_eq!(JsString::from_code_units(&long).as_code_units(), long);
+}
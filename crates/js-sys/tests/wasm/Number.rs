@@ -112,3 +112,39 @@ fn number_inheritance() {
     assert!(n.is_instance_of::<Number>());
     assert!(n.is_instance_of::<Object>());
 }
+
+#[wasm_bindgen_test]
+fn constants() {
+    assert!(Number::is_safe_integer(&Number::MAX_SAFE_INTEGER.into()));
+    assert!(!Number::is_safe_integer(&(Number::MAX_SAFE_INTEGER + 1.).into()));
+    assert!(Number::is_safe_integer(&Number::MIN_SAFE_INTEGER.into()));
+    assert!(Number::MIN_VALUE > 0.);
+    assert_eq!(Number::MIN_VALUE / 2., 0.);
+    assert!(Number::is_nan(&Number::NAN.into()));
+    assert!(!Number::is_finite(&Number::POSITIVE_INFINITY.into()));
+}
+
+#[wasm_bindgen_test]
+fn try_from() {
+    assert_eq!(Number::try_from(&42.into()).unwrap().value_of(), 42.);
+    assert!(Number::try_from(&"42".into()).is_none());
+    assert!(Number::try_from(&JsValue::undefined()).is_none());
+}
+
+#[wasm_bindgen_test]
+fn conversions() {
+    assert_eq!(Number::from(42u8), 42.);
+    assert_eq!(Number::from(-1i32), -1.);
+    assert_eq!(Number::from(0.5f32), 0.5);
+    assert_eq!(f64::from(Number::new(&3.into())), 3.);
+    assert_eq!(f64::from(&Number::from(4)), 4.);
+}
+
+#[wasm_bindgen_test]
+fn comparisons() {
+    assert_eq!(Number::from(1), Number::new(&1.into()));
+    assert!(Number::from(1) < Number::from(2));
+    assert!(Number::from(3) > 2.);
+    assert!(Number::from(NAN) != Number::from(NAN));
+    assert!(Number::from(NAN).partial_cmp(&Number::from(1)).is_none());
+}
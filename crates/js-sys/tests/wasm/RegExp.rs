@@ -154,3 +154,50 @@ fn unicode() {
     let re = RegExp::new("\u{61}", "u");
     assert!(re.unicode());
 }
+
+#[wasm_bindgen_test]
+fn exec_match() {
+    let re = RegExp::new("(?<year>\\d{4})-(?<month>\\d{2})(-(\\d{2}))?", "");
+    let m = re.exec_match("released 2018-09").unwrap();
+    assert_eq!(m.matched, "2018-09");
+    assert_eq!(m.index, 9);
+    assert_eq!(
+        m.captures,
+        [Some("2018".to_string()), Some("09".to_string()), None, None],
+    );
+    assert_eq!(m.groups["year"], Some("2018".to_string()));
+    assert_eq!(m.groups["month"], Some("09".to_string()));
+    assert_eq!(m.groups.len(), 2);
+    assert!(m.indices.is_none());
+
+    assert!(re.exec_match("no dates here").is_none());
+}
+
+#[wasm_bindgen_test]
+fn exec_match_indices() {
+    let re = RegExp::new("a(b)?(c)", "d");
+    let m = re.exec_match("xxac").unwrap();
+    assert_eq!(m.indices, Some(vec![Some((2, 4)), None, Some((3, 4))]));
+}
+
+#[wasm_bindgen_test]
+fn match_all() {
+    let re = RegExp::new("o(\\w)", "g");
+    let matches = re.match_all("foo bob root");
+    let found = matches.iter().map(|m| (m.index, &m.captures[0])).collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        [
+            (1, &Some("o".to_string())),
+            (5, &Some("b".to_string())),
+            (9, &Some("o".to_string())),
+        ],
+    );
+    assert_eq!(re.last_index(), 0);
+
+    let re = RegExp::new("x*", "g");
+    assert_eq!(re.match_all("ab").len(), 3);
+
+    let re = RegExp::new("o", "");
+    assert_eq!(re.match_all("foo").len(), 1);
+}
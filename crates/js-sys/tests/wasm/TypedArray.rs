@@ -88,3 +88,22 @@ macro_rules! test_fill {
 fn new_fill() {
     each!(test_fill);
 }
+
+macro_rules! test_copy {
+    ($arr:ident) => ({
+        let arr = $arr::new(&3.into());
+        arr.copy_from(&[1 as _, 2 as _, 3 as _]);
+        arr.for_each(&mut |x, i, _| {
+            assert_eq!(x as f64, (i + 1) as f64);
+        });
+
+        arr.fill(7 as _, 1, 2);
+        let mut dst = [0 as _; 3];
+        arr.copy_to(&mut dst);
+        assert_eq!(dst, [1 as _, 7 as _, 3 as _]);
+    })
+}
+#[wasm_bindgen_test]
+fn copy_to_and_from() {
+    each!(test_copy);
+}
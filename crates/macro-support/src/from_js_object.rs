@@ -0,0 +1,191 @@
+//! Implementation of `#[derive(FromJsObject)]`, which reads the fields of a
+//! struct out of the named properties of a JS object.
+//!
+//! Each field is read through a structural, catching getter on a private
+//! imported type, so the glue is the same as for a hand-written
+//! `#[wasm_bindgen(method, structural, getter)]` import.
+
+use backend::ast;
+use backend::util::ShortHash;
+use backend::{Diagnostic, TryToTokens};
+use parser::{BindgenAttrs, MacroParse};
+use proc_macro2::{Ident, Span, TokenStream};
+use syn;
+
+struct Field {
+    name: Ident,
+    js_name: Ident,
+    shim: Ident,
+    /// The type read out of the JS object, which is what's inside of the
+    /// `Option` for optional fields.
+    ty: syn::Type,
+    kind: FieldKind,
+}
+
+enum FieldKind {
+    Required,
+    Optional,
+    Default,
+}
+
+pub fn expand(input: TokenStream) -> Result<TokenStream, Diagnostic> {
+    let s = super::syn_parse::<syn::ItemStruct>(input, "struct")?;
+    if s.generics.params.len() > 0 {
+        bail_span!(
+            s.generics,
+            "structs deriving FromJsObject cannot have lifetime or type \
+             parameters currently"
+        );
+    }
+    let named = match s.fields {
+        syn::Fields::Named(ref named) => named,
+        _ => bail_span!(s, "structs deriving FromJsObject must have named fields"),
+    };
+
+    let mut fields = Vec::new();
+    let mut errors = Vec::new();
+    for field in named.named.iter() {
+        match field_from_syn(&s.ident, field) {
+            Ok(f) => fields.push(f),
+            Err(e) => errors.push(e),
+        }
+    }
+    Diagnostic::from_vec(errors)?;
+
+    let getters = fields.iter().map(|f| {
+        let js_name = &f.js_name;
+        let shim = &f.shim;
+        let ty = &f.ty;
+        quote! {
+            #[wasm_bindgen(method, structural, catch, getter = #js_name)]
+            fn #shim(this: &__wbg_FromJsObject)
+                -> Result<Option<#ty>, ::wasm_bindgen::JsValue>;
+        }
+    });
+    let foreign_mod: syn::ItemForeignMod = parse_quote! {
+        extern {
+            type __wbg_FromJsObject;
+            #(#getters)*
+        }
+    };
+    let mut program = ast::Program::default();
+    foreign_mod.macro_parse(&mut program, BindgenAttrs::default())?;
+    let imports = program.try_to_token_stream()?;
+
+    let name = &s.ident;
+    let names = fields.iter().map(|f| &f.name);
+    let values = fields.iter().map(|f| {
+        let shim = &f.shim;
+        match f.kind {
+            FieldKind::Optional => quote! { obj.#shim()? },
+            FieldKind::Default => quote! { obj.#shim()?.unwrap_or_default() },
+            FieldKind::Required => {
+                let msg = format!("missing property `{}`", f.js_name);
+                quote! {
+                    match obj.#shim()? {
+                        Some(value) => value,
+                        None => return Err(::wasm_bindgen::JsValue::from_str(#msg)),
+                    }
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        impl ::wasm_bindgen::FromJsObject for #name {
+            fn from_js_object(obj: &::wasm_bindgen::JsValue)
+                -> Result<#name, ::wasm_bindgen::JsValue>
+            {
+                #imports
+                let obj = <::wasm_bindgen::JsValue as ::wasm_bindgen::JsCast>
+                    ::unchecked_ref::<__wbg_FromJsObject>(obj);
+                Ok(#name {
+                    #(#names: #values,)*
+                })
+            }
+        }
+    })
+}
+
+fn field_from_syn(struct_name: &Ident, field: &syn::Field) -> Result<Field, Diagnostic> {
+    let name = field.ident.clone().unwrap();
+    let mut js_name = name.to_string();
+    let mut default = false;
+    for attr in field.attrs.iter() {
+        if attr.path.segments.len() != 1 || attr.path.segments[0].ident != "js_object" {
+            continue;
+        }
+        let list = match attr.interpret_meta() {
+            Some(syn::Meta::List(list)) => list,
+            _ => bail_span!(attr, "malformed #[js_object] attribute"),
+        };
+        for nested in list.nested.iter() {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::Word(ref word)) if word == "default" => {
+                    default = true;
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(ref nv)) if nv.ident == "js_name" => {
+                    match nv.lit {
+                        syn::Lit::Str(ref s) => js_name = s.value(),
+                        _ => bail_span!(nv.lit, "js_name must be a string literal"),
+                    }
+                }
+                _ => bail_span!(nested, "unknown #[js_object] attribute"),
+            }
+        }
+    }
+    if syn::parse_str::<Ident>(&js_name).is_err() {
+        bail_span!(
+            name,
+            "FromJsObject can only read properties whose names are valid \
+             identifiers, `{}` is not",
+            js_name
+        );
+    }
+
+    let (ty, kind) = match option_inner(&field.ty) {
+        Some(ty) => {
+            if default {
+                bail_span!(name, "`Option` fields already default to `None`");
+            }
+            (ty.clone(), FieldKind::Optional)
+        }
+        None if default => (field.ty.clone(), FieldKind::Default),
+        None => (field.ty.clone(), FieldKind::Required),
+    };
+    Ok(Field {
+        shim: Ident::new(
+            &format!(
+                "__wbg_from_js_object_{}_{}_{}",
+                struct_name,
+                name,
+                ShortHash((struct_name, &name))
+            ),
+            Span::call_site(),
+        ),
+        js_name: Ident::new(&js_name, Span::call_site()),
+        name,
+        ty,
+        kind,
+    })
+}
+
+/// Returns `T` if `ty` is syntactically `Option<T>`.
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let path = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path,
+        _ => return None,
+    };
+    let seg = path.segments.last()?.into_value();
+    if seg.ident != "Option" {
+        return None;
+    }
+    match seg.arguments {
+        syn::PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => {
+            match args.args[0] {
+                syn::GenericArgument::Type(ref ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -4,6 +4,7 @@
 #![doc(html_root_url = "https://docs.rs/wasm-bindgen-macro-support/0.2")]
 
 extern crate proc_macro2;
+#[macro_use]
 extern crate quote;
 #[macro_use]
 extern crate syn;
@@ -14,22 +15,85 @@ extern crate wasm_bindgen_shared as shared;
 pub use parser::BindgenAttrs;
 use parser::MacroParse;
 use backend::{Diagnostic, TryToTokens};
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 
+mod from_js_object;
 mod parser;
 
 /// Takes the parsed input from a `#[wasm_bindgen]` macro and returns the generated bindings
 pub fn expand(attr: TokenStream, input: TokenStream) -> Result<TokenStream, Diagnostic> {
+    // `syn` can't parse `async fn` yet, so take the keyword off before parsing
+    // and put it back on the function we emit afterwards.
+    let (input, async_span) = strip_async(input);
     let item = syn_parse::<syn::Item>(input, "rust item")?;
     let opts = syn_parse(attr, "#[wasm_bindgen] attribute options")?;
 
+    if async_span.is_some() {
+        match item {
+            syn::Item::Fn(_) => {}
+            _ => bail_span!(item, "only free functions can be exported as `async`"),
+        }
+    }
+
     let mut tokens = proc_macro2::TokenStream::new();
     let mut program = backend::ast::Program::default();
     item.macro_parse(&mut program, (Some(opts), &mut tokens))?;
+    if let Some(span) = async_span {
+        tokens = insert_async(tokens, span);
+        program.exports.last_mut().unwrap().is_async = true;
+    }
     program.try_to_tokens(&mut tokens)?;
     Ok(tokens)
 }
 
+/// Removes a top-level `async` keyword in front of `fn` (or `unsafe fn`),
+/// returning its span if one was found.
+fn strip_async(input: TokenStream) -> (TokenStream, Option<Span>) {
+    let tokens = input.into_iter().collect::<Vec<_>>();
+    let mut span = None;
+    let mut ret = Vec::with_capacity(tokens.len());
+    for (i, token) in tokens.iter().enumerate() {
+        let before_fn = match tokens.get(i + 1) {
+            Some(next) => is_ident(next, "fn") || is_ident(next, "unsafe"),
+            None => false,
+        };
+        if span.is_none() && before_fn && is_ident(token, "async") {
+            span = Some(token.span());
+            continue;
+        }
+        ret.push(token.clone());
+    }
+    (ret.into_iter().collect(), span)
+}
+
+/// Puts `async` back in front of the first top-level `fn` (or `unsafe fn`)
+/// in `tokens`.
+fn insert_async(tokens: TokenStream, span: Span) -> TokenStream {
+    let mut inserted = false;
+    let mut ret = TokenStream::new();
+    for token in tokens {
+        if !inserted && (is_ident(&token, "fn") || is_ident(&token, "unsafe")) {
+            ret.extend(Some(TokenTree::Ident(Ident::new("async", span))));
+            inserted = true;
+        }
+        ret.extend(Some(token));
+    }
+    ret
+}
+
+fn is_ident(token: &TokenTree, name: &str) -> bool {
+    match token {
+        TokenTree::Ident(ident) => ident == name,
+        _ => false,
+    }
+}
+
+/// Takes the input of a `#[derive(FromJsObject)]` and returns the generated
+/// `FromJsObject` implementation
+pub fn expand_from_js_object(input: TokenStream) -> Result<TokenStream, Diagnostic> {
+    from_js_object::expand(input)
+}
+
 fn syn_parse<T: syn::synom::Synom>(tokens: TokenStream, name: &str) -> Result<T, Diagnostic> {
     syn::parse2(tokens.clone())
         .map_err(|err| {
@@ -16,42 +16,72 @@ pub struct BindgenAttrs {
 
 impl BindgenAttrs {
     /// Find and parse the wasm_bindgen attributes.
+    ///
+    /// All of the `#[wasm_bindgen]` attributes are removed and their options
+    /// combined, so that some of them can be added with `cfg_attr`.
     fn find(attrs: &mut Vec<syn::Attribute>) -> Result<BindgenAttrs, Diagnostic> {
-        let pos = attrs
-            .iter()
-            .enumerate()
-            .find(|&(_, ref m)| m.path.segments[0].ident == "wasm_bindgen")
-            .map(|a| a.0);
-        let pos = match pos {
-            Some(i) => i,
-            None => return Ok(BindgenAttrs::default()),
-        };
-        let attr = attrs.remove(pos);
-        let mut tts = attr.tts.clone().into_iter();
-        let group = match tts.next() {
-            Some(TokenTree::Group(d)) => d,
-            Some(_) => bail_span!(attr, "malformed #[wasm_bindgen] attribute"),
-            None => return Ok(BindgenAttrs::default()),
-        };
-        if tts.next().is_some() {
-            bail_span!(attr, "malformed #[wasm_bindgen] attribute");
-        }
-        if group.delimiter() != Delimiter::Parenthesis {
-            bail_span!(attr, "malformed #[wasm_bindgen] attribute");
+        let mut ret = BindgenAttrs::default();
+        loop {
+            let pos = attrs
+                .iter()
+                .enumerate()
+                .find(|&(_, ref m)| m.path.segments[0].ident == "wasm_bindgen")
+                .map(|a| a.0);
+            let pos = match pos {
+                Some(i) => i,
+                None => return Ok(ret),
+            };
+            let attr = attrs.remove(pos);
+            let mut tts = attr.tts.clone().into_iter();
+            let group = match tts.next() {
+                Some(TokenTree::Group(d)) => d,
+                Some(_) => bail_span!(attr, "malformed #[wasm_bindgen] attribute"),
+                None => continue,
+            };
+            if tts.next().is_some() {
+                bail_span!(attr, "malformed #[wasm_bindgen] attribute");
+            }
+            if group.delimiter() != Delimiter::Parenthesis {
+                bail_span!(attr, "malformed #[wasm_bindgen] attribute");
+            }
+            let opts: BindgenAttrs =
+                super::syn_parse(group.stream(), "#[wasm_bindgen] attribute options")?;
+            ret.attrs.extend(opts.attrs);
         }
-        super::syn_parse(group.stream(), "#[wasm_bindgen] attribute options")
     }
 
-    /// Get the first module attribute
-    fn module(&self) -> Option<&str> {
+    /// Adds the options of any other `#[wasm_bindgen]` attributes in `attrs`
+    /// to these.
+    fn extend_from(&mut self, attrs: &mut Vec<syn::Attribute>) -> Result<(), Diagnostic> {
+        let more = BindgenAttrs::find(attrs)?;
+        self.attrs.extend(more.attrs);
+        Ok(())
+    }
+
+    /// Get the module given by the node_module attribute, if any
+    fn node_module(&self) -> Option<&str> {
         self.attrs
             .iter()
             .filter_map(|a| match a {
-                BindgenAttr::Module(s) => Some(&s[..]),
+                BindgenAttr::NodeModule(s) => Some(&s[..]),
                 _ => None,
             }).next()
     }
 
+    /// Get the module given by the module attribute, if any, erroring if
+    /// more than one is given
+    fn module(&self) -> Result<Option<String>, Diagnostic> {
+        let mut modules = self.attrs.iter().filter_map(|a| match a {
+            BindgenAttr::Module(s) => Some(s),
+            _ => None,
+        });
+        let module = modules.next().map(|s| s.value());
+        if let Some(duplicate) = modules.next() {
+            bail_span!(duplicate, "only one `module` attribute can be given");
+        }
+        Ok(module)
+    }
+
     /// Whether the catch attribute is present
     fn catch(&self) -> bool {
         self.attrs.iter().any(|a| match a {
@@ -60,6 +90,14 @@ impl BindgenAttrs {
         })
     }
 
+    /// Whether the assert_no_shim attribute is present
+    fn assert_no_shim(&self) -> bool {
+        self.attrs.iter().any(|a| match a {
+            BindgenAttr::AssertNoShim => true,
+            _ => false,
+        })
+    }
+
     /// Whether the constructor attribute is present
     fn constructor(&self) -> bool {
         self.attrs.iter().any(|a| match a {
@@ -156,6 +194,46 @@ impl BindgenAttrs {
         })
     }
 
+    /// Whether the skip attribute is present
+    fn skip(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::Skip => true,
+            _ => false,
+        })
+    }
+
+    /// Whether the enumerable attribute is present
+    fn enumerable(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::Enumerable => true,
+            _ => false,
+        })
+    }
+
+    /// Whether the non_configurable attribute is present
+    fn non_configurable(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::NonConfigurable => true,
+            _ => false,
+        })
+    }
+
+    /// Whether the async_iterator attribute is present
+    fn async_iterator(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::AsyncIterator => true,
+            _ => false,
+        })
+    }
+
+    /// Whether the iterator attribute is present
+    fn iterator(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::Iterator => true,
+            _ => false,
+        })
+    }
+
     /// Get the first js_name attribute
     fn js_name(&self) -> Option<&str> {
         self.attrs
@@ -176,6 +254,48 @@ impl BindgenAttrs {
             }).next()
     }
 
+    /// Whether the optional_args attribute is present
+    fn optional_args(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::OptionalArgs => true,
+            _ => false,
+        })
+    }
+
+    /// Whether the js_map attribute is present
+    fn js_map(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::JsMap => true,
+            _ => false,
+        })
+    }
+
+    /// Whether the variadic attribute is present
+    fn variadic(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::Variadic => true,
+            _ => false,
+        })
+    }
+
+    /// Whether the options attribute is present
+    fn options(&self) -> bool {
+        self.attrs.iter().any(|a| match *a {
+            BindgenAttr::Options => true,
+            _ => false,
+        })
+    }
+
+    /// Return the list of default argument values
+    fn defaults(&self) -> impl Iterator<Item = &DefaultArg> {
+        self.attrs
+            .iter()
+            .filter_map(|a| match a {
+                BindgenAttr::Default(d) => Some(d),
+                _ => None,
+            }).flat_map(|d| d.iter())
+    }
+
     /// Return the list of classes that a type extends
     fn extends(&self) -> impl Iterator<Item = &Ident> {
         self.attrs.iter().filter_map(|a| match a {
@@ -183,6 +303,77 @@ impl BindgenAttrs {
             _ => None,
         })
     }
+
+    /// Get the module given by the polyfill attribute, if any
+    fn polyfill(&self) -> Option<&syn::LitStr> {
+        self.attrs
+            .iter()
+            .filter_map(|a| match a {
+                BindgenAttr::Polyfill(s) => Some(s),
+                _ => None,
+            }).next()
+    }
+
+    /// Get the path to the `wasm_bindgen` crate given by the wasm_bindgen
+    /// attribute, if any
+    fn wasm_bindgen(&self) -> Option<&syn::Path> {
+        self.attrs
+            .iter()
+            .filter_map(|a| match a {
+                BindgenAttr::WasmBindgen(p) => Some(p),
+                _ => None,
+            }).next()
+    }
+
+    /// Get the path to the `wasm_bindgen_futures` crate given by the
+    /// wasm_bindgen_futures attribute, if any
+    fn wasm_bindgen_futures(&self) -> Option<&syn::Path> {
+        self.attrs
+            .iter()
+            .filter_map(|a| match a {
+                BindgenAttr::WasmBindgenFutures(p) => Some(p),
+                _ => None,
+            }).next()
+    }
+
+    /// Return the list of vendor prefixes to fall back on for an import
+    fn vendor_prefixes(&self) -> impl Iterator<Item = &Ident> {
+        self.attrs.iter().filter_map(|a| match a {
+            BindgenAttr::VendorPrefix(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    /// Get the first custom_element attribute
+    fn custom_element(&self) -> Option<&str> {
+        self.attrs
+            .iter()
+            .filter_map(|a| match a {
+                BindgenAttr::CustomElement(s) => Some(&s[..]),
+                _ => None,
+            }).next()
+    }
+
+    /// Get the name given by the shared_class attribute, if any
+    fn shared_class(&self) -> Option<&str> {
+        self.attrs
+            .iter()
+            .filter_map(|a| match a {
+                BindgenAttr::SharedClass(s) => Some(&s[..]),
+                _ => None,
+            }).next()
+    }
+
+    /// Get all the attribute names listed in observed_attributes attributes
+    fn observed_attributes(&self) -> Vec<String> {
+        self.attrs
+            .iter()
+            .filter_map(|a| match a {
+                BindgenAttr::ObservedAttributes(s) => Some(s.iter().cloned()),
+                _ => None,
+            }).flat_map(|s| s)
+            .collect()
+    }
 }
 
 impl syn::synom::Synom for BindgenAttrs {
@@ -208,7 +399,8 @@ pub enum BindgenAttr {
     Method,
     StaticMethodOf(Ident),
     JsNamespace(Ident),
-    Module(String),
+    Module(syn::LitStr),
+    NodeModule(String),
     Getter(Option<Ident>),
     Setter(Option<Ident>),
     IndexingGetter,
@@ -216,9 +408,27 @@ pub enum BindgenAttr {
     IndexingDeleter,
     Structural,
     Readonly,
+    Skip,
     JsName(String),
     JsClass(String),
     Extends(Ident),
+    VendorPrefix(Ident),
+    Polyfill(syn::LitStr),
+    WasmBindgen(syn::Path),
+    WasmBindgenFutures(syn::Path),
+    OptionalArgs,
+    JsMap,
+    Variadic,
+    Default(Vec<DefaultArg>),
+    Options,
+    CustomElement(String),
+    SharedClass(String),
+    ObservedAttributes(Vec<String>),
+    Enumerable,
+    NonConfigurable,
+    AsyncIterator,
+    Iterator,
+    AssertNoShim,
 }
 
 impl syn::synom::Synom for BindgenAttr {
@@ -266,6 +476,56 @@ impl syn::synom::Synom for BindgenAttr {
         |
         call!(term, "readonly") => { |_| BindgenAttr::Readonly }
         |
+        call!(term, "skip") => { |_| BindgenAttr::Skip }
+        |
+        call!(term, "enumerable") => { |_| BindgenAttr::Enumerable }
+        |
+        call!(term, "async_iterator") => { |_| BindgenAttr::AsyncIterator }
+        |
+        call!(term, "iterator") => { |_| BindgenAttr::Iterator }
+        |
+        call!(term, "assert_no_shim") => { |_| BindgenAttr::AssertNoShim }
+        |
+        call!(term, "non_configurable") => { |_| BindgenAttr::NonConfigurable }
+        |
+        call!(term, "optional_args") => { |_| BindgenAttr::OptionalArgs }
+        |
+        call!(term, "js_map") => { |_| BindgenAttr::JsMap }
+        |
+        call!(term, "variadic") => { |_| BindgenAttr::Variadic }
+        |
+        call!(term, "options") => { |_| BindgenAttr::Options }
+        |
+        do_parse!(
+            call!(term, "custom_element") >>
+            punct!(=) >>
+            s: syn!(syn::LitStr) >>
+            (s.value())
+        )=> { BindgenAttr::CustomElement }
+        |
+        do_parse!(
+            call!(term, "shared_class") >>
+            punct!(=) >>
+            s: syn!(syn::LitStr) >>
+            (s.value())
+        )=> { BindgenAttr::SharedClass }
+        |
+        do_parse!(
+            call!(term, "observed_attributes") >>
+            list: parens!(call!(
+                syn::punctuated::Punctuated::<syn::LitStr, syn::token::Comma>::parse_terminated
+            )) >>
+            (list.1.into_iter().map(|s| s.value()).collect())
+        )=> { BindgenAttr::ObservedAttributes }
+        |
+        do_parse!(
+            call!(term, "default") >>
+            list: parens!(call!(
+                syn::punctuated::Punctuated::<_, syn::token::Comma>::parse_terminated
+            )) >>
+            (list.1.into_iter().collect())
+        )=> { BindgenAttr::Default }
+        |
         do_parse!(
             call!(term, "js_namespace") >>
             punct!(=) >>
@@ -274,12 +534,19 @@ impl syn::synom::Synom for BindgenAttr {
         )=> { BindgenAttr::JsNamespace }
         |
         do_parse!(
-            call!(term, "module") >>
+            alt!(call!(term, "module") | call!(term, "web_module")) >>
             punct!(=) >>
             s: syn!(syn::LitStr) >>
-            (s.value())
+            (s)
         )=> { BindgenAttr::Module }
         |
+        do_parse!(
+            call!(term, "node_module") >>
+            punct!(=) >>
+            s: syn!(syn::LitStr) >>
+            (s.value())
+        )=> { BindgenAttr::NodeModule }
+        |
         do_parse!(
             call!(term, "js_name") >>
             punct!(=) >>
@@ -304,9 +571,139 @@ impl syn::synom::Synom for BindgenAttr {
             ns: call!(term2ident) >>
             (ns)
         )=> { BindgenAttr::Extends }
+        |
+        do_parse!(
+            call!(term, "vendor_prefix") >>
+            punct!(=) >>
+            prefix: call!(term2ident) >>
+            (prefix)
+        )=> { BindgenAttr::VendorPrefix }
+        |
+        do_parse!(
+            call!(term, "polyfill") >>
+            punct!(=) >>
+            s: syn!(syn::LitStr) >>
+            (s)
+        )=> { BindgenAttr::Polyfill }
+        |
+        do_parse!(
+            call!(term, "wasm_bindgen") >>
+            punct!(=) >>
+            path: syn!(syn::Path) >>
+            (path)
+        )=> { BindgenAttr::WasmBindgen }
+        |
+        do_parse!(
+            call!(term, "wasm_bindgen_futures") >>
+            punct!(=) >>
+            path: syn!(syn::Path) >>
+            (path)
+        )=> { BindgenAttr::WasmBindgenFutures }
     ));
 }
 
+/// A default value for an argument, as in `#[wasm_bindgen(default(x = 1))]`.
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+pub struct DefaultArg {
+    name: Ident,
+    negative: bool,
+    value: syn::Lit,
+}
+
+impl syn::synom::Synom for DefaultArg {
+    named!(parse -> Self, do_parse!(
+        name: call!(term2ident) >>
+        punct!(=) >>
+        negative: option!(punct!(-)) >>
+        value: syn!(syn::Lit) >>
+        (DefaultArg {
+            name,
+            negative: negative.is_some(),
+            value,
+        })
+    ));
+}
+
+impl DefaultArg {
+    /// Renders the default value as a JS expression.
+    fn to_js(&self) -> Result<String, Diagnostic> {
+        match literal_to_js(self.negative, &self.value) {
+            Some(value) => Ok(value),
+            None => bail_span!(
+                self.value,
+                "default values must be number, string, or boolean literals"
+            ),
+        }
+    }
+}
+
+/// Renders a number, string, or boolean literal, negated if `negative`, as a
+/// JS expression.
+fn literal_to_js(negative: bool, lit: &syn::Lit) -> Option<String> {
+    let value = match lit {
+        syn::Lit::Int(i) => i.value().to_string(),
+        syn::Lit::Float(f) => f.value().to_string(),
+        syn::Lit::Str(s) if !negative => format!("{:?}", s.value()),
+        syn::Lit::Bool(b) if !negative => b.value.to_string(),
+        _ => return None,
+    };
+    if negative {
+        Some(format!("-{}", value))
+    } else {
+        Some(value)
+    }
+}
+
+/// Converts a `const` or `static` item to the constant it's exported to JS as.
+///
+/// The value is written into the generated JS as it is, so it must be a
+/// literal rather than an expression which is only evaluated by Rust.
+fn exported_const(
+    opts: &BindgenAttrs,
+    vis: &syn::Visibility,
+    ident: &Ident,
+    expr: &syn::Expr,
+    attrs: &[syn::Attribute],
+) -> Result<ast::ExportedConst, Diagnostic> {
+    match vis {
+        syn::Visibility::Public(_) => {}
+        _ => bail_span!(ident, "can only #[wasm_bindgen] public constants and statics"),
+    }
+    const NOT_LITERAL: &str = "exported constants must be number, string, or boolean literals";
+    let (negative, lit) = match expr {
+        syn::Expr::Lit(l) => (false, &l.lit),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match &**inner {
+            syn::Expr::Lit(l) => (true, &l.lit),
+            _ => bail_span!(expr, "{}", NOT_LITERAL),
+        },
+        _ => bail_span!(expr, "{}", NOT_LITERAL),
+    };
+    // JS numbers are doubles, which can't hold every 64-bit integer.
+    if let syn::Lit::Int(i) = lit {
+        if i.value() > 1 << 53 {
+            bail_span!(
+                lit,
+                "`{}` can't be represented exactly by a JS number",
+                i.value()
+            );
+        }
+    }
+    let value = match literal_to_js(negative, lit) {
+        Some(value) => value,
+        None => bail_span!(expr, "{}", NOT_LITERAL),
+    };
+    Ok(ast::ExportedConst {
+        js_name: opts.js_name().map_or_else(|| ident.to_string(), |s| s.to_string()),
+        rust_name: ident.clone(),
+        value,
+        comments: extract_doc_comments(attrs),
+    })
+}
+
 /// Consumes a `Ident` with the given name
 fn term<'a>(cursor: syn::buffer::Cursor<'a>, name: &str) -> syn::synom::PResult<'a, ()> {
     if let Some((ident, next)) = cursor.ident() {
@@ -338,10 +735,10 @@ trait ConvertToAst<Ctx> {
     fn convert(self, context: Ctx) -> Result<Self::Target, Diagnostic>;
 }
 
-impl<'a> ConvertToAst<()> for &'a mut syn::ItemStruct {
+impl<'a> ConvertToAst<BindgenAttrs> for &'a mut syn::ItemStruct {
     type Target = ast::Struct;
 
-    fn convert(self, (): ()) -> Result<Self::Target, Diagnostic> {
+    fn convert(self, opts: BindgenAttrs) -> Result<Self::Target, Diagnostic> {
         if self.generics.params.len() > 0 {
             bail_span!(
                 self.generics,
@@ -349,6 +746,10 @@ impl<'a> ConvertToAst<()> for &'a mut syn::ItemStruct {
                  type parameters currently"
             );
         }
+        let js_name = opts
+            .js_name()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| self.ident.to_string());
         let mut fields = Vec::new();
         if let syn::Fields::Named(names) = &mut self.fields {
             for field in names.named.iter_mut() {
@@ -360,16 +761,20 @@ impl<'a> ConvertToAst<()> for &'a mut syn::ItemStruct {
                     Some(n) => n,
                     None => continue,
                 };
-                let ident = self.ident.to_string();
                 let name_str = name.to_string();
-                let getter = shared::struct_field_get(&ident, &name_str);
-                let setter = shared::struct_field_set(&ident, &name_str);
+                let getter = shared::struct_field_get(&js_name, &name_str);
+                let setter = shared::struct_field_set(&js_name, &name_str);
                 let opts = BindgenAttrs::find(&mut field.attrs)?;
+                if opts.skip() {
+                    continue;
+                }
                 let comments = extract_doc_comments(&field.attrs);
                 fields.push(ast::StructField {
                     name: name.clone(),
                     struct_name: self.ident.clone(),
                     readonly: opts.readonly(),
+                    enumerable: opts.enumerable(),
+                    configurable: !opts.non_configurable(),
                     ty: field.ty.clone(),
                     getter: Ident::new(&getter, Span::call_site()),
                     setter: Ident::new(&setter, Span::call_site()),
@@ -378,14 +783,183 @@ impl<'a> ConvertToAst<()> for &'a mut syn::ItemStruct {
             }
         }
         let comments: Vec<String> = extract_doc_comments(&self.attrs);
+        let mut extends = opts.extends();
+        let mut parent = extends.next().cloned();
+        if let Some(next) = extends.next() {
+            bail_span!(next, "exported structs can only extend one class");
+        }
+        let custom_element = match opts.custom_element() {
+            Some(name) => {
+                if let Some(parent) = &parent {
+                    bail_span!(parent, "custom elements always extend `HTMLElement`");
+                }
+                if !is_valid_custom_element_name(name) {
+                    bail_span!(
+                        self.ident,
+                        "custom element names must start with a lowercase \
+                         letter and contain a hyphen"
+                    );
+                }
+                parent = Some(Ident::new("HTMLElement", Span::call_site()));
+                Some(ast::CustomElement {
+                    name: name.to_string(),
+                    observed_attributes: opts.observed_attributes(),
+                })
+            }
+            None => {
+                if !opts.observed_attributes().is_empty() {
+                    bail_span!(
+                        self.ident,
+                        "observed_attributes can only be used with custom_element"
+                    );
+                }
+                None
+            }
+        };
+        let parent_field = match &parent {
+            Some(parent) => parent_field(self, parent)?,
+            None => None,
+        };
+        if opts.iterator() && opts.async_iterator() {
+            bail_span!(
+                self.ident,
+                "a struct can't be both an iterator and an async_iterator"
+            );
+        }
         Ok(ast::Struct {
             name: self.ident.clone(),
+            js_name,
             fields,
             comments,
+            extends: parent,
+            parent_field,
+            custom_element,
+            async_iterator: opts.async_iterator(),
+            iterator: opts.iterator(),
+            shared_class: opts.shared_class().map(|s| s.to_string()),
         })
     }
 }
 
+/// The export behind the JS `next` method of a struct marked `iterator`, which
+/// calls `Iterator::next` on it and returns the item, or `undefined` once the
+/// iterator is done.
+fn iterator_next(s: &ast::Struct) -> ast::Export {
+    let name = &s.name;
+    ast::Export {
+        class: Some(name.clone()),
+        js_class: Some(s.js_name.clone()),
+        method_self: Some(ast::MethodSelf::RefMutable),
+        constructor: None,
+        function: ast::Function {
+            name: shared::ITERATOR_NEXT.to_string(),
+            arguments: Vec::new(),
+            ret: Some(parse_quote! {
+                Option<<#name as ::wasm_bindgen::__rt::core::iter::Iterator>::Item>
+            }),
+            rust_attrs: Vec::new(),
+            rust_vis: parse_quote! { pub },
+        },
+        comments: Vec::new(),
+        rust_name: Ident::new("next", Span::call_site()),
+        optional_args: false,
+        js_map: false,
+        defaults: Vec::new(),
+        enumerable: false,
+        configurable: true,
+        is_async: false,
+        getter: None,
+        setter: None,
+        indexing: None,
+        symbol: None,
+    }
+}
+
+/// Finds the field of a struct which `extends` an exported Rust struct.
+///
+/// The parent must be the first field of a `#[repr(C)]` struct so that the
+/// child can be used in place of the parent. If the first field isn't of the
+/// parent type then the parent is assumed to be a JS class instead.
+fn parent_field(s: &syn::ItemStruct, parent: &Ident) -> Result<Option<Ident>, Diagnostic> {
+    let field = match &s.fields {
+        syn::Fields::Named(names) => match names.named.iter().next() {
+            Some(field) => field,
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    let is_parent = match &field.ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            path.segments.last().map(|s| s.value().ident == *parent) == Some(true)
+        }
+        _ => false,
+    };
+    if !is_parent {
+        return Ok(None);
+    }
+    let repr_c = s.attrs.iter().filter_map(|a| a.interpret_meta()).any(|m| match m {
+        syn::Meta::List(ref l) if l.ident == "repr" => l.nested.iter().any(|n| match n {
+            syn::NestedMeta::Meta(syn::Meta::Word(w)) => w == "C",
+            _ => false,
+        }),
+        _ => false,
+    });
+    if !repr_c {
+        bail_span!(
+            s.ident,
+            "structs which extend another exported struct must be #[repr(C)]"
+        );
+    }
+    Ok(field.ident.clone())
+}
+
+/// Checks the rules browsers apply to the names of custom elements, which
+/// must start with an ASCII lowercase letter, contain a hyphen and not contain
+/// any ASCII uppercase letters.
+fn is_valid_custom_element_name(name: &str) -> bool {
+    match name.chars().next() {
+        Some(c) if c.is_ascii_lowercase() => {}
+        _ => return false,
+    }
+    name.contains('-') && !name.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// Converts a `#[wasm_bindgen(options)]` struct, which is received from JS as a
+/// plain object rather than exported as a class.
+fn options_from_struct(s: &mut syn::ItemStruct) -> Result<ast::OptionsStruct, Diagnostic> {
+    if s.generics.params.len() > 0 {
+        bail_span!(
+            s.generics,
+            "options structs with #[wasm_bindgen] cannot have lifetime or \
+             type parameters currently"
+        );
+    }
+    let mut fields = Vec::new();
+    match &mut s.fields {
+        syn::Fields::Named(names) => {
+            for field in names.named.iter_mut() {
+                let name = field.ident.clone().unwrap();
+                let opts = BindgenAttrs::find(&mut field.attrs)?;
+                let js_name = opts
+                    .js_name()
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| name.to_string());
+                fields.push(ast::OptionsField {
+                    name,
+                    js_name,
+                    ty: field.ty.clone(),
+                });
+            }
+        }
+        syn::Fields::Unit => {}
+        syn::Fields::Unnamed(f) => bail_span!(f, "options structs must have named fields"),
+    }
+    Ok(ast::OptionsStruct {
+        name: s.ident.clone(),
+        fields,
+    })
+}
+
 impl<'a> ConvertToAst<(BindgenAttrs, &'a Option<String>)> for syn::ForeignItemFn {
     type Target = ast::ImportKind;
 
@@ -434,6 +1008,72 @@ impl<'a> ConvertToAst<(BindgenAttrs, &'a Option<String>)> for syn::ForeignItemFn
             operation_kind = ast::OperationKind::IndexingDeleter;
         }
 
+        // Indexing operations are always structural `obj[prop]` accesses, so
+        // check up front that the signature has room for the receiver, the
+        // index and (for setters) the value.
+        let indexing = match operation_kind {
+            ast::OperationKind::IndexingGetter => Some((1, true)),
+            ast::OperationKind::IndexingSetter => Some((2, false)),
+            ast::OperationKind::IndexingDeleter => Some((1, false)),
+            _ => None,
+        };
+        if let Some((nargs, needs_ret)) = indexing {
+            let nargs = if opts.method() {
+                nargs + 1
+            } else if opts.static_method_of().is_some() {
+                nargs
+            } else {
+                bail_span!(
+                    self.ident,
+                    "indexing_getter, indexing_setter, and indexing_deleter \
+                     can only be used with `method` or `static_method_of`"
+                );
+            };
+            if wasm.arguments.len() != nargs {
+                bail_span!(
+                    self.decl.inputs,
+                    "this indexing operation must take exactly {} argument{}",
+                    nargs,
+                    if nargs == 1 { "" } else { "s" }
+                );
+            }
+            if needs_ret && js_ret.is_none() {
+                bail_span!(self.ident, "indexing getters must have a return type");
+            }
+        }
+
+        // The variadic arguments are passed as a slice, which is spread into
+        // the call in JS, so it has to come last and can't be the receiver.
+        if opts.variadic() {
+            let receivers = if opts.method() { 1 } else { 0 };
+            let last = match wasm.arguments.last() {
+                Some(last) if wasm.arguments.len() > receivers => last,
+                _ => bail_span!(
+                    self.ident,
+                    "variadic functions must take a slice of the variadic \
+                     arguments, like `&[JsValue]`, as their last argument"
+                ),
+            };
+            let is_slice = match last.ty {
+                syn::Type::Reference(syn::TypeReference {
+                    mutability: None,
+                    ref elem,
+                    ..
+                }) => match **elem {
+                    syn::Type::Slice(_) => true,
+                    _ => false,
+                },
+                _ => false,
+            };
+            if !is_slice {
+                bail_span!(
+                    last.ty,
+                    "the last argument of a variadic function must be a slice, \
+                     like `&[JsValue]`"
+                );
+            }
+        }
+
         let kind = if opts.method() {
             let class = wasm.arguments.get(0).ok_or_else(|| {
                 err_span!(self, "imported methods must have at least one argument")
@@ -486,7 +1126,9 @@ impl<'a> ConvertToAst<(BindgenAttrs, &'a Option<String>)> for syn::ForeignItemFn
 
             ast::ImportFunctionKind::Method { class, ty, kind }
         } else if opts.constructor() {
-            let class = match wasm.ret {
+            // Note that `js_ret` is used here so `catch` constructors returning
+            // `Result<T, JsValue>` are still associated with `T`.
+            let class = match js_ret {
                 Some(ref ty) => ty,
                 _ => bail_span!(self, "constructor returns must be bare types"),
             };
@@ -532,7 +1174,12 @@ impl<'a> ConvertToAst<(BindgenAttrs, &'a Option<String>)> for syn::ForeignItemFn
             kind,
             js_ret,
             catch,
-            structural: opts.structural(),
+            assert_no_shim: opts.assert_no_shim(),
+            optional_args: opts.optional_args(),
+            variadic: opts.variadic(),
+            vendor_prefixes: opts.vendor_prefixes().cloned().collect(),
+            polyfill: opts.polyfill().map(|s| s.value()),
+            structural: opts.structural() || indexing.is_some(),
             rust_name: self.ident.clone(),
             shim: Ident::new(&shim, Span::call_site()),
             doc_comment: None,
@@ -556,6 +1203,9 @@ impl ConvertToAst<BindgenAttrs> for syn::ForeignItemType {
             rust_name: self.ident,
             js_name,
             extends: attrs.extends().cloned().collect(),
+            shared_class: attrs.shared_class().map(|s| s.to_string()),
+            vendor_prefixes: attrs.vendor_prefixes().cloned().collect(),
+            polyfill: attrs.polyfill().map(|s| s.value()),
         }))
     }
 }
@@ -587,10 +1237,10 @@ impl ConvertToAst<BindgenAttrs> for syn::ForeignItemStatic {
     }
 }
 
-impl ConvertToAst<BindgenAttrs> for syn::ItemFn {
+impl<'a> ConvertToAst<&'a BindgenAttrs> for syn::ItemFn {
     type Target = ast::Function;
 
-    fn convert(self, attrs: BindgenAttrs) -> Result<Self::Target, Diagnostic> {
+    fn convert(self, attrs: &'a BindgenAttrs) -> Result<Self::Target, Diagnostic> {
         match self.vis {
             syn::Visibility::Public(_) => {}
             _ => bail_span!(self, "can only #[wasm_bindgen] public functions"),
@@ -611,6 +1261,216 @@ impl ConvertToAst<BindgenAttrs> for syn::ItemFn {
     }
 }
 
+/// Matches up the `default(..)` values in `opts` with the arguments of
+/// `function`, returning the JS default value (if any) of each argument.
+fn argument_defaults(
+    opts: &BindgenAttrs,
+    function: &ast::Function,
+) -> Result<Vec<Option<String>>, Diagnostic> {
+    let mut defaults = vec![None; function.arguments.len()];
+    for default in opts.defaults() {
+        let pos = function.arguments.iter().position(|arg| match &arg.pat {
+            syn::Pat::Ident(p) => p.ident == default.name,
+            _ => false,
+        });
+        match pos {
+            Some(i) => defaults[i] = Some(default.to_js()?),
+            None => bail_span!(default.name, "no argument named `{}`", default.name),
+        }
+    }
+    if let Some(first) = defaults.iter().position(|d| d.is_some()) {
+        if let Some(i) = defaults[first..].iter().position(|d| d.is_none()) {
+            bail_span!(
+                function.arguments[first + i],
+                "default values can only be given to trailing arguments"
+            );
+        }
+    }
+    Ok(defaults)
+}
+
+/// Works out which JS property, if any, an exported method is a `getter` or
+/// `setter` for, checking that the method has the right shape to be one.
+fn accessor_names(
+    opts: &BindgenAttrs,
+    rust_name: &Ident,
+    function: &ast::Function,
+    method_self: &Option<ast::MethodSelf>,
+    symbol: &Option<String>,
+) -> Result<(Option<String>, Option<String>), Diagnostic> {
+    let getter = opts.getter();
+    let setter = opts.setter();
+    if getter.is_none() && setter.is_none() {
+        return Ok((None, None));
+    }
+    if getter.is_some() && setter.is_some() {
+        bail_span!(rust_name, "a method can't be both a getter and a setter");
+    }
+    if let Some(symbol) = symbol {
+        bail_span!(
+            rust_name,
+            "getters and setters can't be named after symbols, found: Symbol.{}",
+            symbol
+        );
+    }
+    if opts.constructor() {
+        bail_span!(rust_name, "constructors can't be getters or setters");
+    }
+    match method_self {
+        Some(ast::MethodSelf::ByValue) => {
+            bail_span!(rust_name, "getters and setters can't take `self` by value")
+        }
+        Some(_) => {}
+        None => bail_span!(rust_name, "getters and setters must take `&self` or `&mut self`"),
+    }
+
+    if let Some(name) = getter {
+        if function.arguments.len() > 0 || function.ret.is_none() {
+            bail_span!(
+                rust_name,
+                "getters must take no arguments other than `self` and return a value"
+            );
+        }
+        let name = name.map(|n| n.to_string()).unwrap_or_else(|| function.name.clone());
+        return Ok((Some(name), None));
+    }
+
+    if function.arguments.len() != 1 {
+        bail_span!(rust_name, "setters must take exactly one argument other than `self`");
+    }
+    let name = match setter.unwrap() {
+        Some(name) => name.to_string(),
+        None => {
+            if !function.name.starts_with("set_") {
+                bail_span!(
+                    rust_name,
+                    "setters must start with `set_`, or name their property with \
+                     `setter = name`, found: {}",
+                    function.name
+                );
+            }
+            function.name[4..].to_string()
+        }
+    };
+    Ok((None, Some(name)))
+}
+
+/// The well-known symbols which exported methods can be keyed by, with
+/// `js_name = "Symbol.toPrimitive"` and so on.
+///
+/// This is only the symbols JS calls as methods. The others, like
+/// `Symbol.toStringTag` and `Symbol.species`, are read as properties, which
+/// a method keyed by them wouldn't be.
+const WELL_KNOWN_SYMBOLS: &[&str] = &[
+    "asyncIterator",
+    "hasInstance",
+    "iterator",
+    "match",
+    "matchAll",
+    "replace",
+    "search",
+    "split",
+    "toPrimitive",
+];
+
+/// Works out which well-known symbol, if any, an exported method's `js_name`
+/// names.
+fn method_symbol(opts: &BindgenAttrs, rust_name: &Ident) -> Result<Option<String>, Diagnostic> {
+    let name = match opts.js_name() {
+        Some(name) if name.starts_with("Symbol.") => &name["Symbol.".len()..],
+        _ => return Ok(None),
+    };
+    if !WELL_KNOWN_SYMBOLS.contains(&name) {
+        bail_span!(
+            rust_name,
+            "`Symbol.{}` isn't one of the well-known symbols methods can be named after",
+            name
+        );
+    }
+    Ok(Some(name.to_string()))
+}
+
+/// Works out which indexing operation, if any, an exported method implements,
+/// checking that the method has the right shape for it.
+fn indexing_kind(
+    opts: &BindgenAttrs,
+    rust_name: &Ident,
+    function: &ast::Function,
+    method_self: &Option<ast::MethodSelf>,
+) -> Result<Option<ast::IndexingKind>, Diagnostic> {
+    let kinds = [
+        (opts.indexing_getter(), ast::IndexingKind::Getter),
+        (opts.indexing_setter(), ast::IndexingKind::Setter),
+        (opts.indexing_deleter(), ast::IndexingKind::Deleter),
+    ];
+    let mut kinds = kinds.iter().filter(|k| k.0).map(|k| k.1);
+    let kind = match kinds.next() {
+        Some(kind) => kind,
+        None => return Ok(None),
+    };
+    if kinds.next().is_some() {
+        bail_span!(
+            rust_name,
+            "only one of indexing_getter, indexing_setter, and indexing_deleter \
+             can be used on a method"
+        );
+    }
+    if opts.constructor() || opts.getter().is_some() || opts.setter().is_some() {
+        bail_span!(
+            rust_name,
+            "indexing methods can't also be constructors, getters or setters"
+        );
+    }
+    match method_self {
+        Some(ast::MethodSelf::ByValue) => {
+            bail_span!(rust_name, "indexing methods can't take `self` by value")
+        }
+        Some(_) => {}
+        None => bail_span!(rust_name, "indexing methods must take `&self` or `&mut self`"),
+    }
+    let arguments = function.arguments.len();
+    match kind {
+        ast::IndexingKind::Getter if arguments != 1 || function.ret.is_none() => bail_span!(
+            rust_name,
+            "indexing getters must take an index and return a value"
+        ),
+        ast::IndexingKind::Setter if arguments != 2 => bail_span!(
+            rust_name,
+            "indexing setters must take an index and the value to set"
+        ),
+        ast::IndexingKind::Deleter if arguments != 1 => {
+            bail_span!(rust_name, "indexing deleters must take just an index")
+        }
+        _ => {}
+    }
+    Ok(Some(kind))
+}
+
+/// Replaces `Self` in `t` with `self_ty`, including in generic arguments like
+/// the `Self` of `Result<Self, JsValue>`.
+fn replace_self_ty(t: syn::Type, self_ty: &Ident) -> syn::Type {
+    let mut path = match t {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path,
+        other => return other,
+    };
+    if path.segments.len() == 1 && path.segments[0].ident == "Self" {
+        return syn::Type::Path(syn::TypePath {
+            qself: None,
+            path: self_ty.clone().into(),
+        });
+    }
+    for segment in path.segments.iter_mut() {
+        if let syn::PathArguments::AngleBracketed(ref mut generics) = segment.arguments {
+            for arg in generics.args.iter_mut() {
+                if let syn::GenericArgument::Type(ref mut ty) = *arg {
+                    *ty = replace_self_ty(ty.clone(), self_ty);
+                }
+            }
+        }
+    }
+    syn::Type::Path(syn::TypePath { qself: None, path })
+}
+
 /// Construct a function (and gets the self type if appropriate) for our AST from a syn function.
 fn function_from_decl(
     name: &str,
@@ -634,24 +1494,9 @@ fn function_from_decl(
 
     let syn::FnDecl { inputs, output, .. } = { *decl };
 
-    let replace_self = |t: syn::Type| {
-        let self_ty = match self_ty {
-            Some(i) => i,
-            None => return t,
-        };
-        let path = match t {
-            syn::Type::Path(syn::TypePath { qself: None, path }) => path,
-            other => return other,
-        };
-        let new_path = if path.segments.len() == 1 && path.segments[0].ident == "Self" {
-            self_ty.clone().into()
-        } else {
-            path
-        };
-        syn::Type::Path(syn::TypePath {
-            qself: None,
-            path: new_path,
-        })
+    let replace_self = |t: syn::Type| match self_ty {
+        Some(i) => replace_self_ty(t, i),
+        None => t,
     };
 
     let mut method_self = None;
@@ -710,6 +1555,12 @@ impl<'a> MacroParse<(Option<BindgenAttrs>, &'a mut TokenStream)> for syn::Item {
         program: &mut ast::Program,
         (opts, tokens): (Option<BindgenAttrs>, &'a mut TokenStream),
     ) -> Result<(), Diagnostic> {
+        if let Some(path) = opts.as_ref().and_then(|opts| opts.wasm_bindgen()) {
+            program.wasm_bindgen = Some(path.clone());
+        }
+        if let Some(path) = opts.as_ref().and_then(|opts| opts.wasm_bindgen_futures()) {
+            program.wasm_bindgen_futures = Some(path.clone());
+        }
         match self {
             syn::Item::Fn(mut f) => {
                 let no_mangle = f
@@ -725,39 +1576,114 @@ impl<'a> MacroParse<(Option<BindgenAttrs>, &'a mut TokenStream)> for syn::Item {
                     _ => {}
                 }
                 let comments = extract_doc_comments(&f.attrs);
+                let opts = opts.unwrap_or_default();
+                if opts.enumerable() || opts.non_configurable() {
+                    bail_span!(
+                        f.ident,
+                        "enumerable and non_configurable can only be used on \
+                         the fields and methods of exported structs"
+                    );
+                }
+                if opts.getter().is_some() || opts.setter().is_some() {
+                    bail_span!(
+                        f.ident,
+                        "getter and setter can only be used on the methods of \
+                         exported structs"
+                    );
+                }
+                if opts.js_name().map_or(false, |n| n.starts_with("Symbol.")) {
+                    bail_span!(
+                        f.ident,
+                        "only the methods of exported structs can be named \
+                         after symbols"
+                    );
+                }
+                if opts.indexing_getter() || opts.indexing_setter() || opts.indexing_deleter() {
+                    bail_span!(
+                        f.ident,
+                        "indexing_getter, indexing_setter, and indexing_deleter \
+                         can only be used on the methods of exported structs"
+                    );
+                }
+                let optional_args = opts.optional_args();
                 f.to_tokens(tokens);
+                let rust_name = f.ident.clone();
+                let function = f.convert(&opts)?;
+                let defaults = argument_defaults(&opts, &function)?;
                 program.exports.push(ast::Export {
                     class: None,
+                    js_class: None,
                     method_self: None,
                     constructor: None,
                     comments,
-                    rust_name: f.ident.clone(),
-                    function: f.convert(opts.unwrap_or_default())?,
+                    rust_name,
+                    function,
+                    optional_args,
+                    js_map: opts.js_map(),
+                    defaults,
+                    enumerable: false,
+                    configurable: true,
+                    is_async: false,
+                    getter: None,
+                    setter: None,
+                    indexing: None,
+                    symbol: None,
                 });
             }
             syn::Item::Struct(mut s) => {
-                program.structs.push((&mut s).convert(())?);
+                let opts = opts.unwrap_or_default();
+                if opts.options() {
+                    program.options.push(options_from_struct(&mut s)?);
+                } else {
+                    let s = (&mut s).convert(opts)?;
+                    if s.iterator {
+                        program.exports.push(iterator_next(&s));
+                    }
+                    program.structs.push(s);
+                }
                 s.to_tokens(tokens);
             }
             syn::Item::Impl(mut i) => {
-                (&mut i).macro_parse(program, ())?;
+                (&mut i).macro_parse(program, opts.unwrap_or_default())?;
                 i.to_tokens(tokens);
             }
             syn::Item::ForeignMod(mut f) => {
                 let opts = match opts {
-                    Some(opts) => opts,
+                    Some(mut opts) => {
+                        opts.extend_from(&mut f.attrs)?;
+                        opts
+                    }
                     None => BindgenAttrs::find(&mut f.attrs)?,
                 };
                 f.macro_parse(program, opts)?;
             }
-            syn::Item::Enum(e) => {
+            syn::Item::Enum(mut e) => {
+                (&mut e).macro_parse(program, ())?;
                 e.to_tokens(tokens);
-                e.macro_parse(program, ())?;
+            }
+            syn::Item::Trait(mut t) => {
+                export_trait(&mut t, &opts.unwrap_or_default(), program, tokens)?;
+                t.to_tokens(tokens);
+            }
+            syn::Item::Const(c) => {
+                let opts = opts.unwrap_or_default();
+                let c2 = exported_const(&opts, &c.vis, &c.ident, &c.expr, &c.attrs)?;
+                program.exported_consts.push(c2);
+                c.to_tokens(tokens);
+            }
+            syn::Item::Static(s) => {
+                if s.mutability.is_some() {
+                    bail_span!(s.mutability, "can't #[wasm_bindgen] a `static mut`");
+                }
+                let opts = opts.unwrap_or_default();
+                let s2 = exported_const(&opts, &s.vis, &s.ident, &s.expr, &s.attrs)?;
+                program.exported_consts.push(s2);
+                s.to_tokens(tokens);
             }
             _ => bail_span!(
                 self,
                 "#[wasm_bindgen] can only be applied to a function, \
-                 struct, enum, impl, or extern block"
+                 struct, enum, impl, trait, const, static, or extern block"
             ),
         }
 
@@ -765,8 +1691,163 @@ impl<'a> MacroParse<(Option<BindgenAttrs>, &'a mut TokenStream)> for syn::Item {
     }
 }
 
-impl<'a> MacroParse<()> for &'a mut syn::ItemImpl {
-    fn macro_parse(self, program: &mut ast::Program, (): ()) -> Result<(), Diagnostic> {
+/// Exports a trait, which is done by generating the items `ast::Trait`
+/// describes and parsing them like any others: a struct wrapping
+/// `Box<dyn Trait>` with a method calling each of the trait's, and an imported
+/// type implementing the trait by calling the JS methods of the same names.
+fn export_trait(
+    t: &mut syn::ItemTrait,
+    opts: &BindgenAttrs,
+    program: &mut ast::Program,
+    tokens: &mut TokenStream,
+) -> Result<(), Diagnostic> {
+    if t.unsafety.is_some() {
+        bail_span!(t.unsafety, "#[wasm_bindgen] unsafe traits are not supported");
+    }
+    if t.auto_token.is_some() {
+        bail_span!(t.auto_token, "#[wasm_bindgen] auto traits are not supported");
+    }
+    if t.generics.params.len() > 0 || t.generics.where_clause.is_some() {
+        bail_span!(t.generics, "#[wasm_bindgen] generic traits aren't supported");
+    }
+    if t.supertraits.len() > 0 {
+        bail_span!(t.supertraits, "#[wasm_bindgen] traits can't have supertraits");
+    }
+    let name = t.ident.clone();
+    let js_class = opts
+        .js_name()
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| name.to_string());
+    let rust_struct = Ident::new(&format!("__wbg_dyn_{}", name), Span::call_site());
+    let js_type = Ident::new(&format!("__wbg_js_{}", name), Span::call_site());
+
+    let mut exports = Vec::<syn::ImplItem>::new();
+    let mut imports = Vec::<syn::ForeignItem>::new();
+    let mut forwards = Vec::<syn::ImplItem>::new();
+    let mut errors = Vec::new();
+    for item in t.items.iter_mut() {
+        let method = match item {
+            syn::TraitItem::Method(m) => m,
+            _ => {
+                errors.push(err_span!(
+                    item,
+                    "#[wasm_bindgen] traits can only contain methods"
+                ));
+                continue;
+            }
+        };
+        let sig = &method.sig;
+        if sig.constness.is_some() || sig.unsafety.is_some() || sig.abi.is_some() {
+            errors.push(err_span!(
+                sig.ident,
+                "the methods of #[wasm_bindgen] traits must be safe, non-const \
+                 functions"
+            ));
+            continue;
+        }
+        if sig.decl.generics.params.len() > 0 || sig.decl.generics.where_clause.is_some() {
+            errors.push(err_span!(
+                sig.decl.generics,
+                "the methods of #[wasm_bindgen] traits can't have lifetime or \
+                 type parameters"
+            ));
+            continue;
+        }
+        let mutability = match sig.decl.inputs.iter().next() {
+            Some(syn::FnArg::SelfRef(r)) => r.mutability,
+            _ => {
+                errors.push(err_span!(
+                    sig.ident,
+                    "the methods of #[wasm_bindgen] traits must take `&self` or \
+                     `&mut self`"
+                ));
+                continue;
+            }
+        };
+        let mut tys = Vec::new();
+        for arg in sig.decl.inputs.iter().skip(1) {
+            match arg {
+                syn::FnArg::Captured(c) => tys.push(c.ty.clone()),
+                _ => errors.push(err_span!(arg, "unsupported argument")),
+            }
+        }
+        let opts = BindgenAttrs::find(&mut method.attrs)?;
+        let sig = &method.sig;
+        let ident = &sig.ident;
+        let js_name = opts.js_name().map(|s| s.to_string()).unwrap_or_else(|| ident.to_string());
+        let shim = Ident::new(&format!("__wbg_{}", ident), Span::call_site());
+        let args = (0..tys.len())
+            .map(|i| Ident::new(&format!("arg{}", i), Span::call_site()))
+            .collect::<Vec<_>>();
+        let args = &args;
+        let tys = &tys;
+        let ret = &sig.decl.output;
+        let docs = method.attrs.iter().filter(|a| {
+            a.path.segments.iter().any(|s| s.ident.to_string() == "doc")
+        });
+        exports.push(parse_quote! {
+            #(#docs)*
+            #[wasm_bindgen(js_name = #js_name)]
+            pub fn #ident(&#mutability self, #(#args: #tys),*) #ret {
+                #name::#ident(&#mutability *self.0, #(#args),*)
+            }
+        });
+        imports.push(parse_quote! {
+            #[wasm_bindgen(method, structural, js_name = #js_name)]
+            fn #shim(this: &#js_type, #(#args: #tys),*) #ret;
+        });
+        forwards.push(parse_quote! {
+            fn #ident(&#mutability self, #(#args: #tys),*) #ret {
+                self.#shim(#(#args),*)
+            }
+        });
+    }
+    Diagnostic::from_vec(errors)?;
+
+    let items: Vec<syn::Item> = vec![
+        parse_quote! {
+            #[wasm_bindgen(js_name = #js_class)]
+            #[doc(hidden)]
+            #[allow(bad_style)]
+            pub struct #rust_struct(Box<dyn #name>);
+        },
+        parse_quote! {
+            #[wasm_bindgen(js_class = #js_class)]
+            impl #rust_struct {
+                #(#exports)*
+            }
+        },
+        parse_quote! {
+            extern {
+                #[doc(hidden)]
+                pub type #js_type;
+                #(#imports)*
+            }
+        },
+    ];
+    for mut item in items {
+        let opts = match item {
+            syn::Item::Struct(ref mut s) => Some(BindgenAttrs::find(&mut s.attrs)?),
+            syn::Item::Impl(ref mut i) => Some(BindgenAttrs::find(&mut i.attrs)?),
+            _ => None,
+        };
+        item.macro_parse(program, (opts, &mut *tokens))?;
+    }
+    (quote! {
+        impl #name for #js_type {
+            #(#forwards)*
+        }
+    }).to_tokens(tokens);
+    program.traits.push(ast::Trait {
+        name,
+        rust_struct,
+        js_type,
+    });
+    Ok(())
+}
+
+impl<'a> MacroParse<BindgenAttrs> for &'a mut syn::ItemImpl {
+    fn macro_parse(self, program: &mut ast::Program, opts: BindgenAttrs) -> Result<(), Diagnostic> {
         if self.defaultness.is_some() {
             bail_span!(
                 self.defaultness,
@@ -798,9 +1879,15 @@ impl<'a> MacroParse<()> for &'a mut syn::ItemImpl {
                 "unsupported self type in #[wasm_bindgen] impl"
             ),
         };
+        // The impl can't see the struct's attributes, so a struct exported
+        // under another name needs that name again here.
+        let js_class = opts
+            .js_class()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| name.to_string());
         let mut errors = Vec::new();
         for item in self.items.iter_mut() {
-            if let Err(e) = (&name, item).macro_parse(program, ()) {
+            if let Err(e) = (&name, &js_class, item).macro_parse(program, ()) {
                 errors.push(e);
             }
         }
@@ -808,9 +1895,9 @@ impl<'a> MacroParse<()> for &'a mut syn::ItemImpl {
     }
 }
 
-impl<'a, 'b> MacroParse<()> for (&'a Ident, &'b mut syn::ImplItem) {
+impl<'a, 'b, 'c> MacroParse<()> for (&'a Ident, &'b String, &'c mut syn::ImplItem) {
     fn macro_parse(self, program: &mut ast::Program, (): ()) -> Result<(), Diagnostic> {
-        let (class, item) = self;
+        let (class, js_class, item) = self;
         let method = match item {
             syn::ImplItem::Method(ref mut m) => m,
             syn::ImplItem::Const(_) => {
@@ -854,8 +1941,16 @@ impl<'a, 'b> MacroParse<()> for (&'a Ident, &'b mut syn::ImplItem) {
             None
         };
 
+        // Methods named after a symbol keep their Rust name internally, as
+        // symbols can't be part of the names of the generated shims.
+        let symbol = method_symbol(&opts, &method.sig.ident)?;
+        let rust_name = method.sig.ident.to_string();
+        let js_name = match symbol {
+            Some(_) => &rust_name,
+            None => opts.js_name().unwrap_or(&rust_name),
+        };
         let (function, method_self) = function_from_decl(
-            opts.js_name().unwrap_or(&method.sig.ident.to_string()),
+            js_name,
             Box::new(method.sig.decl.clone()),
             method.attrs.clone(),
             method.vis.clone(),
@@ -863,67 +1958,122 @@ impl<'a, 'b> MacroParse<()> for (&'a Ident, &'b mut syn::ImplItem) {
             Some(class),
         )?;
 
+        let defaults = argument_defaults(&opts, &function)?;
+        let (getter, setter) =
+            accessor_names(&opts, &method.sig.ident, &function, &method_self, &symbol)?;
+        let indexing = indexing_kind(&opts, &method.sig.ident, &function, &method_self)?;
         program.exports.push(ast::Export {
             class: Some(class.clone()),
+            js_class: Some(js_class.clone()),
             method_self,
             constructor,
             function,
             comments,
             rust_name: method.sig.ident.clone(),
+            optional_args: opts.optional_args(),
+            js_map: opts.js_map(),
+            defaults,
+            enumerable: opts.enumerable(),
+            configurable: !opts.non_configurable(),
+            is_async: false,
+            getter,
+            setter,
+            indexing,
+            symbol,
         });
         Ok(())
     }
 }
 
-impl MacroParse<()> for syn::ItemEnum {
+impl<'a> MacroParse<()> for &'a mut syn::ItemEnum {
     fn macro_parse(self, program: &mut ast::Program, (): ()) -> Result<(), Diagnostic> {
         match self.vis {
             syn::Visibility::Public(_) => {}
             _ => bail_span!(self, "only public enums are allowed with #[wasm_bindgen]"),
         }
 
-        let variants = self
-            .variants
-            .iter()
-            .enumerate()
-            .map(|(i, v)| {
-                match v.fields {
-                    syn::Fields::Unit => (),
-                    _ => bail_span!(v.fields, "only C-Style enums allowed with #[wasm_bindgen]"),
+        let has_fields = self.variants.iter().any(|v| match v.fields {
+            syn::Fields::Unit => false,
+            _ => true,
+        });
+        if has_fields {
+            program.unions.push(union_from_enum(self)?);
+            return Ok(());
+        }
+
+        // Variants without a discriminant come after the previous one, like
+        // they do in Rust.
+        let mut next_value = 0;
+        let mut string_values = Vec::new();
+        let mut variants = Vec::new();
+        for (i, v) in self.variants.iter_mut().enumerate() {
+            match v.fields {
+                syn::Fields::Unit => (),
+                _ => bail_span!(v.fields, "only C-Style enums allowed with #[wasm_bindgen]"),
+            }
+            let (value, string_value) = match v.discriminant {
+                Some((
+                    _,
+                    syn::Expr::Lit(syn::ExprLit {
+                        attrs: _,
+                        lit: syn::Lit::Int(ref int_lit),
+                    }),
+                )) => {
+                    if int_lit.value() > <u32>::max_value() as u64 {
+                        bail_span!(
+                            int_lit,
+                            "enums with #[wasm_bindgen] can only support \
+                             numbers that can be represented as u32"
+                        );
+                    }
+                    (int_lit.value() as u32, None)
                 }
-                let value = match v.discriminant {
-                    Some((
-                        _,
-                        syn::Expr::Lit(syn::ExprLit {
-                            attrs: _,
-                            lit: syn::Lit::Int(ref int_lit),
-                        }),
-                    )) => {
-                        if int_lit.value() > <u32>::max_value() as u64 {
-                            bail_span!(
-                                int_lit,
-                                "enums with #[wasm_bindgen] can only support \
-                                 numbers that can be represented as u32"
-                            );
-                        }
-                        int_lit.value() as u32
+                Some((
+                    _,
+                    syn::Expr::Lit(syn::ExprLit {
+                        attrs: _,
+                        lit: syn::Lit::Str(ref str_lit),
+                    }),
+                )) => {
+                    let string = str_lit.value();
+                    if string_values.contains(&string) {
+                        bail_span!(str_lit, "duplicate string value `{}` in enum", string);
                     }
-                    None => i as u32,
-                    Some((_, ref expr)) => bail_span!(
-                        expr,
-                        "enums with #[wasm_bidngen] may only have \
-                         number literal values",
-                    ),
-                };
-
-                Ok(ast::Variant {
-                    name: v.ident.clone(),
-                    value,
-                })
-            }).collect::<Result<_, Diagnostic>>()?;
+                    string_values.push(string.clone());
+                    (i as u32, Some(string))
+                }
+                None => (next_value, None),
+                Some((_, ref expr)) => bail_span!(
+                    expr,
+                    "enums with #[wasm_bidngen] may only have \
+                     number or string literal values",
+                ),
+            };
+            // Rust doesn't allow string discriminants, so they're only kept
+            // in the JS bindings.
+            if string_value.is_some() {
+                v.discriminant = None;
+            }
+            next_value = value.wrapping_add(1);
+            variants.push(ast::Variant {
+                name: v.ident.clone(),
+                value,
+                string_value,
+            });
+        }
+        if !string_values.is_empty() {
+            let numeric = variants.iter().position(|v| v.string_value.is_none());
+            if let Some(i) = numeric {
+                bail_span!(
+                    self.variants[i],
+                    "string enums with #[wasm_bindgen] must give every \
+                     variant a string value"
+                );
+            }
+        }
         let comments = extract_doc_comments(&self.attrs);
         program.enums.push(ast::Enum {
-            name: self.ident,
+            name: self.ident.clone(),
             variants,
             comments,
         });
@@ -931,6 +2081,73 @@ impl MacroParse<()> for syn::ItemEnum {
     }
 }
 
+/// Converts an enum whose variants each hold one value, which is received from
+/// JS as a value of any of the variants' types.
+fn union_from_enum(e: &syn::ItemEnum) -> Result<ast::Union, Diagnostic> {
+    if e.generics.params.len() > 0 {
+        bail_span!(
+            e.generics,
+            "enums with #[wasm_bindgen] cannot have lifetime or \
+             type parameters currently"
+        );
+    }
+    let variants = e
+        .variants
+        .iter()
+        .map(|v| {
+            let field = match &v.fields {
+                syn::Fields::Unnamed(f) if f.unnamed.len() == 1 => &f.unnamed[0],
+                _ => bail_span!(
+                    v,
+                    "enums with #[wasm_bindgen] must either be C-style or \
+                     have a single unnamed field in every variant"
+                ),
+            };
+            if let Some((_, expr)) = &v.discriminant {
+                bail_span!(expr, "enums with fields can't have discriminants");
+            }
+            if !is_union_variant_ty(&field.ty) {
+                bail_span!(
+                    field.ty,
+                    "variants of enums with #[wasm_bindgen] can only hold an \
+                     exported struct or a `String`"
+                );
+            }
+            Ok(ast::UnionVariant {
+                name: v.ident.clone(),
+                ty: field.ty.clone(),
+            })
+        }).collect::<Result<_, Diagnostic>>()?;
+    Ok(ast::Union {
+        name: e.ident.clone(),
+        variants,
+    })
+}
+
+/// Whether `ty` could be held by a variant of a union, which JS can only tell
+/// apart from the others if it's an exported struct or a `String`.
+///
+/// Which paths name exported structs isn't known until the CLI runs, so this
+/// only rules out anything else it can see, like primitives and references.
+fn is_union_variant_ty(ty: &syn::Type) -> bool {
+    let path = match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path,
+        _ => return false,
+    };
+    let last = match path.segments.last() {
+        Some(last) => last.into_value(),
+        None => return false,
+    };
+    if !last.arguments.is_empty() {
+        return false;
+    }
+    match &last.ident.to_string()[..] {
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64"
+        | "isize" | "f32" | "f64" | "bool" | "char" | "str" | "JsValue" => false,
+        _ => true,
+    }
+}
+
 impl MacroParse<BindgenAttrs> for syn::ItemForeignMod {
     fn macro_parse(self, program: &mut ast::Program, opts: BindgenAttrs) -> Result<(), Diagnostic> {
         let mut errors = Vec::new();
@@ -944,6 +2161,9 @@ impl MacroParse<BindgenAttrs> for syn::ItemForeignMod {
                 ));
             }
         }
+        if let Err(e) = opts.module() {
+            errors.push(e);
+        }
         for mut item in self.items.into_iter() {
             if let Err(e) = item.macro_parse(program, &opts) {
                 errors.push(e);
@@ -968,8 +2188,15 @@ impl<'a> MacroParse<&'a BindgenAttrs> for syn::ForeignItem {
             };
             BindgenAttrs::find(attrs)?
         };
-        let module = item_opts.module().or(opts.module()).map(|s| s.to_string());
+        // Duplicates in `opts` are reported once for the whole block
+        let module = match item_opts.module()? {
+            Some(module) => Some(module),
+            None => opts.module().unwrap_or_default(),
+        };
+        let node_module = item_opts.node_module().or(opts.node_module()).map(|s| s.to_string());
         let js_namespace = item_opts.js_namespace().or(opts.js_namespace()).cloned();
+        let vendor_prefix = item_opts.vendor_prefixes().next().cloned();
+        let polyfill = item_opts.polyfill().cloned();
         let kind = match self {
             syn::ForeignItem::Fn(f) => f.convert((item_opts, &module))?,
             syn::ForeignItem::Type(t) => t.convert(item_opts)?,
@@ -977,8 +2204,17 @@ impl<'a> MacroParse<&'a BindgenAttrs> for syn::ForeignItem {
             _ => panic!("only foreign functions/types allowed for now"),
         };
 
+        let global = module.is_none() && node_module.is_none() && js_namespace.is_none();
+        if let Some(prefix) = vendor_prefix {
+            check_global_fallback(&prefix, "vendor_prefix", &kind, global)?;
+        }
+        if let Some(polyfill) = polyfill {
+            check_global_fallback(&polyfill, "polyfill", &kind, global)?;
+        }
+
         program.imports.push(ast::Import {
             module,
+            node_module,
             js_namespace,
             kind,
         });
@@ -987,6 +2223,40 @@ impl<'a> MacroParse<&'a BindgenAttrs> for syn::ForeignItem {
     }
 }
 
+/// Checks that an attribute which gives an import something to fall back on,
+/// like `vendor_prefix`, is only used on an import of a global, as the
+/// fallbacks are only looked for when the global itself is missing.
+fn check_global_fallback(
+    attr: &ToTokens,
+    name: &str,
+    kind: &ast::ImportKind,
+    global: bool,
+) -> Result<(), Diagnostic> {
+    let supported = match kind {
+        ast::ImportKind::Function(f) => match f.kind {
+            ast::ImportFunctionKind::Normal => true,
+            ast::ImportFunctionKind::Method { .. } => false,
+        },
+        ast::ImportKind::Type(_) => true,
+        _ => false,
+    };
+    if !supported {
+        bail_span!(
+            attr,
+            "{} can only be used on imported types and free functions",
+            name
+        );
+    }
+    if !global {
+        bail_span!(
+            attr,
+            "{} is only supported for globals, not with `module` or `js_namespace`",
+            name
+        );
+    }
+    Ok(())
+}
+
 /// Get the first type parameter of a generic type, errors on incorrect input.
 fn extract_first_ty_param(ty: Option<&syn::Type>) -> Result<Option<syn::Type>, Diagnostic> {
     let t = match ty {
@@ -0,0 +1,129 @@
+//! Snapshots of the code `#[wasm_bindgen]` generates.
+//!
+//! Each `.rs` file in `tests/expand` is expanded item by item, the way the
+//! compiler would, and the (rustfmt'd) result is checked against the
+//! `.expanded.rs` file next to it, so that any change to the generated code
+//! shows up as a diff of those files. Running the tests with
+//! `MACRO_SNAPSHOTS=update` writes the snapshots instead of checking them.
+
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+extern crate wasm_bindgen_macro_support;
+extern crate wasm_bindgen_shared;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::ToTokens;
+use wasm_bindgen_shared::snapshot;
+
+/// Expands every `#[wasm_bindgen]` item in `source`, leaving any other items
+/// as they are.
+fn expand(source: &str) -> TokenStream {
+    let file = match syn::parse_file(source) {
+        Ok(file) => file,
+        Err(e) => panic!("failed to parse input: {}", e),
+    };
+    let mut tokens = TokenStream::new();
+    for mut item in file.items {
+        let attr = take_wasm_bindgen_attr(&mut item);
+        let input = item.into_token_stream();
+        match attr {
+            Some(attr) => match wasm_bindgen_macro_support::expand(attr, input) {
+                Ok(expanded) => tokens.extend(expanded),
+                Err(diagnostic) => diagnostic.panic(),
+            },
+            None => tokens.extend(input),
+        }
+    }
+    tokens
+}
+
+/// Removes the first `#[wasm_bindgen]` attribute from `item`, returning the
+/// tokens inside its parentheses, which is what the macro is passed.
+fn take_wasm_bindgen_attr(item: &mut syn::Item) -> Option<TokenStream> {
+    let attrs = match item {
+        syn::Item::Fn(i) => &mut i.attrs,
+        syn::Item::Struct(i) => &mut i.attrs,
+        syn::Item::Enum(i) => &mut i.attrs,
+        syn::Item::Impl(i) => &mut i.attrs,
+        syn::Item::ForeignMod(i) => &mut i.attrs,
+        syn::Item::Const(i) => &mut i.attrs,
+        syn::Item::Static(i) => &mut i.attrs,
+        syn::Item::Trait(i) => &mut i.attrs,
+        _ => return None,
+    };
+    let index = attrs.iter().position(|attr| {
+        attr.path.segments.len() == 1 && attr.path.segments[0].ident == "wasm_bindgen"
+    })?;
+    let attr = attrs.remove(index);
+    Some(match attr.tts.into_iter().next() {
+        Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::Parenthesis => {
+            group.stream()
+        }
+        _ => TokenStream::new(),
+    })
+}
+
+/// Checks the expansion of the file at `path` against its snapshot, or
+/// updates the snapshot, returning a description of the problem if they
+/// differ.
+fn check(path: &Path) -> Result<(), String> {
+    let snapshot = path.with_extension("expanded.rs");
+    let source = fs::read_to_string(path).unwrap();
+    let expanded = expand(&source).to_string();
+
+    let expanded = snapshot::format(&expanded)
+        .map_err(|e| format!("failed to format the expansion of {}: {}", path.display(), e))?;
+
+    let actual = path.with_extension("expanded.rs.actual");
+    drop(fs::remove_file(&actual));
+    if env::var("MACRO_SNAPSHOTS").ok().as_ref().map(|s| &s[..]) == Some("update") {
+        fs::write(&snapshot, expanded).unwrap();
+        return Ok(());
+    }
+
+    let expected = fs::read_to_string(&snapshot).unwrap_or_default();
+    if expected != expanded {
+        fs::write(&actual, &expanded).unwrap();
+        return Err(format!(
+            "the expansion of {} doesn't match {}, it was written to {}",
+            path.display(),
+            snapshot.display(),
+            actual.display(),
+        ));
+    }
+    Ok(())
+}
+
+// The macro numbers some of the symbols it generates and only emits each
+// descriptor once per process, so the files are all expanded by one test, in
+// the same order every time.
+#[test]
+fn snapshots() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/expand");
+    let mut inputs = fs::read_dir(&dir)
+        .unwrap()
+        .map(|f| f.unwrap().path())
+        .filter(|f| {
+            let name = f.file_name().unwrap().to_str().unwrap();
+            name.ends_with(".rs") && !name.ends_with(".expanded.rs")
+        })
+        .collect::<Vec<_>>();
+    inputs.sort();
+
+    let errors = inputs
+        .iter()
+        .filter_map(|path| check(path).err())
+        .collect::<Vec<_>>();
+    if !errors.is_empty() {
+        panic!(
+            "{}\n\nif the changes are intended then rerun the tests with \
+             MACRO_SNAPSHOTS=update to update the snapshots",
+            errors.join("\n"),
+        );
+    }
+}
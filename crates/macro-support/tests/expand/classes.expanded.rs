@@ -0,0 +1,339 @@
+pub struct Counter {
+    pub step: u32,
+    count: u32,
+}
+impl ::wasm_bindgen::describe::WasmDescribe for Counter {
+    const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+        use wasm_bindgen::describe::*;
+        Description::Concat(&[Description::Numbers(&[
+            RUST_STRUCT,
+            7u32,
+            67u32,
+            111u32,
+            117u32,
+            110u32,
+            116u32,
+            101u32,
+            114u32,
+        ])])
+    };
+    fn describe() {
+        use wasm_bindgen::describe::*;
+        inform(RUST_STRUCT);
+        inform(7u32);
+        inform(67u32);
+        inform(111u32);
+        inform(117u32);
+        inform(110u32);
+        inform(116u32);
+        inform(101u32);
+        inform(114u32);
+    }
+}
+impl ::wasm_bindgen::convert::IntoWasmAbi for Counter {
+    type Abi = u32;
+    fn into_abi(self, _extra: &mut ::wasm_bindgen::convert::Stack) -> u32 {
+        use wasm_bindgen::__wbindgen_if_not_std;
+        __wbindgen_if_not_std! { compile_error ! { "exporting a class to JS requires the `std` feature to \
+        be enabled in the `wasm-bindgen` crate" } }
+        use wasm_bindgen::__rt::std::boxed::Box;
+        use wasm_bindgen::__rt::WasmRefCell;
+        Box::into_raw(Box::new(WasmRefCell::new(self))) as u32
+    }
+}
+impl ::wasm_bindgen::convert::FromWasmAbi for Counter {
+    type Abi = u32;
+    unsafe fn from_abi(js: u32, _extra: &mut ::wasm_bindgen::convert::Stack) -> Self {
+        use wasm_bindgen::__rt::std::boxed::Box;
+        use wasm_bindgen::__rt::{assert_not_null, WasmRefCell};
+        let ptr = js as *mut WasmRefCell<Counter>;
+        assert_not_null(ptr);
+        let js = Box::from_raw(ptr);
+        js.borrow_mut();
+        js.into_inner()
+    }
+}
+impl ::wasm_bindgen::__rt::core::convert::From<Counter> for ::wasm_bindgen::JsValue {
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    fn from(value: Counter) -> Self {
+        let ptr = ::wasm_bindgen::convert::IntoWasmAbi::into_abi(value, unsafe {
+            &mut ::wasm_bindgen::convert::GlobalStack::new()
+        });
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __wbg_counter_new(ptr: u32) -> u32;
+        }
+        unsafe {
+            <::wasm_bindgen::JsValue as ::wasm_bindgen::convert::FromWasmAbi>::from_abi(
+                __wbg_counter_new(ptr),
+                &mut ::wasm_bindgen::convert::GlobalStack::new(),
+            )
+        }
+    }
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    fn from(_value: Counter) -> Self {
+        panic!("cannot convert to JsValue outside of the wasm target")
+    }
+}
+impl ::wasm_bindgen::convert::OptionIntoWasmAbi for Counter {
+    fn none() -> u32 {
+        0
+    }
+}
+impl ::wasm_bindgen::convert::OptionFromWasmAbi for Counter {
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+impl ::wasm_bindgen::convert::VectorIntoWasmAbi for Counter {}
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+#[no_mangle]
+#[doc(hidden)]
+pub unsafe extern "C" fn __wbg_counter_free(ptr: u32) {
+    <Counter as ::wasm_bindgen::convert::FromWasmAbi>::from_abi(
+        ptr,
+        &mut ::wasm_bindgen::convert::GlobalStack::new(),
+    );
+}
+impl ::wasm_bindgen::convert::RefFromWasmAbi for Counter {
+    type Abi = u32;
+    type Anchor = ::wasm_bindgen::__rt::Ref<'static, Counter>;
+    unsafe fn ref_from_abi(
+        js: Self::Abi,
+        _extra: &mut ::wasm_bindgen::convert::Stack,
+    ) -> Self::Anchor {
+        let js = js as *mut ::wasm_bindgen::__rt::WasmRefCell<Counter>;
+        ::wasm_bindgen::__rt::assert_not_null(js);
+        (*js).borrow()
+    }
+}
+impl ::wasm_bindgen::convert::RefMutFromWasmAbi for Counter {
+    type Abi = u32;
+    type Anchor = ::wasm_bindgen::__rt::RefMut<'static, Counter>;
+    unsafe fn ref_mut_from_abi(
+        js: Self::Abi,
+        _extra: &mut ::wasm_bindgen::convert::Stack,
+    ) -> Self::Anchor {
+        let js = js as *mut ::wasm_bindgen::__rt::WasmRefCell<Counter>;
+        ::wasm_bindgen::__rt::assert_not_null(js);
+        (*js).borrow_mut()
+    }
+}
+impl ::wasm_bindgen::convert::OptionRefFromWasmAbi for Counter {
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+impl ::wasm_bindgen::convert::OptionRefMutFromWasmAbi for Counter {
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+#[no_mangle]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub unsafe extern "C" fn __wbg_get_counter_step(
+    js: u32,
+) -> <u32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi {
+    use wasm_bindgen::__rt::{assert_not_null, return_abi, WasmRefCell};
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<u32>();
+    let js = js as *mut WasmRefCell<Counter>;
+    assert_not_null(js);
+    let val = (*js).borrow().step;
+    return_abi::<u32>(val)
+}
+#[doc = "Describes the type of `__wbg_get_counter_step` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___wbg_get_counter_step() {
+    use wasm_bindgen::describe::*;
+    <u32 as WasmDescribe>::describe();
+}
+#[no_mangle]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub unsafe extern "C" fn __wbg_set_counter_step(
+    js: u32,
+    val: <u32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi,
+) {
+    use wasm_bindgen::__rt::{arg_from_abi, assert_not_null, WasmRefCell};
+    use wasm_bindgen::convert::GlobalStack;
+    let js = js as *mut WasmRefCell<Counter>;
+    assert_not_null(js);
+    let val = arg_from_abi::<u32>(val, &mut GlobalStack::new());
+    (*js).borrow_mut().step = val;
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_4120528f6aa7c09c : [ u8 ; 407usize ] = * b"\x93\x01\0\0{\"exports\":[],\"enums\":[],\"imports\":[],\"structs\":[{\"name\":\"Counter\",\"rust_name\":\"Counter\",\"fields\":[{\"name\":\"step\",\"readonly\":false,\"enumerable\":false,\"configurable\":true,\"comments\":[]}],\"comments\":[],\"extends\":null,\"extends_rust\":false,\"custom_element\":null,\"async_iterator\":false,\"iterator\":false,\"shared_class\":null}],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
+impl Counter {
+    pub fn new(step: u32) -> Counter {
+        Counter { step, count: 0 }
+    }
+    pub fn bump(&mut self) -> u32 {
+        self.count += self.step;
+        self.count
+    }
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+#[doc = "The shim which the generated JS calls for `Counter::new`, converting its arguments and return value to and from the wasm ABI."]
+#[export_name = "counter_new"]
+#[allow(non_snake_case)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wasm_bindgen_generated_Counter_new(
+    arg0: <u32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi,
+) -> <Counter as ::wasm_bindgen::convert::IntoWasmAbi>::Abi {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    ::wasm_bindgen::__rt::catch_unwind(move || {
+        let _ret = {
+            let mut __stack = unsafe { ::wasm_bindgen::convert::GlobalStack::new() };
+            let arg0 = unsafe { ::wasm_bindgen::__rt::arg_from_abi::<u32>(arg0, &mut __stack) };
+            Counter::new(arg0)
+        };
+        ::wasm_bindgen::__rt::return_abi::<Counter>(_ret)
+    })
+}
+#[doc = "Describes the type of `counter_new` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe_counter_new() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <u32 as WasmDescribe>::describe();
+    inform(1);
+    <Counter as WasmDescribe>::describe();
+}
+#[doc = "The shim which the generated JS calls for `Counter::bump`, converting its arguments and return value to and from the wasm ABI."]
+#[export_name = "counter_bump"]
+#[allow(non_snake_case)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wasm_bindgen_generated_Counter_bump(
+    me: u32,
+) -> <u32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    ::wasm_bindgen::__rt::catch_unwind(move || {
+        let _ret = {
+            let mut __stack = unsafe { ::wasm_bindgen::convert::GlobalStack::new() };
+            let mut me =
+                unsafe { ::wasm_bindgen::__rt::ref_mut_from_abi::<Counter>(me, &mut __stack) };
+            let me = &mut *me;
+            me.bump()
+        };
+        ::wasm_bindgen::__rt::return_abi::<u32>(_ret)
+    })
+}
+#[doc = "Describes the type of `counter_bump` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe_counter_bump() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <u32 as WasmDescribe>::describe();
+}
+#[doc = "The shim which the generated JS calls for `Counter::count`, converting its arguments and return value to and from the wasm ABI."]
+#[export_name = "counter_count"]
+#[allow(non_snake_case)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wasm_bindgen_generated_Counter_count(
+    me: u32,
+) -> <u32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    ::wasm_bindgen::__rt::catch_unwind(move || {
+        let _ret = {
+            let mut __stack = unsafe { ::wasm_bindgen::convert::GlobalStack::new() };
+            let me = unsafe { ::wasm_bindgen::__rt::ref_from_abi::<Counter>(me, &mut __stack) };
+            let me = &*me;
+            me.count()
+        };
+        ::wasm_bindgen::__rt::return_abi::<u32>(_ret)
+    })
+}
+#[doc = "Describes the type of `counter_count` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe_counter_count() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <u32 as WasmDescribe>::describe();
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_d92e01f4339e4572 : [ u8 ; 980usize ] = * b"\xD0\x03\0\0{\"exports\":[{\"class\":\"Counter\",\"method\":false,\"consumed\":false,\"constructor\":\"new\",\"function\":{\"name\":\"new\"},\"rust_name\":\"new\",\"comments\":[],\"optional_args\":false,\"js_map\":false,\"defaults\":[null],\"enumerable\":false,\"configurable\":true,\"getter\":null,\"setter\":null,\"indexing\":null,\"symbol\":null},{\"class\":\"Counter\",\"method\":true,\"consumed\":false,\"constructor\":null,\"function\":{\"name\":\"bump\"},\"rust_name\":\"bump\",\"comments\":[],\"optional_args\":false,\"js_map\":false,\"defaults\":[],\"enumerable\":false,\"configurable\":true,\"getter\":null,\"setter\":null,\"indexing\":null,\"symbol\":null},{\"class\":\"Counter\",\"method\":true,\"consumed\":false,\"constructor\":null,\"function\":{\"name\":\"count\"},\"rust_name\":\"count\",\"comments\":[],\"optional_args\":false,\"js_map\":false,\"defaults\":[],\"enumerable\":false,\"configurable\":true,\"getter\":\"count\",\"setter\":null,\"indexing\":null,\"symbol\":null}],\"enums\":[],\"imports\":[],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
+pub enum Color {
+    Red,
+    Green = 10,
+}
+impl Color {
+    #[doc = r" Attempts to convert a JS value back into this enum,"]
+    #[doc = r" returning `None` if the value is not a number matching"]
+    #[doc = r" one of the enum's discriminants."]
+    #[allow(dead_code)]
+    pub fn from_js_value(obj: &::wasm_bindgen::JsValue) -> Option<Color> {
+        let n = obj.as_f64()?;
+        if n == Color::Red as u32 as f64 {
+            Some(Color::Red)
+        } else if n == Color::Green as u32 as f64 {
+            Some(Color::Green)
+        } else {
+            None
+        }
+    }
+}
+impl From<Color> for ::wasm_bindgen::JsValue {
+    fn from(value: Color) -> ::wasm_bindgen::JsValue {
+        ::wasm_bindgen::JsValue::from_f64((value as u32).into())
+    }
+}
+impl ::wasm_bindgen::convert::IntoWasmAbi for Color {
+    type Abi = u32;
+    fn into_abi(self, _extra: &mut ::wasm_bindgen::convert::Stack) -> u32 {
+        self as u32
+    }
+}
+impl ::wasm_bindgen::convert::FromWasmAbi for Color {
+    type Abi = u32;
+    unsafe fn from_abi(js: u32, _extra: &mut ::wasm_bindgen::convert::Stack) -> Self {
+        if js == Color::Red as u32 {
+            Color::Red
+        } else if js == Color::Green as u32 {
+            Color::Green
+        } else {
+            ::wasm_bindgen::throw("invalid enum value passed")
+        }
+    }
+}
+impl ::wasm_bindgen::describe::WasmDescribe for Color {
+    const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+        use wasm_bindgen::describe::*;
+        Description::Concat(&[Description::Numbers(&[ENUM])])
+    };
+    fn describe() {
+        use wasm_bindgen::describe::*;
+        inform(ENUM);
+    }
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_9ee266be154cac23 : [ u8 ; 274usize ] = * b"\x0E\x01\0\0{\"exports\":[],\"enums\":[{\"name\":\"Color\",\"variants\":[{\"name\":\"Red\",\"value\":0,\"string_value\":null},{\"name\":\"Green\",\"value\":10,\"string_value\":null}],\"comments\":[]}],\"imports\":[],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
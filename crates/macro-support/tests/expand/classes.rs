@@ -0,0 +1,29 @@
+#[wasm_bindgen]
+pub struct Counter {
+    pub step: u32,
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl Counter {
+    #[wasm_bindgen(constructor)]
+    pub fn new(step: u32) -> Counter {
+        Counter { step, count: 0 }
+    }
+
+    pub fn bump(&mut self) -> u32 {
+        self.count += self.step;
+        self.count
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+#[wasm_bindgen]
+pub enum Color {
+    Red,
+    Green = 10,
+}
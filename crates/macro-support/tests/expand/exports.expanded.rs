@@ -0,0 +1,123 @@
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+#[doc = "The shim which the generated JS calls for `add`, converting its arguments and return value to and from the wasm ABI."]
+#[export_name = "add"]
+#[allow(non_snake_case)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wasm_bindgen_generated_add(
+    arg0: <u32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi,
+    arg1: <u32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi,
+) -> <u32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    ::wasm_bindgen::__rt::catch_unwind(move || {
+        let _ret = {
+            let mut __stack = unsafe { ::wasm_bindgen::convert::GlobalStack::new() };
+            let arg0 = unsafe { ::wasm_bindgen::__rt::arg_from_abi::<u32>(arg0, &mut __stack) };
+            let arg1 = unsafe { ::wasm_bindgen::__rt::arg_from_abi::<u32>(arg1, &mut __stack) };
+            add(arg0, arg1)
+        };
+        ::wasm_bindgen::__rt::return_abi::<u32>(_ret)
+    })
+}
+#[doc = "Describes the type of `add` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe_add() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <u32 as WasmDescribe>::describe();
+    <u32 as WasmDescribe>::describe();
+    inform(1);
+    <u32 as WasmDescribe>::describe();
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_4d7f083dd4f9b771 : [ u8 ; 418usize ] = * b"\x9E\x01\0\0{\"exports\":[{\"class\":null,\"method\":false,\"consumed\":false,\"constructor\":null,\"function\":{\"name\":\"add\"},\"rust_name\":\"add\",\"comments\":[],\"optional_args\":false,\"js_map\":false,\"defaults\":[null,null],\"enumerable\":false,\"configurable\":true,\"getter\":null,\"setter\":null,\"indexing\":null,\"symbol\":null}],\"enums\":[],\"imports\":[],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
+pub fn greet(name: &str) -> String {
+    format!("hello {}", name)
+}
+#[doc = "The shim which the generated JS calls for `greet`, converting its arguments and return value to and from the wasm ABI."]
+#[export_name = "greetUser"]
+#[allow(non_snake_case)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wasm_bindgen_generated_greetUser(
+    arg0: <str as ::wasm_bindgen::convert::RefFromWasmAbi>::Abi,
+) -> <String as ::wasm_bindgen::convert::IntoWasmAbi>::Abi {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    ::wasm_bindgen::__rt::catch_unwind(move || {
+        let _ret = {
+            let mut __stack = unsafe { ::wasm_bindgen::convert::GlobalStack::new() };
+            let arg0 = unsafe { ::wasm_bindgen::__rt::ref_from_abi::<str>(arg0, &mut __stack) };
+            let arg0 = &*arg0;
+            greet(arg0)
+        };
+        ::wasm_bindgen::__rt::return_abi::<String>(_ret)
+    })
+}
+#[doc = "Describes the type of `greetUser` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe_greetUser() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&str as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_b0d16307485bf287 : [ u8 ; 421usize ] = * b"\xA1\x01\0\0{\"exports\":[{\"class\":null,\"method\":false,\"consumed\":false,\"constructor\":null,\"function\":{\"name\":\"greetUser\"},\"rust_name\":\"greet\",\"comments\":[],\"optional_args\":false,\"js_map\":false,\"defaults\":[null],\"enumerable\":false,\"configurable\":true,\"getter\":null,\"setter\":null,\"indexing\":null,\"symbol\":null}],\"enums\":[],\"imports\":[],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
+pub fn first_byte(bytes: &[u8], fallback: Option<u8>) -> Option<u8> {
+    bytes.first().cloned().or(fallback)
+}
+#[doc = "The shim which the generated JS calls for `first_byte`, converting its arguments and return value to and from the wasm ABI."]
+#[export_name = "first_byte"]
+#[allow(non_snake_case)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wasm_bindgen_generated_first_byte(
+    arg0: <[u8] as ::wasm_bindgen::convert::RefFromWasmAbi>::Abi,
+    arg1: <Option<u8> as ::wasm_bindgen::convert::FromWasmAbi>::Abi,
+) -> <Option<u8> as ::wasm_bindgen::convert::IntoWasmAbi>::Abi {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    ::wasm_bindgen::__rt::catch_unwind(move || {
+        let _ret = {
+            let mut __stack = unsafe { ::wasm_bindgen::convert::GlobalStack::new() };
+            let arg0 = unsafe { ::wasm_bindgen::__rt::ref_from_abi::<[u8]>(arg0, &mut __stack) };
+            let arg0 = &*arg0;
+            let arg1 =
+                unsafe { ::wasm_bindgen::__rt::arg_from_abi::<Option<u8>>(arg1, &mut __stack) };
+            first_byte(arg0, arg1)
+        };
+        ::wasm_bindgen::__rt::return_abi::<Option<u8>>(_ret)
+    })
+}
+#[doc = "Describes the type of `first_byte` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe_first_byte() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&[u8] as WasmDescribe>::describe();
+    <Option<u8> as WasmDescribe>::describe();
+    inform(1);
+    <Option<u8> as WasmDescribe>::describe();
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_ebcf5e0ecc876657 : [ u8 ; 432usize ] = * b"\xAC\x01\0\0{\"exports\":[{\"class\":null,\"method\":false,\"consumed\":false,\"constructor\":null,\"function\":{\"name\":\"first_byte\"},\"rust_name\":\"first_byte\",\"comments\":[],\"optional_args\":false,\"js_map\":false,\"defaults\":[null,null],\"enumerable\":false,\"configurable\":true,\"getter\":null,\"setter\":null,\"indexing\":null,\"symbol\":null}],\"enums\":[],\"imports\":[],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
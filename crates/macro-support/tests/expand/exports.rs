@@ -0,0 +1,14 @@
+#[wasm_bindgen]
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+#[wasm_bindgen(js_name = greetUser)]
+pub fn greet(name: &str) -> String {
+    format!("hello {}", name)
+}
+
+#[wasm_bindgen]
+pub fn first_byte(bytes: &[u8], fallback: Option<u8>) -> Option<u8> {
+    bytes.first().cloned().or(fallback)
+}
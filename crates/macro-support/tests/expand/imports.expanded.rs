@@ -0,0 +1,318 @@
+#[doc = "Describes the type of `__wbg_log_6059a798dda62eb2` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___wbg_log_6059a798dda62eb2() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&str as WasmDescribe>::describe();
+    inform(0);
+}
+#[allow(bad_style)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+#[doc = ""]
+fn log(message: &str) {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    #[link(wasm_import_module = "__wbindgen_placeholder__")]
+    extern "C" {
+        fn __wbg_log_6059a798dda62eb2(
+            message: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+        ) -> ();
+    }
+    unsafe {
+        let _ret = {
+            let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+            let message = ::wasm_bindgen::__rt::arg_into_abi::<&str>(message, &mut __stack);
+            __wbg_log_6059a798dda62eb2(message)
+        };
+        ()
+    }
+}
+#[allow(bad_style, unused_variables)]
+#[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+#[doc = ""]
+fn log(message: &str) {
+    ::wasm_bindgen::__rt::import_on_non_wasm()
+}
+#[doc = "Describes the type of `__wbg_parse_a9165e612bfdef78` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___wbg_parse_a9165e612bfdef78() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&str as WasmDescribe>::describe();
+    inform(1);
+    <JsValue as WasmDescribe>::describe();
+}
+#[allow(bad_style)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+#[doc = ""]
+fn parse(input: &str) -> Result<JsValue, JsValue> {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    #[link(wasm_import_module = "__wbindgen_placeholder__")]
+    extern "C" {
+        fn __wbg_parse_a9165e612bfdef78(
+            input: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            exn_data_ptr: *mut u32,
+        ) -> <JsValue as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+    }
+    unsafe {
+        let mut exn_data = [0; 2];
+        let exn_data_ptr = exn_data.as_mut_ptr();
+        let _ret = {
+            let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+            let input = ::wasm_bindgen::__rt::arg_into_abi::<&str>(input, &mut __stack);
+            __wbg_parse_a9165e612bfdef78(input, exn_data_ptr)
+        };
+        ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+        Ok(::wasm_bindgen::__rt::from_return_abi::<JsValue>(_ret))
+    }
+}
+#[allow(bad_style, unused_variables)]
+#[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+#[doc = ""]
+fn parse(input: &str) -> Result<JsValue, JsValue> {
+    ::wasm_bindgen::__rt::import_on_non_wasm()
+}
+#[allow(bad_style)]
+#[doc = ""]
+#[repr(transparent)]
+struct Widget {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `Widget` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_Widget: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for Widget {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for Widget {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for Widget {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a Widget {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for Widget {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            Widget {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for Widget {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a Widget {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for Widget {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<Widget>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(Widget {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for Widget {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for Widget {
+        fn from(obj: JsValue) -> Widget {
+            Widget { obj }
+        }
+    }
+    impl AsRef<JsValue> for Widget {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for Widget {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<Widget> for JsValue {
+        fn from(obj: Widget) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for Widget {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __wbg_instanceof_Widget_f47100563fbe9226(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __wbg_instanceof_Widget_f47100563fbe9226(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            Widget { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const Widget) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut Widget) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__wbg_new_3bcfa20720f47406` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___wbg_new_3bcfa20720f47406() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&str as WasmDescribe>::describe();
+    inform(1);
+    <Widget as WasmDescribe>::describe();
+}
+impl Widget {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = ""]
+    fn new(name: &str) -> Widget {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __wbg_new_3bcfa20720f47406(
+                name: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Widget as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let name = ::wasm_bindgen::__rt::arg_into_abi::<&str>(name, &mut __stack);
+                __wbg_new_3bcfa20720f47406(name)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Widget>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = ""]
+    fn new(name: &str) -> Widget {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__wbg_name_800a7be9cec85f31` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___wbg_name_800a7be9cec85f31() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Widget as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl Widget {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = ""]
+    fn name(&self) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __wbg_name_800a7be9cec85f31(
+                this: <&Widget as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let this = ::wasm_bindgen::__rt::arg_into_abi::<&Widget>(self, &mut __stack);
+                __wbg_name_800a7be9cec85f31(this)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = ""]
+    fn name(&self) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+static DEFAULT_WIDGET: ::wasm_bindgen::JsStatic<Widget> = {
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    fn init() -> Widget {
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __wbg_static_accessor_DEFAULTWIDGET_DEFAULT_WIDGET(
+            ) -> <Widget as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            ::wasm_bindgen::__rt::from_return_abi::<Widget>(
+                __wbg_static_accessor_DEFAULTWIDGET_DEFAULT_WIDGET(),
+            )
+        }
+    }
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    fn init() -> Widget {
+        panic!("cannot access imported statics on non-wasm targets")
+    }
+    static mut _VAL: ::wasm_bindgen::__rt::core::cell::UnsafeCell<Option<Widget>> =
+        ::wasm_bindgen::__rt::core::cell::UnsafeCell::new(None);
+    ::wasm_bindgen::JsStatic {
+        __inner: unsafe { &_VAL },
+        __init: init,
+    }
+};
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_6e05eb82ead71ccb : [ u8 ; 1856usize ] = * b"<\x07\0\0{\"exports\":[],\"enums\":[],\"imports\":[{\"module\":\"./widgets\",\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__wbg_log_6059a798dda62eb2\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":null,\"structural\":false,\"function\":{\"name\":\"log\"}}},{\"module\":\"./widgets\",\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__wbg_parse_a9165e612bfdef78\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":null,\"structural\":false,\"function\":{\"name\":\"parse\"}}},{\"module\":\"./widgets\",\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"Widget\",\"rust_name\":\"Widget\",\"instanceof_shim\":\"__wbg_instanceof_Widget_f47100563fbe9226\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":\"./widgets\",\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__wbg_new_3bcfa20720f47406\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Widget\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":\"./widgets\",\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__wbg_name_800a7be9cec85f31\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Widget\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"name\"}}}},\"structural\":false,\"function\":{\"name\":\"name\"}}},{\"module\":\"./widgets\",\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"static\",\"name\":\"DEFAULT_WIDGET\",\"shim\":\"__wbg_static_accessor_DEFAULTWIDGET_DEFAULT_WIDGET\"}}],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
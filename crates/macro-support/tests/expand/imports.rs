@@ -0,0 +1,15 @@
+#[wasm_bindgen(module = "./widgets")]
+extern {
+    fn log(message: &str);
+
+    #[wasm_bindgen(catch)]
+    fn parse(input: &str) -> Result<JsValue, JsValue>;
+
+    type Widget;
+    #[wasm_bindgen(constructor)]
+    fn new(name: &str) -> Widget;
+    #[wasm_bindgen(method, getter)]
+    fn name(this: &Widget) -> String;
+
+    static DEFAULT_WIDGET: Widget;
+}
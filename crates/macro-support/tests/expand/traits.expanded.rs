@@ -0,0 +1,501 @@
+#[doc(hidden)]
+#[allow(bad_style)]
+pub struct __wbg_dyn_Shape(Box<dyn Shape>);
+impl __wbg_dyn_Shape {
+    #[doc = " The area of the shape."]
+    pub fn area(&self) -> f64 {
+        Shape::area(&*self.0)
+    }
+    pub fn scale_by(&mut self, arg0: f64) {
+        Shape::scale_by(&mut *self.0, arg0)
+    }
+}
+impl Shape for __wbg_js_Shape {
+    fn area(&self) -> f64 {
+        self.__wbg_area()
+    }
+    fn scale_by(&mut self, arg0: f64) {
+        self.__wbg_scale_by(arg0)
+    }
+}
+pub trait Shape {
+    #[doc = " The area of the shape."]
+    fn area(&self) -> f64;
+    fn scale_by(&mut self, by: f64);
+}
+#[doc = " The area of the shape."]
+#[doc = "The shim which the generated JS calls for `__wbg_dyn_Shape::area`, converting its arguments and return value to and from the wasm ABI."]
+#[export_name = "shape_area"]
+#[allow(non_snake_case)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wasm_bindgen_generated___wbg_dyn_Shape_area(
+    me: u32,
+) -> <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    ::wasm_bindgen::__rt::catch_unwind(move || {
+        let _ret = {
+            let mut __stack = unsafe { ::wasm_bindgen::convert::GlobalStack::new() };
+            let me =
+                unsafe { ::wasm_bindgen::__rt::ref_from_abi::<__wbg_dyn_Shape>(me, &mut __stack) };
+            let me = &*me;
+            me.area()
+        };
+        ::wasm_bindgen::__rt::return_abi::<f64>(_ret)
+    })
+}
+#[doc = "Describes the type of `shape_area` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe_shape_area() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <f64 as WasmDescribe>::describe();
+}
+#[doc = "The shim which the generated JS calls for `__wbg_dyn_Shape::scale_by`, converting its arguments and return value to and from the wasm ABI."]
+#[export_name = "shape_scaleBy"]
+#[allow(non_snake_case)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wasm_bindgen_generated___wbg_dyn_Shape_scaleBy(
+    me: u32,
+    arg1: <f64 as ::wasm_bindgen::convert::FromWasmAbi>::Abi,
+) {
+    ::wasm_bindgen::__rt::link_mem_intrinsics();
+    ::wasm_bindgen::__rt::catch_unwind(move || {
+        let _ret = {
+            let mut __stack = unsafe { ::wasm_bindgen::convert::GlobalStack::new() };
+            let mut me = unsafe {
+                ::wasm_bindgen::__rt::ref_mut_from_abi::<__wbg_dyn_Shape>(me, &mut __stack)
+            };
+            let me = &mut *me;
+            let arg1 = unsafe { ::wasm_bindgen::__rt::arg_from_abi::<f64>(arg1, &mut __stack) };
+            me.scale_by(arg1)
+        };
+    })
+}
+#[doc = "Describes the type of `shape_scaleBy` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe_shape_scaleBy() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <f64 as WasmDescribe>::describe();
+    inform(0);
+}
+impl ::wasm_bindgen::describe::WasmDescribe for __wbg_dyn_Shape {
+    const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+        use wasm_bindgen::describe::*;
+        Description::Concat(&[Description::Numbers(&[
+            RUST_STRUCT,
+            5u32,
+            83u32,
+            104u32,
+            97u32,
+            112u32,
+            101u32,
+        ])])
+    };
+    fn describe() {
+        use wasm_bindgen::describe::*;
+        inform(RUST_STRUCT);
+        inform(5u32);
+        inform(83u32);
+        inform(104u32);
+        inform(97u32);
+        inform(112u32);
+        inform(101u32);
+    }
+}
+impl ::wasm_bindgen::convert::IntoWasmAbi for __wbg_dyn_Shape {
+    type Abi = u32;
+    fn into_abi(self, _extra: &mut ::wasm_bindgen::convert::Stack) -> u32 {
+        use wasm_bindgen::__wbindgen_if_not_std;
+        __wbindgen_if_not_std! { compile_error ! { "exporting a class to JS requires the `std` feature to \
+        be enabled in the `wasm-bindgen` crate" } }
+        use wasm_bindgen::__rt::std::boxed::Box;
+        use wasm_bindgen::__rt::WasmRefCell;
+        Box::into_raw(Box::new(WasmRefCell::new(self))) as u32
+    }
+}
+impl ::wasm_bindgen::convert::FromWasmAbi for __wbg_dyn_Shape {
+    type Abi = u32;
+    unsafe fn from_abi(js: u32, _extra: &mut ::wasm_bindgen::convert::Stack) -> Self {
+        use wasm_bindgen::__rt::std::boxed::Box;
+        use wasm_bindgen::__rt::{assert_not_null, WasmRefCell};
+        let ptr = js as *mut WasmRefCell<__wbg_dyn_Shape>;
+        assert_not_null(ptr);
+        let js = Box::from_raw(ptr);
+        js.borrow_mut();
+        js.into_inner()
+    }
+}
+impl ::wasm_bindgen::__rt::core::convert::From<__wbg_dyn_Shape> for ::wasm_bindgen::JsValue {
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    fn from(value: __wbg_dyn_Shape) -> Self {
+        let ptr = ::wasm_bindgen::convert::IntoWasmAbi::into_abi(value, unsafe {
+            &mut ::wasm_bindgen::convert::GlobalStack::new()
+        });
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __wbg_shape_new(ptr: u32) -> u32;
+        }
+        unsafe {
+            <::wasm_bindgen::JsValue as ::wasm_bindgen::convert::FromWasmAbi>::from_abi(
+                __wbg_shape_new(ptr),
+                &mut ::wasm_bindgen::convert::GlobalStack::new(),
+            )
+        }
+    }
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    fn from(_value: __wbg_dyn_Shape) -> Self {
+        panic!("cannot convert to JsValue outside of the wasm target")
+    }
+}
+impl ::wasm_bindgen::convert::OptionIntoWasmAbi for __wbg_dyn_Shape {
+    fn none() -> u32 {
+        0
+    }
+}
+impl ::wasm_bindgen::convert::OptionFromWasmAbi for __wbg_dyn_Shape {
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+impl ::wasm_bindgen::convert::VectorIntoWasmAbi for __wbg_dyn_Shape {}
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+#[no_mangle]
+#[doc(hidden)]
+pub unsafe extern "C" fn __wbg_shape_free(ptr: u32) {
+    <__wbg_dyn_Shape as ::wasm_bindgen::convert::FromWasmAbi>::from_abi(
+        ptr,
+        &mut ::wasm_bindgen::convert::GlobalStack::new(),
+    );
+}
+impl ::wasm_bindgen::convert::RefFromWasmAbi for __wbg_dyn_Shape {
+    type Abi = u32;
+    type Anchor = ::wasm_bindgen::__rt::Ref<'static, __wbg_dyn_Shape>;
+    unsafe fn ref_from_abi(
+        js: Self::Abi,
+        _extra: &mut ::wasm_bindgen::convert::Stack,
+    ) -> Self::Anchor {
+        let js = js as *mut ::wasm_bindgen::__rt::WasmRefCell<__wbg_dyn_Shape>;
+        ::wasm_bindgen::__rt::assert_not_null(js);
+        (*js).borrow()
+    }
+}
+impl ::wasm_bindgen::convert::RefMutFromWasmAbi for __wbg_dyn_Shape {
+    type Abi = u32;
+    type Anchor = ::wasm_bindgen::__rt::RefMut<'static, __wbg_dyn_Shape>;
+    unsafe fn ref_mut_from_abi(
+        js: Self::Abi,
+        _extra: &mut ::wasm_bindgen::convert::Stack,
+    ) -> Self::Anchor {
+        let js = js as *mut ::wasm_bindgen::__rt::WasmRefCell<__wbg_dyn_Shape>;
+        ::wasm_bindgen::__rt::assert_not_null(js);
+        (*js).borrow_mut()
+    }
+}
+impl ::wasm_bindgen::convert::OptionRefFromWasmAbi for __wbg_dyn_Shape {
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+impl ::wasm_bindgen::convert::OptionRefMutFromWasmAbi for __wbg_dyn_Shape {
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+#[allow(bad_style)]
+#[doc(hidden)]
+#[doc = ""]
+#[repr(transparent)]
+pub struct __wbg_js_Shape {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `__wbg_js_Shape` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const___wbg_js_Shape: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for __wbg_js_Shape {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for __wbg_js_Shape {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for __wbg_js_Shape {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a __wbg_js_Shape {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for __wbg_js_Shape {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            __wbg_js_Shape {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for __wbg_js_Shape {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a __wbg_js_Shape {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for __wbg_js_Shape {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<__wbg_js_Shape>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(__wbg_js_Shape {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for __wbg_js_Shape {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for __wbg_js_Shape {
+        fn from(obj: JsValue) -> __wbg_js_Shape {
+            __wbg_js_Shape { obj }
+        }
+    }
+    impl AsRef<JsValue> for __wbg_js_Shape {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for __wbg_js_Shape {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<__wbg_js_Shape> for JsValue {
+        fn from(obj: __wbg_js_Shape) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for __wbg_js_Shape {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __wbg_instanceof___wbg_js_Shape_29537df0c23b2a2a(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __wbg_instanceof___wbg_js_Shape_29537df0c23b2a2a(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            __wbg_js_Shape { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const __wbg_js_Shape) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut __wbg_js_Shape) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__wbg_area_40dbc71af08051cd` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___wbg_area_40dbc71af08051cd() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&__wbg_js_Shape as WasmDescribe>::describe();
+    inform(1);
+    <f64 as WasmDescribe>::describe();
+}
+impl __wbg_js_Shape {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = ""]
+    fn __wbg_area(&self) -> f64 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __wbg_area_40dbc71af08051cd(
+                this: <&__wbg_js_Shape as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <f64 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let this =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&__wbg_js_Shape>(self, &mut __stack);
+                __wbg_area_40dbc71af08051cd(this)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<f64>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = ""]
+    fn __wbg_area(&self) -> f64 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__wbg_scaleBy_71e1c9c1bb72d3ea` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___wbg_scaleBy_71e1c9c1bb72d3ea() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&__wbg_js_Shape as WasmDescribe>::describe();
+    <f64 as WasmDescribe>::describe();
+    inform(0);
+}
+impl __wbg_js_Shape {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = ""]
+    fn __wbg_scale_by(&self, arg0: f64) {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __wbg_scaleBy_71e1c9c1bb72d3ea(
+                this: <&__wbg_js_Shape as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                arg0: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> ();
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let this =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&__wbg_js_Shape>(self, &mut __stack);
+                let arg0 = ::wasm_bindgen::__rt::arg_into_abi::<f64>(arg0, &mut __stack);
+                __wbg_scaleBy_71e1c9c1bb72d3ea(this, arg0)
+            };
+            ()
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = ""]
+    fn __wbg_scale_by(&self, arg0: f64) {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+impl ::wasm_bindgen::describe::WasmDescribe for Box<dyn Shape> {
+    const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+        use wasm_bindgen::describe::*;
+        Description::Concat(&[<__wbg_dyn_Shape as WasmDescribe>::DESCRIPTION])
+    };
+    fn describe() {
+        use wasm_bindgen::describe::*;
+        <__wbg_dyn_Shape as WasmDescribe>::describe();
+    }
+}
+impl ::wasm_bindgen::convert::IntoWasmAbi for Box<dyn Shape> {
+    type Abi = u32;
+    fn into_abi(self, extra: &mut ::wasm_bindgen::convert::Stack) -> u32 {
+        ::wasm_bindgen::convert::IntoWasmAbi::into_abi(__wbg_dyn_Shape(self), extra)
+    }
+}
+impl ::wasm_bindgen::convert::FromWasmAbi for Box<dyn Shape> {
+    type Abi = u32;
+    unsafe fn from_abi(js: u32, extra: &mut ::wasm_bindgen::convert::Stack) -> Self {
+        <__wbg_dyn_Shape as ::wasm_bindgen::convert::FromWasmAbi>::from_abi(js, extra).0
+    }
+}
+impl ::wasm_bindgen::convert::OptionIntoWasmAbi for Box<dyn Shape> {
+    fn none() -> u32 {
+        0
+    }
+}
+impl ::wasm_bindgen::convert::OptionFromWasmAbi for Box<dyn Shape> {
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+impl ::wasm_bindgen::convert::VectorIntoWasmAbi for Box<dyn Shape> {}
+impl ::wasm_bindgen::__rt::core::convert::From<Box<dyn Shape>> for ::wasm_bindgen::JsValue {
+    fn from(value: Box<dyn Shape>) -> Self {
+        __wbg_dyn_Shape(value).into()
+    }
+}
+#[doc(hidden)]
+#[allow(bad_style)]
+pub struct __wbg_ref_Shape(::wasm_bindgen::__rt::core::mem::ManuallyDrop<__wbg_js_Shape>);
+impl ::wasm_bindgen::__rt::core::ops::Deref for __wbg_ref_Shape {
+    type Target = dyn Shape;
+    fn deref(&self) -> &(dyn Shape + 'static) {
+        let js: &__wbg_js_Shape = &self.0;
+        js
+    }
+}
+impl ::wasm_bindgen::describe::WasmDescribe for dyn Shape {
+    const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+        use wasm_bindgen::describe::*;
+        Description::Concat(&[Description::Numbers(&[ANYREF])])
+    };
+    fn describe() {
+        use wasm_bindgen::describe::*;
+        inform(ANYREF);
+    }
+}
+impl ::wasm_bindgen::convert::RefFromWasmAbi for dyn Shape {
+    type Abi = u32;
+    type Anchor = __wbg_ref_Shape;
+    unsafe fn ref_from_abi(js: u32, extra: &mut ::wasm_bindgen::convert::Stack) -> Self::Anchor {
+        __wbg_ref_Shape(
+            <__wbg_js_Shape as ::wasm_bindgen::convert::RefFromWasmAbi>::ref_from_abi(js, extra),
+        )
+    }
+}
+impl ::wasm_bindgen::convert::OptionRefFromWasmAbi for dyn Shape {
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_b1ce900676cf9f90 : [ u8 ; 1923usize ] = * b"\x7F\x07\0\0{\"exports\":[{\"class\":\"Shape\",\"method\":true,\"consumed\":false,\"constructor\":null,\"function\":{\"name\":\"area\"},\"rust_name\":\"area\",\"comments\":[\"\\\" The area of the shape.\\\"\"],\"optional_args\":false,\"js_map\":false,\"defaults\":[],\"enumerable\":false,\"configurable\":true,\"getter\":null,\"setter\":null,\"indexing\":null,\"symbol\":null},{\"class\":\"Shape\",\"method\":true,\"consumed\":false,\"constructor\":null,\"function\":{\"name\":\"scaleBy\"},\"rust_name\":\"scale_by\",\"comments\":[],\"optional_args\":false,\"js_map\":false,\"defaults\":[null],\"enumerable\":false,\"configurable\":true,\"getter\":null,\"setter\":null,\"indexing\":null,\"symbol\":null}],\"enums\":[],\"imports\":[{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"__wbg_js_Shape\",\"rust_name\":\"__wbg_js_Shape\",\"instanceof_shim\":\"__wbg_instanceof___wbg_js_Shape_29537df0c23b2a2a\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__wbg_area_40dbc71af08051cd\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"__wbg_js_Shape\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":true,\"function\":{\"name\":\"area\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__wbg_scaleBy_71e1c9c1bb72d3ea\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"__wbg_js_Shape\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":true,\"function\":{\"name\":\"scaleBy\"}}}],\"structs\":[{\"name\":\"Shape\",\"rust_name\":\"__wbg_dyn_Shape\",\"fields\":[],\"comments\":[],\"extends\":null,\"extends_rust\":false,\"custom_element\":null,\"async_iterator\":false,\"iterator\":false,\"shared_class\":null}],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
@@ -0,0 +1,8 @@
+#[wasm_bindgen]
+pub trait Shape {
+    /// The area of the shape.
+    fn area(&self) -> f64;
+
+    #[wasm_bindgen(js_name = scaleBy)]
+    fn scale_by(&mut self, by: f64);
+}
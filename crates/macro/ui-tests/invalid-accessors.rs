@@ -0,0 +1,44 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct A {
+    x: u32,
+}
+
+#[wasm_bindgen]
+impl A {
+    #[wasm_bindgen(getter)]
+    pub fn get_with_arg(&self, a: u32) -> u32 {
+        a
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn get_nothing(&self) {}
+
+    #[wasm_bindgen(setter)]
+    pub fn x(&mut self, x: u32) {
+        self.x = x;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_two(&mut self, a: u32, b: u32) {}
+
+    #[wasm_bindgen(getter)]
+    pub fn by_value(self) -> u32 {
+        self.x
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn no_self() -> u32 {
+        1
+    }
+}
+
+#[wasm_bindgen(getter)]
+pub fn free() -> u32 {
+    1
+}
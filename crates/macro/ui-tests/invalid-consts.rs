@@ -0,0 +1,22 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+const A: u32 = 1;
+
+#[wasm_bindgen]
+pub const B: u32 = 1 + 1;
+
+#[wasm_bindgen]
+pub static mut C: u32 = 1;
+
+#[wasm_bindgen]
+pub const D: u64 = 18446744073709551615;
+
+#[wasm_bindgen]
+pub const E: &str = -"e";
+
+fn main() {}
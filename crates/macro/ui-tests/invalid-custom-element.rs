@@ -0,0 +1,14 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(custom_element = "widget")]
+pub struct A {}
+
+#[wasm_bindgen(custom_element = "my-widget", extends = HTMLButtonElement)]
+pub struct B {}
+
+#[wasm_bindgen(observed_attributes("value"))]
+pub struct C {}
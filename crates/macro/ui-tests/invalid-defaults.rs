@@ -0,0 +1,14 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(default(c = 1))]
+pub fn foo(a: u32, b: u32) {}
+
+#[wasm_bindgen(default(a = 1))]
+pub fn bar(a: u32, b: u32) {}
+
+#[wasm_bindgen(default(a = b"x"))]
+pub fn baz(a: u32) {}
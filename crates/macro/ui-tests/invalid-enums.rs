@@ -21,3 +21,20 @@ pub enum C {
 pub enum D {
     X = 4294967296,
 }
+
+#[wasm_bindgen]
+pub enum E {
+    X = "x",
+    Y,
+}
+
+#[wasm_bindgen]
+pub enum F {
+    X = "x",
+    Y = "x",
+}
+
+#[wasm_bindgen]
+enum G {
+    X(String),
+}
@@ -0,0 +1,16 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct A {}
+
+#[wasm_bindgen(extends = A)]
+pub struct B {
+    a: A,
+}
+
+#[wasm_bindgen(extends = A, extends = Error)]
+pub struct C {}
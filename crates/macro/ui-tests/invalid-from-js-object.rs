@@ -0,0 +1,25 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[derive(FromJsObject)]
+struct A(u32);
+
+#[derive(FromJsObject)]
+struct B<T> {
+    t: T,
+}
+
+#[derive(FromJsObject)]
+struct C {
+    #[js_object(js_name = "data-x")]
+    a: u32,
+    #[js_object(default)]
+    b: Option<u32>,
+    #[js_object(rename = "c")]
+    c: u32,
+}
+
+fn main() {}
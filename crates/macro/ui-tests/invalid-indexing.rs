@@ -0,0 +1,60 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    type A;
+
+    #[wasm_bindgen(indexing_getter)]
+    fn f1(prop: u32) -> u32;
+    #[wasm_bindgen(method, indexing_getter)]
+    fn f2(this: &A) -> u32;
+    #[wasm_bindgen(method, indexing_getter)]
+    fn f3(this: &A, prop: u32);
+    #[wasm_bindgen(method, indexing_setter)]
+    fn f4(this: &A, prop: u32);
+    #[wasm_bindgen(static_method_of = A, indexing_deleter)]
+    fn f5(prop: u32, other: u32);
+}
+
+#[wasm_bindgen]
+pub struct B {
+    x: u32,
+}
+
+#[wasm_bindgen]
+impl B {
+    #[wasm_bindgen(indexing_getter)]
+    pub fn get_nothing(&self, index: u32) {}
+
+    #[wasm_bindgen(indexing_setter)]
+    pub fn set_one(&mut self, index: u32) {}
+
+    #[wasm_bindgen(indexing_deleter)]
+    pub fn delete_two(&mut self, index: u32, other: u32) {}
+
+    #[wasm_bindgen(indexing_getter, indexing_deleter)]
+    pub fn both(&mut self, index: u32) -> u32 {
+        index
+    }
+
+    #[wasm_bindgen(indexing_getter)]
+    pub fn by_value(self, index: u32) -> u32 {
+        index
+    }
+
+    #[wasm_bindgen(indexing_getter)]
+    pub fn no_self(index: u32) -> u32 {
+        index
+    }
+}
+
+#[wasm_bindgen(indexing_getter)]
+pub fn free(index: u32) -> u32 {
+    index
+}
+
+fn main() {}
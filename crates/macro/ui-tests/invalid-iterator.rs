@@ -0,0 +1,10 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(iterator, async_iterator)]
+pub struct A {}
+
+fn main() {}
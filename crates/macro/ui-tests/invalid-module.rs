@@ -0,0 +1,19 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "./foo.js", module = "./bar.js")]
+extern {
+    fn foo();
+}
+
+#[wasm_bindgen]
+extern {
+    #[wasm_bindgen(module = "./foo.js")]
+    #[wasm_bindgen(web_module = "./bar.js")]
+    fn bar();
+}
+
+fn main() {}
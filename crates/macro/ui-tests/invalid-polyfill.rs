@@ -0,0 +1,18 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern {
+    type Foo;
+
+    #[wasm_bindgen(method, polyfill = "./foo.js")]
+    fn bar(this: &Foo);
+
+    #[wasm_bindgen(js_namespace = console, polyfill = "./log.js")]
+    fn log(a: u32);
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct A {}
+
+#[wasm_bindgen]
+impl A {
+    #[wasm_bindgen(js_name = "Symbol.toNumber")]
+    pub fn to_number(&self) -> u32 {
+        0
+    }
+
+    #[wasm_bindgen(js_name = "Symbol.toStringTag")]
+    pub fn to_string_tag(&self) -> String {
+        String::new()
+    }
+
+    #[wasm_bindgen(getter, js_name = "Symbol.iterator")]
+    pub fn iterator(&self) -> u32 {
+        0
+    }
+}
+
+#[wasm_bindgen(js_name = "Symbol.toPrimitive")]
+pub fn to_primitive() -> u32 {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub unsafe trait A {}
+
+#[wasm_bindgen]
+pub trait B<T> {}
+
+#[wasm_bindgen]
+pub trait C: Clone {}
+
+#[wasm_bindgen]
+pub trait D {
+    const X: u32;
+    fn new() -> Self;
+    fn by_value(self);
+    fn generic<T>(&self, t: T);
+    unsafe fn f(&self);
+}
+
+fn main() {}
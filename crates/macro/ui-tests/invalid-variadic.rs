@@ -0,0 +1,24 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern {
+    type Foo;
+
+    #[wasm_bindgen(variadic)]
+    fn a();
+
+    #[wasm_bindgen(variadic)]
+    fn b(rest: u32);
+
+    #[wasm_bindgen(method, variadic)]
+    fn c(this: &Foo);
+
+    #[wasm_bindgen(variadic)]
+    fn d(rest: &mut [f64]);
+}
+
+fn main() {}
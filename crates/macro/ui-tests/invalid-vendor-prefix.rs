@@ -0,0 +1,27 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern {
+    type Foo;
+
+    #[wasm_bindgen(method, vendor_prefix = webkit)]
+    fn bar(this: &Foo);
+
+    #[wasm_bindgen(vendor_prefix = webkit)]
+    static BAZ: JsValue;
+
+    #[wasm_bindgen(js_namespace = console, vendor_prefix = webkit)]
+    fn log(a: u32);
+}
+
+#[wasm_bindgen(module = "./foo")]
+extern {
+    #[wasm_bindgen(vendor_prefix = webkit)]
+    type Qux;
+}
+
+fn main() {}
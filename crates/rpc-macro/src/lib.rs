@@ -0,0 +1,271 @@
+//! Implementation of the `#[wasm_bindgen_rpc]` attribute. See the
+//! `wasm-bindgen-rpc` crate for what it generates and how it's used.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use syn::spanned::Spanned;
+
+#[proc_macro_attribute]
+pub fn wasm_bindgen_rpc(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let attr = TokenStream::from(attr);
+    if !attr.is_empty() {
+        return error(attr.span(), "`#[wasm_bindgen_rpc]` doesn't take any arguments").into();
+    }
+    let item = match syn::parse::<syn::ItemImpl>(input) {
+        Ok(item) => item,
+        Err(_) => {
+            return error(
+                Span::call_site(),
+                "`#[wasm_bindgen_rpc]` can only be used on an `impl` block",
+            ).into()
+        }
+    };
+    match expand(&item) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => {
+            let mut tokens = e;
+            tokens.extend(quote! { #item });
+            tokens.into()
+        }
+    }
+}
+
+/// A method which can be called through the proxy.
+struct Method<'a> {
+    name: &'a Ident,
+    args: Vec<(&'a Ident, &'a syn::Type)>,
+    ret: Option<&'a syn::Type>,
+}
+
+fn expand(item: &syn::ItemImpl) -> Result<TokenStream, TokenStream> {
+    if let Some((_, ref path, _)) = item.trait_ {
+        return Err(error(path.span(), "`#[wasm_bindgen_rpc]` can't be used on trait impls"));
+    }
+    if item.generics.params.len() > 0 {
+        return Err(error(
+            item.generics.span(),
+            "`#[wasm_bindgen_rpc]` can't be used on generic impls",
+        ));
+    }
+    let name = match *item.self_ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) if path.segments.len() == 1 => {
+            &path.segments[0].ident
+        }
+        ref ty => {
+            return Err(error(
+                ty.span(),
+                "`#[wasm_bindgen_rpc]` can only be used on an impl of a struct in this module",
+            ))
+        }
+    };
+
+    let mut methods = Vec::new();
+    let mut errors = TokenStream::new();
+    for impl_item in item.items.iter() {
+        let method = match *impl_item {
+            syn::ImplItem::Method(ref method) => method,
+            _ => continue,
+        };
+        match method_from_syn(method) {
+            Ok(Some(method)) => methods.push(method),
+            Ok(None) => {}
+            Err(e) => errors.extend(e),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let server = Ident::new(&format!("{}Server", name), Span::call_site());
+    let proxy = Ident::new(&format!("{}Proxy", name), Span::call_site());
+    let server_doc = format!(
+        "Handles requests posted by a `{}`, calling the methods of a `{}`.",
+        proxy, name
+    );
+    let proxy_doc = format!(
+        "Calls the methods of a `{}` hosted by a `{}` in a worker.",
+        name, server
+    );
+
+    let dispatch = methods.iter().map(|m| {
+        let method = m.name;
+        let method_name = method.to_string();
+        let arg_names = m.args.iter().map(|&(name, _)| name).collect::<Vec<_>>();
+        let arg_names2 = arg_names.clone();
+        let arg_tys = m.args.iter().map(|&(_, ty)| ty);
+        let arg_values = (0..m.args.len() as u32).map(|i| {
+            quote! { ::wasm_bindgen_rpc::__rt::arg(args, #method_name, #i)? }
+        });
+        quote! {
+            #method_name => {
+                #(let #arg_names: #arg_tys = #arg_values;)*
+                let ret = inner.#method(#(#arg_names2),*);
+                Ok(::wasm_bindgen_rpc::RpcValue::into_js(ret))
+            }
+        }
+    });
+
+    let proxy_methods = methods.iter().map(|m| {
+        let method = m.name;
+        let method_name = method.to_string();
+        let doc = match m.ret {
+            Some(ty) => format!(
+                "Calls `{}::{}` in the worker, resolving with the `{}` it returns.",
+                name,
+                method,
+                quote!(#ty).to_string().replace(" ", ""),
+            ),
+            None => format!(
+                "Calls `{}::{}` in the worker, resolving once it has returned.",
+                name, method
+            ),
+        };
+        let arg_names = m.args.iter().map(|&(name, _)| name).collect::<Vec<_>>();
+        let arg_names2 = arg_names.clone();
+        let arg_tys = m.args.iter().map(|&(_, ty)| ty);
+        quote! {
+            #[doc = #doc]
+            pub fn #method(&self, #(#arg_names: #arg_tys),*) -> ::wasm_bindgen_rpc::__rt::Promise {
+                self.client.call(
+                    #method_name,
+                    &[#(::wasm_bindgen_rpc::RpcValue::into_js(#arg_names2)),*],
+                )
+            }
+        }
+    });
+
+    Ok(quote! {
+        #item
+
+        #[doc = #server_doc]
+        #[wasm_bindgen]
+        pub struct #server {
+            inner: #name,
+        }
+
+        #[wasm_bindgen]
+        impl #server {
+            /// Creates the server with the default value of the type it
+            /// serves.
+            #[wasm_bindgen(constructor)]
+            pub fn new() -> #server {
+                #server {
+                    inner: <#name as ::std::default::Default>::default(),
+                }
+            }
+
+            /// Handles a request, returning the response to post back.
+            pub fn handle(&mut self, request: ::wasm_bindgen::JsValue) -> ::wasm_bindgen::JsValue {
+                #[allow(unused_variables)]
+                let inner = &mut self.inner;
+                ::wasm_bindgen_rpc::__rt::handle(&request, |method, args| match method {
+                    #(#dispatch)*
+                    _ => Err(::wasm_bindgen_rpc::__rt::unknown_method(method)),
+                })
+            }
+        }
+
+        #[doc = #proxy_doc]
+        #[wasm_bindgen]
+        pub struct #proxy {
+            client: ::wasm_bindgen_rpc::Client,
+        }
+
+        #[wasm_bindgen]
+        impl #proxy {
+            /// Creates a proxy posting its requests to `worker`.
+            #[wasm_bindgen(constructor)]
+            pub fn new(worker: ::wasm_bindgen::JsValue) -> #proxy {
+                #proxy {
+                    client: ::wasm_bindgen_rpc::Client::new(worker),
+                }
+            }
+
+            /// Settles the promise of the call that `response` is for, which
+            /// should be the data of each message posted back by the worker.
+            pub fn receive(&self, response: ::wasm_bindgen::JsValue) {
+                self.client.receive(&response);
+            }
+
+            #(#proxy_methods)*
+        }
+    })
+}
+
+/// Checks that `method` can be called through the proxy, returning `None` for
+/// private methods and associated functions, which are left alone.
+fn method_from_syn(method: &syn::ImplItemMethod) -> Result<Option<Method>, TokenStream> {
+    match method.vis {
+        syn::Visibility::Public(_) => {}
+        _ => return Ok(None),
+    }
+    let sig = &method.sig;
+    let mut inputs = sig.decl.inputs.iter();
+    match inputs.next() {
+        Some(syn::FnArg::SelfRef(_)) => {}
+        Some(arg @ syn::FnArg::SelfValue(_)) => {
+            return Err(error(
+                arg.span(),
+                "methods called through `#[wasm_bindgen_rpc]` must take `&self` or `&mut self`",
+            ))
+        }
+        _ => return Ok(None),
+    }
+    if sig.decl.generics.params.len() > 0 {
+        return Err(error(
+            sig.decl.generics.span(),
+            "methods called through `#[wasm_bindgen_rpc]` can't be generic",
+        ));
+    }
+
+    let mut args = Vec::new();
+    for arg in inputs {
+        let captured = match *arg {
+            syn::FnArg::Captured(ref captured) => captured,
+            _ => return Err(error(arg.span(), "unsupported argument")),
+        };
+        let name = match captured.pat {
+            syn::Pat::Ident(syn::PatIdent {
+                by_ref: None,
+                subpat: None,
+                ref ident,
+                ..
+            }) => ident,
+            ref pat => {
+                return Err(error(
+                    pat.span(),
+                    "arguments of methods called through `#[wasm_bindgen_rpc]` must be \
+                     plain identifiers",
+                ))
+            }
+        };
+        if let syn::Type::Reference(_) = captured.ty {
+            return Err(error(
+                captured.ty.span(),
+                "arguments are copied to the worker, so they must be owned values",
+            ));
+        }
+        args.push((name, &captured.ty));
+    }
+    let ret = match sig.decl.output {
+        syn::ReturnType::Default => None,
+        syn::ReturnType::Type(_, ref ty) => Some(&**ty),
+    };
+    Ok(Some(Method {
+        name: &sig.ident,
+        args,
+        ret,
+    }))
+}
+
+fn error(span: Span, msg: &str) -> TokenStream {
+    quote_spanned! { span => compile_error!(#msg); }
+}
@@ -0,0 +1,313 @@
+//! Calling into a wasm instance hosted in a Web Worker from the main thread.
+//!
+//! Putting `#[wasm_bindgen_rpc]` on an `impl` block of a struct generates two
+//! exported classes next to the struct. For a struct named `Counter` they're:
+//!
+//! * `CounterServer`, which owns a `Counter` created with `Default` and turns
+//!   requests into calls of its methods, in the worker.
+//! * `CounterProxy`, which has the same methods except that they post a
+//!   request to the worker and return a `Promise` for the result, on the main
+//!   thread.
+//!
+//! Both sides are typically the same wasm module, loaded once in the page and
+//! once in the worker.
+//!
+//! ```rust,ignore
+//! #![feature(use_extern_macros)]
+//!
+//! extern crate wasm_bindgen;
+//! extern crate wasm_bindgen_rpc;
+//!
+//! use wasm_bindgen::prelude::*;
+//! use wasm_bindgen_rpc::wasm_bindgen_rpc;
+//!
+//! #[derive(Default)]
+//! pub struct Counter {
+//!     count: u32,
+//! }
+//!
+//! #[wasm_bindgen_rpc]
+//! impl Counter {
+//!     pub fn add(&mut self, n: u32) -> u32 {
+//!         self.count += n;
+//!         self.count
+//!     }
+//! }
+//! ```
+//!
+//! The JS in the worker passes each request to the server and posts the
+//! response back:
+//!
+//! ```js
+//! const server = new CounterServer();
+//! onmessage = e => postMessage(server.handle(e.data));
+//! ```
+//!
+//! and on the main thread the responses are passed back to the proxy:
+//!
+//! ```js
+//! const counter = new CounterProxy(worker);
+//! worker.onmessage = e => counter.receive(e.data);
+//! counter.add(2).then(count => console.log(count));
+//! ```
+//!
+//! The public methods taking `&self` or `&mut self` can be called through the
+//! proxy, as long as their arguments and return value implement `RpcValue`.
+//! Other methods and associated functions are left alone.
+//!
+//! A worker handles one request at a time, in the order they were posted. If
+//! a method panics the worker's wasm instance can't be used any more, and the
+//! promises for that call and any later ones never settle.
+
+#![feature(use_extern_macros)]
+#![deny(missing_docs)]
+
+extern crate js_sys;
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_rpc_macro;
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use js_sys::{Array, Function, Object, Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+pub use wasm_bindgen_rpc_macro::wasm_bindgen_rpc;
+
+#[wasm_bindgen]
+extern {
+    type Target;
+
+    #[wasm_bindgen(method, structural, catch, js_name = postMessage)]
+    fn post_message(this: &Target, message: &JsValue) -> Result<(), JsValue>;
+}
+
+/// A value that can be passed to or returned from a method called through a
+/// proxy.
+///
+/// Values are posted between the threads as JS values, so they have to
+/// survive the structured clone of `postMessage`.
+pub trait RpcValue: Sized {
+    /// Converts this value into the JS value that's posted.
+    fn into_js(self) -> JsValue;
+
+    /// Converts a posted JS value back, returning an error if it's not a
+    /// valid `Self`.
+    fn from_js(value: JsValue) -> Result<Self, JsValue>;
+}
+
+impl RpcValue for JsValue {
+    fn into_js(self) -> JsValue {
+        self
+    }
+
+    fn from_js(value: JsValue) -> Result<JsValue, JsValue> {
+        Ok(value)
+    }
+}
+
+impl RpcValue for () {
+    fn into_js(self) -> JsValue {
+        JsValue::undefined()
+    }
+
+    fn from_js(_value: JsValue) -> Result<(), JsValue> {
+        Ok(())
+    }
+}
+
+impl RpcValue for bool {
+    fn into_js(self) -> JsValue {
+        JsValue::from_bool(self)
+    }
+
+    fn from_js(value: JsValue) -> Result<bool, JsValue> {
+        value
+            .as_bool()
+            .ok_or_else(|| JsValue::from_str("expected a boolean"))
+    }
+}
+
+impl RpcValue for String {
+    fn into_js(self) -> JsValue {
+        JsValue::from_str(&self)
+    }
+
+    fn from_js(value: JsValue) -> Result<String, JsValue> {
+        value
+            .as_string()
+            .ok_or_else(|| JsValue::from_str("expected a string"))
+    }
+}
+
+impl<T: RpcValue> RpcValue for Option<T> {
+    fn into_js(self) -> JsValue {
+        match self {
+            Some(value) => value.into_js(),
+            None => JsValue::undefined(),
+        }
+    }
+
+    fn from_js(value: JsValue) -> Result<Option<T>, JsValue> {
+        if value.is_undefined() || value.is_null() {
+            Ok(None)
+        } else {
+            T::from_js(value).map(Some)
+        }
+    }
+}
+
+macro_rules! numbers {
+    ($($t:ident)*) => ($(
+        impl RpcValue for $t {
+            fn into_js(self) -> JsValue {
+                JsValue::from_f64(self as f64)
+            }
+
+            fn from_js(value: JsValue) -> Result<$t, JsValue> {
+                value
+                    .as_f64()
+                    .map(|n| n as $t)
+                    .ok_or_else(|| JsValue::from_str("expected a number"))
+            }
+        }
+    )*)
+}
+
+numbers! { i8 u8 i16 u16 i32 u32 f32 f64 }
+
+/// The main thread's end of the connection to a worker, which is what the
+/// generated proxies are built on.
+pub struct Client {
+    target: Target,
+    next_id: Cell<u32>,
+    pending: RefCell<HashMap<u32, (Function, Function)>>,
+}
+
+impl Client {
+    /// Creates a client posting its requests to `target`, which is typically
+    /// a `Worker`.
+    pub fn new(target: JsValue) -> Client {
+        Client {
+            target: target.unchecked_into(),
+            next_id: Cell::new(0),
+            pending: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Calls `method` with `args` in the worker, returning a promise which
+    /// settles once the response is passed to `receive`.
+    ///
+    /// The promise is rejected if the worker fails to handle the request, or
+    /// if the request can't be posted at all.
+    pub fn call(&self, method: &str, args: &[JsValue]) -> Promise {
+        let id = self.next_id.get();
+        self.next_id.set(id.wrapping_add(1));
+
+        let request = JsValue::from(Object::new());
+        let args = args.iter().fold(Array::new(), |array, arg| {
+            array.push(arg);
+            array
+        });
+        set(&request, "id", &JsValue::from_f64(id as f64));
+        set(&request, "method", &JsValue::from_str(method));
+        set(&request, "args", args.as_ref());
+
+        let pending = &self.pending;
+        let promise = Promise::new_with(|resolve, reject| {
+            pending.borrow_mut().insert(id, (resolve, reject));
+        });
+        if let Err(e) = self.target.post_message(&request) {
+            if let Some((_, reject)) = self.pending.borrow_mut().remove(&id) {
+                drop(reject.call1(&JsValue::undefined(), &e));
+            }
+        }
+        promise
+    }
+
+    /// Settles the promise returned by `call` for the request `response` is
+    /// for.
+    ///
+    /// Responses which aren't for a pending call are ignored.
+    pub fn receive(&self, response: &JsValue) {
+        if !response.is_object() {
+            return;
+        }
+        let id = match get(response, "id").as_f64() {
+            Some(id) => id as u32,
+            None => return,
+        };
+        let (resolve, reject) = match self.pending.borrow_mut().remove(&id) {
+            Some(callbacks) => callbacks,
+            None => return,
+        };
+        let value = get(response, "value");
+        let settle = if get(response, "ok").as_bool() == Some(true) {
+            resolve
+        } else {
+            reject
+        };
+        drop(settle.call1(&JsValue::undefined(), &value));
+    }
+}
+
+fn get(object: &JsValue, key: &str) -> JsValue {
+    Reflect::get(object, &JsValue::from_str(key))
+}
+
+fn set(object: &JsValue, key: &str, value: &JsValue) {
+    Reflect::set(object, &JsValue::from_str(key), value);
+}
+
+// Used by the code `#[wasm_bindgen_rpc]` generates, not public API.
+#[doc(hidden)]
+pub mod __rt {
+    pub use js_sys::Promise;
+
+    use js_sys::{Array, Object};
+    use wasm_bindgen::{JsCast, JsValue};
+
+    use super::{get, set, RpcValue};
+
+    /// Handles a request with `f`, which is passed the name of the method
+    /// to call and its arguments, returning the response.
+    pub fn handle<F>(request: &JsValue, f: F) -> JsValue
+    where
+        F: FnOnce(&str, &Array) -> Result<JsValue, JsValue>,
+    {
+        let response = JsValue::from(Object::new());
+        if !request.is_object() {
+            set(&response, "ok", &JsValue::from_bool(false));
+            set(&response, "value", &JsValue::from_str("malformed request"));
+            return response;
+        }
+
+        let method = get(request, "method").as_string();
+        let args = get(request, "args").dyn_into::<Array>();
+        let result = match (method, args) {
+            (Some(method), Ok(args)) => f(&method, &args),
+            _ => Err(JsValue::from_str("malformed request")),
+        };
+        set(&response, "id", &get(request, "id"));
+        let (ok, value) = match result {
+            Ok(value) => (true, value),
+            Err(value) => (false, value),
+        };
+        set(&response, "ok", &JsValue::from_bool(ok));
+        set(&response, "value", &value);
+        response
+    }
+
+    /// Reads argument `index` of a call of `method`.
+    pub fn arg<T: RpcValue>(args: &Array, method: &str, index: u32) -> Result<T, JsValue> {
+        T::from_js(args.get(index)).map_err(|e| {
+            let msg = e.as_string().unwrap_or_else(|| "invalid value".to_string());
+            JsValue::from(format!("argument {} of `{}`: {}", index, method, msg))
+        })
+    }
+
+    pub fn unknown_method(method: &str) -> JsValue {
+        JsValue::from(format!("unknown method `{}`", method))
+    }
+}
@@ -0,0 +1,21 @@
+// Stands in for a worker, keeping the posted requests so that the tests can
+// pass them to a server themselves.
+export class FakeWorker {
+  constructor() {
+    this.requests = [];
+  }
+
+  postMessage(request) {
+    this.requests.push(request);
+  }
+
+  takeRequest() {
+    return this.requests.shift();
+  }
+}
+
+export class ClosedWorker {
+  postMessage() {
+    throw new Error('closed');
+  }
+}
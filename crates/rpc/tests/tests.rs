@@ -0,0 +1,147 @@
+#![feature(use_extern_macros)]
+#![cfg(target_arch = "wasm32")]
+
+extern crate futures;
+extern crate js_sys;
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_futures;
+extern crate wasm_bindgen_rpc;
+extern crate wasm_bindgen_test;
+
+use futures::Future;
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_rpc::wasm_bindgen_rpc;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/tests.js")]
+extern {
+    #[derive(Clone)]
+    type FakeWorker;
+    #[wasm_bindgen(constructor)]
+    fn new() -> FakeWorker;
+    #[wasm_bindgen(method, js_name = takeRequest)]
+    fn take_request(this: &FakeWorker) -> JsValue;
+
+    type ClosedWorker;
+    #[wasm_bindgen(constructor)]
+    fn new() -> ClosedWorker;
+}
+
+#[derive(Default)]
+pub struct Counter {
+    count: u32,
+}
+
+#[wasm_bindgen_rpc]
+impl Counter {
+    pub fn add(&mut self, n: u32) -> u32 {
+        self.count += n;
+        self.count
+    }
+
+    pub fn describe(&self, name: String, unit: Option<String>) -> String {
+        match unit {
+            Some(unit) => format!("{} is {} {}", name, self.count, unit),
+            None => format!("{} is {}", name, self.count),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    #[allow(dead_code)]
+    fn private(&self) {}
+}
+
+/// Calls `f` with a connected proxy, then handles the one request it makes.
+fn roundtrip<F>(server: &mut CounterServer, f: F) -> JsFuture
+where
+    F: FnOnce(&CounterProxy) -> js_sys::Promise,
+{
+    let worker = FakeWorker::new();
+    let proxy = CounterProxy::new(worker.clone().into());
+    let promise = f(&proxy);
+    proxy.receive(server.handle(worker.take_request()));
+    JsFuture::from(promise)
+}
+
+#[wasm_bindgen_test(async)]
+fn calls_methods_in_order() -> impl Future<Item = (), Error = JsValue> {
+    let mut server = CounterServer::new();
+    let first = roundtrip(&mut server, |p| p.add(2));
+    let second = roundtrip(&mut server, |p| p.add(3));
+    let third = roundtrip(&mut server, |p| p.describe("count".to_string(), None));
+    let fourth = roundtrip(&mut server, |p| p.describe("count".to_string(), Some("apples".to_string())));
+    let fifth = roundtrip(&mut server, |p| p.reset());
+    first
+        .join5(second, third, fourth, fifth)
+        .map(|(first, second, third, fourth, fifth)| {
+            assert_eq!(first, 2);
+            assert_eq!(second, 5);
+            assert_eq!(third, "count is 5");
+            assert_eq!(fourth, "count is 5 apples");
+            assert!(fifth.is_undefined());
+        })
+}
+
+#[wasm_bindgen_test(async)]
+fn errors_reject() -> impl Future<Item = (), Error = JsValue> {
+    let worker = FakeWorker::new();
+    let proxy = CounterProxy::new(worker.clone().into());
+    let promise = proxy.add(1);
+
+    // Corrupt the argument before the server sees it.
+    let request = worker.take_request();
+    let args = Reflect::get(&request, &"args".into());
+    args.unchecked_ref::<Array>().set(0, &"one".into());
+
+    proxy.receive(CounterServer::new().handle(request));
+    JsFuture::from(promise).then(|result| {
+        let err = result.unwrap_err();
+        assert_eq!(err, "argument 0 of `add`: expected a number");
+        Ok(())
+    })
+}
+
+#[wasm_bindgen_test(async)]
+fn post_message_errors_reject() -> impl Future<Item = (), Error = JsValue> {
+    let proxy = CounterProxy::new(ClosedWorker::new().into());
+    JsFuture::from(proxy.add(1)).then(|result| {
+        assert!(result.is_err());
+        Ok(())
+    })
+}
+
+#[wasm_bindgen_test]
+fn unknown_methods() {
+    let request = JsValue::from(Object::new());
+    Reflect::set(&request, &"id".into(), &7.into());
+    Reflect::set(&request, &"method".into(), &"private".into());
+    Reflect::set(&request, &"args".into(), &Array::new().into());
+
+    let response = CounterServer::new().handle(request);
+    assert_eq!(Reflect::get(&response, &"id".into()), 7);
+    assert_eq!(Reflect::get(&response, &"ok".into()), false);
+    assert_eq!(
+        Reflect::get(&response, &"value".into()),
+        "unknown method `private`"
+    );
+}
+
+#[wasm_bindgen_test]
+fn malformed_requests() {
+    let response = CounterServer::new().handle(JsValue::from(3));
+    assert_eq!(Reflect::get(&response, &"ok".into()), false);
+    assert_eq!(Reflect::get(&response, &"value".into()), "malformed request");
+}
+
+#[wasm_bindgen_test]
+fn unexpected_responses_are_ignored() {
+    let proxy = CounterProxy::new(FakeWorker::new().into());
+    proxy.receive(JsValue::null());
+    proxy.receive(Object::new().into());
+}
@@ -3,12 +3,27 @@
 #[macro_use]
 extern crate serde_derive;
 
-pub const SCHEMA_VERSION: &str = "8";
+#[doc(hidden)]
+pub mod snapshot;
+
+pub const SCHEMA_VERSION: &str = "31";
+
+/// The version of the stable ABI which the `stable-abi` feature of the
+/// `wasm-bindgen` crate builds against.
+///
+/// Unlike `SCHEMA_VERSION` this doesn't change when the schema does. Programs
+/// built against a stable ABI are decoded as it was frozen, so anything which
+/// would change how they're read, like removing or renaming a field of
+/// `Program` or giving a new field no default, needs a new version and
+/// support for reading the old ones in `cli-support`'s `stable_abi` module.
+pub const STABLE_ABI_VERSION: u32 = 1;
 
 #[derive(Deserialize)]
 pub struct ProgramOnlySchema {
     pub schema_version: String,
     pub version: String,
+    #[serde(default)]
+    pub stable_abi: Option<u32>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -17,13 +32,18 @@ pub struct Program {
     pub enums: Vec<Enum>,
     pub imports: Vec<Import>,
     pub structs: Vec<Struct>,
+    pub consts: Vec<Const>,
     pub version: String,
     pub schema_version: String,
+    /// The version of the stable ABI the program was built against, if any.
+    #[serde(default)]
+    pub stable_abi: Option<u32>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct Import {
     pub module: Option<String>,
+    pub node_module: Option<String>,
     pub js_namespace: Option<String>,
     pub kind: ImportKind,
 }
@@ -41,6 +61,11 @@ pub enum ImportKind {
 pub struct ImportFunction {
     pub shim: String,
     pub catch: bool,
+    pub assert_no_shim: bool,
+    pub optional_args: bool,
+    pub variadic: bool,
+    pub vendor_prefixes: Vec<String>,
+    pub polyfill: Option<String>,
     pub method: Option<MethodData>,
     pub structural: bool,
     pub function: Function,
@@ -83,7 +108,11 @@ pub struct ImportStatic {
 #[derive(Deserialize, Serialize)]
 pub struct ImportType {
     pub name: String,
+    pub rust_name: String,
     pub instanceof_shim: String,
+    pub shared_class: Option<String>,
+    pub vendor_prefixes: Vec<String>,
+    pub polyfill: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -96,7 +125,25 @@ pub struct Export {
     pub consumed: bool,
     pub constructor: Option<String>,
     pub function: Function,
+    pub rust_name: String,
     pub comments: Vec<String>,
+    pub optional_args: bool,
+    #[serde(default)]
+    pub js_map: bool,
+    pub defaults: Vec<Option<String>>,
+    pub enumerable: bool,
+    pub configurable: bool,
+    pub getter: Option<String>,
+    pub setter: Option<String>,
+    pub indexing: Option<IndexingKind>,
+    pub symbol: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum IndexingKind {
+    Getter,
+    Setter,
+    Deleter,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -110,6 +157,7 @@ pub struct Enum {
 pub struct EnumVariant {
     pub name: String,
     pub value: u32,
+    pub string_value: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -120,17 +168,45 @@ pub struct Function {
 #[derive(Deserialize, Serialize)]
 pub struct Struct {
     pub name: String,
+    pub rust_name: String,
     pub fields: Vec<StructField>,
     pub comments: Vec<String>,
+    pub extends: Option<String>,
+    pub extends_rust: bool,
+    pub custom_element: Option<CustomElement>,
+    pub async_iterator: bool,
+    pub iterator: bool,
+    pub shared_class: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CustomElement {
+    pub name: String,
+    pub observed_attributes: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct Const {
+    pub name: String,
+    pub rust_name: String,
+    /// The value as a JS literal, such as `44100` or `"en-US"`.
+    pub value: String,
+    pub comments: Vec<String>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct StructField {
     pub name: String,
     pub readonly: bool,
+    pub enumerable: bool,
+    pub configurable: bool,
     pub comments: Vec<String>,
 }
 
+/// The JS name of the method generated for structs marked `iterator`, which
+/// returns the next item of the Rust iterator or `undefined`.
+pub const ITERATOR_NEXT: &str = "__wbindgen_next";
+
 pub fn new_function(struct_name: &str) -> String {
     let mut name = format!("__wbg_");
     name.extend(struct_name.chars().flat_map(|s| s.to_lowercase()));
@@ -175,6 +251,13 @@ pub fn struct_field_set(struct_: &str, f: &str) -> String {
     return name;
 }
 
+/// The prefix of the names of the runtime's imports from and exports to the
+/// generated JS when it's built against a stable ABI, which is put in front
+/// of their usual names, as in `__wbindgen_abi1__wbindgen_malloc`.
+pub fn stable_abi_prefix(version: u32) -> String {
+    format!("__wbindgen_abi{}", version)
+}
+
 pub fn version() -> String {
     let mut v = env!("CARGO_PKG_VERSION").to_string();
     if let Some(s) = option_env!("WBG_VERSION") {
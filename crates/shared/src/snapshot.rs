@@ -0,0 +1,49 @@
+//! Helpers for the snapshot tests of generated code, which are shared by the
+//! macro's expansion tests and the WebIDL frontend's tests.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Formats `code` with rustfmt and normalizes it for comparison with a
+/// snapshot.
+///
+/// Formatting makes the snapshots readable and is what makes diffs of them
+/// line-based, so there's no checking them without rustfmt, and this fails
+/// if it can't be run.
+pub fn format(code: &str) -> Result<String, String> {
+    rustfmt(code).map(|formatted| normalize(&formatted))
+}
+
+/// Replaces the parts of the generated code which change with every commit,
+/// rather than with the code generation itself.
+fn normalize(code: &str) -> String {
+    code.replace(&::version(), "VERSION")
+}
+
+fn rustfmt(code: &str) -> Result<String, String> {
+    let mut child = Command::new("rustfmt")
+        .arg("--emit")
+        .arg("stdout")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run rustfmt, is it installed? {}", e))?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(code.as_bytes())
+        .map_err(|e| format!("failed to write to rustfmt: {}", e))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("failed to wait for rustfmt: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "rustfmt failed with {}:\n{}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|e| format!("rustfmt printed invalid UTF-8: {}", e))
+}
@@ -6,6 +6,7 @@ extern crate proc_macro2;
 #[macro_use]
 extern crate quote;
 
+use std::iter::Peekable;
 use std::sync::atomic::*;
 use proc_macro2::*;
 
@@ -16,11 +17,19 @@ pub fn wasm_bindgen_test(
     attr: proc_macro::TokenStream,
     body: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    let mut attr = attr.into_iter();
+    let mut attr = attr.into_iter().peekable();
     let mut async = false;
+    let mut setup = None;
+    let mut teardown = None;
     while let Some(token) = attr.next() {
         match &token {
             proc_macro::TokenTree::Ident(i) if i.to_string() == "async" => async = true,
+            proc_macro::TokenTree::Ident(i) if i.to_string() == "setup" => {
+                setup = Some(hook(&mut attr));
+            }
+            proc_macro::TokenTree::Ident(i) if i.to_string() == "teardown" => {
+                teardown = Some(hook(&mut attr));
+            }
             _ => panic!("malformed `#[wasm_bindgen_test]` attribute"),
         }
         match &attr.next() {
@@ -32,14 +41,20 @@ pub fn wasm_bindgen_test(
 
     let mut body = TokenStream::from(body).into_iter();
 
-    // Skip over other attributes to `fn #ident ...`, and extract `#ident`
+    // Skip over other attributes to `fn #ident ...` or `mod #ident ...`, and
+    // extract `#ident`
     let mut leading_tokens = Vec::new();
+    let mut is_mod = false;
     while let Some(token) = body.next() {
         leading_tokens.push(token.clone());
         if let TokenTree::Ident(token) = token {
             if token == "fn" {
                 break
             }
+            if token == "mod" {
+                is_mod = true;
+                break
+            }
         }
     }
     let ident = match body.next() {
@@ -49,10 +64,57 @@ pub fn wasm_bindgen_test(
 
     let mut tokens = Vec::<TokenTree>::new();
 
-    let test_body = if async {
-        quote! { cx.execute_async(test_name, #ident); }
+    // On a module the attribute only gives the `setup` and `teardown` hooks
+    // for the tests inside of it, so all that's left to do is to pass them on
+    // to each test.
+    if is_mod {
+        if async {
+            panic!("`async` can't be used on a module, only on its tests");
+        }
+        let items = match body.next() {
+            Some(TokenTree::Group(ref g)) if g.delimiter() == Delimiter::Brace => g.stream(),
+            _ => panic!("`#[wasm_bindgen_test]` can only be used on inline modules"),
+        };
+        let items = with_module_hooks(items, &setup, &teardown);
+        tokens.extend(leading_tokens);
+        tokens.push(ident.into());
+        tokens.push(Group::new(Delimiter::Brace, items).into());
+        return tokens.into_iter().collect::<TokenStream>().into()
+    }
+
+    let test_body = if setup.is_none() && teardown.is_none() {
+        if async {
+            quote! { cx.execute_async(test_name, #ident); }
+        } else {
+            quote! { cx.execute_sync(test_name, #ident); }
+        }
     } else {
-        quote! { cx.execute_sync(test_name, #ident); }
+        // Tests with a fixture are passed a reference to whatever `setup`
+        // returned, and the fixture is then passed on to `teardown`. Without a
+        // `setup` the fixture is `()` and neither the test nor `teardown` take
+        // an argument.
+        let (test, teardown) = match setup {
+            Some(_) => (quote! { |fixture| #ident(fixture) }, teardown),
+            None => {
+                let teardown = teardown.map(|teardown| quote! { |()| #teardown() });
+                (quote! { |_| #ident() }, teardown)
+            }
+        };
+        let setup = setup.unwrap_or_else(|| if async {
+            quote! { || ::wasm_bindgen_test::__rt::ready(()) }
+        } else {
+            quote! { || () }
+        });
+        let teardown = teardown.unwrap_or_else(|| if async {
+            quote! { |_| ::wasm_bindgen_test::__rt::ready(()) }
+        } else {
+            quote! { drop }
+        });
+        if async {
+            quote! { cx.execute_async_fixture(test_name, #setup, #test, #teardown); }
+        } else {
+            quote! { cx.execute_sync_fixture(test_name, #setup, #test, #teardown); }
+        }
     };
 
     // We generate a `#[no_mangle]` with a known prefix so the test harness can
@@ -77,3 +139,104 @@ pub fn wasm_bindgen_test(
 
     tokens.into_iter().collect::<TokenStream>().into()
 }
+
+/// Parses the `= path::to::function` following `setup` or `teardown`.
+fn hook(attr: &mut Peekable<proc_macro::token_stream::IntoIter>) -> TokenStream {
+    match attr.next() {
+        Some(proc_macro::TokenTree::Punct(ref op)) if op.as_char() == '=' => {}
+        _ => panic!("malformed `#[wasm_bindgen_test]` attribute"),
+    }
+    let mut path = Vec::new();
+    loop {
+        match attr.peek() {
+            Some(proc_macro::TokenTree::Punct(op)) if op.as_char() == ',' => break,
+            None => break,
+            _ => {}
+        }
+        path.push(attr.next().unwrap());
+    }
+    if path.is_empty() {
+        panic!("malformed `#[wasm_bindgen_test]` attribute");
+    }
+    TokenStream::from(path.into_iter().collect::<proc_macro::TokenStream>())
+}
+
+/// Adds a module's `setup` and `teardown` hooks to the `#[wasm_bindgen_test]`
+/// attributes of the tests inside of it.
+///
+/// Tests which name a `setup` or `teardown` of their own keep those instead,
+/// and nested modules are left alone since the hooks' paths are relative to
+/// this module.
+fn with_module_hooks(
+    items: TokenStream,
+    setup: &Option<TokenStream>,
+    teardown: &Option<TokenStream>,
+) -> TokenStream {
+    let mut items = items.into_iter().peekable();
+    let mut tokens = Vec::<TokenTree>::new();
+    while let Some(token) = items.next() {
+        let attr = match (&token, items.peek()) {
+            (TokenTree::Punct(p), Some(TokenTree::Group(g)))
+                if p.as_char() == '#' && g.delimiter() == Delimiter::Bracket => g.clone(),
+            _ => {
+                tokens.push(token);
+                continue
+            }
+        };
+        items.next();
+        tokens.push(token);
+
+        // Only `#[wasm_bindgen_test]` or `#[path::to::wasm_bindgen_test]`
+        // attributes on functions are of interest here.
+        let mut attr_tokens = attr.stream().into_iter().collect::<Vec<_>>();
+        let args = match attr_tokens.last() {
+            Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => {
+                Some(g.stream())
+            }
+            _ => None,
+        };
+        if args.is_some() {
+            attr_tokens.pop();
+        }
+        let is_test = match attr_tokens.last() {
+            Some(TokenTree::Ident(i)) => i == "wasm_bindgen_test",
+            _ => false,
+        };
+        let on_fn = items
+            .clone()
+            .filter_map(|t| match t {
+                TokenTree::Ident(i) => Some(i.to_string()),
+                _ => None,
+            })
+            .find(|i| i == "fn" || i == "mod")
+            == Some("fn".to_string());
+        let args = args.unwrap_or_else(TokenStream::new);
+        let has_hooks = args.clone().into_iter().any(|t| match t {
+            TokenTree::Ident(i) => i == "setup" || i == "teardown",
+            _ => false,
+        });
+        if !is_test || !on_fn || has_hooks {
+            tokens.push(attr.into());
+            continue
+        }
+
+        let mut args = args.into_iter().collect::<Vec<_>>();
+        if let Some(setup) = setup {
+            if !args.is_empty() {
+                args.extend(quote! { , });
+            }
+            args.extend(quote! { setup = #setup });
+        }
+        if let Some(teardown) = teardown {
+            if !args.is_empty() {
+                args.extend(quote! { , });
+            }
+            args.extend(quote! { teardown = #teardown });
+        }
+        attr_tokens.push(Group::new(Delimiter::Parenthesis, args.into_iter().collect()).into());
+        let mut attr_group = Group::new(Delimiter::Bracket, attr_tokens.into_iter().collect());
+        attr_group.set_span(attr.span());
+        tokens.push(attr_group.into());
+    }
+    tokens.into_iter().collect()
+}
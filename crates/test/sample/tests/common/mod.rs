@@ -43,3 +43,50 @@ fn fail_after_3s() -> impl Future<Item = (), Error = JsValue> {
         })
 }
 
+
+fn setup() -> String {
+    console_log!("setting up");
+    String::from("fixture")
+}
+
+fn teardown(fixture: String) {
+    console_log!("tearing down {}", fixture);
+}
+
+#[wasm_bindgen_test(setup = setup, teardown = teardown)]
+fn pass_with_fixture(fixture: &String) {
+    assert_eq!(fixture, "fixture");
+}
+
+#[wasm_bindgen_test(setup = setup, teardown = teardown)]
+fn fail_with_fixture(fixture: &String) {
+    console_log!("the fixture should still be torn down");
+    panic!("this is a failing test using {}", fixture);
+}
+
+#[wasm_bindgen_test(teardown = teardown_without_fixture)]
+fn fail_without_fixture() {
+    panic!("this is a failing test with only a teardown");
+}
+
+fn teardown_without_fixture() {
+    console_log!("tearing down after a test without a fixture");
+}
+
+fn setup_after_1s() -> impl Future<Item = String, Error = JsValue> {
+    Timeout::new(Duration::new(1, 0)).map(|()| String::from("async fixture"))
+}
+
+fn teardown_after_1s(fixture: String) -> impl Future<Item = (), Error = JsValue> {
+    Timeout::new(Duration::new(1, 0)).map(move |()| {
+        console_log!("tearing down {}", fixture);
+    })
+}
+
+#[wasm_bindgen_test(async, setup = setup_after_1s, teardown = teardown_after_1s)]
+fn fail_with_async_fixture(fixture: &String) -> impl Future<Item = (), Error = JsValue> {
+    let fixture = fixture.clone();
+    Timeout::new(Duration::new(1, 0)).map(move |()| {
+        panic!("this is a failing test using {}", fixture);
+    })
+}
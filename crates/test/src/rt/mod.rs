@@ -88,7 +88,7 @@
 // always of course welcome!
 
 
-use std::cell::{RefCell, Cell};
+use std::cell::{RefCell, Cell, UnsafeCell};
 use std::fmt;
 use std::rc::Rc;
 
@@ -348,6 +348,85 @@ impl Context {
         self.execute(name, future::lazy(f))
     }
 
+    /// Entry point for a synchronous test with a fixture in wasm. The
+    /// `#[wasm_bindgen_test(setup = ..., teardown = ...)]` macro generates
+    /// invocations of this method.
+    ///
+    /// The test is given a reference to the fixture returned by `setup`, and
+    /// the fixture is then passed to `teardown` once the test has finished,
+    /// even if it panicked.
+    pub fn execute_sync_fixture<T: 'static>(
+        &self,
+        name: &str,
+        setup: impl FnOnce() -> T + 'static,
+        f: impl FnOnce(&mut T) + 'static,
+        teardown: impl FnOnce(T) + 'static,
+    ) {
+        self.execute_fixture(
+            name,
+            future::lazy(|| Ok(setup())),
+            move |fixture: &mut T| future::ok(f(fixture)),
+            move |fixture| future::lazy(move || Ok(teardown(fixture))),
+        );
+    }
+
+    /// Entry point for an asynchronous test with a fixture in wasm, where
+    /// both the setup and teardown are asynchronous as well. The
+    /// `#[wasm_bindgen_test(async, setup = ..., teardown = ...)]` macro
+    /// generates invocations of this method.
+    ///
+    /// The future returned by the test can't borrow the fixture, so anything
+    /// it needs from the fixture has to be cloned out of it.
+    pub fn execute_async_fixture<T, S, F, D>(
+        &self,
+        name: &str,
+        setup: impl FnOnce() -> S + 'static,
+        f: impl FnOnce(&mut T) -> F + 'static,
+        teardown: impl FnOnce(T) -> D + 'static,
+    )
+        where T: 'static,
+              S: Future<Item = T, Error = JsValue> + 'static,
+              F: Future<Item = (), Error = JsValue> + 'static,
+              D: Future<Item = (), Error = JsValue> + 'static,
+    {
+        self.execute_fixture(name, future::lazy(setup), f, teardown);
+    }
+
+    fn execute_fixture<T, F, D>(
+        &self,
+        name: &str,
+        setup: impl Future<Item = T, Error = JsValue> + 'static,
+        test: impl FnOnce(&mut T) -> F + 'static,
+        teardown: impl FnOnce(T) -> D + 'static,
+    )
+        where T: 'static,
+              F: Future<Item = (), Error = JsValue> + 'static,
+              D: Future<Item = (), Error = JsValue> + 'static,
+    {
+        // The test and its teardown each catch their own panics, so a failing
+        // test still has its fixture torn down. If both fail it's the test's
+        // error that's reported, and if the setup fails there's nothing to
+        // tear down.
+        //
+        // A panic doesn't unwind through the test (we're panic=abort) but
+        // instead throws straight past it, so the fixture lives outside of the
+        // test rather than on its stack for it to still be around afterwards.
+        let test = setup.and_then(move |fixture| {
+            let fixture = Rc::new(UnsafeCell::new(Some(fixture)));
+            let borrowed = fixture.clone();
+            CatchPanic(future::lazy(move || {
+                // The test's only borrowing the fixture for the duration of
+                // this call, since the future it returns is `'static`.
+                test(unsafe { (*borrowed.get()).as_mut().unwrap() })
+            })).then(move |result| {
+                let fixture = unsafe { (*fixture.get()).take().unwrap() };
+                CatchPanic(future::lazy(move || teardown(fixture)))
+                    .then(move |teardown_result| result.and(teardown_result))
+            })
+        });
+        self.execute(name, test);
+    }
+
     fn execute(
         &self,
         name: &str,
@@ -551,6 +630,30 @@ impl<F: Future<Error = JsValue>> Future for TestFuture<F> {
     }
 }
 
+/// A future which catches panics while it's polled, like `TestFuture` does,
+/// without capturing any output of its own.
+///
+/// This is used to run teardown code after a test even if the test panicked.
+struct CatchPanic<F>(F);
+
+impl<F: Future<Error = JsValue>> Future for CatchPanic<F> {
+    type Item = F::Item;
+    type Error = F::Error;
+
+    fn poll(&mut self) -> Poll<F::Item, F::Error> {
+        let future = &mut self.0;
+        let mut future_output = None;
+        __wbg_test_invoke(&mut || future_output = Some(future.poll()))?;
+        future_output.unwrap()
+    }
+}
+
+/// Internal implementation detail of `#[wasm_bindgen_test(async, ...)]`
+/// tests that only have one of a `setup` or a `teardown`.
+pub fn ready<T>(value: T) -> future::FutureResult<T, JsValue> {
+    future::ok(value)
+}
+
 fn tab(s: &str) -> String {
     let mut result = String::new();
     for line in s.lines() {
@@ -0,0 +1,122 @@
+#![feature(use_extern_macros, proc_macro_mod)]
+#![cfg(target_arch = "wasm32")]
+
+extern crate futures;
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_futures;
+extern crate wasm_bindgen_test;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use futures::{future, Future};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::__rt::Context;
+use wasm_bindgen_test::*;
+
+/// A fixture which can't be cloned, to check it's only ever borrowed by the
+/// test.
+struct Fixture(u32);
+
+// A failing test would fail this suite as well, so these tests are run in a
+// `Context` of their own to check their fixtures are torn down whether they
+// pass or panic.
+#[wasm_bindgen_test(async)]
+fn teardown_runs_after_pass_and_panic() -> impl Future<Item = (), Error = JsValue> {
+    let torn_down = Rc::new(Cell::new(Vec::new()));
+    let record = |torn_down: &Rc<Cell<Vec<u32>>>| {
+        let torn_down = torn_down.clone();
+        move |fixture: Fixture| {
+            let mut all = torn_down.take();
+            all.push(fixture.0);
+            torn_down.set(all);
+        }
+    };
+
+    let cx = Context::new();
+    cx.execute_sync_fixture(
+        "pass",
+        || Fixture(1),
+        |fixture| fixture.0 += 1,
+        record(&torn_down),
+    );
+    cx.execute_sync_fixture(
+        "panic",
+        || Fixture(10),
+        |fixture| {
+            fixture.0 += 1;
+            panic!("this test is meant to fail");
+        },
+        record(&torn_down),
+    );
+    let record_async = record(&torn_down);
+    cx.execute_async_fixture(
+        "async pass",
+        || future::ok(Fixture(100)),
+        |fixture| {
+            fixture.0 += 1;
+            future::ok(())
+        },
+        move |fixture| future::ok(record_async(fixture)),
+    );
+    let record_async = record(&torn_down);
+    cx.execute_async_fixture(
+        "async panic",
+        || future::ok(Fixture(1000)),
+        |fixture| -> future::FutureResult<(), JsValue> {
+            fixture.0 += 1;
+            panic!("this test is meant to fail");
+        },
+        move |fixture| future::ok(record_async(fixture)),
+    );
+
+    JsFuture::from(cx.run(Vec::new())).map(move |all_passed| {
+        assert_eq!(all_passed, false);
+        let mut all = torn_down.take();
+        all.sort();
+        assert_eq!(all, [2, 11, 101, 1001]);
+    })
+}
+
+fn setup() -> Fixture {
+    Fixture(1)
+}
+
+fn teardown(fixture: Fixture) {
+    assert_eq!(fixture.0, 2);
+}
+
+#[wasm_bindgen_test(setup = setup, teardown = teardown)]
+fn fixture_is_borrowed_mutably(fixture: &mut Fixture) {
+    fixture.0 += 1;
+}
+
+#[wasm_bindgen_test(setup = module_hooks::setup, teardown = teardown)]
+fn fixture_is_borrowed(fixture: &Fixture) {
+    assert_eq!(fixture.0, 2);
+}
+
+#[wasm_bindgen_test(setup = setup, teardown = teardown)]
+pub mod module_hooks {
+    use super::Fixture;
+    use wasm_bindgen_test::*;
+
+    pub fn setup() -> Fixture {
+        Fixture(2)
+    }
+
+    pub fn teardown(fixture: Fixture) {
+        assert_eq!(fixture.0, 3);
+    }
+
+    #[wasm_bindgen_test]
+    fn tests_get_the_module_fixture(fixture: &mut Fixture) {
+        fixture.0 += 1;
+    }
+
+    #[wasm_bindgen_test(setup = super::setup, teardown = super::teardown)]
+    fn tests_can_have_their_own_fixture(fixture: &mut Fixture) {
+        fixture.0 += 1;
+    }
+}
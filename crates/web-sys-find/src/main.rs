@@ -0,0 +1,105 @@
+//! Finds the `web-sys` items which bind to a JS API.
+//!
+//! ```text
+//! $ cargo run -p web-sys-find -- getElementById
+//! Document.prototype.getElementById()
+//!     web_sys::Document::get_element_by_id
+//!     uses: Document, Element
+//! ...
+//! ```
+//!
+//! Names are matched case-insensitively and ignoring underscores, so Rust
+//! names like `request_animation_frame` can be searched for as well.
+
+extern crate wasm_bindgen_webidl;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "
+Finds the web-sys items binding to a JS API
+
+Usage:
+    web-sys-find [--webidls <dir>] <name>...
+
+Options:
+    --webidls <dir>  Directory of the WebIDL files web-sys is generated from,
+                     defaults to the one in this repository
+";
+
+fn main() {
+    let mut webidls = PathBuf::from(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../web-sys/webidls/enabled"
+    ));
+    let mut names = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--webidls" => match args.next() {
+                Some(dir) => webidls = PathBuf::from(dir),
+                None => usage(),
+            },
+            "-h" | "--help" => usage(),
+            _ => names.push(normalize(&arg)),
+        }
+    }
+    if names.is_empty() {
+        usage();
+    }
+
+    let mut source = String::new();
+    let entries = fs::read_dir(&webidls).unwrap_or_else(|e| {
+        eprintln!("error: failed to read {}: {}", webidls.display(), e);
+        process::exit(1);
+    });
+    let mut paths = entries
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("webidl"))
+        .collect::<Vec<_>>();
+    paths.sort();
+    for path in paths {
+        source.push_str(&fs::read_to_string(&path).unwrap());
+        source.push('\n');
+    }
+
+    let index = wasm_bindgen_webidl::Options::new()
+        .api_index(&source)
+        .unwrap_or_else(|e| {
+            eprintln!("error: failed to compile the WebIDL: {}", e);
+            process::exit(1);
+        });
+
+    let mut found = false;
+    for item in index {
+        let js = normalize(&item.js);
+        let rust = normalize(&item.rust);
+        if !names.iter().any(|n| js.contains(n) || rust.contains(n)) {
+            continue;
+        }
+        found = true;
+        println!("{}", item.js);
+        println!("    web_sys::{}", item.rust);
+        if !item.types.is_empty() {
+            println!("    uses: {}", item.types.join(", "));
+        }
+    }
+    if !found {
+        eprintln!("no web-sys items found");
+        process::exit(1);
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_')
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+fn usage() -> ! {
+    eprint!("{}", USAGE);
+    process::exit(1);
+}
@@ -0,0 +1,139 @@
+//! Writes the `web-sys` bindings which are used without its `generate`
+//! feature into `crates/web-sys/pregenerated`, or checks that they're up to
+//! date.
+//!
+//! ```text
+//! $ cargo run -p web-sys-pregenerate
+//! $ cargo run -p web-sys-pregenerate -- --check
+//! ```
+
+extern crate env_logger;
+extern crate failure;
+extern crate serde_json;
+extern crate sourcefile;
+extern crate wasm_bindgen_shared;
+extern crate wasm_bindgen_webidl;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+#[path = "../../web-sys/generate.rs"]
+mod generate;
+
+const USAGE: &str = "
+Writes, or checks, the pregenerated web-sys bindings
+
+Usage:
+    web-sys-pregenerate [--check]
+
+Options:
+    --check  Only check that the pregenerated bindings are up to date, failing
+             if they aren't
+";
+
+fn main() {
+    env_logger::init();
+
+    let mut check = false;
+    for arg in env::args().skip(1) {
+        match &arg[..] {
+            "--check" => check = true,
+            _ => {
+                eprintln!("{}", USAGE.trim());
+                process::exit(1);
+            }
+        }
+    }
+
+    match try_main(check) {
+        Ok(true) => {}
+        Ok(false) => {
+            eprintln!(
+                "error: the pregenerated bindings are out of date, regenerate them \
+                 with `cargo run -p web-sys-pregenerate`"
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            for c in e.iter_causes() {
+                eprintln!("  caused by {}", c);
+            }
+            process::exit(1);
+        }
+    }
+}
+
+/// Writes or checks the bindings, returning whether they were up to date.
+fn try_main(check: bool) -> Result<bool, failure::Error> {
+    use failure::ResultExt;
+
+    let web_sys = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../web-sys"));
+    let dir = web_sys.join("pregenerated");
+
+    // The names of the generated shims are hashed with the name and version
+    // of the crate being built, which the bindings have to match to be the
+    // same as the build script's.
+    let manifest = fs::read_to_string(web_sys.join("Cargo.toml"))
+        .context("reading web-sys's Cargo.toml")?;
+    let version = manifest
+        .lines()
+        .find(|line| line.starts_with("version = "))
+        .map(|line| line["version = ".len()..].trim_matches('"').to_string())
+        .ok_or_else(|| failure::err_msg("web-sys's Cargo.toml doesn't have a version"))?;
+    env::set_var("CARGO_PKG_NAME", "web-sys");
+    env::set_var("CARGO_PKG_VERSION", version);
+
+    let mut up_to_date = true;
+    for &(name, unstable) in [("stable", false), ("unstable", true)].iter() {
+        let files = generate::webidl_files(&web_sys.join("webidls"), unstable)?;
+        // Formatted, so that changes to them are readable diffs.
+        let (bindings, index) = generate::generate(&files, true, false)?;
+
+        let outputs = vec![
+            (dir.join(name).with_extension("rs"), bindings),
+            (dir.join(name).with_extension("json"), index),
+        ];
+        for (path, contents) in outputs {
+            if !check {
+                fs::create_dir_all(&dir).context("creating the pregenerated directory")?;
+                fs::write(&path, &contents)
+                    .with_context(|_| format!("writing {}", path.display()))?;
+                continue;
+            }
+            let current = fs::read_to_string(&path).ok();
+            if current.map(|s| without_revision(&s)) != Some(without_revision(&contents)) {
+                eprintln!("{} is out of date", path.display());
+                up_to_date = false;
+            }
+        }
+    }
+    Ok(up_to_date)
+}
+
+/// The bindings record the version of `wasm-bindgen` they were generated with,
+/// along with its git revision, which changes with every commit rather than
+/// with the bindings, so it's left out when checking them.
+fn without_revision(bindings: &str) -> String {
+    let version = wasm_bindgen_shared::version();
+    let prefix = match version.find(" (") {
+        Some(i) => &version[..i + 2],
+        None => return bindings.to_string(),
+    };
+    let mut ret = String::new();
+    let mut rest = bindings;
+    while let Some(i) = rest.find(prefix) {
+        let after = &rest[i + prefix.len()..];
+        let end = match after.find(')') {
+            Some(end) => end,
+            None => break,
+        };
+        ret.push_str(&rest[..i + prefix.len()]);
+        ret.push_str("REVISION");
+        rest = &after[end..];
+    }
+    ret.push_str(rest);
+    ret
+}
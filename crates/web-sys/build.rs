@@ -1,21 +1,40 @@
+#[cfg(feature = "generate")]
 extern crate env_logger;
-#[macro_use]
+#[cfg(feature = "generate")]
 extern crate failure;
+#[cfg(feature = "generate")]
+extern crate serde_json;
+#[cfg(feature = "generate")]
 extern crate wasm_bindgen_webidl;
+#[cfg(feature = "generate")]
 extern crate sourcefile;
 
-use failure::{Fail, ResultExt};
-use sourcefile::SourceFile;
 use std::env;
-use std::ffi::OsStr;
 use std::fs;
-use std::path;
-use std::process::{self, Command};
+#[cfg(feature = "generate")]
+use std::path::Path;
+#[cfg(not(feature = "generate"))]
+use std::path::PathBuf;
+#[cfg(feature = "generate")]
+use std::process;
+
+#[cfg(feature = "generate")]
+mod generate;
 
 fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "generate")]
+    generate_bindings();
+    #[cfg(not(feature = "generate"))]
+    copy_pregenerated();
+}
+
+#[cfg(feature = "generate")]
+fn generate_bindings() {
     env_logger::init();
 
-    if let Err(e) = try_main() {
+    if let Err(e) = try_generate_bindings() {
         eprintln!("Error: {}", e);
         for c in e.iter_causes() {
             eprintln!("  caused by {}", c);
@@ -24,58 +43,82 @@ fn main() {
     }
 }
 
-fn try_main() -> Result<(), failure::Error> {
-    println!("cargo:rerun-if-changed=build.rs");
-    println!("cargo:rerun-if-changed=webidls/enabled");
+#[cfg(feature = "generate")]
+fn try_generate_bindings() -> Result<(), failure::Error> {
+    use failure::ResultExt;
 
-    let entries = fs::read_dir("webidls/enabled").context("reading webidls/enabled directory")?;
-    let mut source = SourceFile::default();
-    for entry in entries {
-        let entry = entry.context("getting webidls/enabled/*.webidl entry")?;
-        let path = entry.path();
-        if path.extension() != Some(OsStr::new("webidl")) {
-            continue
-        }
-        println!("cargo:rerun-if-changed={}", path.display());
-        source = source.add_file(&path)
-            .with_context(|_| format!("reading contents of file \"{}\"", path.display()))?;
+    let unstable = env::var("CARGO_FEATURE_UNSTABLE_APIS").is_ok();
+    let files = generate::webidl_files(Path::new("webidls"), unstable)?;
+    println!("cargo:rerun-if-changed=webidls/enabled");
+    if unstable {
+        println!("cargo:rerun-if-changed=webidls/unstable");
+    }
+    for file in files.iter() {
+        println!("cargo:rerun-if-changed={}", file.display());
     }
 
-    let bindings = match wasm_bindgen_webidl::compile(&source.contents) {
-        Ok(bindings) => bindings,
-        Err(e) => match e.kind() {
-            wasm_bindgen_webidl::ErrorKind::ParsingWebIDLSourcePos(pos) => {
-                if let Some(pos) = source.resolve_offset(pos) {
-                    let ctx = format!("compiling WebIDL into wasm-bindgen bindings in file \
-                        \"{}\", line {} column {}", pos.filename, pos.line + 1, pos.col + 1);
-                    return Err(e.context(ctx).into());
-                } else {
-                    return Err(e.context("compiling WebIDL into wasm-bindgen bindings").into());
-                }
-            }
-            _ => {
-                return Err(e.context("compiling WebIDL into wasm-bindgen bindings").into());
-            }
-        }
-    };
+    // run rustfmt on the generated file - really handy for debugging
+    println!("cargo:rerun-if-env-changed=WEBIDL_RUSTFMT_BINDINGS");
+    let rustfmt = env::var("WEBIDL_RUSTFMT_BINDINGS").is_ok();
+
+    // fail if anything is skipped, for checking newly enabled WebIDL
+    println!("cargo:rerun-if-env-changed=WEBIDL_STRICT");
+    let strict = env::var("WEBIDL_STRICT").is_ok();
+
+    let (bindings, index) = generate::generate(&files, rustfmt, strict)?;
 
     let out_dir = env::var("OUT_DIR").context("reading OUT_DIR environment variable")?;
-    let out_file_path = path::Path::new(&out_dir).join("bindings.rs");
-    fs::write(&out_file_path, bindings)
+    fs::write(Path::new(&out_dir).join("bindings.rs"), &bindings)
         .context("writing bindings to output file")?;
+    fs::write(Path::new(&out_dir).join("api-index.json"), &index)
+        .context("writing the API index")?;
+    Ok(())
+}
 
-    // run rustfmt on the generated file - really handy for debugging
-    println!("cargo:rerun-if-env-changed=WEBIDL_RUSTFMT_BINDINGS");
-    if env::var("WEBIDL_RUSTFMT_BINDINGS").is_ok() {
-        let status = Command::new("rustfmt")
-            .arg(&out_file_path)
-            .status()
-            .context("running rustfmt")?;
-        if !status.success() {
-            bail!("rustfmt failed: {}", status)
+/// Without the `generate` feature the WebIDL frontend isn't even built, and
+/// the bindings shipped in `pregenerated` are used instead, `stable.rs` and
+/// `stable.json`, or `unstable.rs` and `unstable.json` with the
+/// `unstable-apis` feature.
+#[cfg(not(feature = "generate"))]
+fn copy_pregenerated() {
+    // The bindings are already expanded, for `wasm-bindgen` without any of
+    // the features which change what `#[wasm_bindgen]` expands to, so they
+    // can't be used when another crate turns one of those on.
+    for &(var, feature) in [
+        ("DEP_WASM_BINDGEN_FEATURES_STATIC_DESCRIPTORS", "static-descriptors"),
+        ("DEP_WASM_BINDGEN_FEATURES_STABLE_ABI", "stable-abi"),
+    ].iter() {
+        println!("cargo:rerun-if-env-changed={}", var);
+        if env::var_os(var).is_some() {
+            panic!(
+                "the pregenerated bindings used without the `generate` feature \
+                 don't support `wasm-bindgen`'s `{}` feature, turn on \
+                 `web-sys`'s `generate` feature to generate bindings for it",
+                feature,
+            );
         }
     }
 
-    Ok(())
-}
+    let name = if env::var("CARGO_FEATURE_UNSTABLE_APIS").is_ok() {
+        "unstable"
+    } else {
+        "stable"
+    };
+    let dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("pregenerated");
+    let bindings = dir.join(name).with_extension("rs");
+    let index = dir.join(name).with_extension("json");
+    println!("cargo:rerun-if-changed={}", bindings.display());
+    println!("cargo:rerun-if-changed={}", index.display());
 
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    for (from, to) in [(&bindings, "bindings.rs"), (&index, "api-index.json")].iter() {
+        if let Err(e) = fs::copy(from, out_dir.join(to)) {
+            panic!(
+                "failed to copy {}, which is included in the published crate, \
+                 and can be generated with `cargo run -p web-sys-pregenerate`: {}",
+                from.display(),
+                e,
+            );
+        }
+    }
+}
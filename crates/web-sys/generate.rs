@@ -0,0 +1,88 @@
+//! Generates the bindings from the WebIDL in `webidls`, which is shared by
+//! the build script, with the `generate` feature, and `web-sys-pregenerate`,
+//! which writes the bindings in `pregenerated`.
+
+use failure::{self, Fail, ResultExt};
+use sourcefile::SourceFile;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json;
+use wasm_bindgen_webidl;
+
+/// Lists the WebIDL files in `webidls`, the directory of that name in
+/// `web-sys`, which the bindings are generated from.
+///
+/// APIs which browsers are still working out are only included when asked
+/// for with `unstable`, since their bindings may change in any release.
+pub fn webidl_files(webidls: &Path, unstable: bool) -> Result<Vec<PathBuf>, failure::Error> {
+    let mut files = list_webidls(&webidls.join("enabled"))?;
+    if unstable {
+        files.extend(list_webidls(&webidls.join("unstable"))?);
+    }
+    Ok(files)
+}
+
+/// Generates the bindings for `files`, returning them along with the index
+/// of everything in them, as JSON.
+pub fn generate(
+    files: &[PathBuf],
+    rustfmt: bool,
+    strict: bool,
+) -> Result<(String, String), failure::Error> {
+    let mut source = SourceFile::default();
+    for path in files {
+        source = source.add_file(path)
+            .with_context(|_| format!("reading contents of file \"{}\"", path.display()))?;
+    }
+
+    let options = {
+        let mut options = wasm_bindgen_webidl::Options::new();
+        options.rustfmt(rustfmt).strict(strict);
+        options
+    };
+    let compiled = options.compile(&source.contents);
+    let bindings = match compiled {
+        Ok(bindings) => bindings,
+        Err(e) => match e.kind() {
+            wasm_bindgen_webidl::ErrorKind::ParsingWebIDLSourcePos(pos) => {
+                if let Some(pos) = source.resolve_offset(pos) {
+                    let ctx = format!("compiling WebIDL into wasm-bindgen bindings in file \
+                        \"{}\", line {} column {}", pos.filename, pos.line + 1, pos.col + 1);
+                    return Err(e.context(ctx).into());
+                } else {
+                    return Err(e.context("compiling WebIDL into wasm-bindgen bindings").into());
+                }
+            }
+            _ => {
+                return Err(e.context("compiling WebIDL into wasm-bindgen bindings").into());
+            }
+        }
+    };
+
+    // An index of everything in the bindings, for tools looking up which
+    // item binds to some JS API, like `web-sys-find`.
+    let index = options.api_index(&source.contents)
+        .context("indexing the generated bindings")?;
+    let index = serde_json::to_string_pretty(&index)?;
+
+    Ok((bindings, index))
+}
+
+// Sorted, so that the bindings come out the same whatever order the files are
+// listed in.
+fn list_webidls(dir: &Path) -> Result<Vec<PathBuf>, failure::Error> {
+    let entries = fs::read_dir(dir)
+        .with_context(|_| format!("reading {} directory", dir.display()))?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry.with_context(|_| format!("getting {}/*.webidl entry", dir.display()))?;
+        let path = entry.path();
+        if path.extension() == Some(OsStr::new("webidl")) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,87 @@
+//! Guards for `console.time` and `console.group`, which end the timer or the
+//! group when they're dropped, so that they can't be left running by an early
+//! return.
+
+use wasm_bindgen::JsValue;
+
+use console;
+
+/// Times how long the scope it's created in takes to run, with
+/// `console.time`.
+///
+/// The time is logged with `console.timeEnd` when the timer is dropped:
+///
+/// ```rust,ignore
+/// fn step(world: &mut World) {
+///     let _timer = ConsoleTimer::new("physics");
+///     world.simulate();
+/// } // logs "physics: 1.23ms"
+/// ```
+///
+/// Note that `let _ = ...` drops the timer immediately, so it has to be bound
+/// to a name to time anything.
+#[must_use = "the timer is stopped as soon as it's dropped"]
+pub struct ConsoleTimer {
+    label: String,
+}
+
+impl ConsoleTimer {
+    /// Starts a timer named `label`.
+    ///
+    /// Timers with the same label can't run at the same time, the browser
+    /// only keeps the first one.
+    pub fn new(label: &str) -> ConsoleTimer {
+        console::time_using_label(label);
+        ConsoleTimer {
+            label: label.to_string(),
+        }
+    }
+
+    /// Logs the time elapsed so far without stopping the timer, with
+    /// `console.timeLog`.
+    pub fn log(&self) {
+        console::time_log_using_label_and_data(&self.label, JsValue::undefined());
+    }
+}
+
+impl Drop for ConsoleTimer {
+    fn drop(&mut self) {
+        console::time_end_using_label(&self.label);
+    }
+}
+
+/// Indents everything logged to the console while it's alive, with
+/// `console.group`.
+///
+/// The group is ended with `console.groupEnd` when it's dropped, and groups
+/// can be nested:
+///
+/// ```rust,ignore
+/// let _group = ConsoleGroup::new("loading level");
+/// console::log(JsValue::from_str("parsing")); // logged inside of the group
+/// ```
+#[must_use = "the group is ended as soon as it's dropped"]
+pub struct ConsoleGroup {
+    _private: (),
+}
+
+impl ConsoleGroup {
+    /// Starts a group named `label`.
+    pub fn new(label: &str) -> ConsoleGroup {
+        console::group(JsValue::from_str(label));
+        ConsoleGroup { _private: () }
+    }
+
+    /// Starts a group named `label` which is collapsed in the console until
+    /// it's expanded, with `console.groupCollapsed`.
+    pub fn collapsed(label: &str) -> ConsoleGroup {
+        console::group_collapsed(JsValue::from_str(label));
+        ConsoleGroup { _private: () }
+    }
+}
+
+impl Drop for ConsoleGroup {
+    fn drop(&mut self) {
+        console::group_end();
+    }
+}
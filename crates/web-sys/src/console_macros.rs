@@ -0,0 +1,128 @@
+//! Implementation details of the `console_log!`, `console_error!` and
+//! `js_dbg!` macros.
+//!
+//! The generated `console::log` and `console::error` bindings only take a
+//! single value, so these import each of the arities the macros use
+//! themselves.
+
+use std::fmt;
+
+use js_sys::{Array, Function, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen]
+extern {
+    #[wasm_bindgen(js_name = console)]
+    static CONSOLE: JsValue;
+
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn log_1(a: &JsValue);
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn log_2(a: &JsValue, b: &JsValue);
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn log_3(a: &JsValue, b: &JsValue, c: &JsValue);
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn log_4(a: &JsValue, b: &JsValue, c: &JsValue, d: &JsValue);
+
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn error_1(a: &JsValue);
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn error_2(a: &JsValue, b: &JsValue);
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn error_3(a: &JsValue, b: &JsValue, c: &JsValue);
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn error_4(a: &JsValue, b: &JsValue, c: &JsValue, d: &JsValue);
+}
+
+/// One of the `console` methods the macros log with, along with its imports
+/// for each arity.
+pub struct Method {
+    name: &'static str,
+    one: fn(&JsValue),
+    two: fn(&JsValue, &JsValue),
+    three: fn(&JsValue, &JsValue, &JsValue),
+    four: fn(&JsValue, &JsValue, &JsValue, &JsValue),
+}
+
+pub const LOG: Method = Method {
+    name: "log",
+    one: log_1,
+    two: log_2,
+    three: log_3,
+    four: log_4,
+};
+
+pub const ERROR: Method = Method {
+    name: "error",
+    one: error_1,
+    two: error_2,
+    three: error_3,
+    four: error_4,
+};
+
+/// Logs `args` formatted as a single string.
+pub fn format(method: &Method, args: fmt::Arguments) {
+    (method.one)(&JsValue::from(args.to_string()));
+}
+
+/// Logs `values` as they are, so that the console shows objects as objects.
+pub fn values(method: &Method, values: &[&JsValue]) {
+    match *values {
+        [a] => (method.one)(a),
+        [a, b] => (method.two)(a, b),
+        [a, b, c] => (method.three)(a, b, c),
+        [a, b, c, d] => (method.four)(a, b, c, d),
+        _ => {
+            // There's no import for this many, or for none at all, so spread
+            // them through `Function.prototype.apply` instead.
+            let args = Array::new();
+            for value in values {
+                args.push(value);
+            }
+            let function = Reflect::get(&CONSOLE, &JsValue::from(method.name));
+            drop(function.unchecked_into::<Function>().apply(&CONSOLE, &args));
+        }
+    }
+}
+
+/// Borrows anything that's a JS value as a `&JsValue`, such as the generated
+/// types.
+pub fn as_js<T: AsRef<JsValue> + ?Sized>(value: &T) -> &JsValue {
+    value.as_ref()
+}
+
+/// Logs a value for `js_dbg!`, after the location and source of the
+/// expression it came from.
+pub fn dbg(file: &str, line: u32, expr: &str, value: &JsValue) {
+    let location = JsValue::from(format!("[{}:{}] {} =", file, line, expr));
+    values(&LOG, &[&location, value]);
+}
+
+/// Wraps the value given to `js_dbg!`, which picks how to log it by calling
+/// `dbg_value` on a `&Dbg`.
+///
+/// Method resolution tries `DbgAsJs`, implemented for `Dbg` itself, before
+/// it auto-refs to `DbgAsDebug`, implemented for `&Dbg`, so JS values are
+/// logged as they are whenever they can be.
+pub struct Dbg<'a, T: 'a + ?Sized>(pub &'a T);
+
+pub trait DbgAsJs {
+    fn dbg_value(&self) -> JsValue;
+}
+
+impl<'a, T: AsRef<JsValue> + ?Sized> DbgAsJs for Dbg<'a, T> {
+    fn dbg_value(&self) -> JsValue {
+        self.0.as_ref().clone()
+    }
+}
+
+pub trait DbgAsDebug {
+    fn dbg_value(&self) -> JsValue;
+}
+
+impl<'a, 'b, T: fmt::Debug + ?Sized> DbgAsDebug for &'b Dbg<'a, T> {
+    fn dbg_value(&self) -> JsValue {
+        JsValue::from(format!("{:?}", self.0))
+    }
+}
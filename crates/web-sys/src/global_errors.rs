@@ -0,0 +1,138 @@
+//! Hand-written helpers for reporting the errors nothing else caught, from the
+//! `error` and `unhandledrejection` events of the global object.
+
+use std::cell::RefCell;
+use std::mem;
+use std::rc::Rc;
+
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+
+use {ErrorEvent, PromiseRejectionEvent};
+
+#[wasm_bindgen]
+extern {
+    #[wasm_bindgen(js_name = addEventListener)]
+    fn add_error_listener(kind: &str, listener: &Closure<FnMut(ErrorEvent)>);
+
+    #[wasm_bindgen(js_name = removeEventListener)]
+    fn remove_error_listener(kind: &str, listener: &Closure<FnMut(ErrorEvent)>);
+
+    #[wasm_bindgen(js_name = addEventListener)]
+    fn add_rejection_listener(kind: &str, listener: &Closure<FnMut(PromiseRejectionEvent)>);
+
+    #[wasm_bindgen(js_name = removeEventListener)]
+    fn remove_rejection_listener(kind: &str, listener: &Closure<FnMut(PromiseRejectionEvent)>);
+
+    #[wasm_bindgen(js_name = String)]
+    fn to_string(value: &JsValue) -> String;
+}
+
+/// An error which wasn't caught, passed to the handler installed with
+/// `on_global_error`.
+#[derive(Debug)]
+pub enum GlobalError {
+    /// An exception thrown by a script, from an `error` event.
+    Uncaught(UncaughtError),
+    /// A promise which was rejected without a rejection handler, from an
+    /// `unhandledrejection` event, along with the value it was rejected with.
+    UnhandledRejection(JsValue),
+}
+
+impl GlobalError {
+    /// Returns the message describing this error.
+    ///
+    /// For a rejected promise this is the rejection's `message` property if
+    /// it has one, like an `Error` does, and otherwise the rejection converted
+    /// to a string.
+    pub fn message(&self) -> String {
+        match *self {
+            GlobalError::Uncaught(ref error) => error.message.clone(),
+            GlobalError::UnhandledRejection(ref reason) => {
+                let message = if reason.is_object() {
+                    Reflect::get(reason, &JsValue::from("message")).as_string()
+                } else {
+                    None
+                };
+                message.unwrap_or_else(|| to_string(reason))
+            }
+        }
+    }
+}
+
+/// Where an uncaught exception was thrown and what was thrown, read from an
+/// `ErrorEvent`.
+#[derive(Debug)]
+pub struct UncaughtError {
+    /// The message describing the error.
+    pub message: String,
+    /// The URL of the script the error was thrown from.
+    pub filename: String,
+    /// The line the error was thrown from.
+    pub lineno: u32,
+    /// The column the error was thrown from.
+    pub colno: u32,
+    /// The value that was thrown, or `null` if the browser hides it, as it
+    /// does for scripts from other origins.
+    pub error: JsValue,
+}
+
+/// Calls `handler` with every error which isn't caught anywhere else, from
+/// both thrown exceptions and unhandled promise rejections.
+///
+/// This listens for the `error` and `unhandledrejection` events of the global
+/// object, so it works in pages and workers alike. The browser still reports
+/// the errors in its console as it normally would.
+///
+/// The handler stays installed until the returned `GlobalErrorHandler` is
+/// dropped, or for good with `GlobalErrorHandler::forget`:
+///
+/// ```rust,ignore
+/// web_sys::on_global_error(|error| {
+///     report_to_server(&error.message());
+/// }).forget();
+/// ```
+pub fn on_global_error<F>(handler: F) -> GlobalErrorHandler
+where
+    F: FnMut(GlobalError) + 'static,
+{
+    let handler = Rc::new(RefCell::new(handler));
+    let handle_error = handler.clone();
+    let error = Closure::wrap(Box::new(move |event: ErrorEvent| {
+        (*handle_error.borrow_mut())(GlobalError::Uncaught(UncaughtError {
+            message: event.message(),
+            filename: event.filename(),
+            lineno: event.lineno(),
+            colno: event.colno(),
+            error: event.error(),
+        }));
+    }) as Box<FnMut(ErrorEvent)>);
+    let rejection = Closure::wrap(Box::new(move |event: PromiseRejectionEvent| {
+        (*handler.borrow_mut())(GlobalError::UnhandledRejection(event.reason()));
+    }) as Box<FnMut(PromiseRejectionEvent)>);
+    add_error_listener("error", &error);
+    add_rejection_listener("unhandledrejection", &rejection);
+    GlobalErrorHandler { error, rejection }
+}
+
+/// The handler installed by `on_global_error`, which is removed when this is
+/// dropped.
+#[must_use = "the handler is removed as soon as it's dropped"]
+pub struct GlobalErrorHandler {
+    error: Closure<FnMut(ErrorEvent)>,
+    rejection: Closure<FnMut(PromiseRejectionEvent)>,
+}
+
+impl GlobalErrorHandler {
+    /// Keeps the handler installed for as long as the page or worker lives.
+    pub fn forget(self) {
+        mem::forget(self);
+    }
+}
+
+impl Drop for GlobalErrorHandler {
+    fn drop(&mut self) {
+        remove_error_listener("error", &self.error);
+        remove_rejection_listener("unhandledrejection", &self.rejection);
+    }
+}
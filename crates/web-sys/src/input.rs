@@ -0,0 +1,289 @@
+//! Typed versions of the strings and numbers which identify keys and mouse
+//! buttons in `KeyboardEvent`s and `MouseEvent`s, so that input handling can
+//! `match` on them instead of comparing strings.
+//!
+//! Only the commonly used values are listed. Anything else is still available
+//! as the raw value it came from.
+
+use {KeyboardEvent, MouseEvent};
+
+macro_rules! string_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($variant:ident = $value:tt,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            /// Looks up the variant for `value`, returning `None` if it isn't
+            /// one of the values listed here.
+            pub fn from_value(value: &str) -> Option<$name> {
+                match value {
+                    $($value => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// The string JS uses for this variant.
+            pub fn value(&self) -> &'static str {
+                match *self {
+                    $($name::$variant => $value,)*
+                }
+            }
+        }
+
+        #[cfg(feature = "try-from")]
+        impl<'a> ::std::convert::TryFrom<&'a str> for $name {
+            type Error = &'a str;
+
+            fn try_from(value: &'a str) -> Result<$name, &'a str> {
+                $name::from_value(value).ok_or(value)
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// The named values of `KeyboardEvent.key`, which is the meaning of the key
+    /// that was pressed, taking the keyboard layout and modifiers into account.
+    ///
+    /// Keys which produce a character, like `"a"` or `" "`, have that
+    /// character as their value instead of a name, and aren't listed here.
+    pub enum Key {
+        Unidentified = "Unidentified",
+        Alt = "Alt",
+        AltGraph = "AltGraph",
+        CapsLock = "CapsLock",
+        Control = "Control",
+        Fn = "Fn",
+        Meta = "Meta",
+        NumLock = "NumLock",
+        ScrollLock = "ScrollLock",
+        Shift = "Shift",
+        Enter = "Enter",
+        Tab = "Tab",
+        ArrowDown = "ArrowDown",
+        ArrowLeft = "ArrowLeft",
+        ArrowRight = "ArrowRight",
+        ArrowUp = "ArrowUp",
+        End = "End",
+        Home = "Home",
+        PageDown = "PageDown",
+        PageUp = "PageUp",
+        Backspace = "Backspace",
+        Clear = "Clear",
+        Copy = "Copy",
+        Cut = "Cut",
+        Delete = "Delete",
+        Insert = "Insert",
+        Paste = "Paste",
+        Redo = "Redo",
+        Undo = "Undo",
+        ContextMenu = "ContextMenu",
+        Escape = "Escape",
+        Pause = "Pause",
+        PrintScreen = "PrintScreen",
+        F1 = "F1",
+        F2 = "F2",
+        F3 = "F3",
+        F4 = "F4",
+        F5 = "F5",
+        F6 = "F6",
+        F7 = "F7",
+        F8 = "F8",
+        F9 = "F9",
+        F10 = "F10",
+        F11 = "F11",
+        F12 = "F12",
+        AudioVolumeDown = "AudioVolumeDown",
+        AudioVolumeMute = "AudioVolumeMute",
+        AudioVolumeUp = "AudioVolumeUp",
+        MediaPlayPause = "MediaPlayPause",
+        MediaStop = "MediaStop",
+        MediaTrackNext = "MediaTrackNext",
+        MediaTrackPrevious = "MediaTrackPrevious",
+    }
+}
+
+string_enum! {
+    /// The values of `KeyboardEvent.code`, which is the physical key that was
+    /// pressed regardless of the keyboard layout, named after the key in that
+    /// position on a US keyboard.
+    pub enum Code {
+        KeyA = "KeyA",
+        KeyB = "KeyB",
+        KeyC = "KeyC",
+        KeyD = "KeyD",
+        KeyE = "KeyE",
+        KeyF = "KeyF",
+        KeyG = "KeyG",
+        KeyH = "KeyH",
+        KeyI = "KeyI",
+        KeyJ = "KeyJ",
+        KeyK = "KeyK",
+        KeyL = "KeyL",
+        KeyM = "KeyM",
+        KeyN = "KeyN",
+        KeyO = "KeyO",
+        KeyP = "KeyP",
+        KeyQ = "KeyQ",
+        KeyR = "KeyR",
+        KeyS = "KeyS",
+        KeyT = "KeyT",
+        KeyU = "KeyU",
+        KeyV = "KeyV",
+        KeyW = "KeyW",
+        KeyX = "KeyX",
+        KeyY = "KeyY",
+        KeyZ = "KeyZ",
+        Digit0 = "Digit0",
+        Digit1 = "Digit1",
+        Digit2 = "Digit2",
+        Digit3 = "Digit3",
+        Digit4 = "Digit4",
+        Digit5 = "Digit5",
+        Digit6 = "Digit6",
+        Digit7 = "Digit7",
+        Digit8 = "Digit8",
+        Digit9 = "Digit9",
+        Backquote = "Backquote",
+        Backslash = "Backslash",
+        BracketLeft = "BracketLeft",
+        BracketRight = "BracketRight",
+        Comma = "Comma",
+        Equal = "Equal",
+        IntlBackslash = "IntlBackslash",
+        Minus = "Minus",
+        Period = "Period",
+        Quote = "Quote",
+        Semicolon = "Semicolon",
+        Slash = "Slash",
+        AltLeft = "AltLeft",
+        AltRight = "AltRight",
+        Backspace = "Backspace",
+        CapsLock = "CapsLock",
+        ContextMenu = "ContextMenu",
+        ControlLeft = "ControlLeft",
+        ControlRight = "ControlRight",
+        Enter = "Enter",
+        MetaLeft = "MetaLeft",
+        MetaRight = "MetaRight",
+        ShiftLeft = "ShiftLeft",
+        ShiftRight = "ShiftRight",
+        Space = "Space",
+        Tab = "Tab",
+        Delete = "Delete",
+        End = "End",
+        Home = "Home",
+        Insert = "Insert",
+        PageDown = "PageDown",
+        PageUp = "PageUp",
+        ArrowDown = "ArrowDown",
+        ArrowLeft = "ArrowLeft",
+        ArrowRight = "ArrowRight",
+        ArrowUp = "ArrowUp",
+        NumLock = "NumLock",
+        Numpad0 = "Numpad0",
+        Numpad1 = "Numpad1",
+        Numpad2 = "Numpad2",
+        Numpad3 = "Numpad3",
+        Numpad4 = "Numpad4",
+        Numpad5 = "Numpad5",
+        Numpad6 = "Numpad6",
+        Numpad7 = "Numpad7",
+        Numpad8 = "Numpad8",
+        Numpad9 = "Numpad9",
+        NumpadAdd = "NumpadAdd",
+        NumpadDecimal = "NumpadDecimal",
+        NumpadDivide = "NumpadDivide",
+        NumpadEnter = "NumpadEnter",
+        NumpadMultiply = "NumpadMultiply",
+        NumpadSubtract = "NumpadSubtract",
+        Escape = "Escape",
+        F1 = "F1",
+        F2 = "F2",
+        F3 = "F3",
+        F4 = "F4",
+        F5 = "F5",
+        F6 = "F6",
+        F7 = "F7",
+        F8 = "F8",
+        F9 = "F9",
+        F10 = "F10",
+        F11 = "F11",
+        F12 = "F12",
+        PrintScreen = "PrintScreen",
+        ScrollLock = "ScrollLock",
+        Pause = "Pause",
+    }
+}
+
+/// The values of `MouseEvent.button`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    /// Usually the left button.
+    Main = 0,
+    /// Usually the wheel or middle button.
+    Auxiliary = 1,
+    /// Usually the right button.
+    Secondary = 2,
+    /// Usually the "browser back" button.
+    Back = 3,
+    /// Usually the "browser forward" button.
+    Forward = 4,
+}
+
+impl MouseButton {
+    /// Looks up the button numbered `button`, returning `None` for buttons
+    /// past the fifth one.
+    pub fn from_button(button: i16) -> Option<MouseButton> {
+        match button {
+            0 => Some(MouseButton::Main),
+            1 => Some(MouseButton::Auxiliary),
+            2 => Some(MouseButton::Secondary),
+            3 => Some(MouseButton::Back),
+            4 => Some(MouseButton::Forward),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "try-from")]
+impl ::std::convert::TryFrom<i16> for MouseButton {
+    type Error = i16;
+
+    fn try_from(button: i16) -> Result<MouseButton, i16> {
+        MouseButton::from_button(button).ok_or(button)
+    }
+}
+
+impl KeyboardEvent {
+    /// This event's `key`, or the raw value if it isn't a named key, like
+    /// the characters typed by most keys.
+    pub fn typed_key(&self) -> Result<Key, String> {
+        let key = self.key();
+        Key::from_value(&key).ok_or(key)
+    }
+
+    /// This event's `code`, or the raw value if it isn't one listed in
+    /// `Code`.
+    pub fn typed_code(&self) -> Result<Code, String> {
+        let code = self.code();
+        Code::from_value(&code).ok_or(code)
+    }
+}
+
+impl MouseEvent {
+    /// This event's `button`, or the raw number if it isn't one of the five
+    /// buttons in `MouseButton`.
+    pub fn typed_button(&self) -> Result<MouseButton, i16> {
+        let button = self.button();
+        MouseButton::from_button(button).ok_or(button)
+    }
+}
@@ -1,6 +1,117 @@
+#![feature(use_extern_macros)]
+#![cfg_attr(feature = "try-from", feature(try_from))]
 #![doc(html_root_url = "https://docs.rs/web-sys/0.2")]
 
 extern crate wasm_bindgen;
 extern crate js_sys;
+#[cfg(feature = "async")]
+extern crate futures;
+#[cfg(feature = "async")]
+extern crate wasm_bindgen_futures;
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+/// Works like `println!`, only logging the formatted message with
+/// `console.log` instead.
+///
+/// Starting the arguments with `values:` logs a list of JS values as they are
+/// instead, so that objects can still be expanded in the browser's console:
+///
+/// ```rust,ignore
+/// console_log!("clicked at {}, {}", x, y);
+/// console_log!(values: &JsValue::from("clicked"), &event);
+/// ```
+///
+/// Anything which implements `AsRef<JsValue>`, like all the types in this
+/// crate, can be logged as a value.
+#[macro_export]
+macro_rules! console_log {
+    (values: $($value:expr),* $(,)*) => (
+        $crate::__console::values(
+            &$crate::__console::LOG,
+            &[$($crate::__console::as_js(&$value)),*],
+        )
+    );
+    ($($arg:tt)*) => (
+        $crate::__console::format(&$crate::__console::LOG, format_args!($($arg)*))
+    );
+}
+
+/// The same as `console_log!`, only logging with `console.error`.
+#[macro_export]
+macro_rules! console_error {
+    (values: $($value:expr),* $(,)*) => (
+        $crate::__console::values(
+            &$crate::__console::ERROR,
+            &[$($crate::__console::as_js(&$value)),*],
+        )
+    );
+    ($($arg:tt)*) => (
+        $crate::__console::format(&$crate::__console::ERROR, format_args!($($arg)*))
+    );
+}
+
+/// Works like `dbg!`, logging the expression along with the file and line
+/// it's on, and its value, with `console.log` before returning it.
+///
+/// Values which implement `AsRef<JsValue>`, like all the types in this crate,
+/// are logged as they are so they can be expanded in the browser's console,
+/// and anything else is logged with its `Debug` implementation:
+///
+/// ```rust,ignore
+/// let context = js_dbg!(canvas.get_context_webgl2()?);
+/// // logs "[src/lib.rs:12] canvas.get_context_webgl2()? =" and the context
+/// let pixels = js_dbg!(canvas.width()) * 2;
+/// // logs "[src/lib.rs:14] canvas.width() = 300"
+/// ```
+///
+/// Several expressions can be logged at once, which returns all of their
+/// values as a tuple, and with none at all only the file and line are logged.
+#[macro_export]
+macro_rules! js_dbg {
+    () => (
+        $crate::console_log!("[{}:{}]", file!(), line!())
+    );
+    ($val:expr) => (
+        // Matching keeps any temporaries in `$val` alive until it's logged,
+        // the same as `dbg!`.
+        match $val {
+            tmp => {
+                #[allow(unused_imports)]
+                use $crate::__console::{DbgAsDebug, DbgAsJs};
+                let value = (&$crate::__console::Dbg(&tmp)).dbg_value();
+                $crate::__console::dbg(file!(), line!(), stringify!($val), &value);
+                tmp
+            }
+        }
+    );
+    ($($val:expr),+ $(,)*) => (
+        ($($crate::js_dbg!($val)),+,)
+    );
+}
+
+#[doc(hidden)]
+#[path = "console_macros.rs"]
+pub mod __console;
+
+mod console_guards;
+mod global_errors;
+mod input;
+#[cfg(feature = "async")]
+mod notification;
+#[cfg(feature = "async")]
+mod service_worker;
+#[cfg(all(feature = "unstable-apis", feature = "async"))]
+mod streams;
+mod webaudio;
+mod webgl;
+mod webmidi;
+
+pub use console_guards::{ConsoleGroup, ConsoleTimer};
+pub use global_errors::{on_global_error, GlobalError, GlobalErrorHandler, UncaughtError};
+pub use input::{Code, Key, MouseButton};
+#[cfg(feature = "async")]
+pub use service_worker::{open_cache, IntoRequest, UpdateFound};
+#[cfg(all(feature = "unstable-apis", feature = "async"))]
+pub use streams::{ReadableStreamItems, WritableStreamSink};
+pub use webmidi::{request_midi_access, MidiMessage};
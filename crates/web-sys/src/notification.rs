@@ -0,0 +1,50 @@
+//! Hand-written helpers for asking to show notifications, since
+//! `Notification.requestPermission` takes a callback in some browsers and
+//! returns a promise in others, and the generated bindings cover neither.
+
+use futures::Future;
+use js_sys::{Function, Promise};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use {Notification, PermissionState};
+
+#[wasm_bindgen]
+extern {
+    #[wasm_bindgen(static_method_of = Notification, js_name = requestPermission, catch)]
+    fn request_permission_with_callback(callback: &Function) -> Result<JsValue, JsValue>;
+}
+
+impl Notification {
+    /// Asks the user for permission to show notifications, like
+    /// `Notification.requestPermission()`.
+    ///
+    /// This works both in browsers which return a promise and in older ones
+    /// which only call a callback. The future resolves to `Prompt` if the
+    /// user dismissed the request without choosing, which the Notifications
+    /// API calls `"default"`.
+    pub fn request_permission() -> impl Future<Item = PermissionState, Error = JsValue> {
+        let permission = Promise::new(&mut |resolve, reject| {
+            match Notification::request_permission_with_callback(&resolve) {
+                // Resolving with the returned promise follows it, and
+                // resolving again from the callback is ignored, so browsers
+                // which do both are fine.
+                Ok(ret) => {
+                    if !ret.is_undefined() {
+                        drop(resolve.call1(&JsValue::undefined(), &ret));
+                    }
+                }
+                Err(e) => {
+                    drop(reject.call1(&JsValue::undefined(), &e));
+                }
+            }
+        });
+        JsFuture::from(permission).map(|permission| {
+            match permission.as_string().as_ref().map(|s| &s[..]) {
+                Some("granted") => PermissionState::Granted,
+                Some("denied") => PermissionState::Denied,
+                _ => PermissionState::Prompt,
+            }
+        })
+    }
+}
@@ -0,0 +1,175 @@
+//! Hand-written helpers for registering service workers and using their
+//! caches, since the generated bindings only return untyped promises and can't
+//! listen for events yet.
+
+use futures::future;
+use futures::sync::mpsc::{self, UnboundedReceiver};
+use futures::{Async, Future, Poll, Stream};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use {Cache, CacheStorage, RegistrationOptions, Request, Response, ServiceWorker};
+use {ServiceWorkerContainer, ServiceWorkerRegistration};
+
+#[wasm_bindgen]
+extern {
+    #[wasm_bindgen(js_name = caches)]
+    static CACHES: CacheStorage;
+
+    #[wasm_bindgen(method, structural, js_name = addEventListener)]
+    fn add_event_listener(this: &ServiceWorkerRegistration, kind: &str, listener: &Closure<FnMut()>);
+
+    #[wasm_bindgen(method, structural, js_name = removeEventListener)]
+    fn remove_event_listener(this: &ServiceWorkerRegistration, kind: &str, listener: &Closure<FnMut()>);
+}
+
+impl ServiceWorkerContainer {
+    /// Registers the service worker script at `script_url`, like
+    /// `navigator.serviceWorker.register()`.
+    ///
+    /// The worker controls the pages under `scope`, or under the directory
+    /// the script is in if `scope` is `None`. The future resolves once the
+    /// script has been fetched, which is before the worker has installed;
+    /// `ServiceWorkerRegistration::update_found` follows it from there.
+    pub fn register(
+        &self,
+        script_url: &str,
+        scope: Option<&str>,
+    ) -> impl Future<Item = ServiceWorkerRegistration, Error = JsValue> {
+        let mut options = RegistrationOptions::new();
+        if let Some(scope) = scope {
+            options.scope(scope);
+        }
+        let registration = self.register_using_script_url_and_options(script_url, &options);
+        JsFuture::from(registration).map(|registration| registration.unchecked_into())
+    }
+}
+
+impl ServiceWorkerRegistration {
+    /// Returns a stream of the workers which start installing for this
+    /// registration, one for each `updatefound` event.
+    ///
+    /// The stream never ends by itself, and stops listening for events once
+    /// it's dropped.
+    pub fn update_found(&self) -> UpdateFound {
+        let (sender, receiver) = mpsc::unbounded();
+        let registration = clone_handle(self);
+        let listener = Closure::wrap(Box::new(move || {
+            if let Some(worker) = registration.installing() {
+                // The receiver removes this listener before going away, so
+                // sending can't fail
+                drop(sender.unbounded_send(worker));
+            }
+        }) as Box<FnMut()>);
+        self.add_event_listener("updatefound", &listener);
+        UpdateFound {
+            registration: clone_handle(self),
+            listener,
+            receiver,
+        }
+    }
+}
+
+/// The stream returned by `ServiceWorkerRegistration::update_found`.
+pub struct UpdateFound {
+    registration: ServiceWorkerRegistration,
+    listener: Closure<FnMut()>,
+    receiver: UnboundedReceiver<ServiceWorker>,
+}
+
+impl Stream for UpdateFound {
+    type Item = ServiceWorker;
+    type Error = JsValue;
+
+    fn poll(&mut self) -> Poll<Option<ServiceWorker>, JsValue> {
+        // The sender lives in `listener`, so the channel stays open and
+        // receiving from it never fails
+        Ok(self.receiver.poll().unwrap_or(Async::NotReady))
+    }
+}
+
+impl Drop for UpdateFound {
+    fn drop(&mut self) {
+        self.registration.remove_event_listener("updatefound", &self.listener);
+    }
+}
+
+/// Something a cache entry can be looked up by: either a `Request` or the URL
+/// to make one for.
+pub trait IntoRequest {
+    /// Converts this into a `Request`, failing if it's an invalid URL.
+    fn into_request(self) -> Result<Request, JsValue>;
+}
+
+impl IntoRequest for Request {
+    fn into_request(self) -> Result<Request, JsValue> {
+        Ok(self)
+    }
+}
+
+impl<'a> IntoRequest for &'a Request {
+    fn into_request(self) -> Result<Request, JsValue> {
+        Ok(clone_handle(self))
+    }
+}
+
+impl<'a> IntoRequest for &'a str {
+    fn into_request(self) -> Result<Request, JsValue> {
+        Request::new_using_usv_str(self)
+    }
+}
+
+impl<'a> IntoRequest for &'a String {
+    fn into_request(self) -> Result<Request, JsValue> {
+        Request::new_using_usv_str(self)
+    }
+}
+
+/// Opens the cache called `name`, like `caches.open()`, creating it if it
+/// doesn't exist yet.
+///
+/// This works both in pages and in service workers.
+pub fn open_cache(name: &str) -> impl Future<Item = Cache, Error = JsValue> {
+    JsFuture::from(CACHES.open(name)).map(|cache| cache.unchecked_into())
+}
+
+impl Cache {
+    /// Stores `response` for `request`, like `cache.put()`, replacing whatever
+    /// was stored for it before.
+    pub fn put<R: IntoRequest>(
+        &self,
+        request: R,
+        response: &Response,
+    ) -> impl Future<Item = (), Error = JsValue> {
+        let stored = request
+            .into_request()
+            .map(|request| self.put_using_request_and_response(&request, response));
+        future::result(stored).and_then(JsFuture::from).map(|_| ())
+    }
+
+    /// Looks up the response stored for `request`, like `cache.match()`.
+    ///
+    /// The future resolves to `None` if nothing matches.
+    pub fn get<R: IntoRequest>(
+        &self,
+        request: R,
+    ) -> impl Future<Item = Option<Response>, Error = JsValue> {
+        let matched = request
+            .into_request()
+            .map(|request| self.match_using_request(&request));
+        future::result(matched).and_then(JsFuture::from).map(|response| {
+            if response.is_undefined() {
+                None
+            } else {
+                Some(response.unchecked_into())
+            }
+        })
+    }
+}
+
+// Makes another handle to the same JS object as `value`, since the generated
+// types aren't `Clone`.
+fn clone_handle<T: JsCast>(value: &T) -> T {
+    JsValue::clone(value.as_ref()).unchecked_into()
+}
@@ -0,0 +1,137 @@
+//! Hand-written adapters between the Streams API and `futures`, so that the
+//! streaming network APIs in the `unstable-apis` feature, like `WebTransport`
+//! and `WebSocketStream`, can be read and written like any other `Stream` and
+//! `Sink`.
+
+use futures::{Async, AsyncSink, Future, Poll, Sink, StartSend, Stream};
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use {ReadableStream, ReadableStreamDefaultReader, WebSocketStream};
+use {WritableStream, WritableStreamDefaultWriter};
+
+impl ReadableStream {
+    /// Locks this stream and returns a `Stream` of the chunks read from it.
+    ///
+    /// This fails if the stream is already locked, for example by another
+    /// reader.
+    pub fn into_stream(self) -> Result<ReadableStreamItems, JsValue> {
+        Ok(ReadableStreamItems {
+            reader: self.get_reader()?,
+            read: None,
+        })
+    }
+}
+
+/// The chunks of a `ReadableStream`, returned by `ReadableStream::into_stream`.
+///
+/// The stream ends when the `ReadableStream` is closed, and fails with the
+/// error it's cancelled or errored with. Dropping it releases the lock on the
+/// `ReadableStream` unless a chunk is still being read.
+pub struct ReadableStreamItems {
+    reader: ReadableStreamDefaultReader,
+    read: Option<JsFuture>,
+}
+
+impl Stream for ReadableStreamItems {
+    type Item = JsValue;
+    type Error = JsValue;
+
+    fn poll(&mut self) -> Poll<Option<JsValue>, JsValue> {
+        let result = {
+            let reader = &self.reader;
+            let read = self.read.get_or_insert_with(|| JsFuture::from(reader.read()));
+            match read.poll()? {
+                Async::Ready(result) => result,
+                Async::NotReady => return Ok(Async::NotReady),
+            }
+        };
+        self.read = None;
+        if Reflect::get(&result, &JsValue::from("done")).as_bool() == Some(true) {
+            return Ok(Async::Ready(None));
+        }
+        Ok(Async::Ready(Some(Reflect::get(&result, &JsValue::from("value")))))
+    }
+}
+
+impl Drop for ReadableStreamItems {
+    fn drop(&mut self) {
+        if self.read.is_none() {
+            drop(self.reader.release_lock());
+        }
+    }
+}
+
+impl WritableStream {
+    /// Locks this stream and returns a `Sink` which writes chunks to it.
+    ///
+    /// This fails if the stream is already locked, for example by another
+    /// writer.
+    pub fn into_sink(self) -> Result<WritableStreamSink, JsValue> {
+        Ok(WritableStreamSink {
+            writer: self.get_writer()?,
+            write: None,
+            close: None,
+        })
+    }
+}
+
+/// A `Sink` which writes to a `WritableStream`, returned by
+/// `WritableStream::into_sink`.
+///
+/// Chunks are written one at a time, with each one waiting for the previous
+/// one to have been handled by the stream. Closing the sink closes the
+/// `WritableStream`.
+pub struct WritableStreamSink {
+    writer: WritableStreamDefaultWriter,
+    write: Option<JsFuture>,
+    close: Option<JsFuture>,
+}
+
+impl Sink for WritableStreamSink {
+    type SinkItem = JsValue;
+    type SinkError = JsValue;
+
+    fn start_send(&mut self, chunk: JsValue) -> StartSend<JsValue, JsValue> {
+        if let Async::NotReady = self.poll_complete()? {
+            return Ok(AsyncSink::NotReady(chunk));
+        }
+        self.write = Some(JsFuture::from(self.writer.write(chunk)));
+        Ok(AsyncSink::Ready)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), JsValue> {
+        if let Some(ref mut write) = self.write {
+            if let Async::NotReady = write.poll()? {
+                return Ok(Async::NotReady);
+            }
+        }
+        self.write = None;
+        Ok(Async::Ready(()))
+    }
+
+    fn close(&mut self) -> Poll<(), JsValue> {
+        if let Async::NotReady = self.poll_complete()? {
+            return Ok(Async::NotReady);
+        }
+        let writer = &self.writer;
+        let close = self.close.get_or_insert_with(|| JsFuture::from(writer.close()));
+        Ok(close.poll()?.map(|_| ()))
+    }
+}
+
+impl WebSocketStream {
+    /// Waits for the connection to open, like `opened`, and returns the
+    /// streams for reading messages from it and writing messages to it.
+    pub fn opened_streams(
+        &self,
+    ) -> impl Future<Item = (ReadableStream, WritableStream), Error = JsValue> {
+        JsFuture::from(self.opened()).map(|info| {
+            let readable = Reflect::get(&info, &JsValue::from("readable"));
+            let writable = Reflect::get(&info, &JsValue::from("writable"));
+            (readable.unchecked_into(), writable.unchecked_into())
+        })
+    }
+}
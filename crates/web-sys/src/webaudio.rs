@@ -0,0 +1,86 @@
+//! Hand-written helpers for passing sample data to Web Audio, whose generated
+//! bindings either have unwieldy overload names or, for `PeriodicWave`'s
+//! options, can't be generated at all.
+
+use js_sys::{Float32Array, Reflect};
+use wasm_bindgen::JsValue;
+
+use {AudioBuffer, AudioParam, BaseAudioContext, PeriodicWave, PeriodicWaveOptions};
+
+impl AudioBuffer {
+    /// Copies `source` into channel `channel` of this buffer, starting at its
+    /// first sample.
+    pub fn copy_to_channel(&self, source: &[f32], channel: i32) -> Result<(), JsValue> {
+        self.copy_to_channel_using_source_and_channel_number(source, channel)
+    }
+
+    /// Copies `source` into channel `channel` of this buffer, starting at
+    /// sample `start`.
+    pub fn copy_to_channel_at(
+        &self,
+        source: &[f32],
+        channel: i32,
+        start: u32,
+    ) -> Result<(), JsValue> {
+        self.copy_to_channel_using_source_and_channel_number_and_start_in_channel(
+            source, channel, start,
+        )
+    }
+}
+
+impl AudioParam {
+    /// Schedules this parameter to follow `values` starting at `start_time`,
+    /// moving on to the next value every `1 / sample_rate` seconds.
+    ///
+    /// This is `setValueCurveAtTime` with the curve's duration worked out from
+    /// its length, so that each value lines up with one sample when
+    /// `sample_rate` is the context's sample rate.
+    pub fn set_value_curve_at_sample_rate(
+        &self,
+        values: &[f32],
+        start_time: f64,
+        sample_rate: f32,
+    ) -> Result<AudioParam, JsValue> {
+        let duration = values.len() as f64 / sample_rate as f64;
+        self.set_value_curve_at_time(values, start_time, duration)
+    }
+}
+
+impl BaseAudioContext {
+    /// Creates a `PeriodicWave` out of the cosine (`real`) and sine (`imag`)
+    /// terms of its Fourier series, which must be the same length.
+    pub fn create_periodic_wave(
+        &self,
+        real: &[f32],
+        imag: &[f32],
+    ) -> Result<PeriodicWave, JsValue> {
+        self.create_periodic_wave_using_real_and_imag(real, imag)
+    }
+}
+
+impl PeriodicWave {
+    /// Constructs a `PeriodicWave` for `context`, like `create_periodic_wave`
+    /// but through the `PeriodicWave` constructor so that normalization can
+    /// be turned off.
+    pub fn new_with_coefficients(
+        context: &BaseAudioContext,
+        real: &[f32],
+        imag: &[f32],
+        disable_normalization: bool,
+    ) -> Result<PeriodicWave, JsValue> {
+        let mut options = PeriodicWaveOptions::new();
+        options.disable_normalization(disable_normalization);
+        let options_value: &JsValue = options.as_ref();
+        Reflect::set(options_value, &JsValue::from("real"), &to_float32_array(real));
+        Reflect::set(options_value, &JsValue::from("imag"), &to_float32_array(imag));
+        PeriodicWave::new_using_context_and_options(context, &options)
+    }
+}
+
+// Copies `values` out of wasm memory, since `PeriodicWaveOptions` holds on to
+// what it's given.
+fn to_float32_array(values: &[f32]) -> JsValue {
+    let array = Float32Array::new(&JsValue::from(values.len() as u32));
+    array.copy_from(values);
+    array.into()
+}
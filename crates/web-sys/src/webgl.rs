@@ -0,0 +1,83 @@
+//! Hand-written helpers for the common WebGL setup code, which otherwise needs
+//! casts and status checks that are easy to get wrong.
+
+use wasm_bindgen::{JsCast, JsValue};
+
+use {HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram, WebGlShader};
+
+impl HtmlCanvasElement {
+    /// Gets this canvas's WebGL 2 context, like `canvas.getContext("webgl2")`.
+    ///
+    /// Returns an error if the browser doesn't support WebGL 2, or if the
+    /// canvas already has a different kind of context.
+    pub fn get_context_webgl2(&self) -> Result<WebGl2RenderingContext, JsValue> {
+        match self.get_context_using_context_id("webgl2")? {
+            Some(context) => context
+                .dyn_into::<WebGl2RenderingContext>()
+                .map_err(JsValue::from),
+            None => Err(JsValue::from_str("WebGL 2 is not available on this canvas")),
+        }
+    }
+}
+
+impl WebGl2RenderingContext {
+    /// Creates a shader of type `shader_type`, either `VERTEX_SHADER` or
+    /// `FRAGMENT_SHADER`, and compiles `source` into it.
+    ///
+    /// If compilation fails the shader is deleted and its info log, which
+    /// describes what went wrong, is returned as the error.
+    pub fn compile_shader_from_source(
+        &self,
+        shader_type: u32,
+        source: &str,
+    ) -> Result<WebGlShader, String> {
+        let shader = self
+            .create_shader(shader_type)
+            .ok_or_else(|| String::from("unable to create shader object"))?;
+        self.shader_source(&shader, source);
+        self.compile_shader(&shader);
+
+        let compiled = self
+            .get_shader_parameter(&shader, WebGl2RenderingContext::COMPILE_STATUS)
+            .as_bool()
+            .unwrap_or(false);
+        if compiled {
+            return Ok(shader);
+        }
+        let log = self
+            .get_shader_info_log(&shader)
+            .unwrap_or_else(|| String::from("unknown error compiling shader"));
+        self.delete_shader(Some(&shader));
+        Err(log)
+    }
+
+    /// Creates a program out of `shaders` and links it.
+    ///
+    /// If linking fails the program is deleted and its info log is returned
+    /// as the error.
+    pub fn link_program_from_shaders(
+        &self,
+        shaders: &[&WebGlShader],
+    ) -> Result<WebGlProgram, String> {
+        let program = self
+            .create_program()
+            .ok_or_else(|| String::from("unable to create program object"))?;
+        for shader in shaders {
+            self.attach_shader(&program, shader);
+        }
+        self.link_program(&program);
+
+        let linked = self
+            .get_program_parameter(&program, WebGl2RenderingContext::LINK_STATUS)
+            .as_bool()
+            .unwrap_or(false);
+        if linked {
+            return Ok(program);
+        }
+        let log = self
+            .get_program_info_log(&program)
+            .unwrap_or_else(|| String::from("unknown error linking program"));
+        self.delete_program(Some(&program));
+        Err(log)
+    }
+}
@@ -0,0 +1,78 @@
+//! Hand-written helpers for Web MIDI: reading `MIDIMessageEvent`s as typed
+//! messages, plus the handler and map iteration which the generated bindings
+//! can't cover yet.
+
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+
+use {MidiInput, MidiInputMap, MidiMessageEvent, Navigator};
+
+#[wasm_bindgen]
+extern {
+    #[wasm_bindgen(js_name = navigator)]
+    static NAVIGATOR: Navigator;
+
+    /// Sets the closure called with a `MidiMessageEvent` for each message
+    /// this input receives.
+    ///
+    /// The closure has to be kept alive for as long as the input might
+    /// receive messages, for example with `Closure::forget`.
+    #[wasm_bindgen(method, structural, setter = onmidimessage)]
+    pub fn set_onmidimessage(this: &MidiInput, handler: &Closure<FnMut(MidiMessageEvent)>);
+
+    /// Calls `f` with each of the inputs in this map.
+    #[wasm_bindgen(method, structural, js_name = forEach)]
+    pub fn for_each(this: &MidiInputMap, f: &mut FnMut(MidiInput));
+}
+
+/// Asks for access to the MIDI devices connected to this computer, like
+/// `navigator.requestMIDIAccess()`.
+///
+/// The returned promise resolves to a `MidiAccess` once the user allows it.
+pub fn request_midi_access() -> Result<Promise, JsValue> {
+    NAVIGATOR.request_midi_access()
+}
+
+/// A MIDI message, as sent by keyboards and other controllers.
+///
+/// Channels are numbered from 0 to 15, and every other value is between 0 and
+/// 127.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MidiMessage {
+    /// A key was pressed.
+    NoteOn { channel: u8, note: u8, velocity: u8 },
+    /// A key was released.
+    NoteOff { channel: u8, note: u8, velocity: u8 },
+    /// A knob, slider or pedal was moved.
+    ControlChange { channel: u8, controller: u8, value: u8 },
+    /// Any other message, with its raw bytes.
+    Other(Vec<u8>),
+}
+
+impl MidiMessage {
+    /// Parses the bytes of a single MIDI message.
+    ///
+    /// A note on message with a velocity of 0 means the same as a note off
+    /// message, and is parsed as one.
+    pub fn parse(data: &[u8]) -> MidiMessage {
+        match *data {
+            [status, note, velocity] if status & 0xf0 == 0x90 && velocity > 0 => {
+                MidiMessage::NoteOn { channel: status & 0x0f, note, velocity }
+            }
+            [status, note, velocity] if status & 0xf0 == 0x80 || status & 0xf0 == 0x90 => {
+                MidiMessage::NoteOff { channel: status & 0x0f, note, velocity }
+            }
+            [status, controller, value] if status & 0xf0 == 0xb0 => {
+                MidiMessage::ControlChange { channel: status & 0x0f, controller, value }
+            }
+            _ => MidiMessage::Other(data.to_vec()),
+        }
+    }
+}
+
+impl MidiMessageEvent {
+    /// Parses the message this event carries.
+    pub fn message(&self) -> Result<MidiMessage, JsValue> {
+        Ok(MidiMessage::parse(&self.data()?))
+    }
+}
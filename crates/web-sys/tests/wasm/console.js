@@ -0,0 +1,33 @@
+// The bindings look up `console.log` and `console.error` once when they're
+// loaded, so these are wrapped as soon as this module is, and only record
+// what they're called with between `capture_console` and `assert_logged`.
+const calls = { log: null, error: null };
+
+for (const method of Object.keys(calls)) {
+  const original = console[method];
+  console[method] = function() {
+    if (calls[method] === null)
+      return original.apply(this, arguments);
+    calls[method].push(Array.from(arguments));
+  };
+}
+
+export function capture_console(method) {
+  calls[method] = [];
+}
+
+// Checks that `console[method]` was called once since `capture_console`, with
+// exactly the values in `expected`.
+export function assert_logged(method, expected) {
+  const captured = calls[method];
+  calls[method] = null;
+  if (captured.length !== 1)
+    throw new Error(`expected one call to console.${method}, got ${captured.length}`);
+  const args = captured[0];
+  if (args.length !== expected.length)
+    throw new Error(`expected ${expected.length} arguments, got ${args.length}`);
+  for (let i = 0; i < args.length; i++) {
+    if (args[i] !== expected[i])
+      throw new Error(`argument ${i} was ${args[i]}, expected ${expected[i]}`);
+  }
+}
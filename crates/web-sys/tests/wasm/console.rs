@@ -1,3 +1,5 @@
+use js_sys::{Array, Object};
+use wasm_bindgen::prelude::*;
 use wasm_bindgen_test::*;
 use web_sys::console;
 
@@ -6,3 +8,102 @@ fn test_console() {
     console::time_using_label("test label");
     console::time_end_using_label("test label");
 }
+
+#[wasm_bindgen_test]
+fn timer_guard() {
+    let timer = web_sys::ConsoleTimer::new("test timer");
+    timer.log();
+    drop(timer);
+
+    // The label can be reused once the first timer is dropped.
+    let _timer = web_sys::ConsoleTimer::new("test timer");
+}
+
+#[wasm_bindgen_test]
+fn group_guards() {
+    let _outer = web_sys::ConsoleGroup::new("outer");
+    let _inner = web_sys::ConsoleGroup::collapsed("inner");
+}
+
+#[wasm_bindgen(module = "./tests/wasm/console.js")]
+extern {
+    fn capture_console(method: &str);
+    fn assert_logged(method: &str, expected: &Array);
+}
+
+fn array(values: &[JsValue]) -> Array {
+    let array = Array::new();
+    for value in values {
+        array.push(value);
+    }
+    array
+}
+
+#[wasm_bindgen_test]
+fn log_formatted() {
+    capture_console("log");
+    web_sys::console_log!("{} + {} = {}", 1, 2, 1 + 2);
+    assert_logged("log", &array(&[JsValue::from("1 + 2 = 3")]));
+}
+
+#[wasm_bindgen_test]
+fn log_values() {
+    let object = Object::new();
+    capture_console("log");
+    web_sys::console_log!(values: &JsValue::from("object:"), &object);
+    assert_logged("log", &array(&[JsValue::from("object:"), object.into()]));
+}
+
+#[wasm_bindgen_test]
+fn log_no_values() {
+    capture_console("log");
+    web_sys::console_log!(values:);
+    assert_logged("log", &array(&[]));
+}
+
+#[wasm_bindgen_test]
+fn error_formatted() {
+    capture_console("error");
+    web_sys::console_error!("failed: {:?}", Some(1));
+    assert_logged("error", &array(&[JsValue::from("failed: Some(1)")]));
+}
+
+#[wasm_bindgen_test]
+fn error_many_values() {
+    let values: Vec<JsValue> = (0..6).map(JsValue::from).collect();
+    capture_console("error");
+    web_sys::console_error!(
+        values: values[0], values[1], values[2], values[3], values[4], values[5],
+    );
+    assert_logged("error", &array(&values));
+}
+
+#[wasm_bindgen_test]
+fn dbg_js_value() {
+    let object = Object::new();
+    let location = format!("[{}:{}] object =", file!(), line!() + 2);
+    capture_console("log");
+    let logged = web_sys::js_dbg!(object);
+    assert_logged("log", &array(&[JsValue::from(location), logged.into()]));
+}
+
+#[wasm_bindgen_test]
+fn dbg_debug() {
+    let location = format!("[{}:{}] Some(1 + 2) =", file!(), line!() + 2);
+    capture_console("log");
+    assert_eq!(web_sys::js_dbg!(Some(1 + 2)), Some(3));
+    assert_logged("log", &array(&[JsValue::from(location), JsValue::from("Some(3)")]));
+}
+
+#[wasm_bindgen_test]
+fn dbg_many() {
+    assert_eq!(web_sys::js_dbg!(1, "two"), (1, "two"));
+}
+
+#[wasm_bindgen_test]
+fn dbg_nothing() {
+    let location = format!("[{}:{}]", file!(), line!() + 2);
+    capture_console("log");
+    web_sys::js_dbg!();
+    assert_logged("log", &array(&[JsValue::from(location)]));
+}
@@ -22,6 +22,10 @@ export function new_del() {
     return document.createElement("del");
 }
 
+export function new_canvas() {
+  return document.createElement("canvas");
+}
+
 export function new_div() {
     return document.createElement("div");
 }
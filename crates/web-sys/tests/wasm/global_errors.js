@@ -0,0 +1,17 @@
+// Fires the events the browser would for an uncaught exception and for a
+// promise rejected without a handler, without actually failing the test.
+export function dispatch_error(message) {
+  dispatchEvent(new ErrorEvent('error', {
+    message,
+    filename: 'script.js',
+    lineno: 3,
+    colno: 7,
+    error: new Error(message),
+  }));
+}
+
+export function dispatch_rejection(reason) {
+  const promise = Promise.reject(reason);
+  promise.catch(() => {});
+  dispatchEvent(new PromiseRejectionEvent('unhandledrejection', { promise, reason }));
+}
@@ -0,0 +1,45 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use web_sys::GlobalError;
+
+#[wasm_bindgen(module = "./tests/wasm/global_errors.js")]
+extern {
+    fn dispatch_error(message: &str);
+    fn dispatch_rejection(reason: &JsValue);
+}
+
+#[wasm_bindgen_test]
+fn reports_uncaught_errors_and_rejections() {
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let seen = errors.clone();
+    let handler = web_sys::on_global_error(move |error| seen.borrow_mut().push(error));
+
+    dispatch_error("boom");
+    dispatch_rejection(&js_sys::Error::new("rejected").into());
+    dispatch_rejection(&JsValue::from(404));
+    drop(handler);
+    dispatch_error("not seen");
+
+    let errors = errors.borrow();
+    assert_eq!(errors.len(), 3);
+    match errors[0] {
+        GlobalError::Uncaught(ref error) => {
+            assert_eq!(error.message, "boom");
+            assert_eq!(error.filename, "script.js");
+            assert_eq!(error.lineno, 3);
+            assert_eq!(error.colno, 7);
+            assert!(error.error.is_object());
+        }
+        ref other => panic!("expected an uncaught error, got {:?}", other),
+    }
+    assert_eq!(errors[0].message(), "boom");
+    match errors[1] {
+        GlobalError::UnhandledRejection(_) => {}
+        ref other => panic!("expected a rejection, got {:?}", other),
+    }
+    assert_eq!(errors[1].message(), "rejected");
+    assert_eq!(errors[2].message(), "404");
+}
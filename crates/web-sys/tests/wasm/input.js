@@ -0,0 +1,7 @@
+export function new_keyboard_event(key, code) {
+  return new KeyboardEvent("keydown", { key, code });
+}
+
+export function new_mouse_event(button) {
+  return new MouseEvent("mousedown", { button });
+}
@@ -0,0 +1,32 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use web_sys::{Code, Key, KeyboardEvent, MouseButton, MouseEvent};
+
+#[wasm_bindgen(module = "./tests/wasm/input.js")]
+extern {
+    fn new_keyboard_event(key: &str, code: &str) -> KeyboardEvent;
+    fn new_mouse_event(button: i16) -> MouseEvent;
+}
+
+#[wasm_bindgen_test]
+fn keys() {
+    assert_eq!(Key::from_value("ArrowUp"), Some(Key::ArrowUp));
+    assert_eq!(Key::from_value("a"), None);
+    assert_eq!(Key::Escape.value(), "Escape");
+
+    let event = new_keyboard_event("Enter", "NumpadEnter");
+    assert_eq!(event.typed_key(), Ok(Key::Enter));
+    assert_eq!(event.typed_code(), Ok(Code::NumpadEnter));
+
+    // Characters aren't named keys, but the raw value is still there.
+    let event = new_keyboard_event("q", "KeyA");
+    assert_eq!(event.typed_key(), Err(String::from("q")));
+    assert_eq!(event.typed_code(), Ok(Code::KeyA));
+}
+
+#[wasm_bindgen_test]
+fn mouse_buttons() {
+    assert_eq!(new_mouse_event(0).typed_button(), Ok(MouseButton::Main));
+    assert_eq!(new_mouse_event(2).typed_button(), Ok(MouseButton::Secondary));
+    assert_eq!(new_mouse_event(7).typed_button(), Err(7));
+}
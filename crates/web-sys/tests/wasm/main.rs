@@ -18,6 +18,7 @@ pub mod console;
 pub mod div_element;
 pub mod element;
 pub mod event;
+pub mod global_errors;
 pub mod head_element;
 pub mod heading_element;
 pub mod headers;
@@ -25,6 +26,7 @@ pub mod history;
 pub mod hr_element;
 pub mod html_element;
 pub mod html_html_element;
+pub mod input;
 pub mod input_element;
 //TODO: Both menu-related tests completely break in Chrome, but run fine in Firefox.
 //pub mod menu_element;
@@ -32,6 +34,8 @@ pub mod input_element;
 pub mod meta_element;
 pub mod meter_element;
 pub mod mod_elements;
+#[cfg(feature = "async")]
+pub mod notification;
 pub mod olist_element;
 pub mod optgroup_element;
 pub mod option_element;
@@ -43,9 +47,13 @@ pub mod pre_element;
 pub mod progress_element;
 pub mod quote_element;
 pub mod response;
+#[cfg(feature = "async")]
+pub mod service_worker;
 pub mod select_element;
 pub mod script_element;
 pub mod slot_element;
+#[cfg(all(feature = "unstable-apis", feature = "async"))]
+pub mod streams;
 pub mod span_element;
 pub mod style_element;
 pub mod table_element;
@@ -53,3 +61,6 @@ pub mod title_element;
 pub mod xpath_result;
 pub mod dom_point;
 pub mod performance;
+pub mod webaudio;
+pub mod webgl;
+pub mod webmidi;
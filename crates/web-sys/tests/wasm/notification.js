@@ -0,0 +1,21 @@
+// Replaces `Notification.requestPermission` with one that answers `result`
+// straight away, either through the callback like older browsers or through
+// the returned promise.
+export function fake_request_permission(legacy, result) {
+  if (typeof Notification === "undefined") {
+    window.Notification = function() {};
+  }
+  Notification.requestPermission = function(callback) {
+    if (legacy) {
+      callback(result);
+      return undefined;
+    }
+    return Promise.resolve(result);
+  };
+}
+
+export function fake_failing_request_permission() {
+  Notification.requestPermission = function() {
+    throw new Error("not allowed here");
+  };
+}
@@ -0,0 +1,43 @@
+use futures::Future;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use web_sys::{Notification, PermissionState};
+
+#[wasm_bindgen(module = "./tests/wasm/notification.js")]
+extern {
+    fn fake_request_permission(legacy: bool, result: &str);
+    fn fake_failing_request_permission();
+}
+
+#[wasm_bindgen_test(async)]
+fn request_permission_with_promise() -> impl Future<Item = (), Error = JsValue> {
+    fake_request_permission(false, "granted");
+    Notification::request_permission().map(|state| {
+        assert_eq!(state, PermissionState::Granted);
+    })
+}
+
+#[wasm_bindgen_test(async)]
+fn request_permission_with_callback() -> impl Future<Item = (), Error = JsValue> {
+    fake_request_permission(true, "denied");
+    Notification::request_permission().map(|state| {
+        assert_eq!(state, PermissionState::Denied);
+    })
+}
+
+#[wasm_bindgen_test(async)]
+fn request_permission_default_is_prompt() -> impl Future<Item = (), Error = JsValue> {
+    fake_request_permission(false, "default");
+    Notification::request_permission().map(|state| {
+        assert_eq!(state, PermissionState::Prompt);
+    })
+}
+
+#[wasm_bindgen_test(async)]
+fn request_permission_error() -> impl Future<Item = (), Error = JsValue> {
+    fake_failing_request_permission();
+    Notification::request_permission().then(|result| {
+        assert!(result.is_err());
+        Ok(())
+    })
+}
@@ -0,0 +1,7 @@
+export function new_text_response(text) {
+  return new Response(text);
+}
+
+export function service_worker_container() {
+  return navigator.serviceWorker;
+}
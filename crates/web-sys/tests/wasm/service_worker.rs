@@ -0,0 +1,60 @@
+use futures::Future;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use web_sys::{open_cache, Response, ServiceWorkerContainer};
+
+#[wasm_bindgen(module = "./tests/wasm/service_worker.js")]
+extern {
+    fn new_text_response(text: &str) -> Response;
+    fn service_worker_container() -> ServiceWorkerContainer;
+}
+
+#[wasm_bindgen_test(async)]
+fn cache_put_and_get() -> impl Future<Item = (), Error = JsValue> {
+    open_cache("web-sys-test")
+        .and_then(|cache| {
+            let response = new_text_response("cached");
+            cache.put("/service-worker-test", &response).and_then(move |()| {
+                cache.get("/service-worker-test")
+            })
+        })
+        .and_then(|response| {
+            response.expect("the response should have been stored").text()
+        })
+        .and_then(JsFuture::from)
+        .map(|text| {
+            assert_eq!(text.as_string().as_ref().map(|s| &s[..]), Some("cached"));
+        })
+}
+
+#[wasm_bindgen_test(async)]
+fn cache_get_missing() -> impl Future<Item = (), Error = JsValue> {
+    open_cache("web-sys-test")
+        .and_then(|cache| cache.get("/never-stored"))
+        .map(|response| {
+            assert!(response.is_none());
+        })
+}
+
+#[wasm_bindgen_test(async)]
+fn cache_invalid_url() -> impl Future<Item = (), Error = JsValue> {
+    open_cache("web-sys-test")
+        .and_then(|cache| cache.get("http://[not a url"))
+        .then(|result| {
+            let error = result.err().expect("looking up an invalid URL should fail");
+            assert!(error.is_instance_of::<js_sys::TypeError>());
+            Ok(())
+        })
+}
+
+#[wasm_bindgen_test(async)]
+fn register_missing_script() -> impl Future<Item = (), Error = JsValue> {
+    service_worker_container()
+        .register("/no-such-service-worker.js", None)
+        .then(|result| {
+            assert!(result.is_err());
+            Ok(())
+        })
+}
@@ -0,0 +1,31 @@
+export function new_readable_stream(chunks) {
+  return new ReadableStream({
+    start(controller) {
+      for (const chunk of chunks) {
+        controller.enqueue(chunk);
+      }
+      controller.close();
+    }
+  });
+}
+
+export function locked_readable_stream() {
+  const stream = new ReadableStream();
+  stream.getReader();
+  return stream;
+}
+
+const WRITTEN = [];
+
+export function new_recording_writable_stream() {
+  WRITTEN.length = 0;
+  return new WritableStream({
+    write(chunk) {
+      WRITTEN.push(chunk);
+    }
+  });
+}
+
+export function written_chunks() {
+  return WRITTEN.slice();
+}
@@ -0,0 +1,48 @@
+use futures::{Future, Sink, Stream};
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use web_sys::{ReadableStream, WritableStream};
+
+#[wasm_bindgen(module = "./tests/wasm/streams.js")]
+extern {
+    fn new_readable_stream(chunks: &Array) -> ReadableStream;
+    fn locked_readable_stream() -> ReadableStream;
+    fn new_recording_writable_stream() -> WritableStream;
+    fn written_chunks() -> Array;
+}
+
+#[wasm_bindgen_test(async)]
+fn read_chunks() -> impl Future<Item = (), Error = JsValue> {
+    let chunks = Array::new();
+    chunks.push(&JsValue::from(1));
+    chunks.push(&JsValue::from(2));
+    chunks.push(&JsValue::from(3));
+    new_readable_stream(&chunks)
+        .into_stream()
+        .unwrap()
+        .collect()
+        .map(|chunks| {
+            assert_eq!(chunks, vec![JsValue::from(1), JsValue::from(2), JsValue::from(3)]);
+        })
+}
+
+#[wasm_bindgen_test]
+fn read_locked_stream() {
+    assert!(locked_readable_stream().into_stream().is_err());
+}
+
+#[wasm_bindgen_test(async)]
+fn write_chunks() -> impl Future<Item = (), Error = JsValue> {
+    new_recording_writable_stream()
+        .into_sink()
+        .unwrap()
+        .send(JsValue::from("a"))
+        .and_then(|sink| sink.send(JsValue::from("b")))
+        .map(|_sink| {
+            let written = written_chunks();
+            assert_eq!(written.length(), 2);
+            assert_eq!(written.get(0), "a");
+            assert_eq!(written.get(1), "b");
+        })
+}
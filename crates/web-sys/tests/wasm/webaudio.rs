@@ -0,0 +1,74 @@
+use wasm_bindgen_test::*;
+use web_sys::{AudioBuffer, AudioBufferOptions, BaseAudioContext, OfflineAudioContext, PeriodicWave};
+
+// WebIDL constructors can all throw, so they return a `Result`, like
+// `new AudioBuffer` does for an empty buffer.
+#[wasm_bindgen_test]
+fn constructors_return_errors() {
+    let buffer = AudioBuffer::new(&AudioBufferOptions::new(4, 8000.0)).unwrap();
+    assert_eq!(buffer.length(), 4);
+    assert!(AudioBuffer::new(&AudioBufferOptions::new(0, 8000.0)).is_err());
+}
+
+fn context() -> BaseAudioContext {
+    OfflineAudioContext::with_number_of_channels_and_length_and_sample_rate(1, 8000, 8000.0)
+        .unwrap()
+        .into()
+}
+
+#[wasm_bindgen_test]
+fn audio_buffer_copy_to_channel() {
+    let mut options = AudioBufferOptions::new(4, 8000.0);
+    options.number_of_channels(2);
+    let buffer = AudioBuffer::new(&options).unwrap();
+
+    buffer.copy_to_channel(&[1.0, 2.0, 3.0, 4.0], 1).unwrap();
+    assert_eq!(buffer.get_channel_data(0).unwrap(), [0.0; 4]);
+    assert_eq!(buffer.get_channel_data(1).unwrap(), [1.0, 2.0, 3.0, 4.0]);
+
+    assert!(buffer.copy_to_channel(&[1.0], 2).is_err());
+}
+
+#[wasm_bindgen_test]
+fn audio_buffer_copy_to_channel_at() {
+    let buffer = AudioBuffer::new(&AudioBufferOptions::new(4, 8000.0)).unwrap();
+
+    buffer.copy_to_channel_at(&[5.0, 6.0], 0, 2).unwrap();
+    assert_eq!(buffer.get_channel_data(0).unwrap(), [0.0, 0.0, 5.0, 6.0]);
+
+    // Anything which doesn't fit in the buffer is left out.
+    buffer.copy_to_channel_at(&[7.0, 8.0], 0, 3).unwrap();
+    assert_eq!(buffer.get_channel_data(0).unwrap(), [0.0, 0.0, 5.0, 7.0]);
+
+    assert!(buffer.copy_to_channel_at(&[1.0], 1, 0).is_err());
+}
+
+#[wasm_bindgen_test]
+fn audio_param_set_value_curve_at_sample_rate() {
+    let gain = context().create_gain().unwrap().gain();
+
+    // Four values at 8000 samples a second make for a curve lasting half a
+    // millisecond, and nothing else can be scheduled while the curve's running.
+    gain.set_value_curve_at_sample_rate(&[0.25, 0.5, 0.75, 1.0], 0.0, 8000.0)
+        .unwrap();
+    assert!(gain.set_value_at_time(0.0, 0.000375).is_err());
+    gain.set_value_at_time(0.0, 0.000625).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn base_audio_context_create_periodic_wave() {
+    let context = context();
+    context.create_periodic_wave(&[0.0, 1.0], &[0.0, 0.0]).unwrap();
+    assert!(context.create_periodic_wave(&[0.0, 1.0], &[0.0]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn periodic_wave_new_with_coefficients() {
+    let context = context();
+    PeriodicWave::new_with_coefficients(&context, &[0.0, 1.0], &[0.0, 0.0], true).unwrap();
+    PeriodicWave::new_with_coefficients(&context, &[0.0, 1.0], &[0.0, 0.0], false).unwrap();
+
+    // The coefficients have to have made it into the options for their
+    // lengths to be checked.
+    assert!(PeriodicWave::new_with_coefficients(&context, &[0.0, 1.0], &[0.0], true).is_err());
+}
@@ -0,0 +1,63 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
+
+#[wasm_bindgen(module = "./tests/wasm/element.js")]
+extern {
+    fn new_canvas() -> HtmlCanvasElement;
+}
+
+const VERTEX_SHADER: &str = r#"#version 300 es
+in vec4 position;
+void main() {
+    gl_Position = position;
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 300 es
+precision mediump float;
+out vec4 color;
+void main() {
+    color = vec4(1.0, 1.0, 1.0, 1.0);
+}
+"#;
+
+// Headless browsers aren't guaranteed to have WebGL 2, in which case there's
+// nothing else to test.
+fn context() -> Option<WebGl2RenderingContext> {
+    new_canvas().get_context_webgl2().ok()
+}
+
+#[wasm_bindgen_test]
+fn compile_and_link() {
+    let gl = match context() {
+        Some(gl) => gl,
+        None => return,
+    };
+    let vertex = gl
+        .compile_shader_from_source(WebGl2RenderingContext::VERTEX_SHADER, VERTEX_SHADER)
+        .unwrap();
+    let fragment = gl
+        .compile_shader_from_source(WebGl2RenderingContext::FRAGMENT_SHADER, FRAGMENT_SHADER)
+        .unwrap();
+    gl.link_program_from_shaders(&[&vertex, &fragment]).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn compile_error_is_info_log() {
+    let gl = match context() {
+        Some(gl) => gl,
+        None => return,
+    };
+    let err = gl
+        .compile_shader_from_source(WebGl2RenderingContext::VERTEX_SHADER, "not glsl")
+        .unwrap_err();
+    assert!(!err.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn wrong_context_is_an_error() {
+    let canvas = new_canvas();
+    canvas.get_context_using_context_id("2d").unwrap();
+    assert!(canvas.get_context_webgl2().is_err());
+}
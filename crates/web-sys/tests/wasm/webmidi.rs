@@ -0,0 +1,34 @@
+use wasm_bindgen_test::*;
+use web_sys::MidiMessage;
+
+#[wasm_bindgen_test]
+fn parses_notes() {
+    assert_eq!(
+        MidiMessage::parse(&[0x90, 60, 100]),
+        MidiMessage::NoteOn { channel: 0, note: 60, velocity: 100 },
+    );
+    assert_eq!(
+        MidiMessage::parse(&[0x83, 60, 64]),
+        MidiMessage::NoteOff { channel: 3, note: 60, velocity: 64 },
+    );
+    // A note on with no velocity is how a lot of keyboards send note offs.
+    assert_eq!(
+        MidiMessage::parse(&[0x9f, 61, 0]),
+        MidiMessage::NoteOff { channel: 15, note: 61, velocity: 0 },
+    );
+}
+
+#[wasm_bindgen_test]
+fn parses_control_changes() {
+    assert_eq!(
+        MidiMessage::parse(&[0xb1, 1, 127]),
+        MidiMessage::ControlChange { channel: 1, controller: 1, value: 127 },
+    );
+}
+
+#[wasm_bindgen_test]
+fn keeps_other_messages() {
+    assert_eq!(MidiMessage::parse(&[0xe0, 0, 64]), MidiMessage::Other(vec![0xe0, 0, 64]));
+    assert_eq!(MidiMessage::parse(&[0xf8]), MidiMessage::Other(vec![0xf8]));
+    assert_eq!(MidiMessage::parse(&[]), MidiMessage::Other(vec![]));
+}
@@ -1,3 +1,4 @@
+extern crate wasm_bindgen_shared;
 extern crate wasm_bindgen_webidl;
 extern crate env_logger;
 
@@ -6,8 +7,15 @@ use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 
+mod snapshot;
+
 fn main() {
     env_logger::init();
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=snapshot.rs");
+    println!("cargo:rerun-if-changed=snapshots");
+    println!("cargo:rerun-if-env-changed=WEBIDL_SNAPSHOTS");
+
     let idls = fs::read_dir(".")
         .unwrap()
         .map(|f| f.unwrap().path())
@@ -17,7 +25,10 @@ fn main() {
     let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
     for (i, (idl, path)) in idls.enumerate() {
         println!("processing {:?}", path);
+        println!("cargo:rerun-if-changed={}", path.display());
+        println!("cargo:rerun-if-changed={}", path.with_extension("js").display());
         let mut generated_rust = wasm_bindgen_webidl::compile(&idl).unwrap();
+        snapshot::check(&path, &generated_rust);
 
         let out_file = out_dir.join(path.file_name().unwrap())
             .with_extension("rs");
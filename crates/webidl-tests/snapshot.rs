@@ -0,0 +1,48 @@
+//! Golden files of the Rust generated for each `.webidl` input.
+//!
+//! The build script checks the (rustfmt'd) code generated for `foo.webidl`
+//! against `snapshots/foo.rs`, so that any change to the WebIDL frontend's
+//! output shows up as a diff of those files. Running the build with
+//! `WEBIDL_SNAPSHOTS=update` writes the snapshots instead of checking them.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Checks `generated`, the code generated for the WebIDL file at `path`,
+/// against its snapshot, or updates the snapshot.
+pub fn check(path: &Path, generated: &str) {
+    let snapshot = path
+        .parent()
+        .unwrap()
+        .join("snapshots")
+        .join(path.file_name().unwrap())
+        .with_extension("rs");
+
+    let generated = match wasm_bindgen_shared::snapshot::format(generated) {
+        Ok(formatted) => formatted,
+        Err(e) => panic!("failed to format the code generated for {}: {}", path.display(), e),
+    };
+
+    let actual = snapshot.with_extension("rs.actual");
+    drop(fs::remove_file(&actual));
+    fs::create_dir_all(snapshot.parent().unwrap()).unwrap();
+    if env::var("WEBIDL_SNAPSHOTS").ok().as_ref().map(|s| &s[..]) == Some("update") {
+        fs::write(&snapshot, generated).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&snapshot).unwrap_or_default();
+    if expected != generated {
+        fs::write(&actual, &generated).unwrap();
+        panic!(
+            "the code generated for {} doesn't match {}\n\n\
+             the generated code was written to {}, if the change is \
+             intended then rerun the build with WEBIDL_SNAPSHOTS=update to \
+             update the snapshot",
+            path.display(),
+            snapshot.display(),
+            actual.display(),
+        );
+    }
+}
@@ -0,0 +1,699 @@
+#[allow(bad_style)]
+#[doc = "The `TestArrays` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays)"]
+#[repr(transparent)]
+pub struct TestArrays {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `TestArrays` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_TestArrays: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for TestArrays {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for TestArrays {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for TestArrays {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a TestArrays {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for TestArrays {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            TestArrays {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for TestArrays {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a TestArrays {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for TestArrays {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<TestArrays>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(TestArrays {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for TestArrays {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for TestArrays {
+        fn from(obj: JsValue) -> TestArrays {
+            TestArrays { obj }
+        }
+    }
+    impl AsRef<JsValue> for TestArrays {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for TestArrays {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<TestArrays> for JsValue {
+        fn from(obj: TestArrays) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for TestArrays {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_TestArrays(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_TestArrays(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            TestArrays { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const TestArrays) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut TestArrays) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <TestArrays as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new TestArrays(..)` constructor, creating a new instance of `TestArrays`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/TestArrays)"]
+    pub fn new() -> Result<TestArrays, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_TestArrays(
+                exn_data_ptr: *mut u32,
+            ) -> <TestArrays as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_TestArrays(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<TestArrays>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new TestArrays(..)` constructor, creating a new instance of `TestArrays`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/TestArrays)"]
+    pub fn new() -> Result<TestArrays, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_strings_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_strings_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `strings()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/strings)"]
+    pub fn strings(&self, arg1: &str) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_strings_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                arg1: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let arg1 = ::wasm_bindgen::__rt::arg_into_abi::<&str>(arg1, &mut __stack);
+                __widl_f_strings_TestArrays(self_, arg1)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `strings()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/strings)"]
+    pub fn strings(&self, arg1: &str) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_byte_strings_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_byte_strings_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `byteStrings()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/byteStrings)"]
+    pub fn byte_strings(&self, arg1: &str) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_byte_strings_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                arg1: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let arg1 = ::wasm_bindgen::__rt::arg_into_abi::<&str>(arg1, &mut __stack);
+                __widl_f_byte_strings_TestArrays(self_, arg1)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `byteStrings()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/byteStrings)"]
+    pub fn byte_strings(&self, arg1: &str) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_usv_strings_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_usv_strings_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `usvStrings()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/usvStrings)"]
+    pub fn usv_strings(&self, arg1: &str) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_usv_strings_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                arg1: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let arg1 = ::wasm_bindgen::__rt::arg_into_abi::<&str>(arg1, &mut __stack);
+                __widl_f_usv_strings_TestArrays(self_, arg1)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `usvStrings()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/usvStrings)"]
+    pub fn usv_strings(&self, arg1: &str) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_f32_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_f32_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[f32] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<f32> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `f32()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/f32)"]
+    pub fn f32(&self, a: &[f32]) -> Vec<f32> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_f32_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[f32] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<f32> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[f32]>(a, &mut __stack);
+                __widl_f_f32_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<f32>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `f32()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/f32)"]
+    pub fn f32(&self, a: &[f32]) -> Vec<f32> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_f64_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_f64_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[f64] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<f64> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `f64()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/f64)"]
+    pub fn f64(&self, a: &[f64]) -> Vec<f64> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_f64_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[f64] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<f64> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[f64]>(a, &mut __stack);
+                __widl_f_f64_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<f64>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `f64()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/f64)"]
+    pub fn f64(&self, a: &[f64]) -> Vec<f64> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_i8_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_i8_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[i8] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<i8> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `i8()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/i8)"]
+    pub fn i8(&self, a: &[i8]) -> Vec<i8> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_i8_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[i8] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<i8> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[i8]>(a, &mut __stack);
+                __widl_f_i8_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<i8>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `i8()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/i8)"]
+    pub fn i8(&self, a: &[i8]) -> Vec<i8> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_i16_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_i16_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[i16] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<i16> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `i16()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/i16)"]
+    pub fn i16(&self, a: &[i16]) -> Vec<i16> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_i16_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[i16] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<i16> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[i16]>(a, &mut __stack);
+                __widl_f_i16_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<i16>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `i16()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/i16)"]
+    pub fn i16(&self, a: &[i16]) -> Vec<i16> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_i32_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_i32_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[i32] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<i32> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `i32()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/i32)"]
+    pub fn i32(&self, a: &[i32]) -> Vec<i32> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_i32_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[i32] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<i32> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[i32]>(a, &mut __stack);
+                __widl_f_i32_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<i32>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `i32()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/i32)"]
+    pub fn i32(&self, a: &[i32]) -> Vec<i32> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_u8_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_u8_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[u8] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<u8> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `u8()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/u8)"]
+    pub fn u8(&self, a: &[u8]) -> Vec<u8> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_u8_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[u8] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<u8> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[u8]>(a, &mut __stack);
+                __widl_f_u8_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<u8>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `u8()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/u8)"]
+    pub fn u8(&self, a: &[u8]) -> Vec<u8> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_u8_clamped_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_u8_clamped_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[u8] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<u8> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `u8Clamped()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/u8Clamped)"]
+    pub fn u8_clamped(&self, a: &[u8]) -> Vec<u8> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_u8_clamped_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[u8] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<u8> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[u8]>(a, &mut __stack);
+                __widl_f_u8_clamped_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<u8>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `u8Clamped()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/u8Clamped)"]
+    pub fn u8_clamped(&self, a: &[u8]) -> Vec<u8> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_u16_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_u16_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[u16] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<u16> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `u16()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/u16)"]
+    pub fn u16(&self, a: &[u16]) -> Vec<u16> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_u16_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[u16] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<u16> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[u16]>(a, &mut __stack);
+                __widl_f_u16_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<u16>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `u16()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/u16)"]
+    pub fn u16(&self, a: &[u16]) -> Vec<u16> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_u32_TestArrays` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_u32_TestArrays() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&TestArrays as WasmDescribe>::describe();
+    <&[u32] as WasmDescribe>::describe();
+    inform(1);
+    <Vec<u32> as WasmDescribe>::describe();
+}
+impl TestArrays {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `u32()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/u32)"]
+    pub fn u32(&self, a: &[u32]) -> Vec<u32> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_u32_TestArrays(
+                self_: <&TestArrays as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&[u32] as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Vec<u32> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&TestArrays>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&[u32]>(a, &mut __stack);
+                __widl_f_u32_TestArrays(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Vec<u32>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `u32()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/TestArrays/u32)"]
+    pub fn u32(&self, a: &[u32]) -> Vec<u32> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_1db099b72cd4c067 : [ u8 ; 5096usize ] = * b"\xE4\x13\0\0{\"exports\":[],\"enums\":[],\"imports\":[{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"TestArrays\",\"rust_name\":\"TestArrays\",\"instanceof_shim\":\"__widl_instanceof_TestArrays\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_TestArrays\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_strings_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"strings\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_byte_strings_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"byteStrings\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_usv_strings_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"usvStrings\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_f32_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"f32\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_f64_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"f64\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_i8_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"i8\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_i16_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"i16\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_i32_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"i32\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_u8_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"u8\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_u8_clamped_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"u8Clamped\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_u16_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"u16\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_u32_TestArrays\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"TestArrays\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"u32\"}}}],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
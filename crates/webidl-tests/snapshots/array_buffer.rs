@@ -0,0 +1,262 @@
+#[allow(bad_style)]
+#[doc = "The `ArrayBufferTest` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ArrayBufferTest)"]
+#[repr(transparent)]
+pub struct ArrayBufferTest {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ArrayBufferTest` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_ArrayBufferTest: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for ArrayBufferTest {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ArrayBufferTest {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for ArrayBufferTest {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ArrayBufferTest {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for ArrayBufferTest {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            ArrayBufferTest {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for ArrayBufferTest {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ArrayBufferTest {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for ArrayBufferTest {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ArrayBufferTest>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ArrayBufferTest {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ArrayBufferTest {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for ArrayBufferTest {
+        fn from(obj: JsValue) -> ArrayBufferTest {
+            ArrayBufferTest { obj }
+        }
+    }
+    impl AsRef<JsValue> for ArrayBufferTest {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for ArrayBufferTest {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<ArrayBufferTest> for JsValue {
+        fn from(obj: ArrayBufferTest) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for ArrayBufferTest {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_ArrayBufferTest(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_ArrayBufferTest(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ArrayBufferTest { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ArrayBufferTest) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ArrayBufferTest) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_ArrayBufferTest` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_ArrayBufferTest() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <ArrayBufferTest as WasmDescribe>::describe();
+}
+impl ArrayBufferTest {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new ArrayBufferTest(..)` constructor, creating a new instance of `ArrayBufferTest`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ArrayBufferTest/ArrayBufferTest)"]
+    pub fn new() -> Result<ArrayBufferTest, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_ArrayBufferTest(
+                exn_data_ptr: *mut u32,
+            ) -> <ArrayBufferTest as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_ArrayBufferTest(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<ArrayBufferTest>(
+                _ret,
+            ))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new ArrayBufferTest(..)` constructor, creating a new instance of `ArrayBufferTest`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ArrayBufferTest/ArrayBufferTest)"]
+    pub fn new() -> Result<ArrayBufferTest, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_get_buffer_ArrayBufferTest` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_get_buffer_ArrayBufferTest() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&ArrayBufferTest as WasmDescribe>::describe();
+    inform(1);
+    <::js_sys::ArrayBuffer as WasmDescribe>::describe();
+}
+impl ArrayBufferTest {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `getBuffer()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ArrayBufferTest/getBuffer)"]
+    pub fn get_buffer(&self) -> ::js_sys::ArrayBuffer {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_get_buffer_ArrayBufferTest(
+                self_: <&ArrayBufferTest as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <::js_sys::ArrayBuffer as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&ArrayBufferTest>(self, &mut __stack);
+                __widl_f_get_buffer_ArrayBufferTest(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<::js_sys::ArrayBuffer>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `getBuffer()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ArrayBufferTest/getBuffer)"]
+    pub fn get_buffer(&self) -> ::js_sys::ArrayBuffer {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_buffer_ArrayBufferTest` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_buffer_ArrayBufferTest() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&ArrayBufferTest as WasmDescribe>::describe();
+    <Option<::js_sys::ArrayBuffer> as WasmDescribe>::describe();
+    inform(0);
+}
+impl ArrayBufferTest {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `setBuffer()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ArrayBufferTest/setBuffer)"]
+    pub fn set_buffer(&self, b: Option<::js_sys::ArrayBuffer>) {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_buffer_ArrayBufferTest(
+                self_: <&ArrayBufferTest as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                b: <Option<::js_sys::ArrayBuffer> as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> ();
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&ArrayBufferTest>(self, &mut __stack);
+                let b = ::wasm_bindgen::__rt::arg_into_abi::<Option<::js_sys::ArrayBuffer>>(
+                    b,
+                    &mut __stack,
+                );
+                __widl_f_set_buffer_ArrayBufferTest(self_, b)
+            };
+            ()
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `setBuffer()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ArrayBufferTest/setBuffer)"]
+    pub fn set_buffer(&self, b: Option<::js_sys::ArrayBuffer>) {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_12907af1b73546e9 : [ u8 ; 1488usize ] = * b"\xCC\x05\0\0{\"exports\":[],\"enums\":[],\"imports\":[{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"ArrayBufferTest\",\"rust_name\":\"ArrayBufferTest\",\"instanceof_shim\":\"__widl_instanceof_ArrayBufferTest\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_ArrayBufferTest\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"ArrayBufferTest\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_get_buffer_ArrayBufferTest\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"ArrayBufferTest\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"getBuffer\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_buffer_ArrayBufferTest\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"ArrayBufferTest\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"setBuffer\"}}}],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
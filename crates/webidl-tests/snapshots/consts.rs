@@ -0,0 +1,954 @@
+#[allow(bad_style)]
+#[doc = "The `ConstBool` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ConstBool)"]
+#[repr(transparent)]
+pub struct ConstBool {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ConstBool` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_ConstBool: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for ConstBool {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ConstBool {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for ConstBool {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ConstBool {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for ConstBool {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            ConstBool {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for ConstBool {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ConstBool {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for ConstBool {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ConstBool>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ConstBool {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ConstBool {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for ConstBool {
+        fn from(obj: JsValue) -> ConstBool {
+            ConstBool { obj }
+        }
+    }
+    impl AsRef<JsValue> for ConstBool {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for ConstBool {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<ConstBool> for JsValue {
+        fn from(obj: ConstBool) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for ConstBool {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_ConstBool(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_ConstBool(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ConstBool { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ConstBool) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ConstBool) }
+        }
+    }
+    ()
+};
+#[allow(bad_style)]
+#[doc = "The `ConstByte` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ConstByte)"]
+#[repr(transparent)]
+pub struct ConstByte {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ConstByte` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_ConstByte: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for ConstByte {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ConstByte {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for ConstByte {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ConstByte {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for ConstByte {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            ConstByte {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for ConstByte {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ConstByte {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for ConstByte {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ConstByte>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ConstByte {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ConstByte {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for ConstByte {
+        fn from(obj: JsValue) -> ConstByte {
+            ConstByte { obj }
+        }
+    }
+    impl AsRef<JsValue> for ConstByte {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for ConstByte {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<ConstByte> for JsValue {
+        fn from(obj: ConstByte) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for ConstByte {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_ConstByte(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_ConstByte(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ConstByte { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ConstByte) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ConstByte) }
+        }
+    }
+    ()
+};
+#[allow(bad_style)]
+#[doc = "The `ConstShort` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ConstShort)"]
+#[repr(transparent)]
+pub struct ConstShort {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ConstShort` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_ConstShort: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for ConstShort {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ConstShort {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for ConstShort {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ConstShort {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for ConstShort {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            ConstShort {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for ConstShort {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ConstShort {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for ConstShort {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ConstShort>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ConstShort {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ConstShort {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for ConstShort {
+        fn from(obj: JsValue) -> ConstShort {
+            ConstShort { obj }
+        }
+    }
+    impl AsRef<JsValue> for ConstShort {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for ConstShort {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<ConstShort> for JsValue {
+        fn from(obj: ConstShort) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for ConstShort {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_ConstShort(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_ConstShort(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ConstShort { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ConstShort) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ConstShort) }
+        }
+    }
+    ()
+};
+#[allow(bad_style)]
+#[doc = "The `ConstLong` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ConstLong)"]
+#[repr(transparent)]
+pub struct ConstLong {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ConstLong` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_ConstLong: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for ConstLong {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ConstLong {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for ConstLong {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ConstLong {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for ConstLong {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            ConstLong {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for ConstLong {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ConstLong {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for ConstLong {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ConstLong>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ConstLong {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ConstLong {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for ConstLong {
+        fn from(obj: JsValue) -> ConstLong {
+            ConstLong { obj }
+        }
+    }
+    impl AsRef<JsValue> for ConstLong {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for ConstLong {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<ConstLong> for JsValue {
+        fn from(obj: ConstLong) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for ConstLong {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_ConstLong(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_ConstLong(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ConstLong { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ConstLong) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ConstLong) }
+        }
+    }
+    ()
+};
+#[allow(bad_style)]
+#[doc = "The `ConstLongLong` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ConstLongLong)"]
+#[repr(transparent)]
+pub struct ConstLongLong {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ConstLongLong` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_ConstLongLong: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for ConstLongLong {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ConstLongLong {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for ConstLongLong {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ConstLongLong {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for ConstLongLong {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            ConstLongLong {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for ConstLongLong {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ConstLongLong {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for ConstLongLong {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ConstLongLong>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ConstLongLong {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ConstLongLong {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for ConstLongLong {
+        fn from(obj: JsValue) -> ConstLongLong {
+            ConstLongLong { obj }
+        }
+    }
+    impl AsRef<JsValue> for ConstLongLong {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for ConstLongLong {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<ConstLongLong> for JsValue {
+        fn from(obj: ConstLongLong) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for ConstLongLong {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_ConstLongLong(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_ConstLongLong(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ConstLongLong { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ConstLongLong) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ConstLongLong) }
+        }
+    }
+    ()
+};
+#[allow(bad_style)]
+#[doc = "The `ConstFloats` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ConstFloats)"]
+#[repr(transparent)]
+pub struct ConstFloats {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ConstFloats` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_ConstFloats: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for ConstFloats {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ConstFloats {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for ConstFloats {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ConstFloats {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for ConstFloats {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            ConstFloats {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for ConstFloats {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ConstFloats {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for ConstFloats {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ConstFloats>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ConstFloats {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ConstFloats {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for ConstFloats {
+        fn from(obj: JsValue) -> ConstFloats {
+            ConstFloats { obj }
+        }
+    }
+    impl AsRef<JsValue> for ConstFloats {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for ConstFloats {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<ConstFloats> for JsValue {
+        fn from(obj: ConstFloats) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for ConstFloats {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_ConstFloats(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_ConstFloats(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ConstFloats { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ConstFloats) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ConstFloats) }
+        }
+    }
+    ()
+};
+#[allow(bad_style)]
+#[doc = "The `ConstDoubles` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ConstDoubles)"]
+#[repr(transparent)]
+pub struct ConstDoubles {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ConstDoubles` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_ConstDoubles: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for ConstDoubles {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ConstDoubles {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for ConstDoubles {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ConstDoubles {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for ConstDoubles {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            ConstDoubles {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for ConstDoubles {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ConstDoubles {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for ConstDoubles {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ConstDoubles>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ConstDoubles {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ConstDoubles {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for ConstDoubles {
+        fn from(obj: JsValue) -> ConstDoubles {
+            ConstDoubles { obj }
+        }
+    }
+    impl AsRef<JsValue> for ConstDoubles {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for ConstDoubles {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<ConstDoubles> for JsValue {
+        fn from(obj: ConstDoubles) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for ConstDoubles {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_ConstDoubles(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_ConstDoubles(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ConstDoubles { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ConstDoubles) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ConstDoubles) }
+        }
+    }
+    ()
+};
+impl ConstBool {
+    pub const NOT_TRUE: bool = false;
+}
+impl ConstBool {
+    pub const NOT_FALSE: bool = true;
+}
+impl ConstByte {
+    pub const IMIN: i8 = -128;
+}
+impl ConstByte {
+    pub const IMAX: i8 = 127;
+}
+impl ConstByte {
+    pub const UMIN: u8 = 0;
+}
+impl ConstByte {
+    pub const UMAX: u8 = 255;
+}
+impl ConstShort {
+    pub const IMIN: i16 = -32768;
+}
+impl ConstShort {
+    pub const IMAX: i16 = 32767;
+}
+impl ConstShort {
+    pub const UMIN: u16 = 0;
+}
+impl ConstShort {
+    pub const UMAX: u16 = 65535;
+}
+impl ConstLong {
+    pub const IMIN: i32 = -2147483648;
+}
+impl ConstLong {
+    pub const IMAX: i32 = 2147483647;
+}
+impl ConstLong {
+    pub const UMIN: u32 = 0;
+}
+impl ConstLong {
+    pub const UMAX: u32 = 4294967295;
+}
+impl ConstLongLong {
+    pub const IMIN: i64 = -9223372036854775808;
+}
+impl ConstLongLong {
+    pub const IMAX: i64 = 9223372036854775807;
+}
+impl ConstLongLong {
+    pub const UMIN: u64 = 0;
+}
+impl ConstLongLong {
+    pub const UMAX: u64 = 18446744073709551615;
+}
+impl ConstFloats {
+    pub const F: f32 = 0.0;
+}
+impl ConstFloats {
+    pub const NEG_INF: f32 = -1.0 / 0.0;
+}
+impl ConstFloats {
+    pub const INF: f32 = 1.0 / 0.0;
+}
+impl ConstFloats {
+    pub const NAN: f32 = 0.0 / 0.0;
+}
+impl ConstDoubles {
+    pub const D: f64 = 0.0;
+}
+impl ConstDoubles {
+    pub const NEG_INF: f64 = -1.0 / 0.0;
+}
+impl ConstDoubles {
+    pub const INF: f64 = 1.0 / 0.0;
+}
+impl ConstDoubles {
+    pub const NAN: f64 = 0.0 / 0.0;
+}
+impl ConstDoubles {
+    pub const ONE: f64 = 1.0;
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_1e89f48ec4515516 : [ u8 ; 1749usize ] = * b"\xD1\x06\0\0{\"exports\":[],\"enums\":[],\"imports\":[{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"ConstBool\",\"rust_name\":\"ConstBool\",\"instanceof_shim\":\"__widl_instanceof_ConstBool\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"ConstByte\",\"rust_name\":\"ConstByte\",\"instanceof_shim\":\"__widl_instanceof_ConstByte\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"ConstShort\",\"rust_name\":\"ConstShort\",\"instanceof_shim\":\"__widl_instanceof_ConstShort\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"ConstLong\",\"rust_name\":\"ConstLong\",\"instanceof_shim\":\"__widl_instanceof_ConstLong\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"ConstLongLong\",\"rust_name\":\"ConstLongLong\",\"instanceof_shim\":\"__widl_instanceof_ConstLongLong\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"ConstFloats\",\"rust_name\":\"ConstFloats\",\"instanceof_shim\":\"__widl_instanceof_ConstFloats\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"ConstDoubles\",\"rust_name\":\"ConstDoubles\",\"instanceof_shim\":\"__widl_instanceof_ConstDoubles\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}}],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
@@ -0,0 +1,1105 @@
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc = "The `B` dictionary"]
+pub struct B {
+    obj: ::js_sys::Object,
+}
+impl B {
+    pub fn new() -> B {
+        let mut _ret = B {
+            obj: ::js_sys::Object::new(),
+        };
+        return _ret;
+    }
+    #[doc = "Change the `c` field of this object"]
+    pub fn c(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(c)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `d` field of this object"]
+    pub fn d(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(d)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `g` field of this object"]
+    pub fn g(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(g)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `h` field of this object"]
+    pub fn h(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(h)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `a` field of this object"]
+    pub fn a(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(a)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `b` field of this object"]
+    pub fn b(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(b)),
+            &JsValue::from(val),
+        );
+        self
+    }
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `B` and the wasm ABI, which are all the same as `Object`'s."]
+const __wbg_generated_const_B: () = {
+    use js_sys::Object;
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::*;
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl From<B> for JsValue {
+        fn from(val: B) -> JsValue {
+            val.obj.into()
+        }
+    }
+    impl AsRef<JsValue> for B {
+        fn as_ref(&self) -> &JsValue {
+            self.obj.as_ref()
+        }
+    }
+    impl AsMut<JsValue> for B {
+        fn as_mut(&mut self) -> &mut JsValue {
+            self.obj.as_mut()
+        }
+    }
+    impl WasmDescribe for B {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<Object as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <Object as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for B {
+        type Abi = <Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a B {
+        type Abi = <&'a Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl FromWasmAbi for B {
+        type Abi = <Object as FromWasmAbi>::Abi;
+        unsafe fn from_abi(abi: Self::Abi, extra: &mut Stack) -> Self {
+            B {
+                obj: Object::from_abi(abi, extra),
+            }
+        }
+    }
+    impl OptionIntoWasmAbi for B {
+        fn none() -> Self::Abi {
+            Object::none()
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a B {
+        fn none() -> Self::Abi {
+            <&'a Object>::none()
+        }
+    }
+    impl OptionFromWasmAbi for B {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl RefFromWasmAbi for B {
+        type Abi = <Object as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<B>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <Object as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(B {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for B {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionRefFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl JsCast for B {
+        fn instanceof(val: &JsValue) -> bool {
+            Object::instanceof(val)
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            B {
+                obj: Object::unchecked_from_js(val),
+            }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const B) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut B) }
+        }
+    }
+};
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc = "The `A` dictionary"]
+pub struct A {
+    obj: ::js_sys::Object,
+}
+impl A {
+    pub fn new() -> A {
+        let mut _ret = A {
+            obj: ::js_sys::Object::new(),
+        };
+        return _ret;
+    }
+    #[doc = "Change the `c` field of this object"]
+    pub fn c(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(c)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `d` field of this object"]
+    pub fn d(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(d)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `g` field of this object"]
+    pub fn g(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(g)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `h` field of this object"]
+    pub fn h(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(h)),
+            &JsValue::from(val),
+        );
+        self
+    }
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `A` and the wasm ABI, which are all the same as `Object`'s."]
+const __wbg_generated_const_A: () = {
+    use js_sys::Object;
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::*;
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl From<A> for JsValue {
+        fn from(val: A) -> JsValue {
+            val.obj.into()
+        }
+    }
+    impl AsRef<JsValue> for A {
+        fn as_ref(&self) -> &JsValue {
+            self.obj.as_ref()
+        }
+    }
+    impl AsMut<JsValue> for A {
+        fn as_mut(&mut self) -> &mut JsValue {
+            self.obj.as_mut()
+        }
+    }
+    impl WasmDescribe for A {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<Object as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <Object as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for A {
+        type Abi = <Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a A {
+        type Abi = <&'a Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl FromWasmAbi for A {
+        type Abi = <Object as FromWasmAbi>::Abi;
+        unsafe fn from_abi(abi: Self::Abi, extra: &mut Stack) -> Self {
+            A {
+                obj: Object::from_abi(abi, extra),
+            }
+        }
+    }
+    impl OptionIntoWasmAbi for A {
+        fn none() -> Self::Abi {
+            Object::none()
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a A {
+        fn none() -> Self::Abi {
+            <&'a Object>::none()
+        }
+    }
+    impl OptionFromWasmAbi for A {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl RefFromWasmAbi for A {
+        type Abi = <Object as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<A>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <Object as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(A {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for A {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionRefFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl JsCast for A {
+        fn instanceof(val: &JsValue) -> bool {
+            Object::instanceof(val)
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            A {
+                obj: Object::unchecked_from_js(val),
+            }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const A) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut A) }
+        }
+    }
+};
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc = "The `C` dictionary"]
+pub struct C {
+    obj: ::js_sys::Object,
+}
+impl C {
+    pub fn new() -> C {
+        let mut _ret = C {
+            obj: ::js_sys::Object::new(),
+        };
+        return _ret;
+    }
+    #[doc = "Change the `c` field of this object"]
+    pub fn c(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(c)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `d` field of this object"]
+    pub fn d(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(d)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `g` field of this object"]
+    pub fn g(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(g)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `h` field of this object"]
+    pub fn h(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(h)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `a` field of this object"]
+    pub fn a(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(a)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `b` field of this object"]
+    pub fn b(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(b)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `e` field of this object"]
+    pub fn e(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(e)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `f` field of this object"]
+    pub fn f(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(f)),
+            &JsValue::from(val),
+        );
+        self
+    }
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `C` and the wasm ABI, which are all the same as `Object`'s."]
+const __wbg_generated_const_C: () = {
+    use js_sys::Object;
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::*;
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl From<C> for JsValue {
+        fn from(val: C) -> JsValue {
+            val.obj.into()
+        }
+    }
+    impl AsRef<JsValue> for C {
+        fn as_ref(&self) -> &JsValue {
+            self.obj.as_ref()
+        }
+    }
+    impl AsMut<JsValue> for C {
+        fn as_mut(&mut self) -> &mut JsValue {
+            self.obj.as_mut()
+        }
+    }
+    impl WasmDescribe for C {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<Object as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <Object as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for C {
+        type Abi = <Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a C {
+        type Abi = <&'a Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl FromWasmAbi for C {
+        type Abi = <Object as FromWasmAbi>::Abi;
+        unsafe fn from_abi(abi: Self::Abi, extra: &mut Stack) -> Self {
+            C {
+                obj: Object::from_abi(abi, extra),
+            }
+        }
+    }
+    impl OptionIntoWasmAbi for C {
+        fn none() -> Self::Abi {
+            Object::none()
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a C {
+        fn none() -> Self::Abi {
+            <&'a Object>::none()
+        }
+    }
+    impl OptionFromWasmAbi for C {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl RefFromWasmAbi for C {
+        type Abi = <Object as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<C>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <Object as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(C {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for C {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionRefFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl JsCast for C {
+        fn instanceof(val: &JsValue) -> bool {
+            Object::instanceof(val)
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            C {
+                obj: Object::unchecked_from_js(val),
+            }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const C) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut C) }
+        }
+    }
+};
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc = "The `camel_case_me` dictionary"]
+pub struct CamelCaseMe {
+    obj: ::js_sys::Object,
+}
+impl CamelCaseMe {
+    pub fn new() -> CamelCaseMe {
+        let mut _ret = CamelCaseMe {
+            obj: ::js_sys::Object::new(),
+        };
+        return _ret;
+    }
+    #[doc = "Change the `snakeCaseMe` field of this object"]
+    pub fn snake_case_me(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(snake_case_me)),
+            &JsValue::from(val),
+        );
+        self
+    }
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `CamelCaseMe` and the wasm ABI, which are all the same as `Object`'s."]
+const __wbg_generated_const_CamelCaseMe: () = {
+    use js_sys::Object;
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::*;
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl From<CamelCaseMe> for JsValue {
+        fn from(val: CamelCaseMe) -> JsValue {
+            val.obj.into()
+        }
+    }
+    impl AsRef<JsValue> for CamelCaseMe {
+        fn as_ref(&self) -> &JsValue {
+            self.obj.as_ref()
+        }
+    }
+    impl AsMut<JsValue> for CamelCaseMe {
+        fn as_mut(&mut self) -> &mut JsValue {
+            self.obj.as_mut()
+        }
+    }
+    impl WasmDescribe for CamelCaseMe {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<Object as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <Object as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for CamelCaseMe {
+        type Abi = <Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a CamelCaseMe {
+        type Abi = <&'a Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl FromWasmAbi for CamelCaseMe {
+        type Abi = <Object as FromWasmAbi>::Abi;
+        unsafe fn from_abi(abi: Self::Abi, extra: &mut Stack) -> Self {
+            CamelCaseMe {
+                obj: Object::from_abi(abi, extra),
+            }
+        }
+    }
+    impl OptionIntoWasmAbi for CamelCaseMe {
+        fn none() -> Self::Abi {
+            Object::none()
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a CamelCaseMe {
+        fn none() -> Self::Abi {
+            <&'a Object>::none()
+        }
+    }
+    impl OptionFromWasmAbi for CamelCaseMe {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl RefFromWasmAbi for CamelCaseMe {
+        type Abi = <Object as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<CamelCaseMe>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <Object as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(CamelCaseMe {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for CamelCaseMe {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionRefFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl JsCast for CamelCaseMe {
+        fn instanceof(val: &JsValue) -> bool {
+            Object::instanceof(val)
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            CamelCaseMe {
+                obj: Object::unchecked_from_js(val),
+            }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const CamelCaseMe) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut CamelCaseMe) }
+        }
+    }
+};
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc = "The `ManyTypes` dictionary"]
+pub struct ManyTypes {
+    obj: ::js_sys::Object,
+}
+impl ManyTypes {
+    pub fn new() -> ManyTypes {
+        let mut _ret = ManyTypes {
+            obj: ::js_sys::Object::new(),
+        };
+        return _ret;
+    }
+    #[doc = "Change the `a` field of this object"]
+    pub fn a(&mut self, val: &str) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(a)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `n1` field of this object"]
+    pub fn n1(&mut self, val: u8) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(n1)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `n2` field of this object"]
+    pub fn n2(&mut self, val: i8) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(n2)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `n3` field of this object"]
+    pub fn n3(&mut self, val: u16) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(n3)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `n4` field of this object"]
+    pub fn n4(&mut self, val: i16) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(n4)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `n5` field of this object"]
+    pub fn n5(&mut self, val: u32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(n5)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `n6` field of this object"]
+    pub fn n6(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(n6)),
+            &JsValue::from(val),
+        );
+        self
+    }
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `ManyTypes` and the wasm ABI, which are all the same as `Object`'s."]
+const __wbg_generated_const_ManyTypes: () = {
+    use js_sys::Object;
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::*;
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl From<ManyTypes> for JsValue {
+        fn from(val: ManyTypes) -> JsValue {
+            val.obj.into()
+        }
+    }
+    impl AsRef<JsValue> for ManyTypes {
+        fn as_ref(&self) -> &JsValue {
+            self.obj.as_ref()
+        }
+    }
+    impl AsMut<JsValue> for ManyTypes {
+        fn as_mut(&mut self) -> &mut JsValue {
+            self.obj.as_mut()
+        }
+    }
+    impl WasmDescribe for ManyTypes {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<Object as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <Object as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for ManyTypes {
+        type Abi = <Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a ManyTypes {
+        type Abi = <&'a Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl FromWasmAbi for ManyTypes {
+        type Abi = <Object as FromWasmAbi>::Abi;
+        unsafe fn from_abi(abi: Self::Abi, extra: &mut Stack) -> Self {
+            ManyTypes {
+                obj: Object::from_abi(abi, extra),
+            }
+        }
+    }
+    impl OptionIntoWasmAbi for ManyTypes {
+        fn none() -> Self::Abi {
+            Object::none()
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a ManyTypes {
+        fn none() -> Self::Abi {
+            <&'a Object>::none()
+        }
+    }
+    impl OptionFromWasmAbi for ManyTypes {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl RefFromWasmAbi for ManyTypes {
+        type Abi = <Object as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<ManyTypes>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <Object as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(ManyTypes {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for ManyTypes {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionRefFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl JsCast for ManyTypes {
+        fn instanceof(val: &JsValue) -> bool {
+            Object::instanceof(val)
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            ManyTypes {
+                obj: Object::unchecked_from_js(val),
+            }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const ManyTypes) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut ManyTypes) }
+        }
+    }
+};
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc = "The `OtherDict` dictionary"]
+pub struct OtherDict {
+    obj: ::js_sys::Object,
+}
+impl OtherDict {
+    pub fn new() -> OtherDict {
+        let mut _ret = OtherDict {
+            obj: ::js_sys::Object::new(),
+        };
+        return _ret;
+    }
+    #[doc = "Change the `a` field of this object"]
+    pub fn a(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(a)),
+            &JsValue::from(val),
+        );
+        self
+    }
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `OtherDict` and the wasm ABI, which are all the same as `Object`'s."]
+const __wbg_generated_const_OtherDict: () = {
+    use js_sys::Object;
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::*;
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl From<OtherDict> for JsValue {
+        fn from(val: OtherDict) -> JsValue {
+            val.obj.into()
+        }
+    }
+    impl AsRef<JsValue> for OtherDict {
+        fn as_ref(&self) -> &JsValue {
+            self.obj.as_ref()
+        }
+    }
+    impl AsMut<JsValue> for OtherDict {
+        fn as_mut(&mut self) -> &mut JsValue {
+            self.obj.as_mut()
+        }
+    }
+    impl WasmDescribe for OtherDict {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<Object as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <Object as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for OtherDict {
+        type Abi = <Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a OtherDict {
+        type Abi = <&'a Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl FromWasmAbi for OtherDict {
+        type Abi = <Object as FromWasmAbi>::Abi;
+        unsafe fn from_abi(abi: Self::Abi, extra: &mut Stack) -> Self {
+            OtherDict {
+                obj: Object::from_abi(abi, extra),
+            }
+        }
+    }
+    impl OptionIntoWasmAbi for OtherDict {
+        fn none() -> Self::Abi {
+            Object::none()
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a OtherDict {
+        fn none() -> Self::Abi {
+            <&'a Object>::none()
+        }
+    }
+    impl OptionFromWasmAbi for OtherDict {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl RefFromWasmAbi for OtherDict {
+        type Abi = <Object as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<OtherDict>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <Object as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(OtherDict {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for OtherDict {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionRefFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl JsCast for OtherDict {
+        fn instanceof(val: &JsValue) -> bool {
+            Object::instanceof(val)
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            OtherDict {
+                obj: Object::unchecked_from_js(val),
+            }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const OtherDict) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut OtherDict) }
+        }
+    }
+};
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc = "The `Required` dictionary"]
+pub struct Required {
+    obj: ::js_sys::Object,
+}
+impl Required {
+    pub fn new(a: i32, b: &str) -> Required {
+        let mut _ret = Required {
+            obj: ::js_sys::Object::new(),
+        };
+        _ret.a(a);
+        _ret.b(b);
+        return _ret;
+    }
+    #[doc = "Change the `a` field of this object"]
+    pub fn a(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(a)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `b` field of this object"]
+    pub fn b(&mut self, val: &str) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(b)),
+            &JsValue::from(val),
+        );
+        self
+    }
+    #[doc = "Change the `c` field of this object"]
+    pub fn c(&mut self, val: i32) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        ::js_sys::Reflect::set(
+            self.obj.as_ref(),
+            &JsValue::from(stringify!(c)),
+            &JsValue::from(val),
+        );
+        self
+    }
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `Required` and the wasm ABI, which are all the same as `Object`'s."]
+const __wbg_generated_const_Required: () = {
+    use js_sys::Object;
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::*;
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl From<Required> for JsValue {
+        fn from(val: Required) -> JsValue {
+            val.obj.into()
+        }
+    }
+    impl AsRef<JsValue> for Required {
+        fn as_ref(&self) -> &JsValue {
+            self.obj.as_ref()
+        }
+    }
+    impl AsMut<JsValue> for Required {
+        fn as_mut(&mut self) -> &mut JsValue {
+            self.obj.as_mut()
+        }
+    }
+    impl WasmDescribe for Required {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<Object as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <Object as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for Required {
+        type Abi = <Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a Required {
+        type Abi = <&'a Object as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl FromWasmAbi for Required {
+        type Abi = <Object as FromWasmAbi>::Abi;
+        unsafe fn from_abi(abi: Self::Abi, extra: &mut Stack) -> Self {
+            Required {
+                obj: Object::from_abi(abi, extra),
+            }
+        }
+    }
+    impl OptionIntoWasmAbi for Required {
+        fn none() -> Self::Abi {
+            Object::none()
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a Required {
+        fn none() -> Self::Abi {
+            <&'a Object>::none()
+        }
+    }
+    impl OptionFromWasmAbi for Required {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl RefFromWasmAbi for Required {
+        type Abi = <Object as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<Required>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <Object as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(Required {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for Required {
+        fn is_none(abi: &Self::Abi) -> bool {
+            <Object as OptionRefFromWasmAbi>::is_none(abi)
+        }
+    }
+    impl JsCast for Required {
+        fn instanceof(val: &JsValue) -> bool {
+            Object::instanceof(val)
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            Required {
+                obj: Object::unchecked_from_js(val),
+            }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const Required) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut Required) }
+        }
+    }
+};
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_15a05027ddfa2774 : [ u8 ; 138usize ] = * b"\x86\0\0\0{\"exports\":[],\"enums\":[],\"imports\":[],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
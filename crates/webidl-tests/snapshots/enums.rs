@@ -0,0 +1,425 @@
+#[allow(bad_style)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ShapeType {
+    Circle = 0,
+    Square = 1,
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+impl ShapeType {
+    pub fn from_js_value(obj: &::wasm_bindgen::JsValue) -> Option<ShapeType> {
+        obj.as_string().and_then(|obj_str| match obj_str.as_str() {
+            "circle" => Some(ShapeType::Circle),
+            "square" => Some(ShapeType::Square),
+            _ => None,
+        })
+    }
+}
+impl ::wasm_bindgen::describe::WasmDescribe for ShapeType {
+    const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+        use wasm_bindgen::describe::*;
+        Description::Concat(&[Description::Numbers(&[
+            STRING_ENUM,
+            2u32,
+            6u32,
+            99u32,
+            105u32,
+            114u32,
+            99u32,
+            108u32,
+            101u32,
+            6u32,
+            115u32,
+            113u32,
+            117u32,
+            97u32,
+            114u32,
+            101u32,
+        ])])
+    };
+    fn describe() {
+        use wasm_bindgen::describe::*;
+        inform(STRING_ENUM);
+        inform(2u32);
+        inform(6u32);
+        inform(99u32);
+        inform(105u32);
+        inform(114u32);
+        inform(99u32);
+        inform(108u32);
+        inform(101u32);
+        inform(6u32);
+        inform(115u32);
+        inform(113u32);
+        inform(117u32);
+        inform(97u32);
+        inform(114u32);
+        inform(101u32);
+    }
+}
+impl ::wasm_bindgen::convert::IntoWasmAbi for ShapeType {
+    type Abi = u32;
+    fn into_abi(self, _extra: &mut ::wasm_bindgen::convert::Stack) -> u32 {
+        match self {
+            ShapeType::__Nonexhaustive => {
+                panic!("attempted to convert invalid ShapeType into JSValue")
+            }
+            other => other as u32,
+        }
+    }
+}
+impl ::wasm_bindgen::convert::FromWasmAbi for ShapeType {
+    type Abi = u32;
+    unsafe fn from_abi(js: u32, _extra: &mut ::wasm_bindgen::convert::Stack) -> Self {
+        match js {
+            0 => ShapeType::Circle,
+            1 => ShapeType::Square,
+            _ => ShapeType::__Nonexhaustive,
+        }
+    }
+}
+impl From<ShapeType> for ::wasm_bindgen::JsValue {
+    fn from(obj: ShapeType) -> ::wasm_bindgen::JsValue {
+        match obj {
+            ShapeType::Circle => ::wasm_bindgen::JsValue::from_str("circle"),
+            ShapeType::Square => ::wasm_bindgen::JsValue::from_str("square"),
+            ShapeType::__Nonexhaustive => {
+                panic!("attempted to convert invalid ShapeType into JSValue")
+            }
+        }
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `Shape` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape)"]
+#[repr(transparent)]
+pub struct Shape {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `Shape` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_Shape: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for Shape {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for Shape {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for Shape {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a Shape {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for Shape {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            Shape {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for Shape {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a Shape {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for Shape {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<Shape>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(Shape {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for Shape {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for Shape {
+        fn from(obj: JsValue) -> Shape {
+            Shape { obj }
+        }
+    }
+    impl AsRef<JsValue> for Shape {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for Shape {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<Shape> for JsValue {
+        fn from(obj: Shape) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for Shape {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_Shape(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_Shape(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            Shape { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const Shape) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut Shape) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_Shape` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_Shape() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <ShapeType as WasmDescribe>::describe();
+    inform(1);
+    <Shape as WasmDescribe>::describe();
+}
+impl Shape {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new Shape(..)` constructor, creating a new instance of `Shape`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/Shape)"]
+    pub fn new(kind: ShapeType) -> Result<Shape, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_Shape(
+                kind: <ShapeType as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <Shape as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let kind = ::wasm_bindgen::__rt::arg_into_abi::<ShapeType>(kind, &mut __stack);
+                __widl_f_new_Shape(kind, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<Shape>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new Shape(..)` constructor, creating a new instance of `Shape`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/Shape)"]
+    pub fn new(kind: ShapeType) -> Result<Shape, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_triangle_Shape` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_triangle_Shape() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <Shape as WasmDescribe>::describe();
+}
+impl Shape {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `triangle()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/triangle)"]
+    pub fn triangle() -> Shape {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_triangle_Shape() -> <Shape as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_triangle_Shape()
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Shape>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `triangle()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/triangle)"]
+    pub fn triangle() -> Shape {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_is_square_Shape` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_is_square_Shape() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Shape as WasmDescribe>::describe();
+    inform(1);
+    <bool as WasmDescribe>::describe();
+}
+impl Shape {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `isSquare()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/isSquare)"]
+    pub fn is_square(&self) -> bool {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_is_square_Shape(
+                self_: <&Shape as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <bool as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Shape>(self, &mut __stack);
+                __widl_f_is_square_Shape(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<bool>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `isSquare()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/isSquare)"]
+    pub fn is_square(&self) -> bool {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_is_circle_Shape` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_is_circle_Shape() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Shape as WasmDescribe>::describe();
+    inform(1);
+    <bool as WasmDescribe>::describe();
+}
+impl Shape {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `isCircle()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/isCircle)"]
+    pub fn is_circle(&self) -> bool {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_is_circle_Shape(
+                self_: <&Shape as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <bool as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Shape>(self, &mut __stack);
+                __widl_f_is_circle_Shape(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<bool>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `isCircle()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/isCircle)"]
+    pub fn is_circle(&self) -> bool {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_get_shape_Shape` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_get_shape_Shape() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Shape as WasmDescribe>::describe();
+    inform(1);
+    <ShapeType as WasmDescribe>::describe();
+}
+impl Shape {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `getShape()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/getShape)"]
+    pub fn get_shape(&self) -> ShapeType {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_get_shape_Shape(
+                self_: <&Shape as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <ShapeType as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Shape>(self, &mut __stack);
+                __widl_f_get_shape_Shape(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<ShapeType>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `getShape()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Shape/getShape)"]
+    pub fn get_shape(&self) -> ShapeType {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_1d041a847919c908 : [ u8 ; 2198usize ] = * b"\x92\x08\0\0{\"exports\":[],\"enums\":[],\"imports\":[{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"enum\"}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"Shape\",\"rust_name\":\"Shape\",\"instanceof_shim\":\"__widl_instanceof_Shape\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_Shape\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Shape\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_triangle_Shape\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Shape\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"triangle\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_is_square_Shape\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Shape\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"isSquare\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_is_circle_Shape\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Shape\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"isCircle\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_get_shape_Shape\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Shape\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"getShape\"}}}],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
@@ -0,0 +1,88 @@
+#[doc = "Describes the type of `__widl_f_add_one_` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_add_one_() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <i32 as WasmDescribe>::describe();
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+#[doc = "Describes the type of `__widl_f_pow_` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_pow_() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <f64 as WasmDescribe>::describe();
+    <f64 as WasmDescribe>::describe();
+    inform(1);
+    <f64 as WasmDescribe>::describe();
+}
+pub mod math_test {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `math_test.add_one()` function\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/math_test/add_one)"]
+    pub fn add_one(val: i32) -> i32 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_add_one_(
+                val: <i32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let val = ::wasm_bindgen::__rt::arg_into_abi::<i32>(val, &mut __stack);
+                __widl_f_add_one_(val)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i32>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `math_test.add_one()` function\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/math_test/add_one)"]
+    pub fn add_one(val: i32) -> i32 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `math_test.pow()` function\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/math_test/pow)"]
+    pub fn pow(base: f64, exponent: f64) -> f64 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_pow_(
+                base: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exponent: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <f64 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let base = ::wasm_bindgen::__rt::arg_into_abi::<f64>(base, &mut __stack);
+                let exponent = ::wasm_bindgen::__rt::arg_into_abi::<f64>(exponent, &mut __stack);
+                __widl_f_pow_(base, exponent)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<f64>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `math_test.pow()` function\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/math_test/pow)"]
+    pub fn pow(base: f64, exponent: f64) -> f64 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_e3e7659ffefe928c : [ u8 ; 713usize ] = * b"\xC5\x02\0\0{\"exports\":[],\"enums\":[],\"imports\":[{\"module\":null,\"node_module\":null,\"js_namespace\":\"math_test\",\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_add_one_\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":null,\"structural\":false,\"function\":{\"name\":\"add_one\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":\"math_test\",\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_pow_\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":null,\"structural\":false,\"function\":{\"name\":\"pow\"}}}],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
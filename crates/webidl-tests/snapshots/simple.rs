@@ -0,0 +1,3553 @@
+#[allow(bad_style)]
+#[doc = "The `Method` object\n\nAn interface with a method.\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Method)"]
+#[repr(transparent)]
+pub struct Method {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `Method` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_Method: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for Method {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for Method {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for Method {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a Method {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for Method {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            Method {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for Method {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a Method {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for Method {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<Method>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(Method {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for Method {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for Method {
+        fn from(obj: JsValue) -> Method {
+            Method { obj }
+        }
+    }
+    impl AsRef<JsValue> for Method {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for Method {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<Method> for JsValue {
+        fn from(obj: Method) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for Method {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_Method(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_Method(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            Method { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const Method) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut Method) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_Method` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_Method() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <f64 as WasmDescribe>::describe();
+    inform(1);
+    <Method as WasmDescribe>::describe();
+}
+impl Method {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new Method(..)` constructor, creating a new instance of `Method`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Method/Method)"]
+    pub fn new(value: f64) -> Result<Method, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_Method(
+                value: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <Method as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let value = ::wasm_bindgen::__rt::arg_into_abi::<f64>(value, &mut __stack);
+                __widl_f_new_Method(value, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<Method>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new Method(..)` constructor, creating a new instance of `Method`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Method/Method)"]
+    pub fn new(value: f64) -> Result<Method, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_my_cmp_Method` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_my_cmp_Method() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&Method as WasmDescribe>::describe();
+    <&Method as WasmDescribe>::describe();
+    inform(1);
+    <bool as WasmDescribe>::describe();
+}
+impl Method {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `myCmp()` method\n\nCompares this with `bar`.\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Method/myCmp)"]
+    pub fn my_cmp(&self, bar: &Method) -> bool {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_my_cmp_Method(
+                self_: <&Method as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                bar: <&Method as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <bool as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Method>(self, &mut __stack);
+                let bar = ::wasm_bindgen::__rt::arg_into_abi::<&Method>(bar, &mut __stack);
+                __widl_f_my_cmp_Method(self_, bar)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<bool>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `myCmp()` method\n\nCompares this with `bar`.\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Method/myCmp)"]
+    pub fn my_cmp(&self, bar: &Method) -> bool {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `Property` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Property)"]
+#[repr(transparent)]
+pub struct Property {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `Property` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_Property: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for Property {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for Property {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for Property {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a Property {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for Property {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            Property {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for Property {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a Property {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for Property {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<Property>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(Property {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for Property {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for Property {
+        fn from(obj: JsValue) -> Property {
+            Property { obj }
+        }
+    }
+    impl AsRef<JsValue> for Property {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for Property {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<Property> for JsValue {
+        fn from(obj: Property) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for Property {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_Property(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_Property(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            Property { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const Property) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut Property) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_Property` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_Property() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <f64 as WasmDescribe>::describe();
+    inform(1);
+    <Property as WasmDescribe>::describe();
+}
+impl Property {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new Property(..)` constructor, creating a new instance of `Property`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Property/Property)"]
+    pub fn new(value: f64) -> Result<Property, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_Property(
+                value: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <Property as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let value = ::wasm_bindgen::__rt::arg_into_abi::<f64>(value, &mut __stack);
+                __widl_f_new_Property(value, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<Property>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new Property(..)` constructor, creating a new instance of `Property`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Property/Property)"]
+    pub fn new(value: f64) -> Result<Property, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_value_Property` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_value_Property() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Property as WasmDescribe>::describe();
+    inform(1);
+    <f64 as WasmDescribe>::describe();
+}
+impl Property {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `value` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Property/value)"]
+    pub fn value(&self) -> f64 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_value_Property(
+                self_: <&Property as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <f64 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Property>(self, &mut __stack);
+                __widl_f_value_Property(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<f64>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `value` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Property/value)"]
+    pub fn value(&self) -> f64 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_value_Property` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_value_Property() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&Property as WasmDescribe>::describe();
+    <f64 as WasmDescribe>::describe();
+    inform(0);
+}
+impl Property {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `value` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Property/value)"]
+    pub fn set_value(&self, value: f64) {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_value_Property(
+                self_: <&Property as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                value: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> ();
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Property>(self, &mut __stack);
+                let value = ::wasm_bindgen::__rt::arg_into_abi::<f64>(value, &mut __stack);
+                __widl_f_set_value_Property(self_, value)
+            };
+            ()
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `value` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Property/value)"]
+    pub fn set_value(&self, value: f64) {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `NamedConstructor` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NamedConstructor)"]
+#[repr(transparent)]
+pub struct NamedConstructor {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `NamedConstructor` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_NamedConstructor: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for NamedConstructor {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for NamedConstructor {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for NamedConstructor {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a NamedConstructor {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for NamedConstructor {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            NamedConstructor {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for NamedConstructor {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a NamedConstructor {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for NamedConstructor {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<NamedConstructor>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(NamedConstructor {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for NamedConstructor {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for NamedConstructor {
+        fn from(obj: JsValue) -> NamedConstructor {
+            NamedConstructor { obj }
+        }
+    }
+    impl AsRef<JsValue> for NamedConstructor {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for NamedConstructor {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<NamedConstructor> for JsValue {
+        fn from(obj: NamedConstructor) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for NamedConstructor {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_NamedConstructor(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_NamedConstructor(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            NamedConstructor { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const NamedConstructor) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut NamedConstructor) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_NamedConstructorBar` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_NamedConstructorBar() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <f64 as WasmDescribe>::describe();
+    inform(1);
+    <NamedConstructor as WasmDescribe>::describe();
+}
+impl NamedConstructor {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new NamedConstructorBar(..)` constructor, creating a new instance of `NamedConstructor`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NamedConstructor/NamedConstructorBar)"]
+    pub fn new(value: f64) -> Result<NamedConstructor, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_NamedConstructorBar(
+                value: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <NamedConstructor as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let value = ::wasm_bindgen::__rt::arg_into_abi::<f64>(value, &mut __stack);
+                __widl_f_new_NamedConstructorBar(value, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<NamedConstructor>(
+                _ret,
+            ))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new NamedConstructorBar(..)` constructor, creating a new instance of `NamedConstructor`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NamedConstructor/NamedConstructorBar)"]
+    pub fn new(value: f64) -> Result<NamedConstructor, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_value_NamedConstructor` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_value_NamedConstructor() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&NamedConstructor as WasmDescribe>::describe();
+    inform(1);
+    <f64 as WasmDescribe>::describe();
+}
+impl NamedConstructor {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `value` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NamedConstructor/value)"]
+    pub fn value(&self) -> f64 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_value_NamedConstructor(
+                self_: <&NamedConstructor as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <f64 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&NamedConstructor>(self, &mut __stack);
+                __widl_f_value_NamedConstructor(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<f64>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `value` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NamedConstructor/value)"]
+    pub fn value(&self) -> f64 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `StaticMethod` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StaticMethod)"]
+#[repr(transparent)]
+pub struct StaticMethod {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `StaticMethod` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_StaticMethod: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for StaticMethod {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for StaticMethod {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for StaticMethod {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a StaticMethod {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for StaticMethod {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            StaticMethod {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for StaticMethod {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a StaticMethod {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for StaticMethod {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<StaticMethod>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(StaticMethod {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for StaticMethod {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for StaticMethod {
+        fn from(obj: JsValue) -> StaticMethod {
+            StaticMethod { obj }
+        }
+    }
+    impl AsRef<JsValue> for StaticMethod {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for StaticMethod {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<StaticMethod> for JsValue {
+        fn from(obj: StaticMethod) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for StaticMethod {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_StaticMethod(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_StaticMethod(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            StaticMethod { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const StaticMethod) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut StaticMethod) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_swap_StaticMethod` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_swap_StaticMethod() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <f64 as WasmDescribe>::describe();
+    inform(1);
+    <f64 as WasmDescribe>::describe();
+}
+impl StaticMethod {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `swap()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StaticMethod/swap)"]
+    pub fn swap(value: f64) -> f64 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_swap_StaticMethod(
+                value: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <f64 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let value = ::wasm_bindgen::__rt::arg_into_abi::<f64>(value, &mut __stack);
+                __widl_f_swap_StaticMethod(value)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<f64>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `swap()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StaticMethod/swap)"]
+    pub fn swap(value: f64) -> f64 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `StaticProperty` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StaticProperty)"]
+#[repr(transparent)]
+pub struct StaticProperty {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `StaticProperty` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_StaticProperty: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for StaticProperty {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for StaticProperty {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for StaticProperty {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a StaticProperty {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for StaticProperty {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            StaticProperty {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for StaticProperty {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a StaticProperty {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for StaticProperty {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<StaticProperty>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(StaticProperty {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for StaticProperty {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for StaticProperty {
+        fn from(obj: JsValue) -> StaticProperty {
+            StaticProperty { obj }
+        }
+    }
+    impl AsRef<JsValue> for StaticProperty {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for StaticProperty {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<StaticProperty> for JsValue {
+        fn from(obj: StaticProperty) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for StaticProperty {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_StaticProperty(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_StaticProperty(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            StaticProperty { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const StaticProperty) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut StaticProperty) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_value_StaticProperty` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_value_StaticProperty() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <f64 as WasmDescribe>::describe();
+}
+impl StaticProperty {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `value` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StaticProperty/value)"]
+    pub fn value() -> f64 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_value_StaticProperty() -> <f64 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_value_StaticProperty()
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<f64>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `value` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StaticProperty/value)"]
+    pub fn value() -> f64 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_value_StaticProperty` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_value_StaticProperty() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <f64 as WasmDescribe>::describe();
+    inform(0);
+}
+impl StaticProperty {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `value` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StaticProperty/value)"]
+    pub fn set_value(value: f64) {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_value_StaticProperty(
+                value: <f64 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> ();
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let value = ::wasm_bindgen::__rt::arg_into_abi::<f64>(value, &mut __stack);
+                __widl_f_set_value_StaticProperty(value)
+            };
+            ()
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `value` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StaticProperty/value)"]
+    pub fn set_value(value: f64) {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `UndefinedMethod` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/UndefinedMethod)"]
+#[repr(transparent)]
+pub struct UndefinedMethod {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `UndefinedMethod` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_UndefinedMethod: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for UndefinedMethod {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for UndefinedMethod {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for UndefinedMethod {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a UndefinedMethod {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for UndefinedMethod {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            UndefinedMethod {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for UndefinedMethod {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a UndefinedMethod {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for UndefinedMethod {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<UndefinedMethod>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(UndefinedMethod {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for UndefinedMethod {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for UndefinedMethod {
+        fn from(obj: JsValue) -> UndefinedMethod {
+            UndefinedMethod { obj }
+        }
+    }
+    impl AsRef<JsValue> for UndefinedMethod {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for UndefinedMethod {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<UndefinedMethod> for JsValue {
+        fn from(obj: UndefinedMethod) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for UndefinedMethod {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_UndefinedMethod(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_UndefinedMethod(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            UndefinedMethod { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const UndefinedMethod) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut UndefinedMethod) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_UndefinedMethod` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_UndefinedMethod() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <UndefinedMethod as WasmDescribe>::describe();
+}
+impl UndefinedMethod {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new UndefinedMethod(..)` constructor, creating a new instance of `UndefinedMethod`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/UndefinedMethod/UndefinedMethod)"]
+    pub fn new() -> Result<UndefinedMethod, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_UndefinedMethod(
+                exn_data_ptr: *mut u32,
+            ) -> <UndefinedMethod as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_UndefinedMethod(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<UndefinedMethod>(
+                _ret,
+            ))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new UndefinedMethod(..)` constructor, creating a new instance of `UndefinedMethod`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/UndefinedMethod/UndefinedMethod)"]
+    pub fn new() -> Result<UndefinedMethod, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_ok_method_UndefinedMethod` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_ok_method_UndefinedMethod() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&UndefinedMethod as WasmDescribe>::describe();
+    inform(1);
+    <bool as WasmDescribe>::describe();
+}
+impl UndefinedMethod {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `ok_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/UndefinedMethod/ok_method)"]
+    pub fn ok_method(&self) -> bool {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_ok_method_UndefinedMethod(
+                self_: <&UndefinedMethod as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <bool as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&UndefinedMethod>(self, &mut __stack);
+                __widl_f_ok_method_UndefinedMethod(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<bool>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `ok_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/UndefinedMethod/ok_method)"]
+    pub fn ok_method(&self) -> bool {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `NullableMethod` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NullableMethod)"]
+#[repr(transparent)]
+pub struct NullableMethod {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `NullableMethod` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_NullableMethod: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for NullableMethod {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for NullableMethod {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for NullableMethod {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a NullableMethod {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for NullableMethod {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            NullableMethod {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for NullableMethod {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a NullableMethod {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for NullableMethod {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<NullableMethod>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(NullableMethod {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for NullableMethod {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for NullableMethod {
+        fn from(obj: JsValue) -> NullableMethod {
+            NullableMethod { obj }
+        }
+    }
+    impl AsRef<JsValue> for NullableMethod {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for NullableMethod {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<NullableMethod> for JsValue {
+        fn from(obj: NullableMethod) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for NullableMethod {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_NullableMethod(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_NullableMethod(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            NullableMethod { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const NullableMethod) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut NullableMethod) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_NullableMethod` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_NullableMethod() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <NullableMethod as WasmDescribe>::describe();
+}
+impl NullableMethod {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new NullableMethod(..)` constructor, creating a new instance of `NullableMethod`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NullableMethod/NullableMethod)"]
+    pub fn new() -> Result<NullableMethod, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_NullableMethod(
+                exn_data_ptr: *mut u32,
+            ) -> <NullableMethod as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_NullableMethod(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<NullableMethod>(
+                _ret,
+            ))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new NullableMethod(..)` constructor, creating a new instance of `NullableMethod`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NullableMethod/NullableMethod)"]
+    pub fn new() -> Result<NullableMethod, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_opt_NullableMethod` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_opt_NullableMethod() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&NullableMethod as WasmDescribe>::describe();
+    <Option<i16> as WasmDescribe>::describe();
+    inform(1);
+    <Option<u8> as WasmDescribe>::describe();
+}
+impl NullableMethod {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `opt()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NullableMethod/opt)"]
+    pub fn opt(&self, a: Option<i16>) -> Option<u8> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_opt_NullableMethod(
+                self_: <&NullableMethod as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <Option<i16> as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <Option<u8> as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&NullableMethod>(self, &mut __stack);
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<Option<i16>>(a, &mut __stack);
+                __widl_f_opt_NullableMethod(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<Option<u8>>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `opt()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/NullableMethod/opt)"]
+    pub fn opt(&self, a: Option<i16>) -> Option<u8> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `GlobalMethod` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GlobalMethod)"]
+#[repr(transparent)]
+pub struct GlobalMethod {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `GlobalMethod` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_GlobalMethod: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for GlobalMethod {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for GlobalMethod {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for GlobalMethod {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a GlobalMethod {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for GlobalMethod {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            GlobalMethod {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for GlobalMethod {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a GlobalMethod {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for GlobalMethod {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<GlobalMethod>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(GlobalMethod {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for GlobalMethod {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for GlobalMethod {
+        fn from(obj: JsValue) -> GlobalMethod {
+            GlobalMethod { obj }
+        }
+    }
+    impl AsRef<JsValue> for GlobalMethod {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for GlobalMethod {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<GlobalMethod> for JsValue {
+        fn from(obj: GlobalMethod) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for GlobalMethod {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_GlobalMethod(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_GlobalMethod(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            GlobalMethod { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const GlobalMethod) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut GlobalMethod) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_GlobalMethod` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_GlobalMethod() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <GlobalMethod as WasmDescribe>::describe();
+}
+impl GlobalMethod {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new GlobalMethod(..)` constructor, creating a new instance of `GlobalMethod`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GlobalMethod/GlobalMethod)"]
+    pub fn new() -> Result<GlobalMethod, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_GlobalMethod(
+                exn_data_ptr: *mut u32,
+            ) -> <GlobalMethod as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_GlobalMethod(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<GlobalMethod>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new GlobalMethod(..)` constructor, creating a new instance of `GlobalMethod`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GlobalMethod/GlobalMethod)"]
+    pub fn new() -> Result<GlobalMethod, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_GlobalMethod` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_GlobalMethod() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&GlobalMethod as WasmDescribe>::describe();
+    inform(1);
+    <u8 as WasmDescribe>::describe();
+}
+impl GlobalMethod {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GlobalMethod/m)"]
+    pub fn m(&self) -> u8 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_GlobalMethod(
+                self_: <&GlobalMethod as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <u8 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&GlobalMethod>(self, &mut __stack);
+                __widl_f_m_GlobalMethod(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<u8>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GlobalMethod/m)"]
+    pub fn m(&self) -> u8 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `Indexing` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Indexing)"]
+#[repr(transparent)]
+pub struct Indexing {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `Indexing` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_Indexing: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for Indexing {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for Indexing {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for Indexing {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a Indexing {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for Indexing {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            Indexing {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for Indexing {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a Indexing {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for Indexing {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<Indexing>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(Indexing {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for Indexing {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for Indexing {
+        fn from(obj: JsValue) -> Indexing {
+            Indexing { obj }
+        }
+    }
+    impl AsRef<JsValue> for Indexing {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for Indexing {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<Indexing> for JsValue {
+        fn from(obj: Indexing) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for Indexing {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_Indexing(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_Indexing(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            Indexing { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const Indexing) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut Indexing) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_Indexing` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_Indexing() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <Indexing as WasmDescribe>::describe();
+}
+impl Indexing {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new Indexing(..)` constructor, creating a new instance of `Indexing`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Indexing/Indexing)"]
+    pub fn new() -> Result<Indexing, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_Indexing(
+                exn_data_ptr: *mut u32,
+            ) -> <Indexing as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_Indexing(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<Indexing>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new Indexing(..)` constructor, creating a new instance of `Indexing`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Indexing/Indexing)"]
+    pub fn new() -> Result<Indexing, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_get_Indexing` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_get_Indexing() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&Indexing as WasmDescribe>::describe();
+    <u32 as WasmDescribe>::describe();
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl Indexing {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The indexing getter\n\n"]
+    pub fn get(&self, index: u32) -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_get_Indexing(
+                self_: <&Indexing as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                index: <u32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Indexing>(self, &mut __stack);
+                let index = ::wasm_bindgen::__rt::arg_into_abi::<u32>(index, &mut __stack);
+                __widl_f_get_Indexing(self_, index)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The indexing getter\n\n"]
+    pub fn get(&self, index: u32) -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_Indexing` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_Indexing() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(3u32);
+    <&Indexing as WasmDescribe>::describe();
+    <u32 as WasmDescribe>::describe();
+    <i16 as WasmDescribe>::describe();
+    inform(0);
+}
+impl Indexing {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The indexing setter\n\n"]
+    pub fn set(&self, index: u32, value: i16) {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_Indexing(
+                self_: <&Indexing as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                index: <u32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                value: <i16 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> ();
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Indexing>(self, &mut __stack);
+                let index = ::wasm_bindgen::__rt::arg_into_abi::<u32>(index, &mut __stack);
+                let value = ::wasm_bindgen::__rt::arg_into_abi::<i16>(value, &mut __stack);
+                __widl_f_set_Indexing(self_, index, value)
+            };
+            ()
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The indexing setter\n\n"]
+    pub fn set(&self, index: u32, value: i16) {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_delete_Indexing` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_delete_Indexing() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&Indexing as WasmDescribe>::describe();
+    <u32 as WasmDescribe>::describe();
+    inform(0);
+}
+impl Indexing {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The indexing deleter\n\n"]
+    pub fn delete(&self, index: u32) {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_delete_Indexing(
+                self_: <&Indexing as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                index: <u32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> ();
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Indexing>(self, &mut __stack);
+                let index = ::wasm_bindgen::__rt::arg_into_abi::<u32>(index, &mut __stack);
+                __widl_f_delete_Indexing(self_, index)
+            };
+            ()
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The indexing deleter\n\n"]
+    pub fn delete(&self, index: u32) {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `OptionalAndUnionArguments` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments)"]
+#[repr(transparent)]
+pub struct OptionalAndUnionArguments {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `OptionalAndUnionArguments` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_OptionalAndUnionArguments: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for OptionalAndUnionArguments {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for OptionalAndUnionArguments {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for OptionalAndUnionArguments {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a OptionalAndUnionArguments {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for OptionalAndUnionArguments {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            OptionalAndUnionArguments {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for OptionalAndUnionArguments {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a OptionalAndUnionArguments {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for OptionalAndUnionArguments {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<OptionalAndUnionArguments>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(OptionalAndUnionArguments {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for OptionalAndUnionArguments {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for OptionalAndUnionArguments {
+        fn from(obj: JsValue) -> OptionalAndUnionArguments {
+            OptionalAndUnionArguments { obj }
+        }
+    }
+    impl AsRef<JsValue> for OptionalAndUnionArguments {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for OptionalAndUnionArguments {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<OptionalAndUnionArguments> for JsValue {
+        fn from(obj: OptionalAndUnionArguments) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for OptionalAndUnionArguments {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_OptionalAndUnionArguments(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_OptionalAndUnionArguments(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            OptionalAndUnionArguments { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const OptionalAndUnionArguments) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut OptionalAndUnionArguments) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_OptionalAndUnionArguments() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <OptionalAndUnionArguments as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new OptionalAndUnionArguments(..)` constructor, creating a new instance of `OptionalAndUnionArguments`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/OptionalAndUnionArguments)"]
+    pub fn new() -> Result<OptionalAndUnionArguments, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_OptionalAndUnionArguments(
+                exn_data_ptr: *mut u32,
+            ) -> <OptionalAndUnionArguments as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_OptionalAndUnionArguments(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<
+                OptionalAndUnionArguments,
+            >(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new OptionalAndUnionArguments(..)` constructor, creating a new instance of `OptionalAndUnionArguments`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/OptionalAndUnionArguments)"]
+    pub fn new() -> Result<OptionalAndUnionArguments, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_using_a_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_using_a_OptionalAndUnionArguments() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&OptionalAndUnionArguments as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_a(&self, a: &str) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_using_a_OptionalAndUnionArguments(
+                self_: <&OptionalAndUnionArguments as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&OptionalAndUnionArguments>(
+                    self,
+                    &mut __stack,
+                );
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&str>(a, &mut __stack);
+                __widl_f_m_using_a_OptionalAndUnionArguments(self_, a)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_a(&self, a: &str) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_using_a_and_b_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_using_a_and_b_OptionalAndUnionArguments() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(3u32);
+    <&OptionalAndUnionArguments as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <bool as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_a_and_b(&self, a: &str, b: bool) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_using_a_and_b_OptionalAndUnionArguments(
+                self_: <&OptionalAndUnionArguments as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                b: <bool as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&OptionalAndUnionArguments>(
+                    self,
+                    &mut __stack,
+                );
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&str>(a, &mut __stack);
+                let b = ::wasm_bindgen::__rt::arg_into_abi::<bool>(b, &mut __stack);
+                __widl_f_m_using_a_and_b_OptionalAndUnionArguments(self_, a, b)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_a_and_b(&self, a: &str, b: bool) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_using_dom_str_and_bool_and_i16_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_using_dom_str_and_bool_and_i16_OptionalAndUnionArguments(
+) {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(4u32);
+    <&OptionalAndUnionArguments as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <bool as WasmDescribe>::describe();
+    <i16 as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_i16(&self, a: &str, b: bool, c: i16) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_using_dom_str_and_bool_and_i16_OptionalAndUnionArguments(
+                self_: <&OptionalAndUnionArguments as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                b: <bool as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                c: <i16 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&OptionalAndUnionArguments>(
+                    self,
+                    &mut __stack,
+                );
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&str>(a, &mut __stack);
+                let b = ::wasm_bindgen::__rt::arg_into_abi::<bool>(b, &mut __stack);
+                let c = ::wasm_bindgen::__rt::arg_into_abi::<i16>(c, &mut __stack);
+                __widl_f_m_using_dom_str_and_bool_and_i16_OptionalAndUnionArguments(self_, a, b, c)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_i16(&self, a: &str, b: bool, c: i16) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_using_dom_str_and_bool_and_dom_str_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_using_dom_str_and_bool_and_dom_str_OptionalAndUnionArguments(
+) {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(4u32);
+    <&OptionalAndUnionArguments as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <bool as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_dom_str(&self, a: &str, b: bool, c: &str) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_using_dom_str_and_bool_and_dom_str_OptionalAndUnionArguments(
+                self_: <&OptionalAndUnionArguments as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                b: <bool as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                c: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&OptionalAndUnionArguments>(
+                    self,
+                    &mut __stack,
+                );
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&str>(a, &mut __stack);
+                let b = ::wasm_bindgen::__rt::arg_into_abi::<bool>(b, &mut __stack);
+                let c = ::wasm_bindgen::__rt::arg_into_abi::<&str>(c, &mut __stack);
+                __widl_f_m_using_dom_str_and_bool_and_dom_str_OptionalAndUnionArguments(
+                    self_, a, b, c,
+                )
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_dom_str(&self, a: &str, b: bool, c: &str) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_using_dom_str_and_bool_and_i16_and_opt_i64_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_using_dom_str_and_bool_and_i16_and_opt_i64_OptionalAndUnionArguments(
+) {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(5u32);
+    <&OptionalAndUnionArguments as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <bool as WasmDescribe>::describe();
+    <i16 as WasmDescribe>::describe();
+    <Option<i64> as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_i16_and_opt_i64(
+        &self,
+        a: &str,
+        b: bool,
+        c: i16,
+        d: Option<i64>,
+    ) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_using_dom_str_and_bool_and_i16_and_opt_i64_OptionalAndUnionArguments(
+                self_: <&OptionalAndUnionArguments as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                b: <bool as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                c: <i16 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                d: <Option<i64> as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&OptionalAndUnionArguments>(
+                    self,
+                    &mut __stack,
+                );
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&str>(a, &mut __stack);
+                let b = ::wasm_bindgen::__rt::arg_into_abi::<bool>(b, &mut __stack);
+                let c = ::wasm_bindgen::__rt::arg_into_abi::<i16>(c, &mut __stack);
+                let d = ::wasm_bindgen::__rt::arg_into_abi::<Option<i64>>(d, &mut __stack);
+                __widl_f_m_using_dom_str_and_bool_and_i16_and_opt_i64_OptionalAndUnionArguments(
+                    self_, a, b, c, d,
+                )
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_i16_and_opt_i64(
+        &self,
+        a: &str,
+        b: bool,
+        c: i16,
+        d: Option<i64>,
+    ) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_using_dom_str_and_bool_and_i16_and_opt_bool_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_using_dom_str_and_bool_and_i16_and_opt_bool_OptionalAndUnionArguments(
+) {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(5u32);
+    <&OptionalAndUnionArguments as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <bool as WasmDescribe>::describe();
+    <i16 as WasmDescribe>::describe();
+    <Option<bool> as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_i16_and_opt_bool(
+        &self,
+        a: &str,
+        b: bool,
+        c: i16,
+        d: Option<bool>,
+    ) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_using_dom_str_and_bool_and_i16_and_opt_bool_OptionalAndUnionArguments(
+                self_: <&OptionalAndUnionArguments as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                b: <bool as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                c: <i16 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                d: <Option<bool> as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&OptionalAndUnionArguments>(
+                    self,
+                    &mut __stack,
+                );
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&str>(a, &mut __stack);
+                let b = ::wasm_bindgen::__rt::arg_into_abi::<bool>(b, &mut __stack);
+                let c = ::wasm_bindgen::__rt::arg_into_abi::<i16>(c, &mut __stack);
+                let d = ::wasm_bindgen::__rt::arg_into_abi::<Option<bool>>(d, &mut __stack);
+                __widl_f_m_using_dom_str_and_bool_and_i16_and_opt_bool_OptionalAndUnionArguments(
+                    self_, a, b, c, d,
+                )
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_i16_and_opt_bool(
+        &self,
+        a: &str,
+        b: bool,
+        c: i16,
+        d: Option<bool>,
+    ) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_i64_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_i64_OptionalAndUnionArguments(
+) {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(5u32);
+    <&OptionalAndUnionArguments as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <bool as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <Option<i64> as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_dom_str_and_opt_i64(
+        &self,
+        a: &str,
+        b: bool,
+        c: &str,
+        d: Option<i64>,
+    ) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_i64_OptionalAndUnionArguments(
+                self_: <&OptionalAndUnionArguments as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                b: <bool as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                c: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                d: <Option<i64> as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&OptionalAndUnionArguments>(
+                    self,
+                    &mut __stack,
+                );
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&str>(a, &mut __stack);
+                let b = ::wasm_bindgen::__rt::arg_into_abi::<bool>(b, &mut __stack);
+                let c = ::wasm_bindgen::__rt::arg_into_abi::<&str>(c, &mut __stack);
+                let d = ::wasm_bindgen::__rt::arg_into_abi::<Option<i64>>(d, &mut __stack);
+                __widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_i64_OptionalAndUnionArguments(
+                    self_, a, b, c, d,
+                )
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_dom_str_and_opt_i64(
+        &self,
+        a: &str,
+        b: bool,
+        c: &str,
+        d: Option<i64>,
+    ) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_bool_OptionalAndUnionArguments` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_bool_OptionalAndUnionArguments(
+) {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(5u32);
+    <&OptionalAndUnionArguments as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <bool as WasmDescribe>::describe();
+    <&str as WasmDescribe>::describe();
+    <Option<bool> as WasmDescribe>::describe();
+    inform(1);
+    <String as WasmDescribe>::describe();
+}
+impl OptionalAndUnionArguments {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_dom_str_and_opt_bool(
+        &self,
+        a: &str,
+        b: bool,
+        c: &str,
+        d: Option<bool>,
+    ) -> String {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_bool_OptionalAndUnionArguments(
+                self_: <&OptionalAndUnionArguments as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                a: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                b: <bool as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                c: <&str as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                d: <Option<bool> as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <String as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&OptionalAndUnionArguments>(
+                    self,
+                    &mut __stack,
+                );
+                let a = ::wasm_bindgen::__rt::arg_into_abi::<&str>(a, &mut __stack);
+                let b = ::wasm_bindgen::__rt::arg_into_abi::<bool>(b, &mut __stack);
+                let c = ::wasm_bindgen::__rt::arg_into_abi::<&str>(c, &mut __stack);
+                let d = ::wasm_bindgen::__rt::arg_into_abi::<Option<bool>>(d, &mut __stack);
+                __widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_bool_OptionalAndUnionArguments(
+                    self_, a, b, c, d,
+                )
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<String>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `m()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OptionalAndUnionArguments/m)"]
+    pub fn m_using_dom_str_and_bool_and_dom_str_and_opt_bool(
+        &self,
+        a: &str,
+        b: bool,
+        c: &str,
+        d: Option<bool>,
+    ) -> String {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `Unforgeable` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Unforgeable)"]
+#[repr(transparent)]
+pub struct Unforgeable {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `Unforgeable` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_Unforgeable: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for Unforgeable {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for Unforgeable {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for Unforgeable {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a Unforgeable {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for Unforgeable {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            Unforgeable {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for Unforgeable {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a Unforgeable {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for Unforgeable {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<Unforgeable>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(Unforgeable {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for Unforgeable {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for Unforgeable {
+        fn from(obj: JsValue) -> Unforgeable {
+            Unforgeable { obj }
+        }
+    }
+    impl AsRef<JsValue> for Unforgeable {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for Unforgeable {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<Unforgeable> for JsValue {
+        fn from(obj: Unforgeable) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for Unforgeable {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_Unforgeable(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_Unforgeable(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            Unforgeable { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const Unforgeable) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut Unforgeable) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_Unforgeable` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_Unforgeable() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <Unforgeable as WasmDescribe>::describe();
+}
+impl Unforgeable {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new Unforgeable(..)` constructor, creating a new instance of `Unforgeable`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Unforgeable/Unforgeable)"]
+    pub fn new() -> Result<Unforgeable, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_Unforgeable(
+                exn_data_ptr: *mut u32,
+            ) -> <Unforgeable as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_Unforgeable(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<Unforgeable>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new Unforgeable(..)` constructor, creating a new instance of `Unforgeable`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Unforgeable/Unforgeable)"]
+    pub fn new() -> Result<Unforgeable, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_uno_Unforgeable` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_uno_Unforgeable() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Unforgeable as WasmDescribe>::describe();
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl Unforgeable {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `uno` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Unforgeable/uno)"]
+    pub fn uno(&self) -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_uno_Unforgeable(
+                self_: <&Unforgeable as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Unforgeable>(self, &mut __stack);
+                __widl_f_uno_Unforgeable(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `uno` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Unforgeable/uno)"]
+    pub fn uno(&self) -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_dos_Unforgeable` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_dos_Unforgeable() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Unforgeable as WasmDescribe>::describe();
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl Unforgeable {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `dos` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Unforgeable/dos)"]
+    pub fn dos(&self) -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_dos_Unforgeable(
+                self_: <&Unforgeable as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Unforgeable>(self, &mut __stack);
+                __widl_f_dos_Unforgeable(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `dos` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Unforgeable/dos)"]
+    pub fn dos(&self) -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `PartialInterface` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface)"]
+#[repr(transparent)]
+pub struct PartialInterface {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `PartialInterface` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_PartialInterface: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for PartialInterface {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for PartialInterface {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for PartialInterface {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a PartialInterface {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for PartialInterface {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            PartialInterface {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for PartialInterface {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a PartialInterface {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for PartialInterface {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<PartialInterface>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(PartialInterface {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for PartialInterface {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for PartialInterface {
+        fn from(obj: JsValue) -> PartialInterface {
+            PartialInterface { obj }
+        }
+    }
+    impl AsRef<JsValue> for PartialInterface {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for PartialInterface {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<PartialInterface> for JsValue {
+        fn from(obj: PartialInterface) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for PartialInterface {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_PartialInterface(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_PartialInterface(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            PartialInterface { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const PartialInterface) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut PartialInterface) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_PartialInterface` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_PartialInterface() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <PartialInterface as WasmDescribe>::describe();
+}
+impl PartialInterface {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new PartialInterface(..)` constructor, creating a new instance of `PartialInterface`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/PartialInterface)"]
+    pub fn new() -> Result<PartialInterface, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_PartialInterface(
+                exn_data_ptr: *mut u32,
+            ) -> <PartialInterface as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_new_PartialInterface(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<PartialInterface>(
+                _ret,
+            ))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new PartialInterface(..)` constructor, creating a new instance of `PartialInterface`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/PartialInterface)"]
+    pub fn new() -> Result<PartialInterface, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_un_PartialInterface` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_un_PartialInterface() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&PartialInterface as WasmDescribe>::describe();
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl PartialInterface {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `un` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/un)"]
+    pub fn un(&self) -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_un_PartialInterface(
+                self_: <&PartialInterface as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&PartialInterface>(self, &mut __stack);
+                __widl_f_un_PartialInterface(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `un` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/un)"]
+    pub fn un(&self) -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_deux_PartialInterface` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_deux_PartialInterface() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&PartialInterface as WasmDescribe>::describe();
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl PartialInterface {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `deux()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/deux)"]
+    pub fn deux(&self) -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_deux_PartialInterface(
+                self_: <&PartialInterface as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&PartialInterface>(self, &mut __stack);
+                __widl_f_deux_PartialInterface(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `deux()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/deux)"]
+    pub fn deux(&self) -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_trois_PartialInterface` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_trois_PartialInterface() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&PartialInterface as WasmDescribe>::describe();
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl PartialInterface {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `trois` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/trois)"]
+    pub fn trois(&self) -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_trois_PartialInterface(
+                self_: <&PartialInterface as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&PartialInterface>(self, &mut __stack);
+                __widl_f_trois_PartialInterface(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `trois` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/trois)"]
+    pub fn trois(&self) -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_quatre_PartialInterface` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_quatre_PartialInterface() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&PartialInterface as WasmDescribe>::describe();
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl PartialInterface {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `quatre()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/quatre)"]
+    pub fn quatre(&self) -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_quatre_PartialInterface(
+                self_: <&PartialInterface as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ =
+                    ::wasm_bindgen::__rt::arg_into_abi::<&PartialInterface>(self, &mut __stack);
+                __widl_f_quatre_PartialInterface(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `quatre()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PartialInterface/quatre)"]
+    pub fn quatre(&self) -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(bad_style)]
+#[doc = "The `MixinFoo` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo)"]
+#[repr(transparent)]
+pub struct MixinFoo {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `MixinFoo` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_MixinFoo: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for MixinFoo {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for MixinFoo {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for MixinFoo {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a MixinFoo {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for MixinFoo {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            MixinFoo {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for MixinFoo {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a MixinFoo {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for MixinFoo {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<MixinFoo>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(MixinFoo {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for MixinFoo {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for MixinFoo {
+        fn from(obj: JsValue) -> MixinFoo {
+            MixinFoo { obj }
+        }
+    }
+    impl AsRef<JsValue> for MixinFoo {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for MixinFoo {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<MixinFoo> for JsValue {
+        fn from(obj: MixinFoo) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for MixinFoo {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_MixinFoo(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_MixinFoo(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            MixinFoo { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const MixinFoo) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut MixinFoo) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_MixinFoo` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_MixinFoo() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <i16 as WasmDescribe>::describe();
+    inform(1);
+    <MixinFoo as WasmDescribe>::describe();
+}
+impl MixinFoo {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new MixinFoo(..)` constructor, creating a new instance of `MixinFoo`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/MixinFoo)"]
+    pub fn new(bar: i16) -> Result<MixinFoo, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_MixinFoo(
+                bar: <i16 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <MixinFoo as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let bar = ::wasm_bindgen::__rt::arg_into_abi::<i16>(bar, &mut __stack);
+                __widl_f_new_MixinFoo(bar, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<MixinFoo>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new MixinFoo(..)` constructor, creating a new instance of `MixinFoo`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/MixinFoo)"]
+    pub fn new(bar: i16) -> Result<MixinFoo, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_default_bar_MixinFoo` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_default_bar_MixinFoo() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl MixinFoo {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `defaultBar` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/defaultBar)"]
+    pub fn default_bar() -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_default_bar_MixinFoo() -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_default_bar_MixinFoo()
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `defaultBar` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/defaultBar)"]
+    pub fn default_bar() -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_default_bar_MixinFoo` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_default_bar_MixinFoo() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <i16 as WasmDescribe>::describe();
+    inform(0);
+}
+impl MixinFoo {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `defaultBar` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/defaultBar)"]
+    pub fn set_default_bar(default_bar: i16) {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_default_bar_MixinFoo(
+                default_bar: <i16 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> ();
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let default_bar =
+                    ::wasm_bindgen::__rt::arg_into_abi::<i16>(default_bar, &mut __stack);
+                __widl_f_set_default_bar_MixinFoo(default_bar)
+            };
+            ()
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `defaultBar` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/defaultBar)"]
+    pub fn set_default_bar(default_bar: i16) {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_bar_MixinFoo` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_bar_MixinFoo() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&MixinFoo as WasmDescribe>::describe();
+    inform(1);
+    <i16 as WasmDescribe>::describe();
+}
+impl MixinFoo {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `bar` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/bar)"]
+    pub fn bar(&self) -> i16 {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_bar_MixinFoo(
+                self_: <&MixinFoo as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> <i16 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&MixinFoo>(self, &mut __stack);
+                __widl_f_bar_MixinFoo(self_)
+            };
+            ::wasm_bindgen::__rt::from_return_abi::<i16>(_ret)
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `bar` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/bar)"]
+    pub fn bar(&self) -> i16 {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_add_to_bar_MixinFoo` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_add_to_bar_MixinFoo() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&MixinFoo as WasmDescribe>::describe();
+    <i16 as WasmDescribe>::describe();
+    inform(0);
+}
+impl MixinFoo {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `addToBar()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/addToBar)"]
+    pub fn add_to_bar(&self, other: i16) {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_add_to_bar_MixinFoo(
+                self_: <&MixinFoo as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                other: <i16 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+            ) -> ();
+        }
+        unsafe {
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&MixinFoo>(self, &mut __stack);
+                let other = ::wasm_bindgen::__rt::arg_into_abi::<i16>(other, &mut __stack);
+                __widl_f_add_to_bar_MixinFoo(self_, other)
+            };
+            ()
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `addToBar()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MixinFoo/addToBar)"]
+    pub fn add_to_bar(&self, other: i16) {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_1981879bfa20dfda : [ u8 ; 18889usize ] = * b"\xC5I\0\0{\"exports\":[],\"enums\":[],\"imports\":[{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"Method\",\"rust_name\":\"Method\",\"instanceof_shim\":\"__widl_instanceof_Method\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_Method\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Method\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_my_cmp_Method\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Method\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"myCmp\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"Property\",\"rust_name\":\"Property\",\"instanceof_shim\":\"__widl_instanceof_Property\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_Property\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Property\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_value_Property\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Property\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"value\"}}}},\"structural\":false,\"function\":{\"name\":\"value\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_value_Property\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Property\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Setter\":\"value\"}}}},\"structural\":false,\"function\":{\"name\":\"set_value\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"NamedConstructor\",\"rust_name\":\"NamedConstructor\",\"instanceof_shim\":\"__widl_instanceof_NamedConstructor\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_NamedConstructorBar\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"NamedConstructorBar\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_value_NamedConstructor\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"NamedConstructor\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"value\"}}}},\"structural\":false,\"function\":{\"name\":\"value\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"StaticMethod\",\"rust_name\":\"StaticMethod\",\"instanceof_shim\":\"__widl_instanceof_StaticMethod\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_swap_StaticMethod\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"StaticMethod\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"swap\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"StaticProperty\",\"rust_name\":\"StaticProperty\",\"instanceof_shim\":\"__widl_instanceof_StaticProperty\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_value_StaticProperty\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"StaticProperty\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":{\"Getter\":\"value\"}}}},\"structural\":false,\"function\":{\"name\":\"value\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_value_StaticProperty\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"StaticProperty\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":{\"Setter\":\"value\"}}}},\"structural\":false,\"function\":{\"name\":\"set_value\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"UndefinedMethod\",\"rust_name\":\"UndefinedMethod\",\"instanceof_shim\":\"__widl_instanceof_UndefinedMethod\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_UndefinedMethod\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"UndefinedMethod\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_ok_method_UndefinedMethod\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"UndefinedMethod\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"ok_method\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"NullableMethod\",\"rust_name\":\"NullableMethod\",\"instanceof_shim\":\"__widl_instanceof_NullableMethod\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_NullableMethod\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"NullableMethod\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_opt_NullableMethod\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"NullableMethod\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"opt\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"GlobalMethod\",\"rust_name\":\"GlobalMethod\",\"instanceof_shim\":\"__widl_instanceof_GlobalMethod\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_GlobalMethod\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"GlobalMethod\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_GlobalMethod\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"GlobalMethod\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":true,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"Indexing\",\"rust_name\":\"Indexing\",\"instanceof_shim\":\"__widl_instanceof_Indexing\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_Indexing\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Indexing\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_get_Indexing\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Indexing\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"IndexingGetter\"}}},\"structural\":true,\"function\":{\"name\":\"get\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_Indexing\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Indexing\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"IndexingSetter\"}}},\"structural\":true,\"function\":{\"name\":\"set\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_delete_Indexing\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Indexing\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"IndexingDeleter\"}}},\"structural\":true,\"function\":{\"name\":\"delete\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"OptionalAndUnionArguments\",\"rust_name\":\"OptionalAndUnionArguments\",\"instanceof_shim\":\"__widl_instanceof_OptionalAndUnionArguments\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_OptionalAndUnionArguments\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_using_a_OptionalAndUnionArguments\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_using_a_and_b_OptionalAndUnionArguments\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_using_dom_str_and_bool_and_i16_OptionalAndUnionArguments\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_using_dom_str_and_bool_and_dom_str_OptionalAndUnionArguments\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_using_dom_str_and_bool_and_i16_and_opt_i64_OptionalAndUnionArguments\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_using_dom_str_and_bool_and_i16_and_opt_bool_OptionalAndUnionArguments\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_i64_OptionalAndUnionArguments\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_m_using_dom_str_and_bool_and_dom_str_and_opt_bool_OptionalAndUnionArguments\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"OptionalAndUnionArguments\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"m\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"Unforgeable\",\"rust_name\":\"Unforgeable\",\"instanceof_shim\":\"__widl_instanceof_Unforgeable\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_Unforgeable\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Unforgeable\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_uno_Unforgeable\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Unforgeable\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"uno\"}}}},\"structural\":true,\"function\":{\"name\":\"uno\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_dos_Unforgeable\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Unforgeable\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"dos\"}}}},\"structural\":false,\"function\":{\"name\":\"dos\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"PartialInterface\",\"rust_name\":\"PartialInterface\",\"instanceof_shim\":\"__widl_instanceof_PartialInterface\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_PartialInterface\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"PartialInterface\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_un_PartialInterface\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"PartialInterface\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"un\"}}}},\"structural\":false,\"function\":{\"name\":\"un\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_deux_PartialInterface\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"PartialInterface\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"deux\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_trois_PartialInterface\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"PartialInterface\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"trois\"}}}},\"structural\":false,\"function\":{\"name\":\"trois\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_quatre_PartialInterface\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"PartialInterface\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"quatre\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"MixinFoo\",\"rust_name\":\"MixinFoo\",\"instanceof_shim\":\"__widl_instanceof_MixinFoo\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_MixinFoo\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"MixinFoo\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_default_bar_MixinFoo\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"MixinFoo\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":{\"Getter\":\"defaultBar\"}}}},\"structural\":false,\"function\":{\"name\":\"defaultBar\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_default_bar_MixinFoo\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"MixinFoo\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":{\"Setter\":\"defaultBar\"}}}},\"structural\":false,\"function\":{\"name\":\"set_defaultBar\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_bar_MixinFoo\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"MixinFoo\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"bar\"}}}},\"structural\":false,\"function\":{\"name\":\"bar\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_add_to_bar_MixinFoo\",\"catch\":false,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"MixinFoo\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"addToBar\"}}}],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
@@ -0,0 +1,706 @@
+#[allow(bad_style)]
+#[doc = "The `Thang` object\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang)"]
+#[repr(transparent)]
+pub struct Thang {
+    obj: ::wasm_bindgen::JsValue,
+}
+#[allow(bad_style)]
+#[doc = "The conversions between `Thang` and the wasm ABI, which are all the same as `JsValue`'s."]
+const __wbg_generated_const_Thang: () = {
+    use wasm_bindgen::__rt::core::mem::ManuallyDrop;
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, Stack};
+    use wasm_bindgen::convert::{OptionFromWasmAbi, OptionIntoWasmAbi};
+    use wasm_bindgen::convert::{OptionRefFromWasmAbi, RefFromWasmAbi};
+    use wasm_bindgen::describe::WasmDescribe;
+    use wasm_bindgen::{JsCast, JsValue};
+    impl WasmDescribe for Thang {
+        const DESCRIPTION: ::wasm_bindgen::describe::Description = {
+            use wasm_bindgen::describe::*;
+            Description::Concat(&[<JsValue as WasmDescribe>::DESCRIPTION])
+        };
+        fn describe() {
+            use wasm_bindgen::describe::*;
+            <JsValue as WasmDescribe>::describe();
+        }
+    }
+    impl IntoWasmAbi for Thang {
+        type Abi = <JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            self.obj.into_abi(extra)
+        }
+    }
+    impl OptionIntoWasmAbi for Thang {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl<'a> OptionIntoWasmAbi for &'a Thang {
+        fn none() -> Self::Abi {
+            0
+        }
+    }
+    impl FromWasmAbi for Thang {
+        type Abi = <JsValue as FromWasmAbi>::Abi;
+        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+            Thang {
+                obj: JsValue::from_abi(js, extra),
+            }
+        }
+    }
+    impl OptionFromWasmAbi for Thang {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl<'a> IntoWasmAbi for &'a Thang {
+        type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+            (&self.obj).into_abi(extra)
+        }
+    }
+    impl RefFromWasmAbi for Thang {
+        type Abi = <JsValue as RefFromWasmAbi>::Abi;
+        type Anchor = ManuallyDrop<Thang>;
+        unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
+            let tmp = <JsValue as RefFromWasmAbi>::ref_from_abi(js, extra);
+            ManuallyDrop::new(Thang {
+                obj: ManuallyDrop::into_inner(tmp),
+            })
+        }
+    }
+    impl OptionRefFromWasmAbi for Thang {
+        fn is_none(abi: &Self::Abi) -> bool {
+            *abi == 0
+        }
+    }
+    impl From<JsValue> for Thang {
+        fn from(obj: JsValue) -> Thang {
+            Thang { obj }
+        }
+    }
+    impl AsRef<JsValue> for Thang {
+        fn as_ref(&self) -> &JsValue {
+            &self.obj
+        }
+    }
+    impl AsMut<JsValue> for Thang {
+        fn as_mut(&mut self) -> &mut JsValue {
+            &mut self.obj
+        }
+    }
+    impl From<Thang> for JsValue {
+        fn from(obj: Thang) -> JsValue {
+            obj.obj
+        }
+    }
+    impl JsCast for Thang {
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        fn instanceof(val: &JsValue) -> bool {
+            #[link(wasm_import_module = "__wbindgen_placeholder__")]
+            extern "C" {
+                fn __widl_instanceof_Thang(val: u32) -> u32;
+            }
+            unsafe {
+                let idx = val.into_abi(&mut ::wasm_bindgen::convert::GlobalStack::new());
+                __widl_instanceof_Thang(idx) != 0
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+        fn instanceof(val: &JsValue) -> bool {
+            drop(val);
+            panic!("cannot check instanceof on non-wasm targets");
+        }
+        fn unchecked_from_js(val: JsValue) -> Self {
+            Thang { obj: val }
+        }
+        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+            unsafe { &*(val as *const JsValue as *const Thang) }
+        }
+        fn unchecked_from_js_mut(val: &mut JsValue) -> &mut Self {
+            unsafe { &mut *(val as *mut JsValue as *mut Thang) }
+        }
+    }
+    ()
+};
+#[doc = "Describes the type of `__widl_f_new_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_new_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <i32 as WasmDescribe>::describe();
+    inform(1);
+    <Thang as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `new Thang(..)` constructor, creating a new instance of `Thang`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/Thang)"]
+    pub fn new(value: i32) -> Result<Thang, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_new_Thang(
+                value: <i32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <Thang as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let value = ::wasm_bindgen::__rt::arg_into_abi::<i32>(value, &mut __stack);
+                __widl_f_new_Thang(value, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<Thang>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `new Thang(..)` constructor, creating a new instance of `Thang`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/Thang)"]
+    pub fn new(value: i32) -> Result<Thang, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_ok_attr_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_ok_attr_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Thang as WasmDescribe>::describe();
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `ok_attr` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_attr)"]
+    pub fn ok_attr(&self) -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_ok_attr_Thang(
+                self_: <&Thang as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Thang>(self, &mut __stack);
+                __widl_f_ok_attr_Thang(self_, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<i32>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `ok_attr` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_attr)"]
+    pub fn ok_attr(&self) -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_ok_attr_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_ok_attr_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&Thang as WasmDescribe>::describe();
+    <i32 as WasmDescribe>::describe();
+    inform(0);
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `ok_attr` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_attr)"]
+    pub fn set_ok_attr(&self, ok_attr: i32) -> Result<(), ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_ok_attr_Thang(
+                self_: <&Thang as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                ok_attr: <i32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> ();
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Thang>(self, &mut __stack);
+                let ok_attr = ::wasm_bindgen::__rt::arg_into_abi::<i32>(ok_attr, &mut __stack);
+                __widl_f_set_ok_attr_Thang(self_, ok_attr, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(())
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `ok_attr` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_attr)"]
+    pub fn set_ok_attr(&self, ok_attr: i32) -> Result<(), ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_err_attr_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_err_attr_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Thang as WasmDescribe>::describe();
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `err_attr` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_attr)"]
+    pub fn err_attr(&self) -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_err_attr_Thang(
+                self_: <&Thang as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Thang>(self, &mut __stack);
+                __widl_f_err_attr_Thang(self_, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<i32>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `err_attr` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_attr)"]
+    pub fn err_attr(&self) -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_err_attr_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_err_attr_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(2u32);
+    <&Thang as WasmDescribe>::describe();
+    <i32 as WasmDescribe>::describe();
+    inform(0);
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `err_attr` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_attr)"]
+    pub fn set_err_attr(&self, err_attr: i32) -> Result<(), ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_err_attr_Thang(
+                self_: <&Thang as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                err_attr: <i32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> ();
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Thang>(self, &mut __stack);
+                let err_attr = ::wasm_bindgen::__rt::arg_into_abi::<i32>(err_attr, &mut __stack);
+                __widl_f_set_err_attr_Thang(self_, err_attr, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(())
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `err_attr` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_attr)"]
+    pub fn set_err_attr(&self, err_attr: i32) -> Result<(), ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_ok_method_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_ok_method_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Thang as WasmDescribe>::describe();
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `ok_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_method)"]
+    pub fn ok_method(&self) -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_ok_method_Thang(
+                self_: <&Thang as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Thang>(self, &mut __stack);
+                __widl_f_ok_method_Thang(self_, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<i32>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `ok_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_method)"]
+    pub fn ok_method(&self) -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_err_method_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_err_method_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <&Thang as WasmDescribe>::describe();
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `err_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_method)"]
+    pub fn err_method(&self) -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_err_method_Thang(
+                self_: <&Thang as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let self_ = ::wasm_bindgen::__rt::arg_into_abi::<&Thang>(self, &mut __stack);
+                __widl_f_err_method_Thang(self_, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<i32>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `err_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_method)"]
+    pub fn err_method(&self) -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_ok_static_method_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_ok_static_method_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `ok_static_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_static_method)"]
+    pub fn ok_static_method() -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_ok_static_method_Thang(
+                exn_data_ptr: *mut u32,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_ok_static_method_Thang(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<i32>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `ok_static_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_static_method)"]
+    pub fn ok_static_method() -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_err_static_method_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_err_static_method_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `err_static_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_static_method)"]
+    pub fn err_static_method() -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_err_static_method_Thang(
+                exn_data_ptr: *mut u32,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_err_static_method_Thang(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<i32>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `err_static_method()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_static_method)"]
+    pub fn err_static_method() -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_ok_static_attr_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_ok_static_attr_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `ok_static_attr` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_static_attr)"]
+    pub fn ok_static_attr() -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_ok_static_attr_Thang(
+                exn_data_ptr: *mut u32,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_ok_static_attr_Thang(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<i32>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `ok_static_attr` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_static_attr)"]
+    pub fn ok_static_attr() -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_ok_static_attr_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_ok_static_attr_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <i32 as WasmDescribe>::describe();
+    inform(0);
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `ok_static_attr` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_static_attr)"]
+    pub fn set_ok_static_attr(ok_static_attr: i32) -> Result<(), ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_ok_static_attr_Thang(
+                ok_static_attr: <i32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> ();
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let ok_static_attr =
+                    ::wasm_bindgen::__rt::arg_into_abi::<i32>(ok_static_attr, &mut __stack);
+                __widl_f_set_ok_static_attr_Thang(ok_static_attr, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(())
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `ok_static_attr` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/ok_static_attr)"]
+    pub fn set_ok_static_attr(ok_static_attr: i32) -> Result<(), ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_err_static_attr_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_err_static_attr_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(0u32);
+    inform(1);
+    <i32 as WasmDescribe>::describe();
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `err_static_attr` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_static_attr)"]
+    pub fn err_static_attr() -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_err_static_attr_Thang(
+                exn_data_ptr: *mut u32,
+            ) -> <i32 as ::wasm_bindgen::convert::FromWasmAbi>::Abi;
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                __widl_f_err_static_attr_Thang(exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(::wasm_bindgen::__rt::from_return_abi::<i32>(_ret))
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `err_static_attr` getter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_static_attr)"]
+    pub fn err_static_attr() -> Result<i32, ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[doc = "Describes the type of `__widl_f_set_err_static_attr_Thang` to the `wasm-bindgen` CLI, which runs this while generating bindings and then removes it from the wasm."]
+#[no_mangle]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+pub extern "C" fn __wbindgen_describe___widl_f_set_err_static_attr_Thang() {
+    use wasm_bindgen::describe::*;
+    inform(FUNCTION);
+    inform(1u32);
+    <i32 as WasmDescribe>::describe();
+    inform(0);
+}
+impl Thang {
+    #[allow(bad_style)]
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[doc = "The `err_static_attr` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_static_attr)"]
+    pub fn set_err_static_attr(err_static_attr: i32) -> Result<(), ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::link_mem_intrinsics();
+        #[link(wasm_import_module = "__wbindgen_placeholder__")]
+        extern "C" {
+            fn __widl_f_set_err_static_attr_Thang(
+                err_static_attr: <i32 as ::wasm_bindgen::convert::IntoWasmAbi>::Abi,
+                exn_data_ptr: *mut u32,
+            ) -> ();
+        }
+        unsafe {
+            let mut exn_data = [0; 2];
+            let exn_data_ptr = exn_data.as_mut_ptr();
+            let _ret = {
+                let mut __stack = ::wasm_bindgen::convert::GlobalStack::new();
+                let err_static_attr =
+                    ::wasm_bindgen::__rt::arg_into_abi::<i32>(err_static_attr, &mut __stack);
+                __widl_f_set_err_static_attr_Thang(err_static_attr, exn_data_ptr)
+            };
+            ::wasm_bindgen::__rt::take_exception(&exn_data)?;
+            Ok(())
+        }
+    }
+    #[allow(bad_style, unused_variables)]
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
+    #[doc = "The `err_static_attr` setter\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Thang/err_static_attr)"]
+    pub fn set_err_static_attr(err_static_attr: i32) -> Result<(), ::wasm_bindgen::JsValue> {
+        ::wasm_bindgen::__rt::import_on_non_wasm()
+    }
+}
+#[allow(non_upper_case_globals)]
+#[cfg(target_arch = "wasm32")]
+#[link_section = "__wasm_bindgen_unstable"]
+#[doc(hidden)]
+pub static __WASM_BINDGEN_GENERATED_bfce4f24326f3401 : [ u8 ; 5244usize ] = * b"x\x14\0\0{\"exports\":[],\"enums\":[],\"imports\":[{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"type\",\"name\":\"Thang\",\"rust_name\":\"Thang\",\"instanceof_shim\":\"__widl_instanceof_Thang\",\"shared_class\":null,\"vendor_prefixes\":[],\"polyfill\":null}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_new_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":\"Constructor\"},\"structural\":false,\"function\":{\"name\":\"new\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_ok_attr_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"ok_attr\"}}}},\"structural\":false,\"function\":{\"name\":\"ok_attr\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_ok_attr_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Setter\":\"ok_attr\"}}}},\"structural\":false,\"function\":{\"name\":\"set_ok_attr\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_err_attr_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Getter\":\"err_attr\"}}}},\"structural\":false,\"function\":{\"name\":\"err_attr\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_err_attr_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":{\"Setter\":\"err_attr\"}}}},\"structural\":false,\"function\":{\"name\":\"set_err_attr\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_ok_method_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"ok_method\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_err_method_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":false,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"err_method\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_ok_static_method_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"ok_static_method\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_err_static_method_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":\"Regular\"}}},\"structural\":false,\"function\":{\"name\":\"err_static_method\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_ok_static_attr_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":{\"Getter\":\"ok_static_attr\"}}}},\"structural\":false,\"function\":{\"name\":\"ok_static_attr\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_ok_static_attr_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":{\"Setter\":\"ok_static_attr\"}}}},\"structural\":false,\"function\":{\"name\":\"set_ok_static_attr\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_err_static_attr_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":{\"Getter\":\"err_static_attr\"}}}},\"structural\":false,\"function\":{\"name\":\"err_static_attr\"}}},{\"module\":null,\"node_module\":null,\"js_namespace\":null,\"kind\":{\"kind\":\"function\",\"shim\":\"__widl_f_set_err_static_attr_Thang\",\"catch\":true,\"assert_no_shim\":false,\"optional_args\":false,\"variadic\":false,\"vendor_prefixes\":[],\"polyfill\":null,\"method\":{\"class\":\"Thang\",\"kind\":{\"Operation\":{\"is_static\":true,\"kind\":{\"Setter\":\"err_static_attr\"}}}},\"structural\":false,\"function\":{\"name\":\"set_err_static_attr\"}}}],\"structs\":[],\"consts\":[],\"version\":\"VERSION\",\"schema_version\":\"31\",\"stable_abi\":null}" ;
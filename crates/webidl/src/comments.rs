@@ -0,0 +1,216 @@
+//! Collecting the doc comments written in WebIDL source.
+//!
+//! The WebIDL parser throws comments away, so they're collected by a separate,
+//! much simpler, scan of the source text which only keeps track of which
+//! definition and member each `/** ... */` (or `///`) comment precedes.
+
+use std::collections::BTreeMap;
+
+/// The doc comments in some WebIDL source, keyed by the name of the
+/// definition they're on and, for comments on members, the member's name.
+#[derive(Default)]
+pub(crate) struct IdlComments {
+    comments: BTreeMap<(String, Option<String>), String>,
+}
+
+impl IdlComments {
+    /// Collects the doc comments in `source`.
+    pub(crate) fn parse(source: &str) -> IdlComments {
+        let mut comments = IdlComments::default();
+        let mut definition = None;
+        let mut depth = 0;
+        let mut brackets = 0;
+        let mut doc: Option<String> = None;
+        let mut statement = String::new();
+
+        let mut rest = source;
+        while let Some(c) = rest.chars().next() {
+            // Like in Rust, `/***` and `////` start regular comments, which
+            // are typically banners rather than documentation.
+            if rest.starts_with("/**") && !rest.starts_with("/**/") && !rest.starts_with("/***") {
+                let (text, end) = match rest[3..].find("*/") {
+                    Some(i) => (&rest[3..i + 3], i + 5),
+                    None => (&rest[3..], rest.len()),
+                };
+                push_doc(&mut doc, clean_block(text));
+                rest = &rest[end..];
+                continue;
+            }
+            if rest.starts_with("///") && !rest.starts_with("////") {
+                let end = rest.find('\n').unwrap_or(rest.len());
+                push_doc(&mut doc, rest[3..end].trim().to_string());
+                rest = &rest[end..];
+                continue;
+            }
+            if rest.starts_with("//") {
+                rest = &rest[rest.find('\n').unwrap_or(rest.len())..];
+                continue;
+            }
+            if rest.starts_with("/*") {
+                rest = &rest[rest.find("*/").map(|i| i + 2).unwrap_or(rest.len())..];
+                continue;
+            }
+            if c == '"' {
+                // Strings only appear in enum values and default values, so
+                // just skip over them so that they don't affect the scan.
+                let end = rest[1..].find('"').map(|i| i + 2).unwrap_or(rest.len());
+                rest = &rest[end..];
+                continue;
+            }
+            rest = &rest[c.len_utf8()..];
+
+            // Extended attributes are left out of the statements, since all
+            // that's needed from them is the names they declare.
+            match c {
+                '[' => {
+                    brackets += 1;
+                    continue;
+                }
+                ']' => {
+                    brackets -= 1;
+                    continue;
+                }
+                _ if brackets > 0 => continue,
+                '{' => {
+                    if depth == 0 {
+                        definition = definition_name(&statement);
+                        if let (Some(name), Some(doc)) = (&definition, doc.take()) {
+                            comments.insert(name, None, doc);
+                        }
+                        statement.clear();
+                    }
+                    depth += 1;
+                    continue;
+                }
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        definition = None;
+                        doc = None;
+                        statement.clear();
+                    }
+                    continue;
+                }
+                ';' => {
+                    if depth == 1 {
+                        if let (Some(name), Some(member), Some(doc)) =
+                            (&definition, member_name(&statement), doc.take())
+                        {
+                            comments.insert(name, Some(member), doc);
+                        }
+                    }
+                    doc = None;
+                    statement.clear();
+                    continue;
+                }
+                _ => {}
+            }
+            statement.push(c);
+        }
+
+        comments
+    }
+
+    /// The doc comment on the definition `name`, or on its member `member`.
+    pub(crate) fn get(&self, name: &str, member: Option<&str>) -> Option<&str> {
+        self.comments
+            .get(&(name.to_string(), member.map(|m| m.to_string())))
+            .map(|s| &s[..])
+    }
+
+    fn insert(&mut self, name: &str, member: Option<String>, doc: String) {
+        if doc.is_empty() {
+            return;
+        }
+        // Partial definitions can document the same thing again, in which
+        // case both comments are kept.
+        let entry = self
+            .comments
+            .entry((name.to_string(), member))
+            .or_insert_with(String::new);
+        if !entry.is_empty() {
+            entry.push_str("\n\n");
+        }
+        entry.push_str(&doc);
+    }
+}
+
+fn push_doc(doc: &mut Option<String>, text: String) {
+    match doc {
+        Some(doc) => {
+            doc.push('\n');
+            doc.push_str(&text);
+        }
+        None => *doc = Some(text),
+    }
+}
+
+/// Removes the leading `*` decorations and the indentation from the lines of
+/// a block comment.
+fn clean_block(text: &str) -> String {
+    let lines = text
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = if line.starts_with('*') { &line[1..] } else { line };
+            if line.starts_with(' ') { &line[1..] } else { line }
+        })
+        .collect::<Vec<_>>();
+    lines.join("\n").trim().to_string()
+}
+
+/// The identifiers in some WebIDL text, ignoring type parameters.
+fn identifiers(text: &str) -> Vec<&str> {
+    let mut idents = Vec::new();
+    let mut generics = 0;
+    let mut start = None;
+    for (i, c) in text.char_indices().chain(Some((text.len(), ' '))) {
+        if c.is_alphanumeric() || c == '_' {
+            if start.is_none() {
+                start = Some(i);
+            }
+            continue;
+        }
+        if let Some(start) = start.take() {
+            if generics == 0 {
+                idents.push(&text[start..i]);
+            }
+        }
+        match c {
+            '<' => generics += 1,
+            '>' if generics > 0 => generics -= 1,
+            _ => {}
+        }
+    }
+    idents
+}
+
+/// The name of the definition whose header is `header`, like
+/// `partial interface Foo` or `dictionary Bar : Baz`.
+fn definition_name(header: &str) -> Option<String> {
+    let header = header.split(':').next().unwrap();
+    identifiers(header).last().map(|name| name.trim_left_matches('_').to_string())
+}
+
+/// The name of the member declared by `statement`, like `attribute long bar`
+/// or `void baz(long qux)`.
+fn member_name(statement: &str) -> Option<String> {
+    let declaration = match statement.find(|c| c == '(' || c == '=') {
+        Some(i) => &statement[..i],
+        None => statement,
+    };
+    let idents = identifiers(declaration);
+    match idents.first() {
+        // Special operations can be unnamed, and there's no telling their
+        // return type apart from their name, so they're skipped entirely.
+        Some(&"getter") | Some(&"setter") | Some(&"deleter") | Some(&"legacycaller") => {
+            return None
+        }
+        Some(&"iterable") | Some(&"maplike") | Some(&"setlike") => return None,
+        _ => {}
+    }
+    if idents.len() < 2 {
+        return None;
+    }
+    idents.last().map(|name| name.trim_left_matches('_').to_string())
+}
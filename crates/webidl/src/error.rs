@@ -19,6 +19,12 @@ pub enum ErrorKind {
     /// Failed to parse a WebIDL file.
     #[fail(display="parsing WebIDL source text")]
     ParsingWebIDLSource,
+    /// Failed to format the generated Rust.
+    #[fail(display="formatting the generated Rust with rustfmt")]
+    FormattingRust,
+    /// Some of the WebIDL was skipped, which `Options::strict` doesn't allow.
+    #[fail(display="generating bindings for all of the WebIDL, as strict mode requires")]
+    SkippedWebIDL,
 }
 
 /// The error type for this crate.
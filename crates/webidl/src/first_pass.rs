@@ -7,6 +7,7 @@
 //! Only `interface`s, `dictionary`s, `enum`s and `mixin`s can
 //! be partial.
 
+use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet};
 
 use weedle::{DictionaryDefinition, PartialDictionaryDefinition};
@@ -18,6 +19,7 @@ use weedle::namespace::NamespaceMember;
 use weedle;
 
 use super::Result;
+use comments::IdlComments;
 use util;
 use util::camel_case_ident;
 
@@ -32,6 +34,14 @@ pub(crate) struct FirstPassRecord<'src> {
     pub(crate) namespaces: BTreeMap<&'src str, NamespaceData<'src>>,
     pub(crate) includes: BTreeMap<&'src str, BTreeSet<&'src str>>,
     pub(crate) dictionaries: BTreeMap<&'src str, DictionaryData<'src>>,
+    /// The doc comments written in the WebIDL.
+    pub(crate) comments: IdlComments,
+    /// The template for links to the documentation of the generated items,
+    /// see `Options::doc_url`.
+    pub(crate) doc_url: Option<String>,
+    /// Why each unsupported piece of WebIDL has no bindings, for
+    /// `Options::strict`.
+    pub(crate) skipped: RefCell<BTreeSet<String>>,
 }
 
 /// We need to collect interface data during the first pass, to be used later.
@@ -549,6 +559,13 @@ impl<'src> FirstPass<'src, &'src str> for weedle::namespace::OperationNamespaceM
 }
 
 impl<'a> FirstPassRecord<'a> {
+    /// Warns that no bindings are generated for something, and why, and
+    /// remembers it in case that's an error.
+    pub(crate) fn skip(&self, why: String) {
+        warn!("{}", why);
+        self.skipped.borrow_mut().insert(why);
+    }
+
     pub fn all_superclasses<'me>(&'me self, interface: &str)
         -> impl Iterator<Item = String> + 'me
     {
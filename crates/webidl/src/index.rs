@@ -0,0 +1,160 @@
+//! An index of the API generated from WebIDL, for finding the Rust item
+//! generated for some JS API.
+
+use std::collections::BTreeSet;
+
+use backend::ast;
+use backend::defined::{ImportedTypeDefinitions, ImportedTypeReferences};
+use proc_macro2::Ident;
+use syn;
+
+/// An item generated from WebIDL, see `Options::api_index`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ApiItem {
+    /// How the item is used in JS, like `Document.prototype.createElement()`
+    /// or `new Event()`.
+    pub js: String,
+    /// The path of the generated Rust item, relative to the module the
+    /// bindings are generated into, like `Document::create_element`.
+    pub rust: String,
+    /// The generated types which are used by the item, sorted by name.
+    pub types: Vec<String>,
+}
+
+/// Lists every item generated for `program`.
+pub(crate) fn index(program: &ast::Program) -> Vec<ApiItem> {
+    let mut defined = BTreeSet::new();
+    program.imported_type_definitions(&mut |id| {
+        defined.insert(id.to_string());
+    });
+
+    let mut items = Vec::new();
+    let imports = program.imports.iter().map(|import| (None, import));
+    let module_imports = program
+        .modules
+        .iter()
+        .flat_map(|m| m.imports.iter().map(move |import| (Some(&m.name), import)));
+    for (module, import) in imports.chain(module_imports) {
+        match &import.kind {
+            ast::ImportKind::Type(ty) => items.push(ApiItem {
+                js: ty.js_name.clone(),
+                rust: ty.rust_name.to_string(),
+                types: vec![ty.rust_name.to_string()],
+            }),
+            ast::ImportKind::Enum(e) => items.push(ApiItem {
+                js: e.name.to_string(),
+                rust: e.name.to_string(),
+                types: vec![e.name.to_string()],
+            }),
+            ast::ImportKind::Function(f) => {
+                let (js, rust) = function_names(module, import, f);
+                items.push(ApiItem {
+                    js,
+                    rust,
+                    types: types(&defined, f),
+                });
+            }
+            ast::ImportKind::Static(_) => {}
+        }
+    }
+    for d in program.dictionaries.iter() {
+        items.push(ApiItem {
+            js: d.name.to_string(),
+            rust: d.name.to_string(),
+            types: types(&defined, d),
+        });
+        for field in d.fields.iter() {
+            let mut types = types(&defined, field);
+            types.push(d.name.to_string());
+            types.sort();
+            types.dedup();
+            items.push(ApiItem {
+                js: format!("{}.{}", d.name, field.name),
+                rust: format!("{}::{}", d.name, field.name),
+                types,
+            });
+        }
+    }
+    for c in program.consts.iter() {
+        let class = match &c.class {
+            Some(class) => class,
+            None => continue,
+        };
+        items.push(ApiItem {
+            js: format!("{}.{}", class, c.name),
+            rust: format!("{}::{}", class, c.name),
+            types: vec![class.to_string()],
+        });
+    }
+    items
+}
+
+/// The types defined in `defined` which `item` refers to.
+fn types<T: ImportedTypeReferences>(defined: &BTreeSet<String>, item: &T) -> Vec<String> {
+    let mut types = BTreeSet::new();
+    item.imported_type_references(&mut |id| {
+        let id = id.to_string();
+        if defined.contains(&id) {
+            types.insert(id);
+        }
+    });
+    types.into_iter().collect()
+}
+
+/// The JS and Rust names of an imported function.
+fn function_names(
+    module: Option<&Ident>,
+    import: &ast::Import,
+    f: &ast::ImportFunction,
+) -> (String, String) {
+    let name = &f.function.name;
+    let (class, ty, kind) = match &f.kind {
+        ast::ImportFunctionKind::Method { class, ty, kind } => (class, ty, kind),
+        ast::ImportFunctionKind::Normal => {
+            let js = match &import.js_namespace {
+                Some(ns) => format!("{}.{}()", ns, name),
+                None => format!("{}()", name),
+            };
+            let rust = match module {
+                Some(module) => format!("{}::{}", module, f.rust_name),
+                None => f.rust_name.to_string(),
+            };
+            return (js, rust);
+        }
+    };
+    let rust = format!("{}::{}", type_name(ty), f.rust_name);
+    let op = match kind {
+        ast::MethodKind::Constructor => return (format!("new {}()", class), rust),
+        ast::MethodKind::Operation(op) => op,
+    };
+    let this = if op.is_static {
+        class.to_string()
+    } else {
+        format!("{}.prototype", class)
+    };
+    let js = match &op.kind {
+        ast::OperationKind::Regular => format!("{}.{}()", this, name),
+        ast::OperationKind::Getter(prop) => {
+            format!("{}.{}", this, prop.as_ref().map(|p| p.to_string()).unwrap_or(name.clone()))
+        }
+        ast::OperationKind::Setter(prop) => format!(
+            "{}.{} =",
+            this,
+            prop.as_ref().map(|p| p.to_string()).unwrap_or(name.clone())
+        ),
+        ast::OperationKind::IndexingGetter => format!("{}[]", this),
+        ast::OperationKind::IndexingSetter => format!("{}[] =", this),
+        ast::OperationKind::IndexingDeleter => format!("delete {}[]", this),
+    };
+    (js, rust)
+}
+
+fn type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => segment.value().ident.to_string(),
+            None => String::new(),
+        },
+        _ => String::new(),
+    }
+}
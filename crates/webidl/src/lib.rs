@@ -20,20 +20,26 @@ extern crate proc_macro2;
 #[macro_use]
 extern crate quote;
 #[macro_use]
+extern crate serde_derive;
+#[macro_use]
 extern crate syn;
 extern crate wasm_bindgen_backend as backend;
 extern crate weedle;
 
+mod comments;
 mod first_pass;
 mod idl_type;
+mod index;
 mod util;
 mod error;
 
+use std::cell::RefCell;
 use std::collections::BTreeSet;
 use std::fs;
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 use std::iter::FromIterator;
 use std::path::Path;
+use std::process::{Command, Stdio};
 
 use backend::ast;
 use backend::TryToTokens;
@@ -48,22 +54,116 @@ use weedle::attribute::{ExtendedAttribute, ExtendedAttributeList};
 use weedle::dictionary::DictionaryMember;
 
 use first_pass::{FirstPass, FirstPassRecord, OperationId};
-use util::{public, webidl_const_v_to_backend_const_v, TypePosition, camel_case_ident, mdn_doc};
+use comments::IdlComments;
+use util::{public, webidl_const_v_to_backend_const_v, TypePosition, camel_case_ident};
 use idl_type::{IdlType, ToIdlType};
 
 pub use error::{Error, ErrorKind, Result};
+pub use index::ApiItem;
+
+/// The template of `Options::doc_url` used by default, linking to MDN.
+const MDN_DOC_URL: &str = "https://developer.mozilla.org/en-US/docs/Web/API/{path}";
+
+/// Options controlling the Rust generated from WebIDL.
+///
+/// `compile` and `compile_file` use the default options, this can be used
+/// to change them.
+#[derive(Debug, Clone)]
+pub struct Options {
+    doc_url: Option<String>,
+    rustfmt: bool,
+    strict: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            doc_url: Some(MDN_DOC_URL.to_string()),
+            rustfmt: false,
+            strict: false,
+        }
+    }
+}
+
+impl Options {
+    /// Creates the default options.
+    pub fn new() -> Options {
+        Options::default()
+    }
+
+    /// Sets the template for the links to documentation added to the doc
+    /// comment of each generated item, or disables the links with `None`.
+    ///
+    /// `{path}` in the template is replaced with the name of the interface
+    /// for the interface itself and with `Interface/member` for its
+    /// members, which is how MDN's URLs are structured. The default links to
+    /// MDN.
+    pub fn doc_url(&mut self, template: Option<&str>) -> &mut Options {
+        self.doc_url = template.map(|s| s.to_string());
+        self
+    }
+
+    /// Sets whether the generated Rust is formatted with `rustfmt`, which
+    /// must then be installed. Without it the generated Rust is all on one
+    /// line, which is fine for compiling it but not for reading it.
+    pub fn rustfmt(&mut self, rustfmt: bool) -> &mut Options {
+        self.rustfmt = rustfmt;
+        self
+    }
+
+    /// Sets whether compiling fails if any of the WebIDL can't have bindings
+    /// generated for it, for example because it uses a type which isn't
+    /// supported yet. The error lists everything that was skipped and why.
+    ///
+    /// By default unsupported WebIDL is skipped with a warning, so that the
+    /// rest of it can still be used.
+    pub fn strict(&mut self, strict: bool) -> &mut Options {
+        self.strict = strict;
+        self
+    }
+
+    /// Compile the given WebIDL file into Rust source text containing
+    /// `wasm-bindgen` bindings to the things described in the WebIDL.
+    pub fn compile_file(&self, webidl_path: &Path) -> Result<String> {
+        let (ast, skipped) = parse_file(webidl_path, self)?;
+        compile_ast(ast, skipped, self)
+    }
 
-/// Parse the WebIDL at the given path into a wasm-bindgen AST.
-fn parse_file(webidl_path: &Path) -> Result<backend::ast::Program> {
+    /// Compile the given WebIDL source text into Rust source text containing
+    /// `wasm-bindgen` bindings to the things described in the WebIDL.
+    pub fn compile(&self, webidl_source: &str) -> Result<String> {
+        let (ast, skipped) = parse(webidl_source, self)?;
+        compile_ast(ast, skipped, self)
+    }
+
+    /// Lists the items which `compile` generates for the given WebIDL
+    /// source text, along with the JS API each of them binds to.
+    pub fn api_index(&self, webidl_source: &str) -> Result<Vec<ApiItem>> {
+        let (mut ast, mut skipped) = parse(webidl_source, self)?;
+        remove_undefined_imports(&mut ast, &mut skipped);
+        Ok(index::index(&ast))
+    }
+}
+
+/// Parse the WebIDL at the given path into a wasm-bindgen AST, along with why
+/// any of it was skipped.
+fn parse_file(
+    webidl_path: &Path,
+    options: &Options,
+) -> Result<(backend::ast::Program, BTreeSet<String>)> {
     let file = fs::File::open(webidl_path).context(ErrorKind::OpeningWebIDLFile)?;
     let mut file = io::BufReader::new(file);
     let mut source = String::new();
     file.read_to_string(&mut source).context(ErrorKind::ReadingWebIDLFile)?;
-    parse(&source)
+    parse(&source, options)
 }
 
-/// Parse a string of WebIDL source text into a wasm-bindgen AST.
-fn parse(webidl_source: &str) -> Result<backend::ast::Program> {
+/// Parse a string of WebIDL source text into a wasm-bindgen AST, along with
+/// why any of it was skipped.
+fn parse(
+    webidl_source: &str,
+    options: &Options,
+) -> Result<(backend::ast::Program, BTreeSet<String>)> {
     let definitions = match weedle::parse(webidl_source) {
         Ok(def) => def,
         Err(e) => {
@@ -92,30 +192,60 @@ fn parse(webidl_source: &str) -> Result<backend::ast::Program> {
         }
     };
 
-    let mut first_pass_record = Default::default();
+    let mut first_pass_record = FirstPassRecord {
+        comments: IdlComments::parse(webidl_source),
+        doc_url: options.doc_url.clone(),
+        ..Default::default()
+    };
     definitions.first_pass(&mut first_pass_record, ())?;
     let mut program = Default::default();
     definitions.webidl_parse(&mut program, &first_pass_record, ())?;
 
-    Ok(program)
+    Ok((program, first_pass_record.skipped.into_inner()))
 }
 
 /// Compile the given WebIDL file into Rust source text containing
 /// `wasm-bindgen` bindings to the things described in the WebIDL.
 pub fn compile_file(webidl_path: &Path) -> Result<String> {
-    let ast = parse_file(webidl_path)?;
-    Ok(compile_ast(ast))
+    Options::new().compile_file(webidl_path)
 }
 
 /// Compile the given WebIDL source text into Rust source text containing
 /// `wasm-bindgen` bindings to the things described in the WebIDL.
 pub fn compile(webidl_source: &str) -> Result<String> {
-    let ast = parse(webidl_source)?;
-    Ok(compile_ast(ast))
+    Options::new().compile(webidl_source)
 }
 
 /// Run codegen on the AST to generate rust code.
-fn compile_ast(mut ast: backend::ast::Program) -> String {
+fn compile_ast(
+    mut ast: backend::ast::Program,
+    mut skipped: BTreeSet<String>,
+    options: &Options,
+) -> Result<String> {
+    remove_undefined_imports(&mut ast, &mut skipped);
+    if options.strict && !skipped.is_empty() {
+        let mut list = String::from("no bindings were generated for:");
+        for why in skipped {
+            list.push_str("\n  ");
+            list.push_str(&why);
+        }
+        return Err(format_err!("{}", list).context(ErrorKind::SkippedWebIDL).into());
+    }
+
+    let mut tokens = proc_macro2::TokenStream::new();
+    if let Err(e) = ast.try_to_tokens(&mut tokens) {
+        e.panic();
+    }
+    if options.rustfmt {
+        rustfmt(&tokens.to_string())
+    } else {
+        Ok(tokens.to_string())
+    }
+}
+
+/// Removes everything which can't be generated from the AST, noting the
+/// undefined types responsible in `skipped`.
+fn remove_undefined_imports(ast: &mut backend::ast::Program, skipped: &mut BTreeSet<String>) {
     // Iteratively prune all entries from the AST which reference undefined
     // fields. Each pass may remove definitions of types and so we need to
     // reexecute this pass to see if we need to keep removing types until we
@@ -128,21 +258,48 @@ fn compile_ast(mut ast: backend::ast::Program) -> String {
         ].into_iter()
             .map(|id| proc_macro2::Ident::new(id, proc_macro2::Span::call_site())),
     );
+    let undefined = RefCell::new(BTreeSet::new());
     loop {
         let mut defined = builtin.clone();
         ast.imported_type_definitions(&mut |id| {
             defined.insert(id.clone());
         });
-        if !ast.remove_undefined_imports(&|id| defined.contains(id)) {
+        let is_defined = |id: &Ident| {
+            if defined.contains(id) {
+                return true
+            }
+            undefined.borrow_mut().insert(id.to_string());
+            false
+        };
+        if !ast.remove_undefined_imports(&is_defined) {
             break
         }
     }
+    for id in undefined.into_inner() {
+        skipped.insert(format!("everything using the undefined type `{}`", id));
+    }
+}
 
-    let mut tokens = proc_macro2::TokenStream::new();
-    if let Err(e) = ast.try_to_tokens(&mut tokens) {
-        e.panic();
+/// Formats `rust` by piping it through `rustfmt`.
+fn rustfmt(rust: &str) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context(ErrorKind::FormattingRust)?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(rust.as_bytes())
+        .context(ErrorKind::FormattingRust)?;
+    let output = child.wait_with_output().context(ErrorKind::FormattingRust)?;
+    if !output.status.success() {
+        return Err(format_err!("rustfmt failed: {}", output.status)
+            .context(ErrorKind::FormattingRust)
+            .into());
     }
-    tokens.to_string()
+    Ok(String::from_utf8(output.stdout).context(ErrorKind::FormattingRust)?)
 }
 
 /// The main trait for parsing WebIDL AST into wasm-bindgen AST.
@@ -206,11 +363,14 @@ impl<'src> WebidlParse<'src, ()> for weedle::Definition<'src> {
             }
 
             // TODO
-            weedle::Definition::Callback(..) => {
-                warn!("Unsupported WebIDL Callback definition: {:?}", self)
+            weedle::Definition::Callback(callback) => {
+                first_pass.skip(format!("unsupported callback `{}`", callback.identifier.0))
             }
-            weedle::Definition::CallbackInterface(..) => {
-                warn!("Unsupported WebIDL CallbackInterface definition: {:?}", self)
+            weedle::Definition::CallbackInterface(callback) => {
+                first_pass.skip(format!(
+                    "unsupported callback interface `{}`",
+                    callback.identifier.0,
+                ))
             }
         }
         Ok(())
@@ -234,14 +394,15 @@ impl<'src> WebidlParse<'src, ()> for weedle::InterfaceDefinition<'src> {
             return Ok(());
         }
 
-        let doc_comment = Some(format!(
-            "The `{}` object\n\n{}",
+        let doc_comment = Some(first_pass.doc_comment(
+            format!("The `{}` object", self.identifier.0),
             self.identifier.0,
-            mdn_doc(self.identifier.0, None),
+            None,
         ));
 
         program.imports.push(backend::ast::Import {
             module: None,
+            node_module: None,
             js_namespace: None,
             kind: backend::ast::ImportKind::Type(backend::ast::ImportType {
                 vis: public(),
@@ -253,6 +414,12 @@ impl<'src> WebidlParse<'src, ()> for weedle::InterfaceDefinition<'src> {
                 extends: first_pass.all_superclasses(self.identifier.0)
                     .map(|name| Ident::new(&name, Span::call_site()))
                     .collect(),
+                shared_class: None,
+                vendor_prefixes: util::vendor_prefixes(&self.attributes)
+                    .into_iter()
+                    .map(rust_ident)
+                    .collect(),
+                polyfill: None,
             }),
         });
 
@@ -360,6 +527,16 @@ impl<'src> WebidlParse<'src, &'src weedle::InterfaceDefinition<'src>> for Extend
             // > exception**.
             let throws = true;
 
+            let doc_comment = first_pass.doc_comment(
+                format!(
+                    "The `new {}(..)` constructor, creating a new instance of `{}`",
+                    class,
+                    interface.identifier.0,
+                ),
+                interface.identifier.0,
+                Some(class),
+            );
+
             for import_function in first_pass.create_function(
                 "new",
                 overloaded,
@@ -372,7 +549,7 @@ impl<'src> WebidlParse<'src, &'src weedle::InterfaceDefinition<'src>> for Extend
                 kind,
                 structural,
                 throws,
-                None,
+                Some(doc_comment),
             ) {
                 program.imports.push(wrap_import_function(import_function));
             }
@@ -431,6 +608,11 @@ impl<'src> WebidlParse<'src, &'src weedle::InterfaceDefinition<'src>> for Extend
             // We handle this elsewhere
             ExtendedAttribute::NoArgs(id) if (id.0).0 == "Unforgeable" => {}
 
+            // Not part of the spec, added to our copies of the WebIDL for
+            // interfaces which older browsers only ship with a prefixed name,
+            // and handled when the type is imported.
+            ExtendedAttribute::Ident(id) if id.lhs_identifier.0 == "VendorPrefix" => {}
+
             // Looks like this attribute just says that we can't call the
             // constructor
             // https://html.spec.whatwg.org/multipage/dom.html#htmlconstructor
@@ -473,15 +655,15 @@ impl<'src> WebidlParse<'src, &'src str> for weedle::interface::InterfaceMember<'
             }
             // TODO
             Maplike(_) => {
-                warn!("Unsupported WebIDL Maplike interface member: {:?}", self);
+                first_pass.skip(format!("unsupported maplike on `{}`", self_name));
                 Ok(())
             }
             Stringifier(_) => {
-                warn!("Unsupported WebIDL Stringifier interface member: {:?}", self);
+                first_pass.skip(format!("unsupported stringifier on `{}`", self_name));
                 Ok(())
             }
             Setlike(_) => {
-                warn!("Unsupported WebIDL Setlike interface member: {:?}", self);
+                first_pass.skip(format!("unsupported setlike on `{}`", self_name));
                 Ok(())
             }
         }
@@ -507,7 +689,7 @@ impl<'a, 'src> WebidlParse<'src, &'a str> for weedle::mixin::MixinMember<'src> {
             }
             // TODO
             weedle::mixin::MixinMember::Stringifier(_) => {
-                warn!("Unsupported WebIDL stringifier mixin member: {:?}", self);
+                first_pass.skip(format!("unsupported stringifier on `{}`", self_name));
                 Ok(())
             }
         }
@@ -576,7 +758,10 @@ fn member_attribute<'src>(
 
     let is_static = match modifier {
         Some(Stringifier(_)) => {
-            warn!("Unsupported stringifier on type: {:?}", (self_name, identifier));
+            first_pass.skip(format!(
+                "`{}.{}`: stringifier attributes are unsupported",
+                self_name, identifier,
+            ));
             return Ok(())
         }
         Some(Inherit(_)) => false,
@@ -585,7 +770,10 @@ fn member_attribute<'src>(
     };
 
     if type_.attributes.is_some() {
-        warn!("Unsupported attributes on type: {:?}", (self_name, identifier));
+        first_pass.skip(format!(
+            "`{}.{}`: extended attributes on its type are unsupported",
+            self_name, identifier,
+        ));
         return Ok(())
     }
 
@@ -680,9 +868,13 @@ fn member_operation<'src>(
         return Ok(());
     }
 
+    let name = identifier.map(|s| s.0).unwrap_or("<unnamed>");
     let is_static = match modifier {
         Some(Stringifier(_)) => {
-            warn!("Unsupported stringifier on type: {:?}", (self_name, identifier));
+            first_pass.skip(format!(
+                "`{}.{}`: stringifier operations are unsupported",
+                self_name, name,
+            ));
             return Ok(())
         }
         Some(Static(_)) => true,
@@ -693,11 +885,10 @@ fn member_operation<'src>(
         OperationId::Operation(identifier.map(|s| s.0)),
     ];
     if specials.len() > 1 {
-        warn!(
-            "Unsupported specials: ({:?}) on type {:?}",
-            specials,
-            (self_name, identifier),
-        );
+        first_pass.skip(format!(
+            "`{}.{}`: operations with more than one special are unsupported",
+            self_name, name,
+        ));
         return Ok(())
     } else if specials.len() == 1 {
         let id = match specials[0] {
@@ -705,7 +896,10 @@ fn member_operation<'src>(
             Special::Setter(weedle::term::Setter) => OperationId::IndexingSetter,
             Special::Deleter(weedle::term::Deleter) => OperationId::IndexingDeleter,
             Special::LegacyCaller(weedle::term::LegacyCaller) => {
-                warn!("Unsupported legacy caller: {:?}", (self_name, identifier));
+                first_pass.skip(format!(
+                    "`{}.{}`: legacy callers are unsupported",
+                    self_name, name,
+                ));
                 return Ok(());
             },
         };
@@ -800,6 +994,7 @@ impl<'src> WebidlParse<'src, ()> for weedle::EnumDefinition<'src> {
         let variants = &self.values.body.list;
         program.imports.push(backend::ast::Import {
             module: None,
+            node_module: None,
             js_namespace: None,
             kind: backend::ast::ImportKind::Enum(backend::ast::ImportEnum {
                 vis: public(),
@@ -841,12 +1036,10 @@ impl<'src> WebidlParse<'src, &'src str> for weedle::interface::ConstMember<'src>
 
         let ty = match idl_type.to_syn_type(TypePosition::Return) {
             None => {
-                warn!(
-                    "Cannot convert const type to syn type: {:?} in {:?} on {:?}",
-                    idl_type,
-                    self,
-                    self_name
-                );
+                first_pass.skip(format!(
+                    "`{}.{}`: unsupported constant type {:?}",
+                    self_name, self.identifier.0, idl_type,
+                ));
                 return Ok(());
             },
             Some(ty) => ty,
@@ -923,7 +1116,10 @@ impl<'src> WebidlParse<'src, (&'src str, &'src mut backend::ast::Module)> for we
                 op.webidl_parse(program, first_pass, (self_name, module))?;
             }
             weedle::namespace::NamespaceMember::Attribute(attr) => {
-                warn!("Unsupported attribute namespace member: {:?}", attr)
+                first_pass.skip(format!(
+                    "`{}.{}`: namespace attributes are unsupported",
+                    self_name, attr.identifier.0,
+                ))
             }
         }
         Ok(())
@@ -951,6 +1147,7 @@ impl<'src> WebidlParse<'src, (&'src str, &'src mut backend::ast::Module)> for we
             module.imports.push(
                 backend::ast::Import {
                     module: None,
+                    node_module: None,
                     js_namespace: Some(raw_ident(self_name)),
                     kind: backend::ast::ImportKind::Function(import_function),
                 }
@@ -979,9 +1176,18 @@ impl<'src> WebidlParse<'src, ()> for weedle::DictionaryDefinition<'src> {
             return Ok(())
         }
 
+        // MDN doesn't have pages for dictionaries, so unlike the other
+        // generated items these don't link to anything.
+        let mut doc_comment = format!("The `{}` dictionary", self.identifier.0);
+        if let Some(comment) = first_pass.comments.get(self.identifier.0, None) {
+            doc_comment.push_str("\n\n");
+            doc_comment.push_str(comment);
+        }
+
         program.dictionaries.push(ast::Dictionary {
             name: rust_ident(&camel_case_ident(self.identifier.0)),
             fields,
+            doc_comment: Some(doc_comment),
         });
 
         return Ok(());
@@ -1011,13 +1217,13 @@ impl<'src> WebidlParse<'src, ()> for weedle::DictionaryDefinition<'src> {
             let members = definition.members.body.iter();
             let partials = dict_data.partials.iter().flat_map(|d| &d.members.body);
             for member in members.chain(partials) {
-                match mkfield(data, member) {
+                match mkfield(data, dict, member) {
                     Some(f) => dst.push(f),
                     None => {
-                        warn!(
-                            "unsupported dictionary field {:?}",
-                            (dict, member.identifier.0),
-                        );
+                        data.skip(format!(
+                            "`{}.{}`: unsupported dictionary field type",
+                            dict, member.identifier.0,
+                        ));
                         // If this is required then we can't support the
                         // dictionary at all, but if it's not required we can
                         // avoid generating bindings for the field and keep
@@ -1039,6 +1245,7 @@ impl<'src> WebidlParse<'src, ()> for weedle::DictionaryDefinition<'src> {
 
         fn mkfield<'src>(
             data: &FirstPassRecord<'src>,
+            dict: &'src str,
             field: &'src DictionaryMember<'src>,
         ) -> Option<ast::DictionaryField> {
             // use argument position now as we're just binding setters
@@ -1063,10 +1270,17 @@ impl<'src> WebidlParse<'src, ()> for weedle::DictionaryDefinition<'src> {
                 return None
             }
 
+            let mut doc_comment = format!("Change the `{}` field of this object", field.identifier.0);
+            if let Some(comment) = data.comments.get(dict, Some(field.identifier.0)) {
+                doc_comment.push_str("\n\n");
+                doc_comment.push_str(comment);
+            }
+
             Some(ast::DictionaryField {
                 required: field.required.is_some(),
                 name: rust_ident(&field.identifier.0.to_snake_case()),
                 ty,
+                doc_comment: Some(doc_comment),
             })
         }
     }
@@ -7,7 +7,7 @@ use heck::{CamelCase, SnakeCase};
 use proc_macro2::Ident;
 use syn;
 use weedle;
-use weedle::attribute::{ExtendedAttributeList, ExtendedAttribute};
+use weedle::attribute::{ExtendedAttributeList, ExtendedAttribute, IdentifierOrString};
 use weedle::argument::Argument;
 use weedle::literal::{ConstValue, FloatLit, IntegerLit};
 
@@ -24,18 +24,42 @@ pub(crate) fn shared_ref(ty: syn::Type) -> syn::Type {
     }.into()
 }
 
-/// Fix camelcase of identifiers like HTMLBRElement
-pub fn camel_case_ident(identifier: &str) -> String {
-  identifier.replace("HTML", "HTML_").to_camel_case()
+/// Operations which write into the typed arrays they're passed, so whose typed
+/// array arguments are taken as `&mut [T]` for the writes to be seen in Rust.
+const FILLS_ARRAY_ARGUMENTS: &[(&str, &str)] = &[
+    ("AnalyserNode", "getByteFrequencyData"),
+    ("AnalyserNode", "getByteTimeDomainData"),
+    ("AnalyserNode", "getFloatFrequencyData"),
+    ("AnalyserNode", "getFloatTimeDomainData"),
+    ("AudioBuffer", "copyFromChannel"),
+];
+
+/// Turn a shared reference to a slice into a mutable one, leaving any other
+/// type as it is.
+fn mut_slice_ref(ty: syn::Type) -> syn::Type {
+    match ty {
+        syn::Type::Reference(mut reference) => {
+            if let syn::Type::Slice(_) = *reference.elem {
+                reference.mutability = Some(Default::default());
+            }
+            reference.into()
+        }
+        ty => ty,
+    }
 }
 
-// Returns a link to MDN
-pub fn mdn_doc(class: &str, method: Option<&str>) -> String {
-    let mut link = format!("https://developer.mozilla.org/en-US/docs/Web/API/{}", class);
-    if let Some(method) = method {
-        link.push_str(&format!("/{}", method));
+/// The JS name of an imported function, with its class for methods, for
+/// saying what was skipped.
+fn js_path(kind: &backend::ast::ImportFunctionKind, name: &str) -> String {
+    match kind {
+        backend::ast::ImportFunctionKind::Method { class, .. } => format!("{}.{}", class, name),
+        backend::ast::ImportFunctionKind::Normal => name.to_string(),
     }
-    format!("[Documentation]({})", link).into()
+}
+
+/// Fix camelcase of identifiers like HTMLBRElement
+pub fn camel_case_ident(identifier: &str) -> String {
+  identifier.replace("HTML", "HTML_").to_camel_case()
 }
 
 // Array type is borrowed for arguments (`&[T]`) and owned for return value (`Vec<T>`).
@@ -196,6 +220,42 @@ pub enum TypePosition {
 }
 
 impl<'src> FirstPassRecord<'src> {
+    /// Creates the doc comment of a generated item from a `summary` of what
+    /// it is, followed by the item's doc comment in the WebIDL, if any, and
+    /// a link to its documentation.
+    pub fn doc_comment(&self, summary: String, self_name: &str, member: Option<&str>) -> String {
+        let mut doc = summary;
+        if let Some(comment) = self.idl_comment(self_name, member) {
+            doc.push_str("\n\n");
+            doc.push_str(comment);
+        }
+        if let Some(template) = &self.doc_url {
+            let path = match member {
+                Some(member) => format!("{}/{}", self_name, member),
+                None => self_name.to_string(),
+            };
+            doc.push_str(&format!("\n\n[Documentation]({})", template.replace("{path}", &path)));
+        }
+        doc
+    }
+
+    /// The doc comment in the WebIDL on `self_name` or its member `member`,
+    /// which for members may be in one of the mixins it includes.
+    fn idl_comment(&self, self_name: &str, member: Option<&str>) -> Option<&str> {
+        if let Some(comment) = self.comments.get(self_name, member) {
+            return Some(comment);
+        }
+        if member.is_none() {
+            return None;
+        }
+        for mixin_name in self.includes.get(self_name).into_iter().flat_map(|names| names) {
+            if let Some(comment) = self.idl_comment(mixin_name, member) {
+                return Some(comment);
+            }
+        }
+        None
+    }
+
     /// Create a wasm-bindgen function, if possible.
     pub fn create_function(
         &self,
@@ -235,11 +295,11 @@ impl<'src> FirstPassRecord<'src> {
             ret @ _ => {
                 match ret.to_syn_type(TypePosition::Return) {
                     None => {
-                        warn!(
-                            "Unsupported return type: {:?} on {:?}",
+                        self.skip(format!(
+                            "`{}`: unsupported return type {:?}",
+                            js_path(&kind, name),
                             ret,
-                            rust_name
-                        );
+                        ));
                         return Vec::new();
                     },
                     Some(ret) => Some(ret),
@@ -268,6 +328,12 @@ impl<'src> FirstPassRecord<'src> {
                 .and_modify(|variants_count| { *variants_count = true; })
                 .or_insert(false);
         }
+        let fills_arrays = match kind {
+            backend::ast::ImportFunctionKind::Method { ref class, .. } => {
+                FILLS_ARRAY_ARGUMENTS.contains(&(class.as_str(), name))
+            }
+            backend::ast::ImportFunctionKind::Normal => false,
+        };
         let mut import_functions = Vec::new();
         'outer: for idl_types in &possibilities {
             let rust_name = if possibilities.len() > 1 {
@@ -319,13 +385,14 @@ impl<'src> FirstPassRecord<'src> {
                 let syn_type = if let Some(syn_type) = idl_type.to_syn_type(TypePosition::Argument) {
                     syn_type
                 } else {
-                    warn!(
-                        "Unsupported argument type: {:?} on {:?}",
+                    self.skip(format!(
+                        "`{}`: unsupported argument type {:?}",
+                        js_path(&kind, name),
                         idl_type,
-                        rust_name
-                    );
+                    ));
                     continue 'outer;
                 };
+                let syn_type = if fills_arrays { mut_slice_ref(syn_type) } else { syn_type };
                 let argument_name = rust_ident(&argument_name.to_snake_case());
                 args_captured.push(simple_fn_arg(argument_name, syn_type));
             }
@@ -341,6 +408,11 @@ impl<'src> FirstPassRecord<'src> {
                 rust_name,
                 js_ret: js_ret.clone(),
                 catch,
+                assert_no_shim: false,
+                optional_args: false,
+                variadic: false,
+                vendor_prefixes: Vec::new(),
+                polyfill: None,
                 structural,
                 kind: kind.clone(),
                 shim,
@@ -397,7 +469,7 @@ impl<'src> FirstPassRecord<'src> {
             first_pass::OperationId::Constructor => panic!("constructors are unsupported"),
             first_pass::OperationId::Operation(name) => match name {
                 None => {
-                    warn!("Unsupported unnamed operation: {:?}", operation_id);
+                    self.skip(format!("`{}`: unsupported unnamed operation", self_name));
                     return Vec::new();
                 }
                 Some(name) => name,
@@ -429,13 +501,11 @@ impl<'src> FirstPassRecord<'src> {
 
         let doc_comment = match &operation_id {
             first_pass::OperationId::Constructor => panic!("constructors are unsupported"),
-            first_pass::OperationId::Operation(_) => Some(
-                format!(
-                    "The `{}()` method\n\n{}",
-                    name,
-                    mdn_doc(self_name, Some(&name))
-                )
-            ),
+            first_pass::OperationId::Operation(_) => Some(self.doc_comment(
+                format!("The `{}()` method", name),
+                self_name,
+                Some(&name),
+            )),
             first_pass::OperationId::IndexingGetter => Some("The indexing getter\n\n".to_string()),
             first_pass::OperationId::IndexingSetter => Some("The indexing setter\n\n".to_string()),
             first_pass::OperationId::IndexingDeleter => Some("The indexing deleter\n\n".to_string()),
@@ -541,7 +611,7 @@ impl<'src> FirstPassRecord<'src> {
         let name = match operation_name {
             Some(name) => name.to_string(),
             None => {
-                warn!("Unsupported unnamed operation: on {:?}", self_name);
+                self.skip(format!("`{}`: unsupported unnamed operation", self_name));
                 return Vec::new();
             }
         };
@@ -551,14 +621,11 @@ impl<'src> FirstPassRecord<'src> {
             Some(idl_type) => idl_type,
         };
 
-        let doc_comment = Some(
-            format!(
-                "The `{}.{}()` function\n\n{}",
-                self_name,
-                name,
-                mdn_doc(self_name, Some(&name))
-            )
-        );
+        let doc_comment = Some(self.doc_comment(
+            format!("The `{}.{}()` function", self_name, name),
+            self_name,
+            Some(&name),
+        ));
 
         let arguments = match self.convert_arguments(arguments) {
             None => return Vec::new(),
@@ -601,7 +668,7 @@ impl<'src> FirstPassRecord<'src> {
                 kind: backend::ast::OperationKind::Getter(Some(raw_ident(name))),
             }),
         };
-        let doc_comment = Some(format!("The `{}` getter\n\n{}", name, mdn_doc(self_name, Some(name))));
+        let doc_comment = Some(self.doc_comment(format!("The `{}` getter", name), self_name, Some(name)));
 
         self.create_function(name, false, false, &[], ret, kind, is_structural, catch, doc_comment)
     }
@@ -624,7 +691,7 @@ impl<'src> FirstPassRecord<'src> {
                 kind: backend::ast::OperationKind::Setter(Some(raw_ident(name))),
             }),
         };
-        let doc_comment = Some(format!("The `{}` setter\n\n{}", name, mdn_doc(self_name, Some(name))));
+        let doc_comment = Some(self.doc_comment(format!("The `{}` setter", name), self_name, Some(name)));
 
         self.create_function(
             &format!("set_{}", name),
@@ -679,6 +746,27 @@ pub fn throws(attrs: &Option<ExtendedAttributeList>) -> bool {
     has_named_attribute(attrs, "Throws")
 }
 
+/// The prefixes named by `VendorPrefix` attributes, for interfaces which older
+/// browsers only define under a prefixed name like `webkitAudioContext`.
+pub fn vendor_prefixes<'a>(ext_attrs: &Option<ExtendedAttributeList<'a>>) -> Vec<&'a str> {
+    let list = match ext_attrs {
+        Some(list) => list,
+        None => return Vec::new(),
+    };
+    list.body
+        .list
+        .iter()
+        .filter_map(|attr| match attr {
+            ExtendedAttribute::Ident(id) if id.lhs_identifier.0 == "VendorPrefix" => {
+                match id.rhs {
+                    IdentifierOrString::Identifier(prefix) => Some(prefix.0),
+                    IdentifierOrString::String(_) => None,
+                }
+            }
+            _ => None,
+        }).collect()
+}
+
 /// Create a syn `pub` token
 pub fn public() -> syn::Visibility {
     syn::Visibility::Public(syn::VisPublic {
@@ -0,0 +1,299 @@
+//! Tests running the WebIDL frontend on small snippets of WebIDL.
+//!
+//! These check the generated Rust directly, which is handy for testing what
+//! does or doesn't get generated without writing a whole `webidl-tests`
+//! module for it.
+
+extern crate failure;
+extern crate proc_macro2;
+extern crate wasm_bindgen_webidl;
+
+use failure::Fail;
+use proc_macro2::TokenStream;
+use wasm_bindgen_webidl::{ErrorKind, Options};
+
+/// Compiles `webidl` and returns the generated Rust.
+fn generate(webidl: &str) -> String {
+    match wasm_bindgen_webidl::compile(webidl) {
+        Ok(rust) => rust,
+        Err(e) => panic!("failed to compile WebIDL: {}\n\n{}", e, webidl),
+    }
+}
+
+/// Normalizes the whitespace in `rust`, so it can be searched for in the
+/// output of `generate`.
+///
+/// Note that `rust` has to be a sequence of complete tokens, so delimiters
+/// must be balanced, and it has to match the generated code exactly apart
+/// from whitespace, including trailing commas.
+fn tokens(rust: &str) -> String {
+    rust.parse::<TokenStream>().unwrap().to_string()
+}
+
+/// Asserts that the Rust generated for `webidl` contains `rust`, ignoring
+/// differences in whitespace.
+fn assert_generates(webidl: &str, rust: &str) {
+    let generated = generate(webidl);
+    let expected = tokens(rust);
+    assert!(
+        generated.contains(&expected),
+        "expected the generated code to contain\n\n{}\n\nbut it was\n\n{}",
+        expected,
+        generated,
+    );
+}
+
+/// Asserts that the Rust generated for `webidl` doesn't contain `rust`,
+/// ignoring differences in whitespace.
+fn assert_not_generates(webidl: &str, rust: &str) {
+    let generated = generate(webidl);
+    let unexpected = tokens(rust);
+    assert!(
+        !generated.contains(&unexpected),
+        "expected the generated code not to contain\n\n{}\n\nbut it was\n\n{}",
+        unexpected,
+        generated,
+    );
+}
+
+#[test]
+fn operations() {
+    let webidl = "
+        interface Foo {
+            double bar(boolean baz);
+            static DOMString qux();
+        };
+    ";
+    assert_generates(webidl, "pub fn bar(&self, baz: bool) -> f64");
+    assert_generates(webidl, "pub fn qux() -> String");
+}
+
+#[test]
+fn attributes() {
+    let webidl = "
+        interface Foo {
+            attribute long bar;
+            readonly attribute long baz;
+        };
+    ";
+    assert_generates(webidl, "pub fn bar(&self,) -> i32");
+    assert_generates(webidl, "pub fn set_bar(&self, bar: i32)");
+    assert_generates(webidl, "pub fn baz(&self,) -> i32");
+    assert_not_generates(webidl, "pub fn set_baz");
+}
+
+#[test]
+fn undefined_types_are_skipped() {
+    let webidl = "
+        interface Foo {
+            boolean ok();
+            boolean bad(Undefined undef);
+        };
+    ";
+    assert_generates(webidl, "pub fn ok(&self,) -> bool");
+    assert_not_generates(webidl, "pub fn bad");
+}
+
+#[test]
+fn arrays_written_to_are_mutable() {
+    let webidl = "
+        interface AnalyserNode {
+            void getFloatTimeDomainData(Float32Array array);
+        };
+        interface Foo {
+            void bar(Float32Array array);
+        };
+    ";
+    assert_generates(webidl, "pub fn get_float_time_domain_data(&self, array: &mut [f32])");
+    assert_generates(webidl, "pub fn bar(&self, array: &[f32])");
+}
+
+#[test]
+fn strict_lists_what_was_skipped() {
+    let webidl = "
+        interface Foo {
+            boolean ok();
+            boolean bad(Undefined undef);
+            stringifier;
+        };
+    ";
+    assert_generates(webidl, "pub fn ok(&self,) -> bool");
+    let err = Options::new().strict(true).compile(webidl).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::SkippedWebIDL);
+    assert_eq!(
+        err.cause().unwrap().to_string(),
+        "no bindings were generated for:\n  \
+         unrecognized type `Undefined`\n  \
+         unsupported stringifier on `Foo`",
+    );
+    assert!(Options::new().strict(true).compile("interface Foo { boolean ok(); };").is_ok());
+}
+
+#[test]
+fn enums() {
+    let webidl = r#"enum Shape { "circle", "square-ish" };"#;
+    assert_generates(webidl, "pub enum Shape");
+    assert_generates(webidl, "Circle = 0, SquareIsh = 1,");
+    assert_generates(webidl, r#""square-ish" => Some(Shape::SquareIsh),"#);
+}
+
+#[test]
+fn consts() {
+    assert_generates(
+        "interface Foo { const unsigned short BAR = 3; };",
+        "pub const BAR: u16 = 3",
+    );
+}
+
+#[test]
+fn invalid_webidl_is_an_error() {
+    assert!(wasm_bindgen_webidl::compile("interface Foo {").is_err());
+}
+
+#[test]
+fn doc_comments() {
+    let webidl = "
+        /**
+         * A foo.
+         *
+         * Really.
+         */
+        interface Foo {
+            // Not documentation.
+            void notDocumented();
+
+            /** The bar. */
+            [Throws]
+            attribute long bar;
+
+            /// Quxes `x`.
+            void qux(long x);
+        };
+    ";
+    assert_generates(
+        webidl,
+        r#"#[doc = "The `Foo` object\n\nA foo.\n\nReally.\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Foo)"]"#,
+    );
+    assert_generates(
+        webidl,
+        r#"#[doc = "The `notDocumented()` method\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Foo/notDocumented)"]"#,
+    );
+    assert_generates(
+        webidl,
+        r#"#[doc = "The `bar` setter\n\nThe bar.\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Foo/bar)"]"#,
+    );
+    assert_generates(
+        webidl,
+        r#"#[doc = "The `qux()` method\n\nQuxes `x`.\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Foo/qux)"]"#,
+    );
+}
+
+#[test]
+fn doc_comments_from_mixins() {
+    let webidl = "
+        interface Foo {};
+        interface mixin Bar {
+            /** The baz. */
+            readonly attribute long baz;
+        };
+        Foo includes Bar;
+    ";
+    assert_generates(
+        webidl,
+        r#"#[doc = "The `baz` getter\n\nThe baz.\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Foo/baz)"]"#,
+    );
+}
+
+#[test]
+fn doc_url() {
+    let webidl = "interface Foo { void bar(); };";
+    let generated = wasm_bindgen_webidl::Options::new()
+        .doc_url(Some("https://example.com/{path}.html"))
+        .compile(webidl)
+        .unwrap();
+    assert!(generated.contains("[Documentation](https://example.com/Foo.html)"));
+    assert!(generated.contains("[Documentation](https://example.com/Foo/bar.html)"));
+
+    let generated = wasm_bindgen_webidl::Options::new()
+        .doc_url(None)
+        .compile(webidl)
+        .unwrap();
+    assert!(!generated.contains("[Documentation]"));
+}
+
+#[test]
+fn dictionary_docs() {
+    let webidl = "
+        /** Options for a foo. */
+        dictionary FooOptions {
+            /** How much bar. */
+            long bar;
+        };
+    ";
+    assert_generates(
+        webidl,
+        r#"#[doc = "The `FooOptions` dictionary\n\nOptions for a foo."]"#,
+    );
+    assert_generates(
+        webidl,
+        r#"#[doc = "Change the `bar` field of this object\n\nHow much bar."]"#,
+    );
+}
+
+#[test]
+fn constructor_docs() {
+    assert_generates(
+        "[Constructor(long bar)] interface Foo {};",
+        r#"#[doc = "The `new Foo(..)` constructor, creating a new instance of `Foo`\n\n[Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Foo/Foo)"]"#,
+    );
+}
+
+#[test]
+fn api_index() {
+    let webidl = "
+        [Constructor]
+        interface Foo {
+            static void bar();
+            Foo baz(Qux qux);
+            attribute long quux;
+            void undefined(Undefined x);
+        };
+        interface Qux {};
+        namespace ns {
+            void f();
+        };
+    ";
+    let index = wasm_bindgen_webidl::Options::new().api_index(webidl).unwrap();
+    let items = index
+        .iter()
+        .map(|item| (&item.js[..], &item.rust[..], item.types.join(" ")))
+        .collect::<Vec<_>>();
+    let expected = [
+        ("Foo", "Foo", "Foo"),
+        ("new Foo()", "Foo::new", "Foo"),
+        ("Foo.bar()", "Foo::bar", "Foo"),
+        ("Foo.prototype.baz()", "Foo::baz", "Foo Qux"),
+        ("Foo.prototype.quux", "Foo::quux", "Foo"),
+        ("Foo.prototype.quux =", "Foo::set_quux", "Foo"),
+        ("Qux", "Qux", "Qux"),
+        ("ns.f()", "ns::f", ""),
+    ];
+    for &(js, rust, types) in expected.iter() {
+        assert!(
+            items.contains(&(js, rust, types.to_string())),
+            "{:?} not found in {:#?}",
+            (js, rust, types),
+            items,
+        );
+    }
+    assert_eq!(items.len(), expected.len());
+}
+
+#[test]
+fn vendor_prefixes() {
+    // The prefixes are only used by the CLI, so look for them in the
+    // description of the program which is passed on to it.
+    let rust = generate("[VendorPrefix=webkit, Constructor] interface Foo {};");
+    assert!(rust.contains(r#"\"vendor_prefixes\":[\"webkit\"]"#));
+    assert!(!generate("[Constructor] interface Foo {};").contains("webkit"));
+}
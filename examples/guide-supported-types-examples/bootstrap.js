@@ -10,3 +10,10 @@ import * as pointers from './pointers.js';
 import * as numbers from './numbers.js';
 import * as boxed_number_slices from './boxed_number_slices.js';
 import * as number_slices from './number_slices.js';
+import * as time from './time.js';
+import * as tuples from './tuples.js';
+import * as maps from './maps.js';
+import * as enums from './enums.js';
+import * as unions from './unions.js';
+import * as result from './result.js';
+import * as vectors from './vectors.js';
@@ -0,0 +1,12 @@
+import {
+  Status,
+  ScrollBehavior,
+  is_success,
+  toggle_behavior,
+} from './guide_supported_types_examples';
+
+console.log(Status.Created); // 201
+console.log(is_success(Status.NotFound)); // false
+
+console.log(ScrollBehavior.Smooth); // "smooth"
+console.log(toggle_behavior("auto")); // "smooth"
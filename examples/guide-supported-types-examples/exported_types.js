@@ -4,6 +4,8 @@ import {
   exported_type_by_shared_ref,
   exported_type_by_exclusive_ref,
   return_exported_type,
+  exported_type_by_optional_ref,
+  return_optional_exported_type,
 } from './guide_supported_types_examples';
 
 let rustThing = return_exported_type();
@@ -12,3 +14,6 @@ console.log(rustThing instanceof ExportedRustType); // true
 exported_type_by_value(rustThing);
 exported_type_by_shared_ref(rustThing);
 exported_type_by_exclusive_ref(rustThing);
+
+exported_type_by_optional_ref(null);
+console.log(return_optional_exported_type()); // undefined
@@ -0,0 +1,11 @@
+import {
+  take_f64_bits_by_value,
+  return_f64_bits,
+} from './guide_supported_types_examples';
+
+const view = new DataView(new ArrayBuffer(8));
+view.setFloat64(0, 0.5);
+take_f64_bits_by_value(view.getBigUint64(0));
+
+let bits = return_f64_bits();
+console.log(bits.toString(16)); // 7ff80000deadbeef
@@ -3,6 +3,7 @@ import {
   imported_type_by_shared_ref,
   return_imported_type,
   take_option_imported_type,
+  take_option_imported_type_by_ref,
   return_option_imported_type,
 } from './guide_supported_types_examples';
 
@@ -15,6 +16,8 @@ console.log(x instanceof SomeJsType); // true
 take_option_imported_type(null);
 take_option_imported_type(undefined);
 take_option_imported_type(new SomeJsType());
+take_option_imported_type_by_ref(null);
+take_option_imported_type_by_ref(new SomeJsType());
 
 let y = return_option_imported_type();
 if (y == null) {
@@ -0,0 +1,13 @@
+import {
+  take_scores,
+  return_names,
+  return_flags,
+} from './guide_supported_types_examples';
+
+console.log(take_scores({ alice: 3, bob: 4 })); // 7
+
+let names = return_names();
+console.log(names.fr); // "un"
+
+let flags = return_flags();
+console.log(flags.get("enabled")); // true
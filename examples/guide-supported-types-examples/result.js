@@ -0,0 +1,22 @@
+import {
+  checked_sqrt,
+  take_items,
+  OutOfStock,
+} from './guide_supported_types_examples';
+
+console.log(checked_sqrt(9)); // 3
+
+try {
+  checked_sqrt(-1);
+} catch (e) {
+  console.log(e instanceof TypeError); // true
+}
+
+try {
+  take_items(2, 5);
+} catch (e) {
+  if (e instanceof OutOfStock) {
+    console.log(e.remaining()); // 2
+    e.free();
+  }
+}
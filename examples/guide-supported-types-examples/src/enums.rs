@@ -0,0 +1,30 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub enum Status {
+    Ok = 200,
+    Created,
+    NotFound = 404,
+}
+
+#[wasm_bindgen]
+pub enum ScrollBehavior {
+    Auto = "auto",
+    Smooth = "smooth",
+}
+
+#[wasm_bindgen]
+pub fn is_success(status: Status) -> bool {
+    match status {
+        Status::Ok | Status::Created => true,
+        Status::NotFound => false,
+    }
+}
+
+#[wasm_bindgen]
+pub fn toggle_behavior(behavior: ScrollBehavior) -> ScrollBehavior {
+    match behavior {
+        ScrollBehavior::Auto => ScrollBehavior::Smooth,
+        ScrollBehavior::Smooth => ScrollBehavior::Auto,
+    }
+}
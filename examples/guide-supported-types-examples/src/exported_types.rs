@@ -18,3 +18,11 @@ pub fn exported_type_by_exclusive_ref(x: &mut ExportedRustType) {}
 pub fn return_exported_type() -> ExportedRustType {
     unimplemented!()
 }
+
+#[wasm_bindgen]
+pub fn exported_type_by_optional_ref(x: Option<&ExportedRustType>) {}
+
+#[wasm_bindgen]
+pub fn return_optional_exported_type() -> Option<ExportedRustType> {
+    None
+}
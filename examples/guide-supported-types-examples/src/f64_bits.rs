@@ -0,0 +1,10 @@
+use wasm_bindgen::float::F64Bits;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn take_f64_bits_by_value(x: F64Bits) {}
+
+#[wasm_bindgen]
+pub fn return_f64_bits() -> F64Bits {
+    F64Bits(f64::from_bits(0x7ff8_0000_dead_beef))
+}
@@ -19,6 +19,9 @@ pub fn return_imported_type() -> SomeJsType {
 #[wasm_bindgen]
 pub fn take_option_imported_type(x: Option<SomeJsType>) { /* ... */ }
 
+#[wasm_bindgen]
+pub fn take_option_imported_type_by_ref(x: Option<&SomeJsType>) { /* ... */ }
+
 #[wasm_bindgen]
 pub fn return_option_imported_type() -> Option<SomeJsType> {
     unimplemented!()
@@ -1,6 +1,7 @@
 #![feature(use_extern_macros)]
 #![allow(unused_variables, dead_code)]
 
+extern crate js_sys;
 extern crate wasm_bindgen;
 
 pub mod imported_types;
@@ -15,3 +16,11 @@ pub mod pointers;
 pub mod numbers;
 pub mod boxed_number_slices;
 pub mod number_slices;
+pub mod time;
+pub mod f64_bits;
+pub mod tuples;
+pub mod maps;
+pub mod enums;
+pub mod unions;
+pub mod result;
+pub mod vectors;
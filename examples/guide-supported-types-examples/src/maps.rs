@@ -0,0 +1,23 @@
+use std::collections::{BTreeMap, HashMap};
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn take_scores(scores: HashMap<String, u32>) -> u32 {
+    scores.values().sum()
+}
+
+#[wasm_bindgen]
+pub fn return_names() -> BTreeMap<String, String> {
+    let mut names = BTreeMap::new();
+    names.insert("en".to_string(), "one".to_string());
+    names.insert("fr".to_string(), "un".to_string());
+    names
+}
+
+#[wasm_bindgen(js_map)]
+pub fn return_flags() -> HashMap<String, bool> {
+    let mut flags = HashMap::new();
+    flags.insert("enabled".to_string(), true);
+    flags
+}
@@ -0,0 +1,30 @@
+use js_sys::TypeError;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn checked_sqrt(x: f64) -> Result<f64, TypeError> {
+    if x < 0.0 {
+        return Err(TypeError::new("can't take the square root of a negative number"));
+    }
+    Ok(x.sqrt())
+}
+
+#[wasm_bindgen]
+pub struct OutOfStock {
+    remaining: u32,
+}
+
+#[wasm_bindgen]
+impl OutOfStock {
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+}
+
+#[wasm_bindgen]
+pub fn take_items(stock: u32, wanted: u32) -> Result<u32, OutOfStock> {
+    if wanted > stock {
+        return Err(OutOfStock { remaining: stock });
+    }
+    Ok(stock - wanted)
+}
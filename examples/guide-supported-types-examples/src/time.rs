@@ -0,0 +1,18 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn take_duration_by_value(x: Duration) {}
+
+#[wasm_bindgen]
+pub fn return_duration() -> Duration {
+    Duration::from_millis(1500)
+}
+
+#[wasm_bindgen]
+pub fn take_system_time_by_value(x: SystemTime) {}
+
+#[wasm_bindgen]
+pub fn return_system_time() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(60)
+}
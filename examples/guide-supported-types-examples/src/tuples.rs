@@ -0,0 +1,11 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn return_pair() -> (u32, String) {
+    (1, "one".to_string())
+}
+
+#[wasm_bindgen]
+pub fn return_triple() -> (f64, bool, JsValue) {
+    (2.5, true, JsValue::NULL)
+}
@@ -0,0 +1,28 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Circle {
+    radius: f64,
+}
+
+#[wasm_bindgen]
+impl Circle {
+    #[wasm_bindgen(constructor)]
+    pub fn new(radius: f64) -> Circle {
+        Circle { radius }
+    }
+}
+
+#[wasm_bindgen]
+pub enum CircleOrName {
+    Circle(Circle),
+    Name(String),
+}
+
+#[wasm_bindgen]
+pub fn describe(shape: CircleOrName) -> String {
+    match shape {
+        CircleOrName::Circle(c) => format!("a circle of radius {}", c.radius),
+        CircleOrName::Name(name) => name,
+    }
+}
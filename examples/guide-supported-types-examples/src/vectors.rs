@@ -0,0 +1,17 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[wasm_bindgen]
+pub fn diagonal(len: i32) -> Vec<Point> {
+    (0..len).map(|i| Point { x: i, y: i }).collect()
+}
+
+#[wasm_bindgen]
+pub fn split_words(s: &str) -> Vec<String> {
+    s.split_whitespace().map(|w| w.to_string()).collect()
+}
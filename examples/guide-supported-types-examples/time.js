@@ -0,0 +1,16 @@
+import {
+  take_duration_by_value,
+  return_duration,
+  take_system_time_by_value,
+  return_system_time,
+} from './guide_supported_types_examples';
+
+take_duration_by_value(250);
+
+let d = return_duration();
+console.log(d); // 1500
+
+take_system_time_by_value(new Date());
+
+let t = return_system_time();
+console.log(t instanceof Date); // true
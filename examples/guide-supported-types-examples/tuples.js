@@ -0,0 +1,10 @@
+import {
+  return_pair,
+  return_triple,
+} from './guide_supported_types_examples';
+
+let [n, s] = return_pair();
+console.log(n, s); // 1 "one"
+
+let triple = return_triple();
+console.log(triple.length); // 3
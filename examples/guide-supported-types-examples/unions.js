@@ -0,0 +1,7 @@
+import {
+  Circle,
+  describe,
+} from './guide_supported_types_examples';
+
+console.log(describe(new Circle(2))); // "a circle of radius 2"
+console.log(describe("a square")); // "a square"
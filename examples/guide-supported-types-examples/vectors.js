@@ -0,0 +1,12 @@
+import {
+  diagonal,
+  split_words,
+} from './guide_supported_types_examples';
+
+for (const point of diagonal(3)) {
+  console.log(point.x, point.y);
+  point.free();
+}
+
+let words = split_words("hello vectors of strings");
+console.log(words); // ["hello", "vectors", "of", "strings"]
@@ -0,0 +1,14 @@
+#!/bin/sh
+
+set -ex
+
+cargo +nightly build --target wasm32-unknown-unknown
+
+# The worker loads the module with `importScripts`, so it's built with
+# `--no-modules`, and the page uses the same build.
+cargo +nightly run --manifest-path ../../crates/cli/Cargo.toml \
+  --bin wasm-bindgen -- \
+  --no-modules \
+  ../../target/wasm32-unknown-unknown/debug/wasm_in_web_worker.wasm --out-dir .
+
+python -m SimpleHTTPServer
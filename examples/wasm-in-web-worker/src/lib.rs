@@ -0,0 +1,63 @@
+#![feature(use_extern_macros)]
+
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_rpc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_rpc::wasm_bindgen_rpc;
+
+/// A sieve of Eratosthenes, which is grown as needed and kept around, so
+/// later calls only do the work of sieving any new numbers.
+///
+/// Instantiating the module in a worker gives a `PrimesServer` owning one of
+/// these, and the main thread calls its methods through a `PrimesProxy`.
+#[derive(Default)]
+pub struct Primes {
+    is_composite: Vec<bool>,
+}
+
+#[wasm_bindgen_rpc]
+impl Primes {
+    /// Counts the primes below `n`.
+    pub fn count_below(&mut self, n: u32) -> u32 {
+        self.sieve(n as usize);
+        (2..n as usize).filter(|&i| !self.is_composite[i]).count() as u32
+    }
+
+    /// Returns the largest prime below `n`, if there is one.
+    pub fn largest_below(&mut self, n: u32) -> Option<u32> {
+        self.sieve(n as usize);
+        (2..n as usize)
+            .rev()
+            .find(|&i| !self.is_composite[i])
+            .map(|i| i as u32)
+    }
+
+    /// How many numbers have been sieved so far.
+    pub fn sieved(&self) -> u32 {
+        self.is_composite.len() as u32
+    }
+
+    fn sieve(&mut self, n: usize) {
+        let start = self.is_composite.len();
+        if n <= start {
+            return;
+        }
+        self.is_composite.resize(n, false);
+        let mut i = 2;
+        while i * i < n {
+            if !self.is_composite[i] {
+                // Multiples below `start` were already crossed off.
+                let first = if start > i * i {
+                    (start + i - 1) / i * i
+                } else {
+                    i * i
+                };
+                for j in (first..n).step_by(i) {
+                    self.is_composite[j] = true;
+                }
+            }
+            i += 1;
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// The worker loads its own instance of the same module the page does.
+importScripts('./wasm_in_web_worker.js');
+
+// Requests can arrive before the module is ready, so they're queued until it
+// is.
+const ready = wasm_bindgen('./wasm_in_web_worker_bg.wasm').then(() => {
+  return new wasm_bindgen.PrimesServer();
+});
+
+onmessage = event => {
+  ready.then(server => postMessage(server.handle(event.data)));
+};
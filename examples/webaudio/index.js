@@ -6,29 +6,52 @@ const rust = import('./webaudio');
 function setup(rust_module) {
     play = function() {
             console.log("About to create some music!");
-            fm = new rust_module.FmOsc();
-
-            fm.set_note(50);
-            fm.set_fm_frequency(0);
-            fm.set_fm_amount(0);
-            fm.set_gain(0.8);
+            // Creating the `AudioContext` fails in some browsers, like when
+            // there are too many of them already, which the constructor
+            // throws rather than panicking.
+            try {
+                fm = new rust_module.FmOsc();
+            } catch (e) {
+                console.error("Failed to start WebAudio:", e);
+                return;
+            }
+
+            fm.setNote(50);
+            fm.setFmFrequency(0);
+            fm.setFmAmount(0);
+            fm.gain = 0.8;
 
     };
 
     // create some UI elements
     const primary_slider = document.getElementById("primary_input");
     primary_slider.oninput = (e) => {
-        fm.set_note(e.target.value);
+        fm.setNote(e.target.value);
     };
 
     const fm_freq = document.getElementById("fm_freq");
     fm_freq.oninput = (e) => {
-        fm.set_fm_frequency(e.target.value);
+        fm.setFmFrequency(e.target.value);
     };
 
     const fm_amount = document.getElementById("fm_amount");
     fm_amount.oninput = (e) => {
-        fm.set_fm_amount(e.target.value);
+        fm.setFmAmount(e.target.value);
+    };
+
+    const organ = document.getElementById("organ");
+    organ.onclick = () => {
+        fm.setHarmonics(new Float32Array([1, 0.5, 0.25, 0.125]));
+    };
+
+    const midi = document.getElementById("midi");
+    midi.onclick = () => {
+        fm.enableMidi();
+    };
+
+    const fade = document.getElementById("fade");
+    fade.onclick = () => {
+        fm.fadeOut(2);
     };
 
     console.log("Ready!  Press the play button!");
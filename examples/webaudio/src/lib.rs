@@ -1,10 +1,17 @@
 #![feature(use_extern_macros, nll)]
 
+extern crate js_sys;
 extern crate wasm_bindgen;
 extern crate web_sys;
 
+use std::cell::Cell;
+use std::rc::Rc;
+
+use js_sys::Promise;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::{throw_val, JsCast};
 use web_sys::{AudioContext, BaseAudioContext, AudioNode, AudioScheduledSourceNode, OscillatorType};
+use web_sys::{GainNode, MidiAccess, MidiInput, MidiMessage, MidiMessageEvent, OscillatorNode};
 
 /// Converts a midi note to frequency
 ///
@@ -13,6 +20,50 @@ pub fn midi_to_freq(note: u8) -> f32 {
     27.5 * 2f32.powf((note as f32 - 21.0) / 12.0)
 }
 
+/// The parts of an `FmOsc` that a MIDI keyboard plays
+struct MidiVoice {
+    primary: OscillatorNode,
+    gain: GainNode,
+    fm_osc: OscillatorNode,
+    fm_gain: GainNode,
+
+    /// The note currently being played, if any
+    note: Cell<Option<u8>>,
+}
+
+impl MidiVoice {
+    fn play(&self, message: MidiMessage) {
+        match message {
+            MidiMessage::NoteOn { note, velocity, .. } => {
+                let freq = midi_to_freq(note);
+                let scale = freq / self.primary.frequency().value();
+                self.primary.frequency().set_value(freq);
+
+                // Keep the FM oscillator at the same ratio to the primary one, like
+                // `FmOsc::set_primary_frequency` does
+                self.fm_osc.frequency().set_value(self.fm_osc.frequency().value() * scale);
+                self.fm_gain.gain().set_value(self.fm_gain.gain().value() * scale);
+
+                self.gain.gain().set_value(velocity as f32 / 127.0);
+                self.note.set(Some(note));
+            }
+            MidiMessage::NoteOff { note, .. } => {
+                // Releasing an earlier key shouldn't cut off the latest one
+                if self.note.get() == Some(note) {
+                    self.gain.gain().set_value(0.0);
+                    self.note.set(None);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Makes another handle to the same JS object as `node`
+fn clone_handle<T: JsCast>(node: &T) -> T {
+    JsValue::clone(node.as_ref()).unchecked_into()
+}
+
 #[wasm_bindgen]
 pub struct FmOsc {
     ctx: AudioContext,
@@ -41,17 +92,15 @@ pub struct FmOsc {
 #[wasm_bindgen]
 impl FmOsc {
     #[wasm_bindgen(constructor)]
-    pub fn new() -> FmOsc {
-        // TODO, how to throw from a constructor?
-
-        let ctx = web_sys::AudioContext::new().unwrap();
+    pub fn new() -> Result<FmOsc, JsValue> {
+        let ctx = web_sys::AudioContext::new()?;
         let base: &BaseAudioContext = ctx.as_ref();
 
         // create our web audio objects
-        let primary = base.create_oscillator().unwrap();
-        let fm_osc = base.create_oscillator().unwrap();
-        let gain = base.create_gain().unwrap();
-        let fm_gain = base.create_gain().unwrap();
+        let primary = base.create_oscillator()?;
+        let fm_osc = base.create_oscillator()?;
+        let gain = base.create_gain()?;
+        let fm_gain = base.create_gain()?;
 
         // some initial settings:
         primary.set_type(OscillatorType::Sine);
@@ -90,7 +139,7 @@ impl FmOsc {
         AsRef::<AudioScheduledSourceNode>::as_ref(&primary).start();
         AsRef::<AudioScheduledSourceNode>::as_ref(&fm_osc).start();
 
-        FmOsc {
+        Ok(FmOsc {
             ctx,
             primary,
             gain,
@@ -98,19 +147,24 @@ impl FmOsc {
             fm_osc,
             fm_freq_ratio: 0.0,
             fm_gain_ratio: 0.0,
-        }
+        })
+    }
 
+    /// The gain for this oscillator, between 0.0 and 1.0
+    #[wasm_bindgen(getter)]
+    pub fn gain(&self) -> f32 {
+        self.gain.gain().value()
     }
 
-    /// Sets the gain for this oscillator, between 0.0 and 1.0
-    #[wasm_bindgen]
+    /// Sets the gain for this oscillator, clamping it between 0.0 and 1.0
+    #[wasm_bindgen(setter)]
     pub fn set_gain(&self, mut gain: f32) {
         if gain > 1.0 { gain = 1.0; }
         if gain < 0.0 { gain = 0.0; }
         self.gain.gain().set_value(gain);
     }
 
-    #[wasm_bindgen]
+    #[wasm_bindgen(js_name = setPrimaryFrequency)]
     pub fn set_primary_frequency(&self, freq: f32) {
         self.primary.frequency().set_value(freq);
 
@@ -121,14 +175,14 @@ impl FmOsc {
 
     }
 
-    #[wasm_bindgen]
+    #[wasm_bindgen(js_name = setNote)]
     pub fn set_note(&self, note: u8) {
         let freq = midi_to_freq(note);
         self.set_primary_frequency(freq);
     }
 
     /// This should be between 0 and 1, though higher values are accepted
-    #[wasm_bindgen]
+    #[wasm_bindgen(js_name = setFmAmount)]
     pub fn set_fm_amount(&mut self, amt: f32) {
         self.fm_gain_ratio = amt;
 
@@ -137,11 +191,77 @@ impl FmOsc {
     }
 
     /// This should be between 0 and 1, though higher values are accepted
-    #[wasm_bindgen]
+    #[wasm_bindgen(js_name = setFmFrequency)]
     pub fn set_fm_frequency(&mut self, amt: f32) {
         self.fm_freq_ratio = amt;
         self.fm_osc.frequency().set_value(self.fm_freq_ratio * self.primary.frequency().value());
     }
 
+    /// Replaces the primary oscillator's sine wave with one made of harmonics,
+    /// where `harmonics[0]` is the amplitude of the fundamental frequency,
+    /// `harmonics[1]` of the first overtone, and so on
+    #[wasm_bindgen(js_name = setHarmonics)]
+    pub fn set_harmonics(&self, harmonics: &[f32]) {
+        let base: &BaseAudioContext = self.ctx.as_ref();
+
+        // The first term of each series is a DC offset, which we leave at 0
+        let real = vec![0.0; harmonics.len() + 1];
+        let mut imag = vec![0.0];
+        imag.extend_from_slice(harmonics);
+
+        let wave = base.create_periodic_wave(&real, &imag).unwrap_or_else(|e| throw_val(e));
+        self.primary.set_periodic_wave(&wave);
+    }
+
+    /// Fades the volume out to silence over `seconds`, one step per sample
+    #[wasm_bindgen(js_name = fadeOut)]
+    pub fn fade_out(&self, seconds: f32) {
+        let base: &BaseAudioContext = self.ctx.as_ref();
+        let sample_rate = base.sample_rate();
+        let gain = self.gain.gain();
+        let start = gain.value();
+
+        let samples = (seconds * sample_rate) as usize;
+        let curve = (0..samples)
+            .map(|i| start * (1.0 - i as f32 / samples as f32))
+            .collect::<Vec<f32>>();
+
+        let now = base.current_time();
+        gain.cancel_scheduled_values(now).unwrap_or_else(|e| throw_val(e));
+        gain.set_value_curve_at_sample_rate(&curve, now, sample_rate).unwrap_or_else(|e| throw_val(e));
+    }
+
+    /// Plays notes from every connected MIDI keyboard, once the user allows
+    /// access to them
+    ///
+    /// The returned promise is rejected if MIDI isn't available
+    #[wasm_bindgen(js_name = enableMidi)]
+    pub fn enable_midi(&self) -> Result<Promise, JsValue> {
+        let voice = Rc::new(MidiVoice {
+            primary: clone_handle(&self.primary),
+            gain: clone_handle(&self.gain),
+            fm_osc: clone_handle(&self.fm_osc),
+            fm_gain: clone_handle(&self.fm_gain),
+            note: Cell::new(None),
+        });
+
+        let enabled = web_sys::request_midi_access()?.then_with(move |access| {
+            let access: MidiAccess = access.unchecked_into();
+            access.inputs().for_each(&mut |input: MidiInput| {
+                let voice = voice.clone();
+                let on_message = Closure::wrap(Box::new(move |event: MidiMessageEvent| {
+                    if let Ok(message) = event.message() {
+                        voice.play(message);
+                    }
+                }) as Box<FnMut(MidiMessageEvent)>);
+                input.set_onmidimessage(&on_message);
+                // The inputs keep this handler for as long as the page is open
+                on_message.forget();
+            });
+            Ok(JsValue::undefined())
+        });
+        Ok(enabled)
+    }
+
 
 }
\ No newline at end of file
@@ -0,0 +1,5 @@
+// For more comments about what's going on here, check out the `hello_world`
+// example.
+import('./wasm_bindgen_webgl_demo').then(webgl => {
+  webgl.draw();
+});
@@ -0,0 +1,137 @@
+#![feature(use_extern_macros)]
+
+extern crate js_sys;
+extern crate wasm_bindgen;
+extern crate web_sys;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlCanvasElement, WebGl2RenderingContext as GL};
+
+#[wasm_bindgen]
+extern "C" {
+    static document: web_sys::Document;
+}
+
+const VERTEX_SHADER: &str = r#"#version 300 es
+in vec2 position;
+in vec2 tex_coord;
+out vec2 v_tex_coord;
+
+void main() {
+    v_tex_coord = tex_coord;
+    gl_Position = vec4(position, 0.0, 1.0);
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 300 es
+precision mediump float;
+
+uniform sampler2D tex;
+in vec2 v_tex_coord;
+out vec4 color;
+
+void main() {
+    color = texture(tex, v_tex_coord);
+}
+"#;
+
+// The position and then texture coordinate of each corner of the triangle.
+const VERTICES: [f32; 12] = [
+    -0.7, -0.7, 0.0, 0.0,
+    0.7, -0.7, 1.0, 0.0,
+    0.0, 0.7, 0.5, 1.0,
+];
+
+#[wasm_bindgen]
+pub fn draw() {
+    // The shader helpers return the info log as the error, which is much more
+    // useful than a generic failure, so make sure it's seen.
+    if let Err(e) = try_draw() {
+        let msg = e.as_string().unwrap_or_else(|| "failed to draw".to_string());
+        wasm_bindgen::throw(&msg);
+    }
+}
+
+fn try_draw() -> Result<(), JsValue> {
+    let canvas = canvas("canvas")?;
+    let gl = canvas.get_context_webgl2()?;
+
+    let vertex = gl.compile_shader_from_source(GL::VERTEX_SHADER, VERTEX_SHADER)?;
+    let fragment = gl.compile_shader_from_source(GL::FRAGMENT_SHADER, FRAGMENT_SHADER)?;
+    let program = gl.link_program_from_shaders(&[&vertex, &fragment])?;
+    gl.use_program(Some(&program));
+
+    // Upload the vertices, which are passed to the shader as two attributes
+    // interleaved in one buffer.
+    let vertices = js_sys::Array::new();
+    for v in VERTICES.iter() {
+        vertices.push(&JsValue::from_f64(*v as f64));
+    }
+    let vertices = js_sys::Float32Array::new(&vertices.into());
+    let buffer = gl.create_buffer().ok_or("failed to create buffer")?;
+    gl.bind_buffer(GL::ARRAY_BUFFER, Some(&buffer));
+    gl.buffer_data_with_u32_and_opt_array_buffer_and_u32(
+        GL::ARRAY_BUFFER,
+        Some(vertices.buffer()),
+        GL::STATIC_DRAW,
+    );
+
+    let stride = 4 * 4;
+    let position = gl.get_attrib_location(&program, "position") as u32;
+    gl.enable_vertex_attrib_array(position);
+    gl.vertex_attrib_pointer(position, 2, GL::FLOAT, false, stride, 0);
+    let tex_coord = gl.get_attrib_location(&program, "tex_coord") as u32;
+    gl.enable_vertex_attrib_array(tex_coord);
+    gl.vertex_attrib_pointer(tex_coord, 2, GL::FLOAT, false, stride, 2 * 4);
+
+    // The texture is a checkerboard drawn on another, hidden, canvas.
+    let texture_canvas = canvas("texture")?;
+    draw_checkerboard(&texture_canvas)?;
+    let texture = gl.create_texture().ok_or("failed to create texture")?;
+    gl.active_texture(GL::TEXTURE0);
+    gl.bind_texture(GL::TEXTURE_2D, Some(&texture));
+    gl.tex_parameteri(GL::TEXTURE_2D, GL::TEXTURE_MIN_FILTER, GL::NEAREST as i32);
+    gl.tex_parameteri(GL::TEXTURE_2D, GL::TEXTURE_MAG_FILTER, GL::NEAREST as i32);
+    gl.tex_image2_d_with_u32_and_i32_and_i32_and_u32_and_u32_and_html_canvas_element(
+        GL::TEXTURE_2D,
+        0,
+        GL::RGBA as i32,
+        GL::RGBA,
+        GL::UNSIGNED_BYTE,
+        &texture_canvas,
+    )?;
+    gl.uniform1i(gl.get_uniform_location(&program, "tex").as_ref(), 0);
+
+    gl.viewport(0, 0, canvas.width() as i32, canvas.height() as i32);
+    gl.clear_color(0.0, 0.0, 0.0, 1.0);
+    gl.clear(GL::COLOR_BUFFER_BIT);
+    gl.draw_arrays(GL::TRIANGLES, 0, 3);
+
+    Ok(())
+}
+
+fn canvas(id: &str) -> Result<HtmlCanvasElement, JsValue> {
+    document
+        .get_element_by_id(id)
+        .ok_or_else(|| JsValue::from(format!("no `#{}` element", id)))?
+        .dyn_into::<HtmlCanvasElement>()
+        .map_err(JsValue::from)
+}
+
+fn draw_checkerboard(canvas: &HtmlCanvasElement) -> Result<(), JsValue> {
+    let context = canvas
+        .get_context_using_context_id("2d")?
+        .ok_or("no 2d context")?
+        .dyn_into::<web_sys::CanvasRenderingContext2D>()?;
+
+    let size = canvas.width() as f64 / 8.0;
+    for row in 0..8 {
+        for column in 0..8 {
+            let color = if (row + column) % 2 == 0 { "#f6c343" } else { "#3b5998" };
+            context.set_fill_style_using_dom_str(color);
+            context.fill_rect(column as f64 * size, row as f64 * size, size, size);
+        }
+    }
+    Ok(())
+}
@@ -132,10 +132,14 @@ impl<T> Closure<T>
 impl<T> WasmDescribe for Closure<T>
     where T: WasmClosure + ?Sized,
 {
+    #[cfg(not(feature = "static-descriptors"))]
     fn describe() {
         inform(CLOSURE);
         T::describe();
     }
+
+    const DESCRIPTION: Description =
+        Description::Concat(&[Description::Numbers(&[CLOSURE]), T::DESCRIPTION]);
 }
 
 // `Closure` can only be passed by reference to imports.
@@ -153,14 +157,50 @@ impl<'a, T> IntoWasmAbi for &'a Closure<T>
     }
 }
 
+// A boxed closure can be returned from an export, in which case ownership of
+// it moves into the JS function that's created. That function has a `free`
+// method which will deallocate the Rust closure.
+impl<T> WasmDescribe for Box<T>
+    where T: WasmClosure + ?Sized,
+{
+    #[cfg(not(feature = "static-descriptors"))]
+    fn describe() {
+        inform(CLOSURE);
+        T::describe();
+    }
+
+    const DESCRIPTION: Description =
+        Description::Concat(&[Description::Numbers(&[CLOSURE]), T::DESCRIPTION]);
+}
+
+impl<T> IntoWasmAbi for Box<T>
+    where T: WasmClosure + ?Sized,
+{
+    type Abi = u32;
+
+    fn into_abi(self, extra: &mut Stack) -> u32 {
+        unsafe extern fn destroy<T: ?Sized>(a: *const UnsafeCell<Box<T>>) {
+            drop(Rc::from_raw(a));
+        }
+        extra.push(T::invoke_fn());
+        extra.push(destroy::<T> as u32);
+        Rc::into_raw(Rc::new(UnsafeCell::new(self))) as u32
+    }
+}
+
 fn _check() {
     fn _assert<T: IntoWasmAbi>() {}
     _assert::<&Closure<Fn()>>();
-    _assert::<&Closure<Fn(String)>>();
-    _assert::<&Closure<Fn() -> String>>();
     _assert::<&Closure<FnMut()>>();
-    _assert::<&Closure<FnMut(String)>>();
-    _assert::<&Closure<FnMut() -> String>>();
+    #[cfg(not(feature = "numbers-only"))]
+    {
+        _assert::<&Closure<Fn(String)>>();
+        _assert::<&Closure<Fn() -> String>>();
+        _assert::<&Closure<FnMut(String)>>();
+        _assert::<&Closure<FnMut() -> String>>();
+    }
+    _assert::<Box<Fn()>>();
+    _assert::<Box<FnMut(u32) -> u32>>();
 }
 
 impl<T> Drop for Closure<T>
@@ -183,7 +223,13 @@ impl<T> Drop for Closure<T>
 /// implement yourself.
 #[doc(hidden)]
 pub unsafe trait WasmClosure: 'static {
-    fn describe();
+    // The same as `WasmDescribe`'s, which doesn't change shape with
+    // `static-descriptors`.
+    const DESCRIPTION: Description = Description::Unknown;
+
+    fn describe() {
+        Self::DESCRIPTION.inform()
+    }
 
     fn invoke_fn() -> u32;
 }
@@ -210,9 +256,7 @@ macro_rules! doit {
         unsafe impl<$($var),*> WasmClosure for Fn($($var),*)
             where $($var: FromWasmAbi + 'static,)*
         {
-            fn describe() {
-                <&Self>::describe();
-            }
+            describe! { [&'static Self] }
 
             fn invoke_fn() -> u32 {
                 #[allow(non_snake_case)]
@@ -242,9 +286,7 @@ macro_rules! doit {
             where $($var: FromWasmAbi + 'static,)*
                   R: IntoWasmAbi + 'static,
         {
-            fn describe() {
-                <&Self>::describe();
-            }
+            describe! { [&'static Self] }
 
             fn invoke_fn() -> u32 {
                 #[allow(non_snake_case)]
@@ -272,9 +314,7 @@ macro_rules! doit {
         unsafe impl<$($var),*> WasmClosure for FnMut($($var),*)
             where $($var: FromWasmAbi + 'static,)*
         {
-            fn describe() {
-                <&mut Self>::describe();
-            }
+            describe! { [&'static mut Self] }
 
             fn invoke_fn() -> u32 {
                 #[allow(non_snake_case)]
@@ -304,9 +344,7 @@ macro_rules! doit {
             where $($var: FromWasmAbi + 'static,)*
                   R: IntoWasmAbi + 'static,
         {
-            fn describe() {
-                <&mut Self>::describe();
-            }
+            describe! { [&'static mut Self] }
 
             fn invoke_fn() -> u32 {
                 #[allow(non_snake_case)]
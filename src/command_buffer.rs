@@ -0,0 +1,212 @@
+//! Recording calls into JS to make them all at once
+//!
+//! Each call from Rust to an imported JS function goes through a generated
+//! shim, which is cheap but adds up for code making hundreds of small calls
+//! at a time, like drawing to a canvas every frame. A `CommandBuffer` instead
+//! records the calls into wasm memory and makes all of them with a single
+//! call into JS.
+
+use std::marker::PhantomData;
+use std::prelude::v1::*;
+
+use JsValue;
+
+const CMD_CALL: f64 = 0.0;
+const CMD_METHOD: f64 = 1.0;
+const CMD_SET: f64 = 2.0;
+
+const ARG_NUMBER: f64 = 0.0;
+const ARG_BOOL: f64 = 1.0;
+const ARG_VALUE: f64 = 2.0;
+const ARG_STR: f64 = 3.0;
+
+/// A sequence of calls into JS, recorded to be made all at once by `flush`.
+///
+/// The buffer only borrows the JS objects and functions passed to it, so
+/// recording a call doesn't need to call into JS at all. Property names and
+/// string arguments must be `'static`, which lets the generated JS decode
+/// each of them only once no matter how many times it's used.
+///
+/// The return values of the recorded calls are ignored. If one of them
+/// throws an exception then the calls after it aren't made, and the exception
+/// propagates out of `flush` like it would from any other imported function.
+/// Calls which are still recorded when the buffer is dropped are discarded.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut commands = CommandBuffer::new();
+/// for rect in rects {
+///     commands.set(&ctx, "fillStyle", rect.color);
+///     commands
+///         .method(&ctx, "fillRect")
+///         .arg(rect.x)
+///         .arg(rect.y)
+///         .arg(rect.width)
+///         .arg(rect.height);
+/// }
+/// commands.flush();
+/// ```
+pub struct CommandBuffer<'a> {
+    words: Vec<f64>,
+    _values: PhantomData<&'a JsValue>,
+}
+
+/// A call being recorded into a `CommandBuffer`, to which arguments can be
+/// appended.
+pub struct Command<'b, 'a: 'b> {
+    buffer: &'b mut CommandBuffer<'a>,
+    argc: usize,
+}
+
+/// An argument of a call recorded into a `CommandBuffer`.
+///
+/// This is typically created through one of its `From` implementations.
+#[derive(Clone, Copy)]
+pub enum CommandArg<'a> {
+    /// A JS number.
+    Number(f64),
+    /// A JS boolean.
+    Bool(bool),
+    /// A JS string.
+    Str(&'static str),
+    /// Any other JS value.
+    Value(&'a JsValue),
+}
+
+impl<'a> CommandBuffer<'a> {
+    /// Creates a new, empty, command buffer.
+    pub fn new() -> CommandBuffer<'a> {
+        CommandBuffer::with_capacity(0)
+    }
+
+    /// Creates a new, empty, command buffer with space for `words` 8-byte
+    /// words of commands before it needs to reallocate.
+    pub fn with_capacity(words: usize) -> CommandBuffer<'a> {
+        CommandBuffer {
+            words: Vec::with_capacity(words),
+            _values: PhantomData,
+        }
+    }
+
+    /// Returns whether there are no calls recorded in this buffer.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Records a call of `func` with `this` as its `this` value, equivalent
+    /// to `func.call(this, ...)` in JS.
+    pub fn call<'b>(&'b mut self, func: &'a JsValue, this: &'a JsValue) -> Command<'b, 'a> {
+        self.words.push(CMD_CALL);
+        self.words.push(func.idx as f64);
+        self.words.push(this.idx as f64);
+        self.command()
+    }
+
+    /// Records a call of the method `name` of `obj`, equivalent to
+    /// `obj[name](...)` in JS.
+    pub fn method<'b>(&'b mut self, obj: &'a JsValue, name: &'static str) -> Command<'b, 'a> {
+        self.words.push(CMD_METHOD);
+        self.words.push(obj.idx as f64);
+        self.push_str(name);
+        self.command()
+    }
+
+    /// Records setting the property `name` of `obj` to `value`, equivalent to
+    /// `obj[name] = value` in JS.
+    pub fn set<T>(&mut self, obj: &'a JsValue, name: &'static str, value: T)
+    where
+        T: Into<CommandArg<'a>>,
+    {
+        self.words.push(CMD_SET);
+        self.words.push(obj.idx as f64);
+        self.push_str(name);
+        self.push_arg(value.into());
+    }
+
+    /// Makes all of the calls recorded so far, in order, with a single call
+    /// into JS, and then clears the buffer.
+    pub fn flush(&mut self) {
+        if self.words.is_empty() {
+            return;
+        }
+        unsafe {
+            super::__wbindgen_replay_commands(self.words.as_ptr(), self.words.len());
+        }
+        self.words.clear();
+    }
+
+    fn command<'b>(&'b mut self) -> Command<'b, 'a> {
+        let argc = self.words.len();
+        self.words.push(0.0);
+        Command { buffer: self, argc }
+    }
+
+    fn push_str(&mut self, s: &'static str) {
+        self.words.push(s.as_ptr() as usize as f64);
+        self.words.push(s.len() as f64);
+    }
+
+    fn push_arg(&mut self, arg: CommandArg<'a>) {
+        match arg {
+            CommandArg::Number(n) => {
+                self.words.push(ARG_NUMBER);
+                self.words.push(n);
+            }
+            CommandArg::Bool(b) => {
+                self.words.push(ARG_BOOL);
+                self.words.push(if b { 1.0 } else { 0.0 });
+            }
+            CommandArg::Str(s) => {
+                self.words.push(ARG_STR);
+                self.push_str(s);
+            }
+            CommandArg::Value(v) => {
+                self.words.push(ARG_VALUE);
+                self.words.push(v.idx as f64);
+            }
+        }
+    }
+}
+
+impl<'b, 'a> Command<'b, 'a> {
+    /// Appends an argument to this call.
+    pub fn arg<T>(self, arg: T) -> Command<'b, 'a>
+    where
+        T: Into<CommandArg<'a>>,
+    {
+        self.buffer.push_arg(arg.into());
+        self.buffer.words[self.argc] += 1.0;
+        self
+    }
+}
+
+macro_rules! numbers {
+    ($($n:ident)*) => ($(
+        impl<'a> From<$n> for CommandArg<'a> {
+            fn from(n: $n) -> CommandArg<'a> {
+                CommandArg::Number(n as f64)
+            }
+        }
+    )*)
+}
+
+numbers! { i8 u8 i16 u16 i32 u32 f32 f64 }
+
+impl<'a> From<bool> for CommandArg<'a> {
+    fn from(b: bool) -> CommandArg<'a> {
+        CommandArg::Bool(b)
+    }
+}
+
+impl<'a> From<&'static str> for CommandArg<'a> {
+    fn from(s: &'static str) -> CommandArg<'a> {
+        CommandArg::Str(s)
+    }
+}
+
+impl<'a> From<&'a JsValue> for CommandArg<'a> {
+    fn from(v: &'a JsValue) -> CommandArg<'a> {
+        CommandArg::Value(v)
+    }
+}
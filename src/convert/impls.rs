@@ -1,10 +1,12 @@
 use core::char;
 use core::mem::{self, ManuallyDrop};
+use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize, Wrapping};
 
 use convert::{Stack, FromWasmAbi, IntoWasmAbi, RefFromWasmAbi};
-use convert::{OptionIntoWasmAbi, OptionFromWasmAbi};
+use convert::{OptionIntoWasmAbi, OptionFromWasmAbi, OptionRefFromWasmAbi};
 use convert::traits::WasmAbi;
 use JsValue;
+use {throw, throw_val};
 
 #[repr(C)]
 pub struct WasmOptionalI32 {
@@ -56,6 +58,44 @@ pub struct WasmOptional64 {
 
 unsafe impl WasmAbi for WasmOptional64 {}
 
+/// A string or slice, as a pointer to its first element and its length.
+#[repr(C)]
+pub struct WasmSlice {
+    pub ptr: u32,
+    pub len: u32,
+}
+
+unsafe impl WasmAbi for WasmSlice {}
+
+/// What a `None` string or slice is passed as.
+#[cfg(not(feature = "numbers-only"))]
+#[inline]
+pub(crate) fn null_slice() -> WasmSlice {
+    WasmSlice { ptr: 0, len: 0 }
+}
+
+/// A 128-bit integer split into four 32-bit limbs, starting from the lowest.
+#[repr(C)]
+pub struct Wasm128 {
+    pub low: u32,
+    pub mid_low: u32,
+    pub mid_high: u32,
+    pub high: u32,
+}
+
+unsafe impl WasmAbi for Wasm128 {}
+
+#[repr(C)]
+pub struct WasmOptional128 {
+    pub present: u32,
+    pub low: u32,
+    pub mid_low: u32,
+    pub mid_high: u32,
+    pub high: u32,
+}
+
+unsafe impl WasmAbi for WasmOptional128 {}
+
 macro_rules! type_wasm_native {
     ($($t:tt as $c:tt => $r:tt)*) => ($(
         impl IntoWasmAbi for $t {
@@ -202,6 +242,76 @@ macro_rules! type_64 {
 
 type_64!(i64 u64);
 
+macro_rules! type_128 {
+    ($($t:tt)*) => ($(
+        impl IntoWasmAbi for $t {
+            type Abi = Wasm128;
+
+            #[inline]
+            fn into_abi(self, _extra: &mut Stack) -> Wasm128 {
+                Wasm128 {
+                    low: self as u32,
+                    mid_low: (self >> 32) as u32,
+                    mid_high: (self >> 64) as u32,
+                    high: (self >> 96) as u32,
+                }
+            }
+        }
+
+        impl FromWasmAbi for $t {
+            type Abi = Wasm128;
+
+            #[inline]
+            unsafe fn from_abi(js: Wasm128, _extra: &mut Stack) -> $t {
+                (js.low as $t)
+                    | ((js.mid_low as $t) << 32)
+                    | ((js.mid_high as $t) << 64)
+                    | ((js.high as $t) << 96)
+            }
+        }
+
+        impl IntoWasmAbi for Option<$t> {
+            type Abi = WasmOptional128;
+
+            fn into_abi(self, _extra: &mut Stack) -> WasmOptional128 {
+                match self {
+                    None => WasmOptional128 {
+                        present: 0,
+                        low: 0,
+                        mid_low: 0,
+                        mid_high: 0,
+                        high: 0,
+                    },
+                    Some(me) => WasmOptional128 {
+                        present: 1,
+                        low: me as u32,
+                        mid_low: (me >> 32) as u32,
+                        mid_high: (me >> 64) as u32,
+                        high: (me >> 96) as u32,
+                    },
+                }
+            }
+        }
+
+        impl FromWasmAbi for Option<$t> {
+            type Abi = WasmOptional128;
+
+            unsafe fn from_abi(js: WasmOptional128, _extra: &mut Stack) -> Self {
+                if js.present == 0 {
+                    None
+                } else {
+                    Some((js.low as $t)
+                        | ((js.mid_low as $t) << 32)
+                        | ((js.mid_high as $t) << 64)
+                        | ((js.high as $t) << 96))
+                }
+            }
+        }
+    )*)
+}
+
+type_128!(i128 u128);
+
 impl IntoWasmAbi for bool {
     type Abi = u32;
 
@@ -244,7 +354,16 @@ impl FromWasmAbi for char {
 
     #[inline]
     unsafe fn from_abi(js: u32, _extra: &mut Stack) -> char {
-        char::from_u32_unchecked(js)
+        from_code_point(js)
+    }
+}
+
+/// Converts a code point received from JS, which may be a lone surrogate, into
+/// a `char`.
+fn from_code_point(js: u32) -> char {
+    match char::from_u32(js) {
+        Some(c) => c,
+        None => throw("invalid char code point passed"),
     }
 }
 
@@ -272,11 +391,94 @@ impl FromWasmAbi for Option<char> {
         if js.present == 0 {
             None
         } else {
-            Some(char::from_u32_unchecked(js.value))
+            Some(from_code_point(js.value))
+        }
+    }
+}
+
+macro_rules! type_non_zero {
+    ($($t:tt as $i:tt)*) => ($(
+        impl IntoWasmAbi for $t {
+            type Abi = <$i as IntoWasmAbi>::Abi;
+
+            #[inline]
+            fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+                self.get().into_abi(extra)
+            }
+        }
+
+        impl FromWasmAbi for $t {
+            type Abi = <$i as FromWasmAbi>::Abi;
+
+            #[inline]
+            unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+                match $t::new(<$i>::from_abi(js, extra)) {
+                    Some(n) => n,
+                    None => throw(concat!("zero passed as a `", stringify!($t), "`")),
+                }
+            }
+        }
+
+        impl IntoWasmAbi for Option<$t> {
+            type Abi = <Option<$i> as IntoWasmAbi>::Abi;
+
+            #[inline]
+            fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+                self.map(|n| n.get()).into_abi(extra)
+            }
+        }
+
+        impl FromWasmAbi for Option<$t> {
+            type Abi = <Option<$i> as FromWasmAbi>::Abi;
+
+            #[inline]
+            unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+                <Option<$i>>::from_abi(js, extra).map(|n| match $t::new(n) {
+                    Some(n) => n,
+                    None => throw(concat!("zero passed as a `", stringify!($t), "`")),
+                })
+            }
         }
+    )*)
+}
+
+type_non_zero!(
+    NonZeroU8 as u8
+    NonZeroU16 as u16
+    NonZeroU32 as u32
+    NonZeroU64 as u64
+    NonZeroU128 as u128
+    NonZeroUsize as usize
+);
+
+impl<T: IntoWasmAbi> IntoWasmAbi for Wrapping<T> {
+    type Abi = T::Abi;
+
+    #[inline]
+    fn into_abi(self, extra: &mut Stack) -> T::Abi {
+        self.0.into_abi(extra)
     }
 }
 
+impl<T: FromWasmAbi> FromWasmAbi for Wrapping<T> {
+    type Abi = T::Abi;
+
+    #[inline]
+    unsafe fn from_abi(js: T::Abi, extra: &mut Stack) -> Self {
+        Wrapping(T::from_abi(js, extra))
+    }
+}
+
+impl<T: OptionIntoWasmAbi> OptionIntoWasmAbi for Wrapping<T> {
+    #[inline]
+    fn none() -> T::Abi { T::none() }
+}
+
+impl<T: OptionFromWasmAbi> OptionFromWasmAbi for Wrapping<T> {
+    #[inline]
+    fn is_none(js: &T::Abi) -> bool { T::is_none(js) }
+}
+
 impl<T> IntoWasmAbi for *const T {
     type Abi = u32;
 
@@ -348,6 +550,20 @@ impl RefFromWasmAbi for JsValue {
     }
 }
 
+/// `None` is passed as index 0 of the heap, where `undefined` always lives, and
+/// the JS glue passes `null` as index 0 too. So `Option<&JsValue>` can't tell an
+/// argument of `undefined` or `null` apart from a missing one, all of which are
+/// `None`, and it's never `Some` of `undefined` or `null`.
+///
+/// Borrowed values are otherwise passed on the stack, at odd indices, so index
+/// 0 is never a `Some`.
+impl OptionRefFromWasmAbi for JsValue {
+    #[inline]
+    fn is_none(abi: &u32) -> bool {
+        *abi == 0
+    }
+}
+
 impl<T: OptionIntoWasmAbi> IntoWasmAbi for Option<T> {
     type Abi = T::Abi;
 
@@ -370,3 +586,63 @@ impl<T: OptionFromWasmAbi> FromWasmAbi for Option<T> {
         }
     }
 }
+
+/// Returning an `Err` from an exported function throws the error, as JS
+/// code would, so an exported constructor can fail by returning
+/// `Result<Self, JsValue>`.
+///
+/// The error can be anything which converts into a `JsValue`, such as a
+/// `js_sys::TypeError` or an exported Rust type, so that JS callers can tell
+/// failures apart with `instanceof`.
+impl<T: IntoWasmAbi, E: Into<JsValue>> IntoWasmAbi for Result<T, E> {
+    type Abi = T::Abi;
+
+    fn into_abi(self, extra: &mut Stack) -> T::Abi {
+        match self {
+            Ok(me) => me.into_abi(extra),
+            Err(e) => throw_val(e.into()),
+        }
+    }
+}
+
+/// An exported function which can only fail, returning `Result<(), E>`,
+/// returns nothing to JS if it succeeds.
+impl<E: Into<JsValue>> IntoWasmAbi for Result<(), E> {
+    type Abi = ();
+
+    fn into_abi(self, _extra: &mut Stack) {
+        if let Err(e) = self {
+            throw_val(e.into());
+        }
+    }
+}
+
+macro_rules! tuples {
+    ($($name:ident => ($($t:ident $f:ident)*))*) => ($(
+        #[repr(C)]
+        pub struct $name<$($t),*> {
+            $(pub $f: $t,)*
+        }
+
+        unsafe impl<$($t: WasmAbi),*> WasmAbi for $name<$($t),*> {}
+
+        impl<$($t: IntoWasmAbi),*> IntoWasmAbi for ($($t,)*) {
+            type Abi = $name<$(<$t as IntoWasmAbi>::Abi),*>;
+
+            #[inline]
+            fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+                let ($($f,)*) = self;
+                $name {
+                    $($f: $f.into_abi(extra),)*
+                }
+            }
+        }
+    )*)
+}
+
+// Tuples are returned as a struct of their elements' ABI representations,
+// which the JS glue reads back out of the return pointer into an array.
+tuples! {
+    WasmTuple2 => (A a B b)
+    WasmTuple3 => (A a B b C c)
+}
@@ -0,0 +1,108 @@
+//! Maps with `String` keys are passed as a `Box<[JsValue]>` of their keys and
+//! values, one after the other, which the JS glue converts from and to either
+//! a plain object or a `Map`.
+
+use std::prelude::v1::*;
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+use convert::{FromWasmAbi, IntoWasmAbi, MapValue, Stack, WasmSlice};
+use JsValue;
+
+fn into_entries<I, V>(map: I) -> Box<[JsValue]>
+where
+    I: IntoIterator<Item = (String, V)>,
+    V: MapValue,
+{
+    let mut entries = Vec::new();
+    for (key, value) in map {
+        entries.push(JsValue::from(key));
+        entries.push(value.into());
+    }
+    entries.into_boxed_slice()
+}
+
+unsafe fn from_entries<V: MapValue>(js: WasmSlice, extra: &mut Stack) -> Vec<(String, V)> {
+    let entries = <Box<[JsValue]>>::from_abi(js, extra).into_vec();
+    let mut pairs = Vec::with_capacity(entries.len() / 2);
+    let mut entries = entries.into_iter();
+    while let (Some(key), Some(value)) = (entries.next(), entries.next()) {
+        let key = match key.as_string() {
+            Some(key) => key,
+            None => ::throw("map keys must be strings"),
+        };
+        let value = match V::from_js_value(value) {
+            Some(value) => value,
+            None => ::throw("invalid map value"),
+        };
+        pairs.push((key, value));
+    }
+    pairs
+}
+
+impl<V: MapValue, S: BuildHasher> IntoWasmAbi for HashMap<String, V, S> {
+    type Abi = WasmSlice;
+
+    #[inline]
+    fn into_abi(self, extra: &mut Stack) -> WasmSlice {
+        into_entries(self).into_abi(extra)
+    }
+}
+
+impl<V: MapValue, S: BuildHasher + Default> FromWasmAbi for HashMap<String, V, S> {
+    type Abi = WasmSlice;
+
+    #[inline]
+    unsafe fn from_abi(js: WasmSlice, extra: &mut Stack) -> Self {
+        from_entries(js, extra).into_iter().collect()
+    }
+}
+
+impl<V: MapValue> IntoWasmAbi for BTreeMap<String, V> {
+    type Abi = WasmSlice;
+
+    #[inline]
+    fn into_abi(self, extra: &mut Stack) -> WasmSlice {
+        into_entries(self).into_abi(extra)
+    }
+}
+
+impl<V: MapValue> FromWasmAbi for BTreeMap<String, V> {
+    type Abi = WasmSlice;
+
+    #[inline]
+    unsafe fn from_abi(js: WasmSlice, extra: &mut Stack) -> Self {
+        from_entries(js, extra).into_iter().collect()
+    }
+}
+
+macro_rules! numbers {
+    ($($n:ident)*) => ($(
+        impl MapValue for $n {
+            fn from_js_value(value: JsValue) -> Option<$n> {
+                value.as_f64().map(|n| n as $n)
+            }
+        }
+    )*)
+}
+
+numbers! { i8 u8 i16 u16 i32 u32 f32 f64 }
+
+impl MapValue for bool {
+    fn from_js_value(value: JsValue) -> Option<bool> {
+        value.as_bool()
+    }
+}
+
+impl MapValue for String {
+    fn from_js_value(value: JsValue) -> Option<String> {
+        value.as_string()
+    }
+}
+
+impl MapValue for JsValue {
+    fn from_js_value(value: JsValue) -> Option<JsValue> {
+        Some(value)
+    }
+}
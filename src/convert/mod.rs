@@ -3,10 +3,17 @@
 
 mod traits;
 mod impls;
+#[cfg(not(feature = "numbers-only"))]
 mod slices;
+#[cfg(not(feature = "numbers-only"))]
+mod strings;
+#[cfg(not(feature = "numbers-only"))]
 mod closures;
+#[cfg(all(feature = "std", not(feature = "numbers-only")))]
+mod maps;
+mod time;
 
-pub use self::slices::WasmSlice;
+pub use self::impls::WasmSlice;
 pub use self::traits::*;
 
 pub struct GlobalStack {
@@ -2,27 +2,15 @@
 use std::prelude::v1::*;
 
 use core::slice;
-use core::str;
 
-use convert::{WasmAbi, IntoWasmAbi, FromWasmAbi, RefFromWasmAbi, RefMutFromWasmAbi};
-use convert::{Stack, OptionIntoWasmAbi};
+use convert::{IntoWasmAbi, FromWasmAbi, RefFromWasmAbi, RefMutFromWasmAbi};
+use convert::{Stack, OptionIntoWasmAbi, WasmSlice};
+use convert::impls::null_slice;
+use JsValue;
 
 if_std! {
-    use core::mem;
-    use convert::OptionFromWasmAbi;
-}
-
-#[repr(C)]
-pub struct WasmSlice {
-    pub ptr: u32,
-    pub len: u32,
-}
-
-unsafe impl WasmAbi for WasmSlice {}
-
-#[inline]
-fn null_slice() -> WasmSlice {
-    WasmSlice { ptr: 0, len: 0 }
+    use convert::{OptionFromWasmAbi, VectorIntoWasmAbi};
+    use __rt;
 }
 
 macro_rules! vectors {
@@ -33,9 +21,7 @@ macro_rules! vectors {
 
                 #[inline]
                 fn into_abi(self, extra: &mut Stack) -> WasmSlice {
-                    let ptr = self.as_ptr();
-                    let len = self.len();
-                    mem::forget(self);
+                    let (ptr, len) = __rt::glue_buffer(self.into_vec());
                     WasmSlice {
                         ptr: ptr.into_abi(extra),
                         len: len as u32,
@@ -54,7 +40,7 @@ macro_rules! vectors {
                 unsafe fn from_abi(js: WasmSlice, extra: &mut Stack) -> Self {
                     let ptr = <*mut $t>::from_abi(js.ptr, extra);
                     let len = js.len as usize;
-                    Vec::from_raw_parts(ptr, len, len).into_boxed_slice()
+                    __rt::take_glue_buffer(ptr, len).into_boxed_slice()
                 }
             }
 
@@ -84,7 +70,10 @@ macro_rules! vectors {
 
             #[inline]
             fn into_abi(self, extra: &mut Stack) -> WasmSlice {
-                (&*self).into_abi(extra)
+                WasmSlice {
+                    ptr: self.as_mut_ptr().into_abi(extra),
+                    len: self.len() as u32,
+                }
             }
         }
 
@@ -150,68 +139,33 @@ if_std! {
     impl<T> OptionFromWasmAbi for Vec<T> where Box<[T]>: FromWasmAbi<Abi = WasmSlice> {
         fn is_none(abi: &WasmSlice) -> bool { abi.ptr == 0 }
     }
-
-    impl IntoWasmAbi for String {
-        type Abi = <Vec<u8> as IntoWasmAbi>::Abi;
-
-        #[inline]
-        fn into_abi(self, extra: &mut Stack) -> Self::Abi {
-            self.into_bytes().into_abi(extra)
-        }
-    }
-
-    impl OptionIntoWasmAbi for String {
-        fn none() -> WasmSlice { null_slice() }
-    }
-
-    impl FromWasmAbi for String {
-        type Abi = <Vec<u8> as FromWasmAbi>::Abi;
-
-        #[inline]
-        unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
-            String::from_utf8_unchecked(<Vec<u8>>::from_abi(js, extra))
-        }
-    }
-
-    impl OptionFromWasmAbi for String {
-        fn is_none(slice: &WasmSlice) -> bool { slice.ptr == 0 }
-    }
 }
 
-impl<'a> IntoWasmAbi for &'a str {
-    type Abi = <&'a [u8] as IntoWasmAbi>::Abi;
+// A borrowed slice of `JsValue`s is passed as the heap indices of its values,
+// which JS looks up without taking ownership of them.
+impl<'a> IntoWasmAbi for &'a [JsValue] {
+    type Abi = WasmSlice;
 
     #[inline]
-    fn into_abi(self, extra: &mut Stack) -> Self::Abi {
-        self.as_bytes().into_abi(extra)
+    fn into_abi(self, extra: &mut Stack) -> WasmSlice {
+        WasmSlice {
+            ptr: self.as_ptr().into_abi(extra),
+            len: self.len() as u32,
+        }
     }
 }
 
-impl<'a> OptionIntoWasmAbi for &'a str {
+impl<'a> OptionIntoWasmAbi for &'a [JsValue] {
     fn none() -> WasmSlice { null_slice() }
 }
 
-impl RefFromWasmAbi for str {
-    type Abi = <[u8] as RefFromWasmAbi>::Abi;
-    type Anchor = &'static str;
-
-    #[inline]
-    unsafe fn ref_from_abi(js: Self::Abi, extra: &mut Stack) -> Self::Anchor {
-        str::from_utf8_unchecked(<[u8]>::ref_from_abi(js, extra))
-    }
-}
-
 if_std! {
-    use JsValue;
-
     impl IntoWasmAbi for Box<[JsValue]> {
         type Abi = WasmSlice;
 
         #[inline]
         fn into_abi(self, extra: &mut Stack) -> WasmSlice {
-            let ptr = self.as_ptr();
-            let len = self.len();
-            mem::forget(self);
+            let (ptr, len) = __rt::glue_buffer(self.into_vec());
             WasmSlice {
                 ptr: ptr.into_abi(extra),
                 len: len as u32,
@@ -230,11 +184,32 @@ if_std! {
         unsafe fn from_abi(js: WasmSlice, extra: &mut Stack) -> Self {
             let ptr = <*mut JsValue>::from_abi(js.ptr, extra);
             let len = js.len as usize;
-            Vec::from_raw_parts(ptr, len, len).into_boxed_slice()
+            __rt::take_glue_buffer(ptr, len).into_boxed_slice()
         }
     }
 
     impl OptionFromWasmAbi for Box<[JsValue]> {
         fn is_none(slice: &WasmSlice) -> bool { slice.ptr == 0 }
     }
+
+    // Other vectors are converted to `JsValue`s first, so JS receives them
+    // the same way as a `Box<[JsValue]>` and only needs to know the type of
+    // their elements for the TypeScript definitions.
+    impl<T: VectorIntoWasmAbi> IntoWasmAbi for Box<[T]> {
+        type Abi = WasmSlice;
+
+        #[inline]
+        fn into_abi(self, extra: &mut Stack) -> WasmSlice {
+            let values = self
+                .into_vec()
+                .into_iter()
+                .map(Into::into)
+                .collect::<Box<[JsValue]>>();
+            values.into_abi(extra)
+        }
+    }
+
+    impl<T: VectorIntoWasmAbi> OptionIntoWasmAbi for Box<[T]> {
+        fn none() -> WasmSlice { null_slice() }
+    }
 }
@@ -0,0 +1,76 @@
+//! Strings are passed as a `WasmSlice` of their UTF-8 bytes.
+
+#[cfg(feature = "std")]
+use std::prelude::v1::*;
+
+use core::slice;
+use core::str;
+
+use convert::{IntoWasmAbi, FromWasmAbi, RefFromWasmAbi, Stack, OptionIntoWasmAbi, WasmSlice};
+use convert::impls::null_slice;
+
+if_std! {
+    use convert::{OptionFromWasmAbi, VectorIntoWasmAbi};
+    use __rt;
+
+    impl IntoWasmAbi for String {
+        type Abi = WasmSlice;
+
+        #[inline]
+        fn into_abi(self, extra: &mut Stack) -> WasmSlice {
+            let (ptr, len) = __rt::glue_buffer(self.into_bytes());
+            WasmSlice {
+                ptr: ptr.into_abi(extra),
+                len: len as u32,
+            }
+        }
+    }
+
+    impl OptionIntoWasmAbi for String {
+        fn none() -> WasmSlice { null_slice() }
+    }
+
+    impl FromWasmAbi for String {
+        type Abi = WasmSlice;
+
+        #[inline]
+        unsafe fn from_abi(js: WasmSlice, extra: &mut Stack) -> Self {
+            let ptr = <*mut u8>::from_abi(js.ptr, extra);
+            let len = js.len as usize;
+            String::from_utf8_unchecked(__rt::take_glue_buffer(ptr, len))
+        }
+    }
+
+    impl OptionFromWasmAbi for String {
+        fn is_none(slice: &WasmSlice) -> bool { slice.ptr == 0 }
+    }
+
+    impl VectorIntoWasmAbi for String {}
+}
+
+impl<'a> IntoWasmAbi for &'a str {
+    type Abi = WasmSlice;
+
+    #[inline]
+    fn into_abi(self, extra: &mut Stack) -> WasmSlice {
+        WasmSlice {
+            ptr: self.as_ptr().into_abi(extra),
+            len: self.len() as u32,
+        }
+    }
+}
+
+impl<'a> OptionIntoWasmAbi for &'a str {
+    fn none() -> WasmSlice { null_slice() }
+}
+
+impl RefFromWasmAbi for str {
+    type Abi = WasmSlice;
+    type Anchor = &'static str;
+
+    #[inline]
+    unsafe fn ref_from_abi(js: WasmSlice, extra: &mut Stack) -> Self::Anchor {
+        let ptr = <*const u8>::from_abi(js.ptr, extra);
+        str::from_utf8_unchecked(slice::from_raw_parts(ptr, js.len as usize))
+    }
+}
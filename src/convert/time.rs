@@ -0,0 +1,119 @@
+use core::time::Duration;
+
+use convert::{Stack, FromWasmAbi, IntoWasmAbi};
+use convert::impls::WasmOptionalF64;
+use throw;
+
+if_std! {
+    use std::time::{SystemTime, UNIX_EPOCH};
+}
+
+// Both `Duration` and `SystemTime` are passed as a number of milliseconds,
+// matching `Date.prototype.getTime` and the arguments to `setTimeout`.
+
+fn duration_to_millis(d: Duration) -> f64 {
+    d.as_secs() as f64 * 1000.0 + d.subsec_nanos() as f64 / 1_000_000.0
+}
+
+fn millis_to_duration(ms: f64) -> Duration {
+    if !(ms >= 0.0) || ms == ::core::f64::INFINITY {
+        throw("negative or non-finite number passed as a `Duration`");
+    }
+    let secs = (ms / 1000.0) as u64;
+    let nanos = ((ms % 1000.0) * 1_000_000.0) as u32;
+    Duration::new(secs, nanos)
+}
+
+impl IntoWasmAbi for Duration {
+    type Abi = f64;
+
+    #[inline]
+    fn into_abi(self, _extra: &mut Stack) -> f64 {
+        duration_to_millis(self)
+    }
+}
+
+impl FromWasmAbi for Duration {
+    type Abi = f64;
+
+    #[inline]
+    unsafe fn from_abi(js: f64, _extra: &mut Stack) -> Duration {
+        millis_to_duration(js)
+    }
+}
+
+impl IntoWasmAbi for Option<Duration> {
+    type Abi = WasmOptionalF64;
+
+    #[inline]
+    fn into_abi(self, extra: &mut Stack) -> WasmOptionalF64 {
+        self.map(duration_to_millis).into_abi(extra)
+    }
+}
+
+impl FromWasmAbi for Option<Duration> {
+    type Abi = WasmOptionalF64;
+
+    #[inline]
+    unsafe fn from_abi(js: WasmOptionalF64, extra: &mut Stack) -> Self {
+        <Option<f64>>::from_abi(js, extra).map(millis_to_duration)
+    }
+}
+
+if_std! {
+    fn system_time_to_millis(t: SystemTime) -> f64 {
+        match t.duration_since(UNIX_EPOCH) {
+            Ok(d) => duration_to_millis(d),
+            Err(e) => -duration_to_millis(e.duration()),
+        }
+    }
+
+    // On `wasm32-unknown-unknown` a `SystemTime` can't represent a point in
+    // time before the Unix epoch, so those dates are rejected along with
+    // invalid ones.
+    fn millis_to_system_time(ms: f64) -> SystemTime {
+        if ms != ms {
+            throw("invalid `Date` passed as a `SystemTime`");
+        }
+        if ms < 0.0 {
+            throw("`Date` before 1970 passed as a `SystemTime`");
+        }
+        UNIX_EPOCH + millis_to_duration(ms)
+    }
+
+    impl IntoWasmAbi for SystemTime {
+        type Abi = f64;
+
+        #[inline]
+        fn into_abi(self, _extra: &mut Stack) -> f64 {
+            system_time_to_millis(self)
+        }
+    }
+
+    impl FromWasmAbi for SystemTime {
+        type Abi = f64;
+
+        #[inline]
+        unsafe fn from_abi(js: f64, _extra: &mut Stack) -> SystemTime {
+            millis_to_system_time(js)
+        }
+    }
+
+    impl IntoWasmAbi for Option<SystemTime> {
+        type Abi = WasmOptionalF64;
+
+        #[inline]
+        fn into_abi(self, extra: &mut Stack) -> WasmOptionalF64 {
+            self.map(system_time_to_millis).into_abi(extra)
+        }
+    }
+
+    impl FromWasmAbi for Option<SystemTime> {
+        type Abi = WasmOptionalF64;
+
+        #[inline]
+        unsafe fn from_abi(js: WasmOptionalF64, extra: &mut Stack) -> Self {
+            <Option<f64>>::from_abi(js, extra).map(millis_to_system_time)
+        }
+    }
+}
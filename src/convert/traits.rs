@@ -1,6 +1,7 @@
 use core::ops::{Deref, DerefMut};
 
 use describe::*;
+use JsValue;
 
 /// A trait for anything that can be converted into a type that can cross the
 /// wasm ABI directly, eg `u32` or `f64`.
@@ -90,6 +91,49 @@ pub trait OptionFromWasmAbi: FromWasmAbi {
     fn is_none(abi: &Self::Abi) -> bool;
 }
 
+/// Indicates that an exported function can take `Option<&Self>` as an
+/// argument.
+///
+/// JS passes `Some` the same way as `&Self`, and `None` as an ABI value which
+/// `RefFromWasmAbi` can never receive otherwise.
+pub trait OptionRefFromWasmAbi: RefFromWasmAbi {
+    /// Tests whether the argument is a "none" instance. If so it will be
+    /// deserialized as `None`, and otherwise it will be passed to
+    /// `RefFromWasmAbi`.
+    fn is_none(abi: &Self::Abi) -> bool;
+}
+
+/// Dual of the `OptionRefFromWasmAbi` trait, except for mutable references.
+pub trait OptionRefMutFromWasmAbi: RefMutFromWasmAbi {
+    /// Same as `OptionRefFromWasmAbi::is_none`
+    fn is_none(abi: &Self::Abi) -> bool;
+}
+
+/// Indicates that a `Box<[Self]>` or `Vec<Self>` can be passed to JS as an
+/// `Array` of the JS values of its elements.
+///
+/// This is implemented for `String`, and for exported Rust structs, whose
+/// vectors JS receives as arrays of strings and class instances.
+pub trait VectorIntoWasmAbi: WasmDescribe + Into<JsValue> {}
+
+/// Indicates that `HashMap<String, Self>` and `BTreeMap<String, Self>` can be
+/// passed between Rust and JS, with each value crossing the boundary as its
+/// `JsValue`.
+///
+/// This is implemented for numbers, `bool`, `String` and `JsValue`, whose
+/// types the JS glue checks before passing a map in.
+pub trait MapValue: WasmDescribe + Into<JsValue> + Sized {
+    /// Converts a value of a map received from JS, returning `None` if it's
+    /// of the wrong type.
+    fn from_js_value(value: JsValue) -> Option<Self>;
+}
+
+/// Indicates that this type is received from JS as a plain options object,
+/// with each field read from a property of the same name.
+///
+/// This trait is implemented by `#[wasm_bindgen(options)]` structs.
+pub trait WasmOptions: FromWasmAbi {}
+
 pub trait Stack {
     fn push(&mut self, bits: u32);
 }
@@ -106,3 +150,4 @@ unsafe impl WasmAbi for u32 {}
 unsafe impl WasmAbi for i32 {}
 unsafe impl WasmAbi for f32 {}
 unsafe impl WasmAbi for f64 {}
+unsafe impl WasmAbi for () {}
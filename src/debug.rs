@@ -0,0 +1,46 @@
+//! Debugging support for finding leaked `JsValue`s
+//!
+//! Every `JsValue` owned by Rust, including the JS functions of `Closure`s,
+//! is an entry in a heap maintained by the generated JS glue. Values which
+//! are never dropped stay in that heap forever, and the functions here report
+//! what it currently holds.
+
+use JsValue;
+
+/// Statistics about the heap of JS values owned by Rust, see `heap_stats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapStats {
+    /// The number of JS values currently owned by Rust.
+    pub live: usize,
+    /// The number of slots in the heap, including both live and free ones.
+    pub capacity: usize,
+}
+
+/// Returns statistics about the heap of JS values owned by Rust.
+///
+/// Checking that `live` returns to the same number after some work is done
+/// is a cheap way to find out whether that work leaks `JsValue`s.
+pub fn heap_stats() -> HeapStats {
+    let mut ret = [0u32; 2];
+    unsafe {
+        super::__wbindgen_heap_stats(ret.as_mut_ptr());
+    }
+    HeapStats {
+        live: ret[0] as usize,
+        capacity: ret[1] as usize,
+    }
+}
+
+/// Returns a JS array describing every JS value currently owned by Rust.
+///
+/// Each element is an object with the `value` itself, the `index` Rust uses
+/// to refer to it, and the number of `refs` to it. When bindings are
+/// generated with `--debug` it also has the `stack` trace of where the value
+/// was first passed to Rust, which is usually enough to find a leak. The same
+/// array is returned by the `__wbindgen_heap_dump` function exported from the
+/// generated JS in that case.
+///
+/// Note that the returned array is itself owned by Rust until it's dropped.
+pub fn heap_dump() -> JsValue {
+    unsafe { JsValue { idx: super::__wbindgen_heap_entries() } }
+}
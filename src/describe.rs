@@ -14,7 +14,9 @@ macro_rules! tys {
     }
 }
 
-// NB: this list must be kept in sync with `crates/cli-support/src/descriptor.rs`
+// NB: this list must be kept in sync with `crates/cli-support/src/descriptor.rs`,
+// and as it's part of the stable ABI new entries go at the end, see
+// `crates/cli-support/src/stable_abi.rs`.
 tys! {
     I8
     U8
@@ -24,6 +26,8 @@ tys! {
     U32
     I64
     U64
+    I128
+    U128
     F32
     F64
     BOOLEAN
@@ -39,20 +43,173 @@ tys! {
     RUST_STRUCT
     CHAR
     OPTIONAL
+    OPTIONS
+    DATE
+    TUPLE
+    UNION
+    STRING_ENUM
+    UNIT
+    MAP
 }
 
 pub fn inform(a: u32) {
     unsafe { super::__wbindgen_describe(a) }
 }
 
+/// The numbers describing a type, as constant data which with the
+/// `static-descriptors` feature the macro writes into the
+/// `__wasm_bindgen_descriptors` section rather than emitting a function for
+/// the CLI to run.
+#[derive(Copy, Clone)]
+pub enum Description {
+    Numbers(&'static [u32]),
+    Concat(&'static [Description]),
+    /// The description of a type which only implements
+    /// `WasmDescribe::describe`, and so can't be used with
+    /// `static-descriptors`.
+    Unknown,
+}
+
+impl Description {
+    /// Informs the CLI of the numbers in the description, as `describe` does.
+    pub fn inform(&self) {
+        match *self {
+            Description::Numbers(numbers) => {
+                for n in numbers {
+                    inform(*n);
+                }
+            }
+            Description::Concat(parts) => {
+                for part in parts {
+                    part.inform();
+                }
+            }
+            Description::Unknown => {
+                panic!("a type implements neither `describe` nor `DESCRIPTION`")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "static-descriptors")]
+impl Description {
+    /// The number of numbers in the description.
+    pub const fn len(&self) -> usize {
+        match *self {
+            Description::Numbers(numbers) => numbers.len(),
+            Description::Concat(parts) => {
+                let mut len = 0;
+                let mut i = 0;
+                while i < parts.len() {
+                    len += parts[i].len();
+                    i += 1;
+                }
+                len
+            }
+            Description::Unknown => panic!(
+                "a type used with the `static-descriptors` feature only implements \
+                 `WasmDescribe::describe`, and needs a `DESCRIPTION`"
+            ),
+        }
+    }
+
+    const fn write(&self, dst: &mut [u8], mut pos: usize) -> usize {
+        match *self {
+            Description::Numbers(numbers) => {
+                let mut i = 0;
+                while i < numbers.len() {
+                    pos = write_u32(dst, pos, numbers[i]);
+                    i += 1;
+                }
+                pos
+            }
+            Description::Concat(parts) => {
+                let mut i = 0;
+                while i < parts.len() {
+                    pos = parts[i].write(dst, pos);
+                    i += 1;
+                }
+                pos
+            }
+            // Already rejected by `len`.
+            Description::Unknown => pos,
+        }
+    }
+}
+
+#[cfg(feature = "static-descriptors")]
+const fn write_u32(dst: &mut [u8], pos: usize, n: u32) -> usize {
+    let bytes = n.to_le_bytes();
+    let mut i = 0;
+    while i < 4 {
+        dst[pos + i] = bytes[i];
+        i += 1;
+    }
+    pos + 4
+}
+
+/// The length of `encode(name, description)`.
+#[cfg(feature = "static-descriptors")]
+pub const fn encoded_len(name: &str, description: &Description) -> usize {
+    4 + name.len() + 4 + 4 * description.len()
+}
+
+/// Encodes a description into the `__wasm_bindgen_descriptors` section: the
+/// length of the name of the item it describes and the name, followed by the
+/// count of numbers and the numbers, all little-endian.
+#[cfg(feature = "static-descriptors")]
+pub const fn encode<const N: usize>(name: &str, description: &Description) -> [u8; N] {
+    let mut dst = [0; N];
+    let name = name.as_bytes();
+    let mut pos = write_u32(&mut dst, 0, name.len() as u32);
+    let mut i = 0;
+    while i < name.len() {
+        dst[pos] = name[i];
+        pos += 1;
+        i += 1;
+    }
+    pos = write_u32(&mut dst, pos, description.len() as u32);
+    description.write(&mut dst, pos);
+    dst
+}
+
+/// Describes a type to the CLI.
+///
+/// Implementations can provide either item, and have the same shape with or
+/// without the `static-descriptors` feature, which only uses `DESCRIPTION`.
+/// `describe` informs the CLI of the numbers in `DESCRIPTION` by default.
 pub trait WasmDescribe {
-    fn describe();
+    const DESCRIPTION: Description = Description::Unknown;
+
+    fn describe() {
+        Self::DESCRIPTION.inform()
+    }
+}
+
+// Implements `WasmDescribe` from the numbers and types describing a type in
+// order, written as `(number)` and `[type]`, as a `Description`, and without
+// `static-descriptors` as a `describe` function informing the CLI of them.
+// Both are there so that other impls can be written in terms of either.
+macro_rules! describe {
+    (@inform ($n:expr)) => (inform($n));
+    (@inform [$t:ty]) => (<$t as WasmDescribe>::describe());
+    (@description ($n:expr)) => (Description::Numbers(&[$n]));
+    (@description [$t:ty]) => (<$t as WasmDescribe>::DESCRIPTION);
+    ($($piece:tt)*) => (
+        #[cfg(not(feature = "static-descriptors"))]
+        fn describe() {
+            $(describe!(@inform $piece);)*
+        }
+
+        const DESCRIPTION: Description =
+            Description::Concat(&[$(describe!(@description $piece)),*]);
+    );
 }
 
 macro_rules! simple {
     ($($t:ident => $d:ident)*) => ($(
         impl WasmDescribe for $t {
-            fn describe() { inform($d) }
+            describe! { ($d) }
         }
     )*)
 }
@@ -66,6 +223,8 @@ simple! {
     u32 => U32
     i64 => I64
     u64 => U64
+    i128 => I128
+    u128 => U128
     isize => I32
     usize => U32
     f32 => F32
@@ -76,61 +235,82 @@ simple! {
     JsValue => ANYREF
 }
 
-impl<T> WasmDescribe for *const T {
-    fn describe() {
-        inform(I32)
+macro_rules! non_zero {
+    ($($t:ident => $i:ident)*) => ($(
+        impl WasmDescribe for ::core::num::$t {
+            describe! { [$i] }
+        }
+    )*)
+}
+
+non_zero! {
+    NonZeroU8 => u8
+    NonZeroU16 => u16
+    NonZeroU32 => u32
+    NonZeroU64 => u64
+    NonZeroU128 => u128
+    NonZeroUsize => usize
+}
+
+impl<T: WasmDescribe> WasmDescribe for ::core::num::Wrapping<T> {
+    describe! { [T] }
+}
+
+impl WasmDescribe for ::core::time::Duration {
+    describe! { (F64) }
+}
+
+if_std! {
+    impl WasmDescribe for ::std::time::SystemTime {
+        describe! { (DATE) }
     }
 }
 
+impl<T> WasmDescribe for *const T {
+    describe! { (I32) }
+}
+
 impl<T> WasmDescribe for *mut T {
-    fn describe() {
-        inform(I32)
-    }
+    describe! { (I32) }
 }
 
 impl<T: WasmDescribe> WasmDescribe for [T] {
-    fn describe() {
-        inform(SLICE);
-        T::describe();
-    }
+    describe! { (SLICE) [T] }
 }
 
 impl<'a, T: WasmDescribe + ?Sized> WasmDescribe for &'a T {
-    fn describe() {
-        inform(REF);
-        T::describe();
-    }
+    describe! { (REF) [T] }
 }
 
 impl<'a, T: WasmDescribe + ?Sized> WasmDescribe for &'a mut T {
-    fn describe() {
-        inform(REFMUT);
-        T::describe();
-    }
+    describe! { (REFMUT) [T] }
 }
 
 if_std! {
     use std::prelude::v1::*;
 
     impl WasmDescribe for String {
-        fn describe() { inform(STRING) }
+        describe! { (STRING) }
     }
 
     impl<T: WasmDescribe> WasmDescribe for Box<[T]> {
-        fn describe() {
-            inform(VECTOR);
-            T::describe();
-        }
+        describe! { (VECTOR) [T] }
     }
 
     impl<T> WasmDescribe for Vec<T> where Box<[T]>: WasmDescribe {
-        fn describe() {
-            <Box<[T]>>::describe();
-        }
+        describe! { [Box<[T]>] }
+    }
+
+    impl<V: WasmDescribe, S> WasmDescribe for ::std::collections::HashMap<String, V, S> {
+        describe! { (MAP) [V] }
+    }
+
+    impl<V: WasmDescribe> WasmDescribe for ::std::collections::BTreeMap<String, V> {
+        describe! { (MAP) [V] }
     }
 }
 
-fn _cnt<T: WasmDescribe>() -> u32 {
+const fn _cnt<T>() -> u32 {
     1
 }
 
@@ -140,48 +320,26 @@ macro_rules! doit {
             where $($var: WasmDescribe,)*
                   R: WasmDescribe
         {
-            fn describe() {
-                inform(FUNCTION);
-                inform(0 $(+ _cnt::<$var>())*);
-                $(<$var as WasmDescribe>::describe();)*
-                inform(1);
-                <R as WasmDescribe>::describe();
-            }
+            describe! { (FUNCTION) (0 $(+ _cnt::<$var>())*) $([$var])* (1) [R] }
         }
 
         impl<'a, $($var,)* > WasmDescribe for Fn($($var),*) + 'a
             where $($var: WasmDescribe,)*
         {
-            fn describe() {
-                inform(FUNCTION);
-                inform(0 $(+ _cnt::<$var>())*);
-                $(<$var as WasmDescribe>::describe();)*
-                inform(0);
-            }
+            describe! { (FUNCTION) (0 $(+ _cnt::<$var>())*) $([$var])* (0) }
         }
 
         impl<'a, $($var,)* R> WasmDescribe for FnMut($($var),*) -> R + 'a
             where $($var: WasmDescribe,)*
                   R: WasmDescribe
         {
-            fn describe() {
-                inform(FUNCTION);
-                inform(0 $(+ _cnt::<$var>())*);
-                $(<$var as WasmDescribe>::describe();)*
-                inform(1);
-                <R as WasmDescribe>::describe();
-            }
+            describe! { (FUNCTION) (0 $(+ _cnt::<$var>())*) $([$var])* (1) [R] }
         }
 
         impl<'a, $($var,)* > WasmDescribe for FnMut($($var),*) + 'a
             where $($var: WasmDescribe,)*
         {
-            fn describe() {
-                inform(FUNCTION);
-                inform(0 $(+ _cnt::<$var>())*);
-                $(<$var as WasmDescribe>::describe();)*
-                inform(0);
-            }
+            describe! { (FUNCTION) (0 $(+ _cnt::<$var>())*) $([$var])* (0) }
         }
     )*)
 }
@@ -197,9 +355,27 @@ doit! {
     (A B C D E F G)
 }
 
+macro_rules! tuples {
+    ($(($($t:ident)*))*) => ($(
+        impl<$($t: WasmDescribe),*> WasmDescribe for ($($t,)*) {
+            describe! { (TUPLE) (0 $(+ _cnt::<$t>())*) $([$t])* }
+        }
+    )*)
+}
+
+tuples! {
+    (A B)
+    (A B C)
+}
+
 impl<T: WasmDescribe> WasmDescribe for Option<T> {
-    fn describe() {
-        inform(OPTIONAL);
-        T::describe();
-    }
+    describe! { (OPTIONAL) [T] }
+}
+
+impl<E: Into<JsValue>> WasmDescribe for Result<(), E> {
+    describe! { (UNIT) }
+}
+
+impl<T: WasmDescribe, E: Into<JsValue>> WasmDescribe for Result<T, E> {
+    describe! { [T] }
 }
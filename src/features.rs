@@ -0,0 +1,23 @@
+//! Runtime checks for the JS features the generated glue can use
+//!
+//! The glue checks once, when it's loaded, for the features it has more than
+//! one codepath for, and these functions report which codepath it picked.
+
+/// Returns whether the JS engine supports `BigInt64Array`.
+///
+/// When it does, `i64` and `u64` values are passed to and from JS as
+/// `BigInt`s. Otherwise they're passed as numbers, which are exact only up to
+/// 2<sup>53</sup>, and slices of them can't be passed at all.
+pub fn supports_bigint64() -> bool {
+    unsafe { super::__wbindgen_supports_bigint64() != 0 }
+}
+
+/// Returns whether the JS engine supports `WeakRef` and
+/// `FinalizationRegistry`.
+///
+/// When it does, exported Rust structs are freed once the JS objects holding
+/// them are garbage collected, if they haven't been freed with `free()`
+/// already. Otherwise they're only freed by `free()`.
+pub fn supports_weak_ref() -> bool {
+    unsafe { super::__wbindgen_supports_weak_ref() != 0 }
+}
@@ -0,0 +1,79 @@
+//! Passing `f64`s between Rust and JS without losing the payload of NaNs
+//!
+//! A NaN's sign and payload bits can be changed whenever an `f64` crosses
+//! into JS: the standards let engines pick any NaN when a wasm `f64` becomes a
+//! JS number and back, and when one is stored into or loaded from a
+//! `Float64Array` or `DataView`, which is how `Option<f64>` is passed. Most
+//! engines happen to leave the bits of plain `f64` arguments alone, but
+//! interpreters and emulators which NaN-box their values can't rely on that.
+
+use convert::{FromWasmAbi, IntoWasmAbi, Stack};
+use describe::*;
+
+/// An `f64` which is passed to and from JS as its bit pattern, so that every
+/// NaN arrives exactly as it was sent.
+///
+/// JS sees the bits as a `u64`, which is a `BigInt` in engines supporting
+/// `BigInt64Array`. They can be converted to and from a number with
+/// `DataView.prototype.getFloat64` and `setFloat64` where the NaN payload
+/// doesn't matter:
+///
+/// ```js
+/// const view = new DataView(new ArrayBuffer(8));
+/// view.setBigUint64(0, bits);
+/// const n = view.getFloat64(0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct F64Bits(pub f64);
+
+impl From<f64> for F64Bits {
+    fn from(n: f64) -> F64Bits {
+        F64Bits(n)
+    }
+}
+
+impl From<F64Bits> for f64 {
+    fn from(n: F64Bits) -> f64 {
+        n.0
+    }
+}
+
+impl WasmDescribe for F64Bits {
+    describe! { (U64) }
+}
+
+impl IntoWasmAbi for F64Bits {
+    type Abi = <u64 as IntoWasmAbi>::Abi;
+
+    #[inline]
+    fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+        self.0.to_bits().into_abi(extra)
+    }
+}
+
+impl FromWasmAbi for F64Bits {
+    type Abi = <u64 as FromWasmAbi>::Abi;
+
+    #[inline]
+    unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> F64Bits {
+        F64Bits(f64::from_bits(u64::from_abi(js, extra)))
+    }
+}
+
+impl IntoWasmAbi for Option<F64Bits> {
+    type Abi = <Option<u64> as IntoWasmAbi>::Abi;
+
+    #[inline]
+    fn into_abi(self, extra: &mut Stack) -> Self::Abi {
+        self.map(|n| n.0.to_bits()).into_abi(extra)
+    }
+}
+
+impl FromWasmAbi for Option<F64Bits> {
+    type Abi = <Option<u64> as FromWasmAbi>::Abi;
+
+    #[inline]
+    unsafe fn from_abi(js: Self::Abi, extra: &mut Stack) -> Self {
+        <Option<u64>>::from_abi(js, extra).map(|n| F64Bits(f64::from_bits(n)))
+    }
+}
@@ -16,9 +16,21 @@ extern crate serde_json;
 
 extern crate wasm_bindgen_macro;
 
+#[cfg(all(
+    feature = "numbers-only",
+    any(feature = "strings", feature = "slices", feature = "closures"),
+))]
+compile_error!(
+    "the `numbers-only` feature of `wasm-bindgen` is enabled, but so is one of its \
+     `strings`, `slices` or `closures` features; they're on by default, so depend \
+     on `wasm-bindgen` with `default-features = false`, and check that nothing else \
+     in the dependency graph turns them back on"
+);
+
 use core::cell::UnsafeCell;
 use core::fmt;
 use core::ops::Deref;
+use core::mem;
 use core::ptr;
 
 use convert::FromWasmAbi;
@@ -36,23 +48,42 @@ macro_rules! if_std {
 /// ```
 pub mod prelude {
     pub use wasm_bindgen_macro::wasm_bindgen;
+    pub use wasm_bindgen_macro::FromJsObject;
+    pub use FromJsObject;
     pub use JsValue;
 
     if_std! {
+        #[cfg(not(feature = "numbers-only"))]
         pub use closure::Closure;
     }
 }
 
 pub mod convert;
+pub mod debug;
+#[macro_use]
 pub mod describe;
+pub mod features;
+pub mod float;
 
 mod cast;
 pub use cast::JsCast;
 
+/// Types which can be read out of the named properties of a JS object.
+///
+/// This is typically implemented with `#[derive(FromJsObject)]`, in which case
+/// every field of the struct is read from the property of the same name.
+pub trait FromJsObject: Sized {
+    /// Reads `Self` out of the properties of `obj`, returning an error if a
+    /// required property is missing or has the wrong type.
+    fn from_js_object(obj: &JsValue) -> Result<Self, JsValue>;
+}
+
 if_std! {
     extern crate std;
     use std::prelude::v1::*;
+    #[cfg(not(feature = "numbers-only"))]
     pub mod closure;
+    pub mod command_buffer;
 }
 
 /// Representation of an object owned by JS.
@@ -90,6 +121,7 @@ impl JsValue {
     ///
     /// The utf-8 string provided is copied to the JS heap and the string will
     /// be owned by the JS garbage collector.
+    #[cfg(not(feature = "numbers-only"))]
     pub fn from_str(s: &str) -> JsValue {
         unsafe {
             JsValue {
@@ -98,6 +130,19 @@ impl JsValue {
         }
     }
 
+    /// Creates a new JS value which is a string, from its UTF-16 code units.
+    ///
+    /// Unlike `from_str`, this can create any JS string, including ones with
+    /// unpaired surrogates.
+    #[cfg(not(feature = "numbers-only"))]
+    pub fn from_utf16(units: &[u16]) -> JsValue {
+        unsafe {
+            JsValue {
+                idx: __wbindgen_string_new_utf16(units.as_ptr(), units.len()),
+            }
+        }
+    }
+
     /// Creates a new JS value which is a number.
     ///
     /// This function creates a JS value representing a number (a heap
@@ -136,6 +181,7 @@ impl JsValue {
     ///
     /// This function will invoke the `Symbol` constructor in JS and return the
     /// JS object corresponding to the symbol created.
+    #[cfg(not(feature = "numbers-only"))]
     pub fn symbol(description: Option<&str>) -> JsValue {
         unsafe {
             let ptr = description.map(|s| s.as_ptr()).unwrap_or(ptr::null());
@@ -187,7 +233,7 @@ impl JsValue {
     /// # Errors
     ///
     /// Returns any error encountered when parsing the JSON into a `T`.
-    #[cfg(feature = "serde-serialize")]
+    #[cfg(all(feature = "serde-serialize", not(feature = "numbers-only")))]
     pub fn into_serde<T>(&self) -> serde_json::Result<T>
     where
         T: for<'a> serde::de::Deserialize<'a>,
@@ -195,7 +241,7 @@ impl JsValue {
         unsafe {
             let mut ptr = ptr::null_mut();
             let len = __wbindgen_json_serialize(self.idx, &mut ptr);
-            let s = Vec::from_raw_parts(ptr, len, len);
+            let s = __rt::take_glue_buffer(ptr, len);
             let s = String::from_utf8_unchecked(s);
             serde_json::from_str(&s)
         }
@@ -232,7 +278,7 @@ impl JsValue {
     ///
     /// If this JS value is not an instance of a string or if it's not valid
     /// utf-8 then this returns `None`.
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(feature = "numbers-only")))]
     pub fn as_string(&self) -> Option<String> {
         unsafe {
             let mut len = 0;
@@ -240,12 +286,32 @@ impl JsValue {
             if ptr.is_null() {
                 None
             } else {
-                let data = Vec::from_raw_parts(ptr, len, len);
+                let data = __rt::take_glue_buffer(ptr, len);
                 Some(String::from_utf8_unchecked(data))
             }
         }
     }
 
+    /// If this JS value is a string value, this function copies its UTF-16
+    /// code units into wasm linear memory and returns them.
+    ///
+    /// Unlike `as_string`, this is lossless: JS strings may contain unpaired
+    /// surrogates, which are preserved here rather than being replaced.
+    ///
+    /// If this JS value is not a string then this returns `None`.
+    #[cfg(all(feature = "std", not(feature = "numbers-only")))]
+    pub fn as_utf16(&self) -> Option<Vec<u16>> {
+        unsafe {
+            let mut len = 0;
+            let ptr = __wbindgen_string_get_utf16(self.idx, &mut len);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(__rt::take_glue_buffer(ptr, len))
+            }
+        }
+    }
+
     /// Returns the `bool` value of this JS value if it's an instance of a
     /// boolean.
     ///
@@ -299,12 +365,14 @@ impl PartialEq<bool> for JsValue {
     }
 }
 
+#[cfg(not(feature = "numbers-only"))]
 impl PartialEq<str> for JsValue {
     fn eq(&self, other: &str) -> bool {
         *self == JsValue::from_str(other)
     }
 }
 
+#[cfg(not(feature = "numbers-only"))]
 impl<'a> PartialEq<&'a str> for JsValue {
     fn eq(&self, other: &&'a str) -> bool {
         <JsValue as PartialEq<str>>::eq(self, other)
@@ -312,11 +380,13 @@ impl<'a> PartialEq<&'a str> for JsValue {
 }
 
 if_std! {
+    #[cfg(not(feature = "numbers-only"))]
     impl PartialEq<String> for JsValue {
         fn eq(&self, other: &String) -> bool {
             <JsValue as PartialEq<str>>::eq(self, other)
         }
     }
+    #[cfg(not(feature = "numbers-only"))]
     impl<'a> PartialEq<&'a String> for JsValue {
         fn eq(&self, other: &&'a String) -> bool {
             <JsValue as PartialEq<str>>::eq(self, other)
@@ -324,6 +394,7 @@ if_std! {
     }
 }
 
+#[cfg(not(feature = "numbers-only"))]
 impl<'a> From<&'a str> for JsValue {
     fn from(s: &'a str) -> JsValue {
         JsValue::from_str(s)
@@ -331,12 +402,14 @@ impl<'a> From<&'a str> for JsValue {
 }
 
 if_std! {
+    #[cfg(not(feature = "numbers-only"))]
     impl<'a> From<&'a String> for JsValue {
         fn from(s: &'a String) -> JsValue {
             JsValue::from_str(s)
         }
     }
 
+    #[cfg(not(feature = "numbers-only"))]
     impl From<String> for JsValue {
         fn from(s: String) -> JsValue {
             JsValue::from_str(&s)
@@ -399,17 +472,26 @@ macro_rules! numbers {
 
 numbers! { i8 u8 i16 u16 i32 u32 f32 f64 }
 
+// With `stable-abi` the names of the runtime's imports and exports start with
+// `shared::stable_abi_prefix(shared::STABLE_ABI_VERSION)`, which the CLI
+// strips again when it reads the wasm file.
 macro_rules! externs {
     ($(fn $name:ident($($args:tt)*) -> $ret:ty;)*) => (
         #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
         #[link(wasm_import_module = "__wbindgen_placeholder__")]
         extern {
-            $(fn $name($($args)*) -> $ret;)*
+            $(
+                #[cfg_attr(
+                    feature = "stable-abi",
+                    link_name = concat!("__wbindgen_abi1", stringify!($name))
+                )]
+                fn $name($($args)*) -> $ret;
+            )*
         }
 
         $(
             #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"))))]
-            #[allow(unused_variables)]
+            #[allow(unused_variables, dead_code)]
             unsafe extern fn $name($($args)*) -> $ret {
                 panic!("function not implemented on non-wasm32 targets")
             }
@@ -421,6 +503,7 @@ externs! {
     fn __wbindgen_object_clone_ref(idx: u32) -> u32;
     fn __wbindgen_object_drop_ref(idx: u32) -> ();
     fn __wbindgen_string_new(ptr: *const u8, len: usize) -> u32;
+    fn __wbindgen_string_new_utf16(ptr: *const u16, len: usize) -> u32;
     fn __wbindgen_number_new(f: f64) -> u32;
     fn __wbindgen_number_get(idx: u32, invalid: *mut u8) -> f64;
     fn __wbindgen_is_null(idx: u32) -> u32;
@@ -432,10 +515,22 @@ externs! {
     fn __wbindgen_is_function(idx: u32) -> u32;
     fn __wbindgen_is_string(idx: u32) -> u32;
     fn __wbindgen_string_get(idx: u32, len: *mut usize) -> *mut u8;
+    fn __wbindgen_string_get_utf16(idx: u32, len: *mut usize) -> *mut u16;
     fn __wbindgen_throw(a: *const u8, b: usize) -> !;
 
     fn __wbindgen_cb_drop(idx: u32) -> ();
     fn __wbindgen_cb_forget(idx: u32) -> ();
+    fn __wbindgen_queue_microtask(f: *mut u8) -> ();
+    fn __wbindgen_panic_error(ptr: *const u8, len: usize) -> u32;
+    fn __wbindgen_rethrow(idx: u32) -> !;
+    fn __wbindgen_set_error_hook(idx: u32) -> ();
+    fn __wbindgen_replay_commands(ptr: *const f64, len: usize) -> ();
+    fn __wbindgen_copy_to_wasm(idx: u32, ptr: *mut u8, len: usize) -> ();
+    fn __wbindgen_copy_from_wasm(idx: u32, ptr: *const u8, len: usize) -> ();
+    fn __wbindgen_heap_stats(ret: *mut u32) -> ();
+    fn __wbindgen_heap_entries() -> u32;
+    fn __wbindgen_supports_bigint64() -> u32;
+    fn __wbindgen_supports_weak_ref() -> u32;
 
     fn __wbindgen_describe(v: u32) -> ();
 
@@ -458,7 +553,7 @@ impl fmt::Debug for JsValue {
         if let Some(n) = self.as_f64() {
             return n.fmt(f);
         }
-        #[cfg(feature = "std")]
+        #[cfg(all(feature = "std", not(feature = "numbers-only")))]
         {
             if let Some(n) = self.as_string() {
                 return n.fmt(f);
@@ -567,10 +662,82 @@ pub fn throw(s: &str) -> ! {
     }
 }
 
+/// Throws `e` as a JS exception.
+///
+/// Like `throw` this doesn't return, and nothing on the wasm stack is dropped
+/// when the exception is thrown.
+#[cold]
+#[inline(never)]
+pub fn throw_val(e: JsValue) -> ! {
+    unsafe {
+        let idx = e.idx;
+        mem::forget(e);
+        __wbindgen_rethrow(idx);
+    }
+}
+
+/// Installs a JS function to be called with every error thrown out of wasm.
+///
+/// `hook` is invoked as `hook(error, context)` where `context.name` is the
+/// name of the exported function (or `"closure"`) the error escaped from,
+/// and, when bindings are generated with `--debug`, `context.args` is a short
+/// summary of the arguments it was called with. The error is rethrown
+/// unchanged afterwards. Passing anything other than a function removes the
+/// hook.
+///
+/// This is the Rust equivalent of the `setWasmErrorHook` function exported
+/// from the generated JS, which is useful for reporting errors to a telemetry
+/// service.
+pub fn set_error_hook(hook: &JsValue) {
+    unsafe { __wbindgen_set_error_hook(hook.idx) }
+}
+
+/// Returns the number of JS values currently owned by Rust.
+///
+/// This is the `live` count of `debug::heap_stats`, for tests which check
+/// that something doesn't leak `JsValue`s, such as a `Closure` which is never
+/// dropped:
+///
+/// ```rust,ignore
+/// let before = wasm_bindgen::externref_heap_live_count();
+/// attach_handlers();
+/// detach_handlers();
+/// assert_eq!(wasm_bindgen::externref_heap_live_count(), before);
+/// ```
+pub fn externref_heap_live_count() -> u32 {
+    debug::heap_stats().live as u32
+}
+
+if_std! {
+    /// Runs `f` on the JS microtask queue, after the currently executing JS
+    /// (and any microtasks already queued) has finished.
+    ///
+    /// This is implemented with `queueMicrotask` where available, falling
+    /// back to a resolved `Promise` otherwise. It's useful for deferring work
+    /// without pulling in a futures executor.
+    pub fn spawn_microtask<F>(f: F)
+        where F: FnOnce() + 'static
+    {
+        let mut f = Some(f);
+        let f = Box::new(move || {
+            if let Some(f) = f.take() {
+                f();
+            }
+        }) as Box<FnMut()>;
+        __rt::link_mem_intrinsics();
+        unsafe {
+            __wbindgen_queue_microtask(Box::into_raw(Box::new(f)) as *mut u8);
+        }
+    }
+}
+
 #[doc(hidden)]
 pub mod __rt {
     use core::cell::{Cell, UnsafeCell};
     use core::ops::{Deref, DerefMut};
+    use convert::{FromWasmAbi, GlobalStack, IntoWasmAbi, Stack};
+    use convert::{OptionRefFromWasmAbi, OptionRefMutFromWasmAbi};
+    use convert::{RefFromWasmAbi, RefMutFromWasmAbi};
     pub extern crate core;
     #[cfg(feature = "std")]
     pub extern crate std;
@@ -602,6 +769,25 @@ pub mod __rt {
         super::throw("null pointer passed to rust");
     }
 
+    /// Copies the elements of the JS typed array `array` into the `len`
+    /// elements at `dst`, with one `TypedArray.prototype.set`.
+    ///
+    /// `dst` must be aligned for the element type of `array`, which must have
+    /// exactly `len` elements.
+    #[cfg(not(feature = "numbers-only"))]
+    #[inline]
+    pub unsafe fn copy_to_wasm<T>(array: &::JsValue, dst: *mut T, len: usize) {
+        super::__wbindgen_copy_to_wasm(array.idx, dst as *mut u8, len);
+    }
+
+    /// The reverse of `copy_to_wasm`, copying the `len` elements at `src`
+    /// into `array`.
+    #[cfg(not(feature = "numbers-only"))]
+    #[inline]
+    pub unsafe fn copy_from_wasm<T>(array: &::JsValue, src: *const T, len: usize) {
+        super::__wbindgen_copy_from_wasm(array.idx, src as *const u8, len);
+    }
+
     /// A vendored version of `RefCell` from the standard library.
     ///
     /// Now why, you may ask, would we do that? Surely `RefCell` in libstd is
@@ -619,11 +805,20 @@ pub mod __rt {
     /// guard accidental reentrancy, so this vendored version is intended solely
     /// to not panic in libstd. Instead when it "panics" it calls our `throw`
     /// function in this crate which raises an error in JS.
+    ///
+    /// The layout of this type is fixed so that `value` is at the same offset
+    /// regardless of `T`. An exported struct which `extends` another exported
+    /// struct embeds its parent as its first field, so a `WasmRefCell` of the
+    /// child can then be used as a `WasmRefCell` of the parent.
+    #[repr(C)]
     pub struct WasmRefCell<T: ?Sized> {
-        borrow: Cell<usize>,
+        borrow: BorrowFlag,
         value: UnsafeCell<T>,
     }
 
+    #[repr(align(8))]
+    struct BorrowFlag(Cell<usize>);
+
     impl<T: ?Sized> WasmRefCell<T> {
         pub fn new(value: T) -> WasmRefCell<T>
         where
@@ -631,7 +826,7 @@ pub mod __rt {
         {
             WasmRefCell {
                 value: UnsafeCell::new(value),
-                borrow: Cell::new(0),
+                borrow: BorrowFlag(Cell::new(0)),
             }
         }
 
@@ -641,26 +836,26 @@ pub mod __rt {
 
         pub fn borrow(&self) -> Ref<T> {
             unsafe {
-                if self.borrow.get() == usize::max_value() {
+                if self.borrow.0.get() == usize::max_value() {
                     borrow_fail();
                 }
-                self.borrow.set(self.borrow.get() + 1);
+                self.borrow.0.set(self.borrow.0.get() + 1);
                 Ref {
                     value: &*self.value.get(),
-                    borrow: &self.borrow,
+                    borrow: &self.borrow.0,
                 }
             }
         }
 
         pub fn borrow_mut(&self) -> RefMut<T> {
             unsafe {
-                if self.borrow.get() != 0 {
+                if self.borrow.0.get() != 0 {
                     borrow_fail();
                 }
-                self.borrow.set(usize::max_value());
+                self.borrow.0.set(usize::max_value());
                 RefMut {
                     value: &mut *self.value.get(),
-                    borrow: &self.borrow,
+                    borrow: &self.borrow.0,
                 }
             }
         }
@@ -728,15 +923,38 @@ pub mod __rt {
     }
 
     if_std! {
-        use std::alloc::{System, GlobalAlloc, Layout};
-        use std::mem;
-
-        #[no_mangle]
+        // The JS glue only allocates memory to pass strings and slices in, so
+        // without those there's no need for the allocator.
+        #[cfg(not(feature = "numbers-only"))]
+        use std::alloc::{self, Layout};
+        use std::boxed::Box;
+        #[cfg(not(feature = "numbers-only"))]
+        use std::{mem, ptr};
+        #[cfg(not(feature = "numbers-only"))]
+        use std::vec::Vec;
+
+        /// The alignment of everything the JS glue allocates, which is enough
+        /// for any number type and for 128-bit SIMD loads of the slices it
+        /// passes in, whatever their element type.
+        ///
+        /// Rust's own allocations, like a `Vec<u8>`, are freed with the
+        /// alignment of their elements, so buffers are only ever handed
+        /// between the two with `take_glue_buffer` and `glue_buffer`.
+        #[cfg(not(feature = "numbers-only"))]
+        const MALLOC_ALIGN: usize = 16;
+
+        #[cfg(not(feature = "numbers-only"))]
+        #[cfg_attr(not(feature = "stable-abi"), no_mangle)]
+        #[cfg_attr(feature = "stable-abi", export_name = "__wbindgen_abi1__wbindgen_malloc")]
         pub extern fn __wbindgen_malloc(size: usize) -> *mut u8 {
-            let align = mem::align_of::<usize>();
-            if let Ok(layout) = Layout::from_size_align(size, align) {
+            // Nothing is allocated for empty strings and slices, which
+            // `__wbindgen_free` doesn't free either.
+            if size == 0 {
+                return MALLOC_ALIGN as *mut u8
+            }
+            if let Ok(layout) = Layout::from_size_align(size, MALLOC_ALIGN) {
                 unsafe {
-                    let ptr = System.alloc(layout);
+                    let ptr = alloc::alloc(layout);
                     if !ptr.is_null() {
                         return ptr
                     }
@@ -746,19 +964,186 @@ pub mod __rt {
             super::throw("invalid malloc request");
         }
 
-        #[no_mangle]
+        #[cfg(not(feature = "numbers-only"))]
+        #[cfg_attr(not(feature = "stable-abi"), no_mangle)]
+        #[cfg_attr(feature = "stable-abi", export_name = "__wbindgen_abi1__wbindgen_free")]
         pub unsafe extern fn __wbindgen_free(ptr: *mut u8, size: usize) {
             // This happens for zero-length slices, and in that case `ptr` is
             // likely bogus so don't actually send this to the system allocator
             if size == 0 {
                 return
             }
-            let align = mem::align_of::<usize>();
-            let layout = Layout::from_size_align_unchecked(size, align);
-            System.dealloc(ptr, layout);
+            let layout = Layout::from_size_align_unchecked(size, MALLOC_ALIGN);
+            alloc::dealloc(ptr, layout);
+        }
+
+        /// Moves the `len` values at `ptr`, which the JS glue allocated with
+        /// `__wbindgen_malloc`, into a `Vec`, freeing the glue's buffer.
+        #[cfg(not(feature = "numbers-only"))]
+        pub unsafe fn take_glue_buffer<T>(ptr: *mut T, len: usize) -> Vec<T> {
+            let mut values = Vec::with_capacity(len);
+            ptr::copy_nonoverlapping(ptr, values.as_mut_ptr(), len);
+            values.set_len(len);
+            __wbindgen_free(ptr as *mut u8, len * mem::size_of::<T>());
+            values
+        }
+
+        /// Moves `values` into a buffer allocated like `__wbindgen_malloc`
+        /// does, for JS glue which frees it with `__wbindgen_free`, and
+        /// returns it along with its length.
+        #[cfg(not(feature = "numbers-only"))]
+        pub fn glue_buffer<T>(values: Vec<T>) -> (*mut T, usize) {
+            let len = values.len();
+            let dst = __wbindgen_malloc(len * mem::size_of::<T>()) as *mut T;
+            unsafe {
+                ptr::copy_nonoverlapping(values.as_ptr(), dst, len);
+                // The values have been moved, so only their memory is freed.
+                let mut values = mem::ManuallyDrop::new(values);
+                drop(Vec::from_raw_parts(values.as_mut_ptr(), 0, values.capacity()));
+            }
+            (dst, len)
+        }
+
+        /// Entry point for microtasks queued by `spawn_microtask`, called by
+        /// the JS glue with the pointer it was handed.
+        #[cfg_attr(not(feature = "stable-abi"), no_mangle)]
+        #[cfg_attr(feature = "stable-abi", export_name = "__wbindgen_abi1__wbindgen_microtask_run")]
+        pub unsafe extern fn __wbindgen_microtask_run(f: *mut u8) {
+            let mut f = Box::from_raw(f as *mut Box<FnMut()>);
+            f();
+        }
+    }
+
+    /// Runs the body of an exported function.
+    ///
+    /// With the `catch-unwind` feature a panic escaping `f` is caught here,
+    /// after unwinding has dropped everything the call owned, and rethrown as
+    /// a JS `WasmPanicError` carrying the panic message. Otherwise this just
+    /// calls `f`.
+    #[cfg(feature = "catch-unwind")]
+    pub fn catch_unwind<F, R>(f: F) -> R
+        where F: FnOnce() -> R
+    {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let payload = match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(ret) => return ret,
+            Err(payload) => payload,
+        };
+        let err = {
+            let msg = match payload.downcast_ref::<&str>() {
+                Some(s) => *s,
+                None => match payload.downcast_ref::<std::string::String>() {
+                    Some(s) => &s[..],
+                    None => "Box<Any>",
+                },
+            };
+            unsafe { super::__wbindgen_panic_error(msg.as_ptr(), msg.len()) }
+        };
+        drop(payload);
+        unsafe { super::__wbindgen_rethrow(err) }
+    }
+
+    #[cfg(not(feature = "catch-unwind"))]
+    #[inline]
+    pub fn catch_unwind<F, R>(f: F) -> R
+        where F: FnOnce() -> R
+    {
+        f()
+    }
+
+    // The helpers below are called by the code `#[wasm_bindgen]` generates
+    // for every export and import, so that each of them is a short call
+    // rather than its own copy of the same few lines, which adds up for
+    // crates binding thousands of functions like `web-sys`.
+
+    /// Converts the value an exported function returned to what its shim
+    /// returns to JS.
+    #[inline]
+    pub fn return_abi<T: IntoWasmAbi>(ret: T) -> T::Abi {
+        ret.into_abi(&mut unsafe { GlobalStack::new() })
+    }
+
+    /// Converts an argument JS passed to an exported function, or the
+    /// method's receiver when it's taken by value, to its Rust value.
+    #[inline]
+    pub unsafe fn arg_from_abi<T: FromWasmAbi>(js: T::Abi, extra: &mut Stack) -> T {
+        T::from_abi(js, extra)
+    }
+
+    /// Same as `arg_from_abi`, for arguments and receivers which are
+    /// borrowed.
+    #[inline]
+    pub unsafe fn ref_from_abi<T>(js: T::Abi, extra: &mut Stack) -> T::Anchor
+        where T: RefFromWasmAbi + ?Sized,
+    {
+        T::ref_from_abi(js, extra)
+    }
+
+    /// Same as `arg_from_abi`, for arguments and receivers which are
+    /// borrowed mutably.
+    #[inline]
+    pub unsafe fn ref_mut_from_abi<T>(js: T::Abi, extra: &mut Stack) -> T::Anchor
+        where T: RefMutFromWasmAbi + ?Sized,
+    {
+        T::ref_mut_from_abi(js, extra)
+    }
+
+    /// Same as `ref_from_abi`, for `Option<&T>` arguments.
+    #[inline]
+    pub unsafe fn option_ref_from_abi<T>(js: T::Abi, extra: &mut Stack) -> Option<T::Anchor>
+        where T: OptionRefFromWasmAbi + ?Sized,
+    {
+        if T::is_none(&js) {
+            None
+        } else {
+            Some(T::ref_from_abi(js, extra))
+        }
+    }
+
+    /// Same as `ref_mut_from_abi`, for `Option<&mut T>` arguments.
+    #[inline]
+    pub unsafe fn option_ref_mut_from_abi<T>(js: T::Abi, extra: &mut Stack) -> Option<T::Anchor>
+        where T: OptionRefMutFromWasmAbi + ?Sized,
+    {
+        if T::is_none(&js) {
+            None
+        } else {
+            Some(T::ref_mut_from_abi(js, extra))
         }
     }
 
+    /// Converts an argument of an imported function to what its JS is
+    /// passed.
+    #[inline]
+    pub fn arg_into_abi<T: IntoWasmAbi>(arg: T, extra: &mut Stack) -> T::Abi {
+        arg.into_abi(extra)
+    }
+
+    /// Converts what an imported function's JS returned to its Rust value.
+    #[inline]
+    pub unsafe fn from_return_abi<T: FromWasmAbi>(ret: T::Abi) -> T {
+        T::from_abi(ret, &mut GlobalStack::new())
+    }
+
+    /// Returns the exception a `catch` import's JS threw, if it did, as
+    /// written to `exn_data` by the JS shim.
+    #[inline]
+    pub unsafe fn take_exception(exn_data: &[u32; 2]) -> Result<(), ::JsValue> {
+        if exn_data[0] == 1 {
+            return Err(::JsValue::from_abi(exn_data[1], &mut GlobalStack::new()));
+        }
+        Ok(())
+    }
+
+    /// What imported functions do when they're called on targets other than
+    /// wasm, where there's no JS to call.
+    #[cold]
+    #[inline(never)]
+    pub fn import_on_non_wasm() -> ! {
+        panic!("cannot call wasm-bindgen imported functions on non-wasm targets")
+    }
+
     pub const GLOBAL_STACK_CAP: usize = 16;
 
     // Increase the alignment to 8 here because this can be used as a
@@ -768,7 +1153,8 @@ pub mod __rt {
 
     static mut GLOBAL_STACK: GlobalData = GlobalData([0; GLOBAL_STACK_CAP]);
 
-    #[no_mangle]
+    #[cfg_attr(not(feature = "stable-abi"), no_mangle)]
+    #[cfg_attr(feature = "stable-abi", export_name = "__wbindgen_abi1__wbindgen_global_argument_ptr")]
     pub unsafe extern "C" fn __wbindgen_global_argument_ptr() -> *mut u32 {
         GLOBAL_STACK.0.as_mut_ptr()
     }
@@ -813,8 +1199,10 @@ pub mod __rt {
     /// Ideas for how to improve this are most welcome!
     #[inline]
     pub fn link_mem_intrinsics() {
-        // the above symbols only exist with the `std` feature enabled.
-        if !cfg!(feature = "std") {
+        // the above symbols only exist with the `std` feature enabled, and
+        // without the `numbers-only` feature, which compiles out the strings
+        // and slices which need them.
+        if !cfg!(all(feature = "std", not(feature = "numbers-only"))) {
             return
         }
 
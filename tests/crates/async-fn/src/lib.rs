@@ -0,0 +1,70 @@
+//! The `async fn` exports tested by `tests/wasm/async_fn.rs`, which are in a
+//! crate of their own as `async fn` needs the 2018 edition.
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use wasm_bindgen::prelude::*;
+
+pub mod reexport {
+    pub use wasm_bindgen_futures;
+}
+
+#[wasm_bindgen]
+pub async fn async_fn_resolves(x: u32) -> JsValue {
+    JsValue::from(x * 2)
+}
+
+#[wasm_bindgen]
+pub async fn async_fn_rejects() -> Result<(), JsValue> {
+    Err(JsValue::from("rejected"))
+}
+
+#[wasm_bindgen(wasm_bindgen_futures = crate::reexport::wasm_bindgen_futures)]
+pub async fn async_fn_through_reexport() -> JsValue {
+    JsValue::from("reexported")
+}
+
+thread_local! {
+    static RELEASED: Cell<bool> = Cell::new(false);
+    static POLLS: Cell<u32> = Cell::new(0);
+    static WAKER: RefCell<Option<Waker>> = RefCell::new(None);
+}
+
+/// Stays pending until `async_fn_release` is called.
+#[wasm_bindgen]
+pub async fn async_fn_pending() -> JsValue {
+    Released.await;
+    JsValue::from("released")
+}
+
+#[wasm_bindgen]
+pub fn async_fn_release() {
+    RELEASED.with(|released| released.set(true));
+    if let Some(waker) = WAKER.with(|waker| waker.borrow_mut().take()) {
+        waker.wake();
+    }
+}
+
+/// How many times the future of `async_fn_pending` has been polled.
+#[wasm_bindgen]
+pub fn async_fn_polls() -> u32 {
+    POLLS.with(|polls| polls.get())
+}
+
+struct Released;
+
+impl Future for Released {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        POLLS.with(|polls| polls.set(polls.get() + 1));
+        if RELEASED.with(|released| released.get()) {
+            return Poll::Ready(());
+        }
+        WAKER.with(|waker| *waker.borrow_mut() = Some(cx.waker().clone()));
+        Poll::Pending
+    }
+}
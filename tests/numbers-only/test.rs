@@ -0,0 +1,69 @@
+//! This is a test that we compile `wasm-bindgen` with only the machinery for
+//! passing numbers, with its `numbers-only` feature, and can still
+//! export/import items which don't need strings, slices or closures.
+//!
+//! This doesn't actually run any tests, it's mostly a compile-time verification
+//! that things work.
+
+#![feature(use_extern_macros)]
+#![allow(dead_code)]
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn add(a: u32, b: f64) -> f64 {
+    f64::from(a) + b
+}
+
+#[wasm_bindgen]
+pub fn checked_div(a: i32, b: i32) -> Option<i32> {
+    a.checked_div(b)
+}
+
+#[wasm_bindgen]
+pub fn is_positive(value: &JsValue) -> Result<bool, JsValue> {
+    match value.as_f64() {
+        Some(n) => Ok(n > 0.0),
+        None => Err(JsValue::from(false)),
+    }
+}
+
+#[wasm_bindgen]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+#[wasm_bindgen]
+pub struct Counter {
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl Counter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Counter {
+        Counter { count: 0 }
+    }
+
+    pub fn step(&mut self, direction: Direction) -> u32 {
+        match direction {
+            Direction::Up => self.count += 1,
+            Direction::Down => self.count -= 1,
+        }
+        self.count
+    }
+}
+
+#[wasm_bindgen]
+extern {
+    fn random() -> f64;
+
+    type Js;
+    #[wasm_bindgen(constructor)]
+    fn new(seed: u32) -> Js;
+    #[wasm_bindgen(method, structural)]
+    fn tick(this: &Js, delta: f64) -> bool;
+}
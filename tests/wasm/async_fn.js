@@ -0,0 +1,37 @@
+const assert = require('assert');
+const wasm = require('wasm-bindgen-test');
+
+exports.js_resolves = async () => {
+  const promise = wasm.async_fn_resolves(21);
+  assert.ok(promise instanceof Promise);
+  assert.strictEqual(await promise, 42);
+  assert.strictEqual(await wasm.async_fn_through_reexport(), 'reexported');
+};
+
+exports.js_rejects = async () => {
+  let error = null;
+  try {
+    await wasm.async_fn_rejects();
+  } catch (e) {
+    error = e;
+  }
+  assert.strictEqual(error, 'rejected');
+};
+
+exports.js_pending = async () => {
+  let settled = false;
+  const promise = wasm.async_fn_pending().then(value => {
+    settled = true;
+    return value;
+  });
+
+  // Nothing wakes the future up until it's released, so it's only polled
+  // the once when the function is called.
+  await new Promise(resolve => setTimeout(resolve, 50));
+  assert.strictEqual(settled, false);
+  assert.strictEqual(wasm.async_fn_polls(), 1);
+
+  wasm.async_fn_release();
+  assert.strictEqual(await promise, 'released');
+  assert.strictEqual(wasm.async_fn_polls(), 2);
+};
@@ -0,0 +1,29 @@
+use futures::Future;
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+// The exports themselves are in `tests/crates/async-fn`, which the JS half of
+// these tests calls.
+#[wasm_bindgen(module = "tests/wasm/async_fn.js")]
+extern {
+    fn js_resolves() -> Promise;
+    fn js_rejects() -> Promise;
+    fn js_pending() -> Promise;
+}
+
+#[wasm_bindgen_test(async)]
+fn resolves() -> impl Future<Item = (), Error = JsValue> {
+    JsFuture::from(js_resolves()).map(|_| ())
+}
+
+#[wasm_bindgen_test(async)]
+fn rejects() -> impl Future<Item = (), Error = JsValue> {
+    JsFuture::from(js_rejects()).map(|_| ())
+}
+
+#[wasm_bindgen_test(async)]
+fn pending() -> impl Future<Item = (), Error = JsValue> {
+    JsFuture::from(js_pending()).map(|_| ())
+}
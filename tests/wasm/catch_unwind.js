@@ -0,0 +1,24 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_panics_are_rethrown = () => {
+    assert.throws(() => wasm.catch_unwind_panic('oh no'), e => {
+        assert.ok(e instanceof wasm.WasmPanicError);
+        assert.ok(e instanceof Error);
+        assert.strictEqual(e.name, 'WasmPanicError');
+        assert.strictEqual(e.message, 'oh no');
+        return true;
+    });
+    assert.throws(() => wasm.catch_unwind_panic_payload(), e => {
+        assert.strictEqual(e.message, 'Box<Any>');
+        return true;
+    });
+};
+
+exports.js_panicking_method_releases_borrow = () => {
+    const counter = new wasm.CatchUnwindCounter();
+    assert.strictEqual(counter.bump(false), 1);
+    assert.throws(() => counter.bump(true), /failed at 2/);
+    assert.strictEqual(counter.bump(false), 3);
+    counter.free();
+};
@@ -0,0 +1,49 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/catch_unwind.js")]
+extern {
+    fn js_panics_are_rethrown();
+    fn js_panicking_method_releases_borrow();
+}
+
+#[wasm_bindgen]
+pub fn catch_unwind_panic(msg: &str) {
+    panic!("{}", msg);
+}
+
+#[wasm_bindgen]
+pub fn catch_unwind_panic_payload() {
+    std::panic::resume_unwind(Box::new(42));
+}
+
+#[wasm_bindgen]
+pub struct CatchUnwindCounter {
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl CatchUnwindCounter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> CatchUnwindCounter {
+        CatchUnwindCounter { count: 0 }
+    }
+
+    pub fn bump(&mut self, fail: bool) -> u32 {
+        self.count += 1;
+        if fail {
+            panic!("failed at {}", self.count);
+        }
+        self.count
+    }
+}
+
+#[wasm_bindgen_test]
+fn panics_are_rethrown() {
+    js_panics_are_rethrown();
+}
+
+#[wasm_bindgen_test]
+fn panicking_method_releases_borrow() {
+    js_panicking_method_releases_borrow();
+}
@@ -2,6 +2,8 @@ const wasm = require('wasm-bindgen-test.js');
 const assert = require('assert');
 
 exports.js_identity = a => a;
+exports.js_optional_identity = a => a;
+exports.js_return_string = () => 'ab';
 
 exports.js_works = () => {
     assert.strictEqual(wasm.letter(), 'a');
@@ -14,4 +16,18 @@ exports.js_works = () => {
     assert.strictEqual(wasm.rust_js_identity('㊻'), '㊻');
     wasm.rust_letter('a');
     wasm.rust_face('😀');
+    assert.throws(() => wasm.rust_identity('\uD800'), /invalid char code point passed/);
+
+    assert.strictEqual(wasm.rust_optional_identity(undefined), undefined);
+    assert.strictEqual(wasm.rust_optional_identity('😀'), '😀');
+    assert.strictEqual(wasm.rust_js_optional_identity('Δ'), 'Δ');
+    assert.strictEqual(wasm.rust_js_optional_identity(undefined), undefined);
+
+    const notAChar = /expected a string of a single character for a char/;
+    assert.throws(() => wasm.rust_identity(''), notAChar);
+    assert.throws(() => wasm.rust_identity('ab'), notAChar);
+    assert.throws(() => wasm.rust_identity('😀a'), notAChar);
+    assert.throws(() => wasm.rust_identity(97), notAChar);
+    assert.throws(() => wasm.rust_optional_identity('ab'), notAChar);
+    assert.throws(() => wasm.rust_js_return_string(), notAChar);
 };
@@ -4,6 +4,8 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen(module = "tests/wasm/char.js")]
 extern {
     fn js_identity(c: char) -> char;
+    fn js_optional_identity(c: Option<char>) -> Option<char>;
+    fn js_return_string() -> char;
     fn js_works();
 }
 
@@ -13,6 +15,17 @@ pub fn rust_identity(c: char) -> char { c }
 #[wasm_bindgen]
 pub fn rust_js_identity(c: char) -> char { js_identity(c) }
 
+#[wasm_bindgen]
+pub fn rust_optional_identity(c: Option<char>) -> Option<char> { c }
+
+#[wasm_bindgen]
+pub fn rust_js_optional_identity(c: Option<char>) -> Option<char> {
+    js_optional_identity(c)
+}
+
+#[wasm_bindgen]
+pub fn rust_js_return_string() -> char { js_return_string() }
+
 #[wasm_bindgen]
 pub fn letter() -> char { 'a' }
 
@@ -83,6 +83,14 @@ exports.js_constructors = () => {
     bar2.free();
 
     assert.strictEqual(wasm.cross_item_construction().get_sum(), 15);
+
+    const multiple = new wasm.ConstructorsMultiple(8);
+    assert.strictEqual(multiple.get_number(), 8);
+    multiple.free();
+
+    const multiple2 = wasm.ConstructorsMultiple.from_bytes(new Uint8Array([1, 2, 3]));
+    assert.strictEqual(multiple2.get_number(), 6);
+    multiple2.free();
 };
 
 exports.js_empty_structs = () => {
@@ -120,6 +128,31 @@ exports.js_readonly_fields = () => {
     a.free();
 };
 
+exports.js_skipped_fields = () => {
+    const s = wasm.Skipped.new();
+    assert.strictEqual(s.a, 1);
+    assert.strictEqual(s.name, undefined);
+    assert.strictEqual(Object.getOwnPropertyDescriptor(wasm.Skipped.prototype, 'name'), undefined);
+    s.free();
+};
+
+exports.js_accessors = () => {
+    const a = wasm.Accessors.new();
+    assert.strictEqual(a.gain, 1);
+    a.gain = 0.5;
+    assert.strictEqual(a.gain, 0.5);
+    a.gain = 2;
+    assert.strictEqual(a.gain, 1);
+    assert.strictEqual(a.set_gain, undefined);
+
+    assert.strictEqual(a.total, 3);
+    assert.strictEqual(a.count, undefined);
+    const total = Object.getOwnPropertyDescriptor(wasm.Accessors.prototype, 'total');
+    assert.strictEqual(typeof total.get, 'function');
+    assert.strictEqual(total.set, undefined);
+    a.free();
+};
+
 exports.js_double_consume = () => {
     const r = wasm.DoubleConsume.new();
     assert.throws(() => r.consume(r), /Attempt to use a moved value/);
@@ -130,3 +163,180 @@ exports.js_js_rename = () => {
     wasm.JsRename.new().bar();
     wasm.classes_foo();
 };
+
+exports.js_rename_class = () => {
+    assert.strictEqual(wasm.RenamedOscillator, undefined);
+    const osc = new wasm.Oscillator();
+    assert.strictEqual(osc.frequency, 440);
+    osc.setPrimaryFrequency(220);
+    assert.strictEqual(osc.frequency, 220);
+    assert.strictEqual(osc.set_primary_frequency, undefined);
+    const up = osc.octaveUp();
+    assert.ok(up instanceof wasm.Oscillator);
+    assert.strictEqual(up.frequency, 440);
+    up.free();
+    osc.free();
+};
+
+exports.js_extends = () => {
+    const square = new wasm.Square(3);
+    assert.ok(square instanceof wasm.Square);
+    assert.ok(square instanceof wasm.Shape);
+    assert.strictEqual(square.area(), 9);
+    assert.strictEqual(square.sides(), 4);
+    square.add_side();
+    assert.strictEqual(square.sides(), 5);
+
+    const shape = new wasm.Shape(3);
+    assert.strictEqual(wasm.Shape.total_sides(shape, square), 8);
+    assert.throws(() => square.consume(), /cannot consume a subclass of `Shape`/);
+    assert.strictEqual(shape.consume(), 3);
+    square.free();
+
+    const err = new wasm.RustError(7);
+    assert.ok(err instanceof Error);
+    assert.strictEqual(err.code(), 7);
+    err.free();
+
+    const child = new wasm.ExtendsImported(2);
+    assert.ok(child instanceof exports.JsBase);
+    assert.strictEqual(child.greet(), 'hello from JsBase');
+    assert.strictEqual(child.id(), 2);
+    child.free();
+};
+
+exports.JsBase = class {
+    greet() {
+        return 'hello from JsBase';
+    }
+};
+
+exports.js_property_descriptors = () => {
+    const d = wasm.Descriptors.new();
+    d.a = 1;
+    assert.strictEqual(d.a, 1);
+    assert.strictEqual(d.shown(), 1);
+    assert.strictEqual(d.b, 2);
+    d.b = 3;
+    assert.strictEqual(d.b, 2);
+
+    const keys = Object.keys(d).filter(k => k !== 'ptr');
+    assert.deepStrictEqual(keys, ['a', 'b']);
+    const { a, b } = { ...d };
+    assert.strictEqual(a, 1);
+    assert.strictEqual(b, 2);
+
+    const inherited = [];
+    for (const k in d) {
+        if (!d.hasOwnProperty(k))
+            inherited.push(k);
+    }
+    assert.deepStrictEqual(inherited, ['shown']);
+
+    const proto = wasm.Descriptors.prototype;
+    assert.strictEqual(Object.getOwnPropertyDescriptor(proto, 'c').configurable, false);
+    assert.strictEqual(Object.getOwnPropertyDescriptor(proto, 'd').configurable, true);
+    assert.strictEqual(Object.getOwnPropertyDescriptor(proto, 'fixed').configurable, false);
+    assert.strictEqual(Object.getOwnPropertyDescriptor(proto, 'shown').enumerable, true);
+    d.c = 4;
+    assert.strictEqual(d.fixed(), 4);
+    d.free();
+};
+
+exports.iterator_result = (value, done) => Promise.resolve({ value, done });
+
+exports.js_async_iterator = () => {
+    const c = wasm.Countdown.new(2);
+    assert.strictEqual(c[Symbol.asyncIterator](), c);
+    assert.ok(c.next() instanceof Promise);
+    c.free();
+};
+
+exports.make_error = msg => new TypeError(msg);
+
+exports.js_fallible_constructors = () => {
+    const f = new wasm.Fallible(3);
+    assert.strictEqual(f.value(), 3);
+    f.free();
+    assert.throws(() => new wasm.Fallible(0), TypeError, "zero isn't allowed");
+};
+
+exports.js_typed_errors = () => {
+    assert.strictEqual(wasm.checked_half(4), 2);
+    let caught;
+    try {
+        wasm.checked_half(3);
+    } catch (e) {
+        caught = e;
+    }
+    assert.ok(caught instanceof wasm.OddNumber);
+    assert.strictEqual(caught.value, 3);
+    caught.free();
+
+    assert.strictEqual(wasm.checked_digit(7), 7);
+    assert.throws(() => wasm.checked_digit(12), RangeError);
+};
+
+exports.js_fallible_methods = () => {
+    assert.throws(() => wasm.Wallet.open(0), /empty wallet/);
+
+    const w = wasm.Wallet.open(10);
+    assert.strictEqual(w.spend(3), 7);
+    assert.throws(() => w.spend(8), /insufficient funds/);
+    // The wallet is still usable after a method on it throws.
+    assert.strictEqual(w.balance, 7);
+    assert.strictEqual(w.check(7), undefined);
+    assert.throws(() => w.check(8), /insufficient funds/);
+    assert.strictEqual(w.close(), 7);
+
+    const rich = wasm.Wallet.open(1000);
+    assert.throws(() => rich.close(), /spend it first/);
+
+    assert.strictEqual(wasm.check_even(2), undefined);
+    assert.throws(() => wasm.check_even(3), /odd/);
+};
+
+exports.js_indexing = () => {
+    const s = new wasm.Slots(4);
+    assert.strictEqual(s[1], undefined);
+    s[1] = 10;
+    s[3] = 30;
+    assert.strictEqual(s[1], 10);
+    assert.strictEqual(s.get(3), 30);
+    assert.strictEqual(s.filled(), 2);
+    delete s[1];
+    assert.strictEqual(s[1], undefined);
+    assert.strictEqual(s.filled(), 1);
+
+    // Other properties are left alone.
+    s.label = 'slots';
+    assert.strictEqual(s.label, 'slots');
+    assert.ok(s instanceof wasm.Slots);
+    s.free();
+};
+
+exports.js_iterator = () => {
+    assert.deepStrictEqual([...new wasm.Digits(3)], [2, 1, 0]);
+
+    let sum = 0;
+    for (const digit of new wasm.Digits(5)) {
+        sum += digit;
+    }
+    assert.strictEqual(sum, 10);
+
+    const digits = new wasm.Digits(1);
+    assert.deepStrictEqual(digits.next(), { value: 0, done: false });
+    assert.deepStrictEqual(digits.next(), { value: undefined, done: true });
+    digits.free();
+};
+
+exports.js_conversions = () => {
+    const m = new wasm.Money(1205);
+    assert.strictEqual(m.toString(), '$12.05');
+    assert.strictEqual(`${m}`, '$12.05');
+    assert.strictEqual(+m, 1205);
+    assert.strictEqual(JSON.stringify({ price: m }), '{"price":1205}');
+    assert.strictEqual(require('util').inspect(m), '$12.05');
+    assert.strictEqual(typeof wasm.Money.prototype[Symbol.toPrimitive], 'function');
+    m.free();
+};
@@ -15,8 +15,33 @@ extern {
     fn js_public_fields();
     fn js_using_self();
     fn js_readonly_fields();
+    fn js_skipped_fields();
+    fn js_accessors();
     fn js_double_consume();
     fn js_js_rename();
+    fn js_rename_class();
+    fn js_extends();
+    fn js_property_descriptors();
+    fn js_async_iterator();
+    fn iterator_result(value: u32, done: bool) -> JsValue;
+    fn js_fallible_constructors();
+    fn make_error(msg: &str) -> JsValue;
+    fn js_typed_errors();
+    fn js_fallible_methods();
+    fn js_indexing();
+    fn js_iterator();
+    fn js_conversions();
+
+    #[wasm_bindgen(js_name = JsBase)]
+    type ImportedBase;
+}
+
+#[wasm_bindgen]
+extern {
+    #[wasm_bindgen(js_name = RangeError)]
+    type JsRangeError;
+    #[wasm_bindgen(constructor, js_class = "RangeError")]
+    fn new(msg: &str) -> JsRangeError;
 }
 
 #[wasm_bindgen_test]
@@ -229,6 +254,28 @@ impl ConstructorsBar {
     }
 }
 
+#[wasm_bindgen]
+pub struct ConstructorsMultiple {
+    number: u32,
+}
+
+#[wasm_bindgen]
+impl ConstructorsMultiple {
+    #[wasm_bindgen(constructor)]
+    pub fn new(number: u32) -> ConstructorsMultiple {
+        ConstructorsMultiple { number }
+    }
+
+    #[wasm_bindgen(constructor)]
+    pub fn from_bytes(bytes: &[u8]) -> ConstructorsMultiple {
+        ConstructorsMultiple { number: bytes.iter().map(|b| *b as u32).sum() }
+    }
+
+    pub fn get_number(&self) -> u32 {
+        self.number
+    }
+}
+
 #[wasm_bindgen_test]
 fn empty_structs() {
     js_empty_structs();
@@ -300,6 +347,58 @@ impl Readonly {
     }
 }
 
+#[wasm_bindgen_test]
+fn skipped_fields() {
+    js_skipped_fields();
+}
+
+#[wasm_bindgen]
+pub struct Skipped {
+    pub a: u32,
+    #[wasm_bindgen(skip)]
+    pub name: String,
+}
+
+#[wasm_bindgen]
+impl Skipped {
+    pub fn new() -> Skipped {
+        Skipped { a: 1, name: String::from("skipped") }
+    }
+}
+
+#[wasm_bindgen_test]
+fn accessors() {
+    js_accessors();
+}
+
+#[wasm_bindgen]
+pub struct Accessors {
+    gain: f32,
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl Accessors {
+    pub fn new() -> Accessors {
+        Accessors { gain: 1.0, count: 3 }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_gain(&mut self, gain: f32) {
+        self.gain = gain.max(0.0).min(1.0);
+    }
+
+    #[wasm_bindgen(getter = total)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
 #[wasm_bindgen_test]
 fn double_consume() {
     js_double_consume();
@@ -344,3 +443,401 @@ impl JsRename {
 
 #[wasm_bindgen(js_name = classes_foo)]
 pub fn foo() {}
+
+#[wasm_bindgen_test]
+fn rename_class_for_js() {
+    js_rename_class();
+}
+
+#[wasm_bindgen(js_name = Oscillator)]
+pub struct RenamedOscillator {
+    pub frequency: f32,
+}
+
+#[wasm_bindgen(js_class = "Oscillator")]
+impl RenamedOscillator {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> RenamedOscillator {
+        RenamedOscillator { frequency: 440.0 }
+    }
+
+    #[wasm_bindgen(js_name = setPrimaryFrequency)]
+    pub fn set_primary_frequency(&mut self, frequency: f32) {
+        self.frequency = frequency;
+    }
+
+    #[wasm_bindgen(js_name = octaveUp)]
+    pub fn octave_up(&self) -> RenamedOscillator {
+        RenamedOscillator { frequency: self.frequency * 2.0 }
+    }
+}
+
+#[wasm_bindgen_test]
+fn extends() {
+    js_extends();
+}
+
+#[wasm_bindgen]
+pub struct Shape {
+    sides: u32,
+}
+
+#[wasm_bindgen]
+impl Shape {
+    #[wasm_bindgen(constructor)]
+    pub fn new(sides: u32) -> Shape {
+        Shape { sides }
+    }
+
+    pub fn sides(&self) -> u32 {
+        self.sides
+    }
+
+    pub fn add_side(&mut self) {
+        self.sides += 1;
+    }
+
+    pub fn consume(self) -> u32 {
+        self.sides
+    }
+
+    pub fn total_sides(a: &Shape, b: &Shape) -> u32 {
+        a.sides + b.sides
+    }
+}
+
+#[wasm_bindgen(extends = Shape)]
+#[repr(C)]
+pub struct Square {
+    shape: Shape,
+    side_len: f64,
+}
+
+#[wasm_bindgen]
+impl Square {
+    #[wasm_bindgen(constructor)]
+    pub fn new(side_len: f64) -> Square {
+        Square {
+            shape: Shape::new(4),
+            side_len,
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        self.side_len * self.side_len
+    }
+}
+
+#[wasm_bindgen(extends = Error)]
+pub struct RustError {
+    code: u32,
+}
+
+#[wasm_bindgen]
+impl RustError {
+    #[wasm_bindgen(constructor)]
+    pub fn new(code: u32) -> RustError {
+        RustError { code }
+    }
+
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+}
+
+#[wasm_bindgen(extends = ImportedBase)]
+pub struct ExtendsImported {
+    id: u32,
+}
+
+#[wasm_bindgen]
+impl ExtendsImported {
+    #[wasm_bindgen(constructor)]
+    pub fn new(id: u32) -> ExtendsImported {
+        ExtendsImported { id }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+#[wasm_bindgen_test]
+fn property_descriptors() {
+    js_property_descriptors();
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct Descriptors {
+    #[wasm_bindgen(enumerable)]
+    pub a: u32,
+    #[wasm_bindgen(enumerable, readonly)]
+    pub b: u32,
+    #[wasm_bindgen(non_configurable)]
+    pub c: u32,
+    pub d: u32,
+}
+
+#[wasm_bindgen]
+impl Descriptors {
+    pub fn new() -> Descriptors {
+        Descriptors {
+            b: 2,
+            ..Descriptors::default()
+        }
+    }
+
+    #[wasm_bindgen(enumerable)]
+    pub fn shown(&self) -> u32 {
+        self.a
+    }
+
+    #[wasm_bindgen(non_configurable)]
+    pub fn fixed(&self) -> u32 {
+        self.c
+    }
+}
+
+#[wasm_bindgen_test]
+fn async_iterator() {
+    js_async_iterator();
+}
+
+#[wasm_bindgen(async_iterator)]
+pub struct Countdown {
+    left: u32,
+}
+
+#[wasm_bindgen]
+impl Countdown {
+    pub fn new(left: u32) -> Countdown {
+        Countdown { left }
+    }
+
+    pub fn next(&mut self) -> JsValue {
+        if self.left == 0 {
+            return iterator_result(0, true);
+        }
+        self.left -= 1;
+        iterator_result(self.left, false)
+    }
+}
+
+#[wasm_bindgen_test]
+fn fallible_constructors() {
+    js_fallible_constructors();
+}
+
+#[wasm_bindgen]
+pub struct Fallible {
+    value: u32,
+}
+
+#[wasm_bindgen]
+impl Fallible {
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: u32) -> Result<Self, JsValue> {
+        if value == 0 {
+            return Err(make_error("zero isn't allowed"));
+        }
+        Ok(Fallible { value })
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[wasm_bindgen_test]
+fn typed_errors() {
+    js_typed_errors();
+}
+
+#[wasm_bindgen]
+pub struct OddNumber {
+    value: u32,
+}
+
+#[wasm_bindgen]
+impl OddNumber {
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[wasm_bindgen]
+pub fn checked_half(n: u32) -> Result<u32, OddNumber> {
+    if n % 2 == 1 {
+        return Err(OddNumber { value: n });
+    }
+    Ok(n / 2)
+}
+
+#[wasm_bindgen]
+pub fn checked_digit(n: u32) -> Result<u32, JsRangeError> {
+    if n > 9 {
+        return Err(JsRangeError::new("not a digit"));
+    }
+    Ok(n)
+}
+
+#[wasm_bindgen_test]
+fn fallible_methods() {
+    js_fallible_methods();
+}
+
+#[wasm_bindgen]
+pub struct Wallet {
+    balance: u32,
+}
+
+#[wasm_bindgen]
+impl Wallet {
+    pub fn open(balance: u32) -> Result<Wallet, JsValue> {
+        if balance == 0 {
+            return Err(JsValue::from_str("empty wallet"));
+        }
+        Ok(Wallet { balance })
+    }
+
+    pub fn spend(&mut self, amount: u32) -> Result<u32, JsValue> {
+        if amount > self.balance {
+            return Err(JsValue::from_str("insufficient funds"));
+        }
+        self.balance -= amount;
+        Ok(self.balance)
+    }
+
+    pub fn check(&self, amount: u32) -> Result<(), JsValue> {
+        if amount > self.balance {
+            return Err(JsValue::from_str("insufficient funds"));
+        }
+        Ok(())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn balance(&self) -> Result<u32, JsValue> {
+        Ok(self.balance)
+    }
+
+    pub fn close(self) -> Result<u32, JsValue> {
+        if self.balance > 100 {
+            return Err(JsValue::from_str("spend it first"));
+        }
+        Ok(self.balance)
+    }
+}
+
+#[wasm_bindgen]
+pub fn check_even(n: u32) -> Result<(), JsValue> {
+    if n % 2 == 1 {
+        return Err(JsValue::from_str("odd"));
+    }
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn indexing() {
+    js_indexing();
+}
+
+#[wasm_bindgen]
+pub struct Slots {
+    slots: Vec<Option<u32>>,
+}
+
+#[wasm_bindgen]
+impl Slots {
+    #[wasm_bindgen(constructor)]
+    pub fn new(len: usize) -> Slots {
+        Slots { slots: vec![None; len] }
+    }
+
+    #[wasm_bindgen(indexing_getter)]
+    pub fn get(&self, index: usize) -> Option<u32> {
+        self.slots.get(index).and_then(|slot| *slot)
+    }
+
+    #[wasm_bindgen(indexing_setter)]
+    pub fn set(&mut self, index: usize, value: u32) {
+        self.slots[index] = Some(value);
+    }
+
+    #[wasm_bindgen(indexing_deleter)]
+    pub fn clear(&mut self, index: usize) {
+        self.slots[index] = None;
+    }
+
+    pub fn filled(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_some()).count()
+    }
+}
+
+#[wasm_bindgen_test]
+fn iterator() {
+    js_iterator();
+}
+
+#[wasm_bindgen(iterator)]
+pub struct Digits {
+    left: u32,
+}
+
+#[wasm_bindgen]
+impl Digits {
+    #[wasm_bindgen(constructor)]
+    pub fn new(left: u32) -> Digits {
+        Digits { left }
+    }
+}
+
+impl Iterator for Digits {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.left == 0 {
+            return None;
+        }
+        self.left -= 1;
+        Some(self.left)
+    }
+}
+
+#[wasm_bindgen_test]
+fn conversions() {
+    js_conversions();
+}
+
+#[wasm_bindgen]
+pub struct Money {
+    cents: u32,
+}
+
+#[wasm_bindgen]
+impl Money {
+    #[wasm_bindgen(constructor)]
+    pub fn new(cents: u32) -> Money {
+        Money { cents }
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        format!("${}.{:02}", self.cents / 100, self.cents % 100)
+    }
+
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> u32 {
+        self.cents
+    }
+
+    #[wasm_bindgen(js_name = "Symbol.toPrimitive")]
+    pub fn to_primitive(&self, hint: JsValue) -> JsValue {
+        if hint.as_string().map_or(false, |hint| hint == "number") {
+            JsValue::from(self.cents)
+        } else {
+            JsValue::from(self.to_string())
+        }
+    }
+}
@@ -0,0 +1,30 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_failed_call_keeps_ownership = () => {
+    const a = new wasm.CleanupClass(1);
+    const b = new wasm.CleanupClass(2);
+
+    // The second argument is bad, so these throw before calling into wasm and
+    // `a` must still own its value afterwards.
+    assert.throws(() => wasm.cleanup_take_class(a, null));
+    assert.strictEqual(a.value(), 1);
+    assert.throws(() => a.consume_with(null));
+    assert.strictEqual(a.value(), 1);
+
+    assert.strictEqual(a.consume_with(b), 3);
+    assert.throws(() => a.value(), /moved value/);
+    b.free();
+};
+
+exports.js_failed_call_releases_arguments = () => {
+    const d = new wasm.CleanupClass(4);
+    for (let i = 0; i < 100; i++) {
+        assert.throws(() => wasm.cleanup_take_values('a', 'bc', 3, null));
+    }
+    assert.strictEqual(wasm.cleanup_take_values('a', 'bc', 3, d), 10);
+    d.free();
+    if (wasm.assertSlabEmpty) {
+        wasm.assertSlabEmpty();
+    }
+};
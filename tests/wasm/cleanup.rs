@@ -0,0 +1,47 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/cleanup.js")]
+extern {
+    fn js_failed_call_keeps_ownership();
+    fn js_failed_call_releases_arguments();
+}
+
+#[wasm_bindgen]
+pub struct CleanupClass(u32);
+
+#[wasm_bindgen]
+impl CleanupClass {
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: u32) -> CleanupClass {
+        CleanupClass(value)
+    }
+
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    pub fn consume_with(self, other: &CleanupClass) -> u32 {
+        self.0 + other.0
+    }
+}
+
+#[wasm_bindgen]
+pub fn cleanup_take_class(a: CleanupClass, b: &CleanupClass) -> u32 {
+    a.0 + b.0
+}
+
+#[wasm_bindgen]
+pub fn cleanup_take_values(a: &str, b: String, c: JsValue, d: &CleanupClass) -> u32 {
+    (a.len() + b.len()) as u32 + c.as_f64().unwrap() as u32 + d.0
+}
+
+#[wasm_bindgen_test]
+fn failed_call_keeps_ownership() {
+    js_failed_call_keeps_ownership();
+}
+
+#[wasm_bindgen_test]
+fn failed_call_releases_arguments() {
+    js_failed_call_releases_arguments();
+}
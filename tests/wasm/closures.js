@@ -1,3 +1,4 @@
+const wasm = require('wasm-bindgen-test.js');
 const assert = require('assert');
 
 exports.works_call = a => {
@@ -90,4 +91,27 @@ exports.string_arguments_call = a => {
 
 exports.string_ret_call = a => {
     assert.strictEqual(a('foo'), 'foobar');
-};
\ No newline at end of file
+};
+exports.returned_closures_call = () => {
+    const add = wasm.make_adder(3);
+    assert.strictEqual(add(4), 7);
+    assert.strictEqual(add(10), 13);
+    add.free();
+    assert.throws(() => add(1), /closure invoked recursively or destroyed already/);
+
+    const counter = wasm.make_counter();
+    assert.strictEqual(counter(), 1);
+    assert.strictEqual(counter(), 2);
+    counter.free();
+
+    const greet = wasm.make_greeter('Hello');
+    assert.strictEqual(greet('world'), 'Hello, world!');
+    greet.free();
+
+    const f = wasm.make_droppable();
+    f();
+    assert.strictEqual(wasm.returned_closure_dropped(), false);
+    f.free();
+    assert.strictEqual(wasm.returned_closure_dropped(), true);
+    f.free();
+};
@@ -2,9 +2,12 @@ use wasm_bindgen_test::*;
 use wasm_bindgen::prelude::*;
 use std::cell::Cell;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[wasm_bindgen(module = "tests/wasm/closures.js")]
 extern {
+    fn returned_closures_call();
+
     fn works_call(a: &Fn());
     fn works_thread(a: &Fn(u32) -> u32) -> u32;
 
@@ -207,3 +210,48 @@ fn string_ret() {
     });
     assert!(x);
 }
+
+#[wasm_bindgen]
+pub fn make_adder(n: u32) -> Box<Fn(u32) -> u32> {
+    Box::new(move |a| a + n)
+}
+
+#[wasm_bindgen]
+pub fn make_counter() -> Box<FnMut() -> u32> {
+    let mut cnt = 0;
+    Box::new(move || {
+        cnt += 1;
+        cnt
+    })
+}
+
+#[wasm_bindgen]
+pub fn make_greeter(greeting: String) -> Box<Fn(String) -> String> {
+    Box::new(move |name| format!("{}, {}!", greeting, name))
+}
+
+static RETURNED_CLOSURE_DROPPED: AtomicBool = AtomicBool::new(false);
+
+struct SetOnDrop;
+
+impl Drop for SetOnDrop {
+    fn drop(&mut self) {
+        RETURNED_CLOSURE_DROPPED.store(true, Ordering::SeqCst);
+    }
+}
+
+#[wasm_bindgen]
+pub fn make_droppable() -> Box<Fn()> {
+    let flag = SetOnDrop;
+    Box::new(move || drop(&flag))
+}
+
+#[wasm_bindgen]
+pub fn returned_closure_dropped() -> bool {
+    RETURNED_CLOSURE_DROPPED.load(Ordering::SeqCst)
+}
+
+#[wasm_bindgen_test]
+fn returned_closures() {
+    returned_closures_call();
+}
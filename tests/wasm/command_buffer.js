@@ -0,0 +1,39 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+class Recorder {
+    constructor() {
+        this.calls = [];
+    }
+
+    record(...args) {
+        assert.strictEqual(this, recorder);
+        this.calls.push(args);
+    }
+}
+
+let recorder = null;
+
+exports.js_replays_in_order = () => {
+    recorder = new Recorder();
+    function func(...args) {
+        assert.strictEqual(this, recorder);
+        assert.strictEqual(this.last, 'a string');
+        this.calls.push(['func', ...args]);
+    }
+    wasm.command_buffer_replay(recorder, func);
+    assert.deepStrictEqual(recorder.calls, [
+        [1, 2.5, true],
+        ['func', 7, 'a string'],
+        [],
+    ]);
+    assert.strictEqual(recorder.last, 'a string');
+};
+
+exports.js_exceptions_stop_replay = () => {
+    recorder = new Recorder();
+    assert.throws(() => {
+        wasm.command_buffer_throw(recorder, () => { throw new Error('replay failed'); });
+    }, /replay failed/);
+    assert.deepStrictEqual(recorder.calls, [[1]]);
+};
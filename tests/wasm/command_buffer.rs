@@ -0,0 +1,45 @@
+use wasm_bindgen::command_buffer::CommandBuffer;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/command_buffer.js")]
+extern {
+    fn js_replays_in_order();
+    fn js_exceptions_stop_replay();
+}
+
+#[wasm_bindgen]
+pub fn command_buffer_replay(target: &JsValue, func: &JsValue) {
+    let value = JsValue::from(7);
+    let mut commands = CommandBuffer::new();
+    assert!(commands.is_empty());
+    commands.method(target, "record").arg(1).arg(2.5f64).arg(true);
+    commands.set(target, "last", "a string");
+    commands.call(func, target).arg(&value).arg("a string");
+    commands.method(target, "record");
+    assert!(!commands.is_empty());
+    commands.flush();
+    assert!(commands.is_empty());
+
+    // Flushing again doesn't repeat anything.
+    commands.flush();
+}
+
+#[wasm_bindgen]
+pub fn command_buffer_throw(target: &JsValue, thrower: &JsValue) {
+    let mut commands = CommandBuffer::new();
+    commands.method(target, "record").arg(1);
+    commands.call(thrower, target);
+    commands.method(target, "record").arg(2);
+    commands.flush();
+}
+
+#[wasm_bindgen_test]
+fn replays_in_order() {
+    js_replays_in_order();
+}
+
+#[wasm_bindgen_test]
+fn exceptions_stop_replay() {
+    js_exceptions_stop_replay();
+}
@@ -0,0 +1,11 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_works = () => {
+    assert.strictEqual(wasm.SAMPLE_RATE, 44100);
+    assert.strictEqual(wasm.MIN_GAIN, -1.5);
+    assert.strictEqual(wasm.STEREO, true);
+    assert.strictEqual(wasm.CODEC, 'opus');
+    assert.strictEqual(wasm.maxVoices, 64);
+    assert.strictEqual(wasm.MAX_VOICES, undefined);
+};
@@ -0,0 +1,30 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/consts.js")]
+extern {
+    fn js_works();
+}
+
+/// The sample rate everything is mixed at.
+#[wasm_bindgen]
+pub const SAMPLE_RATE: u32 = 44100;
+
+#[wasm_bindgen]
+pub const MIN_GAIN: f64 = -1.5;
+
+#[wasm_bindgen]
+pub const STEREO: bool = true;
+
+#[wasm_bindgen]
+pub static CODEC: &str = "opus";
+
+#[wasm_bindgen(js_name = maxVoices)]
+pub const MAX_VOICES: i32 = 64;
+
+#[wasm_bindgen_test]
+fn works() {
+    assert_eq!(SAMPLE_RATE, 44100);
+    assert_eq!(MAX_VOICES, 64);
+    js_works();
+}
@@ -0,0 +1,47 @@
+const assert = require('assert');
+
+// Node doesn't have a DOM, so stand in for the parts of it which custom
+// elements use. This has to happen before the generated class is defined.
+const elements = new Map();
+if (typeof HTMLElement === 'undefined') {
+    global.HTMLElement = class HTMLElement {};
+}
+if (typeof customElements === 'undefined') {
+    global.customElements = {
+        define(name, cls) {
+            elements.set(name, cls);
+        },
+        get(name) {
+            return elements.get(name);
+        },
+    };
+}
+
+exports.js_lifecycle = () => {
+    const TestCounter = customElements.get('test-counter');
+    assert.strictEqual(TestCounter, require('wasm-bindgen-test.js').TestCounter);
+
+    const el = new TestCounter();
+    assert.ok(el instanceof HTMLElement);
+    assert.strictEqual(el.connections(), 0);
+    el.connectedCallback();
+    el.connectedCallback();
+    assert.strictEqual(el.connections(), 2);
+    el.disconnectedCallback();
+    assert.strictEqual(el.connections(), 1);
+    assert.strictEqual(el.adoptedCallback, undefined);
+    el.free();
+};
+
+exports.js_attributes = () => {
+    const TestCounter = customElements.get('test-counter');
+    assert.deepStrictEqual(TestCounter.observedAttributes, ['label']);
+
+    const el = new TestCounter();
+    assert.strictEqual(el.label(), undefined);
+    el.attributeChangedCallback('label', null, 'hello');
+    assert.strictEqual(el.label(), 'hello');
+    el.attributeChangedCallback('label', 'hello', null);
+    assert.strictEqual(el.label(), undefined);
+    el.free();
+};
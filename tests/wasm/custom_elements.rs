@@ -0,0 +1,61 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/custom_elements.js")]
+extern {
+    fn js_lifecycle();
+    fn js_attributes();
+}
+
+#[wasm_bindgen(custom_element = "test-counter", observed_attributes("label"))]
+pub struct TestCounter {
+    connections: u32,
+    label: Option<String>,
+}
+
+#[wasm_bindgen]
+impl TestCounter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> TestCounter {
+        TestCounter {
+            connections: 0,
+            label: None,
+        }
+    }
+
+    pub fn connected_callback(&mut self) {
+        self.connections += 1;
+    }
+
+    pub fn disconnected_callback(&mut self) {
+        self.connections -= 1;
+    }
+
+    pub fn attribute_changed_callback(
+        &mut self,
+        name: String,
+        _old: Option<String>,
+        new: Option<String>,
+    ) {
+        assert_eq!(name, "label");
+        self.label = new;
+    }
+
+    pub fn connections(&self) -> u32 {
+        self.connections
+    }
+
+    pub fn label(&self) -> Option<String> {
+        self.label.clone()
+    }
+}
+
+#[wasm_bindgen_test]
+fn lifecycle() {
+    js_lifecycle();
+}
+
+#[wasm_bindgen_test]
+fn attributes() {
+    js_attributes();
+}
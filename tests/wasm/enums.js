@@ -13,8 +13,20 @@ exports.js_c_style_enum = () => {
 exports.js_c_style_enum_with_custom_values = () => {
     assert.strictEqual(wasm.ColorWithCustomValues.Green, 21);
     assert.strictEqual(wasm.ColorWithCustomValues.Yellow, 34);
-    assert.strictEqual(wasm.ColorWithCustomValues.Red, 2);
+    assert.strictEqual(wasm.ColorWithCustomValues.Red, 35);
     assert.strictEqual(Object.keys(wasm.ColorWithCustomValues).length, 3);
 
     assert.strictEqual(wasm.enum_with_custom_values_cycle(wasm.ColorWithCustomValues.Green), wasm.ColorWithCustomValues.Yellow);
+    assert.strictEqual(wasm.enum_with_custom_values_cycle(wasm.ColorWithCustomValues.Yellow), wasm.ColorWithCustomValues.Red);
+};
+
+exports.js_string_enum = () => {
+    assert.strictEqual(wasm.Speed.Slow, 'slow');
+    assert.strictEqual(wasm.Speed.Fast, 'fast');
+    assert.strictEqual(wasm.Speed.Ludicrous, 'ludicrous speed');
+    assert.strictEqual(Object.keys(wasm.Speed).length, 3);
+
+    assert.strictEqual(wasm.speed_up(wasm.Speed.Slow), 'fast');
+    assert.strictEqual(wasm.speed_up('fast'), wasm.Speed.Ludicrous);
+    assert.throws(() => wasm.speed_up('sideways'), /invalid enum value passed/);
 };
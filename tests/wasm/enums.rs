@@ -6,6 +6,7 @@ use self::inner::ColorWithCustomValues;
 extern {
     fn js_c_style_enum();
     fn js_c_style_enum_with_custom_values();
+    fn js_string_enum();
 }
 
 #[wasm_bindgen]
@@ -44,6 +45,23 @@ pub fn enum_with_custom_values_cycle(color: ColorWithCustomValues) -> ColorWithC
     }
 }
 
+/// How fast to go
+#[wasm_bindgen]
+#[derive(Debug, PartialEq)]
+pub enum Speed {
+    Slow = "slow",
+    Fast = "fast",
+    Ludicrous = "ludicrous speed",
+}
+
+#[wasm_bindgen]
+pub fn speed_up(speed: Speed) -> Speed {
+    match speed {
+        Speed::Slow => Speed::Fast,
+        Speed::Fast | Speed::Ludicrous => Speed::Ludicrous,
+    }
+}
+
 #[wasm_bindgen_test]
 fn c_style_enum() {
     js_c_style_enum();
@@ -53,3 +71,37 @@ fn c_style_enum() {
 fn c_style_enum_with_custom_values() {
     js_c_style_enum_with_custom_values();
 }
+
+#[wasm_bindgen_test]
+fn enum_from_js_value() {
+    assert!(Color::from_js_value(&JsValue::from_f64(0.0)).is_some());
+    match Color::from_js_value(&JsValue::from(Color::Red)) {
+        Some(Color::Red) => {}
+        _ => panic!("expected Color::Red"),
+    }
+    match ColorWithCustomValues::from_js_value(&JsValue::from_f64(34.0)) {
+        Some(ColorWithCustomValues::Yellow) => {}
+        _ => panic!("expected ColorWithCustomValues::Yellow"),
+    }
+    assert!(Color::from_js_value(&JsValue::from_f64(3.0)).is_none());
+    assert!(Color::from_js_value(&JsValue::from_f64(0.5)).is_none());
+    assert!(Color::from_js_value(&JsValue::from_str("Green")).is_none());
+    assert_eq!(JsValue::from(ColorWithCustomValues::Green).as_f64(), Some(21.0));
+}
+
+#[wasm_bindgen_test]
+fn string_enum() {
+    js_string_enum();
+}
+
+#[wasm_bindgen_test]
+fn string_enum_from_js_value() {
+    assert_eq!(Speed::from_js_value(&JsValue::from_str("fast")), Some(Speed::Fast));
+    assert_eq!(
+        Speed::from_js_value(&JsValue::from(Speed::Ludicrous)),
+        Some(Speed::Ludicrous),
+    );
+    assert_eq!(Speed::from_js_value(&JsValue::from_str("Fast")), None);
+    assert_eq!(Speed::from_js_value(&JsValue::from_f64(1.0)), None);
+    assert_eq!(JsValue::from(Speed::Slow).as_string(), Some("slow".to_string()));
+}
@@ -0,0 +1,50 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+function withHook(f) {
+    const reports = [];
+    wasm.setWasmErrorHook((error, context) => reports.push({ error, context }));
+    try {
+        f();
+    } finally {
+        wasm.setWasmErrorHook(null);
+    }
+    return reports;
+}
+
+exports.call_error_hook_throw = () => wasm.error_hook_throw(1, 'x');
+
+exports.js_reports_export_errors = () => {
+    const reports = withHook(() => {
+        assert.throws(() => wasm.error_hook_throw(5, 'some string'), /error hook/);
+        assert.strictEqual(wasm.error_hook_ok(), 3);
+    });
+    assert.strictEqual(reports.length, 1);
+    assert.strictEqual(reports[0].error.message, 'error hook');
+    assert.strictEqual(reports[0].context.name, 'error_hook_throw');
+    // Arguments are only summarized in debug builds.
+    if (reports[0].context.args !== undefined) {
+        assert.deepStrictEqual(reports[0].context.args, ['5', '"some string"']);
+    }
+};
+
+exports.js_reports_nested_errors_once = () => {
+    const reports = withHook(() => {
+        assert.throws(() => wasm.error_hook_nested(), /error hook/);
+    });
+    assert.strictEqual(reports.length, 1);
+    assert.strictEqual(reports[0].context.name, 'error_hook_throw');
+};
+
+exports.js_hook_set_from_rust = () => {
+    const reports = [];
+    wasm.error_hook_set(error => reports.push(error));
+    try {
+        assert.throws(() => wasm.error_hook_throw(1, null));
+    } finally {
+        wasm.error_hook_set(undefined);
+    }
+    assert.strictEqual(reports.length, 1);
+    assert.throws(() => wasm.error_hook_throw(1, null));
+    assert.strictEqual(reports.length, 1);
+};
@@ -0,0 +1,46 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/error_hook.js")]
+extern {
+    fn call_error_hook_throw();
+
+    fn js_reports_export_errors();
+    fn js_reports_nested_errors_once();
+    fn js_hook_set_from_rust();
+}
+
+#[wasm_bindgen]
+pub fn error_hook_throw(_a: u32, _b: &JsValue) {
+    wasm_bindgen::throw("error hook");
+}
+
+#[wasm_bindgen]
+pub fn error_hook_ok() -> u32 {
+    3
+}
+
+#[wasm_bindgen]
+pub fn error_hook_nested() {
+    call_error_hook_throw();
+}
+
+#[wasm_bindgen]
+pub fn error_hook_set(hook: &JsValue) {
+    wasm_bindgen::set_error_hook(hook);
+}
+
+#[wasm_bindgen_test]
+fn reports_export_errors() {
+    js_reports_export_errors();
+}
+
+#[wasm_bindgen_test]
+fn reports_nested_errors_once() {
+    js_reports_nested_errors_once();
+}
+
+#[wasm_bindgen_test]
+fn hook_set_from_rust() {
+    js_hook_set_from_rust();
+}
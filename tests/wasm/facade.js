@@ -0,0 +1,23 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.facade_double = a => a * 2;
+
+exports.FacadeGreeter = class {
+    constructor(name) {
+        this.name = name;
+    }
+
+    greet() {
+        return `hello ${this.name}`;
+    }
+};
+
+exports.js_works = () => {
+    const counter = new wasm.FacadeCounter();
+    assert.strictEqual(counter.bump(), 1);
+    assert.strictEqual(counter.bump(), 2);
+    counter.free();
+
+    assert.strictEqual(wasm.facade_greeting('js'), 'hello js');
+};
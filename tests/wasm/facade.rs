@@ -0,0 +1,51 @@
+use wasm_bindgen_test::*;
+
+// Everything here goes through the re-export rather than `wasm_bindgen`, like
+// a crate that only depends on `wasm_bindgen` through another crate would.
+pub mod reexport {
+    pub use wasm_bindgen;
+}
+
+use self::reexport::wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/facade.js", wasm_bindgen = ::facade::reexport::wasm_bindgen)]
+extern {
+    fn js_works();
+    fn facade_double(a: u32) -> u32;
+
+    type FacadeGreeter;
+    #[wasm_bindgen(constructor)]
+    fn new(name: &str) -> FacadeGreeter;
+    #[wasm_bindgen(method)]
+    fn greet(this: &FacadeGreeter) -> String;
+}
+
+#[wasm_bindgen(wasm_bindgen = ::facade::reexport::wasm_bindgen)]
+pub struct FacadeCounter {
+    count: u32,
+}
+
+#[wasm_bindgen(wasm_bindgen = ::facade::reexport::wasm_bindgen)]
+impl FacadeCounter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> FacadeCounter {
+        FacadeCounter { count: 0 }
+    }
+
+    pub fn bump(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+}
+
+#[wasm_bindgen(wasm_bindgen = ::facade::reexport::wasm_bindgen)]
+pub fn facade_greeting(name: &str) -> JsValue {
+    FacadeGreeter::new(name).greet().into()
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    assert_eq!(facade_double(21), 42);
+    assert_eq!(FacadeGreeter::new("wasm").greet(), "hello wasm");
+    js_works();
+}
@@ -0,0 +1,6 @@
+exports.js_supports_bigint64 = () => typeof BigInt64Array === 'function';
+
+exports.js_supports_weak_ref = () => {
+    return typeof WeakRef === 'function' &&
+        typeof FinalizationRegistry === 'function';
+};
@@ -0,0 +1,15 @@
+use wasm_bindgen::features::{supports_bigint64, supports_weak_ref};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/features.js")]
+extern {
+    fn js_supports_bigint64() -> bool;
+    fn js_supports_weak_ref() -> bool;
+}
+
+#[wasm_bindgen_test]
+fn matches_js() {
+    assert_eq!(supports_bigint64(), js_supports_bigint64());
+    assert_eq!(supports_weak_ref(), js_supports_weak_ref());
+}
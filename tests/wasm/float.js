@@ -0,0 +1,14 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.f64_bits_js_identity = a => a;
+exports.optional_f64_bits_js_identity = a => a;
+
+exports.js_works = () => {
+    assert.strictEqual(wasm.payload_nan(), BigInt('0x7ff80000deadbeef'));
+    assert.strictEqual(wasm.f64_bits_to_bits(BigInt('0x7ff80000deadbeef')), BigInt('0x7ff80000deadbeef'));
+
+    const view = new DataView(new ArrayBuffer(8));
+    view.setFloat64(0, 2.5);
+    assert.strictEqual(wasm.f64_bits_to_bits(view.getBigUint64(0)), view.getBigUint64(0));
+};
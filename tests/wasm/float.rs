@@ -0,0 +1,36 @@
+use wasm_bindgen::float::F64Bits;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/float.js")]
+extern {
+    fn f64_bits_js_identity(a: F64Bits) -> F64Bits;
+    fn optional_f64_bits_js_identity(a: Option<F64Bits>) -> Option<F64Bits>;
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn payload_nan() -> F64Bits {
+    F64Bits(f64::from_bits(0x7ff8_0000_dead_beef))
+}
+
+#[wasm_bindgen]
+pub fn f64_bits_to_bits(a: F64Bits) -> u64 {
+    a.0.to_bits()
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
+
+#[wasm_bindgen_test]
+fn nan_payloads_survive_js() {
+    let nan = f64::from_bits(0xfff4_0000_0000_0001);
+    assert_eq!(f64_bits_js_identity(F64Bits(nan)).0.to_bits(), nan.to_bits());
+    assert_eq!(f64_bits_js_identity(F64Bits(1.5)).0, 1.5);
+
+    let ret = optional_f64_bits_js_identity(Some(F64Bits(nan)));
+    assert_eq!(ret.map(|n| n.0.to_bits()), Some(nan.to_bits()));
+    assert!(optional_f64_bits_js_identity(None).is_none());
+}
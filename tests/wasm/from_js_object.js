@@ -0,0 +1,11 @@
+exports.config = () => ({
+    width: 640,
+    fullScreen: true,
+    retries: 3,
+    title: 'demo',
+    ignored: 'extra properties are ignored',
+});
+
+exports.partial_config = () => ({ width: 320, fullScreen: false });
+
+exports.bad_config = () => ({ fullScreen: true });
@@ -0,0 +1,51 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/from_js_object.js")]
+extern {
+    fn config() -> JsValue;
+    fn partial_config() -> JsValue;
+    fn bad_config() -> JsValue;
+}
+
+#[derive(FromJsObject, Debug, PartialEq)]
+struct Config {
+    width: u32,
+    #[js_object(js_name = "fullScreen")]
+    full_screen: bool,
+    #[js_object(default)]
+    retries: u32,
+    title: Option<String>,
+}
+
+#[wasm_bindgen_test]
+fn reads_fields() {
+    assert_eq!(
+        Config::from_js_object(&config()).unwrap(),
+        Config {
+            width: 640,
+            full_screen: true,
+            retries: 3,
+            title: Some("demo".to_string()),
+        },
+    );
+}
+
+#[wasm_bindgen_test]
+fn defaults() {
+    assert_eq!(
+        Config::from_js_object(&partial_config()).unwrap(),
+        Config {
+            width: 320,
+            full_screen: false,
+            retries: 0,
+            title: None,
+        },
+    );
+}
+
+#[wasm_bindgen_test]
+fn missing_field() {
+    let err = Config::from_js_object(&bad_config()).unwrap_err();
+    assert_eq!(err.as_string().unwrap(), "missing property `width`");
+}
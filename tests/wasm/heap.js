@@ -0,0 +1,25 @@
+const assert = require('assert');
+
+let last = null;
+
+exports.heap_new_object = () => {
+    last = {};
+    return last;
+};
+
+exports.heap_assert_dump_contains_last = dump => {
+    const entry = dump.find(e => e.value === last);
+    assert.ok(entry !== undefined);
+    assert.strictEqual(typeof(entry.index), 'number');
+    assert.strictEqual(entry.refs, 1);
+    // Creation stack traces are only recorded in debug builds.
+    if (entry.stack !== undefined) {
+        assert.ok(/heapnewobject/.test(entry.stack));
+    }
+};
+
+exports.heap_assert_dump_omits_last = dump => {
+    assert.ok(dump.every(e => e.value !== last));
+};
+
+exports.heap_call = f => f();
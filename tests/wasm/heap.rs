@@ -0,0 +1,47 @@
+use wasm_bindgen::debug::{heap_dump, heap_stats};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/heap.js")]
+extern {
+    fn heap_new_object() -> JsValue;
+    fn heap_assert_dump_contains_last(dump: &JsValue);
+    fn heap_assert_dump_omits_last(dump: &JsValue);
+    fn heap_call(f: &Closure<Fn()>);
+}
+
+#[wasm_bindgen_test]
+fn stats_track_live_values() {
+    let before = heap_stats();
+    let a = heap_new_object();
+    let b = a.clone();
+    let c = heap_new_object();
+    let during = heap_stats();
+    // Clones share an entry in the heap, so only `a` and `c` count.
+    assert_eq!(during.live, before.live + 2);
+    assert!(during.capacity >= during.live);
+    drop((a, b, c));
+    assert_eq!(heap_stats().live, before.live);
+}
+
+#[wasm_bindgen_test]
+fn dump_lists_live_values() {
+    let value = heap_new_object();
+    let dump = heap_dump();
+    heap_assert_dump_contains_last(&dump);
+    drop(dump);
+    drop(value);
+    heap_assert_dump_omits_last(&heap_dump());
+}
+
+#[wasm_bindgen_test]
+fn live_count_tracks_values_and_closures() {
+    let before = wasm_bindgen::externref_heap_live_count();
+    let value = heap_new_object();
+    let closure = Closure::new(|| {});
+    heap_call(&closure);
+    assert_eq!(wasm_bindgen::externref_heap_live_count(), before + 2);
+    drop(closure);
+    drop(value);
+    assert_eq!(wasm_bindgen::externref_heap_live_count(), before);
+}
@@ -40,6 +40,18 @@ exports.NewConstructors = class {
   }
 };
 
+exports.FallibleConstructors = class {
+  constructor(field) {
+    if (field < 0)
+      throw new RangeError('field must be non-negative');
+    this.field = field;
+  }
+
+  get() {
+    return this.field;
+  }
+};
+
 let switch_called = false;
 class SwitchMethods {
   constructor() {
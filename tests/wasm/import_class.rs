@@ -29,6 +29,12 @@ extern {
     #[wasm_bindgen(method)]
     fn get(this: &NewConstructors) -> i32;
 
+    type FallibleConstructors;
+    #[wasm_bindgen(constructor, catch)]
+    fn new(arg: i32) -> Result<FallibleConstructors, JsValue>;
+    #[wasm_bindgen(method)]
+    fn get(this: &FallibleConstructors) -> i32;
+
     fn switch_methods_a();
     fn switch_methods_b();
     type SwitchMethods;
@@ -112,6 +118,13 @@ fn new_constructors() {
     assert_eq!(f.get(), 2);
 }
 
+#[wasm_bindgen_test]
+fn fallible_constructors() {
+    let f = FallibleConstructors::new(1).unwrap();
+    assert_eq!(f.get(), 1);
+    assert!(FallibleConstructors::new(-1).is_err());
+}
+
 #[wasm_bindgen_test]
 fn switch_methods() {
     assert!(!switch_methods_called());
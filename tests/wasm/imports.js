@@ -100,3 +100,54 @@ exports.assert_dead_import_not_generated = function() {
   const bindings = fs.readFileSync(filename);
   assert.ok(!bindings.includes("unused_import"));
 };
+
+exports.add_without_shim = (a, b) => a + b;
+
+exports.assert_no_shim_generated = function() {
+  const filename = require.resolve("wasm-bindgen-test");
+  const bindings = fs.readFileSync(filename, "utf8");
+  assert.ok(/__wbg_addwithoutshim_\w+ = add_without_shim;/.test(bindings));
+};
+
+exports.count_args = function(a, b, c) {
+  assert.strictEqual(a, 1);
+  if (b !== undefined)
+    assert.strictEqual(b, 2);
+  if (arguments.length > 2)
+    assert.strictEqual(typeof c, 'boolean');
+  return arguments.length;
+};
+
+// Only defined with a vendor prefix, as they would be in an older browser.
+global.webkitPrefixedCounter = class {
+  constructor() {
+    this.count = 0;
+  }
+
+  increment() {
+    return ++this.count;
+  }
+};
+
+global.mozPrefixedDouble = a => a * 2;
+
+exports.PolyfilledCounter = class {
+  constructor() {
+    this.count = 0;
+  }
+
+  increment() {
+    return ++this.count;
+  }
+};
+
+global.nativeOrPolyfill = () => 1;
+exports.nativeOrPolyfill = () => 2;
+
+exports.variadic_sum = function(first, ...rest) {
+  return rest.reduce((a, b) => a + b, first);
+};
+
+exports.variadic_types = function(...values) {
+  return values.map(v => typeof v).join(' ');
+};
@@ -1,5 +1,6 @@
 use wasm_bindgen_test::*;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 
 #[wasm_bindgen(module = "tests/wasm/imports.js")]
 extern {
@@ -50,11 +51,52 @@ extern {
 
     fn unused_import();
     fn assert_dead_import_not_generated();
+
+    #[wasm_bindgen(assert_no_shim)]
+    fn add_without_shim(a: u32, b: f64) -> f64;
+    fn assert_no_shim_generated();
+
+    #[wasm_bindgen(optional_args)]
+    fn count_args(a: u32, b: Option<f64>, c: Option<bool>) -> u32;
+
+    #[wasm_bindgen(variadic)]
+    fn variadic_sum(first: f64, rest: &[f64]) -> f64;
+    #[wasm_bindgen(variadic)]
+    fn variadic_types(values: &[JsValue]) -> JsValue;
 }
 
 #[wasm_bindgen]
 extern {
     fn parseInt(a: &str) -> u32;
+
+    #[wasm_bindgen(js_namespace = Math, js_name = max, assert_no_shim)]
+    fn math_max(a: f64, b: f64) -> f64;
+
+    #[wasm_bindgen(js_namespace = Math, js_name = max, optional_args)]
+    fn math_max_of(a: Option<f64>, b: Option<f64>) -> f64;
+
+    #[wasm_bindgen(js_namespace = Math, js_name = max, variadic)]
+    fn math_max_variadic(values: &[f64]) -> f64;
+
+    #[wasm_bindgen(vendor_prefix = webkit)]
+    type PrefixedCounter;
+    #[wasm_bindgen(constructor)]
+    fn new() -> PrefixedCounter;
+    #[wasm_bindgen(method)]
+    fn increment(this: &PrefixedCounter) -> u32;
+
+    #[wasm_bindgen(js_name = prefixedDouble, vendor_prefix = webkit, vendor_prefix = moz)]
+    fn prefixed_double(a: u32) -> u32;
+
+    #[wasm_bindgen(polyfill = "tests/wasm/imports.js")]
+    type PolyfilledCounter;
+    #[wasm_bindgen(constructor)]
+    fn new() -> PolyfilledCounter;
+    #[wasm_bindgen(method)]
+    fn increment(this: &PolyfilledCounter) -> u32;
+
+    #[wasm_bindgen(js_name = nativeOrPolyfill, polyfill = "tests/wasm/imports.js")]
+    fn native_or_polyfill() -> u32;
 }
 
 #[wasm_bindgen_test]
@@ -176,3 +218,55 @@ fn rename_static_with_string() {
 fn dead_imports_not_generated() {
     assert_dead_import_not_generated();
 }
+
+#[wasm_bindgen_test]
+fn imports_without_shims() {
+    assert_eq!(add_without_shim(1, 2.5), 3.5);
+    assert_eq!(math_max(1.0, 2.0), 2.0);
+    assert_no_shim_generated();
+}
+
+#[wasm_bindgen_test]
+fn trailing_none_arguments_are_left_out() {
+    assert_eq!(count_args(1, None, None), 1);
+    assert_eq!(count_args(1, Some(2.0), None), 2);
+    assert_eq!(count_args(1, None, Some(false)), 3);
+    assert_eq!(count_args(1, Some(2.0), Some(true)), 3);
+
+    // `Math.max(1, undefined)` would be `NaN`.
+    assert_eq!(math_max_of(Some(1.0), None), 1.0);
+    assert_eq!(math_max_of(None, None), ::std::f64::NEG_INFINITY);
+}
+
+#[wasm_bindgen_test]
+fn vendor_prefixed_globals() {
+    let counter = PrefixedCounter::new();
+    assert_eq!(counter.increment(), 1);
+    assert_eq!(counter.increment(), 2);
+    assert!(JsValue::from(counter).is_instance_of::<PrefixedCounter>());
+
+    assert_eq!(prefixed_double(21), 42);
+}
+
+#[wasm_bindgen_test]
+fn polyfilled_globals() {
+    let counter = PolyfilledCounter::new();
+    assert_eq!(counter.increment(), 1);
+    assert!(JsValue::from(counter).is_instance_of::<PolyfilledCounter>());
+
+    // The native version is used when there is one.
+    assert_eq!(native_or_polyfill(), 1);
+}
+
+#[wasm_bindgen_test]
+fn variadic_arguments_are_spread() {
+    assert_eq!(variadic_sum(1.0, &[]), 1.0);
+    assert_eq!(variadic_sum(1.0, &[2.0, 3.5]), 6.5);
+    assert_eq!(math_max_variadic(&[1.0, 4.0, 2.0]), 4.0);
+    assert_eq!(math_max_variadic(&[]), ::std::f64::NEG_INFINITY);
+
+    // The values are only borrowed, so they're still usable afterwards.
+    let values = [JsValue::from(1), JsValue::TRUE, JsValue::NULL];
+    assert_eq!(variadic_types(&values).as_string().unwrap(), "number boolean object");
+    assert_eq!(values[0].as_f64(), Some(1.0));
+}
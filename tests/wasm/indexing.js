@@ -0,0 +1,17 @@
+exports.new_indexable = () => {
+    return new Proxy({}, {
+        get(obj, prop) {
+            return prop in obj ? obj[prop] : prop.length;
+        },
+        set(obj, prop, value) {
+            obj[prop] = value;
+            return true;
+        },
+        deleteProperty(obj, prop) {
+            delete obj[prop];
+            return true;
+        },
+    });
+};
+
+exports.IndexableStatics = class {};
@@ -0,0 +1,44 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/indexing.js")]
+extern {
+    type Indexable;
+
+    fn new_indexable() -> Indexable;
+
+    #[wasm_bindgen(method, indexing_getter)]
+    fn get(this: &Indexable, prop: &str) -> u32;
+    #[wasm_bindgen(method, indexing_setter)]
+    fn set(this: &Indexable, prop: &str, val: u32);
+    #[wasm_bindgen(method, indexing_deleter)]
+    fn delete(this: &Indexable, prop: &str);
+
+    type IndexableStatics;
+
+    #[wasm_bindgen(static_method_of = IndexableStatics, indexing_getter)]
+    fn get(prop: u32) -> Option<String>;
+    #[wasm_bindgen(static_method_of = IndexableStatics, indexing_setter)]
+    fn set(prop: u32, val: &str);
+    #[wasm_bindgen(static_method_of = IndexableStatics, indexing_deleter)]
+    fn delete(prop: u32);
+}
+
+#[wasm_bindgen_test]
+fn methods() {
+    let obj = new_indexable();
+    assert_eq!(obj.get("ten"), 3);
+    obj.set("ten", 10);
+    assert_eq!(obj.get("ten"), 10);
+    obj.delete("ten");
+    assert_eq!(obj.get("ten"), 3);
+}
+
+#[wasm_bindgen_test]
+fn statics() {
+    assert_eq!(IndexableStatics::get(1), None);
+    IndexableStatics::set(1, "one");
+    assert_eq!(IndexableStatics::get(1), Some("one".to_string()));
+    IndexableStatics::delete(1);
+    assert_eq!(IndexableStatics::get(1), None);
+}
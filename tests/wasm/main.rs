@@ -1,9 +1,13 @@
 #![cfg(target_arch = "wasm32")]
 #![feature(use_extern_macros)]
 
+extern crate futures;
+extern crate js_sys;
+extern crate wasm_bindgen_futures;
 extern crate wasm_bindgen_test;
 extern crate wasm_bindgen;
 extern crate wasm_bindgen_test_crate_a;
+extern crate wasm_bindgen_test_crate_async_fn;
 extern crate wasm_bindgen_test_crate_b;
 
 #[cfg(feature = "serde-serialize")]
@@ -11,23 +15,47 @@ extern crate wasm_bindgen_test_crate_b;
 extern crate serde_derive;
 
 pub mod api;
+pub mod async_fn;
+#[cfg(feature = "catch-unwind")]
+pub mod catch_unwind;
 pub mod char;
 pub mod classes;
+pub mod cleanup;
 pub mod closures;
+pub mod command_buffer;
 pub mod comments;
+pub mod consts;
+pub mod custom_elements;
 pub mod duplicate_deps;
 pub mod duplicates;
 pub mod enums;
+pub mod facade;
+pub mod features;
+pub mod float;
+pub mod error_hook;
+pub mod from_js_object;
+pub mod heap;
 pub mod import_class;
 pub mod imports;
+pub mod indexing;
 pub mod js_objects;
 pub mod jscast;
+pub mod maps;
 pub mod math;
 pub mod node;
+pub mod num;
 pub mod option;
 pub mod optional_primitives;
+pub mod options_objects;
+pub mod shared_class;
 pub mod simple;
 pub mod slice;
+pub mod stack;
 pub mod structural;
+pub mod time;
+pub mod traits;
+pub mod tuples;
 pub mod u64;
+pub mod u128;
+pub mod unions;
 pub mod validate_prt;
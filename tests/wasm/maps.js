@@ -0,0 +1,21 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_works = () => {
+    assert.strictEqual(wasm.map_sum({ a: 1, b: 2.5 }), 3.5);
+    assert.strictEqual(wasm.map_sum({}), 0);
+    assert.throws(() => wasm.map_sum({ a: 'x' }), /of type number/);
+    assert.strictEqual(wasm.map_keys({ b: null, a: {} }), 'a,b');
+
+    assert.deepStrictEqual(wasm.map_doubled({ a: 1, b: 2 }), { a: 2, b: 4 });
+
+    const strings = wasm.map_strings();
+    assert.deepStrictEqual(Object.keys(strings), ['__proto__', 'a']);
+    assert.strictEqual(Object.getPrototypeOf(strings), Object.prototype);
+    assert.strictEqual(strings.a, 'x');
+
+    const negated = wasm.map_negated(new Map([['a', true], ['b', false]]));
+    assert.ok(negated instanceof Map);
+    assert.deepStrictEqual(Array.from(negated), [['a', false], ['b', true]]);
+    assert.throws(() => wasm.map_negated(new Map([[1, true]])), /must be strings/);
+};
@@ -0,0 +1,42 @@
+use std::collections::{BTreeMap, HashMap};
+
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/maps.js")]
+extern {
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn map_sum(map: HashMap<String, f64>) -> f64 {
+    map.values().sum()
+}
+
+#[wasm_bindgen]
+pub fn map_keys(map: BTreeMap<String, JsValue>) -> String {
+    map.keys().cloned().collect::<Vec<_>>().join(",")
+}
+
+#[wasm_bindgen]
+pub fn map_doubled(map: HashMap<String, u32>) -> HashMap<String, u32> {
+    map.into_iter().map(|(k, v)| (k, v * 2)).collect()
+}
+
+#[wasm_bindgen]
+pub fn map_strings() -> BTreeMap<String, String> {
+    let mut map = BTreeMap::new();
+    map.insert("a".to_string(), "x".to_string());
+    map.insert("__proto__".to_string(), "y".to_string());
+    map
+}
+
+#[wasm_bindgen(js_map)]
+pub fn map_negated(map: BTreeMap<String, bool>) -> BTreeMap<String, bool> {
+    map.into_iter().map(|(k, v)| (k, !v)).collect()
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
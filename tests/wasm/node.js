@@ -32,3 +32,5 @@ exports.test_works = function() {
 
   wasm.node_math(1.0, 2.0);
 };
+
+exports.runtime = () => 'node';
@@ -8,6 +8,19 @@ extern {
     fn hit();
 }
 
+// The tests only run in Node, so the web module is never imported.
+#[wasm_bindgen(web_module = "web-only", node_module = "tests/wasm/node.js")]
+extern {
+    fn runtime() -> String;
+}
+
+#[wasm_bindgen]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(module = "tests/wasm/node.js"))]
+extern {
+    #[wasm_bindgen(js_name = runtime)]
+    fn runtime_from_cfg_attr() -> String;
+}
+
 #[wasm_bindgen_test]
 fn works() {
     hit();
@@ -15,6 +28,12 @@ fn works() {
     test_works();
 }
 
+#[wasm_bindgen_test]
+fn modules_for_node() {
+    assert_eq!(runtime(), "node");
+    assert_eq!(runtime_from_cfg_attr(), "node");
+}
+
 #[wasm_bindgen]
 pub struct Foo {
     contents: u32,
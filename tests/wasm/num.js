@@ -0,0 +1,19 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.non_zero_u32_js_identity = a => a;
+exports.optional_non_zero_u8_js_identity = a => a;
+exports.wrapping_i32_js_identity = a => a;
+
+exports.js_works = () => {
+    assert.strictEqual(wasm.non_zero_u32_identity(1), 1);
+    assert.strictEqual(wasm.non_zero_u32_identity(65536), 65536);
+    assert.throws(() => wasm.non_zero_u32_identity(0), /zero passed as a `NonZeroU32`/);
+
+    assert.strictEqual(wasm.optional_non_zero_u8_identity(undefined), undefined);
+    assert.strictEqual(wasm.optional_non_zero_u8_identity(3), 3);
+    assert.throws(() => wasm.optional_non_zero_u8_identity(0), /zero passed as a `NonZeroU8`/);
+
+    assert.strictEqual(wasm.wrapping_i32_add(1, 2), 3);
+    assert.strictEqual(wasm.wrapping_i32_add(2147483647, 1), -2147483648);
+};
@@ -0,0 +1,34 @@
+use std::num::{NonZeroU32, NonZeroU8, Wrapping};
+
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/num.js")]
+extern {
+    fn non_zero_u32_js_identity(a: NonZeroU32) -> NonZeroU32;
+    fn optional_non_zero_u8_js_identity(a: Option<NonZeroU8>) -> Option<NonZeroU8>;
+    fn wrapping_i32_js_identity(a: Wrapping<i32>) -> Wrapping<i32>;
+
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn non_zero_u32_identity(a: NonZeroU32) -> NonZeroU32 { a }
+
+#[wasm_bindgen]
+pub fn optional_non_zero_u8_identity(a: Option<NonZeroU8>) -> Option<NonZeroU8> { a }
+
+#[wasm_bindgen]
+pub fn wrapping_i32_add(a: Wrapping<i32>, b: Wrapping<i32>) -> Wrapping<i32> { a + b }
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+
+    let n = NonZeroU32::new(7).unwrap();
+    assert_eq!(non_zero_u32_js_identity(n), n);
+    assert_eq!(optional_non_zero_u8_js_identity(None), None);
+    let n = NonZeroU8::new(255).unwrap();
+    assert_eq!(optional_non_zero_u8_js_identity(Some(n)), Some(n));
+    assert_eq!(wrapping_i32_js_identity(Wrapping(-3)), Wrapping(-3));
+}
@@ -26,3 +26,39 @@ exports.test_option_values = () => {
     assert.ok(x !== null && x !== undefined);
     assert.ok(x instanceof MyType);
 };
+
+exports.pass_through_counter = x => x;
+
+exports.test_option_structs = () => {
+    assert.strictEqual(wasm.rust_is_some_byref(null), false);
+    assert.strictEqual(wasm.rust_is_some_byref(new MyType()), true);
+
+    // `null` and `undefined` are both `None`, but other falsy values aren't.
+    assert.strictEqual(wasm.rust_is_some_js_value(undefined), false);
+    assert.strictEqual(wasm.rust_is_some_js_value(null), false);
+    assert.strictEqual(wasm.rust_is_some_js_value(0), true);
+    assert.strictEqual(wasm.rust_is_some_js_value(false), true);
+    assert.strictEqual(wasm.rust_is_some_js_value({}), true);
+
+    const counter = new wasm.OptionCounter(1);
+    assert.strictEqual(wasm.rust_count_or_zero(undefined), 0);
+    assert.strictEqual(wasm.rust_count_or_zero(counter), 1);
+    wasm.rust_increment(null);
+    wasm.rust_increment(counter);
+    assert.strictEqual(counter.count(), 2);
+
+    assert.strictEqual(wasm.rust_take_counter(null), 0);
+    assert.strictEqual(wasm.rust_take_counter(counter), 2);
+    assert.strictEqual(counter.ptr, 0);
+
+    assert.strictEqual(wasm.rust_maybe_counter(0), undefined);
+    const some = wasm.rust_maybe_counter(5);
+    assert.ok(some instanceof wasm.OptionCounter);
+    assert.strictEqual(some.count(), 5);
+    some.free();
+};
+
+exports.test_option_strings = () => {
+    assert.strictEqual(wasm.rust_greet(null), undefined);
+    assert.strictEqual(wasm.rust_greet("world"), "hello world");
+};
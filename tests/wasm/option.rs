@@ -18,6 +18,10 @@ extern {
     fn take_none_byref(t: Option<&MyType>);
     #[wasm_bindgen(js_name = take_some_byval)]
     fn take_some_byref(t: Option<&MyType>);
+
+    fn pass_through_counter(t: Option<OptionCounter>) -> Option<OptionCounter>;
+    fn test_option_structs();
+    fn test_option_strings();
 }
 
 #[wasm_bindgen_test]
@@ -59,3 +63,78 @@ fn import_by_ref() {
     take_none_byref(None);
     take_some_byref(Some(&MyType::new()));
 }
+
+#[wasm_bindgen_test]
+fn import_exported_struct() {
+    assert!(pass_through_counter(None).is_none());
+    let counter = pass_through_counter(Some(OptionCounter { count: 3 }));
+    assert_eq!(counter.map(|c| c.count), Some(3));
+}
+
+#[wasm_bindgen_test]
+fn export_exported_struct() {
+    test_option_structs();
+}
+
+#[wasm_bindgen_test]
+fn export_strings() {
+    test_option_strings();
+}
+
+#[wasm_bindgen]
+pub fn rust_is_some_byref(t: Option<&MyType>) -> bool {
+    t.is_some()
+}
+
+#[wasm_bindgen]
+pub fn rust_is_some_js_value(t: Option<&JsValue>) -> bool {
+    t.is_some()
+}
+
+#[wasm_bindgen]
+pub struct OptionCounter {
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl OptionCounter {
+    #[wasm_bindgen(constructor)]
+    pub fn new(count: u32) -> OptionCounter {
+        OptionCounter { count }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+#[wasm_bindgen]
+pub fn rust_count_or_zero(counter: Option<&OptionCounter>) -> u32 {
+    counter.map_or(0, |c| c.count)
+}
+
+#[wasm_bindgen]
+pub fn rust_increment(counter: Option<&mut OptionCounter>) {
+    if let Some(counter) = counter {
+        counter.count += 1;
+    }
+}
+
+#[wasm_bindgen]
+pub fn rust_take_counter(counter: Option<OptionCounter>) -> u32 {
+    counter.map_or(0, |c| c.count)
+}
+
+#[wasm_bindgen]
+pub fn rust_maybe_counter(count: u32) -> Option<OptionCounter> {
+    if count == 0 {
+        None
+    } else {
+        Some(OptionCounter { count })
+    }
+}
+
+#[wasm_bindgen]
+pub fn rust_greet(name: Option<String>) -> Option<String> {
+    name.map(|name| format!("hello {}", name))
+}
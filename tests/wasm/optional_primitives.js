@@ -99,4 +99,14 @@ exports.js_works = () => {
     assert.strictEqual(wasm.optional_char_identity(wasm.optional_char_none()), undefined);
     assert.strictEqual(wasm.optional_char_identity(wasm.optional_char_letter()), 'a');
     assert.strictEqual(wasm.optional_char_identity(wasm.optional_char_face()), '😀');
+
+    assert.strictEqual(wasm.optional_trailing_args(1), 11);
+    assert.strictEqual(wasm.optional_trailing_args(1, 2), 3);
+    assert.strictEqual(wasm.optional_trailing_args(1, undefined, true), 111);
+    assert.strictEqual(wasm.optional_trailing_args(1, 2, true), 103);
+
+    assert.strictEqual(wasm.default_trailing_args(1), 4.5);
+    assert.strictEqual(wasm.default_trailing_args(1, 2), 1.5);
+    assert.strictEqual(wasm.default_trailing_args(1, 2, 3), 6);
+    assert.strictEqual(wasm.default_trailing_args(1, 2, null), 103);
 };
@@ -270,6 +270,16 @@ pub fn optional_char_face() -> Option<char> { Some('😀') }
 #[wasm_bindgen]
 pub fn optional_char_identity(a: Option<char>) -> Option<char> { optional_char_js_identity(a) }
 
+#[wasm_bindgen(optional_args)]
+pub fn optional_trailing_args(a: u32, b: Option<u32>, c: Option<bool>) -> u32 {
+    a + b.unwrap_or(10) + if c.unwrap_or(false) { 100 } else { 0 }
+}
+
+#[wasm_bindgen(default(b = 5, c = -1.5))]
+pub fn default_trailing_args(a: f64, b: u32, c: Option<f64>) -> f64 {
+    a + b as f64 + c.unwrap_or(100.0)
+}
+
 #[wasm_bindgen_test]
 fn works() {
     js_works();
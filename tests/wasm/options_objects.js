@@ -0,0 +1,19 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_works = () => {
+    assert.strictEqual(wasm.options_draw(1, { color: 'red', width: 2 }), '1 red 2 false');
+    assert.strictEqual(
+        wasm.options_draw(3, { color: 'blue', width: 4.5, dashed: true }),
+        '3 blue 4.5 true',
+    );
+
+    // Options objects with only optional fields can be left out.
+    assert.strictEqual(wasm.options_label(), '12 ');
+    assert.strictEqual(wasm.options_label({ 'font-size': 20, text: 'hi' }), '20 hi');
+
+    const canvas = wasm.OptionsCanvas.new();
+    assert.strictEqual(canvas.draw({ color: 'red', width: 2 }), 2);
+    assert.strictEqual(canvas.draw({ color: 'red', width: 3, dashed: false }), 5);
+    canvas.free();
+};
@@ -0,0 +1,65 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/options_objects.js")]
+extern {
+    fn js_works();
+}
+
+#[wasm_bindgen(options)]
+pub struct DrawOpts {
+    color: String,
+    width: f64,
+    #[wasm_bindgen(js_name = dashed)]
+    is_dashed: Option<bool>,
+}
+
+#[wasm_bindgen]
+pub fn options_draw(x: f64, opts: DrawOpts) -> String {
+    format!(
+        "{} {} {} {}",
+        x,
+        opts.color,
+        opts.width,
+        opts.is_dashed.unwrap_or(false),
+    )
+}
+
+#[wasm_bindgen(options)]
+pub struct LabelOpts {
+    #[wasm_bindgen(js_name = "font-size")]
+    font_size: Option<u32>,
+    text: Option<String>,
+}
+
+#[wasm_bindgen]
+pub fn options_label(opts: LabelOpts) -> String {
+    format!(
+        "{} {}",
+        opts.font_size.unwrap_or(12),
+        opts.text.unwrap_or_default(),
+    )
+}
+
+#[wasm_bindgen]
+pub struct OptionsCanvas {
+    lines: u32,
+}
+
+#[wasm_bindgen]
+impl OptionsCanvas {
+    pub fn new() -> OptionsCanvas {
+        OptionsCanvas { lines: 0 }
+    }
+
+    pub fn draw(&mut self, opts: DrawOpts) -> u32 {
+        assert_eq!(opts.color, "red");
+        self.lines += opts.width as u32;
+        self.lines
+    }
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
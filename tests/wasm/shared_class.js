@@ -0,0 +1,25 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+// An exported struct of some other wasm module, with the same shared class
+// name.
+class OtherPlugin {
+    get [Symbol.for('wasm-bindgen.shared_class')]() {
+        return 'tests.SharedPlugin';
+    }
+
+    id() {
+        return 7;
+    }
+}
+
+exports.js_shared_class = () => {
+    const plugin = new wasm.SharedPlugin(3);
+    assert.strictEqual(wasm.shared_plugin_id(plugin), 3);
+    plugin.free();
+
+    assert.strictEqual(wasm.shared_plugin_id(new OtherPlugin()), 7);
+    assert.strictEqual(wasm.shared_plugin_id({ id: () => 1 }), undefined);
+    assert.strictEqual(wasm.shared_plugin_id(null), undefined);
+    assert.strictEqual(wasm.shared_plugin_id(3), undefined);
+};
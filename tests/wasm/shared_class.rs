@@ -0,0 +1,44 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/shared_class.js")]
+extern {
+    fn js_shared_class();
+}
+
+#[wasm_bindgen(shared_class = "tests.SharedPlugin")]
+pub struct SharedPlugin {
+    id: u32,
+}
+
+#[wasm_bindgen]
+impl SharedPlugin {
+    #[wasm_bindgen(constructor)]
+    pub fn new(id: u32) -> SharedPlugin {
+        SharedPlugin { id }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+// How another wasm module would declare `SharedPlugin`.
+#[wasm_bindgen]
+extern {
+    #[wasm_bindgen(shared_class = "tests.SharedPlugin")]
+    type Plugin;
+    #[wasm_bindgen(method, structural)]
+    fn id(this: &Plugin) -> u32;
+}
+
+#[wasm_bindgen]
+pub fn shared_plugin_id(plugin: JsValue) -> Option<u32> {
+    plugin.dyn_ref::<Plugin>().map(|p| p.id())
+}
+
+#[wasm_bindgen_test]
+fn shared_class() {
+    js_shared_class();
+}
@@ -161,6 +161,11 @@ exports.import_mut_js_u32 = import_mut_foo;
 exports.import_mut_js_f32 = import_mut_foo;
 exports.import_mut_js_f64 = import_mut_foo;
 
+exports.import_mut_growing_memory = a => {
+    wasm.grow_memory();
+    import_mut_foo(a);
+};
+
 const export_mut_run = (a, rust) => {
     assert.strictEqual(a.length, 3);
     a[0] = 1;
@@ -204,3 +209,31 @@ exports.js_return_vec = () => {
         assert.strictEqual(bad[8], 9);
     }
 };
+
+exports.js_aligned = () => {
+    for (let len = 1; len < 8; len++) {
+        assert.strictEqual(wasm.u8_slice_misalignment(new Uint8Array(len)), 0);
+        assert.strictEqual(wasm.f32_slice_misalignment(new Float32Array(len)), 0);
+    }
+};
+
+exports.js_return_vec_of_structs = () => {
+    const counters = wasm.counters(3);
+    assert.ok(Array.isArray(counters));
+    assert.strictEqual(counters.length, 3);
+    counters.forEach((counter, i) => {
+        assert.ok(counter instanceof wasm.SliceCounter);
+        assert.strictEqual(counter.count, i);
+        counter.free();
+    });
+
+    assert.strictEqual(wasm.maybe_counters(0), undefined);
+    const some = wasm.maybe_counters(1);
+    assert.strictEqual(some.length, 1);
+    some[0].free();
+};
+
+exports.js_return_vec_of_strings = () => {
+    assert.deepStrictEqual(wasm.words('a bc  def'), ['a', 'bc', 'def']);
+    assert.deepStrictEqual(wasm.words(''), []);
+};
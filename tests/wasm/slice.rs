@@ -12,6 +12,12 @@ extern {
     fn js_export_mut();
 
     fn js_return_vec();
+
+    fn js_aligned();
+
+    fn js_return_vec_of_structs();
+
+    fn js_return_vec_of_strings();
 }
 
 macro_rules! export_macro {
@@ -141,6 +147,24 @@ import_mut_macro! {
     (import_mut_rust_f64, import_mut_js_f64, f64)
 }
 
+#[wasm_bindgen(module = "tests/wasm/slice.js")]
+extern {
+    fn import_mut_growing_memory(a: &mut [f32]);
+}
+
+#[wasm_bindgen]
+pub fn grow_memory() {
+    // Leaked so that the allocator can't hand the same memory back later on.
+    Box::leak(vec![0u8; 1 << 20].into_boxed_slice());
+}
+
+#[wasm_bindgen_test]
+fn import_mut_grows_memory() {
+    let mut buf = [1.0, 2.0, 3.0];
+    import_mut_growing_memory(&mut buf);
+    assert_eq!(buf, [4.0, 5.0, 3.0]);
+}
+
 macro_rules! export_mut_macro {
     ($(($i:ident, $n:ident))*) => ($(
         #[wasm_bindgen]
@@ -208,3 +232,52 @@ impl ReturnVecApplication {
 fn return_vec() {
     js_return_vec();
 }
+
+#[wasm_bindgen]
+pub fn u8_slice_misalignment(a: &[u8]) -> u32 {
+    a.as_ptr() as u32 % 16
+}
+
+#[wasm_bindgen]
+pub fn f32_slice_misalignment(a: &[f32]) -> u32 {
+    a.as_ptr() as u32 % 16
+}
+
+#[wasm_bindgen_test]
+fn aligned() {
+    js_aligned();
+}
+
+#[wasm_bindgen]
+pub struct SliceCounter {
+    pub count: u32,
+}
+
+#[wasm_bindgen]
+pub fn counters(n: u32) -> Vec<SliceCounter> {
+    (0..n).map(|count| SliceCounter { count }).collect()
+}
+
+#[wasm_bindgen]
+pub fn maybe_counters(n: u32) -> Option<Vec<SliceCounter>> {
+    if n == 0 {
+        None
+    } else {
+        Some(counters(n))
+    }
+}
+
+#[wasm_bindgen_test]
+fn return_vec_of_structs() {
+    js_return_vec_of_structs();
+}
+
+#[wasm_bindgen]
+pub fn words(sentence: &str) -> Vec<String> {
+    sentence.split_whitespace().map(|s| s.to_string()).collect()
+}
+
+#[wasm_bindgen_test]
+fn return_vec_of_strings() {
+    js_return_vec_of_strings();
+}
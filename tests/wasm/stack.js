@@ -0,0 +1,37 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.reenter = depth => {
+    const obj = { depth };
+    assert.strictEqual(wasm.stack_borrow_and_reenter(obj, depth), obj);
+};
+
+exports.fail_between_borrows = () => {
+    // The first argument isn't a `StackClass`, so this throws after the
+    // wrapper has started pushing borrowed arguments but before the second
+    // one made it onto the stack.
+    assert.throws(() => wasm.stack_class_then_borrow(null, {}));
+};
+
+exports.throw_from_js = () => {
+    throw new Error('thrown from js');
+};
+
+exports.js_reentrant_borrows = () => {
+    const obj = {};
+    assert.strictEqual(wasm.stack_borrow_and_reenter(obj, 5), obj);
+};
+
+exports.js_failed_call_keeps_outer_borrows = () => {
+    const obj = {};
+    assert.strictEqual(wasm.stack_borrow_around_failure(obj), obj);
+};
+
+exports.js_exception_unwinds_stack = () => {
+    assert.throws(() => wasm.stack_borrow_and_throw({}), /thrown from js/);
+    const obj = {};
+    assert.strictEqual(wasm.stack_borrow_and_reenter(obj, 2), obj);
+    if (wasm.assertStackEmpty) {
+        wasm.assertStackEmpty();
+    }
+};
@@ -0,0 +1,53 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/stack.js")]
+extern {
+    fn reenter(depth: u32);
+    fn fail_between_borrows();
+    fn throw_from_js();
+
+    fn js_reentrant_borrows();
+    fn js_failed_call_keeps_outer_borrows();
+    fn js_exception_unwinds_stack();
+}
+
+#[wasm_bindgen]
+pub struct StackClass;
+
+#[wasm_bindgen]
+pub fn stack_borrow_and_reenter(obj: &JsValue, depth: u32) -> JsValue {
+    if depth > 0 {
+        reenter(depth - 1);
+    }
+    obj.clone()
+}
+
+#[wasm_bindgen]
+pub fn stack_class_then_borrow(_a: &StackClass, _b: &JsValue) {}
+
+#[wasm_bindgen]
+pub fn stack_borrow_around_failure(obj: &JsValue) -> JsValue {
+    fail_between_borrows();
+    obj.clone()
+}
+
+#[wasm_bindgen]
+pub fn stack_borrow_and_throw(_obj: &JsValue) {
+    throw_from_js();
+}
+
+#[wasm_bindgen_test]
+fn reentrant_borrows() {
+    js_reentrant_borrows();
+}
+
+#[wasm_bindgen_test]
+fn failed_call_keeps_outer_borrows() {
+    js_failed_call_keeps_outer_borrows();
+}
+
+#[wasm_bindgen_test]
+fn exception_unwinds_stack() {
+    js_exception_unwinds_stack();
+}
@@ -0,0 +1,28 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.duration_js_identity = a => a;
+exports.optional_duration_js_identity = a => a;
+exports.system_time_js_identity = a => a;
+exports.optional_system_time_js_identity = a => a;
+exports.js_epoch_plus_one_second = () => new Date(1000);
+
+exports.js_works = () => {
+    assert.strictEqual(wasm.duration_identity(0), 0);
+    assert.strictEqual(wasm.duration_identity(1.5), 1.5);
+    assert.strictEqual(wasm.duration_millis(2500), 2500);
+    assert.strictEqual(wasm.optional_duration_identity(undefined), undefined);
+    assert.strictEqual(wasm.optional_duration_identity(10), 10);
+    assert.throws(() => wasm.duration_identity(-1), /negative or non-finite/);
+    assert.throws(() => wasm.duration_identity(NaN), /negative or non-finite/);
+
+    const date = new Date(2018, 7, 28);
+    const ret = wasm.system_time_identity(date);
+    assert.ok(ret instanceof Date);
+    assert.strictEqual(ret.getTime(), date.getTime());
+    assert.strictEqual(wasm.optional_system_time_identity(undefined), undefined);
+    assert.strictEqual(wasm.optional_system_time_identity(date).getTime(), date.getTime());
+    assert.strictEqual(wasm.epoch_plus_one_day().getTime(), 24 * 60 * 60 * 1000);
+    assert.throws(() => wasm.system_time_identity(new Date(NaN)), /invalid `Date`/);
+    assert.throws(() => wasm.system_time_identity(new Date(-5000)), /before 1970/);
+};
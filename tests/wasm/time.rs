@@ -0,0 +1,53 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/time.js")]
+extern {
+    fn duration_js_identity(a: Duration) -> Duration;
+    fn optional_duration_js_identity(a: Option<Duration>) -> Option<Duration>;
+    fn system_time_js_identity(a: SystemTime) -> SystemTime;
+    fn optional_system_time_js_identity(a: Option<SystemTime>) -> Option<SystemTime>;
+    fn js_epoch_plus_one_second() -> SystemTime;
+
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn duration_identity(a: Duration) -> Duration { a }
+
+#[wasm_bindgen]
+pub fn optional_duration_identity(a: Option<Duration>) -> Option<Duration> { a }
+
+#[wasm_bindgen]
+pub fn duration_millis(a: Duration) -> f64 {
+    a.as_secs() as f64 * 1000.0 + a.subsec_nanos() as f64 / 1_000_000.0
+}
+
+#[wasm_bindgen]
+pub fn system_time_identity(a: SystemTime) -> SystemTime { a }
+
+#[wasm_bindgen]
+pub fn optional_system_time_identity(a: Option<SystemTime>) -> Option<SystemTime> { a }
+
+#[wasm_bindgen]
+pub fn epoch_plus_one_day() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(24 * 60 * 60)
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+
+    let d = Duration::from_millis(1500);
+    assert_eq!(duration_js_identity(d), d);
+    assert_eq!(optional_duration_js_identity(None), None);
+    assert_eq!(optional_duration_js_identity(Some(d)), Some(d));
+
+    let t = UNIX_EPOCH + Duration::from_millis(1_234_567);
+    assert_eq!(system_time_js_identity(t), t);
+    assert_eq!(optional_system_time_js_identity(None), None);
+    assert_eq!(optional_system_time_js_identity(Some(t)), Some(t));
+    assert_eq!(js_epoch_plus_one_second(), UNIX_EPOCH + Duration::from_secs(1));
+}
@@ -0,0 +1,25 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_works = () => {
+    const square = wasm.trait_square(2);
+    assert.ok(square instanceof wasm.Shape);
+    assert.strictEqual(square.area(), 4);
+    square.scale(1.5);
+    assert.strictEqual(square.area(), 9);
+    assert.strictEqual(square.describeAs('big'), 'big square');
+
+    // Rust objects can be passed back both by reference and by value...
+    assert.strictEqual(wasm.trait_area(square), 9);
+    assert.strictEqual(wasm.trait_take(square), 9);
+    assert.throws(() => square.area(), /moved value/);
+
+    // ... and any JS object with the same methods is a `&dyn Shape` too.
+    const circle = {
+        area() { return 3; },
+        scale() {},
+        describeAs(prefix) { return `${prefix} circle`; },
+    };
+    assert.strictEqual(wasm.trait_area(circle), 3);
+    assert.strictEqual(wasm.trait_describe(circle), 'a circle');
+};
@@ -0,0 +1,57 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/traits.js")]
+extern {
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub trait Shape {
+    /// The area of the shape.
+    fn area(&self) -> f64;
+    fn scale(&mut self, by: f64);
+    #[wasm_bindgen(js_name = describeAs)]
+    fn describe_as(&self, prefix: &str) -> String;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn scale(&mut self, by: f64) {
+        self.0 *= by;
+    }
+
+    fn describe_as(&self, prefix: &str) -> String {
+        format!("{} square", prefix)
+    }
+}
+
+#[wasm_bindgen]
+pub fn trait_square(side: f64) -> Box<Shape> {
+    Box::new(Square(side))
+}
+
+#[wasm_bindgen]
+pub fn trait_area(shape: &Shape) -> f64 {
+    shape.area()
+}
+
+#[wasm_bindgen]
+pub fn trait_describe(shape: &Shape) -> String {
+    shape.describe_as("a")
+}
+
+#[wasm_bindgen]
+pub fn trait_take(shape: Box<Shape>) -> f64 {
+    shape.area()
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
@@ -0,0 +1,22 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_works = () => {
+    assert.deepStrictEqual(wasm.tuple_pair(-1, 2.5), [-1, 2.5]);
+    assert.deepStrictEqual(wasm.tuple_mixed(), [true, 'x', 1.5]);
+    assert.deepStrictEqual(wasm.tuple_unsigned(), [255, 4294967295]);
+    assert.deepStrictEqual(wasm.tuple_string('abc'), ['ABC', 3]);
+
+    const obj = {};
+    const [same, point] = wasm.tuple_objects(obj);
+    assert.strictEqual(same, obj);
+    assert.ok(point instanceof wasm.TuplePoint);
+    assert.strictEqual(point.x, 3);
+    point.free();
+
+    const [a, b, c] = wasm.tuple_vectors();
+    assert.deepStrictEqual(Array.from(a), [1, 2]);
+    assert.ok(a instanceof Uint8Array);
+    assert.strictEqual(b, 'hi');
+    assert.deepStrictEqual(Array.from(c), [0.5]);
+};
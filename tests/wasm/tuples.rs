@@ -0,0 +1,37 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/tuples.js")]
+extern {
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub struct TuplePoint {
+    pub x: u32,
+}
+
+#[wasm_bindgen]
+pub fn tuple_pair(a: i32, b: f64) -> (i32, f64) { (a, b) }
+
+#[wasm_bindgen]
+pub fn tuple_mixed() -> (bool, char, f64) { (true, 'x', 1.5) }
+
+#[wasm_bindgen]
+pub fn tuple_unsigned() -> (u8, u32) { (255, 4294967295) }
+
+#[wasm_bindgen]
+pub fn tuple_string(a: &str) -> (String, usize) { (a.to_uppercase(), a.len()) }
+
+#[wasm_bindgen]
+pub fn tuple_objects(a: JsValue) -> (JsValue, TuplePoint) { (a, TuplePoint { x: 3 }) }
+
+#[wasm_bindgen]
+pub fn tuple_vectors() -> (Vec<u8>, String, Vec<f64>) {
+    (vec![1, 2], "hi".to_string(), vec![0.5])
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
@@ -0,0 +1,34 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.i128_js_identity = a => a;
+exports.u128_js_identity = a => a;
+exports.optional_u128_js_identity = a => a;
+
+exports.js_works = () => {
+    const i128_min = -(BigInt('1') << BigInt('127'));
+    const i128_max = (BigInt('1') << BigInt('127')) - BigInt('1');
+    const u128_max = (BigInt('1') << BigInt('128')) - BigInt('1');
+
+    assert.strictEqual(wasm.u128_zero(), BigInt('0'));
+    assert.strictEqual(wasm.i128_neg_one(), BigInt('-1'));
+    assert.strictEqual(wasm.i128_min(), i128_min);
+    assert.strictEqual(wasm.i128_max(), i128_max);
+    assert.strictEqual(wasm.u128_max(), u128_max);
+
+    assert.strictEqual(wasm.i128_rust_identity(BigInt('0')), BigInt('0'));
+    assert.strictEqual(wasm.i128_rust_identity(BigInt('-1')), BigInt('-1'));
+    assert.strictEqual(wasm.i128_rust_identity(i128_min), i128_min);
+    assert.strictEqual(wasm.i128_rust_identity(i128_max), i128_max);
+    assert.strictEqual(wasm.u128_rust_identity(u128_max), u128_max);
+    assert.strictEqual(wasm.u128_rust_identity(BigInt('1') << BigInt('128')), BigInt('0'));
+
+    assert.strictEqual(wasm.optional_u128_rust_identity(undefined), undefined);
+    assert.strictEqual(wasm.optional_u128_rust_identity(u128_max), u128_max);
+
+    const uuid = BigInt('0x0123456789abcdef0011223344556677');
+    assert.deepStrictEqual(
+        wasm.u128_limbs(uuid),
+        new Uint32Array([0x44556677, 0x00112233, 0x89abcdef, 0x01234567]),
+    );
+};
@@ -0,0 +1,46 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/u128.js")]
+extern {
+    fn i128_js_identity(a: i128) -> i128;
+    fn u128_js_identity(a: u128) -> u128;
+    fn optional_u128_js_identity(a: Option<u128>) -> Option<u128>;
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn u128_zero() -> u128 { 0 }
+
+#[wasm_bindgen]
+pub fn i128_neg_one() -> i128 { -1 }
+
+#[wasm_bindgen]
+pub fn i128_min() -> i128 { i128::min_value() }
+
+#[wasm_bindgen]
+pub fn i128_max() -> i128 { i128::max_value() }
+
+#[wasm_bindgen]
+pub fn u128_max() -> u128 { u128::max_value() }
+
+#[wasm_bindgen]
+pub fn i128_rust_identity(a: i128) -> i128 { i128_js_identity(a) }
+
+#[wasm_bindgen]
+pub fn u128_rust_identity(a: u128) -> u128 { u128_js_identity(a) }
+
+#[wasm_bindgen]
+pub fn optional_u128_rust_identity(a: Option<u128>) -> Option<u128> {
+    optional_u128_js_identity(a)
+}
+
+#[wasm_bindgen]
+pub fn u128_limbs(a: u128) -> Vec<u32> {
+    (0..4).map(|i| (a >> (32 * i)) as u32).collect()
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
@@ -0,0 +1,18 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_classes = () => {
+    assert.strictEqual(wasm.union_area(wasm.UnionCircle.new(2)), 12);
+    assert.strictEqual(wasm.union_area(wasm.UnionSquare.new(3)), 9);
+
+    const square = wasm.UnionSquare.new(1);
+    wasm.union_area(square);
+    assert.throws(() => wasm.union_area(square), /Attempt to use a moved value/);
+    assert.throws(() => wasm.union_area('square'), /expected a value of type `UnionCircle \| UnionSquare`/);
+};
+
+exports.js_class_or_string = () => {
+    assert.strictEqual(wasm.union_describe(wasm.UnionCircle.new(1), 1), 'circle 1');
+    assert.strictEqual(wasm.union_describe('ab', 2), 'abab');
+    assert.throws(() => wasm.union_describe(3, 1), /expected a value of type `UnionCircle \| string`/);
+};
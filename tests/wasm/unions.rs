@@ -0,0 +1,71 @@
+use wasm_bindgen_test::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "tests/wasm/unions.js")]
+extern {
+    fn js_classes();
+    fn js_class_or_string();
+}
+
+#[wasm_bindgen]
+pub struct UnionCircle {
+    radius: f64,
+}
+
+#[wasm_bindgen]
+impl UnionCircle {
+    pub fn new(radius: f64) -> UnionCircle {
+        UnionCircle { radius }
+    }
+}
+
+#[wasm_bindgen]
+pub struct UnionSquare {
+    side: f64,
+}
+
+#[wasm_bindgen]
+impl UnionSquare {
+    pub fn new(side: f64) -> UnionSquare {
+        UnionSquare { side }
+    }
+}
+
+#[wasm_bindgen]
+pub enum UnionShape {
+    Circle(UnionCircle),
+    Square(UnionSquare),
+}
+
+#[wasm_bindgen]
+pub fn union_area(shape: UnionShape) -> f64 {
+    match shape {
+        UnionShape::Circle(c) => c.radius * c.radius * 3.0,
+        UnionShape::Square(s) => s.side * s.side,
+    }
+}
+
+#[wasm_bindgen]
+pub enum UnionCircleOrName {
+    Circle(UnionCircle),
+    Name(String),
+}
+
+#[wasm_bindgen]
+pub fn union_describe(shape: UnionCircleOrName, times: u32) -> String {
+    let s = match shape {
+        UnionCircleOrName::Circle(c) => format!("circle {}", c.radius),
+        UnionCircleOrName::Name(s) => s,
+    };
+    s.repeat(times as usize)
+}
+
+#[wasm_bindgen_test]
+fn classes() {
+    js_classes();
+}
+
+#[wasm_bindgen_test]
+fn class_or_string() {
+    js_class_or_string();
+}